@@ -0,0 +1,14 @@
+(function () {
+  let array = [];
+
+  for (let i = 0; i < 1000; i++) {
+    array.push(i);
+  }
+
+  let sum = 0;
+  for (let i = 0; i < array.length; i++) {
+    sum += array[i];
+  }
+
+  return sum;
+})();
@@ -0,0 +1,12 @@
+(function () {
+  function add(a, b) {
+    return a + b;
+  }
+
+  let total = 0;
+  for (let i = 0; i < 1000; i++) {
+    total = add(total, i);
+  }
+
+  return total;
+})();
@@ -0,0 +1,9 @@
+(function () {
+  let sum = 0;
+
+  for (let i = 0; i < 1000; i++) {
+    sum += i;
+  }
+
+  return sum;
+})();
@@ -0,0 +1,9 @@
+(function () {
+  let last = null;
+
+  for (let i = 0; i < 1000; i++) {
+    last = { index: i, previous: last, tags: ["a", "b"] };
+  }
+
+  return last.index;
+})();
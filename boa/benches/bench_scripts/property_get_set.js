@@ -0,0 +1,11 @@
+(function () {
+  let point = { x: 0, y: 0, z: 0 };
+
+  for (let i = 0; i < 1000; i++) {
+    point.x = point.x + i;
+    point.y = point.x - point.z;
+    point.z = point.y + 1;
+  }
+
+  return point.z;
+})();
@@ -0,0 +1,562 @@
+/*
+ * A small utility library, in the style of the code commonly found on the web:
+ * collection helpers, an event emitter, a template renderer, a query string
+ * parser and a tiny store.
+ */
+(function (root, factory) {
+  "use strict";
+  if (typeof module === "object" && typeof module.exports === "object") {
+    module.exports = factory();
+  } else {
+    root.util = factory();
+  }
+})(this, function () {
+  "use strict";
+
+  var VERSION = "1.4.2";
+  var hasOwn = Object.prototype.hasOwnProperty;
+  var toString = Object.prototype.toString;
+
+  // ---------------------------------------------------------------------------
+  // Type checks
+  // ---------------------------------------------------------------------------
+
+  function isArray(value) {
+    return toString.call(value) === "[object Array]";
+  }
+
+  function isObject(value) {
+    var type = typeof value;
+    return value != null && (type === "object" || type === "function");
+  }
+
+  function isFunction(value) {
+    return typeof value === "function";
+  }
+
+  function isString(value) {
+    return typeof value === "string";
+  }
+
+  function isNumber(value) {
+    return typeof value === "number" && value === value;
+  }
+
+  function isEmpty(value) {
+    if (value == null) {
+      return true;
+    }
+    if (isArray(value) || isString(value)) {
+      return value.length === 0;
+    }
+    for (var key in value) {
+      if (hasOwn.call(value, key)) {
+        return false;
+      }
+    }
+    return true;
+  }
+
+  // ---------------------------------------------------------------------------
+  // Collections
+  // ---------------------------------------------------------------------------
+
+  function each(collection, iteratee) {
+    var index = -1;
+    if (isArray(collection)) {
+      var length = collection.length;
+      while (++index < length) {
+        if (iteratee(collection[index], index, collection) === false) {
+          break;
+        }
+      }
+    } else if (isObject(collection)) {
+      for (var key in collection) {
+        if (hasOwn.call(collection, key)) {
+          if (iteratee(collection[key], key, collection) === false) {
+            break;
+          }
+        }
+      }
+    }
+    return collection;
+  }
+
+  function map(collection, iteratee) {
+    var result = [];
+    each(collection, function (value, key) {
+      result.push(iteratee(value, key, collection));
+    });
+    return result;
+  }
+
+  function filter(collection, predicate) {
+    var result = [];
+    each(collection, function (value, key) {
+      if (predicate(value, key, collection)) {
+        result.push(value);
+      }
+    });
+    return result;
+  }
+
+  function reduce(collection, iteratee, accumulator) {
+    var initialized = arguments.length > 2;
+    each(collection, function (value, key) {
+      if (!initialized) {
+        accumulator = value;
+        initialized = true;
+      } else {
+        accumulator = iteratee(accumulator, value, key, collection);
+      }
+    });
+    return accumulator;
+  }
+
+  function find(collection, predicate) {
+    var found;
+    each(collection, function (value, key) {
+      if (predicate(value, key, collection)) {
+        found = value;
+        return false;
+      }
+    });
+    return found;
+  }
+
+  function groupBy(collection, iteratee) {
+    return reduce(
+      collection,
+      function (groups, value) {
+        var key = isFunction(iteratee) ? iteratee(value) : value[iteratee];
+        if (hasOwn.call(groups, key)) {
+          groups[key].push(value);
+        } else {
+          groups[key] = [value];
+        }
+        return groups;
+      },
+      {}
+    );
+  }
+
+  function uniq(array) {
+    var seen = {};
+    var result = [];
+    for (var i = 0; i < array.length; i++) {
+      var value = array[i];
+      var key = typeof value + ":" + value;
+      if (!seen[key]) {
+        seen[key] = true;
+        result.push(value);
+      }
+    }
+    return result;
+  }
+
+  function chunk(array, size) {
+    var result = [];
+    size = Math.max(size | 0, 1);
+    for (var i = 0; i < array.length; i += size) {
+      result.push(array.slice(i, i + size));
+    }
+    return result;
+  }
+
+  function range(start, end, step) {
+    if (end === undefined) {
+      end = start;
+      start = 0;
+    }
+    step = step || (start < end ? 1 : -1);
+    var result = [];
+    var value = start;
+    while (step > 0 ? value < end : value > end) {
+      result.push(value);
+      value += step;
+    }
+    return result;
+  }
+
+  // ---------------------------------------------------------------------------
+  // Objects
+  // ---------------------------------------------------------------------------
+
+  function extend(target) {
+    for (var i = 1; i < arguments.length; i++) {
+      var source = arguments[i];
+      if (source) {
+        for (var key in source) {
+          if (hasOwn.call(source, key)) {
+            target[key] = source[key];
+          }
+        }
+      }
+    }
+    return target;
+  }
+
+  function clone(value) {
+    if (!isObject(value) || isFunction(value)) {
+      return value;
+    }
+    if (isArray(value)) {
+      return map(value, clone);
+    }
+    var copy = {};
+    for (var key in value) {
+      if (hasOwn.call(value, key)) {
+        copy[key] = clone(value[key]);
+      }
+    }
+    return copy;
+  }
+
+  function get(object, path, fallback) {
+    var keys = isArray(path) ? path : String(path).split(".");
+    var current = object;
+    for (var i = 0; i < keys.length; i++) {
+      if (current == null) {
+        return fallback;
+      }
+      current = current[keys[i]];
+    }
+    return current === undefined ? fallback : current;
+  }
+
+  function set(object, path, value) {
+    var keys = isArray(path) ? path : String(path).split(".");
+    var current = object;
+    for (var i = 0; i < keys.length - 1; i++) {
+      var key = keys[i];
+      if (!isObject(current[key])) {
+        current[key] = /^\d+$/.test(keys[i + 1]) ? [] : {};
+      }
+      current = current[key];
+    }
+    current[keys[keys.length - 1]] = value;
+    return object;
+  }
+
+  // ---------------------------------------------------------------------------
+  // Functions
+  // ---------------------------------------------------------------------------
+
+  function once(fn) {
+    var called = false;
+    var result;
+    return function () {
+      if (!called) {
+        called = true;
+        result = fn.apply(this, arguments);
+      }
+      return result;
+    };
+  }
+
+  function memoize(fn, resolver) {
+    var cache = {};
+    var memoized = function () {
+      var key = resolver ? resolver.apply(this, arguments) : arguments[0];
+      if (!hasOwn.call(cache, key)) {
+        cache[key] = fn.apply(this, arguments);
+      }
+      return cache[key];
+    };
+    memoized.clear = function () {
+      cache = {};
+    };
+    return memoized;
+  }
+
+  function compose() {
+    var fns = arguments;
+    return function (value) {
+      for (var i = fns.length - 1; i >= 0; i--) {
+        value = fns[i](value);
+      }
+      return value;
+    };
+  }
+
+  // ---------------------------------------------------------------------------
+  // Strings
+  // ---------------------------------------------------------------------------
+
+  var ESCAPES = {
+    "&": "&amp;",
+    "<": "&lt;",
+    ">": "&gt;",
+    '"': "&quot;",
+    "'": "&#39;",
+  };
+
+  function escape(text) {
+    return String(text).replace(/[&<>"']/g, function (character) {
+      return ESCAPES[character];
+    });
+  }
+
+  function camelCase(text) {
+    return String(text)
+      .toLowerCase()
+      .replace(/[-_\s]+(.)?/g, function (match, character) {
+        return character ? character.toUpperCase() : "";
+      });
+  }
+
+  function padStart(text, length, filler) {
+    text = String(text);
+    filler = filler === undefined ? " " : String(filler);
+    while (text.length < length) {
+      text = filler + text;
+    }
+    return text;
+  }
+
+  function formatDate(date) {
+    return (
+      date.getFullYear() +
+      "-" +
+      padStart(date.getMonth() + 1, 2, "0") +
+      "-" +
+      padStart(date.getDate(), 2, "0")
+    );
+  }
+
+  // ---------------------------------------------------------------------------
+  // Templates
+  // ---------------------------------------------------------------------------
+
+  var TEMPLATE = /\{\{\s*([\w.]+)\s*\}\}/g;
+
+  function template(source) {
+    return function (data) {
+      return source.replace(TEMPLATE, function (match, path) {
+        var value = get(data, path, "");
+        return escape(value);
+      });
+    };
+  }
+
+  // ---------------------------------------------------------------------------
+  // Query strings
+  // ---------------------------------------------------------------------------
+
+  function parseQuery(query) {
+    var result = {};
+    if (!query) {
+      return result;
+    }
+    if (query.charAt(0) === "?") {
+      query = query.slice(1);
+    }
+    var pairs = query.split("&");
+    for (var i = 0; i < pairs.length; i++) {
+      if (!pairs[i]) {
+        continue;
+      }
+      var parts = pairs[i].split("=");
+      var key = decodeURIComponent(parts[0]);
+      var value = parts.length > 1 ? decodeURIComponent(parts[1]) : "";
+      if (hasOwn.call(result, key)) {
+        result[key] = [].concat(result[key], value);
+      } else {
+        result[key] = value;
+      }
+    }
+    return result;
+  }
+
+  function stringifyQuery(params) {
+    var parts = [];
+    each(params, function (value, key) {
+      var values = isArray(value) ? value : [value];
+      var name = encodeURIComponent(key);
+      for (var i = 0; i < values.length; i++) {
+        parts.push(name + "=" + encodeURIComponent(values[i]));
+      }
+    });
+    return parts.length ? "?" + parts.join("&") : "";
+  }
+
+  // ---------------------------------------------------------------------------
+  // Events
+  // ---------------------------------------------------------------------------
+
+  function EventEmitter() {
+    this.listeners = {};
+  }
+
+  EventEmitter.prototype.on = function (event, listener) {
+    if (!hasOwn.call(this.listeners, event)) {
+      this.listeners[event] = [];
+    }
+    this.listeners[event].push(listener);
+    return this;
+  };
+
+  EventEmitter.prototype.off = function (event, listener) {
+    var listeners = this.listeners[event];
+    if (!listeners) {
+      return this;
+    }
+    this.listeners[event] = filter(listeners, function (current) {
+      return current !== listener;
+    });
+    return this;
+  };
+
+  EventEmitter.prototype.once = function (event, listener) {
+    var self = this;
+    var wrapper = function () {
+      self.off(event, wrapper);
+      return listener.apply(self, arguments);
+    };
+    return this.on(event, wrapper);
+  };
+
+  EventEmitter.prototype.emit = function (event) {
+    var listeners = this.listeners[event];
+    if (!listeners || listeners.length === 0) {
+      return false;
+    }
+    var args = Array.prototype.slice.call(arguments, 1);
+    var current = listeners.slice();
+    for (var i = 0; i < current.length; i++) {
+      try {
+        current[i].apply(this, args);
+      } catch (error) {
+        if (event !== "error") {
+          this.emit("error", error);
+        } else {
+          throw error;
+        }
+      }
+    }
+    return true;
+  };
+
+  // ---------------------------------------------------------------------------
+  // Store
+  // ---------------------------------------------------------------------------
+
+  function createStore(reducer, initialState) {
+    var state = initialState;
+    var emitter = new EventEmitter();
+    var dispatching = false;
+
+    function getState() {
+      return state;
+    }
+
+    function dispatch(action) {
+      if (!isObject(action) || action.type === undefined) {
+        throw new TypeError("Actions must be objects with a type");
+      }
+      if (dispatching) {
+        throw new Error("Reducers may not dispatch actions");
+      }
+      try {
+        dispatching = true;
+        state = reducer(state, action);
+      } finally {
+        dispatching = false;
+      }
+      emitter.emit("change", state, action);
+      return action;
+    }
+
+    function subscribe(listener) {
+      emitter.on("change", listener);
+      return function unsubscribe() {
+        emitter.off("change", listener);
+      };
+    }
+
+    dispatch({ type: "@@init" });
+
+    return {
+      getState: getState,
+      dispatch: dispatch,
+      subscribe: subscribe,
+    };
+  }
+
+  function toggle(item, id) {
+    return item.id === id ? extend({}, item, { done: !item.done }) : item;
+  }
+
+  function todos(state, action) {
+    state = state || { items: [], filter: "all" };
+    switch (action.type) {
+      case "add":
+        return extend({}, state, {
+          items: state.items.concat([
+            { id: state.items.length + 1, text: action.text, done: false },
+          ]),
+        });
+      case "toggle":
+        return extend({}, state, {
+          items: map(state.items, (item) => toggle(item, action.id)),
+        });
+      case "filter":
+        return extend({}, state, { filter: action.filter });
+      default:
+        return state;
+    }
+  }
+
+  var visibleTodos = memoize(
+    function (state) {
+      switch (state.filter) {
+        case "done":
+          return filter(state.items, (item) => item.done);
+        case "active":
+          return filter(state.items, (item) => !item.done);
+        default:
+          return state.items;
+      }
+    },
+    function (state) {
+      return state.filter + ":" + state.items.length;
+    }
+  );
+
+  return {
+    VERSION: VERSION,
+    isArray: isArray,
+    isObject: isObject,
+    isFunction: isFunction,
+    isString: isString,
+    isNumber: isNumber,
+    isEmpty: isEmpty,
+    each: each,
+    map: map,
+    filter: filter,
+    reduce: reduce,
+    find: find,
+    groupBy: groupBy,
+    uniq: uniq,
+    chunk: chunk,
+    range: range,
+    extend: extend,
+    clone: clone,
+    get: get,
+    set: set,
+    once: once,
+    memoize: memoize,
+    compose: compose,
+    escape: escape,
+    camelCase: camelCase,
+    padStart: padStart,
+    formatDate: formatDate,
+    template: template,
+    parseQuery: parseQuery,
+    stringifyQuery: stringifyQuery,
+    EventEmitter: EventEmitter,
+    createStore: createStore,
+    todos: todos,
+    visibleTodos: visibleTodos,
+  };
+});
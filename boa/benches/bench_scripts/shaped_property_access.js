@@ -0,0 +1,14 @@
+(function () {
+  let points = [];
+  for (let i = 0; i < 10; i++) {
+    points.push({ x: i, y: i + 1, z: i + 2 });
+  }
+
+  let sum = 0;
+  for (let i = 0; i < 1000; i++) {
+    let point = points[i % 10];
+    sum += point.x + point.y + point.z;
+  }
+
+  return sum;
+})();
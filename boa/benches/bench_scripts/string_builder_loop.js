@@ -0,0 +1,9 @@
+(function () {
+  let string = "";
+
+  for (let i = 0; i < 100000; i++) {
+    string += "chunk";
+  }
+
+  return string.length;
+})();
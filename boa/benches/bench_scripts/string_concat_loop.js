@@ -0,0 +1,9 @@
+(function () {
+  let text = "";
+
+  for (let i = 0; i < 1000; i++) {
+    text = text + "item " + i + ", ";
+  }
+
+  return text.length;
+})();
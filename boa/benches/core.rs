@@ -0,0 +1,118 @@
+//! Benchmarks of the core operations of the engine, in hot loops.
+//!
+//! These run faster and with a tighter noise threshold than the other suites, so that they can
+//! be compared across commits, see the README.
+
+use boa::{exec::Executable, syntax::Parser, Context};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::time::Duration;
+
+#[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
+#[cfg_attr(
+    all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"),
+    global_allocator
+)]
+static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
+
+/// Benchmarks the execution of an already parsed script.
+fn bench_execution(c: &mut Criterion, name: &str, src: &str) {
+    let mut context = Context::new();
+
+    // Parse the AST nodes.
+    let nodes = Parser::new(src.as_bytes(), false).parse_all().unwrap();
+
+    // Execute the parsed nodes, passing them through a black box, to avoid over-optimizing by the compiler
+    c.bench_function(name, move |b| {
+        b.iter(|| black_box(&nodes).run(&mut context).unwrap())
+    });
+}
+
+static REAL_WORLD: &str = include_str!("bench_scripts/real_world.js");
+
+fn real_world_parser(c: &mut Criterion) {
+    c.bench_function("Real world file (Parser)", move |b| {
+        b.iter(|| Parser::new(black_box(REAL_WORLD.as_bytes()), false).parse_all())
+    });
+}
+
+static PROPERTY_GET_SET: &str = include_str!("bench_scripts/property_get_set.js");
+
+fn property_get_set(c: &mut Criterion) {
+    bench_execution(c, "Property get/set loop (Execution)", PROPERTY_GET_SET);
+}
+
+static ARRAY_PUSH_INDEX: &str = include_str!("bench_scripts/array_push_index.js");
+
+fn array_push_index(c: &mut Criterion) {
+    bench_execution(c, "Array push/index loop (Execution)", ARRAY_PUSH_INDEX);
+}
+
+static STRING_CONCAT_LOOP: &str = include_str!("bench_scripts/string_concat_loop.js");
+
+fn string_concat_loop(c: &mut Criterion) {
+    bench_execution(
+        c,
+        "String concatenation loop (Execution)",
+        STRING_CONCAT_LOOP,
+    );
+}
+
+static STRING_BUILDER_LOOP: &str = include_str!("bench_scripts/string_builder_loop.js");
+
+fn string_builder_loop(c: &mut Criterion) {
+    bench_execution(c, "String builder loop (Execution)", STRING_BUILDER_LOOP);
+}
+
+static FUNCTION_CALL: &str = include_str!("bench_scripts/function_call.js");
+
+fn function_call(c: &mut Criterion) {
+    bench_execution(c, "Function call loop (Execution)", FUNCTION_CALL);
+}
+
+static OBJECT_ALLOCATION: &str = include_str!("bench_scripts/object_allocation.js");
+
+fn object_allocation(c: &mut Criterion) {
+    bench_execution(c, "Object allocation loop (Execution)", OBJECT_ALLOCATION);
+}
+
+static LOCAL_VARIABLE_LOOP: &str = include_str!("bench_scripts/local_variable_loop.js");
+
+fn local_variable_loop(c: &mut Criterion) {
+    bench_execution(c, "Local variable loop (Execution)", LOCAL_VARIABLE_LOOP);
+}
+
+static SHAPED_PROPERTY_ACCESS: &str = include_str!("bench_scripts/shaped_property_access.js");
+
+fn shaped_property_access(c: &mut Criterion) {
+    bench_execution(
+        c,
+        "Shaped property access loop (Execution)",
+        SHAPED_PROPERTY_ACCESS,
+    );
+}
+
+/// A configuration that keeps the whole suite under a minute, while reporting only the changes
+/// above the usual noise.
+fn config() -> Criterion {
+    Criterion::default()
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(4))
+        .sample_size(50)
+        .noise_threshold(0.05)
+}
+
+criterion_group!(
+    name = core;
+    config = config();
+    targets =
+        real_world_parser,
+        property_get_set,
+        array_push_index,
+        string_concat_loop,
+        string_builder_loop,
+        function_call,
+        object_allocation,
+        local_variable_loop,
+        shaped_property_access,
+);
+criterion_main!(core);
@@ -14,6 +14,18 @@ fn create_realm(c: &mut Criterion) {
     c.bench_function("Create Realm", move |b| b.iter(Realm::create));
 }
 
+fn create_context(c: &mut Criterion) {
+    c.bench_function("Create Context", move |b| b.iter(Context::new));
+}
+
+fn create_context_from_snapshot(c: &mut Criterion) {
+    let snapshot = Context::new().snapshot().unwrap();
+
+    c.bench_function("Create Context from snapshot", move |b| {
+        b.iter(|| Context::from_snapshot(black_box(&snapshot)))
+    });
+}
+
 static SYMBOL_CREATION: &str = include_str!("bench_scripts/symbol_creation.js");
 
 fn symbol_creation(c: &mut Criterion) {
@@ -330,9 +342,43 @@ fn mini_js(c: &mut Criterion) {
     });
 }
 
+static HOT_SCRIPT: &str = "counter += 1; counter * 2 + 1";
+
+fn compiled_script_reuse(c: &mut Criterion) {
+    let mut context = Context::new();
+    context.eval("var counter = 0;").unwrap();
+
+    // Parse the script only once.
+    let script = context.compile(HOT_SCRIPT).unwrap();
+
+    c.bench_function("Compiled script x1000 (Execution)", move |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                context.execute(black_box(&script)).unwrap();
+            }
+        })
+    });
+}
+
+fn eval_script_reuse(c: &mut Criterion) {
+    let mut context = Context::new();
+    context.eval("var counter = 0;").unwrap();
+
+    // Parse the script on every execution.
+    c.bench_function("Eval script x1000 (Execution)", move |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                context.eval(black_box(HOT_SCRIPT)).unwrap();
+            }
+        })
+    });
+}
+
 criterion_group!(
     execution,
     create_realm,
+    create_context,
+    create_context_from_snapshot,
     symbol_creation,
     for_loop_execution,
     fibonacci,
@@ -355,5 +401,7 @@ criterion_group!(
     arithmetic_operations,
     clean_js,
     mini_js,
+    compiled_script_reuse,
+    eval_script_reuse,
 );
 criterion_main!(execution);
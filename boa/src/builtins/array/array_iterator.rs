@@ -1,8 +1,9 @@
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, Value},
+    builtins::{iterable::create_iter_result_object, Array, Value},
     gc::{Finalize, Trace},
-    object::{GcObject, ObjectData},
-    property::{Attribute, DataDescriptor},
+    object::{GcObject, ObjectData, ObjectInitializer},
+    property::Attribute,
+    snapshot::HeapCopier,
     BoaProfiler, Context, Result,
 };
 
@@ -37,6 +38,14 @@ impl ArrayIterator {
         }
     }
 
+    /// Copies the iterator for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            array: copier.copy_value(&self.array),
+            ..self.clone()
+        }
+    }
+
     /// CreateArrayIterator( array, kind )
     ///
     /// Creates a new iterator over the given array.
@@ -68,47 +77,51 @@ impl ArrayIterator {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
     pub(crate) fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
-        if let Value::Object(ref object) = this {
-            let mut object = object.borrow_mut();
-            if let Some(array_iterator) = object.as_array_iterator_mut() {
-                let index = array_iterator.next_index;
-                if array_iterator.array.is_undefined() {
-                    return Ok(create_iter_result_object(context, Value::undefined(), true));
-                }
-                let len = array_iterator
-                    .array
-                    .get_field("length", context)?
-                    .as_number()
-                    .ok_or_else(|| context.construct_type_error("Not an array"))?
-                    as u32;
-                if array_iterator.next_index >= len {
-                    array_iterator.array = Value::undefined();
-                    return Ok(create_iter_result_object(context, Value::undefined(), true));
-                }
-                array_iterator.next_index = index + 1;
-                match array_iterator.kind {
-                    ArrayIterationKind::Key => {
-                        Ok(create_iter_result_object(context, index.into(), false))
-                    }
-                    ArrayIterationKind::Value => {
-                        let element_value = array_iterator.array.get_field(index, context)?;
-                        Ok(create_iter_result_object(context, element_value, false))
-                    }
-                    ArrayIterationKind::KeyAndValue => {
-                        let element_value = array_iterator.array.get_field(index, context)?;
-                        let result = Array::constructor(
-                            &Value::new_object(context),
-                            &[index.into(), element_value],
-                            context,
-                        )?;
-                        Ok(create_iter_result_object(context, result, false))
-                    }
-                }
-            } else {
-                context.throw_type_error("`this` is not an ArrayIterator")
+        // The iterator is not borrowed while the array is read, since the getters of the array
+        // may use the iterator.
+        let (object, array, index, kind) = match this {
+            Value::Object(ref object) => match object.borrow().as_array_iterator() {
+                Some(array_iterator) => (
+                    object.clone(),
+                    array_iterator.array.clone(),
+                    array_iterator.next_index,
+                    array_iterator.kind.clone(),
+                ),
+                None => return context.throw_type_error("`this` is not an ArrayIterator"),
+            },
+            _ => return context.throw_type_error("`this` is not an ArrayIterator"),
+        };
+        if array.is_undefined() {
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+        let len = array
+            .get_field("length", context)?
+            .as_number()
+            .ok_or_else(|| context.construct_type_error("Not an array"))? as u32;
+        if index >= len {
+            if let Some(array_iterator) = object.borrow_mut().as_array_iterator_mut() {
+                array_iterator.array = Value::undefined();
+            }
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+        if let Some(array_iterator) = object.borrow_mut().as_array_iterator_mut() {
+            array_iterator.next_index = index + 1;
+        }
+        match kind {
+            ArrayIterationKind::Key => Ok(create_iter_result_object(context, index.into(), false)),
+            ArrayIterationKind::Value => {
+                let element_value = array.get_field(index, context)?;
+                Ok(create_iter_result_object(context, element_value, false))
+            }
+            ArrayIterationKind::KeyAndValue => {
+                let element_value = array.get_field(index, context)?;
+                let result = Array::constructor(
+                    &Value::new_object(context),
+                    &[index.into(), element_value],
+                    context,
+                )?;
+                Ok(create_iter_result_object(context, result, false))
             }
-        } else {
-            context.throw_type_error("`this` is not an ArrayIterator")
         }
     }
 
@@ -122,13 +135,12 @@ impl ArrayIterator {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         // Create prototype
-        let mut array_iterator = context.construct_object();
-        make_builtin_fn(Self::next, "next", &array_iterator, 0, context);
-        array_iterator.set_prototype_instance(iterator_prototype);
-
         let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
-        let to_string_tag_property = DataDescriptor::new("Array Iterator", Attribute::CONFIGURABLE);
-        array_iterator.insert(to_string_tag, to_string_tag_property);
+        let mut array_iterator = ObjectInitializer::new(context)
+            .function(Self::next, "next", 0)
+            .property(to_string_tag, "Array Iterator", Attribute::BUILTIN_READONLY)
+            .build();
+        array_iterator.set_prototype_instance(iterator_prototype);
         array_iterator
     }
 }
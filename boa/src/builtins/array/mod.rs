@@ -11,23 +11,27 @@
 
 pub mod array_iterator;
 #[cfg(test)]
+mod sparse_tests;
+#[cfg(test)]
 mod tests;
 
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
-    builtins::BuiltIn,
+    builtins::{iterable, BuiltIn, Number},
     gc::GcObject,
     object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
-    property::{Attribute, DataDescriptor},
+    property::{Attribute, DataDescriptor, PropertyKey},
     value::{same_value_zero, IntegerOrInfinity, Value},
     BoaProfiler, Context, Result,
 };
-use num_traits::*;
 use std::{
     cmp::{max, min},
     convert::{TryFrom, TryInto},
 };
 
+/// The maximum length of an array-like object, `2^53 - 1`.
+const MAX_SAFE_LENGTH: usize = Number::MAX_SAFE_INTEGER as usize;
+
 /// JavaScript `Array` built-in implementation.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Array;
@@ -36,7 +40,7 @@ impl BuiltIn for Array {
     const NAME: &'static str = "Array";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -63,16 +67,8 @@ impl BuiltIn for Array {
             0,
             Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
         )
-        .property(
-            "values",
-            values_function.clone(),
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
-        .property(
-            symbol_iterator,
-            values_function,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        )
+        .property("values", values_function.clone(), Attribute::BUILTIN)
+        .property(symbol_iterator, values_function, Attribute::BUILTIN)
         .method(Self::concat, "concat", 1)
         .method(Self::push, "push", 1)
         .method(Self::index_of, "indexOf", 1)
@@ -88,17 +84,19 @@ impl BuiltIn for Array {
         .method(Self::reverse, "reverse", 0)
         .method(Self::shift, "shift", 0)
         .method(Self::unshift, "unshift", 1)
+        .method(Self::splice, "splice", 2)
         .method(Self::every, "every", 1)
         .method(Self::find, "find", 1)
         .method(Self::find_index, "findIndex", 1)
         .method(Self::slice, "slice", 2)
-        .method(Self::some, "some", 2)
-        .method(Self::reduce, "reduce", 2)
-        .method(Self::reduce_right, "reduceRight", 2)
+        .method(Self::some, "some", 1)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::reduce_right, "reduceRight", 1)
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
         // Static Methods
         .static_method(Self::is_array, "isArray", 1)
+        .static_method(Self::from, "from", 1)
         .build();
 
         (Self::NAME, array.into(), Self::attribute())
@@ -153,10 +151,12 @@ impl Array {
             array.set_property(0, DataDescriptor::new(length, Attribute::all()));
             array.set_field("length", 1, context)?;
         } else {
-            if length.is_double() {
+            let int_len = length.to_u32(context)?;
+            #[allow(clippy::float_cmp)]
+            if f64::from(int_len) != length.to_number(context)? {
                 return context.throw_range_error("Invalid array length");
             }
-            array.set_field("length", length.to_u32(context).unwrap(), context)?;
+            array.set_field("length", int_len, context)?;
         }
 
         Ok(array)
@@ -304,11 +304,75 @@ impl Array {
         }
     }
 
+    /// `Array.from( items [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Creates a new array from an iterable or an array-like object. Iterables are read through
+    /// their iterator, so strings are split by code point.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let map_fn = args.get(1).cloned().unwrap_or_else(Value::undefined);
+        let this_arg = args.get(2).cloned().unwrap_or_else(Value::undefined);
+
+        let mapping = !map_fn.is_undefined();
+        if mapping && !map_fn.is_function() {
+            return context.throw_type_error("Array.from: the mapping function is not callable");
+        }
+
+        let mut values = Vec::new();
+        let mut push = |value: Value, context: &mut Context| -> Result<()> {
+            let value = if mapping {
+                let index = Value::from(values.len());
+                context.call(&map_fn, &this_arg, &[value, index])?
+            } else {
+                value
+            };
+            values.push(value);
+            Ok(())
+        };
+
+        let array_like = items.to_object(context)?;
+        let using_iterator = array_like.get(
+            &context.well_known_symbols().iterator_symbol().into(),
+            items.clone(),
+            context,
+        )?;
+        if !using_iterator.is_null_or_undefined() {
+            let iterator_record = iterable::get_iterator(context, items)?;
+            loop {
+                let next = iterator_record.next(context)?;
+                if next.is_done() {
+                    break;
+                }
+                push(next.value(), context)?;
+            }
+        } else {
+            let array_like = Value::from(array_like);
+            let length = array_like
+                .get_field("length", context)?
+                .to_length(context)?;
+            for index in 0..length {
+                let value = array_like.get_field(index, context)?;
+                push(value, context)?;
+            }
+        }
+
+        let array = Self::new_array(context)?;
+        Self::construct_array(&array, &values, context)
+    }
+
     /// `Array.prototype.concat(...arguments)`
     ///
     /// When the concat method is called with zero or more arguments, it returns an
     /// array containing the array elements of the object followed by the array
-    /// elements of each argument in order.
+    /// elements of each argument in order. The arrays, and the objects with a truthy
+    /// `@@isConcatSpreadable`, are flattened one level; other values are appended.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -317,30 +381,61 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.concat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat
     pub(crate) fn concat(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            // If concat is called with no arguments, it returns the original array
-            return Ok(this.clone());
+        let object = Value::from(this.to_object(context)?);
+        let array = Self::new_array(context)?;
+
+        let mut n = 0;
+        for item in std::iter::once(&object).chain(args) {
+            if Self::is_concat_spreadable(item, context)? {
+                let length = item.get_field("length", context)?.to_length(context)?;
+                if n + length > MAX_SAFE_LENGTH {
+                    return context.throw_type_error("concat: the result length is too large");
+                }
+                for k in 0..length {
+                    // Holes stay holes in the result.
+                    let key = PropertyKey::from(k);
+                    if item.as_object().map_or(false, |o| o.has_property(&key)) {
+                        let value = item.get_field(key, context)?;
+                        array.set_property(n, DataDescriptor::new(value, Attribute::all()));
+                    }
+                    n += 1;
+                }
+            } else {
+                if n >= MAX_SAFE_LENGTH {
+                    return context.throw_type_error("concat: the result length is too large");
+                }
+                array.set_property(n, DataDescriptor::new(item, Attribute::all()));
+                n += 1;
+            }
         }
 
-        // Make a new array (using this object as the prototype basis for the new
-        // one)
-        let mut new_values: Vec<Value> = Vec::new();
-
-        let this_length = this.get_field("length", context)?.to_length(context)?;
-        for n in 0..this_length {
-            new_values.push(this.get_field(n, context)?);
-        }
+        array.set_field("length", n, context)?;
+        Ok(array)
+    }
 
-        for concat_array in args {
-            let concat_length = concat_array
-                .get_field("length", context)?
-                .to_length(context)?;
-            for n in 0..concat_length {
-                new_values.push(concat_array.get_field(n, context)?);
-            }
+    /// The abstract operation `IsConcatSpreadable`.
+    ///
+    /// An object is spread by `concat` if its `@@isConcatSpreadable` property is truthy, or if
+    /// the property is undefined and the object is an array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconcatspreadable
+    fn is_concat_spreadable(value: &Value, context: &mut Context) -> Result<bool> {
+        let object = match value {
+            Value::Object(ref object) => object,
+            _ => return Ok(false),
+        };
+        let spreadable = value.get_field(
+            context.well_known_symbols().is_concat_spreadable_symbol(),
+            context,
+        )?;
+        if spreadable.is_undefined() {
+            Ok(object.borrow().is_array())
+        } else {
+            Ok(spreadable.to_boolean())
         }
-
-        Self::construct_array(this, &new_values, context)
     }
 
     /// `Array.prototype.push( ...items )`
@@ -356,8 +451,19 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.push
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push
     pub(crate) fn push(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let new_array = Self::add_to_array_object(this, args, context)?;
-        Ok(new_array.get_field("length", context)?)
+        let mut object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+
+        if length + args.len() > MAX_SAFE_LENGTH {
+            return context.throw_type_error("push: the array length is too large");
+        }
+        for (n, value) in args.iter().enumerate() {
+            object.set_or_throw(length + n, value.clone(), context)?;
+        }
+
+        let length = length + args.len();
+        object.set_or_throw("length", length, context)?;
+        Ok(length.into())
     }
 
     /// `Array.prototype.pop()`
@@ -371,16 +477,19 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.pop
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop
     pub(crate) fn pop(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
-        let curr_length = this.get_field("length", context)?.to_length(context)?;
+        let mut object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
 
-        if curr_length < 1 {
+        if length == 0 {
+            object.set_or_throw("length", 0, context)?;
             return Ok(Value::undefined());
         }
-        let pop_index = curr_length.wrapping_sub(1);
-        let pop_value: Value = this.get_field(pop_index.to_string(), context)?;
-        this.remove_property(pop_index);
-        this.set_field("length", Value::from(pop_index), context)?;
-        Ok(pop_value)
+
+        let index = length - 1;
+        let element = object.get(&index.into(), object.clone().into(), context)?;
+        object.delete_property_or_throw(index, context)?;
+        object.set_or_throw("length", index, context)?;
+        Ok(element)
     }
 
     /// `Array.prototype.forEach( callbackFn [ , thisArg ] )`
@@ -395,7 +504,7 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/forEach
     pub(crate) fn for_each(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from("Missing argument for Array.prototype.forEach"));
+            return context.throw_type_error("Missing argument for Array.prototype.forEach");
         }
 
         let callback_arg = args.get(0).expect("Could not get `callbackFn` argument.");
@@ -404,6 +513,10 @@ impl Array {
         let length = this.get_field("length", context)?.to_length(context)?;
 
         for i in 0..length {
+            // Holes are skipped.
+            if !this.has_field(i) {
+                continue;
+            }
             let element = this.get_field(i, context)?;
             let arguments = [element, Value::from(i), this.clone()];
 
@@ -435,14 +548,20 @@ impl Array {
                 .to_string()
         };
 
-        let mut elem_strs = Vec::new();
+        let mut string = String::new();
         let length = this.get_field("length", context)?.to_length(context)?;
         for n in 0..length {
-            let elem_str = this.get_field(n, context)?.to_string(context)?.to_string();
-            elem_strs.push(elem_str);
+            if n > 0 {
+                string.push_str(&separator);
+            }
+            // Holes, `undefined` and `null` are joined as empty strings.
+            let element = this.get_field(n, context)?;
+            if !element.is_null_or_undefined() {
+                string.push_str(&element.to_string(context)?);
+            }
         }
 
-        Ok(Value::from(elem_strs.join(&separator)))
+        Ok(Value::from(string))
     }
 
     /// `Array.prototype.toString( separator )`
@@ -538,31 +657,21 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.shift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/shift
     pub(crate) fn shift(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
-        let len = this.get_field("length", context)?.to_length(context)?;
+        let mut object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
 
-        if len == 0 {
-            this.set_field("length", 0, context)?;
+        if length == 0 {
+            object.set_or_throw("length", 0, context)?;
             return Ok(Value::undefined());
         }
 
-        let first: Value = this.get_field(0, context)?;
-
-        for k in 1..len {
-            let from = k;
-            let to = k.wrapping_sub(1);
-
-            let from_value = this.get_field(from, context)?;
-            if from_value.is_undefined() {
-                this.remove_property(to);
-            } else {
-                this.set_property(to, DataDescriptor::new(from_value, Attribute::all()));
-            }
+        let first = object.get(&0.into(), object.clone().into(), context)?;
+        for k in 1..length {
+            Self::move_element(&mut object, k, k - 1, context)?;
         }
 
-        let final_index = len.wrapping_sub(1);
-        this.remove_property(final_index);
-        this.set_field("length", Value::from(final_index), context)?;
-
+        object.delete_property_or_throw(length - 1, context)?;
+        object.set_or_throw("length", length - 1, context)?;
         Ok(first)
     }
 
@@ -579,36 +688,118 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.unshift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/unshift
     pub(crate) fn unshift(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let len = this.get_field("length", context)?.to_length(context)?;
+        let mut object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let arg_count = args.len();
 
-        let arg_c = args.len();
+        if arg_count > 0 {
+            if length + arg_count > MAX_SAFE_LENGTH {
+                return context.throw_type_error("unshift: the array length is too large");
+            }
+            for k in (0..length).rev() {
+                Self::move_element(&mut object, k, k + arg_count, context)?;
+            }
+            for (j, item) in args.iter().enumerate() {
+                object.set_or_throw(j, item.clone(), context)?;
+            }
+        }
 
-        if arg_c > 0 {
-            for k in (1..=len).rev() {
-                let from = k.wrapping_sub(1);
-                let to = k.wrapping_add(arg_c).wrapping_sub(1);
+        let length = length + arg_count;
+        object.set_or_throw("length", length, context)?;
+        Ok(length.into())
+    }
 
-                let from_value = this.get_field(from, context)?;
-                if from_value.is_undefined() {
-                    this.remove_property(to);
-                } else {
-                    this.set_property(to, DataDescriptor::new(from_value, Attribute::all()));
+    /// `Array.prototype.splice( start, deleteCount, ...items )`
+    ///
+    /// Removes `deleteCount` elements from `start`, which counts from the end if negative, and
+    /// inserts `items` in their place. The removed elements are returned in a new array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+    pub(crate) fn splice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let mut object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let start = Self::get_relative_start(context, args.get(0), length)?;
+
+        let items = args.get(2..).unwrap_or(&[]);
+        let delete_count = match args.len() {
+            0 => 0,
+            1 => length - start,
+            _ => match args[1].to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(count) if count > 0 => {
+                    (count as u64).min((length - start) as u64) as usize
                 }
+                IntegerOrInfinity::PositiveInfinity => length - start,
+                _ => 0,
+            },
+        };
+
+        if length + items.len() - delete_count > MAX_SAFE_LENGTH {
+            return context.throw_type_error("splice: the array length is too large");
+        }
+
+        // The removed elements keep their holes.
+        let removed = Self::new_array(context)?;
+        for k in 0..delete_count {
+            let from = PropertyKey::from(start + k);
+            if object.has_property(&from) {
+                let value = object.get(&from, object.clone().into(), context)?;
+                removed.set_property(k, DataDescriptor::new(value, Attribute::all()));
             }
-            for j in 0..arg_c {
-                this.set_property(
-                    j,
-                    DataDescriptor::new(
-                        args.get(j).expect("Could not get argument").clone(),
-                        Attribute::all(),
-                    ),
-                );
+        }
+        removed.set_field("length", delete_count, context)?;
+
+        if items.len() < delete_count {
+            for k in start..(length - delete_count) {
+                Self::move_element(&mut object, k + delete_count, k + items.len(), context)?;
             }
+            for k in ((length - delete_count + items.len())..length).rev() {
+                object.delete_property_or_throw(k, context)?;
+            }
+        } else if items.len() > delete_count {
+            for k in (start..(length - delete_count)).rev() {
+                Self::move_element(&mut object, k + delete_count, k + items.len(), context)?;
+            }
+        }
+
+        for (k, item) in items.iter().enumerate() {
+            object.set_or_throw(start + k, item.clone(), context)?;
         }
 
-        let temp = len.wrapping_add(arg_c);
-        this.set_field("length", Value::from(temp), context)?;
-        Ok(Value::from(temp))
+        object.set_or_throw("length", length - delete_count + items.len(), context)?;
+        Ok(removed)
+    }
+
+    /// The abstract operation `LengthOfArrayLike`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-lengthofarraylike
+    fn length_of_array_like(object: &GcObject, context: &mut Context) -> Result<usize> {
+        object
+            .get(&"length".into(), object.clone().into(), context)?
+            .to_length(context)
+    }
+
+    /// Moves the element at index `from` to index `to`, deleting `to` if `from` is a hole.
+    fn move_element(
+        object: &mut GcObject,
+        from: usize,
+        to: usize,
+        context: &mut Context,
+    ) -> Result<()> {
+        let from = PropertyKey::from(from);
+        if object.has_property(&from) {
+            let value = object.get(&from, object.clone().into(), context)?;
+            object.set_or_throw(to, value, context)
+        } else {
+            object.delete_property_or_throw(to, context)
+        }
     }
 
     /// `Array.prototype.every( callback, [ thisArg ] )`
@@ -626,9 +817,8 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/every
     pub(crate) fn every(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from(
-                "missing callback when calling function Array.prototype.every",
-            ));
+            return context
+                .throw_type_error("missing callback when calling function Array.prototype.every");
         }
         let callback = &args[0];
         let this_arg = if args.len() > 1 {
@@ -640,6 +830,10 @@ impl Array {
         let max_len = this.get_field("length", context)?.to_length(context)?;
         let mut len = max_len;
         while i < len {
+            if !this.has_field(i) {
+                i += 1;
+                continue;
+            }
             let element = this.get_field(i, context)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = context.call(callback, &this_arg, &arguments)?;
@@ -668,9 +862,8 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/map
     pub(crate) fn map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from(
-                "missing argument 0 when calling function Array.prototype.map",
-            ));
+            return context
+                .throw_type_error("missing argument 0 when calling function Array.prototype.map");
         }
 
         let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
@@ -683,17 +876,21 @@ impl Array {
         }
 
         let new = Self::new_array(context)?;
+        new.set_field("length", length, context)?;
 
-        let values = (0..length)
-            .map(|idx| {
-                let element = this.get_field(idx, context)?;
-                let args = [element, Value::from(idx), new.clone()];
+        for idx in 0..length {
+            // Holes are skipped, and stay holes in the result.
+            if !this.has_field(idx) {
+                continue;
+            }
+            let element = this.get_field(idx, context)?;
+            let args = [element, Value::from(idx), this.clone()];
 
-                context.call(&callback, &this_val, &args)
-            })
-            .collect::<Result<Vec<Value>>>()?;
+            let value = context.call(&callback, &this_val, &args)?;
+            new.set_property(idx, DataDescriptor::new(value, Attribute::all()));
+        }
 
-        Self::construct_array(&new, &values, context)
+        Ok(new)
     }
 
     /// `Array.prototype.indexOf( searchElement[, fromIndex ] )`
@@ -716,39 +913,22 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.indexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/indexOf
     pub(crate) fn index_of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // If no arguments, return -1. Not described in spec, but is what chrome does.
-        if args.is_empty() {
-            return Ok(Value::from(-1));
-        }
-
-        let search_element = args[0].clone();
-        let len = this.get_field("length", context)?.to_length(context)?;
-
-        let mut idx = match args.get(1) {
-            Some(from_idx_ptr) => {
-                let from_idx = from_idx_ptr.to_number(context)?;
-
-                if !from_idx.is_finite() {
-                    return Ok(Value::from(-1));
-                } else if from_idx < 0.0 {
-                    let k =
-                        isize::try_from(len).map_err(interror_to_value)? + f64_to_isize(from_idx)?;
-                    usize::try_from(max(0, k)).map_err(interror_to_value)?
-                } else {
-                    f64_to_usize(from_idx)?
+        let object = this.to_object(context)?;
+        let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let len = Self::length_of_array_like(&object, context)?;
+
+        // A `fromIndex` of `+Infinity` starts past the end, and `-Infinity` at the start.
+        let mut k = Self::get_relative_start(context, args.get(1), len)?;
+        while k < len {
+            // Holes are skipped.
+            let key = PropertyKey::from(k);
+            if object.has_property(&key) {
+                let element = object.get(&key, object.clone().into(), context)?;
+                if element.strict_equals(&search_element) {
+                    return Ok(Value::from(k));
                 }
             }
-            None => 0,
-        };
-
-        while idx < len {
-            let check_element = this.get_field(idx, context)?.clone();
-
-            if check_element.strict_equals(&search_element) {
-                return Ok(Value::from(idx));
-            }
-
-            idx += 1;
+            k += 1;
         }
 
         Ok(Value::from(-1))
@@ -777,41 +957,37 @@ impl Array {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        // If no arguments, return -1. Not described in spec, but is what chrome does.
-        if args.is_empty() {
+        let object = this.to_object(context)?;
+        let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let len = Self::length_of_array_like(&object, context)?;
+        if len == 0 {
             return Ok(Value::from(-1));
         }
 
-        let search_element = args[0].clone();
-        let len: isize = this
-            .get_field("length", context)?
-            .to_length(context)?
-            .try_into()
-            .map_err(interror_to_value)?;
-
-        let mut idx = match args.get(1) {
-            Some(from_idx_ptr) => {
-                let from_idx = from_idx_ptr.to_integer(context)?;
-
-                if !from_idx.is_finite() {
-                    return Ok(Value::from(-1));
-                } else if from_idx < 0.0 {
-                    len + f64_to_isize(from_idx)?
-                } else {
-                    min(f64_to_isize(from_idx)?, len - 1)
+        let start = match args.get(1) {
+            Some(from_index) => match from_index.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::NegativeInfinity => return Ok(Value::from(-1)),
+                IntegerOrInfinity::Integer(i) if i < 0 => {
+                    match (len as i64).checked_add(i).filter(|k| *k >= 0) {
+                        Some(k) => k as usize,
+                        None => return Ok(Value::from(-1)),
+                    }
                 }
-            }
+                IntegerOrInfinity::Integer(i) => (i as u64).min(len as u64 - 1) as usize,
+                IntegerOrInfinity::PositiveInfinity => len - 1,
+            },
             None => len - 1,
         };
 
-        while idx >= 0 {
-            let check_element = this.get_field(idx, context)?.clone();
-
-            if check_element.strict_equals(&search_element) {
-                return Ok(Value::from(i32::try_from(idx).map_err(interror_to_value)?));
+        for k in (0..=start).rev() {
+            // Holes are skipped.
+            let key = PropertyKey::from(k);
+            if object.has_property(&key) {
+                let element = object.get(&key, object.clone().into(), context)?;
+                if element.strict_equals(&search_element) {
+                    return Ok(Value::from(k));
+                }
             }
-
-            idx -= 1;
         }
 
         Ok(Value::from(-1))
@@ -831,9 +1007,8 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/find
     pub(crate) fn find(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from(
-                "missing callback when calling function Array.prototype.find",
-            ));
+            return context
+                .throw_type_error("missing callback when calling function Array.prototype.find");
         }
         let callback = &args[0];
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
@@ -863,9 +1038,7 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findIndex
     pub(crate) fn find_index(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from(
-                "Missing argument for Array.prototype.findIndex",
-            ));
+            return context.throw_type_error("Missing argument for Array.prototype.findIndex");
         }
 
         let predicate_arg = args.get(0).expect("Could not get `predicate` argument.");
@@ -930,16 +1103,18 @@ impl Array {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
+        let object = this.to_object(context)?;
         let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let len = Self::length_of_array_like(&object, context)?;
 
-        let length = this.get_field("length", context)?.to_length(context)?;
-
-        for idx in 0..length {
-            let check_element = this.get_field(idx, context)?.clone();
-
-            if same_value_zero(&check_element, &search_element) {
+        // Unlike `indexOf`, holes are read as `undefined`.
+        let mut k = Self::get_relative_start(context, args.get(1), len)?;
+        while k < len {
+            let element = object.get(&k.into(), object.clone().into(), context)?;
+            if same_value_zero(&element, &search_element) {
                 return Ok(Value::from(true));
             }
+            k += 1;
         }
 
         Ok(Value::from(false))
@@ -995,9 +1170,9 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/filter
     pub(crate) fn filter(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from(
+            return context.throw_type_error(
                 "missing argument 0 when calling function Array.prototype.filter",
-            ));
+            );
         }
 
         let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
@@ -1008,10 +1183,11 @@ impl Array {
         let new = Self::new_array(context)?;
 
         let values = (0..length)
+            .filter(|idx| this.has_field(*idx))
             .map(|idx| {
                 let element = this.get_field(idx, context)?;
 
-                let args = [element.clone(), Value::from(idx), new.clone()];
+                let args = [element.clone(), Value::from(idx), this.clone()];
 
                 let callback_result = context.call(&callback, &this_val, &args)?;
 
@@ -1044,9 +1220,8 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/some
     pub(crate) fn some(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from(
-                "missing callback when calling function Array.prototype.some",
-            ));
+            return context
+                .throw_type_error("missing callback when calling function Array.prototype.some");
         }
         let callback = &args[0];
         let this_arg = if args.len() > 1 {
@@ -1058,6 +1233,10 @@ impl Array {
         let max_len = this.get_field("length", context)?.to_length(context)?;
         let mut len = max_len;
         while i < len {
+            if !this.has_field(i) {
+                i += 1;
+                continue;
+            }
             let element = this.get_field(i, context)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = context.call(callback, &this_arg, &arguments)?;
@@ -1348,16 +1527,6 @@ impl Array {
     }
 }
 
-fn f64_to_isize(v: f64) -> Result<isize> {
-    v.to_isize()
-        .ok_or_else(|| Value::string("cannot convert f64 to isize - out of range"))
-}
-
-fn f64_to_usize(v: f64) -> Result<usize> {
-    v.to_usize()
-        .ok_or_else(|| Value::string("cannot convert f64 to usize - out of range"))
-}
-
 fn interror_to_value(err: std::num::TryFromIntError) -> Value {
     Value::string(format!("{}", err))
 }
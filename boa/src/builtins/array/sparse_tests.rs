@@ -0,0 +1,132 @@
+//! Tests for the holes of sparse arrays, which are missing indices rather than `undefined` values.
+
+use crate::{forward, Context};
+
+#[test]
+fn holes_are_not_properties() {
+    let mut context = Context::new();
+    let init = r#"
+        var literal = [1, , 3];
+        var trailing = [1, , ];
+        var constructed = new Array(5);
+        var deleted = [1, 2, 3];
+        delete deleted[0];
+        var explicit = [undefined];
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "literal.length"), "3");
+    assert_eq!(forward(&mut context, "1 in literal"), "false");
+    assert_eq!(forward(&mut context, "literal[1]"), "undefined");
+    assert_eq!(forward(&mut context, "trailing.length"), "2");
+    assert_eq!(forward(&mut context, "1 in trailing"), "false");
+    assert_eq!(forward(&mut context, "[,].length"), "1");
+    assert_eq!(forward(&mut context, "constructed.length"), "5");
+    assert_eq!(forward(&mut context, "0 in constructed"), "false");
+    assert_eq!(forward(&mut context, "0 in deleted"), "false");
+    assert_eq!(forward(&mut context, "deleted.length"), "3");
+    assert_eq!(forward(&mut context, "0 in explicit"), "true");
+    assert_eq!(forward(&mut context, "[...[1], , 2].length"), "3");
+    assert_eq!(forward(&mut context, "1 in [...[1], , 2]"), "false");
+}
+
+#[test]
+fn object_keys_omits_holes() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Object.keys([1, , 3]).join()"),
+        "\"0,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(new Array(3)).length"),
+        "0"
+    );
+}
+
+#[test]
+fn for_each_skips_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var visited = [];
+        [1, , undefined, , 5].forEach(function(value, index) {
+            visited.push(index);
+        });
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "visited.join()"), "\"0,2,4\"");
+}
+
+#[test]
+fn map_preserves_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var calls = 0;
+        var mapped = [1, , 3].map(function(value) {
+            calls++;
+            return value * 2;
+        });
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "calls"), "2");
+    assert_eq!(forward(&mut context, "mapped.length"), "3");
+    assert_eq!(forward(&mut context, "1 in mapped"), "false");
+    assert_eq!(forward(&mut context, "mapped[2]"), "6");
+}
+
+#[test]
+fn filter_every_and_some_skip_holes() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, , 3].filter(function() { return true; }).length"
+        ),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, , 3].every(function(value) { return value !== undefined; })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, , 3].some(function(value) { return value === undefined; })"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn index_of_skips_holes() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "[1, , 3].indexOf(undefined)"), "-1");
+    assert_eq!(
+        forward(&mut context, "[1, , 3].lastIndexOf(undefined)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "[, undefined].indexOf(undefined)"),
+        "1"
+    );
+}
+
+#[test]
+fn join_treats_holes_as_empty() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "[1, , 3].join()"), "\"1,,3\"");
+    assert_eq!(forward(&mut context, "new Array(3).join('-')"), "\"--\"");
+    assert_eq!(
+        forward(&mut context, "[null, undefined, 1].join()"),
+        "\",,1\""
+    );
+    assert_eq!(forward(&mut context, "String([1, , 3])"), "\"1,,3\"");
+}
@@ -68,6 +68,49 @@ fn is_array() {
     );
 }
 
+#[test]
+fn from() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Array.from([1, 2, 3]).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.from('abc').join()"),
+        "\"a,b,c\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from({ length: 2, 0: 'x', 1: 'y' }).join()"
+        ),
+        "\"x,y\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from([1, 2], (x, i) => x * 10 + i).join()"
+        ),
+        "\"10,21\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from([1], function(x) { return this.offset + x; }, { offset: 5 })[0]"
+        ),
+        "6"
+    );
+    assert_eq!(
+        forward(&mut context, "Array.isArray(Array.from({}))"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Array.from([], 1)"),
+        "Uncaught \"TypeError\": \"Array.from: the mapping function is not callable\""
+    );
+}
+
 #[test]
 #[ignore]
 fn concat() {
@@ -108,6 +151,50 @@ fn concat() {
     assert_eq!(nn, "a.b.c");
 }
 
+#[test]
+fn concat_spreadable() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [1, 2];
+        var holes = [3, , 5];
+        var arrayLike = { length: 2, 0: "a", 1: "b", [Symbol.isConcatSpreadable]: true };
+        var notSpread = [6, 7];
+        notSpread[Symbol.isConcatSpreadable] = false;
+        var reads = 0;
+        var withGetter = [];
+        Object.defineProperty(withGetter, 0, { get: function() { reads++; return "g"; } });
+        var result = arr.concat(holes, arrayLike, "str", 8, notSpread, withGetter);
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result.length"), "11");
+    assert_eq!(
+        forward(&mut context, "result.slice(0, 8).join()"),
+        "\"1,2,3,,5,a,b,str\""
+    );
+    assert_eq!(forward(&mut context, "3 in result"), "false");
+    assert_eq!(forward(&mut context, "result[9] === notSpread"), "true");
+    assert_eq!(forward(&mut context, "result[10]"), "\"g\"");
+    assert_eq!(forward(&mut context, "reads"), "1");
+    assert_eq!(forward(&mut context, "arr.length"), "2");
+    assert_eq!(forward(&mut context, "arr.concat() !== arr"), "true");
+    assert_eq!(forward(&mut context, "arr.concat().join()"), "\"1,2\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.concat.call('ab', 1)[0] instanceof String"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1].concat({ length: Number.MAX_SAFE_INTEGER, [Symbol.isConcatSpreadable]: true })"
+        ),
+        "Uncaught \"TypeError\": \"concat: the result length is too large\""
+    );
+}
+
 #[test]
 fn join() {
     let mut context = Context::new();
@@ -301,6 +388,104 @@ fn unshift() {
     assert_eq!(forward(&mut context, "arr[1]"), "2");
 }
 
+#[test]
+fn splice() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [1, 2, 3, 4, 5];
+        var removed = arr.splice(1, 2, "a", "b", "c");
+        var tail = [1, 2, 3, 4, 5];
+        var removedTail = tail.splice(-2);
+        var grown = [1, 2];
+        var removedNone = grown.splice(1, 0, "x");
+        var holes = [1, , 3];
+        var removedHole = holes.splice(0, 2);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "removed.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut context, "arr.join()"), "\"1,a,b,c,4,5\"");
+    assert_eq!(forward(&mut context, "removedTail.join()"), "\"4,5\"");
+    assert_eq!(forward(&mut context, "tail.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "removedNone.length"), "0");
+    assert_eq!(forward(&mut context, "grown.join()"), "\"1,x,2\"");
+    assert_eq!(forward(&mut context, "removedHole.length"), "2");
+    assert_eq!(forward(&mut context, "1 in removedHole"), "false");
+    assert_eq!(forward(&mut context, "holes.join()"), "\"3\"");
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].splice(-10, 1).join()"),
+        "\"1\""
+    );
+    assert_eq!(forward(&mut context, "[1, 2, 3].splice(1, -1).length"), "0");
+    assert_eq!(forward(&mut context, "[1, 2, 3].splice().length"), "0");
+}
+
+#[test]
+fn generic_mutators() {
+    let mut context = Context::new();
+    let init = r#"
+        var obj = { length: 1, 0: "a" };
+        var pushed = Array.prototype.push.call(obj, "b", "c");
+        var popped = Array.prototype.pop.call(obj);
+        var unshifted = Array.prototype.unshift.call(obj, "z");
+        var shifted = Array.prototype.shift.call(obj);
+        var spliced = Array.prototype.splice.call(obj, 0, 1, "y");
+        var empty = {};
+        Array.prototype.push.call(empty);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "pushed"), "3");
+    assert_eq!(forward(&mut context, "popped"), "\"c\"");
+    assert_eq!(forward(&mut context, "2 in obj"), "false");
+    assert_eq!(forward(&mut context, "unshifted"), "3");
+    assert_eq!(forward(&mut context, "shifted"), "\"z\"");
+    assert_eq!(forward(&mut context, "spliced[0]"), "\"a\"");
+    assert_eq!(forward(&mut context, "obj.length"), "2");
+    assert_eq!(forward(&mut context, "obj[0]"), "\"y\"");
+    assert_eq!(forward(&mut context, "obj[1]"), "\"b\"");
+    assert_eq!(forward(&mut context, "empty.length"), "0");
+}
+
+#[test]
+fn mutators_throw() {
+    let mut context = Context::new();
+    let init = r#"
+        "use strict";
+        var frozen = Object.freeze([1]);
+        var fixedLength = [1];
+        Object.defineProperty(fixedLength, "length", { writable: false });
+        var huge = { length: Number.MAX_SAFE_INTEGER };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "frozen.push(2)"),
+        "Uncaught \"TypeError\": \"Cannot assign to read only property: 1\""
+    );
+    assert_eq!(forward(&mut context, "frozen.length"), "1");
+    assert_eq!(
+        forward(&mut context, "fixedLength.push(2)"),
+        "Uncaught \"TypeError\": \"Cannot assign to read only property: 1\""
+    );
+    assert_eq!(
+        forward(&mut context, "fixedLength.pop()"),
+        "Uncaught \"TypeError\": \"Cannot assign to read only property: length\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.prototype.push.call(huge, 1)"),
+        "Uncaught \"TypeError\": \"push: the array length is too large\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.prototype.unshift.call(huge, 1)"),
+        "Uncaught \"TypeError\": \"unshift: the array length is too large\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.prototype.splice.call(huge, 0, 0, 1)"),
+        "Uncaught \"TypeError\": \"splice: the array length is too large\""
+    );
+}
+
 #[test]
 fn reverse() {
     let mut context = Context::new();
@@ -584,6 +769,75 @@ fn includes_value() {
     assert_eq!(second_in_many, String::from("false"));
 }
 
+#[test]
+fn search_from_index_boundaries() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = [2, 5, 9, 2];
+        var holes = [, 1];
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "array.indexOf(2, -1)"), "3");
+    assert_eq!(forward(&mut context, "array.indexOf(2, -4)"), "0");
+    assert_eq!(forward(&mut context, "array.indexOf(2, -100)"), "0");
+    assert_eq!(forward(&mut context, "array.indexOf(2, -Infinity)"), "0");
+    assert_eq!(forward(&mut context, "array.indexOf(2, Infinity)"), "-1");
+    assert_eq!(forward(&mut context, "array.indexOf(5, NaN)"), "1");
+    assert_eq!(forward(&mut context, "array.indexOf(9, '2')"), "2");
+
+    assert_eq!(forward(&mut context, "array.lastIndexOf(2)"), "3");
+    assert_eq!(forward(&mut context, "array.lastIndexOf(2, 2)"), "0");
+    assert_eq!(forward(&mut context, "array.lastIndexOf(2, -2)"), "0");
+    assert_eq!(forward(&mut context, "array.lastIndexOf(2, -1)"), "3");
+    assert_eq!(forward(&mut context, "array.lastIndexOf(2, -5)"), "-1");
+    assert_eq!(forward(&mut context, "array.lastIndexOf(2, Infinity)"), "3");
+    assert_eq!(
+        forward(&mut context, "array.lastIndexOf(2, -Infinity)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut context, "array.lastIndexOf(2, undefined)"),
+        "0"
+    );
+
+    assert_eq!(forward(&mut context, "array.includes(2, 3)"), "true");
+    assert_eq!(forward(&mut context, "array.includes(5, -2)"), "false");
+    assert_eq!(
+        forward(&mut context, "array.includes(5, -Infinity)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "array.includes(2, Infinity)"),
+        "false"
+    );
+
+    assert_eq!(forward(&mut context, "holes.indexOf(undefined)"), "-1");
+    assert_eq!(forward(&mut context, "holes.lastIndexOf(undefined)"), "-1");
+    assert_eq!(forward(&mut context, "holes.includes(undefined)"), "true");
+    assert_eq!(forward(&mut context, "[undefined].indexOf()"), "0");
+}
+
+#[test]
+fn search_equality() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "[NaN].indexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut context, "[NaN].lastIndexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut context, "[NaN].includes(NaN)"), "true");
+    assert_eq!(forward(&mut context, "[-0].indexOf(0)"), "0");
+    assert_eq!(forward(&mut context, "[0].lastIndexOf(-0)"), "0");
+    assert_eq!(forward(&mut context, "[-0].includes(0)"), "true");
+    assert_eq!(forward(&mut context, "[1].indexOf('1')"), "-1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.indexOf.call({ length: 2, 0: 'a', 1: 'b' }, 'b')"
+        ),
+        "1"
+    );
+}
+
 #[test]
 fn map() {
     let mut context = Context::new();
@@ -1370,3 +1624,32 @@ fn array_length_is_not_enumerable() {
     let desc = array.get_property("length").unwrap();
     assert!(!desc.enumerable());
 }
+
+#[test]
+fn array_intrinsics_survive_overwritten_global() {
+    let mut context = Context::new();
+    let init = r#"
+        var ArrayPrototype = Array.prototype;
+        globalThis.Array = null;
+        var literal = [1, 2, 3];
+        var mapped = [].map.call(literal, x => x * 2);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "mapped.join()"), "\"2,4,6\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(mapped) === ArrayPrototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(literal) === ArrayPrototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Array"), "null");
+}
@@ -0,0 +1,171 @@
+//! This module implements the global `atob` and `btoa` functions.
+//!
+//! `btoa` encodes a string whose characters all fit in a byte to base64, and `atob` decodes it
+//! back, with the forgiving base64 decoding of the WHATWG Infra standard. They are only built
+//! with the `web` feature.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [Infra reference][infra]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#atob
+//! [infra]: https://infra.spec.whatwg.org/#forgiving-base64
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/btoa
+
+use super::function::make_builtin_fn;
+use crate::{
+    builtins::BuiltIn, object::FunctionBuilder, property::Attribute, BoaProfiler, Context, Result,
+    Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The base64 alphabet, indexed by the value of the digits.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The global `atob` and `btoa` functions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Base64;
+
+impl BuiltIn for Base64 {
+    const NAME: &'static str = "btoa";
+
+    fn attribute() -> Attribute {
+        Attribute::BUILTIN
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let global = context.global_object().clone();
+        make_builtin_fn(Self::atob, "atob", &global, 1, context);
+
+        let btoa = FunctionBuilder::new(context, Self::btoa)
+            .name(Self::NAME)
+            .length(1)
+            .build();
+        (Self::NAME, btoa.into(), Self::attribute())
+    }
+}
+
+impl Base64 {
+    /// `btoa( data )`
+    ///
+    /// Encodes the string to base64, reading each of its characters as a byte. Throws a
+    /// `TypeError`, which stands for the `InvalidCharacterError` of the browsers, if a character
+    /// is above `U+00FF`.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#dom-btoa
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/btoa
+    pub(crate) fn btoa(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let data = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let mut bytes = Vec::with_capacity(data.len());
+        for unit in data.encode_utf16() {
+            if unit > 0xFF {
+                return context.throw_type_error(
+                    "InvalidCharacterError: btoa: the string has characters above U+00FF",
+                );
+            }
+            bytes.push(unit as u8);
+        }
+
+        Ok(encode(&bytes).into())
+    }
+
+    /// `atob( data )`
+    ///
+    /// Decodes a base64 string, returning a string with a character for each byte. The ASCII
+    /// whitespace is ignored, and the padding is optional but must be right if present. Throws a
+    /// `TypeError`, which stands for the `InvalidCharacterError` of the browsers, if the string is
+    /// not valid base64.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#dom-atob
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/atob
+    pub(crate) fn atob(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let data = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        match decode(&data) {
+            Some(bytes) => Ok(bytes.into_iter().map(char::from).collect::<String>().into()),
+            None => context
+                .throw_type_error("InvalidCharacterError: atob: the string is not valid base64"),
+        }
+    }
+}
+
+/// Encodes bytes to base64, with padding.
+fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0, |group, (i, &byte)| {
+            group | (u32::from(byte) << (16 - 8 * i))
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let digit = (group >> (18 - 6 * i)) & 0x3F;
+                encoded.push(char::from(ALPHABET[digit as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes base64 with the forgiving base64 decoding of the Infra standard, or returns `None` if
+/// the string is not valid base64.
+///
+/// More information:
+///  - [Infra reference][spec]
+///
+/// [spec]: https://infra.spec.whatwg.org/#forgiving-base64-decode
+fn decode(data: &str) -> Option<Vec<u8>> {
+    let mut digits: Vec<u8> = data
+        .bytes()
+        .filter(|byte| !matches!(byte, b'\t' | b'\n' | b'\x0C' | b'\r' | b' '))
+        .collect();
+
+    if digits.len() % 4 == 0 {
+        if digits.ends_with(b"==") {
+            digits.truncate(digits.len() - 2);
+        } else if digits.ends_with(b"=") {
+            digits.truncate(digits.len() - 1);
+        }
+    }
+    if digits.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(digits.len() * 3 / 4);
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+    for digit in digits {
+        // The characters that are not ASCII are rejected here too, one byte at a time.
+        let value = ALPHABET.iter().position(|&c| c == digit)?;
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    // The bits left over from the last digit are discarded, even if they are not zero.
+    Some(bytes)
+}
@@ -0,0 +1,97 @@
+use super::{decode, encode};
+use crate::{forward, Context};
+
+#[test]
+fn encode_vectors() {
+    for (bytes, expected) in &[
+        (&b""[..], ""),
+        (&b"f"[..], "Zg=="),
+        (&b"fo"[..], "Zm8="),
+        (&b"foo"[..], "Zm9v"),
+        (&b"foob"[..], "Zm9vYg=="),
+        (&b"fooba"[..], "Zm9vYmE="),
+        (&b"foobar"[..], "Zm9vYmFy"),
+        (&b"\x00\xFF\xFE"[..], "AP/+"),
+    ] {
+        assert_eq!(encode(bytes), *expected, "{:?}", bytes);
+    }
+}
+
+#[test]
+fn decode_vectors() {
+    for (data, expected) in &[
+        ("", Some(&b""[..])),
+        ("Zg==", Some(&b"f"[..])),
+        ("Zg", Some(&b"f"[..])),
+        ("Zm8=", Some(&b"fo"[..])),
+        ("Zm8", Some(&b"fo"[..])),
+        ("Zm9v", Some(&b"foo"[..])),
+        (" Zm9v\tYm\nFy\r\x0C", Some(&b"foobar"[..])),
+        ("Zm9vYg = =", Some(&b"foob"[..])),
+        ("AP/+", Some(&b"\x00\xFF\xFE"[..])),
+        // The bits left over by the last digit may be set.
+        ("Zh==", Some(&b"f"[..])),
+        // The padding must complete a group of four digits.
+        ("Zg=", None),
+        ("Zm8==", None),
+        ("Zg===", None),
+        ("=", None),
+        ("====", None),
+        // `=` is only allowed at the end.
+        ("Zg==Zg==", None),
+        ("Z=g=", None),
+        ("Zm=9v", None),
+        // A single digit of a group is not enough for a byte.
+        ("Z", None),
+        ("Zm9vY", None),
+        // Characters outside of the alphabet, or of ASCII.
+        ("Zm9v-_", None),
+        ("Zm9v\u{A0}", None),
+        ("Zm9vé", None),
+        ("Zm9v\u{0}", None),
+    ] {
+        assert_eq!(decode(data).as_deref(), *expected, "{:?}", data);
+    }
+}
+
+#[test]
+fn atob_and_btoa() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "btoa('Hello, world')"),
+        "\"SGVsbG8sIHdvcmxk\""
+    );
+    assert_eq!(
+        forward(&mut context, "atob('SGVsbG8sIHdvcmxk')"),
+        "\"Hello, world\""
+    );
+    assert_eq!(forward(&mut context, "btoa('\\xFF\\xE9')"), "\"/+k=\"");
+    assert_eq!(
+        forward(&mut context, "atob('/+k=') === '\\xFF\\xE9'"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "btoa(null)"), "\"bnVsbA==\"");
+    assert_eq!(forward(&mut context, "btoa()"), "\"dW5kZWZpbmVk\"");
+    assert_eq!(
+        forward(&mut context, "atob(undefined)"),
+        "Uncaught \"TypeError\": \"InvalidCharacterError: atob: the string is not valid base64\""
+    );
+
+    for source in &[
+        "btoa('\\u0100')",
+        "btoa('😀')",
+        "atob('Zg=')",
+        "atob('Zg==Zg==')",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {}; }} catch (e) {{ e.name }}", source)
+            ),
+            "\"TypeError\"",
+            "{} did not throw",
+            source
+        );
+    }
+}
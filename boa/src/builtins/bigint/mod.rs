@@ -44,7 +44,7 @@ impl BuiltIn for BigInt {
     const NAME: &'static str = "BigInt";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -57,7 +57,7 @@ impl BuiltIn for BigInt {
         )
         .name(Self::NAME)
         .length(Self::LENGTH)
-        .method(Self::to_string, "toString", 1)
+        .method(Self::to_string, "toString", 0)
         .method(Self::value_of, "valueOf", 0)
         .static_method(Self::as_int_n, "asIntN", 2)
         .static_method(Self::as_uint_n, "asUintN", 2)
@@ -63,3 +63,61 @@ fn instances_have_correct_proto_set() {
         &bool_prototype
     ));
 }
+
+#[test]
+fn boolean_wrapper_object() {
+    let mut context = Context::new();
+    let init = r#"
+        let b = new Boolean(false);
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof b"), "\"object\"");
+    assert_eq!(forward(&mut context, "b.valueOf()"), "false");
+    assert_eq!(forward(&mut context, "b == false"), "true");
+    assert_eq!(forward(&mut context, "b === false"), "false");
+    assert_eq!(
+        forward(&mut context, "b ? 'truthy' : 'falsy'"),
+        "\"truthy\""
+    );
+    assert_eq!(
+        forward(&mut context, "Boolean.prototype.valueOf.call({})"),
+        "Uncaught \"TypeError\": \"'this' is not a boolean\""
+    );
+}
+
+#[test]
+fn call_and_construct() {
+    let mut context = Context::new();
+
+    // Called as a function, `Boolean` is `ToBoolean`.
+    for (value, expected) in &[
+        ("", "false"),
+        ("undefined", "false"),
+        ("null", "false"),
+        ("0", "false"),
+        ("-0", "false"),
+        ("NaN", "false"),
+        ("''", "false"),
+        ("0n", "false"),
+        ("1", "true"),
+        ("'false'", "true"),
+        ("' '", "true"),
+        ("1n", "true"),
+        ("Symbol()", "true"),
+        ("{}", "true"),
+        ("[]", "true"),
+        ("new Boolean(false)", "true"),
+    ] {
+        assert_eq!(
+            &forward(&mut context, &format!("Boolean({})", value)),
+            expected,
+            "Boolean({})",
+            value
+        );
+    }
+
+    assert_eq!(forward(&mut context, "typeof Boolean(1)"), "\"boolean\"");
+    assert_eq!(forward(&mut context, "typeof new Boolean(1)"), "\"object\"");
+    assert_eq!(forward(&mut context, "new Boolean(0n).valueOf()"), "false");
+}
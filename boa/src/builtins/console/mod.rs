@@ -18,21 +18,104 @@ mod tests;
 
 use crate::{
     builtins::BuiltIn,
-    object::ObjectInitializer,
-    property::Attribute,
-    value::{display::display_obj, RcString, Value},
+    context::ErrorKind,
+    object::{GcObject, ObjectInitializer},
+    property::{Attribute, PropertyKey},
+    value::{RcString, Value},
     BoaProfiler, Context, Result,
 };
 use rustc_hash::FxHashMap;
-use std::time::SystemTime;
+use std::{
+    fmt::{self, Debug, Display},
+    io::Write,
+    time::Duration,
+};
+
+#[cfg(feature = "system")]
+use std::time::Instant;
 
 /// This represents the different types of log messages.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LogMessage {
     Log(String),
     Info(String),
     Warn(String),
     Error(String),
+    Debug(String),
+}
+
+impl LogMessage {
+    /// Returns the formatted text of the message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Log(msg)
+            | Self::Info(msg)
+            | Self::Warn(msg)
+            | Self::Error(msg)
+            | Self::Debug(msg) => msg,
+        }
+    }
+}
+
+impl Display for LogMessage {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// A host hook that receives everything printed through the `console` object.
+///
+/// Messages arrive already formatted and indented for the current group, tagged with
+/// their log level. Register a logger with [`Context::set_console_logger`].
+pub trait ConsoleLogger {
+    /// Receives a single message printed by one of the `console` methods.
+    fn log(&mut self, msg: LogMessage);
+}
+
+/// The default console logger, which prints errors to the standard error and everything
+/// else to the standard output.
+#[cfg(feature = "system")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdoutLogger;
+
+#[cfg(feature = "system")]
+impl ConsoleLogger for StdoutLogger {
+    fn log(&mut self, msg: LogMessage) {
+        match msg {
+            LogMessage::Error(msg) => eprintln!("{}", msg),
+            msg => println!("{}", msg),
+        }
+    }
+}
+
+/// A console logger that writes every message, regardless of its level, as a line into a
+/// [`Write`] implementor.
+#[derive(Debug, Default, Clone)]
+pub struct WriteLogger<W> {
+    writer: W,
+}
+
+impl<W: Write> WriteLogger<W> {
+    /// Creates a new logger writing into `writer`.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Consumes the logger, returning the underlying writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> ConsoleLogger for WriteLogger<W> {
+    fn log(&mut self, msg: LogMessage) {
+        // The console methods cannot report I/O errors to the script, so they are dropped.
+        let _ = writeln!(self.writer, "{}", msg);
+    }
 }
 
 /// Helper function that returns the argument at a specified index.
@@ -44,21 +127,60 @@ where
 }
 
 /// Helper function for logging messages.
-pub(crate) fn logger(msg: LogMessage, console_state: &Console) {
-    let indent = 2 * console_state.groups.len();
-
-    match msg {
-        LogMessage::Error(msg) => {
-            eprintln!("{:>width$}", msg, width = indent);
+///
+/// Throws an `Error` if no logger has been set and the `system` feature is disabled.
+pub(crate) fn logger(msg: LogMessage, context: &mut Context) -> Result<()> {
+    let console_state = context.console_mut();
+    let indent = " ".repeat(2 * console_state.groups.len());
+    let indented = |msg: String| {
+        if indent.is_empty() {
+            return msg;
         }
-        LogMessage::Log(msg) | LogMessage::Info(msg) | LogMessage::Warn(msg) => {
-            println!("{:>width$}", msg, width = indent);
+        msg.lines()
+            .map(|line| format!("{}{}", indent, line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let msg = match msg {
+        LogMessage::Log(msg) => LogMessage::Log(indented(msg)),
+        LogMessage::Info(msg) => LogMessage::Info(indented(msg)),
+        LogMessage::Warn(msg) => LogMessage::Warn(indented(msg)),
+        LogMessage::Error(msg) => LogMessage::Error(indented(msg)),
+        LogMessage::Debug(msg) => LogMessage::Debug(indented(msg)),
+    };
+
+    match console_state.logger {
+        Some(ref mut logger) => {
+            logger.log(msg);
+            Ok(())
         }
+        None => Err(context.construct_error(
+            ErrorKind::Error,
+            "no console logger has been set by the host, see `Context::set_console_logger`",
+        )),
     }
 }
 
+/// The depth up to which the console prints nested objects.
+const INSPECT_DEPTH: usize = 2;
+
 /// This represents the `console` formatter.
+///
+/// If the first argument is a string it is used as the format string, otherwise every argument is
+/// inspected and printed separated by spaces.
 pub fn formatter(data: &[Value], context: &mut Context) -> Result<String> {
+    match data.get(0) {
+        None | Some(Value::String(_)) => {}
+        Some(_) => {
+            return Ok(data
+                .iter()
+                .map(|value| value.display_obj(INSPECT_DEPTH).to_string())
+                .collect::<Vec<_>>()
+                .join(" "))
+        }
+    }
+
     let target = data
         .get(0)
         .cloned()
@@ -99,7 +221,7 @@ pub fn formatter(data: &[Value], context: &mut Context) -> Result<String> {
                         /* object, FIXME: how to render this properly? */
                         'o' | 'O' => {
                             let arg = data.get(arg_index).cloned().unwrap_or_default();
-                            formatted.push_str(&format!("{}", arg.display()));
+                            formatted.push_str(&format!("{}", arg.display_obj(INSPECT_DEPTH)));
                             arg_index += 1
                         }
                         /* string */
@@ -126,7 +248,7 @@ pub fn formatter(data: &[Value], context: &mut Context) -> Result<String> {
 
             /* unformatted data */
             for rest in data.iter().skip(arg_index) {
-                formatted.push_str(&format!(" {}", rest.to_string(context)?))
+                formatted.push_str(&format!(" {}", rest.display_obj(INSPECT_DEPTH)))
             }
 
             Ok(formatted)
@@ -134,19 +256,101 @@ pub fn formatter(data: &[Value], context: &mut Context) -> Result<String> {
     }
 }
 
+/// Renders the given header and rows as a box-drawn table, centering every cell.
+fn render_table(header: &[String], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(title.chars().count()))
+                .max()
+                .unwrap_or_default()
+                + 2
+        })
+        .collect();
+
+    let separator = |left: &str, middle: &str, right: &str| {
+        let lines: Vec<String> = widths.iter().map(|width| "─".repeat(*width)).collect();
+        format!("{}{}{}", left, lines.join(middle), right)
+    };
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                let padding = width - cell.chars().count();
+                format!(
+                    "{}{}{}",
+                    " ".repeat(padding / 2),
+                    cell,
+                    " ".repeat(padding - padding / 2)
+                )
+            })
+            .collect();
+        format!("│{}│", cells.join("│"))
+    };
+
+    let mut table = vec![
+        separator("┌", "┬", "┐"),
+        line(header),
+        separator("├", "┼", "┤"),
+    ];
+    table.extend(rows.iter().map(|row| line(row)));
+    table.push(separator("└", "┴", "┘"));
+    table.join("\n")
+}
+
 /// This is the internal console object state.
-#[derive(Debug, Default)]
 pub(crate) struct Console {
     count_map: FxHashMap<RcString, u32>,
-    timer_map: FxHashMap<RcString, u128>,
+    timer_map: FxHashMap<RcString, Duration>,
     groups: Vec<String>,
+    logger: Option<Box<dyn ConsoleLogger>>,
+    clock: Option<Box<dyn Fn() -> Duration>>,
+}
+
+impl Default for Console {
+    #[cfg(feature = "system")]
+    fn default() -> Self {
+        let origin = Instant::now();
+        Self {
+            count_map: FxHashMap::default(),
+            timer_map: FxHashMap::default(),
+            groups: Vec::new(),
+            logger: Some(Box::new(StdoutLogger)),
+            clock: Some(Box::new(move || origin.elapsed())),
+        }
+    }
+
+    #[cfg(not(feature = "system"))]
+    fn default() -> Self {
+        Self {
+            count_map: FxHashMap::default(),
+            timer_map: FxHashMap::default(),
+            groups: Vec::new(),
+            logger: None,
+            clock: None,
+        }
+    }
+}
+
+impl Debug for Console {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Console")
+            .field("count_map", &self.count_map)
+            .field("timer_map", &self.timer_map)
+            .field("groups", &self.groups)
+            .finish()
+    }
 }
 
 impl BuiltIn for Console {
     const NAME: &'static str = "console";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -171,6 +375,7 @@ impl BuiltIn for Console {
             .function(Self::time_end, "timeEnd", 0)
             .function(Self::dir, "dir", 0)
             .function(Self::dir, "dirxml", 0)
+            .function(Self::table, "table", 0)
             .build();
 
         (Self::NAME, console.into(), Self::attribute())
@@ -181,6 +386,32 @@ impl Console {
     /// The name of the object.
     pub(crate) const NAME: &'static str = "console";
 
+    /// Replaces the logger receiving the console output.
+    #[inline]
+    pub(crate) fn set_logger(&mut self, logger: Box<dyn ConsoleLogger>) {
+        self.logger = Some(logger);
+    }
+
+    /// Replaces the monotonic clock used by the console timers.
+    #[inline]
+    pub(crate) fn set_clock(&mut self, clock: Box<dyn Fn() -> Duration>) {
+        self.clock = Some(clock);
+    }
+
+    /// Returns the current reading of the console clock.
+    ///
+    /// Throws an `Error` if no clock has been set and the `system` feature is disabled.
+    #[inline]
+    fn now(context: &mut Context) -> Result<Duration> {
+        match context.console().clock {
+            Some(ref clock) => Ok(clock()),
+            None => Err(context.construct_error(
+                ErrorKind::Error,
+                "no console clock has been set by the host, see `Context::set_console_clock`",
+            )),
+        }
+    }
+
     /// `console.assert(condition, ...data)`
     ///
     /// Prints a JavaScript value to the standard error if first argument evaluates to `false` or there
@@ -203,14 +434,11 @@ impl Console {
             } else if !args[0].is_string() {
                 args.insert(0, Value::from(message));
             } else {
-                let concat = format!("{}: {}", message, args[0].display());
+                let concat = format!("{}: {}", message, args[0].to_string(context)?);
                 args[0] = Value::from(concat);
             }
 
-            logger(
-                LogMessage::Error(formatter(&args, context)?),
-                context.console(),
-            );
+            logger(LogMessage::Error(formatter(&args, context)?), context)?;
         }
 
         Ok(Value::undefined())
@@ -242,10 +470,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#debug
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/debug
     pub(crate) fn debug(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        logger(
-            LogMessage::Log(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogMessage::Debug(formatter(args, context)?), context)?;
         Ok(Value::undefined())
     }
 
@@ -260,10 +485,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#error
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/error
     pub(crate) fn error(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        logger(
-            LogMessage::Error(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogMessage::Error(formatter(args, context)?), context)?;
         Ok(Value::undefined())
     }
 
@@ -278,10 +500,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#info
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/info
     pub(crate) fn info(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        logger(
-            LogMessage::Info(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogMessage::Info(formatter(args, context)?), context)?;
         Ok(Value::undefined())
     }
 
@@ -296,10 +515,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#log
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/log
     pub(crate) fn log(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        logger(
-            LogMessage::Log(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogMessage::Log(formatter(args, context)?), context)?;
         Ok(Value::undefined())
     }
 
@@ -315,16 +531,13 @@ impl Console {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/trace
     pub(crate) fn trace(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if !args.is_empty() {
-            logger(
-                LogMessage::Log(formatter(args, context)?),
-                context.console(),
-            );
+            logger(LogMessage::Log(formatter(args, context)?), context)?;
 
             /* TODO: get and print stack trace */
             logger(
                 LogMessage::Log("Not implemented: <stack trace>".to_string()),
-                context.console(),
-            )
+                context,
+            )?
         }
 
         Ok(Value::undefined())
@@ -341,10 +554,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#warn
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/warn
     pub(crate) fn warn(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        logger(
-            LogMessage::Warn(formatter(args, context)?),
-            context.console(),
-        );
+        logger(LogMessage::Warn(formatter(args, context)?), context)?;
         Ok(Value::undefined())
     }
 
@@ -368,10 +578,7 @@ impl Console {
         let c = context.console_mut().count_map.entry(label).or_insert(0);
         *c += 1;
 
-        logger(
-            LogMessage::Info(format!("{} {}", msg, c)),
-            context.console(),
-        );
+        logger(LogMessage::Info(format!("{} {}", msg, c)), context)?;
         Ok(Value::undefined())
     }
 
@@ -391,24 +598,16 @@ impl Console {
             None => "default".into(),
         };
 
-        context.console_mut().count_map.remove(&label);
-
-        logger(
-            LogMessage::Warn(format!("countReset {}", label)),
-            context.console(),
-        );
+        if context.console_mut().count_map.remove(&label).is_none() {
+            logger(
+                LogMessage::Warn(format!("Count for '{}' does not exist", label)),
+                context,
+            )?;
+        }
 
         Ok(Value::undefined())
     }
 
-    /// Returns current system time in ms.
-    fn system_time_in_ms() -> u128 {
-        let now = SystemTime::now();
-        now.duration_since(SystemTime::UNIX_EPOCH)
-            .expect("negative duration")
-            .as_millis()
-    }
-
     /// `console.time(label)`
     ///
     /// Starts the timer for given label.
@@ -428,10 +627,10 @@ impl Console {
         if context.console().timer_map.get(&label).is_some() {
             logger(
                 LogMessage::Warn(format!("Timer '{}' already exist", label)),
-                context.console(),
-            );
+                context,
+            )?;
         } else {
-            let time = Self::system_time_in_ms();
+            let time = Self::now(context)?;
             context.console_mut().timer_map.insert(label, time);
         }
 
@@ -454,18 +653,18 @@ impl Console {
             None => "default".into(),
         };
 
-        if let Some(t) = context.console().timer_map.get(&label) {
-            let time = Self::system_time_in_ms();
-            let mut concat = format!("{}: {} ms", label, time - t);
+        if let Some(t) = context.console().timer_map.get(&label).copied() {
+            let time = Self::now(context)?;
+            let mut concat = format!("{}: {} ms", label, time.saturating_sub(t).as_millis());
             for msg in args.iter().skip(1) {
                 concat = concat + " " + &msg.display().to_string();
             }
-            logger(LogMessage::Log(concat), context.console());
+            logger(LogMessage::Log(concat), context)?;
         } else {
             logger(
                 LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
-                context.console(),
-            );
+                context,
+            )?;
         }
 
         Ok(Value::undefined())
@@ -488,16 +687,20 @@ impl Console {
         };
 
         if let Some(t) = context.console_mut().timer_map.remove(label.as_str()) {
-            let time = Self::system_time_in_ms();
+            let time = Self::now(context)?;
             logger(
-                LogMessage::Info(format!("{}: {} ms - timer removed", label, time - t)),
-                context.console(),
-            );
+                LogMessage::Info(format!(
+                    "{}: {} ms - timer removed",
+                    label,
+                    time.saturating_sub(t).as_millis()
+                )),
+                context,
+            )?;
         } else {
             logger(
                 LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
-                context.console(),
-            );
+                context,
+            )?;
         }
 
         Ok(Value::undefined())
@@ -518,8 +721,8 @@ impl Console {
 
         logger(
             LogMessage::Info(format!("group: {}", &group_label)),
-            context.console(),
-        );
+            context,
+        )?;
         context.console_mut().groups.push(group_label);
 
         Ok(Value::undefined())
@@ -554,10 +757,127 @@ impl Console {
     pub(crate) fn dir(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         let undefined = Value::undefined();
         logger(
-            LogMessage::Info(display_obj(args.get(0).unwrap_or(&undefined), true)),
-            context.console(),
-        );
+            LogMessage::Info(
+                args.get(0)
+                    .unwrap_or(&undefined)
+                    .display_obj(INSPECT_DEPTH)
+                    .show_hidden(true)
+                    .to_string(),
+            ),
+            context,
+        )?;
 
         Ok(Value::undefined())
     }
+
+    /// `console.table(tabularData, properties)`
+    ///
+    /// Prints the rows of the tabular data, and the properties of each row, as a table.
+    /// Falls back to `console.log` if the data is not an object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [WHATWG `console` specification][spec]
+    ///
+    /// [spec]: https://console.spec.whatwg.org/#table
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/table
+    pub(crate) fn table(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let data = match args.get(0).and_then(Value::as_object) {
+            Some(data) => data,
+            None => return Self::log(this, args, context),
+        };
+
+        let filter = match args.get(1).and_then(Value::as_object) {
+            Some(properties) => {
+                let mut filter = Vec::new();
+                for key in Self::enumerable_keys(&properties) {
+                    let value = properties.get(&key, properties.clone().into(), context)?;
+                    filter.push(value.to_string(context)?.to_string());
+                }
+                Some(filter)
+            }
+            None => None,
+        };
+
+        let mut columns: Vec<String> = Vec::new();
+        let mut has_values = false;
+        let mut rows = Vec::new();
+        for index in Self::enumerable_keys(&data) {
+            let row = data.get(&index, data.clone().into(), context)?;
+            let mut cells = Vec::new();
+            let mut value = None;
+            match row.as_object() {
+                Some(row) if !row.is_callable() => {
+                    for key in Self::enumerable_keys(&row) {
+                        let name = key.to_string();
+                        if filter.as_ref().map_or(false, |f| !f.contains(&name)) {
+                            continue;
+                        }
+                        let cell = row.get(&key, row.clone().into(), context)?;
+                        if !columns.contains(&name) {
+                            columns.push(name.clone());
+                        }
+                        cells.push((name, cell.display().to_string()));
+                    }
+                }
+                _ => {
+                    has_values = true;
+                    value = Some(row.display().to_string());
+                }
+            }
+            rows.push((index, cells, value));
+        }
+
+        if let Some(filter) = filter {
+            columns = filter;
+        }
+
+        let mut header = vec!["(index)".to_string()];
+        header.extend(columns.iter().cloned());
+        if has_values {
+            header.push("Values".to_string());
+        }
+
+        let rows: Vec<Vec<String>> = rows
+            .into_iter()
+            .map(|(index, cells, value)| {
+                let mut line = vec![index.to_string()];
+                line.extend(columns.iter().map(|column| {
+                    cells
+                        .iter()
+                        .find(|(key, _)| key == column)
+                        .map(|(_, cell)| cell.clone())
+                        .unwrap_or_default()
+                }));
+                if has_values {
+                    line.push(value.unwrap_or_default());
+                }
+                line
+            })
+            .collect();
+
+        logger(LogMessage::Log(render_table(&header, &rows)), context)?;
+
+        Ok(Value::undefined())
+    }
+
+    /// Returns the enumerable own string and index keys of the object, with the index keys first
+    /// and in ascending order.
+    fn enumerable_keys(object: &GcObject) -> Vec<PropertyKey> {
+        let mut keys: Vec<PropertyKey> = object
+            .own_property_keys()
+            .into_iter()
+            .filter(|key| !matches!(key, PropertyKey::Symbol(_)))
+            .filter(|key| {
+                object
+                    .get_own_property(key)
+                    .map_or(false, |desc| desc.enumerable())
+            })
+            .collect();
+        keys.sort_by_key(|key| match *key {
+            PropertyKey::Index(index) => (false, index),
+            _ => (true, 0),
+        });
+        keys
+    }
 }
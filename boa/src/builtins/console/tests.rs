@@ -1,4 +1,13 @@
-use crate::{builtins::console::formatter, Context, Value};
+use crate::{
+    builtins::console::{formatter, ConsoleLogger, LogMessage},
+    forward, Context, Value,
+};
+use std::{
+    cell::{Cell, RefCell},
+    io::{self, Write},
+    rc::Rc,
+    time::Duration,
+};
 
 #[test]
 fn formatter_no_args_is_empty_string() {
@@ -69,3 +78,277 @@ fn formatter_float_format_works() {
     let res = formatter(&val, &mut context).unwrap();
     assert_eq!(res, "3.141500");
 }
+
+/// A writer that can still be inspected after being handed to the context.
+#[derive(Debug, Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct RecordingLogger(Rc<RefCell<Vec<LogMessage>>>);
+
+impl ConsoleLogger for RecordingLogger {
+    fn log(&mut self, msg: LogMessage) {
+        self.0.borrow_mut().push(msg);
+    }
+}
+
+#[test]
+fn console_writer_captures_output() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.log("hello", 42);
+        console.error("oh %s", "no");
+        "#,
+    );
+
+    assert_eq!(buffer.contents(), "hello 42\noh no\n");
+}
+
+#[test]
+fn console_logger_receives_log_levels() {
+    let mut context = Context::new();
+    let logger = RecordingLogger::default();
+    context.set_console_logger(logger.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.log("a");
+        console.info("b");
+        console.warn("c");
+        console.error("d");
+        console.debug("e");
+        "#,
+    );
+
+    assert_eq!(
+        *logger.0.borrow(),
+        vec![
+            LogMessage::Log("a".to_string()),
+            LogMessage::Info("b".to_string()),
+            LogMessage::Warn("c".to_string()),
+            LogMessage::Error("d".to_string()),
+            LogMessage::Debug("e".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn console_count_goes_through_logger() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        "console.count(); console.count('x'); console.count();",
+    );
+
+    assert_eq!(
+        buffer.contents(),
+        "count default: 1\ncount x: 1\ncount default: 2\n"
+    );
+}
+
+#[test]
+fn console_group_indents_messages() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.log("top");
+        console.group("outer");
+        console.log("one\ntwo");
+        console.group("inner");
+        console.log("deep");
+        console.groupEnd();
+        console.groupEnd();
+        console.log("back");
+        "#,
+    );
+
+    assert_eq!(
+        buffer.contents(),
+        "top\ngroup: outer\n  one\n  two\n  group: inner\n    deep\nback\n"
+    );
+}
+
+#[test]
+fn console_count_reset_warns_on_unknown_label() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.count("a");
+        console.countReset("a");
+        console.countReset("a");
+        console.count("a");
+        "#,
+    );
+
+    assert_eq!(
+        buffer.contents(),
+        "count a: 1\nCount for 'a' does not exist\ncount a: 1\n"
+    );
+}
+
+#[test]
+fn console_timers_use_clock_hook() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    let now = Rc::new(Cell::new(Duration::from_millis(0)));
+    context.set_console_writer(buffer.clone());
+    {
+        let now = now.clone();
+        context.set_console_clock(move || now.get());
+    }
+
+    forward(&mut context, "console.time('t')");
+    now.set(Duration::from_millis(15));
+    forward(&mut context, "console.timeLog('t', 'x')");
+    now.set(Duration::from_millis(40));
+    forward(&mut context, "console.timeEnd('t'); console.timeEnd('t')");
+
+    assert_eq!(
+        buffer.contents(),
+        "t: 15 ms \"x\"\nt: 40 ms - timer removed\nTimer 't' doesn't exist\n"
+    );
+}
+
+#[test]
+fn console_timers_clamp_a_clock_going_backwards() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    let now = Rc::new(Cell::new(Duration::from_millis(100)));
+    context.set_console_writer(buffer.clone());
+    {
+        let now = now.clone();
+        context.set_console_clock(move || now.get());
+    }
+
+    forward(&mut context, "console.time('t')");
+    now.set(Duration::from_millis(60));
+    forward(&mut context, "console.timeLog('t'); console.timeEnd('t')");
+
+    assert_eq!(buffer.contents(), "t: 0 ms\nt: 0 ms - timer removed\n");
+}
+
+#[test]
+fn console_assert_only_logs_falsy_conditions() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        r#"
+        console.assert(true, "hidden");
+        console.assert(1, "hidden");
+        console.assert(0, "shown");
+        console.assert(false);
+        "#,
+    );
+
+    assert_eq!(
+        buffer.contents(),
+        "Assertion failed: shown\nAssertion failed\n"
+    );
+}
+
+#[test]
+fn console_table_renders_array_of_objects() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        r#"console.table([{ a: 1 }, { a: 22, b: "x" }, 3])"#,
+    );
+
+    assert_eq!(
+        buffer.contents(),
+        "\
+┌─────────┬────┬─────┬────────┐
+│ (index) │ a  │  b  │ Values │
+├─────────┼────┼─────┼────────┤
+│    0    │ 1  │     │        │
+│    1    │ 22 │ \"x\" │        │
+│    2    │    │     │   3    │
+└─────────┴────┴─────┴────────┘
+"
+    );
+}
+
+#[test]
+fn console_table_filters_columns() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        r#"console.table({ first: { a: 1, b: 2 } }, ["b"])"#,
+    );
+
+    assert_eq!(
+        buffer.contents(),
+        "\
+┌─────────┬───┐
+│ (index) │ b │
+├─────────┼───┤
+│  first  │ 2 │
+└─────────┴───┘
+"
+    );
+}
+
+#[test]
+fn console_log_inspects_objects() {
+    let mut context = Context::new();
+    let buffer = SharedBuffer::default();
+    context.set_console_writer(buffer.clone());
+
+    forward(
+        &mut context,
+        r#"
+        let o = { a: { b: { c: { d: 1 } } } };
+        let cyclic = {};
+        cyclic.me = cyclic;
+        console.log(o, "text");
+        console.log("value: %o", cyclic);
+        "#,
+    );
+
+    assert_eq!(
+        buffer.contents(),
+        "{ a: { b: { c: [Object] } } } text\nvalue: { me: [Circular] }\n"
+    );
+}
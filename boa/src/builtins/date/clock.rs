@@ -0,0 +1,45 @@
+//! The wall clock that `Date` reads the current time from.
+
+#[cfg(feature = "system")]
+use chrono::Utc;
+use std::{fmt, rc::Rc};
+
+/// A wall clock, set with [`Context::set_clock`](crate::Context::set_clock).
+///
+/// The clock returns the number of milliseconds elapsed since the epoch.
+#[derive(Clone)]
+pub(crate) struct Clock(Rc<dyn Fn() -> f64>);
+
+impl Clock {
+    /// Creates a clock calling `clock` for the current time.
+    pub(crate) fn new<F>(clock: F) -> Self
+    where
+        F: Fn() -> f64 + 'static,
+    {
+        Self(Rc::new(clock))
+    }
+
+    /// Returns the clock of the system.
+    #[cfg(feature = "system")]
+    pub(crate) fn system() -> Option<Self> {
+        Some(Self::new(|| Utc::now().timestamp_millis() as f64))
+    }
+
+    /// Returns `None`, as the clock of the system is only available with the `system` feature.
+    #[cfg(not(feature = "system"))]
+    pub(crate) fn system() -> Option<Self> {
+        None
+    }
+
+    /// Returns the current time, in milliseconds since the epoch.
+    #[inline]
+    pub(crate) fn now(&self) -> f64 {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clock").finish()
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod clock;
 #[cfg(test)]
 mod tests;
 
@@ -12,6 +13,18 @@ use crate::{
 use chrono::{prelude::*, Duration, LocalResult};
 use std::fmt::Display;
 
+/// The time zone of local times: the one of the system, or UTC without the `system` feature.
+#[cfg(feature = "system")]
+pub type LocalTimeZone = Local;
+/// The time zone of local times: the one of the system, or UTC without the `system` feature.
+#[cfg(not(feature = "system"))]
+pub type LocalTimeZone = Utc;
+
+#[cfg(feature = "system")]
+const LOCAL: LocalTimeZone = Local;
+#[cfg(not(feature = "system"))]
+const LOCAL: LocalTimeZone = Utc;
+
 /// The number of nanoseconds in a millisecond.
 const NANOS_PER_MS: i64 = 1_000_000;
 /// The number of milliseconds in an hour.
@@ -106,6 +119,7 @@ unsafe impl Trace for Date {
     empty_trace!();
 }
 
+#[cfg(feature = "system")]
 impl Default for Date {
     fn default() -> Self {
         Self(Some(Utc::now().naive_utc()))
@@ -116,79 +130,83 @@ impl BuiltIn for Date {
     const NAME: &'static str = "Date";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let date_object = ConstructorBuilder::new(context, Self::constructor)
-            .name(Self::NAME)
-            .length(Self::LENGTH)
-            .method(getter_method!(get_date), "getDate", 0)
-            .method(getter_method!(get_day), "getDay", 0)
-            .method(getter_method!(get_full_year), "getFullYear", 0)
-            .method(getter_method!(get_hours), "getHours", 0)
-            .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
-            .method(getter_method!(get_minutes), "getMinutes", 0)
-            .method(getter_method!(get_month), "getMonth", 0)
-            .method(getter_method!(get_seconds), "getSeconds", 0)
-            .method(getter_method!(get_time), "getTime", 0)
-            .method(getter_method!(get_year), "getYear", 0)
-            .method(
-                getter_method!(Self::get_timezone_offset),
-                "getTimezoneOffset",
-                0,
-            )
-            .method(getter_method!(get_utc_date), "getUTCDate", 0)
-            .method(getter_method!(get_utc_day), "getUTCDay", 0)
-            .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
-            .method(getter_method!(get_utc_hours), "getUTCHours", 0)
-            .method(
-                getter_method!(get_utc_milliseconds),
-                "getUTCMilliseconds",
-                0,
-            )
-            .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
-            .method(getter_method!(get_utc_month), "getUTCMonth", 0)
-            .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
-            .method(setter_method!(set_date(0)), "setDate", 1)
-            .method(setter_method!(set_full_year(0, 1, 2)), "setFullYear", 1)
-            .method(setter_method!(set_hours(0, 1, 2, 3)), "setHours", 1)
-            .method(setter_method!(set_milliseconds(0)), "setMilliseconds", 1)
-            .method(setter_method!(set_minutes(0, 1, 2)), "setMinutes", 1)
-            .method(setter_method!(set_month(0, 1)), "setMonth", 1)
-            .method(setter_method!(set_seconds(0, 1)), "setSeconds", 1)
-            .method(setter_method!(set_year(0, 1, 2)), "setYear", 1)
-            .method(setter_method!(set_time(0)), "setTime", 1)
-            .method(setter_method!(set_utc_date(0)), "setUTCDate", 1)
-            .method(
-                setter_method!(set_utc_full_year(0, 1, 2)),
-                "setUTCFullYear",
-                1,
-            )
-            .method(setter_method!(set_utc_hours(0, 1, 2, 3)), "setUTCHours", 1)
-            .method(
-                setter_method!(set_utc_milliseconds(0)),
-                "setUTCMilliseconds",
-                1,
-            )
-            .method(setter_method!(set_utc_minutes(0, 1, 2)), "setUTCMinutes", 1)
-            .method(setter_method!(set_utc_month(0, 1)), "setUTCMonth", 1)
-            .method(setter_method!(set_utc_seconds(0, 1)), "setUTCSeconds", 1)
-            .method(getter_method!(to_date_string), "toDateString", 0)
-            .method(getter_method!(to_gmt_string), "toGMTString", 0)
-            .method(getter_method!(to_iso_string), "toISOString", 0)
-            .method(getter_method!(to_json), "toJSON", 0)
-            // Locale strings
-            .method(getter_method!(to_string), "toString", 0)
-            .method(getter_method!(to_time_string), "toTimeString", 0)
-            .method(getter_method!(to_utc_string), "toUTCString", 0)
-            .method(getter_method!(value_of), "valueOf", 0)
-            .static_method(Self::now, "now", 0)
-            .static_method(Self::parse, "parse", 1)
-            .static_method(Self::utc, "UTC", 7)
-            .build();
+        let date_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().date_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(getter_method!(get_date), "getDate", 0)
+        .method(getter_method!(get_day), "getDay", 0)
+        .method(getter_method!(get_full_year), "getFullYear", 0)
+        .method(getter_method!(get_hours), "getHours", 0)
+        .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
+        .method(getter_method!(get_minutes), "getMinutes", 0)
+        .method(getter_method!(get_month), "getMonth", 0)
+        .method(getter_method!(get_seconds), "getSeconds", 0)
+        .method(getter_method!(get_time), "getTime", 0)
+        .method(getter_method!(get_year), "getYear", 0)
+        .method(
+            getter_method!(Self::get_timezone_offset),
+            "getTimezoneOffset",
+            0,
+        )
+        .method(getter_method!(get_utc_date), "getUTCDate", 0)
+        .method(getter_method!(get_utc_day), "getUTCDay", 0)
+        .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
+        .method(getter_method!(get_utc_hours), "getUTCHours", 0)
+        .method(
+            getter_method!(get_utc_milliseconds),
+            "getUTCMilliseconds",
+            0,
+        )
+        .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
+        .method(getter_method!(get_utc_month), "getUTCMonth", 0)
+        .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
+        .method(setter_method!(set_date(0)), "setDate", 1)
+        .method(setter_method!(set_full_year(0, 1, 2)), "setFullYear", 3)
+        .method(setter_method!(set_hours(0, 1, 2, 3)), "setHours", 4)
+        .method(setter_method!(set_milliseconds(0)), "setMilliseconds", 1)
+        .method(setter_method!(set_minutes(0, 1, 2)), "setMinutes", 3)
+        .method(setter_method!(set_month(0, 1)), "setMonth", 2)
+        .method(setter_method!(set_seconds(0, 1)), "setSeconds", 2)
+        .method(setter_method!(set_year(0, 1, 2)), "setYear", 1)
+        .method(setter_method!(set_time(0)), "setTime", 1)
+        .method(setter_method!(set_utc_date(0)), "setUTCDate", 1)
+        .method(
+            setter_method!(set_utc_full_year(0, 1, 2)),
+            "setUTCFullYear",
+            3,
+        )
+        .method(setter_method!(set_utc_hours(0, 1, 2, 3)), "setUTCHours", 4)
+        .method(
+            setter_method!(set_utc_milliseconds(0)),
+            "setUTCMilliseconds",
+            1,
+        )
+        .method(setter_method!(set_utc_minutes(0, 1, 2)), "setUTCMinutes", 3)
+        .method(setter_method!(set_utc_month(0, 1)), "setUTCMonth", 2)
+        .method(setter_method!(set_utc_seconds(0, 1)), "setUTCSeconds", 2)
+        .method(getter_method!(to_date_string), "toDateString", 0)
+        .method(getter_method!(to_gmt_string), "toGMTString", 0)
+        .method(getter_method!(to_iso_string), "toISOString", 0)
+        .method(getter_method!(to_json), "toJSON", 1)
+        // Locale strings
+        .method(getter_method!(to_string), "toString", 0)
+        .method(getter_method!(to_time_string), "toTimeString", 0)
+        .method(getter_method!(to_utc_string), "toUTCString", 0)
+        .method(getter_method!(value_of), "valueOf", 0)
+        .static_method(Self::now, "now", 0)
+        .static_method(Self::parse, "parse", 1)
+        .static_method(Self::utc, "UTC", 7)
+        .build();
 
         (Self::NAME, date_object.into(), Self::attribute())
     }
@@ -214,20 +232,33 @@ impl Date {
         }
     }
 
+    /// Creates a `Date` from a number of milliseconds since the epoch.
+    ///
+    /// The date is invalid if the time is not finite or out of range.
+    fn from_time(time: f64) -> Self {
+        let time = Some(time)
+            .filter(|time| time.is_finite())
+            .and_then(Self::time_clip)
+            .and_then(|time| {
+                let secs = (time / 1_000f64).floor() as i64;
+                let nsecs = (time.rem_euclid(1_000f64) * 1_000_000f64) as u32;
+                NaiveDateTime::from_timestamp_opt(secs, nsecs)
+            });
+        Self(time)
+    }
+
     /// Converts the `Date` to a local `DateTime`.
     ///
     /// If the `Date` is invalid (i.e. NAN), this function will return `None`.
-    pub fn to_local(&self) -> Option<DateTime<Local>> {
-        self.0
-            .map(|utc| Local::now().timezone().from_utc_datetime(&utc))
+    pub fn to_local(&self) -> Option<DateTime<LocalTimeZone>> {
+        self.0.map(|utc| LOCAL.from_utc_datetime(&utc))
     }
 
     /// Converts the `Date` to a UTC `DateTime`.
     ///
     /// If the `Date` is invalid (i.e. NAN), this function will return `None`.
     pub fn to_utc(&self) -> Option<DateTime<Utc>> {
-        self.0
-            .map(|utc| Utc::now().timezone().from_utc_datetime(&utc))
+        self.0.map(|utc| Utc.from_utc_datetime(&utc))
     }
 
     /// Optionally sets the individual components of the `Date`.
@@ -335,7 +366,8 @@ impl Date {
             let duration_hour = Duration::milliseconds(hour.checked_mul(MILLIS_PER_HOUR)?);
             let duration_minute = Duration::milliseconds(minute.checked_mul(MILLIS_PER_MINUTE)?);
             let duration_second = Duration::milliseconds(second.checked_mul(MILLIS_PER_SECOND)?);
-            let duration_milisecond = Duration::milliseconds(millisecond);
+            // `Duration` can't hold `i64::MIN` milliseconds, which is out of range anyway.
+            let duration_milisecond = Duration::milliseconds(millisecond.max(-i64::MAX));
 
             let duration = duration_hour
                 .checked_add(&duration_minute)?
@@ -348,7 +380,7 @@ impl Date {
                     if utc {
                         Some(Utc.from_utc_datetime(&dt).naive_utc())
                     } else {
-                        ignore_ambiguity(Local.from_local_datetime(&dt)).map(|dt| dt.naive_utc())
+                        ignore_ambiguity(LOCAL.from_local_datetime(&dt)).map(|dt| dt.naive_utc())
                     }
                 })
                 .filter(|dt| Self::time_clip(dt.timestamp_millis() as f64).is_some())
@@ -371,7 +403,7 @@ impl Date {
         context: &mut Context,
     ) -> Result<Value> {
         if new_target.is_undefined() {
-            Self::make_date_string()
+            Self::make_date_string(context)
         } else {
             let prototype = new_target
                 .as_object()
@@ -381,12 +413,12 @@ impl Date {
                         .transpose()
                 })
                 .transpose()?
-                .unwrap_or_else(|| context.standard_objects().object_object().prototype());
+                .unwrap_or_else(|| context.standard_objects().date_object().prototype());
             let mut obj = context.construct_object();
             obj.set_prototype_instance(prototype.into());
             let this = obj.into();
             if args.is_empty() {
-                Self::make_date_now(&this)
+                Self::make_date_now(&this, context)
             } else if args.len() == 1 {
                 Self::make_date_single(&this, args, context)
             } else {
@@ -405,8 +437,12 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
-    pub(crate) fn make_date_string() -> Result<Value> {
-        Ok(Value::from(Local::now().to_rfc3339()))
+    pub(crate) fn make_date_string(context: &mut Context) -> Result<Value> {
+        let date = Self::from_time(context.current_time()?);
+        Ok(Value::from(date.to_local().map_or_else(
+            || "Invalid Date".to_string(),
+            |date| date.to_rfc3339(),
+        )))
     }
 
     /// `Date()`
@@ -419,8 +455,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
-    pub(crate) fn make_date_now(this: &Value) -> Result<Value> {
-        let date = Date::default();
+    pub(crate) fn make_date_now(this: &Value, context: &mut Context) -> Result<Value> {
+        let date = Self::from_time(context.current_time()?);
         this.set_data(ObjectData::Date(date));
         Ok(this.clone())
     }
@@ -517,9 +553,11 @@ impl Date {
             year
         };
 
-        let final_date = NaiveDate::from_ymd_opt(year, month + 1, day)
+        let final_date = month
+            .checked_add(1)
+            .and_then(|month| NaiveDate::from_ymd_opt(year, month, day))
             .and_then(|naive_date| naive_date.and_hms_milli_opt(hour, min, sec, milli))
-            .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
+            .and_then(|local| ignore_ambiguity(LOCAL.from_local_datetime(&local)))
             .map(|local| local.naive_utc())
             .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
 
@@ -691,7 +729,10 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTimezoneOffset
     #[inline]
     pub fn get_timezone_offset() -> f64 {
-        let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
+        #[cfg(feature = "system")]
+        let offset_seconds = Local::now().offset().local_minus_utc() as f64;
+        #[cfg(not(feature = "system"))]
+        let offset_seconds = 0f64;
         offset_seconds / 60f64
     }
 
@@ -1020,7 +1061,7 @@ impl Date {
         if let Some(time) = time {
             let secs = (time / 1_000f64) as i64;
             let nsecs = ((time % 1_000f64) * 1_000_000f64) as u32;
-            self.0 = ignore_ambiguity(Local.timestamp_opt(secs, nsecs)).map(|dt| dt.naive_utc());
+            self.0 = ignore_ambiguity(LOCAL.timestamp_opt(secs, nsecs)).map(|dt| dt.naive_utc());
         } else {
             self.0 = None
         }
@@ -1313,8 +1354,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.now
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now
-    pub(crate) fn now(_: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        Ok(Value::from(Utc::now().timestamp_millis() as f64))
+    pub(crate) fn now(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Value::from(context.current_time()?))
     }
 
     /// `Date.parse()`
@@ -1394,7 +1435,9 @@ impl Date {
             year
         };
 
-        NaiveDate::from_ymd_opt(year, month + 1, day)
+        month
+            .checked_add(1)
+            .and_then(|month| NaiveDate::from_ymd_opt(year, month, day))
             .and_then(|f| f.and_hms_milli_opt(hour, min, sec, milli))
             .and_then(|f| Self::time_clip(f.timestamp_millis() as f64))
             .map_or(Ok(Value::number(f64::NAN)), |time| Ok(Value::number(time)))
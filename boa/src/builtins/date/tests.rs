@@ -1378,3 +1378,26 @@ fn date_json() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn date_set_clock() {
+    let mut context = Context::new();
+    // 2020-07-08T09:16:15.779Z
+    context.set_clock(|| 1_594_199_775_779.0);
+
+    assert_eq!(forward(&mut context, "Date.now()"), "1594199775779");
+    assert_eq!(
+        forward(&mut context, "new Date().getTime()"),
+        "1594199775779"
+    );
+    assert_eq!(
+        forward(&mut context, "new Date().toISOString()"),
+        "\"2020-07-08T09:16:15.779Z\""
+    );
+
+    context.set_clock(|| f64::NAN);
+    assert_eq!(
+        forward(&mut context, "String(new Date())"),
+        "\"Invalid Date\""
+    );
+}
@@ -0,0 +1,98 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! The `AggregateError` object represents an error when several errors
+//! need to be wrapped in a single error.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{
+        error::{create_error_object, inherit_error_constructor},
+        iterable, Array, BuiltIn,
+    },
+    object::ConstructorBuilder,
+    profiler::BoaProfiler,
+    property::{Attribute, DataDescriptor},
+    Context, Result, Value,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    fn attribute() -> Attribute {
+        Attribute::BUILTIN
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::BUILTIN;
+        let mut aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype.into())
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+        inherit_error_constructor(&mut aggregate_error_object, context);
+
+        (Self::NAME, aggregate_error_object.into(), Self::attribute())
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `AggregateError( errors, message [ , options ] )`
+    ///
+    /// Create a new error object holding the values of the `errors` iterable.
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let prototype = context
+            .standard_objects()
+            .aggregate_error_object()
+            .prototype();
+        let error =
+            create_error_object(new_target, prototype, args.get(1..).unwrap_or(&[]), context)?;
+
+        let iterator = iterable::get_iterator(
+            context,
+            args.get(0).cloned().unwrap_or_else(Value::undefined),
+        )?;
+        let mut errors = Vec::new();
+        loop {
+            let next = iterator.next(context)?;
+            if next.is_done() {
+                break;
+            }
+            errors.push(next.value());
+        }
+
+        let errors_array = Array::new_array(context)?;
+        let errors_array = Array::construct_array(&errors_array, &errors, context)?;
+        error.set_property(
+            "errors",
+            DataDescriptor::new(errors_array, Attribute::BUILTIN),
+        );
+
+        Ok(error)
+    }
+}
@@ -11,10 +11,12 @@
 //! [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-evalerror
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/EvalError
 
-use crate::object::PROTOTYPE;
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData},
+    builtins::{
+        error::{create_error_object, inherit_error_constructor},
+        BuiltIn,
+    },
+    object::ConstructorBuilder,
     profiler::BoaProfiler,
     property::Attribute,
     Context, Result, Value,
@@ -28,15 +30,15 @@ impl BuiltIn for EvalError {
     const NAME: &'static str = "EvalError";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         let error_prototype = context.standard_objects().error_object().prototype();
-        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
-        let eval_error_object = ConstructorBuilder::with_standard_object(
+        let attribute = Attribute::BUILTIN;
+        let mut eval_error_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
             context.standard_objects().eval_error_object().clone(),
@@ -47,6 +49,7 @@ impl BuiltIn for EvalError {
         .property("name", Self::NAME, attribute)
         .property("message", "", attribute)
         .build();
+        inherit_error_constructor(&mut eval_error_object, context);
 
         (Self::NAME, eval_error_object.into(), Self::attribute())
     }
@@ -62,27 +65,7 @@ impl EvalError {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let prototype = new_target
-            .as_object()
-            .and_then(|obj| {
-                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
-                    .map(|o| o.as_object())
-                    .transpose()
-            })
-            .transpose()?
-            .unwrap_or_else(|| context.standard_objects().error_object().prototype());
-        let mut obj = context.construct_object();
-        obj.set_prototype_instance(prototype.into());
-        let this = Value::from(obj);
-        if let Some(message) = args.get(0) {
-            if !message.is_undefined() {
-                this.set_field("message", message.to_string(context)?, context)?;
-            }
-        }
-
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
-        Ok(this)
+        let prototype = context.standard_objects().eval_error_object().prototype();
+        create_error_object(new_target, prototype, args, context)
     }
 }
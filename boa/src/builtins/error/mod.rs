@@ -12,12 +12,13 @@
 
 use crate::{
     builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, GcObject, ObjectData, PROTOTYPE},
     profiler::BoaProfiler,
     property::Attribute,
     Context, Result, Value,
 };
 
+pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -28,6 +29,7 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -43,13 +45,13 @@ impl BuiltIn for Error {
     const NAME: &'static str = "Error";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let attribute = Attribute::BUILTIN;
         let error_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -70,7 +72,7 @@ impl Error {
     /// The amount of arguments this function object takes.
     pub(crate) const LENGTH: usize = 1;
 
-    /// `Error( message )`
+    /// `Error( message [ , options ] )`
     ///
     /// Create a new error object.
     pub(crate) fn constructor(
@@ -78,28 +80,8 @@ impl Error {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let prototype = new_target
-            .as_object()
-            .and_then(|obj| {
-                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
-                    .map(|o| o.as_object())
-                    .transpose()
-            })
-            .transpose()?
-            .unwrap_or_else(|| context.standard_objects().error_object().prototype());
-        let mut obj = context.construct_object();
-        obj.set_prototype_instance(prototype.into());
-        let this = Value::from(obj);
-        if let Some(message) = args.get(0) {
-            if !message.is_undefined() {
-                this.set_field("message", message.to_string(context)?, context)?;
-            }
-        }
-
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
-        Ok(this)
+        let prototype = context.standard_objects().error_object().prototype();
+        create_error_object(new_target, prototype, args, context)
     }
 
     /// `Error.prototype.toString()`
@@ -144,3 +126,76 @@ impl Error {
         }
     }
 }
+
+/// Create the object of an error constructor, with the `prototype` of `new_target` or the given
+/// default `prototype`, and install its `message` and `cause` from the first two arguments.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-nativeerror
+pub(crate) fn create_error_object(
+    new_target: &Value,
+    prototype: GcObject,
+    args: &[Value],
+    context: &mut Context,
+) -> Result<Value> {
+    let prototype = new_target
+        .as_object()
+        .and_then(|obj| {
+            obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                .map(|o| o.as_object())
+                .transpose()
+        })
+        .transpose()?
+        .unwrap_or(prototype);
+    let mut obj = context.construct_object();
+    obj.set_prototype_instance(prototype.into());
+
+    let attribute = Attribute::BUILTIN;
+    if let Some(message) = args.get(0) {
+        if !message.is_undefined() {
+            obj.insert_property("message", message.to_string(context)?, attribute);
+        }
+    }
+    install_error_cause(&mut obj, args.get(1), context)?;
+
+    // This value is used by console.log and other routines to match Object type
+    // to its Javascript Identifier (global constructor method name)
+    obj.borrow_mut().data = ObjectData::Error;
+    Ok(obj.into())
+}
+
+/// Make the object of a native error constructor inherit from the `Error` constructor, which is
+/// the `[[Prototype]]` of every native error constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-the-nativeerror-constructors
+pub(crate) fn inherit_error_constructor(constructor: &mut GcObject, context: &Context) {
+    let error_constructor = context.standard_objects().error_object().constructor();
+    constructor.set_prototype_instance(error_constructor.into());
+}
+
+/// Define the `cause` own property of an error object if `options` has one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-installerrorcause
+fn install_error_cause(
+    obj: &mut GcObject,
+    options: Option<&Value>,
+    context: &mut Context,
+) -> Result<()> {
+    if let Some(options) = options.and_then(Value::as_object) {
+        let cause_key = "cause".into();
+        if options.has_property(&cause_key) {
+            let cause = options.get(&cause_key, options.clone().into(), context)?;
+            obj.insert_property("cause", cause, Attribute::BUILTIN);
+        }
+    }
+
+    Ok(())
+}
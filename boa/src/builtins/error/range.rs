@@ -10,8 +10,11 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError
 
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    builtins::{
+        error::{create_error_object, inherit_error_constructor},
+        BuiltIn,
+    },
+    object::ConstructorBuilder,
     profiler::BoaProfiler,
     property::Attribute,
     Context, Result, Value,
@@ -25,15 +28,15 @@ impl BuiltIn for RangeError {
     const NAME: &'static str = "RangeError";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         let error_prototype = context.standard_objects().error_object().prototype();
-        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
-        let range_error_object = ConstructorBuilder::with_standard_object(
+        let attribute = Attribute::BUILTIN;
+        let mut range_error_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
             context.standard_objects().range_error_object().clone(),
@@ -44,6 +47,7 @@ impl BuiltIn for RangeError {
         .property("name", Self::NAME, attribute)
         .property("message", "", attribute)
         .build();
+        inherit_error_constructor(&mut range_error_object, context);
 
         (Self::NAME, range_error_object.into(), Self::attribute())
     }
@@ -59,27 +63,7 @@ impl RangeError {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let prototype = new_target
-            .as_object()
-            .and_then(|obj| {
-                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
-                    .map(|o| o.as_object())
-                    .transpose()
-            })
-            .transpose()?
-            .unwrap_or_else(|| context.standard_objects().error_object().prototype());
-        let mut obj = context.construct_object();
-        obj.set_prototype_instance(prototype.into());
-        let this = Value::from(obj);
-        if let Some(message) = args.get(0) {
-            if !message.is_undefined() {
-                this.set_field("message", message.to_string(context)?, context)?;
-            }
-        }
-
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
-        Ok(this)
+        let prototype = context.standard_objects().range_error_object().prototype();
+        create_error_object(new_target, prototype, args, context)
     }
 }
@@ -84,3 +84,118 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn error_cause() {
+    let mut context = Context::new();
+    let init = r#"
+        let inner = new TypeError('inner');
+        let with_cause = new Error('outer', { cause: inner });
+        let undefined_cause = new RangeError('outer', { cause: undefined });
+        let without_cause = new Error('outer', {});
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "with_cause.cause === inner"), "true");
+    assert_eq!(
+        forward(&mut context, "with_cause.propertyIsEnumerable('cause')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "undefined_cause.hasOwnProperty('cause')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "without_cause.hasOwnProperty('cause')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "new Error('m', 1).hasOwnProperty('cause')"),
+        "false"
+    );
+}
+
+#[test]
+fn error_message_property() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Error('m').propertyIsEnumerable('message')"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "new Error().hasOwnProperty('message')"),
+        "false"
+    );
+}
+
+#[test]
+fn native_error_prototypes() {
+    let mut context = Context::new();
+    let init = r#"
+        let e = new TypeError('msg');
+        let called = TypeError('msg');
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "e instanceof TypeError"), "true");
+    assert_eq!(forward(&mut context, "e instanceof Error"), "true");
+    assert_eq!(forward(&mut context, "e instanceof RangeError"), "false");
+    assert_eq!(forward(&mut context, "called instanceof TypeError"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(TypeError.prototype) === Error.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(URIError) === Error"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "TypeError.prototype.hasOwnProperty('name')"),
+        "true"
+    );
+}
+
+#[test]
+fn error_subclass_name() {
+    let mut context = Context::new();
+    let init = r#"
+        function MyError(message) {
+            this.message = message;
+        }
+        MyError.prototype = Object.create(Error.prototype);
+        MyError.prototype.name = 'MyError';
+        let e = new MyError('custom');
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "e instanceof Error"), "true");
+    assert_eq!(forward(&mut context, "e.toString()"), "\"MyError: custom\"");
+}
+
+#[test]
+fn aggregate_error() {
+    let mut context = Context::new();
+    let init = r#"
+        let first = new TypeError('first');
+        let e = new AggregateError([first, 'second'], 'many', { cause: 'reason' });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "AggregateError.length"), "2");
+    assert_eq!(forward(&mut context, "e instanceof AggregateError"), "true");
+    assert_eq!(forward(&mut context, "e instanceof Error"), "true");
+    assert_eq!(forward(&mut context, "e.errors.length"), "2");
+    assert_eq!(forward(&mut context, "e.errors[0] === first"), "true");
+    assert_eq!(forward(&mut context, "e.errors[1]"), "\"second\"");
+    assert_eq!(forward(&mut context, "e.cause"), "\"reason\"");
+    assert_eq!(
+        forward(&mut context, "e.toString()"),
+        "\"AggregateError: many\""
+    );
+    assert_eq!(
+        forward(&mut context, "new AggregateError(1)"),
+        "Uncaught \"TypeError\": \"Not an iterable\""
+    );
+}
@@ -0,0 +1,242 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A `FinalizationRegistry` calls a cleanup callback, as a job of the [`Context`], after the
+//! objects registered with it have been garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, GcObject, Trace, WeakGcObject},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    snapshot::HeapCopier,
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A registration of a target object in a `FinalizationRegistry`.
+#[derive(Debug, Trace, Finalize)]
+struct Registration {
+    target: WeakGcObject,
+    held_value: Value,
+    unregister_token: Option<WeakGcObject>,
+}
+
+/// The internal state of a `FinalizationRegistry` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    cleanup: GcObject,
+    registrations: Vec<Registration>,
+}
+
+impl BuiltIn for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    fn attribute() -> Attribute {
+        Attribute::BUILTIN
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let finalization_registry_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context
+                .standard_objects()
+                .finalization_registry_object()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::register, "register", 2)
+        .method(Self::unregister, "unregister", 1)
+        .callable(false)
+        .build();
+
+        (
+            Self::NAME,
+            finalization_registry_object.into(),
+            Self::attribute(),
+        )
+    }
+}
+
+impl FinalizationRegistry {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Removes the registrations whose target has been collected, returning the calls to the
+    /// cleanup callback that have to be scheduled for them.
+    pub(crate) fn take_cleanup_jobs(&mut self) -> Vec<(GcObject, Value)> {
+        let mut jobs = Vec::new();
+        let cleanup = &self.cleanup;
+        self.registrations.retain(|registration| {
+            if registration.target.is_collected() {
+                jobs.push((cleanup.clone(), registration.held_value.clone()));
+                false
+            } else {
+                true
+            }
+        });
+        jobs
+    }
+
+    /// Copies the registry for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        let registrations = self
+            .registrations
+            .iter()
+            .map(|registration| Registration {
+                target: copier.copy_weak(&registration.target),
+                held_value: copier.copy_value(&registration.held_value),
+                unregister_token: registration
+                    .unregister_token
+                    .as_ref()
+                    .map(|token| copier.copy_weak(token)),
+            })
+            .collect();
+
+        Self {
+            cleanup: copier.copy_object(&self.cleanup),
+            registrations,
+        }
+    }
+
+    /// `FinalizationRegistry( cleanupCallback )`
+    ///
+    /// Creates a new registry calling the given callback after its registered objects are
+    /// collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error("FinalizationRegistry requires new");
+        }
+
+        let cleanup = match args.get(0).and_then(Value::as_object) {
+            Some(cleanup) if cleanup.is_callable() => cleanup,
+            _ => return context.throw_type_error("FinalizationRegistry: cleanup must be callable"),
+        };
+
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .finalization_registry_object()
+                    .prototype()
+            });
+
+        let mut obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        obj.borrow_mut().data = ObjectData::FinalizationRegistry(Self {
+            cleanup,
+            registrations: Vec::new(),
+        });
+        context.track_finalization_registry(&obj);
+
+        Ok(obj.into())
+    }
+
+    /// `FinalizationRegistry.prototype.register( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// Registers an object, the held value is passed to the cleanup callback after the object
+    /// has been collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = match args.get(0).and_then(Value::as_object) {
+            Some(target) => target,
+            None => return context.throw_type_error("register: target must be an object"),
+        };
+        let held_value = args.get(1).cloned().unwrap_or_default();
+        if same_value(&target.clone().into(), &held_value) {
+            return context
+                .throw_type_error("register: target and held value must not be the same");
+        }
+        let unregister_token = match args.get(2) {
+            None | Some(Value::Undefined) => None,
+            Some(Value::Object(ref token)) => Some(WeakGcObject::new(token)),
+            Some(_) => {
+                return context.throw_type_error("register: unregister token must be an object")
+            }
+        };
+
+        let target = WeakGcObject::new(&target);
+
+        if let Value::Object(ref object) = this {
+            if let ObjectData::FinalizationRegistry(ref mut registry) = object.borrow_mut().data {
+                registry.registrations.push(Registration {
+                    target,
+                    held_value,
+                    unregister_token,
+                });
+                return Ok(Value::undefined());
+            }
+        }
+
+        context.throw_type_error("'this' is not a FinalizationRegistry")
+    }
+
+    /// `FinalizationRegistry.prototype.unregister( unregisterToken )`
+    ///
+    /// Removes the registrations made with the given token, returning whether there were any.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let token = match args.get(0).and_then(Value::as_object) {
+            Some(token) => token,
+            None => return context.throw_type_error("unregister: token must be an object"),
+        };
+
+        if let Value::Object(ref object) = this {
+            if let ObjectData::FinalizationRegistry(ref mut registry) = object.borrow_mut().data {
+                let registered = registry.registrations.len();
+                registry.registrations.retain(|registration| {
+                    !registration
+                        .unregister_token
+                        .as_ref()
+                        .map_or(false, |registered| registered.is(&token))
+                });
+                return Ok((registry.registrations.len() != registered).into());
+            }
+        }
+
+        context.throw_type_error("'this' is not a FinalizationRegistry")
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{forward, Context};
+
+#[test]
+fn cleanup_runs_after_collection() {
+    let mut context = Context::new();
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry(held => cleaned.push(held));
+        var kept = {};
+        registry.register({}, "collected");
+        registry.register(kept, "kept");
+        "#;
+    forward(&mut context, init);
+
+    context.force_gc();
+    assert_eq!(forward(&mut context, "cleaned.length"), "0");
+
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned.join()"), "\"collected\"");
+}
+
+#[test]
+fn unregister() {
+    let mut context = Context::new();
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry(held => cleaned.push(held));
+        var token = {};
+        registry.register({}, "unregistered", token);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "false");
+
+    context.force_gc();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned.length"), "0");
+}
+
+#[test]
+fn register_errors() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "var registry = new FinalizationRegistry(() => {});",
+    );
+
+    assert_eq!(
+        forward(&mut context, "registry.register(1, 'held')"),
+        "Uncaught \"TypeError\": \"register: target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut context, "var o = {}; registry.register(o, o)"),
+        "Uncaught \"TypeError\": \"register: target and held value must not be the same\""
+    );
+    assert_eq!(
+        forward(&mut context, "new FinalizationRegistry(1)"),
+        "Uncaught \"TypeError\": \"FinalizationRegistry: cleanup must be callable\""
+    );
+}
@@ -14,11 +14,16 @@
 use crate::object::PROTOTYPE;
 use crate::{
     builtins::{Array, BuiltIn},
+    context::SourceText,
     environment::lexical_environment::Environment,
     gc::{empty_trace, Finalize, Trace},
     object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
     property::{Attribute, DataDescriptor},
-    syntax::ast::node::{FormalParameter, RcStatementList},
+    snapshot::HeapCopier,
+    syntax::ast::{
+        node::{FormalParameter, RcStatementList},
+        Span,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use bitflags::bitflags;
@@ -55,6 +60,7 @@ bitflags! {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const STRICT = 0b0000_1000;
     }
 }
 
@@ -86,12 +92,52 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_strict(&self) -> bool {
+        self.contains(Self::STRICT)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
     empty_trace!();
 }
 
+/// The source code of an ordinary function, which is a span of the script that defines it.
+///
+/// The source text of the script is shared by all of its functions.
+#[derive(Debug, Clone, Finalize)]
+pub struct FunctionSource {
+    script: SourceText,
+    span: Span,
+}
+
+impl FunctionSource {
+    /// Creates the source of a function defined at `span` in `script`.
+    pub(crate) fn new(script: SourceText, span: Span) -> Self {
+        Self { script, span }
+    }
+
+    /// Gets the source code of the script that defines the function.
+    pub(crate) fn script(&self) -> &SourceText {
+        &self.script
+    }
+
+    /// Gets the span of the function in its script.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the source text of the function, exactly as it is written in its script.
+    pub fn text(&self) -> Option<&str> {
+        self.span.slice(self.script.text())
+    }
+}
+
+unsafe impl Trace for FunctionSource {
+    empty_trace!();
+}
+
 /// Boa representation of a Function Object.
 ///
 /// FunctionBody is specific to this interpreter, it will either be Rust code or JavaScript code (AST Node)
@@ -105,10 +151,48 @@ pub enum Function {
         body: RcStatementList,
         params: Box<[FormalParameter]>,
         environment: Environment,
+        home_object: Option<GcObject>,
+        source: Option<FunctionSource>,
     },
 }
 
 impl Function {
+    /// Copies the function for a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// The body of ordinary functions is shared, only their environment is copied.
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        match self {
+            Self::BuiltIn(..) => self.clone(),
+            Self::Ordinary {
+                flags,
+                body,
+                params,
+                environment,
+                home_object,
+                source,
+            } => Self::Ordinary {
+                flags: *flags,
+                body: body.clone(),
+                params: params.clone(),
+                environment: copier.copy_environment(environment),
+                home_object: home_object
+                    .as_ref()
+                    .map(|object| copier.copy_object(object)),
+                source: source.clone(),
+            },
+        }
+    }
+
+    /// Sets the `[[HomeObject]]` of a method, the object whose prototype its `super` property
+    /// references are looked up on.
+    ///
+    /// Built-in functions have no home object, so this does nothing for them.
+    pub(crate) fn set_home_object(&mut self, object: GcObject) {
+        if let Self::Ordinary { home_object, .. } = self {
+            *home_object = Some(object);
+        }
+    }
+
     // Adds the final rest parameters to the Environment as an array
     pub(crate) fn add_rest_param(
         &self,
@@ -118,9 +202,10 @@ impl Function {
         context: &mut Context,
         local_env: &Environment,
     ) {
-        // Create array of values
+        // Create array of values, which is empty if there are fewer arguments than parameters
         let array = Array::new_array(context).unwrap();
-        Array::add_to_array_object(&array, &args_list[index..], context).unwrap();
+        let rest = args_list.get(index..).unwrap_or_default();
+        Array::add_to_array_object(&array, rest, context).unwrap();
 
         // Create binding
         local_env
@@ -239,15 +324,13 @@ pub fn make_builtin_fn<N>(
             .prototype()
             .into(),
     );
-    let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+    let attribute = Attribute::BUILTIN_READONLY;
     function.insert_property("length", length, attribute);
     function.insert_property("name", name.as_str(), attribute);
 
-    parent.clone().insert_property(
-        name,
-        function,
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-    );
+    parent
+        .clone()
+        .insert_property(name, function, Attribute::BUILTIN);
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -330,13 +413,49 @@ impl BuiltInFunctionObject {
         // TODO?: 5. PrepareForTailCall
         context.call(this, &this_arg, &arg_list)
     }
+
+    /// `Function.prototype.toString()`
+    ///
+    /// The toString() method returns the source code of the function. Built-in functions, and
+    /// the functions whose source is unknown, are represented with a `[native code]` body.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
+    fn to_string(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let object = match this.as_object() {
+            Some(object) if object.is_callable() => object,
+            _ => return context.throw_type_error(format!("{} is not a function", this.display())),
+        };
+
+        if let Some(Function::Ordinary {
+            source: Some(source),
+            ..
+        }) = object.borrow().as_function()
+        {
+            if let Some(text) = source.text() {
+                return Ok(text.into());
+            }
+        }
+
+        // The name is read from the own data property, so that no getter runs.
+        let name = object
+            .get_own_property(&"name".into())
+            .and_then(|property| property.as_data_descriptor().map(|data| data.value()))
+            .and_then(|name| name.as_string().map(|name| name.to_string()))
+            .unwrap_or_default();
+        Ok(format!("function {}() {{ [native code] }}", name).into())
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
     const NAME: &'static str = "Function";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -358,7 +477,8 @@ impl BuiltIn for BuiltInFunctionObject {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .method(Self::call, "call", 1)
-        .method(Self::apply, "apply", 1)
+        .method(Self::apply, "apply", 2)
+        .method(Self::to_string, "toString", 0)
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
@@ -212,3 +212,181 @@ fn function_prototype_apply_on_object() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_names() {
+    let mut context = Context::new();
+    let init = r#"
+        function declared() {}
+        var expression = function () {};
+        var named = function inner() {};
+        let arrow = () => {};
+        const constant = (a) => a;
+        var assigned;
+        assigned = function () {};
+        var object = {
+            property: function () {},
+            arrow: () => {},
+            method() {},
+            get accessor() { return 1; },
+            set accessor(value) {},
+        };
+        var accessor = Object.getOwnPropertyDescriptor(object, "accessor");
+    "#;
+    forward(&mut context, init);
+
+    let names = [
+        ("declared.name", "\"declared\""),
+        ("expression.name", "\"expression\""),
+        ("named.name", "\"inner\""),
+        ("arrow.name", "\"arrow\""),
+        ("constant.name", "\"constant\""),
+        ("assigned.name", "\"assigned\""),
+        ("object.property.name", "\"property\""),
+        ("object.arrow.name", "\"arrow\""),
+        ("object.method.name", "\"method\""),
+        ("accessor.get.name", "\"get accessor\""),
+        ("accessor.set.name", "\"set accessor\""),
+        ("(function () {}).name", "\"\""),
+        ("(() => {}).hasOwnProperty('name')", "true"),
+        ("[function () {}][0].name", "\"\""),
+    ];
+    for (expression, name) in names.iter() {
+        assert_eq!(&forward(&mut context, expression), name, "{}", expression);
+    }
+
+    let descriptor = r#"
+        var descriptor = Object.getOwnPropertyDescriptor(declared, "name");
+        [descriptor.writable, descriptor.enumerable, descriptor.configurable].join()
+    "#;
+    assert_eq!(forward(&mut context, descriptor), "\"false,false,true\"");
+}
+
+#[test]
+fn function_lengths() {
+    let mut context = Context::new();
+
+    let lengths = [
+        ("(function () {}).length", "0"),
+        ("(function (a, b) {}).length", "2"),
+        ("((a, b, c) => {}).length", "3"),
+        ("(function (a, b = 1, c) {}).length", "1"),
+        ("(function (a, ...rest) {}).length", "1"),
+        (
+            "Object.getOwnPropertyDescriptor({ set x(value) {} }, 'x').set.length",
+            "1",
+        ),
+    ];
+    for (expression, length) in lengths.iter() {
+        assert_eq!(&forward(&mut context, expression), length, "{}", expression);
+    }
+}
+
+#[test]
+fn builtin_function_names_and_lengths() {
+    let mut context = Context::new();
+
+    let builtins = [
+        ("Array.prototype.reduce", "reduce", 1),
+        ("Array.prototype.some", "some", 1),
+        ("Function.prototype.apply", "apply", 2),
+        ("Math.hypot", "hypot", 2),
+        ("Math.max", "max", 2),
+        ("Date.prototype.setHours", "setHours", 4),
+        ("Date.prototype.toJSON", "toJSON", 1),
+        ("Map", "Map", 0),
+        ("parseInt", "parseInt", 2),
+        ("structuredClone", "structuredClone", 1),
+        ("String.prototype[Symbol.iterator]", "[Symbol.iterator]", 0),
+    ];
+    for (builtin, name, length) in builtins.iter() {
+        assert_eq!(
+            forward(&mut context, &format!("{}.name", builtin)),
+            format!("\"{}\"", name),
+            "{}",
+            builtin
+        );
+        assert_eq!(
+            forward(&mut context, &format!("{}.length", builtin)),
+            length.to_string(),
+            "{}",
+            builtin
+        );
+    }
+
+    let descriptor = r#"
+        var descriptor = Object.getOwnPropertyDescriptor(Function.prototype, "name");
+        [descriptor.writable, descriptor.enumerable, descriptor.configurable].join()
+    "#;
+    assert_eq!(forward(&mut context, descriptor), "\"false,false,true\"");
+}
+
+#[test]
+fn function_to_string() {
+    let mut context = Context::new();
+
+    let source = "var a = 1;\r\nfunction add(x, /* y */ y) {\n  // é\n  return x + y;\n}\n\
+                  const double = (x) =>  x * 2 ;\n\
+                  var object = { method(a) { return a; }, get value() { return 1; } };\n\
+                  var named = function name () {};";
+    context.eval(source).unwrap();
+
+    let texts = [
+        (
+            "add",
+            "function add(x, /* y */ y) {\n  // é\n  return x + y;\n}",
+        ),
+        ("double", "(x) =>  x * 2"),
+        ("object.method", "method(a) { return a; }"),
+        (
+            "Object.getOwnPropertyDescriptor(object, 'value').get",
+            "get value() { return 1; }",
+        ),
+        ("named", "function name () {}"),
+    ];
+    for (function, text) in texts.iter() {
+        let value = context.eval(format!("{}.toString()", function)).unwrap();
+        assert_eq!(
+            value.as_string().map(|string| string.as_str()),
+            Some(*text),
+            "{}",
+            function
+        );
+    }
+}
+
+#[test]
+fn function_to_string_round_trip() {
+    let mut context = Context::new();
+
+    context
+        .eval("function add(a, b) { return a + b; }; var double = x => x * 2;")
+        .unwrap();
+    for (function, call, result) in [("add", "(1, 2)", 3), ("double", "(4)", 8)].iter() {
+        let text = context.eval(format!("{}.toString()", function)).unwrap();
+        let text = text.as_string().unwrap();
+        let value = context.eval(format!("({}){}", text, call)).unwrap();
+        assert_eq!(value.as_number(), Some(f64::from(*result)), "{}", function);
+    }
+}
+
+#[test]
+fn builtin_function_to_string() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Math.max.toString()"),
+        "\"function max() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut context, "Function.prototype.toString.call(Array)"),
+        "\"function Array() { [native code] }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function.prototype.toString.call({}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
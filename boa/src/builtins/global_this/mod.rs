@@ -22,7 +22,7 @@ impl BuiltIn for GlobalThis {
     const NAME: &'static str = "globalThis";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
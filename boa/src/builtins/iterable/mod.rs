@@ -5,6 +5,7 @@ use crate::{
     builtins::MapIterator,
     object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor},
+    snapshot::HeapCopier,
     BoaProfiler, Context, Result, Value,
 };
 
@@ -38,6 +39,17 @@ impl IteratorPrototypes {
         }
     }
 
+    /// Copies the prototypes for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            iterator_prototype: copier.copy_object(&self.iterator_prototype),
+            array_iterator: copier.copy_object(&self.array_iterator),
+            string_iterator: copier.copy_object(&self.string_iterator),
+            map_iterator: copier.copy_object(&self.map_iterator),
+            for_in_iterator: copier.copy_object(&self.for_in_iterator),
+        }
+    }
+
     #[inline]
     pub fn array_iterator(&self) -> GcObject {
         self.array_iterator.clone()
@@ -79,8 +91,15 @@ pub fn create_iter_result_object(context: &mut Context, value: Value, done: bool
 
 /// Get an iterator record
 pub fn get_iterator(context: &mut Context, iterable: Value) -> Result<IteratorRecord> {
-    let iterator_function =
-        iterable.get_field(context.well_known_symbols().iterator_symbol(), context)?;
+    if iterable.is_null_or_undefined() {
+        return Err(context.construct_type_error("Not an iterable"));
+    }
+    // Primitives, like strings, are iterated through the methods of their wrapper object.
+    let iterator_function = iterable.to_object(context)?.get(
+        &context.well_known_symbols().iterator_symbol().into(),
+        iterable.clone(),
+        context,
+    )?;
     if iterator_function.is_null_or_undefined() {
         return Err(context.construct_type_error("Not an iterable"));
     }
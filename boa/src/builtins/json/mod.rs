@@ -35,15 +35,17 @@ impl BuiltIn for Json {
     const NAME: &'static str = "JSON";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let string_tag = context.well_known_symbols().to_string_tag_symbol();
         let json_object = ObjectInitializer::new(context)
             .function(Self::parse, "parse", 2)
             .function(Self::stringify, "stringify", 3)
+            .property(string_tag, Self::NAME, Attribute::BUILTIN_READONLY)
             .build();
 
         (Self::NAME, json_object.into(), Self::attribute())
@@ -165,7 +167,16 @@ impl Json {
                 };
                 Value::from(&SPACE_INDENT[..space_mv])
             } else if let Some(string) = space.as_string() {
-                Value::from(&string[..std::cmp::min(string.len(), 10)])
+                // The gap is made of the first 10 code units of the string.
+                let mut code_units = 0;
+                let gap: String = string
+                    .chars()
+                    .take_while(|c| {
+                        code_units += c.len_utf16();
+                        code_units <= 10
+                    })
+                    .collect();
+                Value::from(gap)
             } else {
                 Value::from("")
             }
@@ -177,12 +188,7 @@ impl Json {
 
         let replacer = match args.get(1) {
             Some(replacer) if replacer.is_object() => replacer,
-            _ => {
-                return Ok(Value::from(json_to_pretty_string(
-                    &object.to_json(context)?,
-                    gap,
-                )))
-            }
+            _ => return Ok(json_to_value(object.to_json_property(context)?, gap)),
         };
 
         let replacer_as_object = replacer
@@ -193,7 +199,9 @@ impl Json {
                 .as_object()
                 .map(|obj| {
                     let object_to_return = Value::object(Object::default());
-                    for key in obj.borrow().keys() {
+                    // The keys are collected first, the replacer may modify the object.
+                    let keys: Vec<_> = obj.borrow().keys().collect();
+                    for key in keys {
                         let val = obj.get(&key, obj.clone().into(), context)?;
                         let this_arg = object.clone();
                         object_to_return.set_property(
@@ -208,33 +216,36 @@ impl Json {
                             ),
                         );
                     }
-                    Ok(Value::from(json_to_pretty_string(
-                        &object_to_return.to_json(context)?,
+                    Ok(json_to_value(
+                        object_to_return.to_json_property(context)?,
                         gap,
-                    )))
+                    ))
                 })
                 .ok_or_else(Value::undefined)?
         } else if replacer_as_object.is_array() {
             let mut obj_to_return = serde_json::Map::new();
-            let replacer_as_object = replacer_as_object.borrow();
-            let fields = replacer_as_object.keys().filter_map(|key| {
-                if key == "length" {
-                    None
-                } else {
-                    Some(
-                        replacer
-                            .get_property(key)
-                            .as_ref()
-                            .and_then(|p| p.as_data_descriptor())
-                            .map(|d| d.value())
-                            .unwrap_or_else(Value::undefined),
-                    )
-                }
-            });
+            // The fields are collected first, converting them to strings may modify the replacer.
+            let fields: Vec<_> = replacer_as_object
+                .borrow()
+                .keys()
+                .filter_map(|key| {
+                    if key == "length" {
+                        None
+                    } else {
+                        Some(
+                            replacer
+                                .get_property(key)
+                                .as_ref()
+                                .and_then(|p| p.as_data_descriptor())
+                                .map(|d| d.value())
+                                .unwrap_or_else(Value::undefined),
+                        )
+                    }
+                })
+                .collect();
             for field in fields {
                 let v = object.get_field(field.to_string(context)?, context)?;
-                if !v.is_undefined() {
-                    let value = v.to_json(context)?;
+                if let Some(value) = v.to_json_property(context)? {
                     obj_to_return.insert(field.to_string(context)?.to_string(), value);
                 }
             }
@@ -243,14 +254,19 @@ impl Json {
                 gap,
             )))
         } else {
-            Ok(Value::from(json_to_pretty_string(
-                &object.to_json(context)?,
-                gap,
-            )))
+            Ok(json_to_value(object.to_json_property(context)?, gap))
         }
     }
 }
 
+/// Converts the result of the serialization of a value to a string, or to `undefined` if the
+/// value has no JSON representation.
+fn json_to_value(json: Option<JSONValue>, gap: &str) -> Value {
+    json.map_or_else(Value::undefined, |json| {
+        Value::from(json_to_pretty_string(&json, gap))
+    })
+}
+
 fn json_to_pretty_string(json: &JSONValue, gap: &str) -> String {
     if gap.is_empty() {
         return json.to_string();
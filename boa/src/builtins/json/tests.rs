@@ -188,6 +188,22 @@ fn json_stringify_symbol() {
     assert_eq!(actual_symbol, expected);
 }
 
+#[test]
+fn json_stringify_to_json_returning_undefined() {
+    let mut context = Context::new();
+
+    let top_level = forward(&mut context, r#"JSON.stringify({ toJSON() {} })"#);
+    let property = forward(
+        &mut context,
+        r#"JSON.stringify({ a: { toJSON() {} }, b: 1 })"#,
+    );
+    let item = forward(&mut context, r#"JSON.stringify([{ toJSON() {} }, 1])"#);
+
+    assert_eq!(top_level, "undefined");
+    assert_eq!(property, r#""{"b":1}""#);
+    assert_eq!(item, r#""[null,1]""#);
+}
+
 #[test]
 fn json_stringify_no_args() {
     let mut context = Context::new();
@@ -461,3 +477,20 @@ fn json_parse_with_no_args_throws_syntax_error() {
     let result = forward(&mut context, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_to_string_tag() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(JSON)"),
+        "\"[object JSON]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(JSON.parse, 'name').value"
+        ),
+        "\"parse\""
+    );
+    assert_eq!(forward(&mut context, "JSON.stringify.length"), "3");
+}
@@ -1,7 +1,8 @@
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, Value},
-    object::{GcObject, ObjectData},
-    property::{Attribute, DataDescriptor},
+    builtins::{iterable::create_iter_result_object, Array, Value},
+    object::{GcObject, ObjectData, ObjectInitializer},
+    property::Attribute,
+    snapshot::HeapCopier,
     BoaProfiler, Context, Result,
 };
 use gc::{Finalize, Trace};
@@ -37,6 +38,14 @@ impl MapIterator {
         }
     }
 
+    /// Copies the iterator for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            iterated_map: copier.copy_value(&self.iterated_map),
+            ..self.clone()
+        }
+    }
+
     /// Abstract operation CreateMapIterator( map, kind )
     ///
     /// Creates a new iterator over the given map.
@@ -142,13 +151,12 @@ impl MapIterator {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         // Create prototype
-        let mut map_iterator = context.construct_object();
-        make_builtin_fn(Self::next, "next", &map_iterator, 0, context);
-        map_iterator.set_prototype_instance(iterator_prototype);
-
         let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
-        let to_string_tag_property = DataDescriptor::new("Map Iterator", Attribute::CONFIGURABLE);
-        map_iterator.insert(to_string_tag, to_string_tag_property);
+        let mut map_iterator = ObjectInitializer::new(context)
+            .function(Self::next, "next", 0)
+            .property(to_string_tag, "Map Iterator", Attribute::BUILTIN_READONLY)
+            .build();
+        map_iterator.set_prototype_instance(iterator_prototype);
         map_iterator
     }
 }
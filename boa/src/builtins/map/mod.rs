@@ -22,7 +22,7 @@ impl BuiltIn for Map {
     const NAME: &'static str = "Map";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -37,36 +37,32 @@ impl BuiltIn for Map {
             .constructable(false)
             .build();
 
-        let map_object = ConstructorBuilder::new(context, Self::constructor)
-            .name(Self::NAME)
-            .length(Self::LENGTH)
-            .property(
-                "entries",
-                entries_function.clone(),
-                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
-            .property(
-                iterator_symbol,
-                entries_function,
-                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
-            .method(Self::keys, "keys", 0)
-            .method(Self::set, "set", 2)
-            .method(Self::delete, "delete", 1)
-            .method(Self::get, "get", 1)
-            .method(Self::clear, "clear", 0)
-            .method(Self::has, "has", 1)
-            .method(Self::for_each, "forEach", 1)
-            .method(Self::values, "values", 0)
-            .callable(false)
-            .build();
+        let map_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property("entries", entries_function.clone(), Attribute::BUILTIN)
+        .property(iterator_symbol, entries_function, Attribute::BUILTIN)
+        .method(Self::keys, "keys", 0)
+        .method(Self::set, "set", 2)
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::clear, "clear", 0)
+        .method(Self::has, "has", 1)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::values, "values", 0)
+        .callable(false)
+        .build();
 
         (Self::NAME, map_object.into(), Self::attribute())
     }
 }
 
 impl Map {
-    pub(crate) const LENGTH: usize = 1;
+    pub(crate) const LENGTH: usize = 0;
 
     /// Create a new map
     pub(crate) fn constructor(
@@ -77,17 +73,6 @@ impl Map {
         if new_target.is_undefined() {
             return context.throw_type_error("Map requires new");
         }
-        let map_prototype = context
-            .global_object()
-            .clone()
-            .get(
-                &"Map".into(),
-                context.global_object().clone().into(),
-                context,
-            )?
-            .get_field(PROTOTYPE, context)?
-            .as_object()
-            .expect("'Map' global property should be an object");
         let prototype = new_target
             .as_object()
             .and_then(|obj| {
@@ -96,7 +81,7 @@ impl Map {
                     .transpose()
             })
             .transpose()?
-            .unwrap_or(map_prototype);
+            .unwrap_or_else(|| context.standard_objects().map_object().prototype());
 
         let mut obj = context.construct_object();
         obj.set_prototype_instance(prototype.into());
@@ -176,7 +161,7 @@ impl Map {
     }
 
     /// Helper function to set the size property.
-    fn set_size(this: &Value, size: usize) {
+    pub(crate) fn set_size(this: &Value, size: usize) {
         let size = DataDescriptor::new(
             size,
             Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
@@ -336,7 +321,7 @@ impl Map {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/forEach
     pub(crate) fn for_each(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
-            return Err(Value::from("Missing argument for Map.prototype.forEach"));
+            return context.throw_type_error("Missing argument for Map.prototype.forEach");
         }
 
         let callback_arg = &args[0];
@@ -158,13 +158,16 @@ fn set() {
         "#;
     forward(&mut context, init);
     let result = forward(&mut context, "map.set()");
-    assert_eq!(result, "Map { undefined → undefined }");
+    assert_eq!(result, "Map(1) { undefined => undefined }");
     let result = forward(&mut context, "map.set('1', 'one')");
-    assert_eq!(result, "Map { undefined → undefined, \"1\" → \"one\" }");
+    assert_eq!(
+        result,
+        "Map(2) { undefined => undefined, \"1\" => \"one\" }"
+    );
     let result = forward(&mut context, "map.set('2')");
     assert_eq!(
         result,
-        "Map { undefined → undefined, \"1\" → \"one\", \"2\" → undefined }"
+        "Map(3) { undefined => undefined, \"1\" => \"one\", \"2\" => undefined }"
     );
 }
 
@@ -307,20 +310,20 @@ fn order() {
         "#;
     forward(&mut context, init);
     let result = forward(&mut context, "map");
-    assert_eq!(result, "Map { 1 → \"one\", 2 → \"two\" }");
+    assert_eq!(result, "Map(2) { 1 => \"one\", 2 => \"two\" }");
     let result = forward(&mut context, "map.set(1, \"five\");map");
-    assert_eq!(result, "Map { 1 → \"five\", 2 → \"two\" }");
+    assert_eq!(result, "Map(2) { 1 => \"five\", 2 => \"two\" }");
     let result = forward(&mut context, "map.set();map");
     assert_eq!(
         result,
-        "Map { 1 → \"five\", 2 → \"two\", undefined → undefined }"
+        "Map(3) { 1 => \"five\", 2 => \"two\", undefined => undefined }"
     );
     let result = forward(&mut context, "map.delete(2);map");
-    assert_eq!(result, "Map { 1 → \"five\", undefined → undefined }");
+    assert_eq!(result, "Map(2) { 1 => \"five\", undefined => undefined }");
     let result = forward(&mut context, "map.set(2, \"two\");map");
     assert_eq!(
         result,
-        "Map { 1 → \"five\", undefined → undefined, 2 → \"two\" }"
+        "Map(3) { 1 => \"five\", undefined => undefined, 2 => \"two\" }"
     );
 }
 
@@ -334,9 +337,12 @@ fn recursive_display() {
         "#;
     forward(&mut context, init);
     let result = forward(&mut context, "map");
-    assert_eq!(result, "Map { \"y\" → Map(1) }");
+    assert_eq!(result, "Map(1) { \"y\" => [Circular] }");
     let result = forward(&mut context, "map.set(\"z\", array)");
-    assert_eq!(result, "Map { \"y\" → Map(2), \"z\" → Array(1) }");
+    assert_eq!(
+        result,
+        "Map(2) { \"y\" => [Circular], \"z\" => [ [ [Circular] ] ] }"
+    );
 }
 
 #[test]
@@ -354,3 +360,25 @@ fn not_a_function() {
         "\"TypeError: function object is not callable\""
     );
 }
+
+#[test]
+fn map_intrinsics_survive_overwritten_global() {
+    let mut context = Context::new();
+    let init = r#"
+        var MapConstructor = Map;
+        globalThis.Map = null;
+        var map = new MapConstructor([["a", 1]]);
+        var entries = [...map];
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "map.get('a')"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(map) === MapConstructor.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "entries[0].join()"), "\"a,1\"");
+}
@@ -17,6 +17,7 @@ use crate::{
 };
 use std::f64;
 
+pub(crate) mod random;
 #[cfg(test)]
 mod tests;
 
@@ -28,13 +29,14 @@ impl BuiltIn for Math {
     const NAME: &'static str = "Math";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::CONSTANT;
+        let string_tag = context.well_known_symbols().to_string_tag_symbol();
         let object = ObjectInitializer::new(context)
             .property("E", f64::consts::E, attribute)
             .property("LN2", f64::consts::LN_2, attribute)
@@ -61,8 +63,8 @@ impl BuiltIn for Math {
             .function(Self::expm1, "expm1", 1)
             .function(Self::floor, "floor", 1)
             .function(Self::fround, "fround", 1)
-            .function(Self::hypot, "hypot", 1)
-            .function(Self::imul, "imul", 1)
+            .function(Self::hypot, "hypot", 2)
+            .function(Self::imul, "imul", 2)
             .function(Self::log, "log", 1)
             .function(Self::log1p, "log1p", 1)
             .function(Self::log10, "log10", 1)
@@ -79,6 +81,7 @@ impl BuiltIn for Math {
             .function(Self::tan, "tan", 1)
             .function(Self::tanh, "tanh", 1)
             .function(Self::trunc, "trunc", 1)
+            .property(string_tag, Self::NAME, Attribute::BUILTIN_READONLY)
             .build();
 
         (Self::NAME, object.into(), Self::attribute())
@@ -545,8 +548,16 @@ impl Math {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-math.random
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/random
-    pub(crate) fn random(_: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        Ok(rand::random::<f64>().into())
+    pub(crate) fn random(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let random = context.next_random()?;
+        if !(0.0..1.0).contains(&random) {
+            return context.throw_range_error(format!(
+                "Math.random: the random source returned {}, which is not in [0, 1)",
+                random
+            ));
+        }
+
+        Ok(random.into())
     }
 
     /// Round a number to the nearest integer.
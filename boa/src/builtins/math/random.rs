@@ -0,0 +1,82 @@
+//! The random number sources that `Math.random` can use.
+
+use std::fmt;
+
+/// A random number generator, set with [`Context::set_random_source`](crate::Context::set_random_source).
+pub(crate) struct RandomSource(Box<dyn FnMut() -> f64>);
+
+impl RandomSource {
+    /// Creates a source calling `source` for each random number.
+    pub(crate) fn new<F>(source: F) -> Self
+    where
+        F: FnMut() -> f64 + 'static,
+    {
+        Self(Box::new(source))
+    }
+
+    /// Creates a source generating the same numbers for the same seed on every platform.
+    pub(crate) fn seeded(seed: u64) -> Self {
+        let mut generator = Pcg32::new(seed);
+        Self::new(move || generator.next_f64())
+    }
+
+    /// Returns the next number of the source.
+    #[inline]
+    pub(crate) fn next(&mut self) -> f64 {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for RandomSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RandomSource").finish()
+    }
+}
+
+/// The `pcg32` generator (PCG-XSH-RR with a 64 bits state).
+///
+/// More information:
+///  - [PCG website][pcg]
+///
+/// [pcg]: https://www.pcg-random.org/
+#[derive(Debug, Clone, Copy)]
+struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+    /// The stream used by the reference implementation in its examples.
+    const STREAM: u64 = 54;
+
+    /// Creates a generator, like `pcg32_srandom_r` in the reference implementation.
+    fn new(seed: u64) -> Self {
+        let mut generator = Self {
+            state: 0,
+            increment: (Self::STREAM << 1) | 1,
+        };
+        generator.next_u32();
+        generator.state = generator.state.wrapping_add(seed);
+        generator.next_u32();
+        generator
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+        self.state = state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        let xor_shifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rotation = (state >> 59) as u32;
+        xor_shifted.rotate_right(rotation)
+    }
+
+    /// Returns a number in `[0, 1)` made of 53 random bits.
+    fn next_f64(&mut self) -> f64 {
+        let high = u64::from(self.next_u32() >> 5);
+        let low = u64::from(self.next_u32() >> 6);
+        ((high << 26) + low) as f64 / (1_u64 << 53) as f64
+    }
+}
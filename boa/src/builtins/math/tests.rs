@@ -1,6 +1,6 @@
 #![allow(clippy::float_cmp)]
 
-use crate::{forward, forward_val, Context};
+use crate::{context::ContextBuilder, forward, forward_val, Context};
 use std::f64;
 
 #[test]
@@ -727,3 +727,65 @@ fn trunc() {
     assert_eq!(a.to_number(&mut context).unwrap(), 13_f64);
     assert_eq!(b.to_number(&mut context).unwrap(), 0_f64);
 }
+
+#[test]
+fn random() {
+    let mut context = Context::new();
+    for _ in 0..100 {
+        let random = forward_val(&mut context, "Math.random()")
+            .unwrap()
+            .as_number()
+            .unwrap();
+        assert!((0.0..1.0).contains(&random));
+    }
+}
+
+#[test]
+fn random_seed() {
+    let mut context = ContextBuilder::new().random_seed(42).build();
+    let numbers = forward(
+        &mut context,
+        "[Math.random(), Math.random(), Math.random(), Math.random(), Math.random()].join()",
+    );
+
+    assert_eq!(
+        numbers,
+        "\"0.6303102186438938,0.7270080560068604,0.7486033647998483,0.7491247468042271,0.8989134056383017\""
+    );
+
+    let mut other = ContextBuilder::new().random_seed(42).build();
+    assert_eq!(forward(&mut other, "Math.random()"), "0.6303102186438938");
+}
+
+#[test]
+fn random_source() {
+    let mut context = Context::new();
+    let mut next = 0.0;
+    context.set_random_source(move || {
+        next += 0.25;
+        next % 1.0
+    });
+
+    assert_eq!(forward(&mut context, "Math.random()"), "0.25");
+    assert_eq!(forward(&mut context, "Math.random()"), "0.5");
+
+    context.set_random_source(|| 1.0);
+    assert!(forward(&mut context, "Math.random()").starts_with("Uncaught \"RangeError\""));
+}
+
+#[test]
+fn math_to_string_tag() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(Math)"),
+        "\"[object Math]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(Math, Symbol.toStringTag).writable"
+        ),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Object.keys(Math).length"), "0");
+}
@@ -1,12 +1,15 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
 pub mod array;
+#[cfg(feature = "web")]
+pub mod base64;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
 pub mod console;
 pub mod date;
 pub mod error;
+pub mod finalization_registry;
 pub mod function;
 pub mod global_this;
 pub mod infinity;
@@ -19,15 +22,22 @@ pub mod number;
 pub mod object;
 pub mod regexp;
 pub mod string;
+pub mod structured_clone;
 pub mod symbol;
+pub mod timers;
 pub mod undefined;
+pub mod weak_ref;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
     bigint::BigInt,
     boolean::Boolean,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
+    finalization_registry::FinalizationRegistry,
     function::BuiltInFunctionObject,
     global_this::GlobalThis,
     infinity::Infinity,
@@ -41,12 +51,18 @@ pub(crate) use self::{
     object::Object as BuiltInObjectObject,
     regexp::RegExp,
     string::String,
+    structured_clone::StructuredClone,
     symbol::Symbol,
+    timers::Scheduling,
     undefined::Undefined,
+    weak_ref::WeakRef,
 };
 use crate::{
-    property::{Attribute, DataDescriptor},
-    Context, Value,
+    builtins::function::NativeFunction,
+    context::StandardObjects,
+    object::{FunctionBuilder, GcObject},
+    property::{AccessorDescriptor, Attribute, DataDescriptor},
+    Context, Result, Value,
 };
 
 pub(crate) trait BuiltIn {
@@ -57,9 +73,129 @@ pub(crate) trait BuiltIn {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute);
 }
 
+/// Rarely used builtins, which are only initialized when they are first needed.
+///
+/// Until then, their global property is an accessor whose getter initializes the builtin and
+/// replaces the accessor with the usual data property. The accessor is never observed, since the
+/// engine initializes the builtin before the descriptor of its global property is read or
+/// changed, with [`Context::initialize_global_property`]. It also initializes them before using
+/// their intrinsics, with [`Context::initialize_builtin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum LazyBuiltIn {
+    Date,
+    RegExp,
+    WeakRef,
+    FinalizationRegistry,
+    EvalError,
+    UriError,
+    AggregateError,
+}
+
+impl LazyBuiltIn {
+    /// Every lazily initialized builtin.
+    const ALL: [Self; 7] = [
+        Self::Date,
+        Self::RegExp,
+        Self::WeakRef,
+        Self::FinalizationRegistry,
+        Self::EvalError,
+        Self::UriError,
+        Self::AggregateError,
+    ];
+
+    /// The binding name of the global property.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Date => Date::NAME,
+            Self::RegExp => RegExp::NAME,
+            Self::WeakRef => WeakRef::NAME,
+            Self::FinalizationRegistry => FinalizationRegistry::NAME,
+            Self::EvalError => EvalError::NAME,
+            Self::UriError => UriError::NAME,
+            Self::AggregateError => AggregateError::NAME,
+        }
+    }
+
+    /// Initializes the builtin, like [`BuiltIn::init`].
+    pub(crate) fn init(self, context: &mut Context) -> (&'static str, Value, Attribute) {
+        match self {
+            Self::Date => Date::init(context),
+            Self::RegExp => RegExp::init(context),
+            Self::WeakRef => WeakRef::init(context),
+            Self::FinalizationRegistry => FinalizationRegistry::init(context),
+            Self::EvalError => EvalError::init(context),
+            Self::UriError => UriError::init(context),
+            Self::AggregateError => AggregateError::init(context),
+        }
+    }
+
+    /// The constructor of the builtin, which is only complete once it has been initialized.
+    fn constructor(self, objects: &StandardObjects) -> GcObject {
+        match self {
+            Self::Date => objects.date_object().constructor(),
+            Self::RegExp => objects.regexp_object().constructor(),
+            Self::WeakRef => objects.weak_ref_object().constructor(),
+            Self::FinalizationRegistry => objects.finalization_registry_object().constructor(),
+            Self::EvalError => objects.eval_error_object().constructor(),
+            Self::UriError => objects.uri_error_object().constructor(),
+            Self::AggregateError => objects.aggregate_error_object().constructor(),
+        }
+    }
+
+    /// The getter of the global property.
+    fn getter(self) -> NativeFunction {
+        match self {
+            Self::Date => |_, _, context| lazy_get(LazyBuiltIn::Date, context),
+            Self::RegExp => |_, _, context| lazy_get(LazyBuiltIn::RegExp, context),
+            Self::WeakRef => |_, _, context| lazy_get(LazyBuiltIn::WeakRef, context),
+            Self::FinalizationRegistry => {
+                |_, _, context| lazy_get(LazyBuiltIn::FinalizationRegistry, context)
+            }
+            Self::EvalError => |_, _, context| lazy_get(LazyBuiltIn::EvalError, context),
+            Self::UriError => |_, _, context| lazy_get(LazyBuiltIn::UriError, context),
+            Self::AggregateError => |_, _, context| lazy_get(LazyBuiltIn::AggregateError, context),
+        }
+    }
+
+    /// The setter of the global property.
+    fn setter(self) -> NativeFunction {
+        match self {
+            Self::Date => |_, args, context| lazy_set(LazyBuiltIn::Date, args, context),
+            Self::RegExp => |_, args, context| lazy_set(LazyBuiltIn::RegExp, args, context),
+            Self::WeakRef => |_, args, context| lazy_set(LazyBuiltIn::WeakRef, args, context),
+            Self::FinalizationRegistry => {
+                |_, args, context| lazy_set(LazyBuiltIn::FinalizationRegistry, args, context)
+            }
+            Self::EvalError => |_, args, context| lazy_set(LazyBuiltIn::EvalError, args, context),
+            Self::UriError => |_, args, context| lazy_set(LazyBuiltIn::UriError, args, context),
+            Self::AggregateError => {
+                |_, args, context| lazy_set(LazyBuiltIn::AggregateError, args, context)
+            }
+        }
+    }
+}
+
+/// Reads the global property of a lazy builtin, initializing it.
+fn lazy_get(builtin: LazyBuiltIn, context: &mut Context) -> Result<Value> {
+    context.initialize_builtin(builtin);
+    Ok(builtin.constructor(context.standard_objects()).into())
+}
+
+/// Assigns to the global property of a lazy builtin, which initializes it first so that the
+/// assignment replaces the data property.
+fn lazy_set(builtin: LazyBuiltIn, args: &[Value], context: &mut Context) -> Result<Value> {
+    context.initialize_builtin(builtin);
+    let value = args.get(0).cloned().unwrap_or_default();
+    let mut global = context.global_object().clone();
+    global.set(builtin.name().into(), value, global.clone().into(), context)?;
+    Ok(Value::undefined())
+}
+
 /// Initializes builtin objects and functions
+///
+/// Returns the getters installed for the [`LazyBuiltIn`]s, which are initialized later.
 #[inline]
-pub fn init(context: &mut Context) {
+pub(crate) fn init(context: &mut Context) -> Vec<(LazyBuiltIn, GcObject)> {
     let globals = [
         // Global properties.
         Undefined::init,
@@ -70,24 +206,24 @@ pub fn init(context: &mut Context) {
         BuiltInObjectObject::init,
         Math::init,
         Json::init,
+        StructuredClone::init,
+        Scheduling::init,
         Array::init,
         BigInt::init,
         Boolean::init,
-        Date::init,
         Map::init,
         Number::init,
         String::init,
-        RegExp::init,
         Symbol::init,
         Error::init,
         RangeError::init,
         ReferenceError::init,
         TypeError::init,
         SyntaxError::init,
-        EvalError::init,
-        UriError::init,
         #[cfg(feature = "console")]
         console::Console::init,
+        #[cfg(feature = "web")]
+        base64::Base64::init,
     ];
 
     let global_object = context.global_object().clone();
@@ -97,4 +233,23 @@ pub fn init(context: &mut Context) {
         let property = DataDescriptor::new(value, attribute);
         global_object.borrow_mut().insert(name, property);
     }
+
+    let attribute = Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+    LazyBuiltIn::ALL
+        .iter()
+        .map(|&builtin| {
+            let name = builtin.name();
+            let getter = FunctionBuilder::new(context, builtin.getter())
+                .name(format!("get {}", name))
+                .length(0)
+                .build();
+            let setter = FunctionBuilder::new(context, builtin.setter())
+                .name(format!("set {}", name))
+                .length(1)
+                .build();
+            let property = AccessorDescriptor::new(Some(getter.clone()), Some(setter), attribute);
+            global_object.borrow_mut().insert(name, property);
+            (builtin, getter)
+        })
+        .collect()
 }
@@ -1,3 +1,5 @@
+use crate::builtins::{BigInt, String};
+
 /// Converts a 64-bit floating point number to an `i32` according to the [`ToInt32`][ToInt32] algorithm.
 ///
 /// [ToInt32]: https://tc39.es/ecma262/#sec-toint32
@@ -84,3 +86,51 @@ pub(crate) fn f64_to_int32(number: f64) -> i32 {
 pub(crate) fn f64_to_uint32(number: f64) -> u32 {
     f64_to_int32(number) as u32
 }
+
+/// Converts a string to a number according to the [`StringToNumber`][StringToNumber] algorithm.
+///
+/// The string is trimmed, then parsed as a decimal literal, `Infinity` with an optional sign, or
+/// a binary, octal or hexadecimal integer literal without a sign. An empty string is `0`, and any
+/// other string is `NaN`.
+///
+/// [StringToNumber]: https://tc39.es/ecma262/#sec-stringtonumber
+pub(crate) fn string_to_number(string: &str) -> f64 {
+    let string = string.trim_matches(String::is_trimmable_whitespace);
+    if string.is_empty() {
+        return 0.0;
+    }
+
+    let radix = match string.get(..2) {
+        Some("0b") | Some("0B") => 2,
+        Some("0o") | Some("0O") => 8,
+        Some("0x") | Some("0X") => 16,
+        _ => 10,
+    };
+    if radix != 10 {
+        let digits = &string[2..];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return f64::NAN;
+        }
+        return BigInt::from_string_radix(digits, radix).map_or(f64::NAN, |n| n.to_f64());
+    }
+
+    let (sign, unsigned) = match string.as_bytes()[0] {
+        b'+' => (1.0, &string[1..]),
+        b'-' => (-1.0, &string[1..]),
+        _ => (1.0, string),
+    };
+    if unsigned == "Infinity" {
+        return sign * f64::INFINITY;
+    }
+
+    // Rust also parses `inf`, `infinity` and `nan`, and a second sign, which are not part of a
+    // decimal literal.
+    let is_decimal_literal = unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && unsigned
+            .bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'+' | b'-'));
+    if !is_decimal_literal {
+        return f64::NAN;
+    }
+    unsigned.parse().map_or(f64::NAN, |n: f64| sign * n)
+}
@@ -25,7 +25,7 @@ use num_traits::{float::FloatCore, Num};
 
 mod conversions;
 
-pub(crate) use conversions::{f64_to_int32, f64_to_uint32};
+pub(crate) use conversions::{f64_to_int32, f64_to_uint32, string_to_number};
 
 #[cfg(test)]
 mod tests;
@@ -46,13 +46,13 @@ impl BuiltIn for Number {
     const NAME: &'static str = "Number";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::CONSTANT;
         let number_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -108,7 +108,7 @@ impl Number {
 
     /// The `Number.MAX_SAFE_INTEGER` constant represents the maximum safe integer in JavaScript (`2^53 - 1`).
     ///
-    /// /// More information:
+    /// More information:
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
@@ -116,7 +116,7 @@ impl Number {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_SAFE_INTEGER
     pub(crate) const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991_f64;
 
-    /// The `Number.MIN_SAFE_INTEGER` constant represents the minimum safe integer in JavaScript (`-(253 - 1)`).
+    /// The `Number.MIN_SAFE_INTEGER` constant represents the minimum safe integer in JavaScript (`-(2^53 - 1)`).
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -150,7 +150,7 @@ impl Number {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-number.min_value
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_VALUE
-    pub(crate) const MIN_VALUE: f64 = f64::MIN;
+    pub(crate) const MIN_VALUE: f64 = 5e-324;
 
     /// `Number( value )`
     pub(crate) fn constructor(
@@ -251,8 +251,12 @@ impl Number {
     pub(crate) fn to_fixed(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         let this_num = Self::this_number_value(this, context)?;
         let precision = match args.get(0) {
-            Some(n) => match n.to_integer(context)? as i32 {
-                x if x > 0 => n.to_integer(context)? as usize,
+            Some(n) => match n.to_integer(context)? {
+                x if x > 100.0 => {
+                    return context
+                        .throw_range_error("toFixed() digits argument must not exceed 100")
+                }
+                x if x > 0.0 => x as usize,
                 _ => 0,
             },
             None => 0,
@@ -531,7 +535,7 @@ impl Number {
                         } else {
                             let c: u8 = frac_buf[fraction_cursor];
                             // Reconstruct digit.
-                            let digit_0 = (c as char).to_digit(10).unwrap();
+                            let digit_0 = (c as char).to_digit(radix as u32).unwrap();
                             if digit_0 + 1 >= radix as u32 {
                                 continue;
                             }
@@ -923,7 +927,7 @@ impl Number {
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-isnan-number
+    /// [spec]: https://tc39.es/ecma262/#sec-number.isnan
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isNaN
     pub(crate) fn number_is_nan(_: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
         Ok(Value::from(if let Some(val) = args.get(0) {
@@ -949,8 +953,8 @@ impl Number {
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-isnan-number
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isNaN
+    /// [spec]: https://tc39.es/ecma262/#sec-number.issafeinteger
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger
     pub(crate) fn is_safe_integer(_: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
         Ok(Value::from(match args.get(0) {
             Some(Value::Integer(_)) => true,
@@ -519,6 +519,53 @@ fn number_constants() {
         .is_null_or_undefined());
 }
 
+#[test]
+fn number_constant_values() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Number.EPSILON === 2 ** -52"), "true");
+    assert_eq!(
+        forward(&mut context, "Number.MAX_SAFE_INTEGER === 2 ** 53 - 1"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Number.MIN_SAFE_INTEGER === -(2 ** 53 - 1)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Number.MAX_VALUE === 1.7976931348623157e308"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Number.MIN_VALUE === 5e-324"), "true");
+    assert_eq!(forward(&mut context, "Number.MIN_VALUE / 2"), "0");
+    assert_eq!(
+        forward(&mut context, "Number.POSITIVE_INFINITY === Infinity"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Number.NEGATIVE_INFINITY === -Infinity"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Number.NaN !== Number.NaN"), "true");
+
+    // The constants can't be changed.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Number.MAX_SAFE_INTEGER = 1; Number.MAX_SAFE_INTEGER === 2 ** 53 - 1"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "delete Number.EPSILON; Number.hasOwnProperty('EPSILON')"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Object.keys(Number).length"), "0");
+}
+
 #[test]
 fn parse_int_simple() {
     let mut context = Context::new();
@@ -734,9 +781,8 @@ fn global_is_nan() {
     assert_eq!("false", &forward(&mut context, "isNaN('37.37')"));
     assert_eq!("true", &forward(&mut context, "isNaN('37,5')"));
     assert_eq!("true", &forward(&mut context, "isNaN('123ABC')"));
-    // Incorrect due to ToNumber implementation inconsistencies.
-    //assert_eq!("false", &forward(&mut context, "isNaN('')"));
-    //assert_eq!("false", &forward(&mut context, "isNaN(' ')"));
+    assert_eq!("false", &forward(&mut context, "isNaN('')"));
+    assert_eq!("false", &forward(&mut context, "isNaN(' ')"));
     assert_eq!("true", &forward(&mut context, "isNaN('blabla')"));
 }
 
@@ -816,6 +862,10 @@ fn number_is_integer() {
         &forward(&mut context, "Number.isInteger(Number(5.0000000000000001))")
     );
     assert_eq!("false", &forward(&mut context, "Number.isInteger()"));
+    assert_eq!("true", &forward(&mut context, "Number.isInteger(-0)"));
+    assert_eq!("true", &forward(&mut context, "Number.isInteger(2 ** 53)"));
+    assert_eq!("false", &forward(&mut context, "Number.isInteger(5n)"));
+    assert_eq!("false", &forward(&mut context, "Number.isInteger('NaN')"));
     assert_eq!(
         "false",
         &forward(&mut context, "Number.isInteger(new Number(5))")
@@ -838,10 +888,11 @@ fn number_is_nan() {
     assert_eq!("false", &forward(&mut context, "Number.isNaN('37.37')"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN('37,5')"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN('123ABC')"));
-    // Incorrect due to ToNumber implementation inconsistencies.
-    //assert_eq!("false", &forward(&mut context, "Number.isNaN('')"));
-    //assert_eq!("false", &forward(&mut context, "Number.isNaN(' ')"));
+    assert_eq!("false", &forward(&mut context, "Number.isNaN('')"));
+    assert_eq!("false", &forward(&mut context, "Number.isNaN(' ')"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN('blabla')"));
+    assert_eq!("false", &forward(&mut context, "Number.isNaN('NaN')"));
+    assert_eq!("false", &forward(&mut context, "Number.isNaN(0n)"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN(Number(5))"));
     assert_eq!("true", &forward(&mut context, "Number.isNaN(Number(NaN))"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN(BigInt(5))"));
@@ -860,6 +911,20 @@ fn number_is_safe_integer() {
     let mut context = Context::new();
 
     assert_eq!("true", &forward(&mut context, "Number.isSafeInteger(3)"));
+    assert_eq!("true", &forward(&mut context, "Number.isSafeInteger(-0)"));
+    assert_eq!(
+        "false",
+        &forward(&mut context, "Number.isSafeInteger(2 ** 53)")
+    );
+    assert_eq!(
+        "true",
+        &forward(&mut context, "Number.isSafeInteger(-(2 ** 53 - 1))")
+    );
+    assert_eq!(
+        "false",
+        &forward(&mut context, "Number.isSafeInteger(-(2 ** 53))")
+    );
+    assert_eq!("false", &forward(&mut context, "Number.isSafeInteger(5n)"));
     assert_eq!(
         "false",
         &forward(&mut context, "Number.isSafeInteger(Math.pow(2, 53))")
@@ -881,3 +946,26 @@ fn number_is_safe_integer() {
         &forward(&mut context, "Number.isSafeInteger(new Number(5))")
     );
 }
+
+#[test]
+fn number_wrapper_object() {
+    let mut context = Context::new();
+    let init = r#"
+        let n = new Number(5);
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof n"), "\"object\"");
+    assert_eq!(forward(&mut context, "n.valueOf()"), "5");
+    assert_eq!(forward(&mut context, "n == 5"), "true");
+    assert_eq!(forward(&mut context, "n === 5"), "false");
+    assert_eq!(forward(&mut context, "n + 1"), "6");
+    assert_eq!(
+        forward(&mut context, "Number.prototype.toFixed.call({})"),
+        "Uncaught \"TypeError\": \"'this' is not a number\""
+    );
+    assert_eq!(
+        forward(&mut context, "Number.prototype.valueOf.call('5')"),
+        "Uncaught \"TypeError\": \"'this' is not a number\""
+    );
+}
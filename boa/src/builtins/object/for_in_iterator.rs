@@ -1,10 +1,11 @@
 use crate::property::PropertyKey;
 use crate::value::RcString;
 use crate::{
-    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    builtins::iterable::create_iter_result_object,
     gc::{Finalize, Trace},
-    object::{GcObject, ObjectData},
-    property::{Attribute, DataDescriptor},
+    object::{GcObject, ObjectData, ObjectInitializer},
+    property::Attribute,
+    snapshot::HeapCopier,
     BoaProfiler, Context, Result, Value,
 };
 use rustc_hash::FxHashSet;
@@ -37,6 +38,14 @@ impl ForInIterator {
         }
     }
 
+    /// Copies the iterator for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            object: copier.copy_value(&self.object),
+            ..self.clone()
+        }
+    }
+
     /// CreateForInIterator( object )
     ///
     /// Creates a new iterator over the given object.
@@ -59,6 +68,12 @@ impl ForInIterator {
     ///
     /// Gets the next result in the object.
     ///
+    /// The own keys of each object of the prototype chain are listed when the iteration reaches
+    /// it, in the order of `[[OwnPropertyKeys]]`. The keys deleted before they are reached are
+    /// skipped, as well as the symbols and the keys shadowed by an object already visited. The
+    /// properties added during the iteration are only visited if they are added to an object
+    /// of the prototype chain that the iteration has not reached yet.
+    ///
     /// More information:
     ///  - [ECMA reference][spec]
     ///
@@ -100,14 +115,12 @@ impl ForInIterator {
                             }
                         }
                     }
-                    match object.prototype_instance().to_object(context) {
-                        Ok(o) => {
-                            object = o;
-                        }
+                    object = match object.prototype_instance() {
+                        Value::Object(prototype) => prototype,
                         _ => {
                             return Ok(create_iter_result_object(context, Value::undefined(), true))
                         }
-                    }
+                    };
                     iterator.object = Value::from(object.clone());
                     iterator.object_was_visited = false;
                 }
@@ -129,14 +142,16 @@ impl ForInIterator {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         // Create prototype
-        let mut for_in_iterator = context.construct_object();
-        make_builtin_fn(Self::next, "next", &for_in_iterator, 0, context);
-        for_in_iterator.set_prototype_instance(iterator_prototype);
-
         let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
-        let to_string_tag_property =
-            DataDescriptor::new("For In Iterator", Attribute::CONFIGURABLE);
-        for_in_iterator.insert(to_string_tag, to_string_tag_property);
+        let mut for_in_iterator = ObjectInitializer::new(context)
+            .function(Self::next, "next", 0)
+            .property(
+                to_string_tag,
+                "For In Iterator",
+                Attribute::BUILTIN_READONLY,
+            )
+            .build();
+        for_in_iterator.set_prototype_instance(iterator_prototype);
         for_in_iterator
     }
 }
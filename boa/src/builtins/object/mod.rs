@@ -14,13 +14,12 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::BuiltIn,
-    object::{
-        ConstructorBuilder, Object as BuiltinObject, ObjectData, ObjectInitializer, PROTOTYPE,
-    },
+    builtins::{Array, BuiltIn},
+    object::{ConstructorBuilder, IntegrityLevel, Object as BuiltinObject, ObjectData, PROTOTYPE},
     property::Attribute,
     property::DataDescriptor,
     property::PropertyDescriptor,
+    property::PropertyKey,
     value::{same_value, Type, Value},
     BoaProfiler, Context, Result,
 };
@@ -37,7 +36,7 @@ impl BuiltIn for Object {
     const NAME: &'static str = "Object";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -51,16 +50,21 @@ impl BuiltIn for Object {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .inherit(Value::null())
-        .method(Self::has_own_property, "hasOwnProperty", 0)
-        .method(Self::property_is_enumerable, "propertyIsEnumerable", 0)
+        .method(Self::has_own_property, "hasOwnProperty", 1)
+        .method(Self::property_is_enumerable, "propertyIsEnumerable", 1)
         .method(Self::to_string, "toString", 0)
-        .method(Self::is_prototype_of, "isPrototypeOf", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .method(Self::value_of, "valueOf", 0)
+        .method(Self::is_prototype_of, "isPrototypeOf", 1)
         .static_method(Self::create, "create", 2)
         .static_method(Self::set_prototype_of, "setPrototypeOf", 2)
         .static_method(Self::get_prototype_of, "getPrototypeOf", 1)
         .static_method(Self::define_property, "defineProperty", 3)
         .static_method(Self::define_properties, "defineProperties", 2)
         .static_method(Self::is, "is", 2)
+        .static_method(Self::has_own, "hasOwn", 2)
+        .static_method(Self::freeze, "freeze", 1)
+        .static_method(Self::is_frozen, "isFrozen", 1)
         .static_method(
             Self::get_own_property_descriptor,
             "getOwnPropertyDescriptor",
@@ -71,6 +75,9 @@ impl BuiltIn for Object {
             "getOwnPropertyDescriptors",
             1,
         )
+        .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
+        .static_method(Self::get_own_property_names, "getOwnPropertyNames", 1)
+        .static_method(Self::keys, "keys", 1)
         .build();
 
         (Self::NAME, object.into(), Self::attribute())
@@ -135,7 +142,9 @@ impl Object {
         };
 
         if !properties.is_undefined() {
-            return Object::define_properties(&Value::Undefined, &[obj, properties], context);
+            obj.as_object()
+                .expect("obj was just created as an object")
+                .define_properties(properties, context)?;
         }
 
         Ok(obj)
@@ -163,8 +172,9 @@ impl Object {
         if let Some(key) = args.get(1) {
             let key = key.to_property_key(context)?;
 
+            context.initialize_global_property(&object, &key);
             if let Some(desc) = object.get_own_property(&key) {
-                return Ok(Self::from_property_descriptor(desc, context)?);
+                return Ok(desc.to_object(context));
             }
         }
 
@@ -192,15 +202,10 @@ impl Object {
             .to_object(context)?;
         let descriptors = context.construct_object();
 
-        for key in object.borrow().keys() {
-            let descriptor = {
-                let desc = object
-                    .get_own_property(&key)
-                    .expect("Expected property to be on object.");
-                Self::from_property_descriptor(desc, context)?
-            };
-
-            if !descriptor.is_undefined() {
+        context.initialize_global_properties(&object);
+        for key in object.own_property_keys() {
+            if let Some(desc) = object.get_own_property(&key) {
+                let descriptor = desc.to_object(context);
                 descriptors.borrow_mut().insert(
                     key,
                     PropertyDescriptor::from(DataDescriptor::new(descriptor, Attribute::all())),
@@ -211,47 +216,104 @@ impl Object {
         Ok(Value::Object(descriptors))
     }
 
-    /// The abstract operation `FromPropertyDescriptor`.
+    /// `Object.getOwnPropertySymbols( object )`
     ///
-    /// [ECMAScript reference][spec]
+    /// Returns an array of all the symbol properties found directly upon the given object.
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
-    fn from_property_descriptor(desc: PropertyDescriptor, context: &mut Context) -> Result<Value> {
-        let mut descriptor = ObjectInitializer::new(context);
-
-        if let PropertyDescriptor::Data(data_desc) = &desc {
-            descriptor.property("value", data_desc.value(), Attribute::all());
-        }
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertysymbols
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertySymbols
+    pub fn get_own_property_symbols(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_object(context)?;
+        let symbols: Vec<Value> = object
+            .borrow()
+            .symbol_property_keys()
+            .cloned()
+            .map(Value::from)
+            .collect();
 
-        if let PropertyDescriptor::Accessor(accessor_desc) = &desc {
-            if let Some(setter) = accessor_desc.setter() {
-                descriptor.property("set", Value::Object(setter.to_owned()), Attribute::all());
-            }
-            if let Some(getter) = accessor_desc.getter() {
-                descriptor.property("get", Value::Object(getter.to_owned()), Attribute::all());
-            }
-        }
+        let array = Array::new_array(context)?;
+        Array::construct_array(&array, &symbols, context)
+    }
 
-        let writable = if let PropertyDescriptor::Data(data_desc) = &desc {
-            data_desc.writable()
-        } else {
-            false
-        };
+    /// `Object.getOwnPropertyNames( object )`
+    ///
+    /// Returns an array of all the string keys found directly upon the given object, enumerable
+    /// or not, in property order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertynames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames
+    pub fn get_own_property_names(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_object(context)?;
+        let names: Vec<Value> = object
+            .own_property_keys()
+            .iter()
+            .filter_map(Self::key_name)
+            .collect();
+
+        let array = Array::new_array(context)?;
+        Array::construct_array(&array, &names, context)
+    }
 
-        descriptor
-            .property("writable", Value::from(writable), Attribute::all())
-            .property(
-                "enumerable",
-                Value::from(desc.enumerable()),
-                Attribute::all(),
-            )
-            .property(
-                "configurable",
-                Value::from(desc.configurable()),
-                Attribute::all(),
-            );
+    /// `Object.keys( object )`
+    ///
+    /// Returns an array of the enumerable string keys found directly upon the given object, in
+    /// property order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys
+    pub fn keys(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_object(context)?;
+        let keys: Vec<Value> = object
+            .own_property_keys()
+            .iter()
+            .filter(|key| {
+                object
+                    .get_own_property(key)
+                    .map_or(false, |desc| desc.enumerable())
+            })
+            .filter_map(Self::key_name)
+            .collect();
+
+        let array = Array::new_array(context)?;
+        Array::construct_array(&array, &keys, context)
+    }
 
-        Ok(descriptor.build().into())
+    /// The name of a string key or an index as a string value, `None` for symbols.
+    fn key_name(key: &PropertyKey) -> Option<Value> {
+        match key {
+            PropertyKey::String(ref string) => Some(string.clone().into()),
+            PropertyKey::Index(index) => Some(index.to_string().into()),
+            PropertyKey::Symbol(_) => None,
+        }
     }
 
     /// Uses the SameValue algorithm to check equality of objects
@@ -397,6 +459,7 @@ impl Object {
             context.throw_type_error("Expected an object")
         }
     }
+
     /// `Object.prototype.toString()`
     ///
     /// This method returns a string representing the object.
@@ -443,7 +506,39 @@ impl Object {
         }
     }
 
-    /// `Object.prototype.hasOwnPrototype( property )`
+    /// `Object.prototype.toLocaleString()`
+    ///
+    /// This method returns the result of calling `toString()` on the object, so that objects
+    /// can override it for locale-specific purposes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toLocaleString
+    pub fn to_locale_string(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let to_string = this
+            .to_object(context)?
+            .get(&"toString".into(), this.clone(), context)?;
+        context.call(&to_string, this, &[])
+    }
+
+    /// `Object.prototype.valueOf()`
+    ///
+    /// This method returns the `this` value converted to an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/valueOf
+    pub fn value_of(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(this.to_object(context)?.into())
+    }
+
+    /// `Object.prototype.hasOwnProperty( property )`
     ///
     /// The method returns a boolean indicating whether the object has the specified property
     /// as its own property (as opposed to inheriting it).
@@ -464,17 +559,94 @@ impl Object {
         Ok(object.has_own_property(key).into())
     }
 
+    /// `Object.hasOwn( object, property )`
+    ///
+    /// The static counterpart of `Object.prototype.hasOwnProperty`, which does not rely on the
+    /// object inheriting from `Object.prototype`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.hasown
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn
+    pub fn has_own(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_object(context)?;
+        let key = args
+            .get(1)
+            .unwrap_or(&Value::undefined())
+            .to_property_key(context)?;
+
+        Ok(object.has_own_property(key).into())
+    }
+
+    /// `Object.freeze( object )`
+    ///
+    /// Freezes an object: no property can be added, removed, reconfigured or written anymore.
+    /// Non-object arguments are returned unchanged.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze
+    pub fn freeze(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_else(Value::undefined);
+
+        if let Some(mut o) = object.as_object() {
+            if !o.set_integrity_level(IntegrityLevel::Frozen, context)? {
+                return context.throw_type_error("Object.freeze: cannot freeze object");
+            }
+        }
+
+        Ok(object)
+    }
+
+    /// `Object.isFrozen( object )`
+    ///
+    /// Determines whether an object is frozen. Non-object arguments are always frozen.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen
+    pub fn is_frozen(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        let frozen = args
+            .get(0)
+            .and_then(Value::as_object)
+            .map_or(true, |object| {
+                object.test_integrity_level(IntegrityLevel::Frozen)
+            });
+
+        Ok(frozen.into())
+    }
+
+    /// `Object.prototype.propertyIsEnumerable( property )`
+    ///
+    /// The method returns a boolean indicating whether the specified property is an own
+    /// enumerable property of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.propertyisenumerable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/propertyIsEnumerable
     pub fn property_is_enumerable(
         this: &Value,
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let key = match args.get(0) {
-            None => return Ok(Value::from(false)),
-            Some(key) => key,
-        };
-
-        let key = key.to_property_key(context)?;
+        let key = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_property_key(context)?;
         let own_property = this.to_object(context)?.get_own_property(&key);
 
         Ok(own_property.map_or(Value::from(false), |own_prop| {
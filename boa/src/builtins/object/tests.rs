@@ -1,4 +1,4 @@
-use crate::{forward, Context, Value};
+use crate::{forward, forward_val, Context, Value};
 
 #[test]
 fn object_create_with_regular_object() {
@@ -144,6 +144,76 @@ fn object_property_is_enumerable() {
     )
 }
 
+#[test]
+fn object_has_own_property_key_conversion() {
+    let mut context = Context::new();
+    let init = r#"
+        let sym = Symbol("s");
+        let x = { 1: 'one' };
+        x[sym] = 'sym';
+        let hasOwnProperty = Object.prototype.hasOwnProperty;
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "x.hasOwnProperty(1)"), "true");
+    assert_eq!(forward(&mut context, "x.hasOwnProperty('1')"), "true");
+    assert_eq!(forward(&mut context, "x.hasOwnProperty(sym)"), "true");
+    assert_eq!(
+        forward(&mut context, "x.hasOwnProperty(Symbol('s'))"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "hasOwnProperty.call('abc', 'length')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "hasOwnProperty.call(undefined, 'a')"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
+#[test]
+fn object_has_own() {
+    let mut context = Context::new();
+    let init = r#"
+        let x = Object.create(null);
+        x.a = 1;
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Object.hasOwn(x, 'a')"), "true");
+    assert_eq!(forward(&mut context, "Object.hasOwn(x, 'b')"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn({}, 'toString')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn('abc', 'length')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Object.hasOwn.length"), "2");
+}
+
+#[test]
+fn object_property_is_enumerable_on_primitive() {
+    let mut context = Context::new();
+    let init = r#"
+        let propertyIsEnumerable = Object.prototype.propertyIsEnumerable;
+        let x = { undefined: 1 };
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "propertyIsEnumerable.call('abc', 'foo')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "propertyIsEnumerable.call('abc', 'length')"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "x.propertyIsEnumerable()"), "true");
+}
+
 #[test]
 fn object_to_string() {
     let mut context = Context::new();
@@ -197,6 +267,77 @@ fn object_to_string() {
     assert_eq!(forward(&mut context, "o.toString()"), "\"[object Object]\"");
 }
 
+#[test]
+fn object_to_string_tag() {
+    let mut context = Context::new();
+    let init = r#"
+        let tagged = {};
+        tagged[Symbol.toStringTag] = "Custom";
+        let array = [];
+        array[Symbol.toStringTag] = "NotArray";
+        let numeric = {};
+        numeric[Symbol.toStringTag] = 1;
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(tagged)"),
+        "\"[object Custom]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(array)"),
+        "\"[object NotArray]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(numeric)"),
+        "\"[object Object]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(1)"),
+        "\"[object Number]\""
+    );
+}
+
+#[test]
+fn object_to_locale_string() {
+    let mut context = Context::new();
+    let init = r#"
+        let o = { toString: function() { return "custom"; } };
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "o.toLocaleString()"), "\"custom\"");
+    assert_eq!(
+        forward(&mut context, "({}).toLocaleString()"),
+        "\"[object Object]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toLocaleString.call(true)"),
+        "\"true\""
+    );
+}
+
+#[test]
+fn object_value_of() {
+    let mut context = Context::new();
+    let init = r#"
+        let o = {};
+        let valueOf = Object.prototype.valueOf;
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "o.valueOf() === o"), "true");
+    assert_eq!(
+        forward(&mut context, "typeof valueOf.call(1)"),
+        "\"object\""
+    );
+    assert_eq!(forward(&mut context, "o + ''"), "\"[object Object]\"");
+    assert_eq!(
+        forward(&mut context, "valueOf.call(null)"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
 #[test]
 fn define_symbol_property() {
     let mut context = Context::new();
@@ -279,6 +420,259 @@ fn object_define_properties() {
     assert_eq!(forward(&mut context, "obj.p"), "42");
 }
 
+#[test]
+fn object_create_with_accessor_properties() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let proto = { base: 10 };
+        let obj = Object.create(proto, {
+            doubled: {
+                get: function() { return this.base * 2; },
+                enumerable: true
+            },
+            stored: {
+                set: function(v) { this.base = v; }
+            }
+        });
+        obj.stored = 21;
+        let result = Object.getOwnPropertyDescriptor(obj, "doubled");
+    "#,
+    );
+
+    assert_eq!(forward(&mut context, "obj.doubled"), "42");
+    assert_eq!(forward(&mut context, "proto.base"), "10");
+    assert_eq!(forward(&mut context, "typeof result.get"), "\"function\"");
+    assert_eq!(forward(&mut context, "result.set"), "undefined");
+    assert_eq!(forward(&mut context, "result.enumerable"), "true");
+    assert_eq!(forward(&mut context, "result.configurable"), "false");
+    assert_eq!(forward(&mut context, "'writable' in result"), "false");
+    assert_eq!(forward(&mut context, "'value' in result"), "false");
+}
+
+#[test]
+fn object_define_properties_throwing_getter() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let obj = {};
+        let props = {
+            a: { value: 1 },
+            get b() { throw "boom"; },
+            c: { value: 3 }
+        };
+    "#,
+    );
+
+    assert_eq!(
+        forward(&mut context, "Object.defineProperties(obj, props)"),
+        "Uncaught \"boom\""
+    );
+    assert_eq!(forward(&mut context, "Object.keys(obj).length"), "0");
+    assert_eq!(forward(&mut context, "obj.hasOwnProperty('a')"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.create({}, { a: { value: 1, get: function() {} } })"
+        ),
+        "Uncaught \"TypeError\": \"Invalid property descriptor. Cannot both specify accessors and a value or writable attribute\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.defineProperties({}, { a: 1 })"),
+        "Uncaught \"TypeError\": \"Property description must be an object\""
+    );
+}
+
+#[test]
+fn define_property_keeps_absent_attributes() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let obj = {a: 1};
+        Object.defineProperty(obj, "a", { value: 2 });
+        let result = Object.getOwnPropertyDescriptor(obj, "a");
+    "#,
+    );
+
+    assert_eq!(forward(&mut context, "result.value"), "2");
+    assert_eq!(forward(&mut context, "result.enumerable"), "true");
+    assert_eq!(forward(&mut context, "result.writable"), "true");
+    assert_eq!(forward(&mut context, "result.configurable"), "true");
+}
+
+#[test]
+fn define_property_converts_data_to_accessor() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let obj = {a: 1};
+        Object.defineProperty(obj, "a", { get: function() { return 3; } });
+        let result = Object.getOwnPropertyDescriptor(obj, "a");
+    "#,
+    );
+
+    assert_eq!(forward(&mut context, "obj.a"), "3");
+    assert_eq!(forward(&mut context, "result.enumerable"), "true");
+    assert_eq!(forward(&mut context, "result.set"), "undefined");
+}
+
+#[test]
+fn define_property_invalid_descriptors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty({}, 'a', { value: 1, get: function() {} })"
+        ),
+        "Uncaught \"TypeError\": \"Invalid property descriptor. Cannot both specify accessors and a value or writable attribute\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.defineProperty({}, 'a', { get: 1 })"),
+        "Uncaught \"TypeError\": \"Property descriptor getter must be callable\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(Object.defineProperty({}, 'a', { get: undefined }), 'a').get"
+        ),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperties(Object.defineProperty({}, 'a', { value: 1 }), { a: { value: 2 } })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: a\""
+    );
+}
+
+#[test]
+fn define_property_non_configurable_undefined_value() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let obj = {};
+        Object.defineProperty(obj, "a", { value: undefined });
+    "#,
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'a', { value: 1 })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: a\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'a', { value: undefined }) === obj"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.defineProperty(obj, 'a', {}) === obj"),
+        "true"
+    );
+}
+
+#[test]
+fn define_property_non_configurable_attributes() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let obj = {};
+        Object.defineProperty(obj, "a", { value: 1, writable: true });
+        Object.defineProperty(obj, "b", { get: function() {} });
+    "#,
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'a', { configurable: true })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: a\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'a', { enumerable: true })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: a\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'a', { get: function() {} })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: a\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'b', { get: function() {} })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: b\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'b', { value: 1 })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: b\""
+    );
+
+    forward(
+        &mut context,
+        "Object.defineProperty(obj, 'a', { value: 2, writable: false })",
+    );
+    assert_eq!(forward(&mut context, "obj.a"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'a', { writable: true })"
+        ),
+        "Uncaught \"TypeError\": \"Cannot redefine property: a\""
+    );
+}
+
+#[test]
+fn define_property_array_length() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let arr = [1, 2, 3];
+        Object.defineProperty(arr, "length", { value: 1 });
+    "#,
+    );
+
+    assert_eq!(forward(&mut context, "arr.length"), "1");
+    assert_eq!(forward(&mut context, "arr[2]"), "undefined");
+
+    forward(
+        &mut context,
+        "Object.defineProperty(arr, 'length', { writable: false }); arr[5] = 1;",
+    );
+    assert_eq!(forward(&mut context, "arr.length"), "1");
+    assert_eq!(forward(&mut context, "arr[5]"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(arr, 'length', { value: -1 })"
+        ),
+        "Uncaught \"RangeError\": \"bad length for array\""
+    );
+}
+
 #[test]
 fn object_is_prototype_of() {
     let mut context = Context::new();
@@ -289,3 +683,249 @@ fn object_is_prototype_of() {
 
     assert_eq!(context.eval(init).unwrap(), Value::boolean(true));
 }
+
+#[test]
+fn symbol_properties_do_not_alias() {
+    let mut context = Context::new();
+    let init = r#"
+        let obj = {};
+        let symbols = [];
+        for (let i = 0; i < 1000; i++) {
+            let sym = Symbol("same");
+            symbols.push(sym);
+            obj[sym] = i;
+        }
+
+        let mismatches = 0;
+        for (let i = 0; i < 1000; i++) {
+            if (obj[symbols[i]] !== i) {
+                mismatches++;
+            }
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "mismatches"), "0");
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertySymbols(obj).length"),
+        "1000"
+    );
+}
+
+#[test]
+fn symbols_from_different_contexts_are_distinct() {
+    let mut first = Context::new();
+    let mut second = Context::new();
+
+    // Both symbols get the same hash and description, but they are not the same symbol.
+    let a = forward_val(&mut first, "Symbol('key')").unwrap();
+    let b = forward_val(&mut second, "Symbol('key')").unwrap();
+
+    assert!(a.strict_equals(&a.clone()));
+    assert!(!a.strict_equals(&b));
+}
+
+#[test]
+fn get_own_property_symbols_round_trip() {
+    let mut context = Context::new();
+    let init = r#"
+        let obj = { a: 1 };
+        let sym = Symbol("key");
+        Object.defineProperty(obj, sym, { value: 2, enumerable: true });
+
+        let symbols = Object.getOwnPropertySymbols(obj);
+        let found = symbols[0];
+
+        let copy = {};
+        Object.defineProperty(copy, found, Object.getOwnPropertyDescriptor(obj, found));
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "symbols.length"), "1");
+    assert_eq!(forward(&mut context, "found === sym"), "true");
+    assert_eq!(forward(&mut context, "copy[sym]"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertySymbols(copy)[0] === sym"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn object_freeze() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let obj = { a: 1, get b() { return 2; } };
+        let arr = [1, 2];
+        let returned = Object.freeze(obj);
+        Object.freeze(arr);
+
+        obj.a = 10;
+        obj.c = 3;
+        delete obj.a;
+        arr[0] = 10;
+        arr.length = 0;
+        let desc = Object.getOwnPropertyDescriptor(obj, "a");
+    "#,
+    );
+
+    assert_eq!(forward(&mut context, "returned === obj"), "true");
+    assert_eq!(forward(&mut context, "obj.a"), "1");
+    assert_eq!(forward(&mut context, "obj.b"), "2");
+    assert_eq!(forward(&mut context, "obj.c"), "undefined");
+    assert_eq!(forward(&mut context, "desc.writable"), "false");
+    assert_eq!(forward(&mut context, "desc.configurable"), "false");
+    assert_eq!(forward(&mut context, "arr[0]"), "1");
+    assert_eq!(forward(&mut context, "arr.length"), "2");
+    assert_eq!(forward(&mut context, "Object.freeze(1)"), "1");
+}
+
+#[test]
+fn object_is_frozen() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let frozen = Object.freeze({ a: 1 });
+        let sealedLike = { a: 1 };
+        Object.defineProperty(sealedLike, "a", { configurable: false });
+    "#,
+    );
+
+    assert_eq!(forward(&mut context, "Object.isFrozen(frozen)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.isFrozen(sealedLike)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Object.isFrozen({})"), "false");
+    assert_eq!(forward(&mut context, "Object.isFrozen(1)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.isFrozen(Object.freeze({}))"),
+        "true"
+    );
+}
+
+#[test]
+fn keys_and_own_property_names() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let obj = { b: 1, 1: 2, a: 3 };
+        obj[0] = 4;
+        obj[Symbol("c")] = 5;
+        Object.defineProperty(obj, "hidden", { value: 6, enumerable: false });
+    "#,
+    );
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).join()"),
+        "\"0,1,b,a\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(obj).join()"),
+        "\"0,1,b,a,hidden\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Object.keys(obj)[0]"),
+        "\"string\""
+    );
+    assert_eq!(forward(&mut context, "Object.keys('ab').join()"), "\"0,1\"");
+    assert_eq!(
+        forward(&mut context, "Object.keys(null)"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
+#[test]
+fn builtin_property_attributes() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        // Writes the attributes of a property as `wec`, with `-` for the missing ones.
+        function attributes(object, key) {
+            let desc = Object.getOwnPropertyDescriptor(object, key);
+            return (desc.writable ? "w" : "-") + (desc.enumerable ? "e" : "-") +
+                (desc.configurable ? "c" : "-");
+        }
+    "#,
+    );
+
+    for (object, key, expected) in &[
+        // Constants.
+        ("Math", "'PI'", "---"),
+        ("Math", "'E'", "---"),
+        ("Math", "'SQRT2'", "---"),
+        ("Number", "'MAX_SAFE_INTEGER'", "---"),
+        ("Number", "'EPSILON'", "---"),
+        ("Number", "'NaN'", "---"),
+        ("Symbol", "'iterator'", "---"),
+        ("Symbol", "'toStringTag'", "---"),
+        ("globalThis", "'NaN'", "---"),
+        ("globalThis", "'Infinity'", "---"),
+        ("globalThis", "'undefined'", "---"),
+        ("String.prototype", "'length'", "---"),
+        // The prototypes of the constructors.
+        ("Object", "'prototype'", "---"),
+        ("Function", "'prototype'", "---"),
+        ("Array", "'prototype'", "---"),
+        ("Error", "'prototype'", "---"),
+        ("TypeError", "'prototype'", "---"),
+        ("RegExp", "'prototype'", "---"),
+        ("Map", "'prototype'", "---"),
+        // Methods and the other properties.
+        ("globalThis", "'Math'", "w-c"),
+        ("globalThis", "'JSON'", "w-c"),
+        ("globalThis", "'Array'", "w-c"),
+        ("globalThis", "'globalThis'", "w-c"),
+        ("Object", "'keys'", "w-c"),
+        ("Object.prototype", "'toString'", "w-c"),
+        ("Object.prototype", "'constructor'", "w-c"),
+        ("Array.prototype", "'push'", "w-c"),
+        ("Array.prototype", "Symbol.iterator", "w-c"),
+        ("Map.prototype", "Symbol.iterator", "w-c"),
+        ("String.prototype", "'charAt'", "w-c"),
+        ("Number", "'isNaN'", "w-c"),
+        ("Math", "'max'", "w-c"),
+        ("JSON", "'stringify'", "w-c"),
+        ("RegExp.prototype", "'exec'", "w-c"),
+        ("Error.prototype", "'message'", "w-c"),
+        ("Error.prototype", "'name'", "w-c"),
+        // The names and lengths of functions, and the string tags.
+        ("Array", "'length'", "--c"),
+        ("Array", "'name'", "--c"),
+        ("Array.prototype.push", "'length'", "--c"),
+        ("Array.prototype.push", "'name'", "--c"),
+        ("Math", "Symbol.toStringTag", "--c"),
+        ("JSON", "Symbol.toStringTag", "--c"),
+        (
+            "Object.getPrototypeOf([].values())",
+            "Symbol.toStringTag",
+            "--c",
+        ),
+        ("RegExp.prototype", "'global'", "--c"),
+        // The properties of instances.
+        ("Array.prototype", "'length'", "w--"),
+        ("[]", "'length'", "w--"),
+        ("'ab'", "'length'", "---"),
+        ("/a/g", "'lastIndex'", "w--"),
+    ] {
+        assert_eq!(
+            forward(&mut context, &format!("attributes({}, {})", object, key)),
+            format!("\"{}\"", expected),
+            "the attributes of {}[{}]",
+            object,
+            key
+        );
+    }
+
+    assert_eq!(
+        forward(&mut context, "RegExp.prototype.hasOwnProperty('lastIndex')"),
+        "false"
+    );
+}
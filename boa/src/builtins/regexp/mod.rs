@@ -10,9 +10,9 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{BuiltIn, LazyBuiltIn},
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, GcObject, ObjectData, PROTOTYPE},
     property::{Attribute, DataDescriptor},
     value::{RcString, Value},
     BoaProfiler, Context, Result,
@@ -65,12 +65,13 @@ impl BuiltIn for RegExp {
     const NAME: &'static str = "RegExp";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let accessor_attribute = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
         let regexp_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -78,14 +79,29 @@ impl BuiltIn for RegExp {
         )
         .name(Self::NAME)
         .length(Self::LENGTH)
-        .property("lastIndex", 0, Attribute::all())
         .method(Self::test, "test", 1)
         .method(Self::exec, "exec", 1)
         .method(Self::to_string, "toString", 0)
+        .accessor_native("dotAll", Some(Self::get_dot_all), None, accessor_attribute)
+        .accessor_native("flags", Some(Self::get_flags), None, accessor_attribute)
+        .accessor_native("global", Some(Self::get_global), None, accessor_attribute)
+        .accessor_native(
+            "ignoreCase",
+            Some(Self::get_ignore_case),
+            None,
+            accessor_attribute,
+        )
+        .accessor_native(
+            "multiline",
+            Some(Self::get_multiline),
+            None,
+            accessor_attribute,
+        )
+        .accessor_native("source", Some(Self::get_source), None, accessor_attribute)
+        .accessor_native("sticky", Some(Self::get_sticky), None, accessor_attribute)
+        .accessor_native("unicode", Some(Self::get_unicode), None, accessor_attribute)
         .build();
 
-        // TODO: add them RegExp accessor properties
-
         (Self::NAME, regexp_object.into(), Self::attribute())
     }
 }
@@ -97,12 +113,36 @@ impl RegExp {
     /// The amount of arguments this function object takes.
     pub(crate) const LENGTH: usize = 2;
 
+    /// The abstract operation `IsRegExp`.
+    ///
+    /// An object is treated as a regular expression if its `@@match` property is truthy, or if
+    /// the property is undefined and the object is a `RegExp`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isregexp
+    pub(crate) fn is_regexp(value: &Value, context: &mut Context) -> Result<bool> {
+        let object = match value {
+            Value::Object(ref object) => object,
+            _ => return Ok(false),
+        };
+        let matcher = value.get_field(context.well_known_symbols().match_symbol(), context)?;
+        if matcher.is_undefined() {
+            Ok(object.is_regexp())
+        } else {
+            Ok(matcher.to_boolean())
+        }
+    }
+
     /// Create a new `RegExp`
     pub(crate) fn constructor(
         new_target: &Value,
         args: &[Value],
         ctx: &mut Context,
     ) -> Result<Value> {
+        // The string methods construct regular expressions without reading the global binding.
+        ctx.initialize_builtin(LazyBuiltIn::RegExp);
         let prototype = new_target
             .as_object()
             .and_then(|obj| {
@@ -128,15 +168,28 @@ impl RegExp {
                 )
             }
             Value::Object(ref obj) => {
-                let obj = obj.borrow();
-                if let Some(regex) = obj.as_regexp() {
+                let regex = obj
+                    .borrow()
+                    .as_regexp()
+                    .map(|regex| (regex.original_source.clone(), regex.original_flags.clone()));
+                match regex {
                     // first argument is another `RegExp` object, so copy its pattern and flags
-                    (regex.original_source.clone(), regex.original_flags.clone())
-                } else {
-                    (
+                    Some(parts) => parts,
+                    // other objects recognized by `IsRegExp` give their `source` and `flags`
+                    None if Self::is_regexp(arg, ctx)? => {
+                        let source = arg.get_field("source", ctx)?.to_string(ctx)?;
+                        let flags = arg.get_field("flags", ctx)?;
+                        let flags = if flags.is_undefined() {
+                            RcString::from("")
+                        } else {
+                            flags.to_string(ctx)?
+                        };
+                        (source.as_str().into(), flags.as_str().into())
+                    }
+                    None => (
                         String::new().into_boxed_str(),
                         String::new().into_boxed_str(),
-                    )
+                    ),
                 }
             }
             _ => return Err(Value::undefined()),
@@ -203,124 +256,211 @@ impl RegExp {
         };
 
         this.set_data(ObjectData::RegExp(Box::new(regexp)));
+        this.set_property(
+            "lastIndex",
+            DataDescriptor::new(0, Attribute::WRITABLE | Attribute::NON_ENUMERABLE),
+        );
 
         Ok(this)
     }
 
-    // /// `RegExp.prototype.dotAll`
-    // ///
-    // /// The `dotAll` property indicates whether or not the "`s`" flag is used with the regular expression.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.dotAll
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/dotAll
-    // fn get_dot_all(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     this.with_internal_state_ref(|regex: &RegExp| Ok(Value::from(regex.dot_all)))
-    // }
-
-    // /// `RegExp.prototype.flags`
-    // ///
-    // /// The `flags` property returns a string consisting of the [`flags`][flags] of the current regular expression object.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.flags
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/flags
-    // /// [flags]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions#Advanced_searching_with_flags_2
-    // fn get_flags(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     this.with_internal_state_ref(|regex: &RegExp| Ok(Value::from(regex.flags.clone())))
-    // }
-
-    // /// `RegExp.prototype.global`
-    // ///
-    // /// The `global` property indicates whether or not the "`g`" flag is used with the regular expression.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.global
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/global
-    // fn get_global(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     this.with_internal_state_ref(|regex: &RegExp| Ok(Value::from(regex.global)))
-    // }
-
-    // /// `RegExp.prototype.ignoreCase`
-    // ///
-    // /// The `ignoreCase` property indicates whether or not the "`i`" flag is used with the regular expression.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.ignorecase
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/ignoreCase
-    // fn get_ignore_case(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     this.with_internal_state_ref(|regex: &RegExp| Ok(Value::from(regex.ignore_case)))
-    // }
-
-    // /// `RegExp.prototype.multiline`
-    // ///
-    // /// The multiline property indicates whether or not the "m" flag is used with the regular expression.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.multiline
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/multiline
-    // fn get_multiline(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     this.with_internal_state_ref(|regex: &RegExp| Ok(Value::from(regex.multiline)))
-    // }
-
-    // /// `RegExp.prototype.source`
-    // ///
-    // /// The `source` property returns a `String` containing the source text of the regexp object,
-    // /// and it doesn't contain the two forward slashes on both sides and any flags.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.source
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/source
-    // fn get_source(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     Ok(this.get_internal_slot("OriginalSource"))
-    // }
-
-    // /// `RegExp.prototype.sticky`
-    // ///
-    // /// The `flags` property returns a string consisting of the [`flags`][flags] of the current regular expression object.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.sticky
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/sticky
-    // fn get_sticky(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     this.with_internal_state_ref(|regex: &RegExp| Ok(Value::from(regex.sticky)))
-    // }
-
-    // /// `RegExp.prototype.unicode`
-    // ///
-    // /// The unicode property indicates whether or not the "`u`" flag is used with a regular expression.
-    // /// unicode is a read-only property of an individual regular expression instance.
-    // ///
-    // /// More information:
-    // ///  - [ECMAScript reference][spec]
-    // ///  - [MDN documentation][mdn]
-    // ///
-    // /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.unicode
-    // /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicode
-    // fn get_unicode(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-    //     this.with_internal_state_ref(|regex: &RegExp| Ok(Value::from(regex.unicode)))
-    // }
+    /// The abstract operation `RegExpHasFlag`, shared by the flag accessors.
+    ///
+    /// Returns `undefined` when called on `RegExp.prototype` itself.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexphasflag
+    fn has_flag(this: &Value, flag: char, name: &str, context: &mut Context) -> Result<Value> {
+        if let Some(object) = this.as_object() {
+            if let Some(regex) = object.borrow().as_regexp() {
+                return Ok(regex.flags.contains(flag).into());
+            }
+
+            let prototype = context.standard_objects().regexp_object().prototype();
+            if GcObject::equals(&object, &prototype) {
+                return Ok(Value::undefined());
+            }
+        }
+
+        context.throw_type_error(format!(
+            "RegExp.prototype.{} getter called on incompatible receiver {}",
+            name,
+            this.display()
+        ))
+    }
+
+    /// `get RegExp.prototype.dotAll`
+    ///
+    /// The `dotAll` property indicates whether or not the "`s`" flag is used with the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.dotAll
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/dotAll
+    pub(crate) fn get_dot_all(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::has_flag(this, 's', "dotAll", context)
+    }
+
+    /// `get RegExp.prototype.flags`
+    ///
+    /// The `flags` property returns a string consisting of the [`flags`][flags] of the current regular expression object.
+    ///
+    /// The flags are read through the individual flag properties, so this works on any object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.flags
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/flags
+    /// [flags]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions#Advanced_searching_with_flags_2
+    pub(crate) fn get_flags(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return context.throw_type_error(format!(
+                "RegExp.prototype.flags getter called on non-object {}",
+                this.display()
+            ));
+        }
+
+        let mut result = String::new();
+        for &(name, flag) in &[
+            ("global", 'g'),
+            ("ignoreCase", 'i'),
+            ("multiline", 'm'),
+            ("dotAll", 's'),
+            ("unicode", 'u'),
+            ("sticky", 'y'),
+        ] {
+            if this.get_field(name, context)?.to_boolean() {
+                result.push(flag);
+            }
+        }
+
+        Ok(result.into())
+    }
+
+    /// `get RegExp.prototype.global`
+    ///
+    /// The `global` property indicates whether or not the "`g`" flag is used with the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.global
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/global
+    pub(crate) fn get_global(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::has_flag(this, 'g', "global", context)
+    }
+
+    /// `get RegExp.prototype.ignoreCase`
+    ///
+    /// The `ignoreCase` property indicates whether or not the "`i`" flag is used with the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.ignorecase
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/ignoreCase
+    pub(crate) fn get_ignore_case(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::has_flag(this, 'i', "ignoreCase", context)
+    }
+
+    /// `get RegExp.prototype.multiline`
+    ///
+    /// The multiline property indicates whether or not the "m" flag is used with the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.multiline
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/multiline
+    pub(crate) fn get_multiline(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::has_flag(this, 'm', "multiline", context)
+    }
+
+    /// `get RegExp.prototype.source`
+    ///
+    /// The `source` property returns a `String` containing the source text of the regexp object,
+    /// and it doesn't contain the two forward slashes on both sides and any flags.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.source
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/source
+    pub(crate) fn get_source(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        if let Some(object) = this.as_object() {
+            if let Some(regex) = object.borrow().as_regexp() {
+                if regex.original_source.is_empty() {
+                    return Ok("(?:)".into());
+                }
+                return Ok(Value::from(regex.original_source.to_string()));
+            }
+
+            let prototype = context.standard_objects().regexp_object().prototype();
+            if GcObject::equals(&object, &prototype) {
+                return Ok("(?:)".into());
+            }
+        }
+
+        context.throw_type_error(format!(
+            "RegExp.prototype.source getter called on incompatible receiver {}",
+            this.display()
+        ))
+    }
+
+    /// `get RegExp.prototype.sticky`
+    ///
+    /// The `sticky` property indicates whether or not the "`y`" flag is used with the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.sticky
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/sticky
+    pub(crate) fn get_sticky(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::has_flag(this, 'y', "sticky", context)
+    }
+
+    /// `get RegExp.prototype.unicode`
+    ///
+    /// The unicode property indicates whether or not the "`u`" flag is used with a regular expression.
+    /// unicode is a read-only property of an individual regular expression instance.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.unicode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicode
+    pub(crate) fn get_unicode(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::has_flag(this, 'u', "unicode", context)
+    }
+
+    /// Finds the first match in `string` starting at the byte `last_index`.
+    ///
+    /// The `lastIndex` property can be set to any index by the script, so there is no match past
+    /// the end of the string, and a start inside a character is moved to the next one.
+    fn find_from(&self, string: &str, last_index: usize) -> Option<regress::Match> {
+        if last_index > string.len() {
+            return None;
+        }
+        let start = (last_index..=string.len()).find(|&index| string.is_char_boundary(index))?;
+        self.matcher.find_from(string, start).next()
+    }
 
     /// `RegExp.prototype.test( string )`
     ///
@@ -347,18 +487,17 @@ impl RegExp {
             // 4. Let match be ? RegExpExec(R, string).
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
-                let result =
-                    if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
-                        if regex.use_last_index {
-                            last_index = m.end();
-                        }
-                        true
-                    } else {
-                        if regex.use_last_index {
-                            last_index = 0;
-                        }
-                        false
-                    };
+                let result = if let Some(m) = regex.find_from(arg_str.as_str(), last_index) {
+                    if regex.use_last_index {
+                        last_index = m.end();
+                    }
+                    true
+                } else {
+                    if regex.use_last_index {
+                        last_index = 0;
+                    }
+                    false
+                };
 
                 // 5. If match is not null, return true; else return false.
                 Ok(Value::boolean(result))
@@ -391,17 +530,17 @@ impl RegExp {
         // 4. Return ? RegExpBuiltinExec(R, S).
         let mut last_index = this.get_field("lastIndex", context)?.to_index(context)?;
         let result = if let Some(object) = this.as_object() {
+            // 3. Let S be ? ToString(string).
+            let arg_str = args
+                .get(0)
+                .cloned()
+                .unwrap_or_default()
+                .to_string(context)?;
+
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
-                // 3. Let S be ? ToString(string).
-                let arg_str = args
-                    .get(0)
-                    .cloned()
-                    .unwrap_or_default()
-                    .to_string(context)?;
-
                 let result = {
-                    if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
+                    if let Some(m) = regex.find_from(arg_str.as_str(), last_index) {
                         if regex.use_last_index {
                             last_index = m.end();
                         }
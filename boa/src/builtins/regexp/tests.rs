@@ -15,33 +15,77 @@ fn constructors() {
     assert_eq!(forward(&mut context, "ctor_literal.test('1.0')"), "true");
 }
 
-// TODO: uncomment this test when property getters are supported
-
-//    #[test]
-//    fn flags() {
-//        let mut context = Context::new();
-//        let init = r#"
-//                var re_gi = /test/gi;
-//                var re_sm = /test/sm;
-//                "#;
-//
-//        eprintln!("{}", forward(&mut context, init));
-//        assert_eq!(forward(&mut context, "re_gi.global"), "true");
-//        assert_eq!(forward(&mut context, "re_gi.ignoreCase"), "true");
-//        assert_eq!(forward(&mut context, "re_gi.multiline"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.dotAll"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.unicode"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.sticky"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.flags"), "gi");
-//
-//        assert_eq!(forward(&mut context, "re_sm.global"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.ignoreCase"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.multiline"), "true");
-//        assert_eq!(forward(&mut context, "re_sm.dotAll"), "true");
-//        assert_eq!(forward(&mut context, "re_sm.unicode"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.sticky"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.flags"), "ms");
-//    }
+#[test]
+fn construct_from_is_regexp_object() {
+    let mut context = Context::new();
+    let init = r#"
+        var pattern = { source: "a+b", flags: "i" };
+        pattern[Symbol.match] = true;
+        var from_pattern = new RegExp(pattern);
+        "#;
+
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "from_pattern.test('xAAb')"), "true");
+}
+
+#[test]
+fn flags() {
+    let mut context = Context::new();
+    let init = r#"
+        var re_gi = /test/gi;
+        var re_sm = /test/sm;
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "re_gi.global"), "true");
+    assert_eq!(forward(&mut context, "re_gi.ignoreCase"), "true");
+    assert_eq!(forward(&mut context, "re_gi.multiline"), "false");
+    assert_eq!(forward(&mut context, "re_gi.dotAll"), "false");
+    assert_eq!(forward(&mut context, "re_gi.unicode"), "false");
+    assert_eq!(forward(&mut context, "re_gi.sticky"), "false");
+    assert_eq!(forward(&mut context, "re_gi.flags"), "\"gi\"");
+
+    assert_eq!(forward(&mut context, "re_sm.global"), "false");
+    assert_eq!(forward(&mut context, "re_sm.ignoreCase"), "false");
+    assert_eq!(forward(&mut context, "re_sm.multiline"), "true");
+    assert_eq!(forward(&mut context, "re_sm.dotAll"), "true");
+    assert_eq!(forward(&mut context, "re_sm.unicode"), "false");
+    assert_eq!(forward(&mut context, "re_sm.sticky"), "false");
+    assert_eq!(forward(&mut context, "re_sm.flags"), "\"ms\"");
+    assert_eq!(forward(&mut context, "re_sm.source"), "\"test\"");
+}
+
+#[test]
+fn flag_accessors_are_native_getters() {
+    let mut context = Context::new();
+    let init = r#"
+        var desc = Object.getOwnPropertyDescriptor(RegExp.prototype, "flags");
+        "#;
+
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "typeof desc.get"), "\"function\"");
+    assert_eq!(forward(&mut context, "desc.get.name"), "\"get flags\"");
+    assert_eq!(forward(&mut context, "desc.set"), "undefined");
+    assert_eq!(forward(&mut context, "desc.enumerable"), "false");
+    assert_eq!(forward(&mut context, "desc.configurable"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "desc.get.call({ global: true, sticky: 1, unicode: 0 })"
+        ),
+        "\"gy\""
+    );
+    assert_eq!(
+        forward(&mut context, "RegExp.prototype.global"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "RegExp.prototype.source"), "\"(?:)\"");
+    assert!(forward(
+        &mut context,
+        "Object.getOwnPropertyDescriptor(RegExp.prototype, 'global').get.call({})"
+    )
+    .starts_with("Uncaught \"TypeError\""));
+}
 
 #[test]
 fn last_index() {
@@ -20,12 +20,11 @@ use crate::{
     builtins::{string::string_iterator::StringIterator, Array, BuiltIn, RegExp},
     object::{ConstructorBuilder, Object, ObjectData},
     property::Attribute,
-    value::{RcString, Value},
+    value::{IntegerOrInfinity, RcString, Value},
     BoaProfiler, Context, Result,
 };
 use regress::Regex;
 use std::{
-    char::{decode_utf16, from_u32},
     cmp::{max, min},
     f64::NAN,
     string::String as StdString,
@@ -68,7 +67,7 @@ impl BuiltIn for String {
     const NAME: &'static str = "String";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -76,7 +75,7 @@ impl BuiltIn for String {
 
         let symbol_iterator = context.well_known_symbols().iterator_symbol();
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::CONSTANT;
         let string_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -170,7 +169,7 @@ impl String {
 
         let length = DataDescriptor::new(
             Value::from(string.encode_utf16().count()),
-            Attribute::NON_ENUMERABLE,
+            Attribute::CONSTANT,
         );
         this.set_property("length", length);
 
@@ -237,8 +236,9 @@ impl String {
         // unicode code points
         // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of
         // bytes is an O(1) operation.
+        // A half of a surrogate pair can't be stored in a string, it becomes U+FFFD.
         if let Some(utf16_val) = primitive_val.encode_utf16().nth(pos as usize) {
-            Ok(Value::from(from_u32(utf16_val as u32).unwrap()))
+            Ok(Value::from(StdString::from_utf16_lossy(&[utf16_val])))
         } else {
             Ok("".into())
         }
@@ -453,42 +453,21 @@ impl String {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
-
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
-
-        if Self::is_regexp_object(&arg) {
-            context.throw_type_error(
-                "First argument to String.prototype.startsWith must not be a regular expression",
-            )?;
-        }
-
-        let search_string = arg.to_string(context)?;
+        let this = this.require_object_coercible(context)?;
+        let string = this.to_string(context)?;
 
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
+        let search_string = Self::search_string(
+            args.get(0),
+            "First argument to String.prototype.startsWith must not be a regular expression",
+            context,
+        )?;
 
-        // If less than 2 args specified, position is 'undefined', defaults to 0
-        let position = if args.len() < 2 {
-            0
-        } else {
-            args.get(1)
-                .expect("failed to get arg")
-                .to_integer(context)? as i32
-        };
+        let string: Vec<u16> = string.encode_utf16().collect();
+        let search_string: Vec<u16> = search_string.encode_utf16().collect();
 
-        let start = min(max(position, 0), length);
-        let end = start.wrapping_add(search_length);
+        let start = Self::clamp_position(args.get(1), 0, string.len(), context)?;
 
-        if end > length {
-            Ok(Value::from(false))
-        } else {
-            // Only use the part of the string from "start"
-            let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
-            Ok(Value::from(this_string.starts_with(search_string.as_str())))
-        }
+        Ok(Value::from(string[start..].starts_with(&search_string)))
     }
 
     /// `String.prototype.endsWith( searchString[, length] )`
@@ -502,43 +481,21 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.endswith
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/endsWith
     pub(crate) fn ends_with(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
-
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
-
-        if Self::is_regexp_object(&arg) {
-            context.throw_type_error(
-                "First argument to String.prototype.endsWith must not be a regular expression",
-            )?;
-        }
-
-        let search_string = arg.to_string(context)?;
+        let this = this.require_object_coercible(context)?;
+        let string = this.to_string(context)?;
 
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
+        let search_string = Self::search_string(
+            args.get(0),
+            "First argument to String.prototype.endsWith must not be a regular expression",
+            context,
+        )?;
 
-        // If less than 2 args specified, end_position is 'undefined', defaults to
-        // length of this
-        let end_position = if args.len() < 2 {
-            length
-        } else {
-            args.get(1)
-                .expect("Could not get argumetn")
-                .to_integer(context)? as i32
-        };
+        let string: Vec<u16> = string.encode_utf16().collect();
+        let search_string: Vec<u16> = search_string.encode_utf16().collect();
 
-        let end = min(max(end_position, 0), length);
-        let start = end.wrapping_sub(search_length);
+        let end = Self::clamp_position(args.get(1), string.len(), string.len(), context)?;
 
-        if start < 0 {
-            Ok(Value::from(false))
-        } else {
-            // Only use the part of the string up to "end"
-            let this_string: StdString = primitive_val.chars().take(end as usize).collect();
-            Ok(Value::from(this_string.ends_with(search_string.as_str())))
-        }
+        Ok(Value::from(string[..end].ends_with(&search_string)))
     }
 
     /// `String.prototype.includes( searchString[, position] )`
@@ -552,43 +509,79 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/includes
     pub(crate) fn includes(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
-
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let this = this.require_object_coercible(context)?;
+        let string = this.to_string(context)?;
 
-        if Self::is_regexp_object(&arg) {
-            context.throw_type_error(
-                "First argument to String.prototype.includes must not be a regular expression",
-            )?;
-        }
+        let search_string = Self::search_string(
+            args.get(0),
+            "First argument to String.prototype.includes must not be a regular expression",
+            context,
+        )?;
 
-        let search_string = arg.to_string(context)?;
+        let string: Vec<u16> = string.encode_utf16().collect();
+        let search_string: Vec<u16> = search_string.encode_utf16().collect();
 
-        let length = primitive_val.chars().count() as i32;
+        let start = Self::clamp_position(args.get(1), 0, string.len(), context)?;
 
-        // If less than 2 args specified, position is 'undefined', defaults to 0
-        let position = if args.len() < 2 {
-            0
-        } else {
-            args.get(1)
-                .expect("Could not get argument")
-                .to_integer(context)? as i32
-        };
+        if search_string.is_empty() {
+            return Ok(Value::from(true));
+        }
 
-        let start = min(max(position, 0), length);
+        Ok(Value::from(
+            string[start..]
+                .windows(search_string.len())
+                .any(|window| window == search_string.as_slice()),
+        ))
+    }
 
-        // Take the string from "this" and use only the part of it after "start"
-        let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
+    /// Converts the search value of `startsWith`, `endsWith` and `includes` to a string,
+    /// throwing a `TypeError` with the given message if it is a regular expression.
+    fn search_string(
+        value: Option<&Value>,
+        message: &str,
+        context: &mut Context,
+    ) -> Result<RcString> {
+        let value = value.cloned().unwrap_or_else(Value::undefined);
+        if RegExp::is_regexp(&value, context)? {
+            return Err(context.construct_type_error(message));
+        }
+        value.to_string(context)
+    }
 
-        Ok(Value::from(this_string.contains(search_string.as_str())))
+    /// Clamps a position argument to `0..=length` with `ToIntegerOrInfinity`, using `default`
+    /// when the argument is undefined.
+    fn clamp_position(
+        position: Option<&Value>,
+        default: usize,
+        length: usize,
+        context: &mut Context,
+    ) -> Result<usize> {
+        let position = match position {
+            Some(position) if !position.is_undefined() => position,
+            _ => return Ok(default),
+        };
+        Ok(match position.to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(position) if position < 0 => 0,
+            IntegerOrInfinity::Integer(position) => min(position as usize, length),
+            IntegerOrInfinity::NegativeInfinity => 0,
+            IntegerOrInfinity::PositiveInfinity => length,
+        })
     }
 
-    /// Return either the string itself or the string of the regex equivalent
+    /// Return either the string itself, escaped to be matched literally, or the string of the
+    /// regex equivalent
     fn get_regex_string(value: &Value) -> StdString {
         match value {
-            Value::String(ref body) => body.to_string(),
+            Value::String(ref body) => {
+                let mut escaped = StdString::with_capacity(body.len());
+                for c in body.chars() {
+                    if "^$\\.*+?()[]{}|".contains(c) {
+                        escaped.push('\\');
+                    }
+                    escaped.push(c);
+                }
+                escaped
+            }
             Value::Object(ref obj) => {
                 let obj = obj.borrow();
 
@@ -602,13 +595,6 @@ impl String {
         }
     }
 
-    fn is_regexp_object(value: &Value) -> bool {
-        match value {
-            Value::Object(ref obj) => obj.borrow().is_regexp(),
-            _ => false,
-        }
-    }
-
     /// `String.prototype.replace( regexp|substr, newSubstr|function )`
     ///
     /// The `replace()` method returns a new string with some or all matches of a `pattern` replaced by a `replacement`.
@@ -632,7 +618,9 @@ impl String {
         }
 
         let regex_body = Self::get_regex_string(args.get(0).expect("Value needed"));
-        let re = Regex::new(&regex_body).expect("unable to convert regex to regex object");
+        let re = Regex::new(&regex_body).map_err(|error| {
+            context.construct_syntax_error(format!("failed to create matcher: {}", error.text))
+        })?;
         let mat = match re.find(&primitive_val) {
             Some(mat) => mat,
             None => return Ok(Value::from(primitive_val)),
@@ -897,6 +885,9 @@ impl String {
         }
 
         let filter = fill_string.as_deref().unwrap_or(" ");
+        if filter.is_empty() {
+            return Ok(Value::from(primitive));
+        }
 
         let fill_len = max_length.wrapping_sub(primitive_length);
         let mut fill_str = StdString::new();
@@ -929,7 +920,7 @@ impl String {
     pub(crate) fn pad_end(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         let primitive = this.to_string(context)?;
         if args.is_empty() {
-            return Err(Value::from("padEnd requires maxLength argument"));
+            return context.throw_type_error("padEnd requires maxLength argument");
         }
         let max_length = args
             .get(0)
@@ -956,7 +947,7 @@ impl String {
     pub(crate) fn pad_start(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         let primitive = this.to_string(context)?;
         if args.is_empty() {
-            return Err(Value::from("padStart requires maxLength argument"));
+            return context.throw_type_error("padStart requires maxLength argument");
         }
         let max_length = args
             .get(0)
@@ -970,7 +961,7 @@ impl String {
 
     /// Helper function to check if a `char` is trimmable.
     #[inline]
-    fn is_trimmable_whitespace(c: char) -> bool {
+    pub(crate) fn is_trimmable_whitespace(c: char) -> bool {
         // The rust implementation of `trim` does not regard the same characters whitespace as ecma standard does
         //
         // Rust uses \p{White_Space} by default, which also includes:
@@ -1129,15 +1120,14 @@ impl String {
         let from = min(final_start, final_end) as usize;
         let to = max(final_start, final_end) as usize;
         // Extract the part of the string contained between the start index and the end index
-        // where start is guaranteed to be smaller or equals to end
-        let extracted_string: std::result::Result<StdString, _> = decode_utf16(
-            primitive_val
-                .encode_utf16()
-                .skip(from)
-                .take(to.wrapping_sub(from)),
-        )
-        .collect();
-        Ok(Value::from(extracted_string.expect("Invalid string")))
+        // where start is guaranteed to be smaller or equals to end. The halves of the surrogate
+        // pairs cut by the bounds become U+FFFD.
+        let extracted_string: Vec<u16> = primitive_val
+            .encode_utf16()
+            .skip(from)
+            .take(to.wrapping_sub(from))
+            .collect();
+        Ok(Value::from(StdString::from_utf16_lossy(&extracted_string)))
     }
 
     /// `String.prototype.substr( start[, length] )`
@@ -1230,9 +1220,7 @@ impl String {
                         ];
                         splitter.call(this, arguments, context)
                     }),
-                    Err(_) => Some(Err(
-                        context.construct_type_error("separator[Symbol.split] is not a function")
-                    )),
+                    Err(error) => Some(Err(error)),
                 }
             })
         {
@@ -1326,7 +1314,17 @@ impl String {
         RegExp::match_all(&re, this.to_string(context)?.to_string(), context)
     }
 
+    /// `String.prototype[ @@iterator ]( )`
+    ///
+    /// Returns an iterator over the code points of the string, which keeps surrogate pairs
+    /// together.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype-@@iterator
     pub(crate) fn iterator(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
-        StringIterator::create_string_iterator(context, this.clone())
+        let string = this.require_object_coercible(context)?.to_string(context)?;
+        StringIterator::create_string_iterator(context, string.into())
     }
 }
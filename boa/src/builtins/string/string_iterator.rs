@@ -1,10 +1,9 @@
 use crate::{
-    builtins::{
-        function::make_builtin_fn, iterable::create_iter_result_object, string::code_point_at,
-    },
+    builtins::{iterable::create_iter_result_object, string::code_point_at},
     gc::{Finalize, Trace},
-    object::{GcObject, ObjectData},
-    property::{Attribute, DataDescriptor},
+    object::{GcObject, ObjectData, ObjectInitializer},
+    property::Attribute,
+    snapshot::HeapCopier,
     BoaProfiler, Context, Result, Value,
 };
 
@@ -22,6 +21,14 @@ impl StringIterator {
         }
     }
 
+    /// Copies the iterator for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            string: copier.copy_value(&self.string),
+            ..self.clone()
+        }
+    }
+
     pub fn create_string_iterator(context: &mut Context, string: Value) -> Result<Value> {
         let string_iterator = Value::new_object(context);
         string_iterator.set_data(ObjectData::StringIterator(Self::new(string)));
@@ -73,14 +80,16 @@ impl StringIterator {
         let _timer = BoaProfiler::global().start_event("String Iterator", "init");
 
         // Create prototype
-        let mut array_iterator = context.construct_object();
-        make_builtin_fn(Self::next, "next", &array_iterator, 0, context);
-        array_iterator.set_prototype_instance(iterator_prototype);
-
         let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
-        let to_string_tag_property =
-            DataDescriptor::new("String Iterator", Attribute::CONFIGURABLE);
-        array_iterator.insert(to_string_tag, to_string_tag_property);
-        array_iterator
+        let mut string_iterator = ObjectInitializer::new(context)
+            .function(Self::next, "next", 0)
+            .property(
+                to_string_tag,
+                "String Iterator",
+                Attribute::BUILTIN_READONLY,
+            )
+            .build();
+        string_iterator.set_prototype_instance(iterator_prototype);
+        string_iterator
     }
 }
@@ -82,6 +82,24 @@ fn concat() {
     assert_eq!(b, "\"Hello, world! Have a nice day.\"");
 }
 
+#[test]
+fn concat_in_loop() {
+    let mut context = Context::new();
+    let init = r#"
+        var s = "";
+        for (var i = 0; i < 1000; i++) {
+            s += "ab";
+        }
+        var t = "ab".repeat(1000);
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "s.length"), "2000");
+    assert_eq!(forward(&mut context, "s === t"), "true");
+    assert_eq!(forward(&mut context, "s.charAt(1999)"), "\"b\"");
+    assert_eq!(forward(&mut context, "(s + 'c').slice(-3)"), "\"abc\"");
+}
+
 #[test]
 fn generic_concat() {
     let mut context = Context::new();
@@ -112,6 +130,15 @@ fn construct_and_call() {
 
     assert_eq!(hello.is_object(), true);
     assert_eq!(world.is_string(), true);
+
+    assert_eq!(forward(&mut context, "String()"), "\"\"");
+    assert_eq!(forward(&mut context, "String(undefined)"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "new String().valueOf()"), "\"\"");
+    assert_eq!(forward(&mut context, "String(12n)"), "\"12\"");
+    assert_eq!(
+        forward(&mut context, "String(Symbol('a'))"),
+        "\"Symbol(a)\""
+    );
 }
 
 #[test]
@@ -444,6 +471,115 @@ fn includes_with_regex_arg() {
     )
 }
 
+#[test]
+fn search_methods_with_is_regexp_arg() {
+    let mut context = Context::new();
+    let init = r#"
+        var matcher = { toString: function() { return 'day'; } };
+        matcher[Symbol.match] = true;
+        var re = /day/;
+        re[Symbol.match] = false;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'Saturday'.startsWith(matcher) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'Saturday'.endsWith(matcher) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'Saturday'.includes(matcher) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "matcher[Symbol.match] = 0; 'Saturday'.endsWith(matcher)"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "'/day/'.endsWith(re)"), "true");
+    assert_eq!(forward(&mut context, "'a/day/b'.includes(re)"), "true");
+}
+
+#[test]
+fn search_methods_positions() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'abc'.startsWith('bc', 1)"), "true");
+    assert_eq!(forward(&mut context, "'abc'.startsWith('a', -5)"), "true");
+    assert_eq!(forward(&mut context, "'abc'.startsWith('a', NaN)"), "true");
+    assert_eq!(
+        forward(&mut context, "'abc'.startsWith('', Infinity)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.startsWith('c', Infinity)"),
+        "false"
+    );
+
+    assert_eq!(forward(&mut context, "'abc'.endsWith('ab', 2)"), "true");
+    assert_eq!(
+        forward(&mut context, "'abc'.endsWith('c', undefined)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.endsWith('c', Infinity)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "'abc'.endsWith('a', NaN)"), "false");
+    assert_eq!(
+        forward(&mut context, "'abc'.endsWith('', -Infinity)"),
+        "true"
+    );
+
+    assert_eq!(forward(&mut context, "'abc'.includes('a', 1)"), "false");
+    assert_eq!(
+        forward(&mut context, "'abc'.includes('a', -Infinity)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "'abc'.includes('', 10)"), "true");
+
+    // positions count UTF-16 code units
+    assert_eq!(
+        forward(&mut context, "'\u{1F600}a'.startsWith('a', 2)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'\u{1F600}a'.endsWith('\u{1F600}', 2)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'\u{1F600}a'.includes('a', 3)"),
+        "false"
+    );
+}
+
+#[test]
+fn search_methods_require_object_coercible() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype.includes.call(null, 'a') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
 #[test]
 fn match_all() {
     let mut context = Context::new();
@@ -1093,3 +1229,128 @@ fn unicode_iter() {
     assert_eq!(forward(&mut context, "next.value"), "undefined");
     assert_eq!(forward(&mut context, "next.done"), "true");
 }
+
+#[test]
+fn string_wrapper_object() {
+    let mut context = Context::new();
+    let init = r#"
+        let s = new String("ab");
+        s[0] = "z";
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof s"), "\"object\"");
+    assert_eq!(forward(&mut context, "s.valueOf()"), "\"ab\"");
+    assert_eq!(forward(&mut context, "s == 'ab'"), "true");
+    assert_eq!(forward(&mut context, "s === 'ab'"), "false");
+    assert_eq!(forward(&mut context, "s[0]"), "\"a\"");
+    assert_eq!(forward(&mut context, "s[2]"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(s, 1).enumerable"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(s, 1).writable"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.defineProperty(s, 0, { value: 'x' })"),
+        "Uncaught \"TypeError\": \"Cannot redefine property: 0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(s, 0, { value: 'a' }) === s"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(s, 'length').writable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn string_primitive_property_access() {
+    let mut context = Context::new();
+    let init = r#"
+        String.prototype.thisType = function() { return typeof this; };
+        Object.defineProperty(String.prototype, "receiver", {
+            get: function() { return this; }
+        });
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "'abc'[1]"), "\"b\"");
+    assert_eq!(forward(&mut context, "'abc'.length"), "3");
+    assert_eq!(forward(&mut context, "'abc'.thisType()"), "\"object\"");
+    assert_eq!(forward(&mut context, "'abc'['thisType']()"), "\"object\"");
+    assert_eq!(forward(&mut context, "'abc'.toUpperCase()"), "\"ABC\"");
+    assert_eq!(forward(&mut context, "typeof 'abc'.receiver"), "\"object\"");
+}
+
+#[test]
+fn string_exotic_index_access() {
+    let mut context = Context::new();
+    let init = r#"
+        var astral = "a\u{1F600}b";
+        var s = new String("ab");
+        s.x = 1;
+        s[5] = 2;
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "'abc'['1']"), "\"b\"");
+    assert_eq!(forward(&mut context, "'abc'[3]"), "undefined");
+    assert_eq!(forward(&mut context, "'abc'[-1]"), "undefined");
+    assert_eq!(forward(&mut context, "'abc'[1.5]"), "undefined");
+    assert_eq!(forward(&mut context, "astral.length"), "4");
+    assert_eq!(forward(&mut context, "astral[3]"), "\"b\"");
+    assert_eq!(forward(&mut context, "astral[4]"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.keys(s).join()"),
+        "\"0,1,5,x\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(s).join()"),
+        "\"0,1,5,length,x\""
+    );
+    assert_eq!(forward(&mut context, "1 in s"), "true");
+    assert_eq!(forward(&mut context, "2 in s"), "false");
+}
+
+#[test]
+fn string_iteration_by_code_point() {
+    let mut context = Context::new();
+    let init = r#"
+        var astral = "a\u{1F600}b";
+        var chars = [];
+        for (var c of astral) {
+            chars.push(c);
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "chars.length"), "3");
+    assert_eq!(forward(&mut context, "chars[1] === '\\u{1F600}'"), "true");
+    assert_eq!(forward(&mut context, "[...astral].length"), "3");
+    assert_eq!(forward(&mut context, "[...astral][2]"), "\"b\"");
+    assert_eq!(forward(&mut context, "Array.from(astral).length"), "3");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(new String(astral))[1] === '\\u{1F600}'"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "[...''].length"), "0");
+}
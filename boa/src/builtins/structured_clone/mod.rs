@@ -0,0 +1,221 @@
+//! This module implements the global `structuredClone` function.
+//!
+//! The `structuredClone` function makes a deep copy of a value with the structured clone
+//! algorithm of the HTML standard, as browsers and Node do. Objects reached twice are copied
+//! once, so the copy has the same shared references and cycles as the original.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+use crate::{
+    builtins::{map::ordered_map::OrderedMap, Array, BuiltIn, Map},
+    object::{FunctionBuilder, GcObject, Object, ObjectData},
+    property::{Attribute, DataDescriptor, PropertyKey},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::GcCell;
+use rustc_hash::FxHashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// The global `structuredClone` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StructuredClone;
+
+impl BuiltIn for StructuredClone {
+    const NAME: &'static str = "structuredClone";
+
+    fn attribute() -> Attribute {
+        Attribute::BUILTIN
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function = FunctionBuilder::new(context, Self::structured_clone)
+            .name(Self::NAME)
+            .length(1)
+            .build();
+
+        (Self::NAME, function.into(), Self::attribute())
+    }
+}
+
+impl StructuredClone {
+    /// `structuredClone( value[, options] )`
+    ///
+    /// Returns a deep copy of the value. Functions, symbols and the objects the algorithm does
+    /// not know how to copy throw a `TypeError`, which stands for the `DataCloneError` of the
+    /// browsers. Transferring objects with the `transfer` option is not supported.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+    pub(crate) fn structured_clone(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if let Some(options) = args.get(1).filter(|options| options.is_object()) {
+            if !options.get_field("transfer", context)?.is_undefined() {
+                return context
+                    .throw_type_error("structuredClone: the transfer option is not supported");
+            }
+        }
+
+        let mut cloner = Cloner::default();
+        let clone = cloner.clone_value(args.get(0).unwrap_or(&Value::undefined()), context)?;
+        cloner.finish(context)?;
+        Ok(clone)
+    }
+}
+
+/// Clones a graph of values, preserving the references between its objects.
+///
+/// Like the [`HeapCopier`](crate::snapshot::HeapCopier) of the snapshots, it records the clone
+/// of an object before copying its contents, so that every object is cloned once and long chains
+/// of objects are not cloned recursively. Unlike it, the clones get the intrinsic prototypes of
+/// the context, and only the data the structured clone algorithm knows about is copied.
+#[derive(Debug, Default)]
+struct Cloner {
+    /// The clones of the objects, by their address, with the objects themselves.
+    ///
+    /// The getters run while cloning can start a collection, so the map keeps the objects alive:
+    /// otherwise a collected object could be freed and its address reused by another one.
+    clones: FxHashMap<*const GcCell<Object>, (GcObject, GcObject)>,
+    /// The objects whose contents have not been copied yet, with their clones.
+    pending: Vec<(GcObject, GcObject)>,
+}
+
+impl Cloner {
+    /// Returns the clone of `value`.
+    ///
+    /// Primitive values are immutable, so they are not copied.
+    fn clone_value(&mut self, value: &Value, context: &mut Context) -> Result<Value> {
+        match value {
+            Value::Object(object) => self.clone_object(object, context).map(Value::from),
+            Value::Symbol(_) => Err(data_clone_error("symbols", context)),
+            _ => Ok(value.clone()),
+        }
+    }
+
+    /// Returns the clone of `object`, which is empty until [`finish`](Self::finish) copies
+    /// its properties or its entries.
+    fn clone_object(&mut self, object: &GcObject, context: &mut Context) -> Result<GcObject> {
+        let address = object.as_ref() as *const GcCell<Object>;
+        if let Some((_, clone)) = self.clones.get(&address) {
+            return Ok(clone.clone());
+        }
+
+        let (clone, has_contents) = match object.borrow().data {
+            ObjectData::Ordinary => (context.construct_object(), true),
+            ObjectData::Array => {
+                let array = Array::new_array(context)?;
+                (array.as_object().expect("arrays are objects"), true)
+            }
+            ObjectData::Map(_) => {
+                let prototype = context.standard_objects().map_object().prototype();
+                let map =
+                    Object::with_prototype(prototype.into(), ObjectData::Map(OrderedMap::new()));
+                (GcObject::new(map), true)
+            }
+            ObjectData::Boolean(boolean) => (Value::from(boolean).to_object(context)?, false),
+            ObjectData::Number(number) => (Value::from(number).to_object(context)?, false),
+            ObjectData::String(ref string) => {
+                (Value::from(string.clone()).to_object(context)?, false)
+            }
+            ObjectData::BigInt(ref bigint) => {
+                (Value::from(bigint.clone()).to_object(context)?, false)
+            }
+            ObjectData::Date(date) => {
+                let prototype = context.standard_objects().date_object().prototype();
+                let date = Object::with_prototype(prototype.into(), ObjectData::Date(date));
+                (GcObject::new(date), false)
+            }
+            ObjectData::RegExp(ref regexp) => {
+                let prototype = context.standard_objects().regexp_object().prototype();
+                let mut regexp =
+                    Object::with_prototype(prototype.into(), ObjectData::RegExp(regexp.clone()));
+                regexp.insert_property(
+                    "lastIndex",
+                    0,
+                    Attribute::WRITABLE | Attribute::NON_ENUMERABLE,
+                );
+                (GcObject::new(regexp), false)
+            }
+            ObjectData::Function(_) => return Err(data_clone_error("functions", context)),
+            ref data => {
+                return Err(data_clone_error(&format!("{} objects", data), context));
+            }
+        };
+
+        if object.is_array() {
+            // The clone has the length of the array, even when its last elements are holes.
+            let length = object.get(&"length".into(), object.clone().into(), context)?;
+            Value::from(clone.clone()).set_field("length", length, context)?;
+        }
+
+        self.clones.insert(address, (object.clone(), clone.clone()));
+        if has_contents {
+            self.pending.push((object.clone(), clone.clone()));
+        }
+        Ok(clone)
+    }
+
+    /// Copies the contents of all the objects cloned so far.
+    ///
+    /// The entries of the maps are copied, and the enumerable string-keyed properties of the
+    /// arrays and the ordinary objects, whose getters are called.
+    fn finish(&mut self, context: &mut Context) -> Result<()> {
+        while let Some((object, clone)) = self.pending.pop() {
+            let entries = object.borrow().as_map_ref().map(|map| {
+                map.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect::<Vec<_>>()
+            });
+            if let Some(entries) = entries {
+                for (key, value) in entries {
+                    let key = self.clone_value(&key, context)?;
+                    let value = self.clone_value(&value, context)?;
+                    if let Some(map) = clone.borrow_mut().as_map_mut() {
+                        map.insert(key, value);
+                    }
+                }
+                let size = clone.borrow().as_map_ref().map_or(0, |map| map.len());
+                Map::set_size(&clone.into(), size);
+                continue;
+            }
+
+            for key in object.own_property_keys() {
+                if let PropertyKey::Symbol(_) = key {
+                    continue;
+                }
+                // The getters of the properties before it may have removed it.
+                let enumerable = object
+                    .get_own_property(&key)
+                    .map_or(false, |desc| desc.enumerable());
+                if !enumerable {
+                    continue;
+                }
+                let value = object.get(&key, object.clone().into(), context)?;
+                let value = self.clone_value(&value, context)?;
+                Value::from(clone.clone())
+                    .set_property(key, DataDescriptor::new(value, Attribute::all()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates the `TypeError` that stands for the `DataCloneError` of the browsers.
+fn data_clone_error(values: &str, context: &mut Context) -> Value {
+    context.construct_type_error(format!("DataCloneError: {} can't be cloned", values))
+}
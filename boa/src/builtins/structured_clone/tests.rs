@@ -0,0 +1,125 @@
+use crate::{forward, Context};
+
+#[test]
+fn clones_values() {
+    let mut context = Context::new();
+
+    for (source, expected) in &[
+        ("structuredClone(1.5)", "1.5"),
+        ("structuredClone('a')", "\"a\""),
+        ("structuredClone(true)", "true"),
+        ("structuredClone(null)", "null"),
+        ("structuredClone()", "undefined"),
+        ("structuredClone(10n)", "10n"),
+        (
+            "JSON.stringify(structuredClone({ a: 1, b: [2, { c: 'd' }] }))",
+            "\"{\\\"a\\\":1,\\\"b\\\":[2,{\\\"c\\\":\\\"d\\\"}]}\"",
+        ),
+        ("structuredClone([1, , 3]).length", "3"),
+        ("1 in structuredClone([1, , 3])", "false"),
+        ("Array.isArray(structuredClone([]))", "true"),
+        ("structuredClone(new Date(5)).getTime()", "5"),
+        ("structuredClone(/a+/gi).source", "\"a+\""),
+        ("structuredClone(/a+/gi).flags", "\"gi\""),
+        ("structuredClone(new Map([[1, 'a']])).get(1)", "\"a\""),
+        ("structuredClone(new Map([[1, 'a']])).size", "1"),
+        ("typeof structuredClone(new Number(1))", "\"object\""),
+        ("structuredClone(new String('ab')).length", "2"),
+        ("structuredClone(new Boolean(false)).valueOf()", "false"),
+    ] {
+        assert_eq!(&forward(&mut context, source), expected, "{}", source);
+    }
+}
+
+#[test]
+fn copies_are_independent() {
+    let mut context = Context::new();
+    let init = r#"
+        var original = { nested: { a: 1 }, list: [1], map: new Map() };
+        var copy = structuredClone(original);
+        copy.nested.a = 2;
+        copy.list.push(2);
+        copy.map.set('key', 'value');
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "original.nested.a"), "1");
+    assert_eq!(forward(&mut context, "original.list.length"), "1");
+    assert_eq!(forward(&mut context, "original.map.size"), "0");
+    assert_eq!(forward(&mut context, "copy === original"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(copy) === Object.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn shared_references_and_cycles() {
+    let mut context = Context::new();
+    let init = r#"
+        var child = { value: 1 };
+        var original = { first: child, second: child, list: [child] };
+        original.self = original;
+        var map = new Map([[child, child]]);
+        map.set(map, original);
+        var copy = structuredClone(original);
+        var mapCopy = structuredClone(map);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "copy.first === copy.second"), "true");
+    assert_eq!(forward(&mut context, "copy.first === copy.list[0]"), "true");
+    assert_eq!(forward(&mut context, "copy.first === child"), "false");
+    assert_eq!(forward(&mut context, "copy.self === copy"), "true");
+
+    assert_eq!(
+        forward(&mut context, "mapCopy.get(mapCopy).self.first.value"),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(mapCopy.keys())[0] === Array.from(mapCopy.values())[0]"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn uncloneable_values() {
+    let mut context = Context::new();
+
+    for source in &[
+        "structuredClone(function () {})",
+        "structuredClone(() => 1)",
+        "structuredClone(Symbol())",
+        "structuredClone({ a: [Symbol('a')] })",
+        "structuredClone(new Map([[1, Math.max]]))",
+        "structuredClone(Object(Symbol()))",
+        "structuredClone(new Error('e'))",
+        "structuredClone(globalThis)",
+        "structuredClone([].values())",
+        "structuredClone({}, { transfer: [] })",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {}; }} catch (e) {{ e.name }}", source)
+            ),
+            "\"TypeError\"",
+            "{} did not throw",
+            source
+        );
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { structuredClone({ f() {} }) } catch (e) { e.message }"
+        ),
+        "\"DataCloneError: functions can't be cloned\""
+    );
+}
@@ -237,7 +237,7 @@ impl BuiltIn for Symbol {
     const NAME: &'static str = "Symbol";
 
     fn attribute() -> Attribute {
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+        Attribute::BUILTIN
     }
 
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
@@ -260,7 +260,7 @@ impl BuiltIn for Symbol {
 
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::CONSTANT;
         let symbol_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -281,9 +281,11 @@ impl BuiltIn for Symbol {
         .static_property("toPrimitive", symbol_to_primitive, attribute)
         .static_property("toStringTag", symbol_to_string_tag, attribute)
         .static_property("unscopables", symbol_unscopables, attribute)
+        .static_method(Self::for_, "for", 1)
+        .static_method(Self::key_for, "keyFor", 1)
         .method(Self::to_string, "toString", 0)
         .callable(true)
-        .constructable(false)
+        .constructable(true)
         .build();
 
         (Self::NAME, symbol_object.into(), Self::attribute())
@@ -320,7 +322,8 @@ impl Symbol {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        if new_target.is_undefined() {
+        // `new_target` is only defined when `Symbol` is called with `new`, which is not allowed.
+        if !new_target.is_undefined() {
             return context.throw_type_error("Symbol is not a constructor");
         }
         let description = match args.get(0) {
@@ -362,4 +365,44 @@ impl Symbol {
         let description = symbol.description().unwrap_or("");
         Ok(Value::from(format!("Symbol({})", description)))
     }
+
+    /// `Symbol.for( key )`
+    ///
+    /// This method returns the symbol of the global symbol registry with the given key, which is
+    /// created and registered if there is none.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for
+    pub(crate) fn for_(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let key = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        Ok(context.registered_symbol(key).into())
+    }
+
+    /// `Symbol.keyFor( sym )`
+    ///
+    /// This method returns the key of a symbol of the global symbol registry, or `undefined` if
+    /// the symbol is not registered.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.keyfor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
+    pub(crate) fn key_for(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        match args.get(0) {
+            Some(Value::Symbol(symbol)) => Ok(context
+                .symbol_registry_key(symbol)
+                .map_or_else(Value::undefined, Value::from)),
+            _ => context.throw_type_error("Symbol.keyFor: argument is not a symbol"),
+        }
+    }
 }
@@ -37,3 +37,92 @@ fn symbol_access() {
     assert_eq!(forward(&mut context, "x[sym2]"), "20");
     assert_eq!(forward(&mut context, "x['Symbol(Hello)']"), "undefined");
 }
+
+#[test]
+fn call_and_construct() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "typeof Symbol('x')"), "\"symbol\"");
+    assert_eq!(
+        forward(&mut context, "typeof Symbol.call({})"),
+        "\"symbol\""
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol(undefined).toString()"),
+        "\"Symbol()\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Symbol('x')"),
+        "Uncaught \"TypeError\": \"Symbol is not a constructor\""
+    );
+}
+
+#[test]
+fn string_conversions() {
+    let mut context = Context::new();
+    forward(&mut context, "var sym = Symbol('desc');");
+
+    // `String` is the only conversion to a string that accepts symbols.
+    assert_eq!(forward(&mut context, "String(sym)"), "\"Symbol(desc)\"");
+    assert_eq!(forward(&mut context, "String(Symbol())"), "\"Symbol()\"");
+    for conversion in &[
+        "`${sym}`",
+        "'' + sym",
+        "sym + ''",
+        "new String(sym)",
+        "'a'.concat(sym)",
+        "[sym].join()",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {}; }} catch (e) {{ e.name }}", conversion)
+            ),
+            "\"TypeError\"",
+            "{} did not throw",
+            conversion
+        );
+    }
+}
+
+#[test]
+fn global_symbol_registry() {
+    let mut context = Context::new();
+    let init = r#"
+        var registered = Symbol.for('x');
+        var plain = Symbol('x');
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "registered === Symbol.for('x')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "registered === plain"), "false");
+    assert_eq!(
+        forward(&mut context, "registered === Symbol.for('y')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "registered.toString()"),
+        "\"Symbol(x)\""
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.for() === Symbol.for('undefined')"),
+        "true"
+    );
+
+    assert_eq!(forward(&mut context, "Symbol.keyFor(registered)"), "\"x\"");
+    assert_eq!(forward(&mut context, "Symbol.keyFor(plain)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Symbol.keyFor(Symbol.iterator)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Symbol.keyFor('x') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,358 @@
+//! This module implements the scheduling functions of the HTML standard: `queueMicrotask`,
+//! `setTimeout`, `setInterval`, `clearTimeout` and `clearInterval`.
+//!
+//! The engine has no event loop, so the timers are handed to a [`TimerScheduler`] set by the host
+//! with [`Context::set_timer_scheduler`]. Without one, they wait in a queue of the context, which
+//! [`Context::run_jobs_until_idle`] runs in the order of their due time.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timers
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/setTimeout
+
+use super::{function::make_builtin_fn, number::f64_to_int32};
+use crate::{
+    builtins::BuiltIn, object::FunctionBuilder, property::Attribute, snapshot::HeapCopier,
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// The identifier of a timer, which `setTimeout` and `setInterval` return to the script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId(u32);
+
+impl TimerId {
+    /// Creates the identifier of a timer.
+    ///
+    /// The scripts pass the identifiers back to `clearTimeout` and `clearInterval`, which convert
+    /// them to 32-bit signed integers, so they should be positive and at most `i32::MAX`.
+    #[inline]
+    pub fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Gets the number the script sees.
+    #[inline]
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// The callback of a timer, with the arguments it is called with.
+#[derive(Debug, Clone)]
+pub struct TimerJob {
+    callback: Value,
+    args: Vec<Value>,
+    interval: Option<f64>,
+}
+
+impl TimerJob {
+    /// Gets the number of milliseconds between the runs of the job, if it was scheduled by
+    /// `setInterval`.
+    ///
+    /// A repeating job is run again every `interval` milliseconds until its timer is cancelled.
+    #[inline]
+    pub fn interval(&self) -> Option<f64> {
+        self.interval
+    }
+
+    /// Calls the callback of the timer.
+    ///
+    /// Returns the error thrown by the callback, if any. The microtasks it queues are left for
+    /// [`Context::run_jobs`].
+    pub fn run(&self, context: &mut Context) -> Result<()> {
+        let global: Value = context.global_object().clone().into();
+        context.call(&self.callback, &global, &self.args)?;
+        Ok(())
+    }
+
+    /// Copies the job for a [`Snapshot`](crate::snapshot::Snapshot).
+    fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            callback: copier.copy_value(&self.callback),
+            args: self.args.iter().map(|arg| copier.copy_value(arg)).collect(),
+            interval: self.interval,
+        }
+    }
+}
+
+/// A host hook that runs the timers of `setTimeout` and `setInterval`.
+///
+/// Register a scheduler with [`Context::set_timer_scheduler`]. It is responsible for calling
+/// [`TimerJob::run`] when a timer is due, and for running the repeating jobs again.
+pub trait TimerScheduler {
+    /// Schedules `job` to run in `delay_ms` milliseconds, returning the identifier of its timer.
+    fn schedule(&mut self, delay_ms: f64, job: TimerJob) -> TimerId;
+
+    /// Cancels the timer, so that its job does not run anymore.
+    ///
+    /// The identifier comes from the script, so it may name no timer, or a timer that has
+    /// already run.
+    fn cancel(&mut self, id: TimerId);
+}
+
+/// A timer waiting in the queue of a context.
+#[derive(Debug)]
+struct Timer {
+    id: TimerId,
+    due: f64,
+    /// The order in which the timers were scheduled, which breaks the ties of the due times.
+    sequence: u64,
+    job: TimerJob,
+}
+
+/// The timers of a context, which are either passed to the scheduler of the host or queued.
+#[derive(Default)]
+pub(crate) struct Timers {
+    scheduler: Option<Box<dyn TimerScheduler>>,
+    queue: Vec<Timer>,
+    /// The time of the queue, which jumps to the due time of every timer that runs.
+    time: f64,
+    /// The timer whose job is running, unless it was cancelled by its own job.
+    running: Option<TimerId>,
+    last_id: u32,
+    last_sequence: u64,
+}
+
+impl Timers {
+    /// Replaces the scheduler of the host, which takes over the timers scheduled from now on.
+    #[inline]
+    pub(crate) fn set_scheduler(&mut self, scheduler: Box<dyn TimerScheduler>) {
+        self.scheduler = Some(scheduler);
+    }
+
+    /// Schedules `job` to run in `delay` milliseconds.
+    ///
+    /// The queued timers are due `delay` milliseconds after the current time of the clock, or of
+    /// the queue if it is later. Without a clock, only the time of the queue is used.
+    pub(crate) fn schedule(&mut self, now: Option<f64>, delay: f64, job: TimerJob) -> TimerId {
+        if let Some(ref mut scheduler) = self.scheduler {
+            return scheduler.schedule(delay, job);
+        }
+
+        self.last_id += 1;
+        let id = TimerId(self.last_id);
+        let due = now.map_or(self.time, |now| now.max(self.time)) + delay;
+        self.push(id, due, job);
+        id
+    }
+
+    /// Cancels a timer.
+    pub(crate) fn cancel(&mut self, id: TimerId) {
+        if let Some(ref mut scheduler) = self.scheduler {
+            scheduler.cancel(id);
+            return;
+        }
+
+        self.queue.retain(|timer| timer.id != id);
+        if self.running == Some(id) {
+            self.running = None;
+        }
+    }
+
+    /// Removes the queued timer that is due first, and advances the time of the queue to it.
+    pub(crate) fn pop(&mut self) -> Option<(TimerId, TimerJob)> {
+        let index = self
+            .queue
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                a.due
+                    .partial_cmp(&b.due)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(a.sequence.cmp(&b.sequence))
+            })
+            .map(|(index, _)| index)?;
+        let timer = self.queue.remove(index);
+        self.time = self.time.max(timer.due);
+        self.running = Some(timer.id);
+        Some((timer.id, timer.job))
+    }
+
+    /// Queues a repeating job again after it has run, unless its job cancelled its timer.
+    pub(crate) fn finish(&mut self, id: TimerId, job: TimerJob) {
+        if self.running.take() != Some(id) {
+            return;
+        }
+        if let Some(interval) = job.interval {
+            let due = self.time + interval;
+            self.push(id, due, job);
+        }
+    }
+
+    fn push(&mut self, id: TimerId, due: f64, job: TimerJob) {
+        self.last_sequence += 1;
+        self.queue.push(Timer {
+            id,
+            due,
+            sequence: self.last_sequence,
+            job,
+        });
+    }
+
+    /// Copies the queued timers for a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// The scheduler of the host is not copied, like the other host hooks.
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            scheduler: None,
+            queue: self
+                .queue
+                .iter()
+                .map(|timer| Timer {
+                    job: timer.job.copy_with(copier),
+                    ..*timer
+                })
+                .collect(),
+            time: self.time,
+            running: None,
+            last_id: self.last_id,
+            last_sequence: self.last_sequence,
+        }
+    }
+}
+
+impl fmt::Debug for Timers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timers")
+            .field("queue", &self.queue)
+            .field("time", &self.time)
+            .finish()
+    }
+}
+
+/// The global scheduling functions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scheduling;
+
+impl BuiltIn for Scheduling {
+    const NAME: &'static str = "queueMicrotask";
+
+    fn attribute() -> Attribute {
+        Attribute::BUILTIN
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let global = context.global_object().clone();
+        make_builtin_fn(Self::set_timeout, "setTimeout", &global, 2, context);
+        make_builtin_fn(Self::set_interval, "setInterval", &global, 2, context);
+        make_builtin_fn(Self::clear_timer, "clearTimeout", &global, 1, context);
+        make_builtin_fn(Self::clear_timer, "clearInterval", &global, 1, context);
+
+        let queue_microtask = FunctionBuilder::new(context, Self::queue_microtask)
+            .name(Self::NAME)
+            .length(1)
+            .build();
+        (Self::NAME, queue_microtask.into(), Self::attribute())
+    }
+}
+
+impl Scheduling {
+    /// `queueMicrotask( callback )`
+    ///
+    /// Queues a call of the callback in the job queue, which runs after the current script.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-queuemicrotask
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/queueMicrotask
+    pub(crate) fn queue_microtask(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return context.throw_type_error("queueMicrotask: the callback is not a function");
+        }
+        context.enqueue_job(callback, Vec::new());
+        Ok(Value::undefined())
+    }
+
+    /// `setTimeout( callback[, delay[, ...args]] )`
+    ///
+    /// Schedules a call of the callback with the extra arguments in `delay` milliseconds, and
+    /// returns the identifier of the timer.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-settimeout
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/setTimeout
+    pub(crate) fn set_timeout(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_timer("setTimeout", args, false, context)
+    }
+
+    /// `setInterval( callback[, delay[, ...args]] )`
+    ///
+    /// Schedules calls of the callback with the extra arguments every `delay` milliseconds, and
+    /// returns the identifier of the timer.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-setinterval
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/setInterval
+    pub(crate) fn set_interval(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_timer("setInterval", args, true, context)
+    }
+
+    /// The timer initialization steps shared by `setTimeout` and `setInterval`.
+    ///
+    /// The callback must be a function, since the engine does not evaluate strings of code. The
+    /// delay is converted like a WebIDL `long`, and the negative delays are `0`.
+    fn set_timer(name: &str, args: &[Value], repeat: bool, context: &mut Context) -> Result<Value> {
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return context.throw_type_error(format!("{}: the callback is not a function", name));
+        }
+        let delay = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_number(context)?;
+        let delay = f64::from(f64_to_int32(delay).max(0));
+
+        let job = TimerJob {
+            callback,
+            args: args.get(2..).unwrap_or_default().to_vec(),
+            interval: if repeat { Some(delay) } else { None },
+        };
+        Ok(context.schedule_timer(delay, job).get().into())
+    }
+
+    /// `clearTimeout( id )` and `clearInterval( id )`
+    ///
+    /// Cancels the timer with the given identifier. Both functions cancel the timers of both
+    /// `setTimeout` and `setInterval`, and do nothing for the numbers that name no timer.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-cleartimeout
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/clearTimeout
+    pub(crate) fn clear_timer(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let id = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_number(context)?;
+        let id = f64_to_int32(id);
+        if id > 0 {
+            context.cancel_timer(TimerId(id as u32));
+        }
+        Ok(Value::undefined())
+    }
+}
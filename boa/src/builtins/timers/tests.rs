@@ -0,0 +1,163 @@
+use super::{TimerId, TimerJob, TimerScheduler};
+use crate::{forward, Context};
+use std::{cell::RefCell, rc::Rc};
+
+/// Creates a context whose clock is stopped, so that the timers only advance the time of the
+/// queue.
+fn context() -> Context {
+    let mut context = Context::new();
+    context.set_clock(|| 1000.0);
+    forward(&mut context, "var log = [];");
+    context
+}
+
+#[test]
+fn queue_microtask() {
+    let mut context = context();
+    forward(
+        &mut context,
+        "queueMicrotask(() => log.push('first')); queueMicrotask(() => log.push('second'));",
+    );
+    assert_eq!(forward(&mut context, "log.length"), "0");
+
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"first,second\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { queueMicrotask(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn timeouts_run_in_order_of_due_time() {
+    let mut context = context();
+    let init = r#"
+        setTimeout(() => log.push('c'), 30);
+        setTimeout(() => log.push('a'), 10);
+        setTimeout(() => {
+            log.push('b');
+            setTimeout(() => log.push('d'), 15);
+            queueMicrotask(() => log.push('b2'));
+        }, 20);
+        setTimeout((x, y) => log.push(x + y), 10, 'a', '2');
+        setTimeout(() => log.push('zero'));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.length"), "0");
+
+    context.run_jobs_until_idle().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"zero,a,a2,b,b2,c,d\""
+    );
+}
+
+#[test]
+fn timer_ids() {
+    let mut context = context();
+    assert_eq!(forward(&mut context, "setTimeout(() => {})"), "1");
+    assert_eq!(forward(&mut context, "setInterval(() => {}, 10)"), "2");
+    forward(&mut context, "clearInterval(2)");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { setTimeout('log.push(1)') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+
+    context.run_jobs_until_idle().unwrap();
+}
+
+#[test]
+fn clear_timers() {
+    let mut context = context();
+    let init = r#"
+        var cleared = setTimeout(() => log.push('cleared'), 10);
+        setTimeout(() => log.push('kept'), 20);
+        clearTimeout(cleared);
+        clearTimeout(undefined);
+        clearTimeout(1000);
+        var count = 0;
+        var interval = setInterval((step) => {
+            count += step;
+            log.push('tick ' + count);
+            if (count === 3) clearInterval(interval);
+        }, 8, 1);
+        "#;
+    forward(&mut context, init);
+
+    context.run_jobs_until_idle().unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"tick 1,tick 2,kept,tick 3\""
+    );
+}
+
+#[test]
+fn errors_of_timers() {
+    let mut context = context();
+    let init = r#"
+        setTimeout(() => { throw new Error('first'); }, 10);
+        setTimeout(() => log.push('second'), 20);
+        "#;
+    forward(&mut context, init);
+
+    assert!(context.run_jobs_until_idle().is_err());
+    assert_eq!(forward(&mut context, "log.length"), "0");
+    context.run_jobs_until_idle().unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"second\"");
+}
+
+/// A scheduler that records the timers, for the host to run them.
+#[derive(Debug, Default)]
+struct RecordingScheduler {
+    timers: Rc<RefCell<Vec<(TimerId, f64, TimerJob)>>>,
+}
+
+impl TimerScheduler for RecordingScheduler {
+    fn schedule(&mut self, delay_ms: f64, job: TimerJob) -> TimerId {
+        let mut timers = self.timers.borrow_mut();
+        let id = TimerId::new(timers.len() as u32 + 100);
+        timers.push((id, delay_ms, job));
+        id
+    }
+
+    fn cancel(&mut self, id: TimerId) {
+        self.timers
+            .borrow_mut()
+            .retain(|(timer, _, _)| *timer != id);
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn host_scheduler() {
+    let mut context = context();
+    let scheduler = RecordingScheduler::default();
+    let timers = scheduler.timers.clone();
+    context.set_timer_scheduler(scheduler);
+
+    assert_eq!(
+        forward(&mut context, "setInterval(x => log.push(x), -5, 'a')"),
+        "100"
+    );
+    assert_eq!(forward(&mut context, "setTimeout(() => {}, 2.5)"), "101");
+    forward(&mut context, "clearTimeout(101)");
+
+    // The timers of the host are not run by the context.
+    context.run_jobs_until_idle().unwrap();
+    assert_eq!(forward(&mut context, "log.length"), "0");
+
+    let (id, delay, job) = timers.borrow_mut().remove(0);
+    assert!(timers.borrow().is_empty());
+    assert_eq!(id.get(), 100);
+    assert_eq!(delay, 0.0);
+    assert_eq!(job.interval(), Some(0.0));
+    job.run(&mut context).unwrap();
+    job.run(&mut context).unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"a,a\"");
+}
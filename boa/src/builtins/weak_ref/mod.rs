@@ -0,0 +1,117 @@
+//! This module implements the global `WeakRef` object.
+//!
+//! A `WeakRef` holds a reference to an object that does not keep it from being garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+
+use crate::{
+    builtins::BuiltIn,
+    gc::WeakGcObject,
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `WeakRef` builtin, whose instances hold a [`WeakGcObject`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakRef;
+
+impl BuiltIn for WeakRef {
+    const NAME: &'static str = "WeakRef";
+
+    fn attribute() -> Attribute {
+        Attribute::BUILTIN
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_ref_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_ref_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::deref, "deref", 0)
+        .callable(false)
+        .build();
+
+        (Self::NAME, weak_ref_object.into(), Self::attribute())
+    }
+}
+
+impl WeakRef {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `WeakRef( target )`
+    ///
+    /// Creates a new weak reference to the target object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref-target
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/WeakRef
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error("WeakRef requires new");
+        }
+
+        let target = match args.get(0).and_then(Value::as_object) {
+            Some(target) => target,
+            None => return context.throw_type_error("WeakRef: target must be an object"),
+        };
+
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or_else(|| context.standard_objects().weak_ref_object().prototype());
+
+        let mut obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        let this = Value::from(obj);
+        this.set_data(ObjectData::WeakRef(WeakGcObject::new(&target)));
+
+        Ok(this)
+    }
+
+    /// `WeakRef.prototype.deref()`
+    ///
+    /// Returns the target object, or `undefined` if it has been garbage collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+    pub(crate) fn deref(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            if let ObjectData::WeakRef(ref weak) = object.borrow().data {
+                return Ok(weak.upgrade().map(Value::from).unwrap_or_default());
+            }
+        }
+
+        context.throw_type_error("'this' is not a WeakRef")
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{forward, Context};
+
+#[test]
+fn deref_returns_target() {
+    let mut context = Context::new();
+    let init = r#"
+        var target = { a: 1 };
+        var weak = new WeakRef(target);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "weak.deref() === target"), "true");
+    assert_eq!(forward(&mut context, "weak.deref().a"), "1");
+}
+
+#[test]
+fn deref_after_collection() {
+    let mut context = Context::new();
+    let init = r#"
+        var target = {};
+        var weak = new WeakRef(target);
+        target = null;
+        "#;
+    forward(&mut context, init);
+
+    context.force_gc();
+    context.run_jobs().unwrap();
+
+    assert_eq!(forward(&mut context, "weak.deref()"), "undefined");
+}
+
+#[test]
+fn constructor_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "new WeakRef(1)"),
+        "Uncaught \"TypeError\": \"WeakRef: target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut context, "WeakRef({})"),
+        "Uncaught \"TypeError\": \"function object is not callable\""
+    );
+}
@@ -3,38 +3,51 @@
 use crate::{
     builtins::{
         self,
-        function::{Function, FunctionFlags, NativeFunction},
+        date::clock::Clock,
+        error::create_error_object,
+        function::{Function, FunctionFlags, FunctionSource, NativeFunction},
         iterable::IteratorPrototypes,
+        math::random::RandomSource,
         symbol::{Symbol, WellKnownSymbols},
+        timers::{TimerId, TimerJob, TimerScheduler, Timers},
+        LazyBuiltIn,
     },
     class::{Class, ClassBuilder},
-    exec::Interpreter,
-    object::{GcObject, Object, ObjectData, PROTOTYPE},
-    property::{Attribute, DataDescriptor, PropertyKey},
+    coverage::{Coverage, CoverageCollector, ScriptCounters},
+    environment::scope::Scope,
+    error::{Diagnostic, EvalError, EvalResult, TerminationReason},
+    exec::Completion,
+    gc::{self, GcStats, WeakGcObject},
+    object::{FunctionBuilder, GcObject, IntegrityLevel, Object, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     realm::Realm,
+    snapshot::{HeapCopier, Snapshot},
     syntax::{
         ast::{
-            node::{
-                statement_list::RcStatementList, Call, FormalParameter, Identifier, New,
-                StatementList,
-            },
-            Const, Node,
+            node::{statement_list::RcStatementList, FormalParameter, StatementList},
+            Span,
         },
         Parser,
     },
-    value::{RcString, RcSymbol, Value},
+    trace::{TraceControl, TraceEvent, Tracer},
+    value::{same_value, RcString, RcSymbol, Value},
     BoaProfiler, Executable, Result,
 };
-use std::result::Result as StdResult;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+    any::Any,
+    collections::VecDeque,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    result::Result as StdResult,
+};
 
 #[cfg(feature = "console")]
-use crate::builtins::console::Console;
+use crate::builtins::console::{Console, ConsoleLogger, WriteLogger};
 
 #[cfg(feature = "vm")]
-use crate::vm::{
-    compilation::{CodeGen, Compiler},
-    VM,
-};
+use crate::vm::VM;
 
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
 #[derive(Debug, Clone)]
@@ -61,6 +74,14 @@ impl StandardConstructor {
         }
     }
 
+    /// Copies the constructor and its prototype for a [`Snapshot`].
+    fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            constructor: copier.copy_object(&self.constructor),
+            prototype: copier.copy_object(&self.prototype),
+        }
+    }
+
     /// Return the constructor object.
     ///
     /// This is the same as `Object`, `Array`, etc.
@@ -97,6 +118,11 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    aggregate_error: StandardConstructor,
+    map: StandardConstructor,
+    date: StandardConstructor,
+    weak_ref: StandardConstructor,
+    finalization_registry: StandardConstructor,
 }
 
 impl Default for StandardObjects {
@@ -118,11 +144,43 @@ impl Default for StandardObjects {
             syntax_error: StandardConstructor::default(),
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
+            aggregate_error: StandardConstructor::default(),
+            map: StandardConstructor::default(),
+            date: StandardConstructor::default(),
+            weak_ref: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
         }
     }
 }
 
 impl StandardObjects {
+    /// Copies the objects for a [`Snapshot`].
+    fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            object: self.object.copy_with(copier),
+            function: self.function.copy_with(copier),
+            array: self.array.copy_with(copier),
+            bigint: self.bigint.copy_with(copier),
+            number: self.number.copy_with(copier),
+            boolean: self.boolean.copy_with(copier),
+            string: self.string.copy_with(copier),
+            regexp: self.regexp.copy_with(copier),
+            symbol: self.symbol.copy_with(copier),
+            error: self.error.copy_with(copier),
+            type_error: self.type_error.copy_with(copier),
+            referece_error: self.referece_error.copy_with(copier),
+            range_error: self.range_error.copy_with(copier),
+            syntax_error: self.syntax_error.copy_with(copier),
+            eval_error: self.eval_error.copy_with(copier),
+            uri_error: self.uri_error.copy_with(copier),
+            aggregate_error: self.aggregate_error.copy_with(copier),
+            map: self.map.copy_with(copier),
+            date: self.date.copy_with(copier),
+            weak_ref: self.weak_ref.copy_with(copier),
+            finalization_registry: self.finalization_registry.copy_with(copier),
+        }
+    }
+
     #[inline]
     pub fn object_object(&self) -> &StandardConstructor {
         &self.object
@@ -201,6 +259,193 @@ impl StandardObjects {
     pub fn uri_error_object(&self) -> &StandardConstructor {
         &self.uri_error
     }
+
+    #[inline]
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
+
+    #[inline]
+    pub fn map_object(&self) -> &StandardConstructor {
+        &self.map
+    }
+
+    #[inline]
+    pub fn date_object(&self) -> &StandardConstructor {
+        &self.date
+    }
+
+    #[inline]
+    pub fn weak_ref_object(&self) -> &StandardConstructor {
+        &self.weak_ref
+    }
+
+    #[inline]
+    pub fn finalization_registry_object(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+}
+
+/// The kinds of native errors that [`Context::construct_error`] can create.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// An `Error`.
+    Error,
+    /// A `TypeError`.
+    Type,
+    /// A `RangeError`.
+    Range,
+    /// A `ReferenceError`.
+    Reference,
+    /// A `SyntaxError`.
+    Syntax,
+    /// An `EvalError`.
+    Eval,
+    /// A `URIError`.
+    Uri,
+}
+
+impl ErrorKind {
+    /// The standard constructor of this kind of error.
+    fn standard_constructor(self, objects: &StandardObjects) -> &StandardConstructor {
+        match self {
+            Self::Error => objects.error_object(),
+            Self::Type => objects.type_error_object(),
+            Self::Range => objects.range_error_object(),
+            Self::Reference => objects.reference_error_object(),
+            Self::Syntax => objects.syntax_error_object(),
+            Self::Eval => objects.eval_error_object(),
+            Self::Uri => objects.uri_error_object(),
+        }
+    }
+}
+
+/// A parsed script, that can be executed multiple times without having to parse it again.
+///
+/// Scripts are created with [`Context::compile`] and executed with [`Context::execute`]. Cloning
+/// a script is cheap, since the parsed statements are reference counted.
+#[derive(Debug, Clone)]
+pub struct Script {
+    statement_list: RcStatementList,
+    source_name: Rc<str>,
+    source: Option<Rc<str>>,
+    /// The span of each statement of the script, by the index the parser assigned to it.
+    statement_spans: Rc<[Span]>,
+}
+
+impl Script {
+    /// Creates a new script from the given statements and the name of their source.
+    #[inline]
+    pub fn new<S>(statement_list: StatementList, source_name: S) -> Self
+    where
+        S: Into<Rc<str>>,
+    {
+        Self {
+            statement_list: statement_list.into(),
+            source_name: source_name.into(),
+            source: None,
+            statement_spans: Rc::from(Vec::new()),
+        }
+    }
+
+    /// Sets the source code the statements were parsed from.
+    ///
+    /// The functions defined by the script keep a reference to it, to return their source code
+    /// from `Function.prototype.toString`.
+    #[inline]
+    pub fn with_source<S>(mut self, source: S) -> Self
+    where
+        S: Into<Rc<str>>,
+    {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sets the span of each statement of the script, by the index the parser assigned to it.
+    #[inline]
+    fn with_statement_spans(mut self, statement_spans: &[Span]) -> Self {
+        self.statement_spans = statement_spans.into();
+        self
+    }
+
+    /// Returns the parsed statements of the script.
+    #[inline]
+    pub fn statement_list(&self) -> &StatementList {
+        &self.statement_list
+    }
+
+    /// Returns the name of the source code of the script, such as its file name.
+    #[inline]
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    /// Returns the source code of the script, if it is known.
+    #[inline]
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the source code of the script with its name, if it is known.
+    fn source_text(&self) -> Option<SourceText> {
+        self.source.as_ref().map(|text| SourceText {
+            name: self.source_name.clone(),
+            text: text.clone(),
+            coverage: None,
+        })
+    }
+}
+
+impl From<StatementList> for Script {
+    #[inline]
+    fn from(statement_list: StatementList) -> Self {
+        Self::new(statement_list, ANONYMOUS_SOURCE_NAME)
+    }
+}
+
+/// The source code of a script with its name, shared by the script and the functions it
+/// defines.
+#[derive(Debug, Clone)]
+pub(crate) struct SourceText {
+    name: Rc<str>,
+    text: Rc<str>,
+    /// The execution counts of the statements, if the coverage is collected.
+    coverage: Option<Rc<ScriptCounters>>,
+}
+
+impl SourceText {
+    /// Gets the name of the source code, such as its file name.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the source code.
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// The statement that threw an error, recorded while the error propagates.
+#[derive(Debug)]
+struct ErrorLocation {
+    error: Value,
+    source: SourceText,
+    span: Span,
+}
+
+/// The source name used for code evaluated without an explicit source name.
+pub const ANONYMOUS_SOURCE_NAME: &str = "<anonymous>";
+
+/// Gets the message of a caught panic, which is a string unless the panic was raised with a
+/// value of another type.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic payload")
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -213,14 +458,17 @@ pub struct Context {
     /// realm holds both the global object and the environment
     realm: Realm,
 
-    /// The current executor.
-    executor: Interpreter,
-
     /// Symbol hash.
     ///
     /// For now this is an incremented u64 number.
     symbol_count: u64,
 
+    /// The global symbol registry of `Symbol.for` and `Symbol.keyFor`, by key.
+    ///
+    /// It holds the symbols for the whole life of the context, since a symbol registered with a
+    /// key can be looked up again by a script that lost it.
+    symbol_registry: FxHashMap<RcString, RcSymbol>,
+
     /// console object state.
     #[cfg(feature = "console")]
     console: Console,
@@ -233,22 +481,97 @@ pub struct Context {
 
     /// Cached standard objects and their prototypes.
     standard_objects: StandardObjects,
+
+    /// The builtins which have not been initialized yet, with the getter of their global property.
+    lazy_builtins: Vec<(LazyBuiltIn, GcObject)>,
+
+    /// The number of bytes the heap may use before allocating scripts are stopped.
+    memory_limit: Option<usize>,
+
+    /// The heap size when the memory limit was last reported as exceeded.
+    memory_high_water: usize,
+
+    /// The error thrown once the memory limit was exceeded, which is thrown again until the
+    /// evaluation it stopped returns, and tells that evaluation apart from the ones that threw
+    /// other errors.
+    memory_limit_error: Option<Value>,
+
+    /// The error thrown when a native function last panicked, with the panic message, to report
+    /// the evaluations it stopped as panics.
+    panic_error: Option<(Value, String)>,
+
+    /// The jobs waiting to be run by [`Context::run_jobs`].
+    job_queue: VecDeque<Job>,
+
+    /// The `FinalizationRegistry` objects whose cleanups have to be scheduled.
+    finalization_registries: Vec<WeakGcObject>,
+
+    /// The timers of `setTimeout` and `setInterval`.
+    timers: Timers,
+
+    /// The source of `Math.random`, or `None` for the thread-local generator.
+    random_source: Option<RandomSource>,
+
+    /// The wall clock of `Date`, or `None` if it has not been set.
+    clock: Option<Clock>,
+
+    /// The hook called before each statement is executed, if one is set.
+    tracer: Option<Tracer>,
+
+    /// The execution counts of the statements of the scripts, if the coverage is collected.
+    coverage: Option<CoverageCollector>,
+
+    /// The source code of the script being run, which the functions it creates refer to.
+    active_source: Option<SourceText>,
+
+    /// The innermost statement that threw the error being propagated, if it is known.
+    error_location: Option<ErrorLocation>,
+
+    /// The location of the error of the last compiled or executed script.
+    last_diagnostic: Option<Diagnostic>,
+
+    /// Whether the scripts and functions are compiled to bytecode and run by the VM.
+    #[cfg(feature = "vm")]
+    use_vm: bool,
+}
+
+/// A function call waiting in the job queue of a [`Context`].
+#[derive(Debug)]
+struct Job {
+    function: Value,
+    args: Vec<Value>,
 }
 
 impl Default for Context {
     fn default() -> Self {
         let realm = Realm::create();
-        let executor = Interpreter::new();
         let (well_known_symbols, symbol_count) = WellKnownSymbols::new();
         let mut context = Self {
             realm,
-            executor,
             symbol_count,
+            symbol_registry: FxHashMap::default(),
             #[cfg(feature = "console")]
             console: Console::default(),
             well_known_symbols,
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
+            lazy_builtins: Vec::new(),
+            memory_limit: None,
+            memory_high_water: 0,
+            memory_limit_error: None,
+            panic_error: None,
+            job_queue: VecDeque::new(),
+            finalization_registries: Vec::new(),
+            timers: Timers::default(),
+            random_source: None,
+            clock: Clock::system(),
+            tracer: None,
+            coverage: None,
+            active_source: None,
+            error_location: None,
+            last_diagnostic: None,
+            #[cfg(feature = "vm")]
+            use_vm: false,
         };
 
         // Add new builtIns to Context Realm
@@ -260,39 +583,631 @@ impl Default for Context {
     }
 }
 
+/// A builder for a [`Context`] with non-default options.
+///
+/// # Examples
+///
+/// ```
+/// use boa::context::ContextBuilder;
+///
+/// let mut context = ContextBuilder::new().memory_limit(64 * 1024 * 1024).build();
+/// assert!(context.eval("1 + 1").is_ok());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ContextBuilder {
+    memory_limit: Option<usize>,
+    frozen_intrinsics: bool,
+    frozen_global_object: bool,
+    random_seed: Option<u64>,
+    collect_coverage: bool,
+    #[cfg(feature = "vm")]
+    use_vm: bool,
+}
+
+impl ContextBuilder {
+    /// Create a new `ContextBuilder` with the default options.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the number of bytes the heap may use.
+    ///
+    /// When a script allocates past the limit, the garbage is collected and, if that is not
+    /// enough, the script is stopped: a `RangeError` is thrown, and again before every node the
+    /// script runs afterwards, so that `catch` and `finally` blocks cannot resume it. The
+    /// evaluation then fails with
+    /// [`TerminationReason::MemoryLimit`](crate::error::TerminationReason::MemoryLimit). The
+    /// scripts evaluated afterwards run as long as they don't allocate past the heap size the
+    /// limit was exceeded at, so the context stays usable. The limit covers the whole heap of the
+    /// current thread and is checked against the approximate statistics of the [`gc`](crate::gc)
+    /// module.
+    #[inline]
+    pub fn memory_limit(&mut self, bytes: usize) -> &mut Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Freezes all the intrinsic objects once the realm has been initialized.
+    ///
+    /// Every object reachable from the global object (`Object.prototype`, `Array.prototype`,
+    /// the constructors, ...) is frozen, so a script cannot pollute the builtins and affect
+    /// the scripts evaluated after it. The global object itself stays mutable unless
+    /// [`frozen_global_object`](Self::frozen_global_object) is also set.
+    #[inline]
+    pub fn frozen_intrinsics(&mut self, frozen: bool) -> &mut Self {
+        self.frozen_intrinsics = frozen;
+        self
+    }
+
+    /// Also freezes the global object when the intrinsics are frozen.
+    ///
+    /// This has no effect without [`frozen_intrinsics`](Self::frozen_intrinsics).
+    #[inline]
+    pub fn frozen_global_object(&mut self, frozen: bool) -> &mut Self {
+        self.frozen_global_object = frozen;
+        self
+    }
+
+    /// Seeds `Math.random`, so that it generates the same numbers on every run and platform.
+    ///
+    /// The numbers come from a `pcg32` generator. Without a seed, `Math.random` uses the
+    /// thread-local generator of the [`rand`] crate, seeded by the operating system.
+    #[inline]
+    pub fn random_seed(&mut self, seed: u64) -> &mut Self {
+        self.random_seed = Some(seed);
+        self
+    }
+
+    /// Counts the executions of the statements of the scripts, which are returned by
+    /// [`Context::take_coverage`], see the [`coverage`](crate::coverage) module.
+    ///
+    /// The contexts using the VM interpret the code when the coverage is collected.
+    #[inline]
+    pub fn collect_coverage(&mut self, collect: bool) -> &mut Self {
+        self.collect_coverage = collect;
+        self
+    }
+
+    /// Compiles the scripts and the functions to bytecode, and runs them in the
+    /// [`VM`](crate::vm::VM).
+    ///
+    /// The VM supports a subset of the language for now: the scripts and the function bodies
+    /// using anything else are run by the AST interpreter instead, so the results are the same
+    /// in both modes. The contexts with a [memory limit](ContextBuilder::memory_limit) always
+    /// use the AST interpreter, which enforces it.
+    #[cfg(feature = "vm")]
+    #[inline]
+    pub fn use_vm(&mut self, use_vm: bool) -> &mut Self {
+        self.use_vm = use_vm;
+        self
+    }
+
+    /// Builds the `Context`.
+    #[inline]
+    pub fn build(&self) -> Context {
+        let mut context = Context::default();
+        context.memory_limit = self.memory_limit;
+        context.random_source = self.random_seed.map(RandomSource::seeded);
+        if self.collect_coverage {
+            context.coverage = Some(CoverageCollector::default());
+        }
+        #[cfg(feature = "vm")]
+        {
+            context.use_vm = self.use_vm;
+        }
+        if self.frozen_intrinsics {
+            context.freeze_intrinsics(self.frozen_global_object);
+        }
+        context
+    }
+}
+
 impl Context {
     /// Create a new `Context`.
     #[inline]
-    pub fn new() -> Self {
-        Default::default()
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Takes a snapshot of the state of the context.
+    ///
+    /// The snapshot holds a copy of the objects and environments of the context, so the context
+    /// can keep running without affecting it. Isolated contexts can then be created from it with
+    /// [`Context::from_snapshot`], which is much cheaper than creating the intrinsics and running
+    /// the setup code again. The state of the `console` and the source of `Math.random` are not
+    /// part of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if the context holds native objects, which cannot be copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut setup = Context::new();
+    /// setup.eval("var counter = 0; function next() { return ++counter; }").unwrap();
+    /// let snapshot = setup.snapshot().unwrap();
+    ///
+    /// let mut first = Context::from_snapshot(&snapshot);
+    /// let mut second = Context::from_snapshot(&snapshot);
+    /// first.eval("next(); next();").unwrap();
+    /// assert_eq!(first.eval("counter").unwrap().as_number(), Some(2.0));
+    /// assert_eq!(second.eval("next()").unwrap().as_number(), Some(1.0));
+    /// ```
+    pub fn snapshot(&mut self) -> Result<Snapshot> {
+        let _timer = BoaProfiler::global().start_event("Context::snapshot", "context");
+        let mut copier = HeapCopier::new();
+        let context = self.copy_with(&mut copier);
+        if !copier.finish() {
+            return self.throw_type_error("Cannot snapshot a context holding native objects");
+        }
+
+        Ok(Snapshot { context })
+    }
+
+    /// Creates a new `Context` from a [`Snapshot`].
+    ///
+    /// The new context starts with a copy of the state of the snapshot, and is isolated from the
+    /// snapshot and the other contexts created from it.
+    pub fn from_snapshot(snapshot: &Snapshot) -> Self {
+        let _timer = BoaProfiler::global().start_event("Context::from_snapshot", "context");
+        let mut copier = HeapCopier::new();
+        let context = snapshot.context.copy_with(&mut copier);
+        let copied = copier.finish();
+        debug_assert!(copied, "snapshots only hold objects that can be copied");
+        context
+    }
+
+    /// Copies the state of the context, the contents of the objects are copied by `copier`.
+    fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        let job_queue = self
+            .job_queue
+            .iter()
+            .map(|job| Job {
+                function: copier.copy_value(&job.function),
+                args: job.args.iter().map(|arg| copier.copy_value(arg)).collect(),
+            })
+            .collect();
+
+        Self {
+            realm: self.realm.copy_with(copier),
+            symbol_count: self.symbol_count,
+            symbol_registry: self.symbol_registry.clone(),
+            #[cfg(feature = "console")]
+            console: Console::default(),
+            well_known_symbols: self.well_known_symbols.clone(),
+            iterator_prototypes: self.iterator_prototypes.copy_with(copier),
+            standard_objects: self.standard_objects.copy_with(copier),
+            lazy_builtins: self
+                .lazy_builtins
+                .iter()
+                .map(|(builtin, getter)| (*builtin, copier.copy_object(getter)))
+                .collect(),
+            memory_limit: self.memory_limit,
+            memory_high_water: 0,
+            memory_limit_error: None,
+            panic_error: None,
+            job_queue,
+            finalization_registries: self
+                .finalization_registries
+                .iter()
+                .map(|registry| copier.copy_weak(registry))
+                .collect(),
+            timers: self.timers.copy_with(copier),
+            random_source: None,
+            clock: self.clock.clone(),
+            tracer: None,
+            coverage: self.coverage.as_ref().map(|_| CoverageCollector::default()),
+            active_source: None,
+            error_location: None,
+            last_diagnostic: None,
+            #[cfg(feature = "vm")]
+            use_vm: self.use_vm,
+        }
+    }
+
+    #[inline]
+    pub fn realm(&self) -> &Realm {
+        &self.realm
+    }
+
+    #[inline]
+    pub fn realm_mut(&mut self) -> &mut Realm {
+        &mut self.realm
+    }
+
+    /// Returns the scopes of the running code, from the innermost one to the global scope.
+    ///
+    /// Between two evaluations only the global scope is left. From a trace hook, use
+    /// [`TraceEvent::scopes`] to inspect the scopes of the statement being run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::{environment::scope::ScopeKind, Context, Value};
+    ///
+    /// let mut context = Context::new();
+    /// context.eval("let answer = 42;").unwrap();
+    ///
+    /// let global = context.scopes().last().unwrap();
+    /// assert_eq!(global.kind(), ScopeKind::Global);
+    /// assert!(global.binding_names().contains(&String::from("Array")));
+    /// assert_eq!(global.get("answer"), Some(Value::from(42)));
+    /// ```
+    #[inline]
+    pub fn scopes(&self) -> impl Iterator<Item = Scope> + '_ {
+        self.realm
+            .environment
+            .environments()
+            .map(|environment| Scope::new(environment.clone()))
+    }
+
+    /// A helper function for getting an immutable reference to the `console` object.
+    #[cfg(feature = "console")]
+    pub(crate) fn console(&self) -> &Console {
+        &self.console
+    }
+
+    /// A helper function for getting a mutable reference to the `console` object.
+    #[cfg(feature = "console")]
+    #[inline]
+    pub(crate) fn console_mut(&mut self) -> &mut Console {
+        &mut self.console
+    }
+
+    /// Routes the output of the `console` object through the given logger.
+    ///
+    /// By default everything is printed to the standard output, and errors to the standard error.
+    /// Without the `system` feature there is no default logger, and the `console` methods throw
+    /// an `Error` until one is set.
+    #[cfg(feature = "console")]
+    #[inline]
+    pub fn set_console_logger<L>(&mut self, logger: L)
+    where
+        L: ConsoleLogger + 'static,
+    {
+        self.console.set_logger(Box::new(logger));
+    }
+
+    /// Writes the output of the `console` object, one message per line, into the given writer.
+    #[cfg(feature = "console")]
+    #[inline]
+    pub fn set_console_writer<W>(&mut self, writer: W)
+    where
+        W: std::io::Write + 'static,
+    {
+        self.set_console_logger(WriteLogger::new(writer));
+    }
+
+    /// Replaces the monotonic clock used by `console.time`, `console.timeLog` and
+    /// `console.timeEnd`.
+    ///
+    /// The clock returns the time elapsed since an arbitrary but fixed origin, and should never go
+    /// backwards: the timers report an elapsed time of 0 ms if it does. Without the `system`
+    /// feature there is no default clock, and the timers throw an `Error` until one is set.
+    #[cfg(feature = "console")]
+    #[inline]
+    pub fn set_console_clock<F>(&mut self, clock: F)
+    where
+        F: Fn() -> std::time::Duration + 'static,
+    {
+        self.console.set_clock(Box::new(clock));
+    }
+
+    /// Replaces the source of the numbers returned by `Math.random`.
+    ///
+    /// The source must return numbers in `[0, 1)`, otherwise `Math.random` throws a
+    /// `RangeError`. See [`ContextBuilder::random_seed`] for a seeded generator. Without the
+    /// `system` feature there is no default source, and `Math.random` throws an `Error` until one
+    /// is set.
+    #[inline]
+    pub fn set_random_source<F>(&mut self, source: F)
+    where
+        F: FnMut() -> f64 + 'static,
+    {
+        self.random_source = Some(RandomSource::new(source));
+    }
+
+    /// Replaces the wall clock used by `Date.now()` and `new Date()`.
+    ///
+    /// The clock returns the number of milliseconds elapsed since January 1, 1970 00:00:00 UTC.
+    /// The timers of the `console` use their own monotonic clock, which is not affected by this
+    /// one, see `set_console_clock`. Without the `system` feature there is no default clock, and
+    /// `Date.now()` and `new Date()` throw an `Error` until one is set.
+    #[inline]
+    pub fn set_clock<F>(&mut self, clock: F)
+    where
+        F: Fn() -> f64 + 'static,
+    {
+        self.clock = Some(Clock::new(clock));
+    }
+
+    /// Returns the current time of the wall clock used by `Date`, in milliseconds since the epoch.
+    ///
+    /// Throws an `Error` if no clock has been set and the `system` feature is disabled.
+    #[inline]
+    pub(crate) fn current_time(&mut self) -> Result<f64> {
+        match self.clock {
+            Some(ref clock) => Ok(clock.now()),
+            None => Err(self.construct_error(
+                ErrorKind::Error,
+                "no clock has been set by the host, see `Context::set_clock`",
+            )),
+        }
+    }
+
+    /// Returns the next number of the source of `Math.random`.
+    ///
+    /// Throws an `Error` if no source has been set and the `system` feature is disabled.
+    #[inline]
+    pub(crate) fn next_random(&mut self) -> Result<f64> {
+        match self.random_source {
+            Some(ref mut source) => Ok(source.next()),
+            #[cfg(feature = "system")]
+            None => Ok(rand::random()),
+            #[cfg(not(feature = "system"))]
+            None => Err(self.construct_error(
+                ErrorKind::Error,
+                "no random source has been set by the host, see `Context::set_random_source`",
+            )),
+        }
+    }
+
+    /// Collects all the garbage of the current thread.
+    ///
+    /// The garbage collected heap is shared by all the contexts of a thread.
+    #[inline]
+    pub fn force_gc(&mut self) {
+        gc::force_collect();
+        self.schedule_finalization_cleanups();
+    }
+
+    /// Returns approximate statistics about the garbage collected heap of the current thread.
+    ///
+    /// See the [`gc`](crate::gc) module for the limits of this accounting.
+    #[inline]
+    pub fn gc_stats(&self) -> GcStats {
+        gc::stats()
+    }
+
+    /// Sets the number of bytes that can be allocated before a collection is started.
+    ///
+    /// See [`gc::set_threshold`] for details.
+    #[inline]
+    pub fn set_gc_threshold(&mut self, bytes: Option<usize>) {
+        gc::set_threshold(bytes);
+    }
+
+    /// Adds a call of `function` to the end of the job queue.
+    #[inline]
+    pub(crate) fn enqueue_job(&mut self, function: Value, args: Vec<Value>) {
+        self.job_queue.push_back(Job { function, args });
+    }
+
+    /// Runs the queued jobs, including the cleanups of the `FinalizationRegistry` objects whose
+    /// targets have been collected, until the queue is empty.
+    ///
+    /// If a job throws, the error is returned and the remaining jobs stay queued.
+    pub fn run_jobs(&mut self) -> Result<()> {
+        self.schedule_finalization_cleanups();
+        while let Some(job) = self.job_queue.pop_front() {
+            self.call(&job.function, &Value::undefined(), &job.args)?;
+        }
+        Ok(())
+    }
+
+    /// Runs the queued jobs and the queued timers of `setTimeout` and `setInterval` until there
+    /// is nothing left to run.
+    ///
+    /// The timers run in the order of their due time, and the jobs they queue run before the next
+    /// timer. Instead of waiting for a timer, the time of the queue jumps to its due time, so the
+    /// order of the timers only depends on the clock set with [`Context::set_clock`]. An interval
+    /// that is never cleared keeps this function running forever. The timers given to the
+    /// scheduler of the host, see [`Context::set_timer_scheduler`], are not run.
+    ///
+    /// If a job or a timer throws, the error is returned and the remaining ones stay queued.
+    pub fn run_jobs_until_idle(&mut self) -> Result<()> {
+        self.run_jobs()?;
+        while let Some((id, job)) = self.timers.pop() {
+            let result = job.run(self);
+            self.timers.finish(id, job);
+            result?;
+            self.run_jobs()?;
+        }
+        Ok(())
+    }
+
+    /// Hands the timers of `setTimeout` and `setInterval` to the given scheduler.
+    ///
+    /// By default the timers are queued in the context, and run by
+    /// [`Context::run_jobs_until_idle`]. The timers already queued stay there.
+    #[inline]
+    pub fn set_timer_scheduler<S>(&mut self, scheduler: S)
+    where
+        S: TimerScheduler + 'static,
+    {
+        self.timers.set_scheduler(Box::new(scheduler));
+    }
+
+    /// Schedules a timer, with the scheduler of the host or in the queue of the context.
+    #[inline]
+    pub(crate) fn schedule_timer(&mut self, delay: f64, job: TimerJob) -> TimerId {
+        let now = self.clock.as_ref().map(Clock::now);
+        self.timers.schedule(now, delay, job)
+    }
+
+    /// Cancels a timer of `setTimeout` or `setInterval`.
+    #[inline]
+    pub(crate) fn cancel_timer(&mut self, id: TimerId) {
+        self.timers.cancel(id);
+    }
+
+    /// Sets the hook called before each statement is executed, for debuggers and step-through
+    /// tools, see the [`trace`](crate::trace) module.
+    ///
+    /// The hook replaces the previous one. The statements are not traced unless a hook is set,
+    /// and the contexts using the VM interpret the code while one is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::{
+    ///     trace::{TraceControl, TraceEvent},
+    ///     Context,
+    /// };
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// let mut context = Context::new();
+    /// let lines = Rc::new(RefCell::new(Vec::new()));
+    /// let recorded = lines.clone();
+    /// context.set_trace_hook(move |event: TraceEvent<'_>| {
+    ///     if let Some(span) = event.span() {
+    ///         recorded.borrow_mut().push(span.start().line_number());
+    ///     }
+    ///     TraceControl::Continue
+    /// });
+    ///
+    /// context.eval("let a = 1;\nlet b = a + 1;").unwrap();
+    /// assert_eq!(*lines.borrow(), [1, 2]);
+    /// ```
+    #[inline]
+    pub fn set_trace_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(TraceEvent<'_>) -> TraceControl + 'static,
+    {
+        self.tracer = Some(Tracer::new(Box::new(hook)));
+    }
+
+    /// Removes the hook set with [`Context::set_trace_hook`].
+    #[inline]
+    pub fn remove_trace_hook(&mut self) {
+        self.tracer = None;
+    }
+
+    /// Returns the number of times each statement of the scripts run since the last call was
+    /// executed, by source name.
+    ///
+    /// The coverage is only collected by the contexts built with
+    /// [`ContextBuilder::collect_coverage`], and is empty otherwise. The counts are reset, so the
+    /// next call only returns the executions after this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::context::ContextBuilder;
+    ///
+    /// let mut context = ContextBuilder::new().collect_coverage(true).build();
+    /// context
+    ///     .eval_with_source_name("if (false) {\n    unreachable();\n}", "script.js")
+    ///     .unwrap();
+    ///
+    /// let coverage = context.take_coverage();
+    /// assert_eq!(coverage.script("script.js").unwrap().uncovered_lines(), [2]);
+    /// ```
+    #[inline]
+    pub fn take_coverage(&mut self) -> Coverage {
+        self.coverage
+            .as_mut()
+            .map(CoverageCollector::take)
+            .unwrap_or_default()
     }
 
+    /// Counts an execution of the statement at `index` of the running script, if the coverage is
+    /// collected.
     #[inline]
-    pub fn realm(&self) -> &Realm {
-        &self.realm
+    pub(crate) fn cover_statement(&self, index: Option<u32>) {
+        let coverage = self
+            .active_source
+            .as_ref()
+            .and_then(|source| source.coverage.as_ref());
+        if let (Some(coverage), Some(index)) = (coverage, index) {
+            coverage.hit(index);
+        }
     }
 
+    /// Calls the trace hook, if one is set, before the statement at `span` is executed.
+    ///
+    /// Throws an `Error` if the hook stopped the script.
     #[inline]
-    pub fn realm_mut(&mut self) -> &mut Realm {
-        &mut self.realm
+    pub(crate) fn trace_statement(&mut self, span: Option<Span>) -> Result<()> {
+        if let Some(mut tracer) = self.tracer.take() {
+            // The hook only gets a shared reference to the context, so it cannot replace itself.
+            let stop_error = tracer.trace(span, self);
+            self.tracer = Some(tracer);
+            if let Some(error) = stop_error {
+                return Err(error);
+            }
+        }
+        Ok(())
     }
 
+    /// Registers a `FinalizationRegistry` object, so its cleanups get scheduled.
     #[inline]
-    pub fn executor(&mut self) -> &mut Interpreter {
-        &mut self.executor
+    pub(crate) fn track_finalization_registry(&mut self, registry: &GcObject) {
+        self.finalization_registries
+            .push(WeakGcObject::new(registry));
     }
 
-    /// A helper function for getting an immutable reference to the `console` object.
-    #[cfg(feature = "console")]
-    pub(crate) fn console(&self) -> &Console {
-        &self.console
+    /// Queues the cleanup callbacks of the registered objects that have been collected.
+    fn schedule_finalization_cleanups(&mut self) {
+        let mut jobs = Vec::new();
+        self.finalization_registries
+            .retain(|registry| match registry.upgrade() {
+                Some(registry) => {
+                    if let ObjectData::FinalizationRegistry(ref mut registry) =
+                        registry.borrow_mut().data
+                    {
+                        jobs.extend(registry.take_cleanup_jobs());
+                    }
+                    true
+                }
+                None => false,
+            });
+
+        for (cleanup, held_value) in jobs {
+            self.enqueue_job(cleanup.into(), vec![held_value]);
+        }
     }
 
-    /// A helper function for getting a mutable reference to the `console` object.
-    #[cfg(feature = "console")]
+    /// Throws a `RangeError` if the heap has grown past the memory limit since it was last
+    /// reported as exceeded, and collecting the garbage does not bring it back under the limit.
+    ///
+    /// Once thrown, the error is thrown again by every check until the evaluation it stopped
+    /// returns.
     #[inline]
-    pub(crate) fn console_mut(&mut self) -> &mut Console {
-        &mut self.console
+    pub(crate) fn check_memory_limit(&mut self) -> Result<()> {
+        let limit = match self.memory_limit {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        if let Some(ref error) = self.memory_limit_error {
+            return Err(error.clone());
+        }
+
+        let allocated = gc::stats().allocated_bytes;
+        if allocated <= limit || allocated <= self.memory_high_water {
+            return Ok(());
+        }
+
+        gc::force_collect();
+        let allocated = gc::stats().allocated_bytes;
+        if allocated <= limit {
+            return Ok(());
+        }
+
+        // Constructing the error allocates, so the limit is lifted while doing it.
+        self.memory_limit = None;
+        let error = self.construct_range_error(format!(
+            "out of memory: the heap uses {} bytes, the limit is {} bytes",
+            allocated, limit
+        ));
+        self.memory_limit = Some(limit);
+        self.memory_high_water = gc::stats().allocated_bytes;
+        self.memory_limit_error = Some(error.clone());
+        Err(error)
     }
 
     /// Sets up the default global objects within Global
@@ -300,7 +1215,127 @@ impl Context {
     fn create_intrinsics(&mut self) {
         let _timer = BoaProfiler::global().start_event("create_intrinsics", "interpreter");
         // Create intrinsics, add global objects here
-        builtins::init(self);
+        self.lazy_builtins = builtins::init(self);
+    }
+
+    /// Initializes a builtin which is initialized on first use, if it has not been yet.
+    ///
+    /// Its global property is replaced by the usual data property, unless a script has already
+    /// redefined it or made it non-configurable.
+    pub(crate) fn initialize_builtin(&mut self, builtin: LazyBuiltIn) {
+        let index = match self.lazy_builtins.iter().position(|(b, _)| *b == builtin) {
+            Some(index) => index,
+            None => return,
+        };
+        let (_, getter) = self.lazy_builtins.remove(index);
+
+        let (name, value, attribute) = builtin.init(self);
+        let global = self.global_object().clone();
+        let key = PropertyKey::from(name);
+        let unchanged = match global.get_own_property(&key) {
+            Some(PropertyDescriptor::Accessor(ref accessor)) => {
+                accessor.configurable()
+                    && accessor
+                        .getter()
+                        .map_or(false, |current| GcObject::equals(current, &getter))
+            }
+            _ => false,
+        };
+        if unchanged {
+            global
+                .borrow_mut()
+                .insert(key, DataDescriptor::new(value, attribute));
+        }
+    }
+
+    /// Initializes the builtin whose global property is `key`, if `object` is the global object and
+    /// the builtin is still waiting for its first use.
+    ///
+    /// The accessor standing in for the global property of such a builtin must never be observed,
+    /// so this is called before the descriptor of a property of the global object is read or
+    /// changed.
+    #[inline]
+    pub(crate) fn initialize_global_property(&mut self, object: &GcObject, key: &PropertyKey) {
+        if self.lazy_builtins.is_empty() || !GcObject::equals(object, self.global_object()) {
+            return;
+        }
+        let name = match key {
+            PropertyKey::String(name) => name,
+            _ => return,
+        };
+        let builtin = self
+            .lazy_builtins
+            .iter()
+            .map(|&(builtin, _)| builtin)
+            .find(|builtin| builtin.name() == name.as_str());
+        if let Some(builtin) = builtin {
+            self.initialize_builtin(builtin);
+        }
+    }
+
+    /// Initializes every builtin still waiting for its first use if `object` is the global object,
+    /// before the descriptors of all its properties are read.
+    #[inline]
+    pub(crate) fn initialize_global_properties(&mut self, object: &GcObject) {
+        if !self.lazy_builtins.is_empty() && GcObject::equals(object, self.global_object()) {
+            self.initialize_builtins();
+        }
+    }
+
+    /// Initializes every builtin which is still waiting for its first use.
+    fn initialize_builtins(&mut self) {
+        while let Some(&(builtin, _)) = self.lazy_builtins.first() {
+            self.initialize_builtin(builtin);
+        }
+    }
+
+    /// Freezes every object reachable from the global object and the cached iterator prototypes.
+    ///
+    /// The global object itself is only frozen if `freeze_global` is `true`.
+    fn freeze_intrinsics(&mut self, freeze_global: bool) {
+        let _timer = BoaProfiler::global().start_event("freeze_intrinsics", "interpreter");
+        // The lazy builtins are not reachable from the global object until they are initialized.
+        self.initialize_builtins();
+        let global = self.global_object().clone();
+        let prototypes = self.iterator_prototypes();
+        let mut pending = vec![
+            global.clone(),
+            prototypes.iterator_prototype(),
+            prototypes.array_iterator(),
+            prototypes.string_iterator(),
+            prototypes.map_iterator(),
+            prototypes.for_in_iterator(),
+        ];
+        // The intrinsics reference each other, so visited objects are tracked by address.
+        let mut visited = FxHashSet::default();
+
+        while let Some(mut object) = pending.pop() {
+            if !visited.insert(object.as_ref() as *const _) {
+                continue;
+            }
+
+            if let Some(prototype) = object.get_prototype_of().as_object() {
+                pending.push(prototype);
+            }
+            for key in object.own_property_keys() {
+                match object.get_own_property(&key) {
+                    Some(PropertyDescriptor::Data(desc)) => {
+                        pending.extend(desc.value().as_object());
+                    }
+                    Some(PropertyDescriptor::Accessor(desc)) => {
+                        pending.extend(desc.getter().cloned());
+                        pending.extend(desc.setter().cloned());
+                    }
+                    None => {}
+                }
+            }
+
+            if freeze_global || !GcObject::equals(&object, &global) {
+                object
+                    .set_integrity_level(IntegrityLevel::Frozen, self)
+                    .expect("intrinsics must be freezable");
+            }
+        }
     }
 
     /// Generates a new `Symbol` internal hash.
@@ -319,6 +1354,40 @@ impl Context {
         RcSymbol::from(Symbol::new(self.generate_hash(), description))
     }
 
+    /// Gets the symbol registered with the given key in the global symbol registry, registering a
+    /// new symbol if there is none, as done by `Symbol.for`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let symbol = context.registered_symbol("app.id".into());
+    /// assert_eq!(symbol.description(), Some("app.id"));
+    /// assert_eq!(context.registered_symbol("app.id".into()), symbol);
+    /// ```
+    pub fn registered_symbol(&mut self, key: RcString) -> RcSymbol {
+        if let Some(symbol) = self.symbol_registry.get(&key) {
+            return symbol.clone();
+        }
+        let symbol = self.construct_symbol(Some(key.clone()));
+        self.symbol_registry.insert(key, symbol.clone());
+        symbol
+    }
+
+    /// Gets the key of a symbol in the global symbol registry, as done by `Symbol.keyFor`.
+    ///
+    /// Returns `None` if the symbol was not registered.
+    pub fn symbol_registry_key(&self, symbol: &RcSymbol) -> Option<RcString> {
+        // A registered symbol has its key as description.
+        let (key, registered) = self.symbol_registry.get_key_value(symbol.description()?)?;
+        if registered == symbol {
+            Some(key.clone())
+        } else {
+            None
+        }
+    }
+
     /// Construct an empty object.
     #[inline]
     pub fn construct_object(&self) -> GcObject {
@@ -331,7 +1400,10 @@ impl Context {
     pub(crate) fn call(&mut self, f: &Value, this: &Value, args: &[Value]) -> Result<Value> {
         match *f {
             Value::Object(ref object) => object.call(this, args, self),
-            _ => self.throw_type_error("not a function"),
+            _ => self.throw_error_fmt(
+                ErrorKind::Type,
+                format_args!("{} is not a function", f.get_type().name()),
+            ),
         }
     }
 
@@ -341,19 +1413,88 @@ impl Context {
         &self.realm().global_object
     }
 
+    /// Constructs an error of the given kind with the specified message, without throwing it.
+    ///
+    /// The error is created from the standard constructor, so it is not affected by scripts
+    /// replacing the global binding of the constructor.
+    pub fn construct_error<M>(&mut self, kind: ErrorKind, message: M) -> Value
+    where
+        M: Into<Box<str>>,
+    {
+        match kind {
+            ErrorKind::Eval => self.initialize_builtin(LazyBuiltIn::EvalError),
+            ErrorKind::Uri => self.initialize_builtin(LazyBuiltIn::UriError),
+            _ => {}
+        }
+        let prototype = kind
+            .standard_constructor(self.standard_objects())
+            .prototype();
+        create_error_object(
+            &Value::undefined(),
+            prototype,
+            &[Value::from(message.into())],
+            self,
+        )
+        .expect("creating an error from a string message cannot fail")
+    }
+
+    /// Throws the given value, which does not have to be an error object.
+    ///
+    /// A `catch` clause of the script binds the exact value that is thrown, so this is also the
+    /// way for a native function to rethrow a value it caught.
+    ///
+    /// ```
+    /// # use boa::{Context, Value};
+    /// let mut context = Context::new();
+    /// let error = context.throw(42).unwrap_err();
+    /// assert!(error.strict_equals(&Value::from(42)));
+    /// ```
+    #[inline]
+    pub fn throw<V>(&mut self, value: V) -> Result<Value>
+    where
+        V: Into<Value>,
+    {
+        Err(value.into())
+    }
+
+    /// Throws an error of the given kind with the specified message.
+    #[inline]
+    pub fn throw_error<M>(&mut self, kind: ErrorKind, message: M) -> Result<Value>
+    where
+        M: Into<Box<str>>,
+    {
+        Err(self.construct_error(kind, message))
+    }
+
+    /// Throws an error of the given kind with a message built by `format_args!`.
+    ///
+    /// ```
+    /// # use boa::{context::ErrorKind, Context};
+    /// let mut context = Context::new();
+    /// let error = context
+    ///     .throw_error_fmt(ErrorKind::Range, format_args!("{} is out of range", 42))
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(&mut context).unwrap().as_str(),
+    ///     "RangeError: 42 is out of range"
+    /// );
+    /// ```
+    #[inline]
+    pub fn throw_error_fmt(
+        &mut self,
+        kind: ErrorKind,
+        message: fmt::Arguments<'_>,
+    ) -> Result<Value> {
+        self.throw_error(kind, message.to_string())
+    }
+
     /// Constructs a `RangeError` with the specified message.
     #[inline]
     pub fn construct_range_error<M>(&mut self, message: M) -> Value
     where
         M: Into<Box<str>>,
     {
-        // Runs a `new RangeError(message)`.
-        New::from(Call::new(
-            Identifier::from("RangeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error(ErrorKind::Range, message)
     }
 
     /// Throws a `RangeError` with the specified message.
@@ -362,7 +1503,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_range_error(message))
+        self.throw_error(ErrorKind::Range, message)
     }
 
     /// Constructs a `TypeError` with the specified message.
@@ -371,13 +1512,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        // Runs a `new TypeError(message)`.
-        New::from(Call::new(
-            Identifier::from("TypeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error(ErrorKind::Type, message)
     }
 
     /// Throws a `TypeError` with the specified message.
@@ -386,7 +1521,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_type_error(message))
+        self.throw_error(ErrorKind::Type, message)
     }
 
     /// Constructs a `ReferenceError` with the specified message.
@@ -395,12 +1530,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("ReferenceError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error(ErrorKind::Reference, message)
     }
 
     /// Throws a `ReferenceError` with the specified message.
@@ -409,7 +1539,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_reference_error(message))
+        self.throw_error(ErrorKind::Reference, message)
     }
 
     /// Constructs a `SyntaxError` with the specified message.
@@ -418,12 +1548,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("SyntaxError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error(ErrorKind::Syntax, message)
     }
 
     /// Throws a `SyntaxError` with the specified message.
@@ -432,57 +1557,55 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_syntax_error(message))
+        self.throw_error(ErrorKind::Syntax, message)
     }
 
     /// Constructs a `EvalError` with the specified message.
+    #[inline]
     pub fn construct_eval_error<M>(&mut self, message: M) -> Value
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("EvalError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error(ErrorKind::Eval, message)
     }
 
-    /// Constructs a `URIError` with the specified message.
-    pub fn construct_uri_error<M>(&mut self, message: M) -> Value
+    /// Throws a `EvalError` with the specified message.
+    #[inline]
+    pub fn throw_eval_error<M>(&mut self, message: M) -> Result<Value>
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("URIError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.throw_error(ErrorKind::Eval, message)
     }
 
-    /// Throws a `EvalError` with the specified message.
-    pub fn throw_eval_error<M>(&mut self, message: M) -> Result<Value>
+    /// Constructs a `URIError` with the specified message.
+    #[inline]
+    pub fn construct_uri_error<M>(&mut self, message: M) -> Value
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_eval_error(message))
+        self.construct_error(ErrorKind::Uri, message)
     }
 
     /// Throws a `URIError` with the specified message.
+    #[inline]
     pub fn throw_uri_error<M>(&mut self, message: M) -> Result<Value>
     where
         M: Into<Box<str>>,
     {
-        Err(self.construct_uri_error(message))
+        self.throw_error(ErrorKind::Uri, message)
     }
 
     /// Utility to create a function Value for Function Declarations, Arrow Functions or Function Expressions
+    ///
+    /// The `span` of the function is its location in the source code of the running script.
     pub(crate) fn create_function<P, B>(
         &mut self,
+        name: &str,
         params: P,
         body: B,
-        flags: FunctionFlags,
+        span: Option<Span>,
+        mut flags: FunctionFlags,
     ) -> Result<Value>
     where
         P: Into<Box<[FormalParameter]>>,
@@ -495,23 +1618,63 @@ impl Context {
         let proto = Value::new_object(self);
 
         let params = params.into();
-        let params_len = params.len();
+        // The length counts the parameters before the first one with a default value or rest.
+        let length = params
+            .iter()
+            .take_while(|param| param.init().is_none() && !param.is_rest_param())
+            .count();
+        let body = body.into();
+        if body.strict() {
+            flags |= FunctionFlags::STRICT;
+        }
         let func = Function::Ordinary {
             flags,
-            body: RcStatementList::from(body.into()),
+            body: RcStatementList::from(body),
             params,
             environment: self.realm.environment.get_current_environment().clone(),
+            home_object: None,
+            source: match (&self.active_source, span) {
+                (Some(script), Some(span)) => Some(FunctionSource::new(script.clone(), span)),
+                _ => None,
+            },
         };
 
         let new_func = Object::function(func, function_prototype);
 
         let val = Value::from(new_func);
 
-        // Set constructor field to the newly created Value (function object)
-        proto.set_field("constructor", val.clone(), self)?;
-
-        val.set_field(PROTOTYPE, proto, self)?;
-        val.set_field("length", Value::from(params_len), self)?;
+        // Set constructor field to the newly created Value (function object).
+        // The properties are defined rather than assigned, so that they do not depend on
+        // the writability of the inherited ones.
+        proto.set_property(
+            "constructor",
+            DataDescriptor::new(
+                val.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+
+        val.set_property(
+            PROTOTYPE,
+            DataDescriptor::new(
+                proto,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+        val.set_property(
+            "length",
+            DataDescriptor::new(
+                length,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        val.set_property(
+            "name",
+            DataDescriptor::new(
+                name,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
 
         Ok(val)
     }
@@ -523,24 +1686,12 @@ impl Context {
         length: usize,
         body: NativeFunction,
     ) -> Result<GcObject> {
-        let function_prototype: Value = self.standard_objects().object_object().prototype().into();
-
-        // Every new function has a prototype property pre-made
-        let proto = Value::new_object(self);
-        let mut function = GcObject::new(Object::function(
-            Function::BuiltIn(body.into(), FunctionFlags::CALLABLE),
-            function_prototype,
-        ));
-        function.set(PROTOTYPE.into(), proto, function.clone().into(), self)?;
-        function.set(
-            "length".into(),
-            length.into(),
-            function.clone().into(),
-            self,
-        )?;
-        function.set("name".into(), name.into(), function.clone().into(), self)?;
-
-        Ok(function)
+        Ok(FunctionBuilder::new(self, body)
+            .name(name)
+            .length(length)
+            .callable(true)
+            .constructable(false)
+            .build())
     }
 
     /// Register a global function.
@@ -583,11 +1734,7 @@ impl Context {
                         let array = Value::new_object(self);
                         array.set_data(ObjectData::Array);
                         array.as_object().expect("object").set_prototype_instance(
-                            self.realm()
-                                .environment
-                                .get_binding_value("Array")
-                                .expect("Array was not initialized")
-                                .get_field(PROTOTYPE, self)?,
+                            self.standard_objects().array_object().prototype().into(),
                         );
                         array.set_field(0, key, self)?;
                         array.set_field(1, value, self)?;
@@ -614,27 +1761,18 @@ impl Context {
         }
     }
 
-    #[inline]
-    pub(crate) fn set_value(&mut self, node: &Node, value: Value) -> Result<Value> {
-        match node {
-            Node::Identifier(ref name) => {
-                self.realm
-                    .environment
-                    .set_mutable_binding(name.as_ref(), value.clone(), true)
-                    .map_err(|e| e.to_error(self))?;
-                Ok(value)
-            }
-            Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
-                .obj()
-                .run(self)?
-                .set_field(get_const_field_node.field(), value, self)?),
-            Node::GetField(ref get_field) => {
-                let field = get_field.field().run(self)?;
-                let key = field.to_property_key(self)?;
-                Ok(get_field.obj().run(self)?.set_field(key, value, self)?)
-            }
-            _ => self.throw_type_error(format!("invalid assignment to {}", node)),
-        }
+    /// Runs `f`, then pops the environments it left on the stack.
+    ///
+    /// The statements that push environments run in this, so that the stack stays balanced when
+    /// an exception, a `break` or a `return` exits them early.
+    pub(crate) fn run_in_environment_scope<T, F>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        let depth = self.realm.environment.depth();
+        let result = f(self);
+        self.realm.environment.truncate(depth);
+        result
     }
 
     /// Register a global class of type `T`, where `T` implements `Class`.
@@ -692,6 +1830,9 @@ impl Context {
 
     /// Evaluates the given code.
     ///
+    /// The errors tell apart the code that could not be parsed, the values thrown by the code and
+    /// the evaluations stopped by the engine, see [`EvalError`].
+    ///
     /// # Examples
     /// ```
     ///# use boa::Context;
@@ -702,21 +1843,12 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
-    #[cfg(not(feature = "vm"))]
     #[allow(clippy::unit_arg, clippy::drop_copy)]
     #[inline]
-    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Value> {
+    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> EvalResult<Value> {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
-        let src_bytes: &[u8] = src.as_ref();
 
-        let parsing_result = Parser::new(src_bytes, false)
-            .parse_all()
-            .map_err(|e| e.to_string());
-
-        let execution_result = match parsing_result {
-            Ok(statement_list) => statement_list.run(self),
-            Err(e) => self.throw_syntax_error(e),
-        };
+        let execution_result = self.compile(src).and_then(|script| self.execute(&script));
 
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
@@ -725,48 +1857,340 @@ impl Context {
         execution_result
     }
 
-    /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
+    /// Evaluates the given code, using `source_name` to identify it in error messages.
+    ///
+    /// This behaves like [`Context::eval`], but syntax errors will include the given source name,
+    /// such as a file name: `myfile.js:3:14: unexpected token '='`.
     ///
     /// # Examples
     /// ```
     ///# use boa::Context;
     /// let mut context = Context::new();
     ///
-    /// let value = context.eval("1 + 3").unwrap();
+    /// let error = context.eval_with_source_name("1 +", "myfile.js").unwrap_err();
     ///
-    /// assert!(value.is_number());
-    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// assert!(error.to_string().contains("myfile.js"));
     /// ```
-    #[cfg(feature = "vm")]
     #[allow(clippy::unit_arg, clippy::drop_copy)]
-    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Value> {
+    pub fn eval_with_source_name<T: AsRef<[u8]>>(
+        &mut self,
+        src: T,
+        source_name: &str,
+    ) -> EvalResult<Value> {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
+
+        let execution_result = self
+            .compile_with_source_name(src, source_name)
+            .and_then(|script| self.execute(&script));
+
+        // The main_timer needs to be dropped before the BoaProfiler is.
+        drop(main_timer);
+        BoaProfiler::global().drop();
+
+        execution_result
+    }
+
+    /// Parses the given code into a [`Script`], without executing it.
+    ///
+    /// The script can then be executed as many times as needed with [`Context::execute`], avoiding
+    /// the parsing cost on each execution. If the code can't be parsed, an [`EvalError::Parse`] is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context.eval("var x = 0;").unwrap();
+    /// let script = context.compile("x += 1; x").unwrap();
+    ///
+    /// context.execute(&script).unwrap();
+    /// let value = context.execute(&script).unwrap();
+    ///
+    /// assert_eq!(value.as_number().unwrap(), 2.0);
+    /// ```
+    #[inline]
+    pub fn compile<T: AsRef<[u8]>>(&mut self, src: T) -> EvalResult<Script> {
+        self.compile_with_source_name(src, ANONYMOUS_SOURCE_NAME)
+    }
+
+    /// Parses the given code into a [`Script`], using `source_name` to identify it in error
+    /// messages.
+    pub fn compile_with_source_name<T: AsRef<[u8]>>(
+        &mut self,
+        src: T,
+        source_name: &str,
+    ) -> EvalResult<Script> {
+        let _timer = BoaProfiler::global().start_event("Compile", "Main");
         let src_bytes: &[u8] = src.as_ref();
+        let source = String::from_utf8_lossy(src_bytes);
+        self.last_diagnostic = None;
+
+        let mut parser = Parser::new(src_bytes, false);
+        match parser.parse_all() {
+            Ok(statement_list) => Ok(Script::new(statement_list, source_name)
+                .with_source(source.as_ref())
+                .with_statement_spans(parser.statement_spans())),
+            Err(error) => {
+                self.last_diagnostic = Diagnostic::parse_error(source_name, &source, &error);
+                Err(EvalError::Parse {
+                    error,
+                    source_name: source_name.into(),
+                })
+            }
+        }
+    }
+
+    /// Executes a [`Script`] previously created with [`Context::compile`].
+    ///
+    /// A script is not bound to the context that compiled it, so it can be executed in any
+    /// context.
+    ///
+    /// If the engine or a native function panics while running the script, the panic is caught
+    /// and returned as an [`EvalError::InternalPanic`]. The panic hook still runs, so the panic
+    /// is printed as usual unless the embedder replaced the hook.
+    #[inline]
+    pub fn execute(&mut self, script: &Script) -> EvalResult<Value> {
+        let mut source = script.source_text();
+        if let (Some(source), Some(coverage)) = (source.as_mut(), self.coverage.as_mut()) {
+            source.coverage = coverage.counters(&script.source_name, &script.statement_spans);
+        }
+        self.execute_statements(&script.statement_list, source)
+    }
+
+    /// Executes statements returned by [`parse`](crate::parse), or built or modified by the
+    /// embedder.
+    ///
+    /// The statements are not bound to a context, so any statements can be executed in any
+    /// context. The parser resolves some identifiers to the slots of their bindings, and the
+    /// interpreter checks the name of the binding it finds there, so moving nodes around is safe.
+    /// The identifiers of the nodes built by the embedder are looked up by name.
+    ///
+    /// This is [`Context::execute`] with a script made from the statements, without copying them.
+    /// Since the source code of the statements is not known, the errors have no
+    /// [`Diagnostic`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::{
+    ///     syntax::ast::node::{Identifier, Node, StatementList},
+    ///     Context, Value,
+    /// };
+    ///
+    /// let mut context = Context::new();
+    /// let statements = boa::parse("var answer = 21; answer * 2;", false).unwrap();
+    /// assert_eq!(context.execute_ast(&statements).unwrap(), Value::from(42));
+    ///
+    /// // Replace the last statement with a reference to `answer`.
+    /// assert!(matches!(statements.items()[0], Node::VarDeclList(_)));
+    /// let mut items = statements.items().to_vec();
+    /// items[1] = Identifier::from("answer").into();
+    /// let modified = StatementList::from(items);
+    /// assert_eq!(modified.to_string(), "var answer = 21;\nanswer;\n");
+    ///
+    /// assert_eq!(context.execute_ast(&modified).unwrap(), Value::from(21));
+    /// ```
+    #[inline]
+    pub fn execute_ast(&mut self, statement_list: &StatementList) -> EvalResult<Value> {
+        self.execute_statements(statement_list, None)
+    }
 
-        let parsing_result = Parser::new(src_bytes, false)
-            .parse_all()
-            .map_err(|e| e.to_string());
+    /// Executes statements, parsed from `source` if it is known.
+    fn execute_statements(
+        &mut self,
+        statement_list: &StatementList,
+        source: Option<SourceText>,
+    ) -> EvalResult<Value> {
+        let _timer = BoaProfiler::global().start_event("Execute", "Main");
+        self.last_diagnostic = None;
+        self.error_location = None;
+        if let Some(ref mut tracer) = self.tracer {
+            tracer.take_stop_error();
+        }
 
-        let statement_list = match parsing_result {
-            Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
+        let depth = self.realm.environment.depth();
+        let active_source = self.active_source.clone();
+        // The context is unwind safe enough: the environments and the source of the running
+        // script are restored below, and the objects the script was updating are only observed by
+        // the embedder, who is told about the panic and can discard the context.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            self.with_active_source(source, |context| context.run_statement_list(statement_list))
+        }));
+        let result = match result {
+            Ok(result) => result,
+            Err(payload) => {
+                self.realm.environment.truncate(depth);
+                self.active_source = active_source;
+                self.error_location = None;
+                return Err(EvalError::InternalPanic(panic_message(payload.as_ref())));
+            }
         };
 
-        let mut compiler = Compiler::default();
-        statement_list.compile(&mut compiler);
-        dbg!(&compiler);
+        result.map(Completion::into_value).map_err(|error| {
+            self.last_diagnostic = self
+                .error_location
+                .take()
+                .filter(|location| same_value(&location.error, &error))
+                .and_then(|location| {
+                    Diagnostic::new(
+                        location.source.name(),
+                        location.source.text(),
+                        location.span,
+                    )
+                });
+            self.eval_error(error)
+        })
+    }
 
-        let mut vm = VM::new(compiler, self);
-        // Generate Bytecode and place it into instruction_stack
-        // Interpret the Bytecode
-        let result = vm.run();
-        // The main_timer needs to be dropped before the BoaProfiler is.
-        drop(main_timer);
-        BoaProfiler::global().drop();
+    /// Gets the location in the source code of the error of the last script compiled or executed
+    /// by the context, if it failed.
+    ///
+    /// Parsing errors are located at the code that could not be parsed, and the errors thrown by
+    /// the script at the statement that threw them. The location is not known for the errors
+    /// thrown by the code compiled to bytecode, or by the scripts created from a
+    /// [`StatementList`] without their source code.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let source = "let a = 1;\nnull.property;";
+    /// context.eval_with_source_name(source, "script.js").unwrap_err();
+    ///
+    /// let diagnostic = context.last_diagnostic().unwrap();
+    /// assert_eq!(diagnostic.source_name(), "script.js");
+    /// assert_eq!(diagnostic.line_number(), 2);
+    /// assert_eq!(diagnostic.line(), "null.property;");
+    /// assert_eq!(diagnostic.caret(), "^^^^^^^^^^^^^");
+    /// ```
+    #[inline]
+    pub fn last_diagnostic(&self) -> Option<&Diagnostic> {
+        self.last_diagnostic.as_ref()
+    }
+
+    /// Records the statement at `span` as the one that threw `error`, unless the error was
+    /// already located by an inner statement.
+    pub(crate) fn record_error_location(&mut self, error: &Value, span: Span) {
+        if let Some(ref location) = self.error_location {
+            if same_value(&location.error, error) {
+                return;
+            }
+        }
+        if let Some(source) = self.active_source.clone() {
+            self.error_location = Some(ErrorLocation {
+                error: error.clone(),
+                source,
+                span,
+            });
+        }
+    }
+
+    /// Forgets the location of the error being propagated, once it is caught.
+    #[inline]
+    pub(crate) fn clear_error_location(&mut self) {
+        self.error_location = None;
+    }
+
+    /// Converts a value thrown by a script to the error returned by [`Context::execute`].
+    fn eval_error(&mut self, error: Value) -> EvalError {
+        if let Some((panic_error, message)) = self.panic_error.take() {
+            if same_value(&panic_error, &error) {
+                return EvalError::InternalPanic(message);
+            }
+        }
+        if let Some(stop_error) = self.tracer.as_mut().and_then(Tracer::take_stop_error) {
+            if same_value(&stop_error, &error) {
+                return EvalError::Terminated(TerminationReason::TraceHook);
+            }
+        }
+        match self.memory_limit_error.take() {
+            Some(memory_limit_error) if same_value(&memory_limit_error, &error) => {
+                EvalError::Terminated(TerminationReason::MemoryLimit)
+            }
+            _ => EvalError::Thrown(error),
+        }
+    }
+
+    /// Calls a native function, throwing an `Error` if it panics.
+    ///
+    /// The error can be caught by the script, and is reported as an [`EvalError::InternalPanic`]
+    /// if it is not. Native functions can run scripts, so the environments and the source of the
+    /// running script they left are restored.
+    pub(crate) fn call_native(
+        &mut self,
+        function: NativeFunction,
+        this: &Value,
+        args: &[Value],
+    ) -> Result<Value> {
+        let depth = self.realm.environment.depth();
+        let active_source = self.active_source.clone();
+        // See `Context::execute` for why the context is unwind safe enough.
+        match panic::catch_unwind(AssertUnwindSafe(|| function(this, args, self))) {
+            Ok(result) => result,
+            Err(payload) => {
+                self.realm.environment.truncate(depth);
+                self.active_source = active_source;
+                let message = panic_message(payload.as_ref());
+                let error = self.construct_error(
+                    ErrorKind::Error,
+                    format!("a native function panicked: {}", message),
+                );
+                self.panic_error = Some((error.clone(), message));
+                Err(error)
+            }
+        }
+    }
 
+    /// Gets the source code of the running script, if it is known.
+    #[inline]
+    pub(crate) fn active_source(&self) -> Option<&SourceText> {
+        self.active_source.as_ref()
+    }
+
+    /// Runs `f` with `source` as the source code of the running script, restoring the previous
+    /// one afterwards.
+    pub(crate) fn with_active_source<T, F>(&mut self, source: Option<SourceText>, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let previous = std::mem::replace(&mut self.active_source, source);
+        let result = f(self);
+        self.active_source = previous;
         result
     }
 
+    /// Runs a script or the body of a function.
+    #[cfg(not(feature = "vm"))]
+    #[inline]
+    pub(crate) fn run_statement_list(
+        &mut self,
+        statement_list: &StatementList,
+    ) -> Result<Completion> {
+        statement_list.run_statement(self)
+    }
+
+    /// Runs a script or the body of a function, in the VM if it is enabled and supports the code.
+    #[cfg(feature = "vm")]
+    pub(crate) fn run_statement_list(
+        &mut self,
+        statement_list: &StatementList,
+    ) -> Result<Completion> {
+        // The VM does not call the trace hook, count the executions of the statements nor check
+        // the memory limit.
+        if self.use_vm
+            && self.tracer.is_none()
+            && self.coverage.is_none()
+            && self.memory_limit.is_none()
+        {
+            if let Some(code) = statement_list.code_block() {
+                return VM::new(code, self).run();
+            }
+        }
+        statement_list.run_statement(self)
+    }
+
     /// Returns a structure that contains the JavaScript well known symbols.
     ///
     /// # Examples
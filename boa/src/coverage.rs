@@ -0,0 +1,354 @@
+//! Coverage of the statements executed by the scripts of a [`Context`](crate::Context).
+//!
+//! The contexts built with
+//! [`ContextBuilder::collect_coverage`](crate::context::ContextBuilder::collect_coverage) count
+//! the executions of each statement of the scripts they run, and
+//! [`Context::take_coverage`](crate::Context::take_coverage) returns the counts. The parser
+//! numbers the statements of each script, so counting an execution only increments an element of
+//! a vector.
+//!
+//! Only the scripts compiled from source code are covered, see
+//! [`Context::compile`](crate::Context::compile), and the code of the contexts using the VM is
+//! interpreted while the coverage is collected.
+
+use crate::syntax::ast::Span;
+use std::{cell::Cell, collections::BTreeMap, rc::Rc};
+
+/// The coverage of the scripts run by a context, by source name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    scripts: BTreeMap<String, ScriptCoverage>,
+}
+
+impl Coverage {
+    /// Gets the coverage of the scripts, sorted by source name.
+    pub fn scripts(&self) -> impl Iterator<Item = (&str, &ScriptCoverage)> {
+        self.scripts
+            .iter()
+            .map(|(name, script)| (name.as_str(), script))
+    }
+
+    /// Gets the coverage of the scripts named `source_name`.
+    pub fn script(&self, source_name: &str) -> Option<&ScriptCoverage> {
+        self.scripts.get(source_name)
+    }
+
+    /// Converts the coverage to the LCOV tracefile format, with the execution count of each line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::context::ContextBuilder;
+    ///
+    /// let mut context = ContextBuilder::new().collect_coverage(true).build();
+    /// context
+    ///     .eval_with_source_name("let a = 1;\nif (a > 1) {\n    a = 0;\n}", "script.js")
+    ///     .unwrap();
+    ///
+    /// let lcov = context.take_coverage().to_lcov();
+    /// assert_eq!(
+    ///     lcov,
+    ///     "TN:\nSF:script.js\nDA:1,1\nDA:2,1\nDA:3,0\nLF:3\nLH:2\nend_of_record\n"
+    /// );
+    /// ```
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (name, script) in self.scripts() {
+            let lines = script.lines();
+            let hit = lines.iter().filter(|(_, count)| *count > 0).count();
+
+            lcov.push_str("TN:\n");
+            lcov.push_str(&format!("SF:{}\n", name));
+            for (line, count) in &lines {
+                lcov.push_str(&format!("DA:{},{}\n", line, count));
+            }
+            lcov.push_str(&format!("LF:{}\nLH:{}\n", lines.len(), hit));
+            lcov.push_str("end_of_record\n");
+        }
+        lcov
+    }
+}
+
+/// The coverage of the scripts with the same source name.
+///
+/// The statements of the scripts compiled several times from the same source code are merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptCoverage {
+    statements: Vec<StatementCoverage>,
+}
+
+impl ScriptCoverage {
+    /// Gets the statements of the scripts, sorted by their position in the source code.
+    ///
+    /// The statements that never executed are included, with a count of zero.
+    pub fn statements(&self) -> &[StatementCoverage] {
+        &self.statements
+    }
+
+    /// Gets the execution count of each line starting a statement, sorted by line number.
+    ///
+    /// The count of a line is the largest count of the statements starting on it.
+    pub fn lines(&self) -> Vec<(u32, u32)> {
+        let mut lines = BTreeMap::new();
+        for statement in &self.statements {
+            let count = lines
+                .entry(statement.span.start().line_number())
+                .or_insert(0);
+            *count = statement.count.max(*count);
+        }
+        lines.into_iter().collect()
+    }
+
+    /// Gets the lines starting statements which never executed, sorted by line number.
+    pub fn uncovered_lines(&self) -> Vec<u32> {
+        self.lines()
+            .into_iter()
+            .filter(|(_, count)| *count == 0)
+            .map(|(line, _)| line)
+            .collect()
+    }
+
+    /// Adds the counts of the statements of a script, summing the counts of the same statements.
+    fn merge(&mut self, statements: &[Span], counts: &[Cell<u32>]) {
+        self.statements
+            .extend(
+                statements
+                    .iter()
+                    .zip(counts)
+                    .map(|(span, count)| StatementCoverage {
+                        span: *span,
+                        count: count.get(),
+                    }),
+            );
+        self.statements
+            .sort_by_key(|statement| (statement.span.start(), statement.span.end()));
+        self.statements.dedup_by(|statement, previous| {
+            if statement.span == previous.span {
+                previous.count = previous.count.saturating_add(statement.count);
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+/// The number of times a statement was executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatementCoverage {
+    span: Span,
+    count: u32,
+}
+
+impl StatementCoverage {
+    /// Gets the span of the statement in its script.
+    #[inline]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the number of times the statement was executed.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Whether the statement was executed at least once.
+    #[inline]
+    pub fn is_covered(&self) -> bool {
+        self.count > 0
+    }
+}
+
+/// The execution counts of the statements of a script, by the index the parser assigned to them.
+#[derive(Debug)]
+pub(crate) struct ScriptCounters {
+    name: Rc<str>,
+    statements: Rc<[Span]>,
+    counts: Box<[Cell<u32>]>,
+}
+
+impl ScriptCounters {
+    /// Counts an execution of the statement at `index`.
+    #[inline]
+    pub(crate) fn hit(&self, index: u32) {
+        if let Some(count) = self.counts.get(index as usize) {
+            count.set(count.get().saturating_add(1));
+        }
+    }
+}
+
+/// The counters of the scripts run by a context which collects the coverage.
+#[derive(Debug, Default)]
+pub(crate) struct CoverageCollector {
+    scripts: Vec<Rc<ScriptCounters>>,
+}
+
+impl CoverageCollector {
+    /// Gets the counters of the script named `name` with the given statements, which are shared by
+    /// all the executions of the script.
+    ///
+    /// Returns `None` if the statements of the script are not known.
+    pub(crate) fn counters(
+        &mut self,
+        name: &Rc<str>,
+        statements: &Rc<[Span]>,
+    ) -> Option<Rc<ScriptCounters>> {
+        if statements.is_empty() {
+            return None;
+        }
+        if let Some(counters) = self
+            .scripts
+            .iter()
+            .find(|counters| Rc::ptr_eq(&counters.statements, statements))
+        {
+            return Some(counters.clone());
+        }
+
+        let counters = Rc::new(ScriptCounters {
+            name: name.clone(),
+            statements: statements.clone(),
+            counts: statements.iter().map(|_| Cell::new(0)).collect(),
+        });
+        self.scripts.push(counters.clone());
+        Some(counters)
+    }
+
+    /// Returns the coverage collected so far, and resets the counts.
+    ///
+    /// The counters which are no longer shared by a running script or by the functions it defined
+    /// cannot count again, so they are dropped, instead of growing with every `eval`.
+    pub(crate) fn take(&mut self) -> Coverage {
+        let mut coverage = Coverage::default();
+        for counters in &self.scripts {
+            coverage
+                .scripts
+                .entry(counters.name.to_string())
+                .or_default()
+                .merge(&counters.statements, &counters.counts);
+            for count in counters.counts.iter() {
+                count.set(0);
+            }
+        }
+        self.scripts
+            .retain(|counters| Rc::strong_count(counters) > 1);
+        coverage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{context::ContextBuilder, syntax::ast::Position, Context};
+
+    const SIGN: &str = r#"
+function sign(x) {
+    if (x < 0) {
+        return "negative";
+    } else {
+        return "positive";
+    }
+}
+sign(1);
+sign(2);
+"#;
+
+    const ABS: &str = r#"
+function abs(x) {
+    if (x < 0)
+        return -x;
+    return x;
+}
+abs(1);
+"#;
+
+    #[test]
+    fn untaken_branch() {
+        let mut context = ContextBuilder::new().collect_coverage(true).build();
+        context.eval_with_source_name(SIGN, "sign.js").unwrap();
+
+        let coverage = context.take_coverage();
+        let script = coverage.script("sign.js").unwrap();
+        assert_eq!(
+            script.lines(),
+            [(2, 1), (3, 2), (4, 0), (6, 2), (9, 1), (10, 1)]
+        );
+        assert_eq!(script.uncovered_lines(), [4]);
+        assert_eq!(
+            script
+                .statements()
+                .iter()
+                .filter(|statement| !statement.is_covered())
+                .count(),
+            1
+        );
+
+        let lcov = coverage.to_lcov();
+        assert!(lcov.starts_with("TN:\nSF:sign.js\nDA:2,1\nDA:3,2\nDA:4,0\n"));
+        assert!(lcov.ends_with("LF:6\nLH:5\nend_of_record\n"));
+
+        // The statements nested without braces are counted as well.
+        context.eval_with_source_name(ABS, "abs.js").unwrap();
+        let coverage = context.take_coverage();
+        let script = coverage.script("abs.js").unwrap();
+        assert_eq!(script.lines(), [(2, 1), (3, 1), (4, 0), (5, 1), (7, 1)]);
+        assert_eq!(script.uncovered_lines(), [4]);
+    }
+
+    #[test]
+    fn counts_are_reset() {
+        let mut context = ContextBuilder::new().collect_coverage(true).build();
+        context.eval_with_source_name(SIGN, "sign.js").unwrap();
+        context.take_coverage();
+
+        // The functions defined by a script keep counting into its counters.
+        context
+            .eval_with_source_name("sign(-1);", "call.js")
+            .unwrap();
+        let coverage = context.take_coverage();
+        let names: Vec<_> = coverage.scripts().map(|(name, _)| name).collect();
+        assert_eq!(names, ["call.js", "sign.js"]);
+        assert_eq!(
+            coverage.script("sign.js").unwrap().lines(),
+            [(2, 0), (3, 1), (4, 1), (6, 0), (9, 0), (10, 0)]
+        );
+    }
+
+    #[test]
+    fn scripts_with_the_same_source_name_are_merged() {
+        let mut context = ContextBuilder::new().collect_coverage(true).build();
+        let script = context.compile_with_source_name("1;", "one.js").unwrap();
+        context.execute(&script).unwrap();
+        context.execute(&script).unwrap();
+        context.eval_with_source_name("1;", "one.js").unwrap();
+
+        let coverage = context.take_coverage();
+        assert_eq!(coverage.script("one.js").unwrap().lines(), [(1, 3)]);
+    }
+
+    #[test]
+    fn unused_counters_are_dropped() {
+        let mut collector = CoverageCollector::default();
+        let name: Rc<str> = "one.js".into();
+        let statements: Rc<[Span]> =
+            vec![Span::new(Position::new(1, 1), Position::new(1, 3))].into();
+        let counters = collector.counters(&name, &statements).unwrap();
+        counters.hit(0);
+
+        let coverage = collector.take();
+        assert_eq!(coverage.script("one.js").unwrap().lines(), [(1, 1)]);
+        assert_eq!(collector.scripts.len(), 1);
+
+        drop(counters);
+        collector.take();
+        assert!(collector.scripts.is_empty());
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let mut context = Context::new();
+        context.eval_with_source_name(SIGN, "sign.js").unwrap();
+
+        assert_eq!(context.take_coverage(), Coverage::default());
+    }
+}
@@ -11,10 +11,17 @@ use crate::{
         environment_record_trait::EnvironmentRecordTrait,
         lexical_environment::{Environment, EnvironmentType},
     },
-    gc::{Finalize, Trace},
+    gc::{custom_trace, Finalize, Trace},
+    snapshot::HeapCopier,
     Value,
 };
-use rustc_hash::FxHashMap;
+use indexmap::IndexMap;
+use rustc_hash::FxHasher;
+use std::{
+    hash::BuildHasherDefault,
+    iter::FromIterator,
+    ops::{Deref, DerefMut},
+};
 
 /// Declarative Bindings have a few properties for book keeping purposes, such as mutability (const vs let).
 /// Can it be deleted? and strict mode.
@@ -29,31 +36,156 @@ pub struct DeclarativeEnvironmentRecordBinding {
     pub strict: bool,
 }
 
+impl DeclarativeEnvironmentRecordBinding {
+    /// Gets the value of the binding named `name`, which must have been initialized.
+    pub(crate) fn get_value(&self, name: &str) -> Result<Value, ErrorKind> {
+        if let Some(ref val) = self.value {
+            Ok(val.clone())
+        } else {
+            Err(ErrorKind::new_reference_error(format!(
+                "{} is an uninitialized binding",
+                name
+            )))
+        }
+    }
+
+    /// Sets the value of the binding named `name`, which must have been initialized.
+    #[allow(clippy::else_if_without_else)]
+    pub(crate) fn set_value(
+        &mut self,
+        name: &str,
+        value: Value,
+        mut strict: bool,
+    ) -> Result<(), ErrorKind> {
+        if self.strict {
+            strict = true
+        }
+        if self.value.is_none() {
+            return Err(ErrorKind::new_reference_error(format!(
+                "{} has not been initialized",
+                name
+            )));
+        }
+        if self.mutable {
+            self.value = Some(value);
+        } else if strict {
+            return Err(ErrorKind::new_type_error(format!(
+                "Cannot mutate an immutable binding {}",
+                name
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// The bindings of a declarative record.
+///
+/// They are kept in creation order, so that the identifiers resolved after parsing can reach
+/// their binding by its slot, without hashing the name.
+#[derive(Debug, Clone, Default)]
+pub struct Bindings(
+    IndexMap<String, DeclarativeEnvironmentRecordBinding, BuildHasherDefault<FxHasher>>,
+);
+
+impl Bindings {
+    /// Gets the binding at `slot`, if it is the binding named `name`.
+    #[inline]
+    pub fn get_at(&self, slot: usize, name: &str) -> Option<&DeclarativeEnvironmentRecordBinding> {
+        self.0
+            .get_index(slot)
+            .filter(|(key, _)| key.as_str() == name)
+            .map(|(_, binding)| binding)
+    }
+
+    /// Gets the binding at `slot` mutably, if it is the binding named `name`.
+    #[inline]
+    pub fn get_at_mut(
+        &mut self,
+        slot: usize,
+        name: &str,
+    ) -> Option<&mut DeclarativeEnvironmentRecordBinding> {
+        self.0
+            .get_index_mut(slot)
+            .filter(|(key, _)| key.as_str() == name)
+            .map(|(_, binding)| binding)
+    }
+}
+
+impl Deref for Bindings {
+    type Target =
+        IndexMap<String, DeclarativeEnvironmentRecordBinding, BuildHasherDefault<FxHasher>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Bindings {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromIterator<(String, DeclarativeEnvironmentRecordBinding)> for Bindings {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (String, DeclarativeEnvironmentRecordBinding)>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Finalize for Bindings {}
+unsafe impl Trace for Bindings {
+    custom_trace!(this, {
+        for binding in this.0.values() {
+            mark(binding);
+        }
+    });
+}
+
 /// A declarative Environment Record binds the set of identifiers defined by the
 /// declarations contained within its scope.
 #[derive(Debug, Trace, Finalize, Clone)]
 pub struct DeclarativeEnvironmentRecord {
-    pub env_rec: FxHashMap<String, DeclarativeEnvironmentRecordBinding>,
+    pub env_rec: Bindings,
     pub outer_env: Option<Environment>,
 }
 
+impl DeclarativeEnvironmentRecord {
+    /// Copies the record for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            env_rec: copier.copy_bindings(&self.env_rec),
+            outer_env: self
+                .outer_env
+                .as_ref()
+                .map(|env| copier.copy_environment(env)),
+        }
+    }
+}
+
 impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
     fn has_binding(&self, name: &str) -> bool {
         self.env_rec.contains_key(name)
     }
 
+    fn binding_names(&self) -> Vec<String> {
+        self.env_rec.keys().cloned().collect()
+    }
+
     fn create_mutable_binding(
         &mut self,
         name: String,
         deletion: bool,
         allow_name_reuse: bool,
     ) -> Result<(), ErrorKind> {
-        if !allow_name_reuse {
-            assert!(
-                !self.env_rec.contains_key(&name),
+        if !allow_name_reuse && self.env_rec.contains_key(&name) {
+            return Err(ErrorKind::new_syntax_error(format!(
                 "Identifier {} has already been declared",
                 name
-            );
+            )));
         }
 
         self.env_rec.insert(
@@ -69,11 +201,12 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
     }
 
     fn create_immutable_binding(&mut self, name: String, strict: bool) -> Result<(), ErrorKind> {
-        assert!(
-            !self.env_rec.contains_key(&name),
-            "Identifier {} has already been declared",
-            name
-        );
+        if self.env_rec.contains_key(&name) {
+            return Err(ErrorKind::new_syntax_error(format!(
+                "Identifier {} has already been declared",
+                name
+            )));
+        }
 
         self.env_rec.insert(
             name,
@@ -97,12 +230,11 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         panic!("record must have binding for {}", name);
     }
 
-    #[allow(clippy::else_if_without_else)]
     fn set_mutable_binding(
         &mut self,
         name: &str,
         value: Value,
-        mut strict: bool,
+        strict: bool,
     ) -> Result<(), ErrorKind> {
         if self.env_rec.get(name).is_none() {
             if strict {
@@ -117,48 +249,37 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
             return Ok(());
         }
 
-        let record: &mut DeclarativeEnvironmentRecordBinding = self.env_rec.get_mut(name).unwrap();
-        if record.strict {
-            strict = true
-        }
-        if record.value.is_none() {
-            return Err(ErrorKind::new_reference_error(format!(
-                "{} has not been initialized",
-                name
-            )));
-        }
-        if record.mutable {
-            record.value = Some(value);
-        } else if strict {
-            return Err(ErrorKind::new_type_error(format!(
-                "Cannot mutate an immutable binding {}",
-                name
-            )));
-        }
-
-        Ok(())
+        self.env_rec
+            .get_mut(name)
+            .unwrap()
+            .set_value(name, value, strict)
     }
 
     fn get_binding_value(&self, name: &str, _strict: bool) -> Result<Value, ErrorKind> {
         if let Some(binding) = self.env_rec.get(name) {
-            if let Some(ref val) = binding.value {
-                Ok(val.clone())
-            } else {
-                Err(ErrorKind::new_reference_error(format!(
-                    "{} is an uninitialized binding",
-                    name
-                )))
-            }
+            binding.get_value(name)
         } else {
             panic!("Cannot get binding value for {}", name);
         }
     }
 
+    fn binding_at(&self, slot: usize, name: &str) -> Option<&DeclarativeEnvironmentRecordBinding> {
+        self.env_rec.get_at(slot, name)
+    }
+
+    fn binding_at_mut(
+        &mut self,
+        slot: usize,
+        name: &str,
+    ) -> Option<&mut DeclarativeEnvironmentRecordBinding> {
+        self.env_rec.get_at_mut(slot, name)
+    }
+
     fn delete_binding(&mut self, name: &str) -> bool {
         match self.env_rec.get(name) {
             Some(binding) => {
                 if binding.can_delete {
-                    self.env_rec.remove(name);
+                    self.env_rec.shift_remove(name);
                     true
                 } else {
                     false
@@ -180,6 +301,10 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         false
     }
 
+    fn get_super_base(&self) -> Value {
+        Value::undefined()
+    }
+
     fn with_base_object(&self) -> Value {
         Value::undefined()
     }
@@ -202,4 +327,8 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
             None => None,
         }
     }
+
+    fn copy_record(&self, copier: &mut HeapCopier) -> Box<dyn EnvironmentRecordTrait> {
+        Box::new(self.copy_with(copier))
+    }
 }
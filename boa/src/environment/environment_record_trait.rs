@@ -10,12 +10,27 @@
 //!
 use super::ErrorKind;
 use crate::{
-    environment::lexical_environment::{Environment, EnvironmentType},
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecordBinding,
+        lexical_environment::{Environment, EnvironmentType},
+    },
     gc::{Finalize, Trace},
+    object::GcObject,
+    snapshot::HeapCopier,
     Value,
 };
 use std::fmt::Debug;
 
+/// The value of a binding, or the getter to call to get it, see
+/// [`EnvironmentRecordTrait::get_binding`].
+#[derive(Debug, Clone)]
+pub enum BindingValue {
+    /// The value of the binding.
+    Value(Value),
+    /// The getter of an accessor property of a binding object, with the object to call it on.
+    Getter(GcObject, Value),
+}
+
 /// <https://tc39.es/ecma262/#sec-environment-records>
 ///
 /// In the ECMAScript specification Environment Records are hierachical and have a base class with abstract methods.
@@ -24,6 +39,12 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Determine if an Environment Record has a binding for the String value N. Return true if it does and false if it does not.
     fn has_binding(&self, name: &str) -> bool;
 
+    /// Gets the names of the bindings of the Environment Record.
+    ///
+    /// The declarative records list them in the order they were created, and the object records
+    /// list the string keys of the own properties of their binding object.
+    fn binding_names(&self) -> Vec<String>;
+
     /// Create a new but uninitialized mutable binding in an Environment Record. The String value N is the text of the bound name.
     /// If the Boolean argument deletion is true the binding may be subsequently deleted.
     ///
@@ -66,6 +87,38 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// otherwise require strict mode reference semantics.
     fn get_binding_value(&self, name: &str, strict: bool) -> Result<Value, ErrorKind>;
 
+    /// Gets the value of the binding `name` like `get_binding_value`, or the getter to call to get
+    /// it if the binding is an accessor property of a binding object.
+    ///
+    /// Calling the getter needs a `Context`, so `get_binding_value` returns `undefined` for such
+    /// bindings and the caller has to call the getter itself.
+    fn get_binding(&self, name: &str, strict: bool) -> Result<BindingValue, ErrorKind> {
+        self.get_binding_value(name, strict)
+            .map(BindingValue::Value)
+    }
+
+    /// Gets the binding at `slot`, if the record stores its bindings by slot and the binding
+    /// there is named `name`.
+    ///
+    /// This is the fast path of the identifiers resolved after parsing, which fall back to the
+    /// lookup by name when it returns `None`.
+    fn binding_at(
+        &self,
+        _slot: usize,
+        _name: &str,
+    ) -> Option<&DeclarativeEnvironmentRecordBinding> {
+        None
+    }
+
+    /// Mutable version of [`binding_at`](EnvironmentRecordTrait::binding_at).
+    fn binding_at_mut(
+        &mut self,
+        _slot: usize,
+        _name: &str,
+    ) -> Option<&mut DeclarativeEnvironmentRecordBinding> {
+        None
+    }
+
     /// Delete a binding from an Environment Record.
     /// The String value name is the text of the bound name.
     /// If a binding for name exists, remove the binding and return true.
@@ -83,6 +136,10 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Return true if it does and false if it does not.
     fn has_super_binding(&self) -> bool;
 
+    /// Return the object `super` property references are looked up on,
+    /// or undefined if the Environment Record has no super binding.
+    fn get_super_base(&self) -> Value;
+
     /// If this Environment Record is associated with a with statement, return the with object.
     /// Otherwise, return undefined.
     fn with_base_object(&self) -> Value;
@@ -98,4 +155,13 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
 
     /// Fetch global variable
     fn get_global_object(&self) -> Option<Value>;
+
+    /// Gets the function whose call created the Environment Record, if it is a function
+    /// Environment Record.
+    fn get_function_object(&self) -> Option<GcObject> {
+        None
+    }
+
+    /// Copies the record for a [`Snapshot`](crate::snapshot::Snapshot).
+    fn copy_record(&self, copier: &mut HeapCopier) -> Box<dyn EnvironmentRecordTrait>;
 }
@@ -11,15 +11,15 @@
 use super::ErrorKind;
 use crate::{
     environment::{
-        declarative_environment_record::DeclarativeEnvironmentRecordBinding,
+        declarative_environment_record::{Bindings, DeclarativeEnvironmentRecordBinding},
         environment_record_trait::EnvironmentRecordTrait,
         lexical_environment::{Environment, EnvironmentType},
     },
     gc::{empty_trace, Finalize, Trace},
     object::GcObject,
+    snapshot::HeapCopier,
     Value,
 };
-use rustc_hash::FxHashMap;
 
 /// Different binding status for `this`.
 /// Usually set on a function environment record
@@ -40,7 +40,7 @@ unsafe impl Trace for BindingStatus {
 /// <https://tc39.es/ecma262/#table-16>
 #[derive(Debug, Trace, Finalize, Clone)]
 pub struct FunctionEnvironmentRecord {
-    pub env_rec: FxHashMap<String, DeclarativeEnvironmentRecordBinding>,
+    pub env_rec: Bindings,
     /// This is the this value used for this invocation of the function.
     pub this_value: Value,
     /// If the value is "lexical", this is an ArrowFunction and does not have a local this value.
@@ -61,6 +61,22 @@ pub struct FunctionEnvironmentRecord {
 }
 
 impl FunctionEnvironmentRecord {
+    /// Copies the record for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            env_rec: copier.copy_bindings(&self.env_rec),
+            this_value: copier.copy_value(&self.this_value),
+            this_binding_status: self.this_binding_status.clone(),
+            function: copier.copy_object(&self.function),
+            home_object: copier.copy_value(&self.home_object),
+            new_target: copier.copy_value(&self.new_target),
+            outer_env: self
+                .outer_env
+                .as_ref()
+                .map(|env| copier.copy_environment(env)),
+        }
+    }
+
     pub fn bind_this_value(&mut self, value: Value) -> Result<Value, ErrorKind> {
         match self.this_binding_status {
             // You can not bind an arrow function, their `this` value comes from the lexical scope above
@@ -79,18 +95,6 @@ impl FunctionEnvironmentRecord {
             }
         }
     }
-
-    pub fn get_super_base(&self) -> Value {
-        let home = &self.home_object;
-        if home.is_undefined() {
-            Value::Undefined
-        } else {
-            assert!(home.is_object());
-            home.as_object()
-                .expect("home_object must be an Object")
-                .prototype_instance()
-        }
-    }
 }
 
 impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
@@ -98,18 +102,21 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         self.env_rec.contains_key(name)
     }
 
+    fn binding_names(&self) -> Vec<String> {
+        self.env_rec.keys().cloned().collect()
+    }
+
     fn create_mutable_binding(
         &mut self,
         name: String,
         deletion: bool,
         allow_name_reuse: bool,
     ) -> Result<(), ErrorKind> {
-        if !allow_name_reuse {
-            assert!(
-                !self.env_rec.contains_key(&name),
+        if !allow_name_reuse && self.env_rec.contains_key(&name) {
+            return Err(ErrorKind::new_syntax_error(format!(
                 "Identifier {} has already been declared",
                 name
-            );
+            )));
         }
 
         self.env_rec.insert(
@@ -138,11 +145,12 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
     }
 
     fn create_immutable_binding(&mut self, name: String, strict: bool) -> Result<(), ErrorKind> {
-        assert!(
-            !self.env_rec.contains_key(&name),
-            "Identifier {} has already been declared",
-            name
-        );
+        if self.env_rec.contains_key(&name) {
+            return Err(ErrorKind::new_syntax_error(format!(
+                "Identifier {} has already been declared",
+                name
+            )));
+        }
 
         self.env_rec.insert(
             name,
@@ -166,12 +174,11 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         panic!("record must have binding for {}", name)
     }
 
-    #[allow(clippy::else_if_without_else)]
     fn set_mutable_binding(
         &mut self,
         name: &str,
         value: Value,
-        mut strict: bool,
+        strict: bool,
     ) -> Result<(), ErrorKind> {
         if self.env_rec.get(name).is_none() {
             if strict {
@@ -186,48 +193,37 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
             return Ok(());
         }
 
-        let record: &mut DeclarativeEnvironmentRecordBinding = self.env_rec.get_mut(name).unwrap();
-        if record.strict {
-            strict = true
-        }
-        if record.value.is_none() {
-            return Err(ErrorKind::new_reference_error(format!(
-                "{} has not been initialized",
-                name
-            )));
-        }
-        if record.mutable {
-            record.value = Some(value);
-        } else if strict {
-            return Err(ErrorKind::new_type_error(format!(
-                "Cannot mutate an immutable binding {}",
-                name
-            )));
-        }
-
-        Ok(())
+        self.env_rec
+            .get_mut(name)
+            .unwrap()
+            .set_value(name, value, strict)
     }
 
     fn get_binding_value(&self, name: &str, _strict: bool) -> Result<Value, ErrorKind> {
         if let Some(binding) = self.env_rec.get(name) {
-            if let Some(ref val) = binding.value {
-                Ok(val.clone())
-            } else {
-                Err(ErrorKind::new_reference_error(format!(
-                    "{} is an uninitialized binding",
-                    name
-                )))
-            }
+            binding.get_value(name)
         } else {
             panic!("Cannot get binding value for {}", name);
         }
     }
 
+    fn binding_at(&self, slot: usize, name: &str) -> Option<&DeclarativeEnvironmentRecordBinding> {
+        self.env_rec.get_at(slot, name)
+    }
+
+    fn binding_at_mut(
+        &mut self,
+        slot: usize,
+        name: &str,
+    ) -> Option<&mut DeclarativeEnvironmentRecordBinding> {
+        self.env_rec.get_at_mut(slot, name)
+    }
+
     fn delete_binding(&mut self, name: &str) -> bool {
         match self.env_rec.get(name) {
             Some(binding) => {
                 if binding.can_delete {
-                    self.env_rec.remove(name);
+                    self.env_rec.shift_remove(name);
                     true
                 } else {
                     false
@@ -245,6 +241,18 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         }
     }
 
+    fn get_super_base(&self) -> Value {
+        let home = &self.home_object;
+        if home.is_undefined() {
+            Value::Undefined
+        } else {
+            assert!(home.is_object());
+            home.as_object()
+                .expect("home_object must be an Object")
+                .prototype_instance()
+        }
+    }
+
     fn has_this_binding(&self) -> bool {
         !matches!(self.this_binding_status, BindingStatus::Lexical)
     }
@@ -274,4 +282,12 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
             None => None,
         }
     }
+
+    fn get_function_object(&self) -> Option<GcObject> {
+        Some(self.function.clone())
+    }
+
+    fn copy_record(&self, copier: &mut HeapCopier) -> Box<dyn EnvironmentRecordTrait> {
+        Box::new(self.copy_with(copier))
+    }
 }
@@ -11,12 +11,13 @@ use super::ErrorKind;
 use crate::{
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
-        environment_record_trait::EnvironmentRecordTrait,
+        environment_record_trait::{BindingValue, EnvironmentRecordTrait},
         lexical_environment::{Environment, EnvironmentType},
         object_environment_record::ObjectEnvironmentRecord,
     },
     gc::{Finalize, Trace},
     property::{Attribute, DataDescriptor},
+    snapshot::HeapCopier,
     Value,
 };
 use rustc_hash::FxHashSet;
@@ -30,6 +31,16 @@ pub struct GlobalEnvironmentRecord {
 }
 
 impl GlobalEnvironmentRecord {
+    /// Copies the record for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            object_record: self.object_record.copy_with(copier),
+            global_this_binding: copier.copy_value(&self.global_this_binding),
+            declarative_record: self.declarative_record.copy_with(copier),
+            var_names: self.var_names.clone(),
+        }
+    }
+
     pub fn has_var_declaration(&self, name: &str) -> bool {
         self.var_names.contains(name)
     }
@@ -131,6 +142,12 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         self.object_record.has_binding(name)
     }
 
+    fn binding_names(&self) -> Vec<String> {
+        let mut names = self.object_record.binding_names();
+        names.extend(self.declarative_record.binding_names());
+        names
+    }
+
     fn create_mutable_binding(
         &mut self,
         name: String,
@@ -193,6 +210,13 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         self.object_record.get_binding_value(name, strict)
     }
 
+    fn get_binding(&self, name: &str, strict: bool) -> Result<BindingValue, ErrorKind> {
+        if self.declarative_record.has_binding(&name) {
+            return self.declarative_record.get_binding(name, strict);
+        }
+        self.object_record.get_binding(name, strict)
+    }
+
     fn delete_binding(&mut self, name: &str) -> bool {
         if self.declarative_record.has_binding(&name) {
             return self.declarative_record.delete_binding(name);
@@ -220,6 +244,10 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         false
     }
 
+    fn get_super_base(&self) -> Value {
+        Value::undefined()
+    }
+
     fn with_base_object(&self) -> Value {
         Value::undefined()
     }
@@ -240,4 +268,8 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
     fn get_global_object(&self) -> Option<Value> {
         Some(self.global_this_binding.clone())
     }
+
+    fn copy_record(&self, copier: &mut HeapCopier) -> Box<dyn EnvironmentRecordTrait> {
+        Box::new(self.copy_with(copier))
+    }
 }
@@ -8,17 +8,18 @@
 use super::ErrorKind;
 use crate::{
     environment::{
-        declarative_environment_record::DeclarativeEnvironmentRecord,
-        environment_record_trait::EnvironmentRecordTrait,
+        declarative_environment_record::{Bindings, DeclarativeEnvironmentRecord},
+        environment_record_trait::{BindingValue, EnvironmentRecordTrait},
         function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
         global_environment_record::GlobalEnvironmentRecord,
         object_environment_record::ObjectEnvironmentRecord,
     },
     object::GcObject,
+    snapshot::HeapCopier,
     BoaProfiler, Value,
 };
 use gc::{Gc, GcCell};
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashSet;
 use std::{collections::VecDeque, error, fmt};
 
 /// Environments are wrapped in a Box and then in a GC wrapper
@@ -43,6 +44,33 @@ pub enum VariableScope {
     Function,
 }
 
+/// The location of a binding resolved after parsing.
+///
+/// `depth` counts the environments pushed on the stack since the one holding the binding, and
+/// `slot` is the position of the binding in that environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BindingLocator {
+    depth: usize,
+    slot: usize,
+}
+
+impl BindingLocator {
+    /// Creates a new `BindingLocator`.
+    pub fn new(depth: usize, slot: usize) -> Self {
+        Self { depth, slot }
+    }
+
+    /// Gets the number of environments between the current one and the one holding the binding.
+    pub fn depth(self) -> usize {
+        self.depth
+    }
+
+    /// Gets the position of the binding in its environment.
+    pub fn slot(self) -> usize {
+        self.slot
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct LexicalEnvironment {
     environment_stack: VecDeque<Environment>,
@@ -92,6 +120,17 @@ impl LexicalEnvironment {
         lexical_env
     }
 
+    /// Copies the environments for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            environment_stack: self
+                .environment_stack
+                .iter()
+                .map(|env| copier.copy_environment(env))
+                .collect(),
+        }
+    }
+
     pub fn push(&mut self, env: Environment) {
         let current_env: Environment = self.get_current_environment().clone();
         env.borrow_mut().set_outer_environment(current_env);
@@ -102,6 +141,23 @@ impl LexicalEnvironment {
         self.environment_stack.pop_back()
     }
 
+    /// Gets the number of environments on the stack.
+    pub(crate) fn depth(&self) -> usize {
+        self.environment_stack.len()
+    }
+
+    /// Pops the environments above `depth`, which were left on the stack by a statement exited
+    /// early, by an exception or a `return`.
+    pub(crate) fn truncate(&mut self, depth: usize) {
+        self.environment_stack.truncate(depth);
+    }
+
+    /// Gets the environment `depth` environments below the current one.
+    fn environment_at(&self, depth: usize) -> Option<&Environment> {
+        let index = self.environment_stack.len().checked_sub(depth + 1)?;
+        self.environment_stack.get(index)
+    }
+
     pub fn environments(&self) -> impl Iterator<Item = &Environment> {
         self.environment_stack.iter().rev()
     }
@@ -121,6 +177,20 @@ impl LexicalEnvironment {
             .unwrap_or_else(|| Ok(Value::Undefined))
     }
 
+    /// Returns the object `super` property references are looked up on, or `None` if the closest
+    /// function with a `this` binding is not a method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsuperbase
+    pub fn get_super_base(&self) -> Option<Value> {
+        self.environments()
+            .find(|env| env.borrow().has_this_binding())
+            .filter(|env| env.borrow().has_super_binding())
+            .map(|env| env.borrow().get_super_base())
+    }
+
     pub fn create_mutable_binding(
         &mut self,
         name: String,
@@ -249,12 +319,61 @@ impl LexicalEnvironment {
                 )))
             })
     }
+
+    /// Gets the value of the binding `name`, or the getter to call to get it if the binding is an
+    /// accessor property of the global object or of a `with` statement object.
+    pub fn get_binding(&self, name: &str) -> Result<BindingValue, ErrorKind> {
+        self.environments()
+            .find(|env| env.borrow().has_binding(name))
+            .map(|env| env.borrow().get_binding(name, false))
+            .unwrap_or_else(|| {
+                Err(ErrorKind::new_reference_error(format!(
+                    "{} is not defined",
+                    name
+                )))
+            })
+    }
+
+    /// Gets the value of the binding `name`, or the getter to call to get it, resolved to
+    /// `locator` after parsing.
+    ///
+    /// Falls back to the lookup by name if the binding isn't at that location.
+    pub fn get_binding_at(
+        &self,
+        locator: BindingLocator,
+        name: &str,
+    ) -> Result<BindingValue, ErrorKind> {
+        if let Some(env) = self.environment_at(locator.depth()) {
+            if let Some(binding) = env.borrow().binding_at(locator.slot(), name) {
+                return binding.get_value(name).map(BindingValue::Value);
+            }
+        }
+        self.get_binding(name)
+    }
+
+    /// Sets the value of the binding `name`, resolved to `locator` after parsing.
+    ///
+    /// Falls back to the lookup by name if the binding isn't at that location.
+    pub fn set_mutable_binding_at(
+        &mut self,
+        locator: BindingLocator,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), ErrorKind> {
+        if let Some(env) = self.environment_at(locator.depth()) {
+            if let Some(binding) = env.borrow_mut().binding_at_mut(locator.slot(), name) {
+                return binding.set_value(name, value, strict);
+            }
+        }
+        self.set_mutable_binding(name, value, strict)
+    }
 }
 
 pub fn new_declarative_environment(env: Option<Environment>) -> Environment {
     let _timer = BoaProfiler::global().start_event("new_declarative_environment", "env");
     let boxed_env = Box::new(DeclarativeEnvironmentRecord {
-        env_rec: FxHashMap::default(),
+        env_rec: Bindings::default(),
         outer_env: env,
     });
 
@@ -267,12 +386,13 @@ pub fn new_function_environment(
     outer: Option<Environment>,
     binding_status: BindingStatus,
     new_target: Value,
+    home_object: Value,
 ) -> Environment {
     let mut func_env = FunctionEnvironmentRecord {
-        env_rec: FxHashMap::default(),
+        env_rec: Bindings::default(),
         function: f,
         this_binding_status: binding_status,
-        home_object: Value::undefined(),
+        home_object,
         new_target,
         outer_env: outer, // this will come from Environment set as a private property of F - https://tc39.es/ecma262/#sec-ecmascript-function-objects
         this_value: Value::undefined(),
@@ -310,7 +430,7 @@ pub fn new_global_environment(global: Value, this_value: Value) -> Environment {
     };
 
     let dcl_rec = DeclarativeEnvironmentRecord {
-        env_rec: FxHashMap::default(),
+        env_rec: Bindings::default(),
         outer_env: None,
     };
 
@@ -6,11 +6,13 @@ pub mod function_environment_record;
 pub mod global_environment_record;
 pub mod lexical_environment;
 pub mod object_environment_record;
+pub mod scope;
 
 #[derive(Debug)]
 pub enum ErrorKind {
     ReferenceError(Box<str>),
     TypeError(Box<str>),
+    SyntaxError(Box<str>),
 }
 
 use crate::value::Value;
@@ -21,6 +23,7 @@ impl ErrorKind {
         match self {
             ErrorKind::ReferenceError(msg) => ctx.construct_reference_error(msg.clone()),
             ErrorKind::TypeError(msg) => ctx.construct_type_error(msg.clone()),
+            ErrorKind::SyntaxError(msg) => ctx.construct_syntax_error(msg.clone()),
         }
     }
 
@@ -37,4 +40,11 @@ impl ErrorKind {
     {
         Self::TypeError(msg.into())
     }
+
+    pub fn new_syntax_error<M>(msg: M) -> Self
+    where
+        M: Into<Box<str>>,
+    {
+        Self::SyntaxError(msg.into())
+    }
 }
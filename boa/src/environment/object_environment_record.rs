@@ -10,11 +10,12 @@ use super::*;
 use crate::property::PropertyDescriptor;
 use crate::{
     environment::{
-        environment_record_trait::EnvironmentRecordTrait,
+        environment_record_trait::{BindingValue, EnvironmentRecordTrait},
         lexical_environment::{Environment, EnvironmentType},
     },
     gc::{Finalize, Trace},
-    property::{Attribute, DataDescriptor},
+    property::{Attribute, DataDescriptor, PropertyKey},
+    snapshot::HeapCopier,
     Value,
 };
 
@@ -25,6 +26,20 @@ pub struct ObjectEnvironmentRecord {
     pub outer_env: Option<Environment>,
 }
 
+impl ObjectEnvironmentRecord {
+    /// Copies the record for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            bindings: copier.copy_value(&self.bindings),
+            with_environment: self.with_environment,
+            outer_env: self
+                .outer_env
+                .as_ref()
+                .map(|env| copier.copy_environment(env)),
+        }
+    }
+}
+
 impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     fn has_binding(&self, name: &str) -> bool {
         if self.bindings.has_field(name) {
@@ -37,6 +52,20 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         }
     }
 
+    fn binding_names(&self) -> Vec<String> {
+        match self.bindings.as_object() {
+            Some(object) => object
+                .own_property_keys()
+                .into_iter()
+                .filter_map(|key| match key {
+                    PropertyKey::String(name) => Some(name.to_string()),
+                    PropertyKey::Symbol(_) | PropertyKey::Index(_) => None,
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
     fn create_mutable_binding(
         &mut self,
         name: String,
@@ -75,12 +104,27 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         strict: bool,
     ) -> Result<(), ErrorKind> {
         debug_assert!(value.is_object() || value.is_function());
-        let mut property = DataDescriptor::new(value, Attribute::ENUMERABLE);
-        property.set_configurable(strict);
-        self.bindings
-            .as_object()
-            .expect("binding object")
-            .insert(name, property);
+        let mut bindings = self.bindings.as_object().expect("binding object");
+        let property = match bindings.get_own_property(&name.into()) {
+            Some(PropertyDescriptor::Data(ref desc)) if !desc.writable() => {
+                if strict {
+                    return Err(ErrorKind::new_type_error(format!(
+                        "Cannot assign to read only binding {}",
+                        name
+                    )));
+                }
+                return Ok(());
+            }
+            Some(PropertyDescriptor::Data(ref desc)) => {
+                DataDescriptor::new(value, desc.attributes())
+            }
+            // The setter can't be called without a context, so the accessor is replaced.
+            Some(PropertyDescriptor::Accessor(ref desc)) => {
+                DataDescriptor::new(value, desc.attributes() | Attribute::WRITABLE)
+            }
+            _ => DataDescriptor::new(value, Attribute::all()),
+        };
+        bindings.insert(name, property);
         Ok(())
     }
 
@@ -100,6 +144,19 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         }
     }
 
+    fn get_binding(&self, name: &str, strict: bool) -> Result<BindingValue, ErrorKind> {
+        match self.bindings.get_property(name) {
+            Some(PropertyDescriptor::Data(ref desc)) => Ok(BindingValue::Value(desc.value())),
+            Some(PropertyDescriptor::Accessor(ref accessor)) => Ok(match accessor.getter() {
+                Some(getter) => BindingValue::Getter(getter.clone(), self.bindings.clone()),
+                None => BindingValue::Value(Value::undefined()),
+            }),
+            None => self
+                .get_binding_value(name, strict)
+                .map(BindingValue::Value),
+        }
+    }
+
     fn delete_binding(&mut self, name: &str) -> bool {
         self.bindings.remove_property(name);
         true
@@ -117,6 +174,10 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         false
     }
 
+    fn get_super_base(&self) -> Value {
+        Value::undefined()
+    }
+
     fn with_base_object(&self) -> Value {
         // Object Environment Records return undefined as their
         // WithBaseObject unless their withEnvironment flag is true.
@@ -149,4 +210,8 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
             None
         }
     }
+
+    fn copy_record(&self, copier: &mut HeapCopier) -> Box<dyn EnvironmentRecordTrait> {
+        Box::new(self.copy_with(copier))
+    }
 }
@@ -0,0 +1,212 @@
+//! # Scopes
+//!
+//! A read-only view of the environments of a [`Context`](crate::Context), to inspect the
+//! variables visible to the running code, for debuggers and the autocompletion of REPLs.
+
+use crate::{
+    environment::lexical_environment::{Environment, EnvironmentType},
+    Value,
+};
+
+/// The kind of code a [`Scope`] holds the variables of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+    /// The top level of the scripts, whose variables include the properties of the global object.
+    Global,
+    /// The body of a function, with its parameters and its `var` declarations.
+    Function,
+    /// A block, with its `let`, `const` and `class` declarations.
+    Block,
+    /// A `with` statement, whose variables are the properties of its object.
+    With,
+}
+
+/// A scope of the environment chain, returned by [`Context::scopes`](crate::Context::scopes) and
+/// [`TraceEvent::scopes`](crate::trace::TraceEvent::scopes).
+///
+/// The scope stays readable after the code has left it, and reads the current values of its
+/// variables.
+#[derive(Debug, Clone)]
+pub struct Scope {
+    environment: Environment,
+}
+
+impl Scope {
+    /// Creates a new `Scope`.
+    pub(crate) fn new(environment: Environment) -> Self {
+        Self { environment }
+    }
+
+    /// Gets the kind of the scope.
+    pub fn kind(&self) -> ScopeKind {
+        let environment = self.environment.borrow();
+        if !environment.with_base_object().is_undefined() {
+            return ScopeKind::With;
+        }
+        match environment.get_environment_type() {
+            EnvironmentType::Global => ScopeKind::Global,
+            EnvironmentType::Declarative => ScopeKind::Block,
+            EnvironmentType::Function | EnvironmentType::Object => ScopeKind::Function,
+        }
+    }
+
+    /// Gets the names of the variables of the scope.
+    ///
+    /// The variables of the global scope include all the properties of the global object, such as
+    /// the builtin constructors.
+    pub fn binding_names(&self) -> Vec<String> {
+        self.environment.borrow().binding_names()
+    }
+
+    /// Gets the current value of the variable `name` of the scope.
+    ///
+    /// Returns `None` if the scope has no such variable, or if it is a `let` or `const` variable
+    /// which has not been initialized yet. No getter runs, so the variables that are accessor
+    /// properties of the global object or of a `with` object are `undefined`.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        let environment = self.environment.borrow();
+        if environment.has_binding(name) {
+            environment.get_binding_value(name, false).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Gets the names and the current values of the variables of the scope, see
+    /// [`Scope::get`].
+    pub fn bindings(&self) -> Vec<(String, Option<Value>)> {
+        self.binding_names()
+            .into_iter()
+            .map(|name| {
+                let value = self.get(&name);
+                (name, value)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        trace::{TraceControl, TraceEvent},
+        Context,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    /// Gets the kind and the sorted binding names of the scopes, without the global scope.
+    fn describe(scopes: &[Scope]) -> Vec<(ScopeKind, Vec<String>)> {
+        scopes[..scopes.len() - 1]
+            .iter()
+            .map(|scope| {
+                let mut names = scope.binding_names();
+                names.sort();
+                (scope.kind(), names)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scopes_of_nested_closures() {
+        let mut context = Context::new();
+        let recorded = Rc::new(RefCell::new(Vec::new()));
+        let scopes = recorded.clone();
+        context.set_trace_hook(move |event: TraceEvent<'_>| {
+            if event.span().unwrap().start().line_number() == 9 {
+                scopes.borrow_mut().extend(event.scopes());
+            }
+            TraceControl::Continue
+        });
+
+        let source = r#"
+            var kept = [];
+            function outer(a) {
+                let count = 1;
+                function middle(b) {
+                    const step = 2;
+                    function inner(c) {
+                        let total = a + b + c + count + step;
+                        return total;
+                    }
+                    kept.push(inner);
+                    return inner(3);
+                }
+                kept.push(middle);
+                return middle(2);
+            }
+            outer(1);
+        "#;
+        assert_eq!(context.eval(source).unwrap(), Value::from(9));
+
+        let scopes = recorded.borrow();
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        assert_eq!(
+            describe(&scopes),
+            [
+                (ScopeKind::Function, names(&["arguments", "c", "total"])),
+                (
+                    ScopeKind::Function,
+                    names(&["arguments", "b", "inner", "step"])
+                ),
+                (
+                    ScopeKind::Function,
+                    names(&["a", "arguments", "count", "middle"])
+                ),
+            ]
+        );
+        assert_eq!(scopes[0].get("total"), Some(Value::from(9)));
+        assert_eq!(scopes[1].get("step"), Some(Value::from(2)));
+        assert_eq!(scopes[1].get("total"), None);
+
+        let global = scopes.last().unwrap();
+        assert_eq!(global.kind(), ScopeKind::Global);
+        assert!(global.binding_names().contains(&String::from("kept")));
+
+        // The scopes read the current values of the variables, after the code has left them.
+        drop(scopes);
+        context.eval("kept.length = 0;").unwrap();
+        let kept = recorded.borrow().last().unwrap().get("kept").unwrap();
+        assert_eq!(
+            kept.get_field("length", &mut context).unwrap(),
+            Value::from(0)
+        );
+    }
+
+    #[test]
+    fn scopes_between_evaluations() {
+        let mut context = Context::new();
+        context
+            .eval("let answer = 42; { let hidden = true; }")
+            .unwrap();
+
+        let scopes: Vec<_> = context.scopes().collect();
+        assert_eq!(scopes.len(), 1);
+        assert_eq!(scopes[0].kind(), ScopeKind::Global);
+        assert_eq!(scopes[0].get("answer"), Some(Value::from(42)));
+        assert_eq!(scopes[0].get("hidden"), None);
+        assert!(scopes[0]
+            .bindings()
+            .contains(&(String::from("answer"), Some(Value::from(42)))));
+    }
+
+    #[test]
+    fn block_scopes() {
+        let mut context = Context::new();
+        let kinds = Rc::new(RefCell::new(Vec::new()));
+        let recorded = kinds.clone();
+        context.set_trace_hook(move |event: TraceEvent<'_>| {
+            let scopes: Vec<_> = event.scopes().map(|scope| scope.kind()).collect();
+            recorded.borrow_mut().push(scopes);
+            TraceControl::Continue
+        });
+
+        context.eval("{ let inside = 1; }").unwrap();
+        assert_eq!(
+            *kinds.borrow(),
+            [
+                vec![ScopeKind::Global],
+                vec![ScopeKind::Block, ScopeKind::Global]
+            ]
+        );
+    }
+}
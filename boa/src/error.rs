@@ -0,0 +1,375 @@
+//! The errors returned to the embedders of the engine when evaluating scripts.
+//!
+//! The builtins and the interpreter throw plain [`Value`]s, which is what the language needs. Rust
+//! code evaluating scripts with [`Context::eval`] and the related methods gets an [`EvalError`]
+//! instead, which tells apart the code that could not be parsed, the values thrown by the script,
+//! the evaluations stopped by the engine and the panics caught while running the script.
+//!
+//! The location of the last error in the source code is kept by the context as a [`Diagnostic`],
+//! see [`Context::last_diagnostic`].
+
+use crate::{
+    context::ErrorKind,
+    syntax::{
+        ast::{Position, Span},
+        parser::ParseError,
+    },
+    Context, Value,
+};
+use std::{error, fmt, result::Result as StdResult};
+
+/// The result of the evaluation of a script by an embedder.
+pub type EvalResult<T> = StdResult<T, EvalError>;
+
+/// An error of the evaluation of a script with [`Context::eval`], [`Context::compile`] or
+/// [`Context::execute`].
+///
+/// A thrown value belongs to the context that threw it, so an `EvalError` can't be sent to other
+/// threads. To pass it to error handling libraries requiring `Send` errors, like `anyhow`, convert
+/// it to an [`ErrorReport`] with [`EvalError::to_report`].
+///
+/// # Examples
+///
+/// ```
+/// use boa::{error::EvalError, Context};
+///
+/// let mut context = Context::new();
+///
+/// let error = context.eval("1 +").unwrap_err();
+/// assert!(matches!(error, EvalError::Parse { .. }));
+///
+/// let error = context.eval("null.property").unwrap_err();
+/// assert_eq!(error.name(&mut context).as_deref(), Some("TypeError"));
+/// ```
+#[derive(Debug)]
+pub enum EvalError {
+    /// The source code could not be parsed.
+    Parse {
+        /// The parsing error.
+        error: ParseError,
+        /// The name of the source code, used in the error message.
+        source_name: Box<str>,
+    },
+    /// The script threw a value, which was not caught.
+    Thrown(Value),
+    /// The engine stopped the evaluation of the script.
+    Terminated(TerminationReason),
+    /// The engine or a native function panicked while running the script, with the panic message.
+    ///
+    /// The panic was caught and the evaluation stopped, leaving the context usable. Its objects
+    /// may still have been left half updated, so the embedder may prefer to discard it.
+    InternalPanic(String),
+}
+
+impl EvalError {
+    /// Returns the value thrown by the script, if any.
+    #[inline]
+    pub fn thrown(&self) -> Option<&Value> {
+        match self {
+            Self::Thrown(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Gets the name of the error, which is `SyntaxError` for parsing errors and the `name`
+    /// property of a thrown object.
+    pub fn name(&self, context: &mut Context) -> Option<String> {
+        match self {
+            Self::Parse { .. } => Some(String::from("SyntaxError")),
+            Self::Thrown(value) => string_property(value, "name", context),
+            Self::Terminated(_) | Self::InternalPanic(_) => None,
+        }
+    }
+
+    /// Gets the message of the error, which is the `message` property of a thrown object.
+    pub fn message(&self, context: &mut Context) -> Option<String> {
+        match self {
+            Self::Parse { error, source_name } => {
+                Some(error.with_source_name(source_name).to_string())
+            }
+            Self::Thrown(value) => string_property(value, "message", context),
+            Self::Terminated(reason) => Some(reason.to_string()),
+            Self::InternalPanic(message) => Some(message.clone()),
+        }
+    }
+
+    /// Gets the stack trace of a thrown object, from its `stack` property.
+    ///
+    /// The engine does not record the stack of the errors, so only the objects whose `stack`
+    /// property was set by the script have one.
+    pub fn stack(&self, context: &mut Context) -> Option<String> {
+        match self {
+            Self::Thrown(value) => string_property(value, "stack", context),
+            _ => None,
+        }
+    }
+
+    /// Renders the error into an [`ErrorReport`], which can be sent to other threads.
+    ///
+    /// The report is located with [`Context::last_diagnostic`], so it must be created before the
+    /// context runs other scripts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::Context;
+    ///
+    /// fn run(source: &str) -> anyhow::Result<String> {
+    ///     let mut context = Context::new();
+    ///     let value = context
+    ///         .eval_with_source_name(source, "script.js")
+    ///         .map_err(|error| error.to_report(&mut context))?;
+    ///     Ok(value.display().to_string())
+    /// }
+    ///
+    /// assert_eq!(run("1 + 1").unwrap(), "2");
+    ///
+    /// let error = run("null.property").unwrap_err();
+    /// let report = error.downcast_ref::<boa::error::ErrorReport>().unwrap();
+    /// assert_eq!(report.name(), Some("TypeError"));
+    /// assert_eq!(report.diagnostic().unwrap().line_number(), 1);
+    /// ```
+    pub fn to_report(&self, context: &mut Context) -> ErrorReport {
+        ErrorReport {
+            name: self.name(context),
+            message: self.to_string(),
+            diagnostic: context.last_diagnostic().cloned(),
+        }
+    }
+
+    /// Converts the error to the value a script would have caught.
+    ///
+    /// Parsing errors become `SyntaxError` objects, the terminated evaluations become
+    /// `RangeError` objects and the panics become `Error` objects.
+    pub fn into_value(self, context: &mut Context) -> Value {
+        match self {
+            Self::Parse { error, source_name } => {
+                context.construct_syntax_error(error.with_source_name(&source_name).to_string())
+            }
+            Self::Thrown(value) => value,
+            Self::Terminated(reason) => context.construct_range_error(reason.to_string()),
+            Self::InternalPanic(message) => context.construct_error(ErrorKind::Error, message),
+        }
+    }
+}
+
+/// Gets a string property of a value, if it is an object.
+fn string_property(value: &Value, key: &str, context: &mut Context) -> Option<String> {
+    if !value.is_object() {
+        return None;
+    }
+    value
+        .get_field(key, context)
+        .ok()?
+        .as_string()
+        .map(|string| string.to_string())
+}
+
+impl From<Value> for EvalError {
+    #[inline]
+    fn from(value: Value) -> Self {
+        Self::Thrown(value)
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { error, source_name } => {
+                write!(f, "SyntaxError: {}", error.with_source_name(source_name))
+            }
+            Self::Thrown(value) => fmt::Display::fmt(&value.display(), f),
+            Self::Terminated(reason) => write!(f, "evaluation terminated: {}", reason),
+            Self::InternalPanic(message) => write!(f, "internal panic: {}", message),
+        }
+    }
+}
+
+impl error::Error for EvalError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Parse { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// An [`EvalError`] rendered to strings, which can be sent to other threads, created by
+/// [`EvalError::to_report`].
+///
+/// It is displayed like the `EvalError`, followed by its [`Diagnostic`] if it is located.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    name: Option<String>,
+    message: String,
+    diagnostic: Option<Diagnostic>,
+}
+
+impl ErrorReport {
+    /// Gets the name of the error, see [`EvalError::name`].
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the displayed error.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the location of the error in the source code, if it is known.
+    #[inline]
+    pub fn diagnostic(&self) -> Option<&Diagnostic> {
+        self.diagnostic.as_ref()
+    }
+}
+
+impl fmt::Display for ErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if let Some(ref diagnostic) = self.diagnostic {
+            write!(f, "\n{}", diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ErrorReport {}
+
+/// The reason why the engine stopped the evaluation of a script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TerminationReason {
+    /// The heap grew past the limit set with
+    /// [`ContextBuilder::memory_limit`](crate::context::ContextBuilder::memory_limit).
+    MemoryLimit,
+    /// The hook set with [`Context::set_trace_hook`](crate::Context::set_trace_hook) returned
+    /// [`TraceControl::Stop`](crate::trace::TraceControl::Stop).
+    TraceHook,
+}
+
+impl fmt::Display for TerminationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MemoryLimit => "the memory limit was exceeded",
+            Self::TraceHook => "the trace hook stopped the script",
+        })
+    }
+}
+
+/// The location of an error in the source code, with the line of code it is on.
+///
+/// It is displayed the way compilers print their diagnostics, with a caret under the code:
+///
+/// ```text
+///  --> script.js:2:1
+///   |
+/// 2 | null.property;
+///   | ^^^^^^^^^^^^^
+/// ```
+///
+/// Runtime errors are located at the statement that threw them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    source_name: Box<str>,
+    position: Position,
+    line: Box<str>,
+    caret: Box<str>,
+}
+
+impl Diagnostic {
+    /// Creates the diagnostic of the code at `span` in `source`, if the source contains it.
+    ///
+    /// The caret goes up to the end of the span, or to the end of the line if the span continues
+    /// on the next lines.
+    pub(crate) fn new(source_name: &str, source: &str, span: Span) -> Option<Self> {
+        let position = span.start();
+        let line_start = Position::new(position.line_number(), 1).byte_offset(source)?;
+        let line = source[line_start..]
+            .split(|c| matches!(c, '\r' | '\n' | '\u{2028}' | '\u{2029}'))
+            .next()
+            .unwrap_or_default();
+
+        let line_length = line.chars().count();
+        let column = position.column_number() as usize - 1;
+        if column > line_length {
+            return None;
+        }
+        let end = if span.end().line_number() == position.line_number() {
+            (span.end().column_number() as usize - 1).min(line_length)
+        } else {
+            line_length
+        };
+
+        // The tabs of the line are kept, so that the caret is under the code whatever their width.
+        let mut caret: String = line
+            .chars()
+            .take(column)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        caret.extend(std::iter::repeat('^').take(end.saturating_sub(column).max(1)));
+
+        Some(Self {
+            source_name: source_name.into(),
+            position,
+            line: line.into(),
+            caret: caret.into(),
+        })
+    }
+
+    /// Creates the diagnostic of a parsing error.
+    ///
+    /// The errors found at the end of the source, which have no position, are located there.
+    pub(crate) fn parse_error(source_name: &str, source: &str, error: &ParseError) -> Option<Self> {
+        let position = error.position().unwrap_or_else(|| Position::end_of(source));
+        Self::new(source_name, source, position.into())
+    }
+
+    /// Gets the name of the source code, such as its file name.
+    #[inline]
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    /// Gets the position of the error in the source code.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Gets the line number of the error.
+    #[inline]
+    pub fn line_number(&self) -> u32 {
+        self.position.line_number()
+    }
+
+    /// Gets the column number of the error, in characters.
+    #[inline]
+    pub fn column_number(&self) -> u32 {
+        self.position.column_number()
+    }
+
+    /// Gets the text of the line of the error, without its line terminator.
+    #[inline]
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    /// Gets the caret that underlines the error when printed under [`Diagnostic::line`].
+    #[inline]
+    pub fn caret(&self) -> &str {
+        &self.caret
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line_number = self.line_number().to_string();
+        let gutter = " ".repeat(line_number.len());
+        writeln!(f, "{}--> {}:{}", gutter, self.source_name, self.position)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_number, self.line)?;
+        write!(f, "{} | {}", gutter, self.caret)
+    }
+}
@@ -1,51 +1,121 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
+mod reference;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use reference::Reference;
+
 use crate::{Context, Result, Value};
 
 pub trait Executable {
     /// Runs this executable in the given context.
     fn run(&self, context: &mut Context) -> Result<Value>;
+
+    /// Runs this executable as a statement, and returns its completion record.
+    ///
+    /// Expressions complete normally, with their value.
+    #[inline]
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
+        self.run(context).map(Completion::normal)
+    }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-pub(crate) enum InterpreterState {
-    Executing,
+/// The type of a completion record.
+///
+/// The `throw` completions are the errors of the statements, returned in the `Err` of their
+/// [`Result`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompletionType {
+    /// The statement ran to its end.
+    Normal,
+    /// A `return` statement.
     Return,
+    /// A `break` statement, with its label.
     Break(Option<Box<str>>),
+    /// A `continue` statement, with its label.
     Continue(Option<Box<str>>),
 }
 
-/// A Javascript intepreter
-#[derive(Debug)]
-pub struct Interpreter {
-    /// the current state of the interpreter.
-    state: InterpreterState,
+/// The completion record of a statement, which is how the statement ended and its value.
+///
+/// The value of a statement list is the value of its last statement that has one, so
+/// `1; var x = 2;` evaluates to `1`, since declarations complete with an empty value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-completion-record-specification-type
+#[derive(Debug, Clone)]
+pub struct Completion {
+    kind: CompletionType,
+    value: Option<Value>,
 }
 
-impl Default for Interpreter {
-    fn default() -> Self {
-        Self::new()
+impl Completion {
+    /// Creates a completion record, with `None` as the empty value.
+    #[inline]
+    pub fn new(kind: CompletionType, value: Option<Value>) -> Self {
+        Self { kind, value }
     }
-}
 
-impl Interpreter {
-    /// Creates a new interpreter.
-    pub fn new() -> Self {
-        Self {
-            state: InterpreterState::Executing,
-        }
+    /// Creates a normal completion with a value.
+    #[inline]
+    pub fn normal(value: Value) -> Self {
+        Self::new(CompletionType::Normal, Some(value))
+    }
+
+    /// Creates a normal completion with an empty value.
+    #[inline]
+    pub fn empty() -> Self {
+        Self::new(CompletionType::Normal, None)
+    }
+
+    /// Gets the type of the completion.
+    #[inline]
+    pub fn kind(&self) -> &CompletionType {
+        &self.kind
+    }
+
+    /// Gets the value of the completion, or `None` if it is empty.
+    #[inline]
+    pub fn value(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+
+    /// Checks whether the completion is abrupt, which means that it isn't normal.
+    #[inline]
+    pub fn is_abrupt(&self) -> bool {
+        self.kind != CompletionType::Normal
+    }
+
+    /// Takes the value out of the completion, leaving it empty.
+    #[inline]
+    pub fn take_value(&mut self) -> Option<Value> {
+        self.value.take()
     }
 
+    /// Sets the value of the completion to `value` if it is empty, where `value` can itself be
+    /// empty.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-updateempty
     #[inline]
-    pub(crate) fn set_current_state(&mut self, new_state: InterpreterState) {
-        self.state = new_state
+    pub fn update_empty<V>(mut self, value: V) -> Self
+    where
+        V: Into<Option<Value>>,
+    {
+        if self.value.is_none() {
+            self.value = value.into();
+        }
+        self
     }
 
+    /// Converts the completion to its value, which is `undefined` if it is empty.
     #[inline]
-    pub(crate) fn get_current_state(&self) -> &InterpreterState {
-        &self.state
+    pub fn into_value(self) -> Value {
+        self.value.unwrap_or_default()
     }
 }
@@ -0,0 +1,124 @@
+//! References, which are the targets of the assignment and update operators.
+
+use crate::{
+    property::PropertyKey,
+    syntax::ast::node::{Identifier, Node},
+    Context, Executable, Result, Value,
+};
+
+/// A resolved reference: a binding, or a property of a base value.
+///
+/// The base and the key of a property are evaluated once, when the reference is resolved, so
+/// that reading the target and then writing it back doesn't evaluate them again.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reference-record-specification-type
+#[derive(Debug, Clone)]
+pub(crate) enum Reference<'a> {
+    /// A binding, resolved in the environment when it is read or written.
+    Binding(&'a Identifier),
+    /// A property of a base value.
+    Property { base: Value, key: PropertyKey },
+    /// A `super` property, looked up on `base` with `this` as the receiver.
+    SuperProperty {
+        base: Value,
+        key: PropertyKey,
+        this: Value,
+    },
+}
+
+impl<'a> Reference<'a> {
+    /// Evaluates the base and the key of the target of an assignment or an update expression.
+    pub(crate) fn resolve(node: &'a Node, context: &mut Context) -> Result<Self> {
+        match node {
+            Node::Identifier(ref identifier) => Ok(Self::Binding(identifier)),
+            Node::GetConstField(ref get_const_field)
+                if matches!(get_const_field.obj(), Node::Super) =>
+            {
+                Self::resolve_super(get_const_field.field().into(), context)
+            }
+            Node::GetField(ref get_field) if matches!(get_field.obj(), Node::Super) => {
+                let key = get_field.field().run(context)?.to_property_key(context)?;
+                Self::resolve_super(key, context)
+            }
+            Node::GetConstField(ref get_const_field) => Ok(Self::Property {
+                base: get_const_field.obj().run(context)?,
+                key: get_const_field.field().into(),
+            }),
+            Node::GetField(ref get_field) => {
+                let base = get_field.obj().run(context)?;
+                let key = get_field.field().run(context)?.to_property_key(context)?;
+                Ok(Self::Property { base, key })
+            }
+            _ => context.throw_type_error(format!("invalid assignment to {}", node)),
+        }
+    }
+
+    /// Makes the reference to the `key` property of `super` in the running method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makesuperpropertyreference
+    pub(crate) fn resolve_super(key: PropertyKey, context: &mut Context) -> Result<Self> {
+        let this = context.realm().environment.get_this_binding();
+        let this = this.map_err(|e| e.to_error(context))?;
+        match context.realm().environment.get_super_base() {
+            Some(base) => Ok(Self::SuperProperty { base, key, this }),
+            None => Err(context.construct_syntax_error("'super' keyword unexpected here")),
+        }
+    }
+
+    /// The `this` value of a call through the reference.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getthisvalue
+    pub(crate) fn this_value(&self) -> Value {
+        match self {
+            Self::Binding(_) => Value::undefined(),
+            Self::Property { base, .. } => base.clone(),
+            Self::SuperProperty { this, .. } => this.clone(),
+        }
+    }
+
+    /// Reads the value of the reference.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getvalue
+    pub(crate) fn get_value(&self, context: &mut Context) -> Result<Value> {
+        match self {
+            Self::Binding(identifier) => identifier.run(context),
+            Self::Property { base, key } => base.get_v(key, context),
+            Self::SuperProperty { base, key, this } => {
+                base.to_object(context)?.get(key, this.clone(), context)
+            }
+        }
+    }
+
+    /// Writes `value` to the reference.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
+    pub(crate) fn put_value(&self, value: Value, context: &mut Context) -> Result<()> {
+        match self {
+            Self::Binding(identifier) => identifier.set_value(value, context),
+            Self::Property { base, key } => {
+                base.set_field(key.clone(), value, context)?;
+                Ok(())
+            }
+            Self::SuperProperty { base, key, this } => {
+                base.to_object(context)?
+                    .set(key.clone(), value, this.clone(), context)?;
+                Ok(())
+            }
+        }
+    }
+}
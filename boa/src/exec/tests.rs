@@ -1,4 +1,12 @@
-use crate::{builtins::Number, exec, forward, forward_val, Context, Value};
+use crate::{
+    builtins::Number,
+    context::{ContextBuilder, ErrorKind},
+    error::{EvalError, TerminationReason},
+    exec, forward, forward_val,
+    object::{GcObject, Object},
+    property::{Attribute, PropertyKey},
+    Context, Value,
+};
 
 #[test]
 fn function_declaration_returns_undefined() {
@@ -663,6 +671,29 @@ fn unary_delete() {
     assert_eq!(&exec(delete_recursive), "true");
 }
 
+#[test]
+fn unary_delete_symbol_key() {
+    let delete_symbol = r#"
+        const s = Symbol("foo");
+        const a = {};
+        a[s] = 1;
+        a[s.toString()] = 2;
+        delete a[s];
+        a[s] + ' ' + a[s.toString()]
+    "#;
+    assert_eq!(&exec(delete_symbol), "\"undefined 2\"");
+
+    let delete_string = r#"
+        const s = Symbol("foo");
+        const a = {};
+        a[s] = 1;
+        a[s.toString()] = 2;
+        delete a["Symbol(foo)"];
+        a[s] + ' ' + a[s.toString()]
+    "#;
+    assert_eq!(&exec(delete_string), "\"1 undefined\"");
+}
+
 #[cfg(test)]
 mod in_operator {
     use super::*;
@@ -737,6 +768,26 @@ mod in_operator {
         assert_eq!(forward(&mut context, "x"), "true");
     }
 
+    #[test]
+    fn type_error_message() {
+        assert_eq!(
+            &exec("'a' in 'abc'"),
+            "Uncaught \"TypeError\": \"right-hand side of 'in' should be an object, got string\""
+        );
+    }
+
+    #[test]
+    fn parenthesized_in_for_initializer() {
+        let scenario = r#"
+            var o = {a: 1}, n = 0;
+            for (var has = ('a' in o); has; has = false) {
+                n++;
+            }
+            n
+        "#;
+        assert_eq!(&exec(scenario), "1");
+    }
+
     #[test]
     fn should_set_this_value() {
         let mut context = Context::new();
@@ -1157,6 +1208,98 @@ fn check_this_binding_in_object_literal() {
     assert_eq!(forward(&mut context, init), "8");
 }
 
+#[test]
+fn this_resolution() {
+    let mut context = Context::new();
+    let init = r#"
+        function sloppy() { return this; }
+        function strict() { 'use strict'; return this; }
+        var obj = { sloppy: sloppy, strict: strict };
+        String.prototype.sloppy = sloppy;
+        String.prototype.strict = strict;
+        "#;
+    forward(&mut context, init);
+
+    // global `this` in scripts
+    assert_eq!(forward(&mut context, "this === globalThis"), "true");
+    // method calls use the base of the reference
+    assert_eq!(forward(&mut context, "obj.sloppy() === obj"), "true");
+    assert_eq!(forward(&mut context, "obj['strict']() === obj"), "true");
+    // bare calls in sloppy mode see the global object
+    assert_eq!(forward(&mut context, "sloppy() === globalThis"), "true");
+    assert_eq!(
+        forward(&mut context, "sloppy.call(undefined) === globalThis"),
+        "true"
+    );
+    // bare calls in strict mode see `undefined`
+    assert_eq!(forward(&mut context, "strict()"), "undefined");
+    assert_eq!(forward(&mut context, "strict.call(null)"), "null");
+    // primitive receivers are boxed in sloppy mode only
+    assert_eq!(forward(&mut context, "typeof 'abc'.sloppy()"), "\"object\"");
+    assert_eq!(forward(&mut context, "typeof 'abc'.strict()"), "\"string\"");
+}
+
+#[test]
+fn this_resolution_in_strict_script() {
+    let mut context = Context::new();
+    let init = r#"
+        'use strict';
+        function f() { return this; }
+        var nested = function () { return (function () { return this; })(); };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "this === globalThis"), "true");
+    assert_eq!(forward(&mut context, "f()"), "undefined");
+    assert_eq!(forward(&mut context, "nested()"), "undefined");
+}
+
+#[test]
+fn super_property_access() {
+    let mut context = Context::new();
+    let init = r#"
+        var base = {
+            name: "base",
+            describe() { return "base of " + this.name; }
+        };
+        var middle = {
+            name: "middle",
+            describe() { return "middle, " + super.describe(); }
+        };
+        var leaf = {
+            name: "leaf",
+            describe() { return "leaf, " + super['describe'](); },
+            setName(name) { super.name = name; },
+            remove() { delete super.name; },
+            arrow() { return (() => super.name)(); }
+        };
+        Object.setPrototypeOf(middle, base);
+        Object.setPrototypeOf(leaf, middle);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "leaf.describe()"),
+        "\"leaf, middle, base of leaf\""
+    );
+    assert_eq!(
+        forward(&mut context, "middle.describe.call({ name: 'other' })"),
+        "\"middle, base of other\""
+    );
+    // arrow functions use the `super` of the enclosing method
+    assert_eq!(forward(&mut context, "leaf.arrow()"), "\"middle\"");
+    // assignments to `super` properties are made on `this`
+    assert_eq!(
+        forward(&mut context, "leaf.setName('renamed'); leaf.name"),
+        "\"renamed\""
+    );
+    assert_eq!(forward(&mut context, "middle.name"), "\"middle\"");
+    assert_eq!(
+        forward(&mut context, "try { leaf.remove() } catch (e) { e.name }"),
+        "\"ReferenceError\""
+    );
+}
+
 #[test]
 fn array_creation_benchmark() {
     let mut context = Context::new();
@@ -1171,7 +1314,12 @@ fn array_creation_benchmark() {
         })();
         "#;
 
-    assert_eq!(forward(&mut context, init), "[ \"p0\", \"p1\", \"p2\", \"p3\", \"p4\", \"p5\", \"p6\", \"p7\", \"p8\", \"p9\", \"p10\", \"p11\", \"p12\", \"p13\", \"p14\", \"p15\", \"p16\", \"p17\", \"p18\", \"p19\", \"p20\", \"p21\", \"p22\", \"p23\", \"p24\", \"p25\", \"p26\", \"p27\", \"p28\", \"p29\", \"p30\", \"p31\", \"p32\", \"p33\", \"p34\", \"p35\", \"p36\", \"p37\", \"p38\", \"p39\", \"p40\", \"p41\", \"p42\", \"p43\", \"p44\", \"p45\", \"p46\", \"p47\", \"p48\", \"p49\", \"p50\", \"p51\", \"p52\", \"p53\", \"p54\", \"p55\", \"p56\", \"p57\", \"p58\", \"p59\", \"p60\", \"p61\", \"p62\", \"p63\", \"p64\", \"p65\", \"p66\", \"p67\", \"p68\", \"p69\", \"p70\", \"p71\", \"p72\", \"p73\", \"p74\", \"p75\", \"p76\", \"p77\", \"p78\", \"p79\", \"p80\", \"p81\", \"p82\", \"p83\", \"p84\", \"p85\", \"p86\", \"p87\", \"p88\", \"p89\", \"p90\", \"p91\", \"p92\", \"p93\", \"p94\", \"p95\", \"p96\", \"p97\", \"p98\", \"p99\", \"p100\", \"p101\", \"p102\", \"p103\", \"p104\", \"p105\", \"p106\", \"p107\", \"p108\", \"p109\", \"p110\", \"p111\", \"p112\", \"p113\", \"p114\", \"p115\", \"p116\", \"p117\", \"p118\", \"p119\", \"p120\", \"p121\", \"p122\", \"p123\", \"p124\", \"p125\", \"p126\", \"p127\", \"p128\", \"p129\", \"p130\", \"p131\", \"p132\", \"p133\", \"p134\", \"p135\", \"p136\", \"p137\", \"p138\", \"p139\", \"p140\", \"p141\", \"p142\", \"p143\", \"p144\", \"p145\", \"p146\", \"p147\", \"p148\", \"p149\", \"p150\", \"p151\", \"p152\", \"p153\", \"p154\", \"p155\", \"p156\", \"p157\", \"p158\", \"p159\", \"p160\", \"p161\", \"p162\", \"p163\", \"p164\", \"p165\", \"p166\", \"p167\", \"p168\", \"p169\", \"p170\", \"p171\", \"p172\", \"p173\", \"p174\", \"p175\", \"p176\", \"p177\", \"p178\", \"p179\", \"p180\", \"p181\", \"p182\", \"p183\", \"p184\", \"p185\", \"p186\", \"p187\", \"p188\", \"p189\", \"p190\", \"p191\", \"p192\", \"p193\", \"p194\", \"p195\", \"p196\", \"p197\", \"p198\", \"p199\", \"p200\", \"p201\", \"p202\", \"p203\", \"p204\", \"p205\", \"p206\", \"p207\", \"p208\", \"p209\", \"p210\", \"p211\", \"p212\", \"p213\", \"p214\", \"p215\", \"p216\", \"p217\", \"p218\", \"p219\", \"p220\", \"p221\", \"p222\", \"p223\", \"p224\", \"p225\", \"p226\", \"p227\", \"p228\", \"p229\", \"p230\", \"p231\", \"p232\", \"p233\", \"p234\", \"p235\", \"p236\", \"p237\", \"p238\", \"p239\", \"p240\", \"p241\", \"p242\", \"p243\", \"p244\", \"p245\", \"p246\", \"p247\", \"p248\", \"p249\", \"p250\", \"p251\", \"p252\", \"p253\", \"p254\", \"p255\", \"p256\", \"p257\", \"p258\", \"p259\", \"p260\", \"p261\", \"p262\", \"p263\", \"p264\", \"p265\", \"p266\", \"p267\", \"p268\", \"p269\", \"p270\", \"p271\", \"p272\", \"p273\", \"p274\", \"p275\", \"p276\", \"p277\", \"p278\", \"p279\", \"p280\", \"p281\", \"p282\", \"p283\", \"p284\", \"p285\", \"p286\", \"p287\", \"p288\", \"p289\", \"p290\", \"p291\", \"p292\", \"p293\", \"p294\", \"p295\", \"p296\", \"p297\", \"p298\", \"p299\", \"p300\", \"p301\", \"p302\", \"p303\", \"p304\", \"p305\", \"p306\", \"p307\", \"p308\", \"p309\", \"p310\", \"p311\", \"p312\", \"p313\", \"p314\", \"p315\", \"p316\", \"p317\", \"p318\", \"p319\", \"p320\", \"p321\", \"p322\", \"p323\", \"p324\", \"p325\", \"p326\", \"p327\", \"p328\", \"p329\", \"p330\", \"p331\", \"p332\", \"p333\", \"p334\", \"p335\", \"p336\", \"p337\", \"p338\", \"p339\", \"p340\", \"p341\", \"p342\", \"p343\", \"p344\", \"p345\", \"p346\", \"p347\", \"p348\", \"p349\", \"p350\", \"p351\", \"p352\", \"p353\", \"p354\", \"p355\", \"p356\", \"p357\", \"p358\", \"p359\", \"p360\", \"p361\", \"p362\", \"p363\", \"p364\", \"p365\", \"p366\", \"p367\", \"p368\", \"p369\", \"p370\", \"p371\", \"p372\", \"p373\", \"p374\", \"p375\", \"p376\", \"p377\", \"p378\", \"p379\", \"p380\", \"p381\", \"p382\", \"p383\", \"p384\", \"p385\", \"p386\", \"p387\", \"p388\", \"p389\", \"p390\", \"p391\", \"p392\", \"p393\", \"p394\", \"p395\", \"p396\", \"p397\", \"p398\", \"p399\", \"p400\", \"p401\", \"p402\", \"p403\", \"p404\", \"p405\", \"p406\", \"p407\", \"p408\", \"p409\", \"p410\", \"p411\", \"p412\", \"p413\", \"p414\", \"p415\", \"p416\", \"p417\", \"p418\", \"p419\", \"p420\", \"p421\", \"p422\", \"p423\", \"p424\", \"p425\", \"p426\", \"p427\", \"p428\", \"p429\", \"p430\", \"p431\", \"p432\", \"p433\", \"p434\", \"p435\", \"p436\", \"p437\", \"p438\", \"p439\", \"p440\", \"p441\", \"p442\", \"p443\", \"p444\", \"p445\", \"p446\", \"p447\", \"p448\", \"p449\", \"p450\", \"p451\", \"p452\", \"p453\", \"p454\", \"p455\", \"p456\", \"p457\", \"p458\", \"p459\", \"p460\", \"p461\", \"p462\", \"p463\", \"p464\", \"p465\", \"p466\", \"p467\", \"p468\", \"p469\", \"p470\", \"p471\", \"p472\", \"p473\", \"p474\", \"p475\", \"p476\", \"p477\", \"p478\", \"p479\", \"p480\", \"p481\", \"p482\", \"p483\", \"p484\", \"p485\", \"p486\", \"p487\", \"p488\", \"p489\", \"p490\", \"p491\", \"p492\", \"p493\", \"p494\", \"p495\", \"p496\", \"p497\", \"p498\", \"p499\", \"p500\" ]");
+    // Only the first hundred items are displayed.
+    let items: Vec<_> = (0..100).map(|i| format!("\"p{}\"", i)).collect();
+    assert_eq!(
+        forward(&mut context, init),
+        format!("[ {}, ... 401 more items ]", items.join(", "))
+    );
 }
 
 #[test]
@@ -1240,7 +1388,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: object is not a function\""
     );
     let scenario = r#"
         try {
@@ -1251,7 +1399,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: undefined is not a function\""
     );
     let scenario = r#"
         try {
@@ -1262,7 +1410,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: boolean is not a function\""
     );
 }
 
@@ -1396,7 +1544,7 @@ fn test_conditional_op() {
 #[test]
 fn test_identifier_op() {
     let scenario = "break = 1";
-    assert_eq!(&exec(scenario), "\"SyntaxError\": \"expected token \'identifier\', got \'=\' in binding identifier at line 1, col 7\"");
+    assert_eq!(&exec(scenario), "\"SyntaxError\": \"<anonymous>:1:7: expected token \'identifier\', got \'=\' in binding identifier\"");
 }
 
 #[test]
@@ -1519,3 +1667,771 @@ fn test_strict_mode_dup_func_parameters() {
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
+
+#[test]
+fn compiled_script_can_be_executed_multiple_times() {
+    let mut context = Context::new();
+    forward(&mut context, "var counter = 0;");
+
+    let script = context
+        .compile("counter += 1; counter")
+        .expect("could not compile script");
+
+    for expected in 1..=10 {
+        let value = context.execute(&script).expect("could not execute script");
+        assert_eq!(value.as_number(), Some(f64::from(expected)));
+    }
+
+    let other_script = script.clone();
+    let value = context.execute(&other_script).unwrap();
+    assert_eq!(value.as_number(), Some(11.0));
+}
+
+#[test]
+fn compiled_script_can_be_executed_in_another_context() {
+    let script = Context::new()
+        .compile("counter += 1; counter")
+        .expect("could not compile script");
+
+    let mut context = Context::new();
+    forward(&mut context, "var counter = 0;");
+    context.execute(&script).unwrap();
+
+    let mut other_context = Context::new();
+    forward(&mut other_context, "var counter = 0;");
+    let value = other_context.execute(&script).unwrap();
+    assert_eq!(value.as_number(), Some(1.0));
+}
+
+#[test]
+fn compile_syntax_error() {
+    let mut context = Context::new();
+
+    let error = context
+        .compile("var = ;")
+        .expect_err("invalid code compiled");
+    assert!(matches!(error, EvalError::Parse { .. }));
+
+    let error = error.into_value(&mut context);
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "error instanceof SyntaxError"),
+        "true"
+    );
+}
+
+#[test]
+fn eval_with_source_name_syntax_error() {
+    let mut context = Context::new();
+
+    let error = context
+        .eval_with_source_name("var a = 1;\nbreak = 1", "myfile.js")
+        .expect_err("invalid code evaluated");
+
+    assert_eq!(
+        error.to_string(),
+        "SyntaxError: myfile.js:2:7: expected token 'identifier', got '=' in binding identifier"
+    );
+    assert_eq!(error.name(&mut context).as_deref(), Some("SyntaxError"));
+}
+
+#[test]
+fn eval_error_of_thrown_value() {
+    let mut context = Context::new();
+
+    let error = context
+        .eval("throw new RangeError('too far')")
+        .expect_err("no error thrown");
+    assert!(error.thrown().is_some());
+    assert_eq!(error.name(&mut context).as_deref(), Some("RangeError"));
+    assert_eq!(error.message(&mut context).as_deref(), Some("too far"));
+    assert_eq!(error.stack(&mut context), None);
+
+    let error = context.eval("throw 1").expect_err("no error thrown");
+    assert_eq!(error.to_string(), "1");
+    assert_eq!(error.name(&mut context), None);
+
+    let error = EvalError::from(Value::from("thrown"));
+    assert_eq!(error.into_value(&mut context), Value::from("thrown"));
+}
+
+#[test]
+fn eval_error_of_memory_limit() {
+    let mut context = ContextBuilder::new().memory_limit(10 * 1024 * 1024).build();
+
+    let error = context
+        .eval("(function () { let a = []; while (true) { a.push('x'.repeat(1e6)); } })()")
+        .expect_err("the memory limit was not enforced");
+    assert!(matches!(
+        error,
+        EvalError::Terminated(TerminationReason::MemoryLimit)
+    ));
+
+    // The memory limit error cannot be caught, so it stops the evaluation anyway.
+    let error = context
+        .eval(
+            r#"
+            try {
+                (function () {
+                    let b = [];
+                    while (true) {
+                        b.push('x'.repeat(1e6));
+                    }
+                })();
+            } catch (e) {}
+            throw 1;
+            "#,
+        )
+        .expect_err("no error thrown");
+    assert!(matches!(
+        error,
+        EvalError::Terminated(TerminationReason::MemoryLimit)
+    ));
+}
+
+#[test]
+fn eval_error_of_long_operator_chain() {
+    let mut context = Context::new();
+
+    // The interpreter recurses once per operator, so the chain is rejected by the parser instead
+    // of overflowing the stack.
+    for separator in &[" + ", " && ", ", "] {
+        let js = vec!["1"; 100_000].join(separator);
+        let error = context.eval(&js).unwrap_err();
+        assert!(matches!(error, EvalError::Parse { .. }), "{}", separator);
+    }
+}
+
+#[test]
+fn eval_error_of_panicking_native_function() {
+    fn panicking(_: &Value, _: &[Value], _: &mut Context) -> crate::Result<Value> {
+        panic!("native bug")
+    }
+
+    let mut context = Context::new();
+    context
+        .register_global_function("panicking", 0, panicking)
+        .unwrap();
+
+    let error = context
+        .eval("panicking()")
+        .expect_err("the panic was not caught");
+    match error {
+        EvalError::InternalPanic(ref message) => assert_eq!(message, "native bug"),
+        error => panic!("unexpected error: {}", error),
+    }
+    assert_eq!(error.to_string(), "internal panic: native bug");
+
+    // The context is still usable, with the environments of the interrupted calls popped.
+    let error = context
+        .eval("(function () { let inner = 1; [1].forEach(function () { panicking(); }); })()")
+        .expect_err("the panic was not caught");
+    assert!(matches!(error, EvalError::InternalPanic(_)));
+    assert_eq!(forward(&mut context, "typeof inner"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "1 + 1"), "2");
+
+    // The script can catch the error of the panic.
+    assert_eq!(
+        forward(&mut context, "try { panicking(); } catch (e) { e.message }"),
+        "\"a native function panicked: native bug\""
+    );
+    let error = context
+        .eval("try { panicking(); } catch (e) {} throw 1")
+        .expect_err("no error thrown");
+    assert!(error.thrown().is_some());
+}
+
+#[test]
+fn memory_limit_stops_runaway_allocation() {
+    let mut context = ContextBuilder::new().memory_limit(10 * 1024 * 1024).build();
+    let scenario = r#"
+        var caught = 0;
+        (function () {
+            let a = [];
+            while (true) {
+                try {
+                    a.push('x'.repeat(1e6));
+                } catch (e) {
+                    caught++;
+                } finally {
+                    a.push('x'.repeat(1e6));
+                }
+            }
+        })();
+    "#;
+
+    // Catching the error does not keep the script allocating.
+    let error = context
+        .eval(scenario)
+        .expect_err("the memory limit was not enforced");
+    assert!(matches!(
+        error,
+        EvalError::Terminated(TerminationReason::MemoryLimit)
+    ));
+    assert_eq!(forward(&mut context, "caught"), "0");
+
+    // The runaway array is garbage now, so the context can allocate again.
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].map(x => 'y'.repeat(x)).join()"),
+        "\"y,yy,yyy\""
+    );
+}
+
+#[test]
+fn frozen_intrinsics_resist_prototype_pollution() {
+    let mut context = ContextBuilder::new().frozen_intrinsics(true).build();
+    forward(
+        &mut context,
+        r#"
+        Array.prototype.push = null;
+        Object.prototype.polluted = true;
+        var globalsStayMutable = 1;
+        "#,
+    );
+
+    assert_eq!(
+        forward(&mut context, "var a = []; a.push(1, 2); a.length"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "({}).polluted"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "function F(a, b) {} F.length + F.name + (new F() instanceof F)"
+        ),
+        "\"2Ftrue\""
+    );
+    assert_eq!(forward(&mut context, "Object.isFrozen(Math)"), "true");
+    assert_eq!(forward(&mut context, "globalsStayMutable"), "1");
+    assert_eq!(
+        forward(&mut context, "Object.isFrozen(globalThis)"),
+        "false"
+    );
+}
+
+#[test]
+fn frozen_global_object() {
+    let mut context = ContextBuilder::new()
+        .frozen_intrinsics(true)
+        .frozen_global_object(true)
+        .build();
+    forward(&mut context, "Array = null;");
+
+    assert_eq!(forward(&mut context, "typeof Array"), "\"function\"");
+    assert_eq!(forward(&mut context, "Object.isFrozen(globalThis)"), "true");
+}
+
+#[test]
+fn contexts_from_snapshot_are_isolated() {
+    let mut setup = Context::new();
+    forward(
+        &mut setup,
+        r#"
+        var state = { count: 0 };
+        state.self = state;
+        let seen = new Map([[state, "state"]]);
+        function makeCounter() {
+            let local = 0;
+            return () => ++local + state.count;
+        }
+        var counter = makeCounter();
+        "#,
+    );
+    let snapshot = setup.snapshot().expect("could not take the snapshot");
+    forward(&mut setup, "state.count = 100;");
+
+    let mut first = Context::from_snapshot(&snapshot);
+    forward(
+        &mut first,
+        "counter(); state.count = 10; Array.prototype.push = null;",
+    );
+    assert_eq!(forward(&mut first, "counter()"), "12");
+
+    let mut second = Context::from_snapshot(&snapshot);
+    assert_eq!(forward(&mut second, "counter()"), "1");
+    assert_eq!(forward(&mut second, "state.self === state"), "true");
+    assert_eq!(forward(&mut second, "seen.get(state)"), "\"state\"");
+    assert_eq!(forward(&mut second, "var a = []; a.push(1); a.length"), "1");
+    assert_eq!(forward(&mut second, "[] instanceof Array"), "true");
+    assert_eq!(forward(&mut setup, "state.count"), "100");
+}
+
+#[test]
+fn snapshot_rejects_native_objects() {
+    let mut context = Context::new();
+    let native = GcObject::new(Object::native_object(String::from("native")));
+    context.register_global_property("native", native, Attribute::all());
+
+    assert!(context.snapshot().is_err());
+}
+
+#[test]
+fn construct_error_uses_standard_constructors() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "TypeError = function() { return 'replaced'; };",
+    );
+
+    let error = context.construct_error(ErrorKind::Type, "bad type");
+    assert!(error.is_object());
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(forward(&mut context, "error.name"), "\"TypeError\"");
+    assert_eq!(forward(&mut context, "error.message"), "\"bad type\"");
+
+    let error = context
+        .throw_error_fmt(ErrorKind::Uri, format_args!("{} is malformed", "%E0"))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(&mut context).unwrap().as_str(),
+        "URIError: %E0 is malformed"
+    );
+}
+
+#[test]
+fn not_a_function_messages() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "null()"),
+        "Uncaught \"TypeError\": \"null is not a function\""
+    );
+    assert_eq!(
+        forward(&mut context, "(1)()"),
+        "Uncaught \"TypeError\": \"number is not a function\""
+    );
+    assert_eq!(
+        forward(&mut context, "new ({})()"),
+        "Uncaught \"TypeError\": \"object is not a constructor\""
+    );
+}
+
+#[test]
+fn resolved_bindings() {
+    let scenario = r#"
+        function sum(n) {
+            let total = 0;
+            for (let i = 0; i < n; i++) {
+                let double = i * 2;
+                total += double;
+            }
+            return total;
+        }
+        sum(4);
+    "#;
+    assert_eq!(&exec(scenario), "12");
+
+    let scenario = r#"
+        function skipped(c) {
+            if (c) {
+                var a = 1;
+            }
+            var b = 2;
+            b++;
+            return b;
+        }
+        skipped(false);
+    "#;
+    assert_eq!(&exec(scenario), "3");
+
+    let scenario = r#"
+        function shadowed(e) {
+            try {
+                throw 2;
+            } catch (e) {
+                e *= 3;
+                return e;
+            }
+        }
+        shadowed(5);
+    "#;
+    assert_eq!(&exec(scenario), "6");
+}
+
+#[test]
+fn environment_stack_is_balanced() {
+    let mut context = Context::new();
+    let depth = context.realm().environment.depth();
+    let scenario = r#"
+        function C() {}
+        function f() {
+            for (const x of [1, 2]) {
+                if (x === 1) {
+                    continue;
+                }
+                break;
+            }
+            for (const key in { a: 1 }) {
+                return key;
+            }
+        }
+        function g() {
+            for (let i = 0; i < 2; i++) {
+                return i;
+            }
+        }
+        try {
+            {
+                let a = 1;
+                throw a;
+            }
+        } catch (e) {}
+        new C();
+        f() + g();
+    "#;
+    assert_eq!(forward(&mut context, scenario), "\"a0\"");
+    assert_eq!(context.realm().environment.depth(), depth);
+}
+
+#[test]
+fn completion_values() {
+    assert_eq!(&exec("if (true) { 42 }"), "42");
+    assert_eq!(&exec("if (false) {}"), "undefined");
+    assert_eq!(&exec("1; if (true) {}"), "undefined");
+    assert_eq!(&exec("var x = 5"), "undefined");
+    assert_eq!(&exec("1; var x = 2;"), "1");
+    assert_eq!(&exec("1; function f() {}"), "1");
+    assert_eq!(&exec("1; { }"), "1");
+    assert_eq!(&exec("let i = 0; while (i < 3) { i++; }"), "2");
+    assert_eq!(&exec("let i = 0; while (i < 3) { i++; break; }"), "0");
+    assert_eq!(&exec("1; while (false) {}"), "undefined");
+    assert_eq!(&exec("let i = 0; do { i++; } while (i < 3)"), "2");
+    assert_eq!(&exec("for (let i = 0; i < 3; i++) { i * 2 }"), "4");
+    assert_eq!(
+        &exec("for (const x of [1, 2]) { if (x > 1) continue; x }"),
+        "undefined"
+    );
+    assert_eq!(
+        &exec("outer: for (;;) { for (;;) { 7; break outer; } }"),
+        "7"
+    );
+    assert_eq!(&exec("try { 1 } finally { 2 }"), "1");
+    assert_eq!(&exec("try { throw 1 } catch (e) { e + 1 }"), "2");
+    assert_eq!(
+        &exec("switch (1) { case 1: 'a'; case 2: 'b'; break; }"),
+        "\"b\""
+    );
+    assert_eq!(&exec("switch (1) { case 2: 'a'; }"), "undefined");
+}
+
+#[test]
+fn functions_return_undefined_without_return() {
+    assert_eq!(&exec("(function () { 1 })()"), "undefined");
+    assert_eq!(&exec("(() => { 1; })()"), "undefined");
+    assert_eq!(
+        &exec("(function () { try { return 1 } finally { 2 } })()"),
+        "1"
+    );
+    assert_eq!(
+        &exec("(function () { try { return 1 } finally { return 2 } })()"),
+        "2"
+    );
+}
+
+#[test]
+fn lazy_builtins_are_initialized_on_first_access() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyNames(globalThis).includes('RegExp')"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var desc = Object.getOwnPropertyDescriptor(globalThis, 'Date');
+            desc.value === Date && desc.writable && !desc.enumerable && desc.configurable
+                && !('get' in desc)
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "typeof Date"), "\"function\"");
+    assert_eq!(forward(&mut context, "/b/.test('abc')"), "true");
+    assert_eq!(
+        forward(&mut context, "new EvalError('x').name"),
+        "\"EvalError\""
+    );
+
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            Object.defineProperty(globalThis, 'RegExp', { value: 1 });
+            var desc = Object.getOwnPropertyDescriptor(globalThis, 'RegExp');
+            desc.value === 1 && desc.writable && !desc.enumerable && desc.configurable
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptors(globalThis).WeakRef.value === WeakRef"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn lazy_builtins_are_initialized_by_the_engine() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'abc'.match('b')[0]"), "\"b\"");
+    assert_eq!(forward(&mut context, "/b/ instanceof RegExp"), "true");
+
+    let mut context = Context::new();
+    let error = context.construct_error(ErrorKind::Uri, "malformed");
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(forward(&mut context, "error instanceof URIError"), "true");
+}
+
+#[test]
+fn lazy_builtins_can_be_replaced() {
+    let mut context = Context::new();
+    forward(&mut context, "RegExp = 1; globalThis.Date = 2;");
+    assert_eq!(forward(&mut context, "RegExp"), "1");
+    assert_eq!(forward(&mut context, "Date"), "2");
+    assert_eq!(forward(&mut context, "'a'.match('a')[0]"), "\"a\"");
+    assert_eq!(forward(&mut context, "RegExp"), "1");
+}
+
+#[test]
+fn global_accessors_are_read_by_identifiers() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "Object.defineProperty(globalThis, 'x', { get() { return 1; } });",
+    );
+    assert_eq!(forward(&mut context, "x + 1"), "2");
+}
+
+#[test]
+fn computed_member_keys_are_property_keys() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var key = { toString() { return "x"; } };
+        var sym = Symbol("desc");
+        var obj = {};
+        obj[key] = 1;
+        obj[sym] = 2;
+        obj[1] = 3;
+        obj["01"] = 4;
+        "#,
+    );
+
+    assert_eq!(forward(&mut context, "obj.x"), "1");
+    assert_eq!(forward(&mut context, "obj[sym]"), "2");
+    assert_eq!(forward(&mut context, "obj['Symbol(desc)']"), "undefined");
+    assert_eq!(forward(&mut context, "obj['1']"), "3");
+    assert_eq!(forward(&mut context, "obj[1.0]"), "3");
+    assert_eq!(forward(&mut context, "obj['01']"), "4");
+    assert_eq!(forward(&mut context, "obj[1.5]"), "undefined");
+    assert_eq!(forward(&mut context, "key in obj"), "true");
+    assert_eq!(forward(&mut context, "sym in obj"), "true");
+    assert_eq!(
+        forward(&mut context, "delete obj[key]; 'x' in obj"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, key, { value: 5 }); obj.x"
+        ),
+        "5"
+    );
+}
+
+#[test]
+fn property_key_from_value() {
+    let mut context = Context::new();
+
+    let key = PropertyKey::from_value(&Value::from("1"), &mut context).unwrap();
+    assert!(matches!(key, PropertyKey::Index(1)));
+    let key = PropertyKey::from_value(&Value::from(1), &mut context).unwrap();
+    assert!(matches!(key, PropertyKey::Index(1)));
+    let key = PropertyKey::from_value(&Value::from("+1"), &mut context).unwrap();
+    assert!(matches!(key, PropertyKey::String(_)));
+    let key = PropertyKey::from_value(&Value::from(-0.0), &mut context).unwrap();
+    assert!(matches!(key, PropertyKey::Index(0)));
+
+    let symbol = context.construct_symbol(Some("desc".into()));
+    let key = PropertyKey::from_value(&symbol.into(), &mut context).unwrap();
+    assert!(matches!(key, PropertyKey::Symbol(_)));
+}
+
+#[test]
+fn thrown_values_keep_their_identity() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "try { throw 42 } catch (e) { typeof e }"),
+        "\"number\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { throw 'message' } catch (e) { e }"),
+        "\"message\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { throw undefined } catch (e) { e }"),
+        "undefined"
+    );
+
+    let scenario = r#"
+        var thrown = { marker: 'original' };
+        var caught;
+        try {
+            try {
+                throw thrown;
+            } catch (e) {
+                e.rethrown = true;
+                throw e;
+            }
+        } catch (e) {
+            caught = e;
+        }
+    "#;
+    forward(&mut context, scenario);
+    assert_eq!(forward(&mut context, "caught === thrown"), "true");
+    assert_eq!(forward(&mut context, "caught.marker"), "\"original\"");
+    assert_eq!(forward(&mut context, "caught.rethrown"), "true");
+}
+
+#[test]
+fn native_functions_propagate_thrown_values() {
+    let mut context = Context::new();
+    forward(&mut context, "var thrown = { marker: 'native' };");
+
+    let cases = [
+        "[1, 2].map(function () { throw thrown; })",
+        "String({ toString() { throw thrown; } })",
+        "-({ valueOf() { throw thrown; } })",
+        "'a,b'.split(Object.defineProperty({}, Symbol.split, { get() { throw thrown; } }))",
+        "JSON.stringify({ toJSON() { throw thrown; } })",
+        "new Map([[1, 2]]).forEach(function () { throw thrown; })",
+    ];
+    for case in cases.iter() {
+        let scenario = format!("try {{ {}; false }} catch (e) {{ e === thrown }}", case);
+        assert_eq!(forward(&mut context, &scenario), "true", "{}", case);
+    }
+}
+
+#[test]
+fn throw_from_native_function() {
+    let mut context = Context::new();
+
+    let marker = Value::from("marker");
+    let error = context.throw(marker.clone()).unwrap_err();
+    assert!(error.strict_equals(&marker));
+
+    let object = context.construct_object();
+    let thrown = Value::from(object);
+    let error = context
+        .throw(thrown.clone())
+        .map_err(EvalError::from)
+        .unwrap_err();
+    assert!(matches!(error.thrown(), Some(value) if value.strict_equals(&thrown)));
+
+    context.register_global_property("thrown", thrown.clone(), Attribute::all());
+    let error = context.eval("throw thrown").unwrap_err();
+    assert!(matches!(error.thrown(), Some(value) if value.strict_equals(&thrown)));
+}
+
+#[test]
+fn lexical_redeclaration_is_an_early_error() {
+    let mut context = Context::new();
+
+    for scenario in &[
+        "var ran = true; { let a; let a; }",
+        "var ran = true; (function () { const b = 1; let b; });",
+        "var ran = true; let c, c;",
+    ] {
+        let error = context.eval(scenario).unwrap_err();
+        assert!(matches!(error, EvalError::Parse { .. }), "{}", scenario);
+    }
+
+    // The script is rejected before any of its statements runs.
+    assert_eq!(&forward(&mut context, "typeof ran"), "\"undefined\"");
+}
+
+#[test]
+fn contextual_keywords_as_identifiers() {
+    let mut context = Context::new();
+
+    let scenario = r#"
+        let of = [1, 2];
+        var get = 0;
+        for (let x of of) { get += x; }
+        function async() { return get; }
+        var o = { get static() { return async(); } };
+        o.static
+    "#;
+    assert_eq!(&forward(&mut context, scenario), "3");
+}
+
+#[test]
+fn parse_error_diagnostic() {
+    let mut context = Context::new();
+
+    context
+        .eval_with_source_name("var a = 1;\r\nbreak = 1", "myfile.js")
+        .expect_err("invalid code evaluated");
+    let diagnostic = context.last_diagnostic().expect("no diagnostic");
+    assert_eq!(diagnostic.source_name(), "myfile.js");
+    assert_eq!(
+        (diagnostic.line_number(), diagnostic.column_number()),
+        (2, 7)
+    );
+    assert_eq!(diagnostic.line(), "break = 1");
+    assert_eq!(diagnostic.caret(), "      ^");
+    assert_eq!(
+        diagnostic.to_string(),
+        " --> myfile.js:2:7\n  |\n2 | break = 1\n  |       ^"
+    );
+
+    // The errors at the end of the source are located there.
+    context
+        .eval("let b = 'é' +")
+        .expect_err("invalid code evaluated");
+    let diagnostic = context.last_diagnostic().expect("no diagnostic");
+    assert_eq!(
+        (diagnostic.line_number(), diagnostic.column_number()),
+        (1, 14)
+    );
+    assert_eq!(diagnostic.caret(), "             ^");
+}
+
+#[test]
+fn runtime_error_diagnostic() {
+    let mut context = Context::new();
+
+    let source = "var s = 'ü';\r\nfunction f(x) {\r\n\t'é'; x.y.z;\r\n}\r\nf(1);";
+    let error = context
+        .eval_with_source_name(source, "script.js")
+        .expect_err("no error thrown");
+    assert_eq!(error.name(&mut context).as_deref(), Some("TypeError"));
+
+    let diagnostic = context.last_diagnostic().expect("no diagnostic");
+    assert_eq!(diagnostic.source_name(), "script.js");
+    assert_eq!(
+        (diagnostic.line_number(), diagnostic.column_number()),
+        (3, 7)
+    );
+    assert_eq!(diagnostic.line(), "\t'é'; x.y.z;");
+    assert_eq!(diagnostic.caret(), "\t     ^^^^^");
+
+    // The caught errors are forgotten.
+    context
+        .eval("try { null.a } catch (e) {}\nthrow 1;")
+        .expect_err("no error thrown");
+    let diagnostic = context.last_diagnostic().expect("no diagnostic");
+    assert_eq!(diagnostic.line_number(), 2);
+    assert_eq!(diagnostic.caret(), "^^^^^^^");
+
+    context.eval("1").unwrap();
+    assert!(context.last_diagnostic().is_none());
+}
@@ -1,4 +1,10 @@
 //! This module represents the main way to interact with the garbacge collector.
+//!
+//! `rust-gc` does not expose its internal accounting, so the statistics reported by [`stats`]
+//! are kept by boa itself: only `Object`s, their properties and string data are tracked, their
+//! sizes are approximated by the sizes of the structs holding them, and only collections started
+//! through boa ([`force_collect`] or the configured [threshold](set_threshold)) are counted. All
+//! of this state is per thread, like the garbage collected heap itself.
 
 // This is because `rust-gc` unsafe_empty_trace has a `unsafe_`
 // when it should be `empty_trace`.
@@ -6,6 +12,301 @@
 
 pub use crate::object::GcObject;
 pub use gc::{
-    custom_trace, force_collect, unsafe_empty_trace as empty_trace, Finalize, GcCellRef as Ref,
+    custom_trace, unsafe_empty_trace as empty_trace, Finalize, GcCellRef as Ref,
     GcCellRefMut as RefMut, Trace,
 };
+
+use crate::{object::Object, profiler::category, BoaProfiler};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::Rc,
+};
+
+/// Approximate statistics about the garbage collected heap of the current thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GcStats {
+    /// The approximate number of bytes used by the live objects, their properties and strings.
+    pub allocated_bytes: usize,
+    /// The number of live objects.
+    pub object_count: usize,
+    /// The number of collections started by boa.
+    pub collections: usize,
+}
+
+thread_local! {
+    static STATS: Cell<GcStats> = Cell::new(GcStats::default());
+    static THRESHOLD: Cell<Option<usize>> = Cell::new(None);
+    static ALLOCATED_SINCE_COLLECTION: Cell<usize> = Cell::new(0);
+
+    /// The liveness flags of the weak references, by the address of their target.
+    static WEAK_TARGETS: RefCell<FxHashMap<usize, Vec<Rc<Cell<bool>>>>> = RefCell::default();
+}
+
+/// Collects all the garbage of the current thread.
+#[inline]
+pub fn force_collect() {
+    let _timer = BoaProfiler::global().start_event("collection", category::GC);
+    ALLOCATED_SINCE_COLLECTION.with(|allocated| allocated.set(0));
+    gc::force_collect();
+    STATS.with(|stats| {
+        let mut current = stats.get();
+        current.collections += 1;
+        stats.set(current);
+    });
+}
+
+/// Returns the statistics of the garbage collected heap of the current thread.
+#[inline]
+pub fn stats() -> GcStats {
+    STATS.with(Cell::get)
+}
+
+/// Sets the number of bytes that can be allocated before boa starts a collection.
+///
+/// A smaller threshold keeps less garbage around at the cost of more frequent pauses. This only
+/// adds collections on top of the ones `rust-gc` starts by itself, it cannot delay those. `None`
+/// leaves the scheduling entirely to `rust-gc`, which is the default.
+#[inline]
+pub fn set_threshold(bytes: Option<usize>) {
+    THRESHOLD.with(|threshold| threshold.set(bytes));
+}
+
+/// Returns the collection threshold, see [`set_threshold`].
+#[inline]
+pub fn threshold() -> Option<usize> {
+    THRESHOLD.with(Cell::get)
+}
+
+/// Records the allocation of a garbage collected value of the given size, collecting first if
+/// the threshold has been reached.
+pub(crate) fn track_allocation(bytes: usize) {
+    let allocated = ALLOCATED_SINCE_COLLECTION.with(|allocated| {
+        let total = allocated.get() + bytes;
+        allocated.set(total);
+        total
+    });
+    if threshold().map_or(false, |threshold| allocated > threshold) {
+        force_collect();
+    }
+
+    STATS.with(|stats| {
+        let mut current = stats.get();
+        current.allocated_bytes += bytes;
+        current.object_count += 1;
+        stats.set(current);
+    });
+}
+
+/// Records that a garbage collected value of the given size has been collected.
+pub(crate) fn track_deallocation(bytes: usize) {
+    // The heap is also swept while the thread is shutting down, after this state may be gone.
+    let _ = STATS.try_with(|stats| {
+        let mut current = stats.get();
+        current.allocated_bytes = current.allocated_bytes.saturating_sub(bytes);
+        current.object_count = current.object_count.saturating_sub(1);
+        stats.set(current);
+    });
+}
+
+/// Records the allocation of data owned by a garbage collected value, like its properties, or
+/// of string data.
+pub(crate) fn track_data_allocation(bytes: usize) {
+    STATS.with(|stats| {
+        let mut current = stats.get();
+        current.allocated_bytes += bytes;
+        stats.set(current);
+    });
+}
+
+/// Records that data tracked by [`track_data_allocation`] has been freed.
+pub(crate) fn track_data_deallocation(bytes: usize) {
+    let _ = STATS.try_with(|stats| {
+        let mut current = stats.get();
+        current.allocated_bytes = current.allocated_bytes.saturating_sub(bytes);
+        stats.set(current);
+    });
+}
+
+/// Clears the weak references to the object at the given address, which is being collected.
+///
+/// The collector finalizes all the garbage before freeing any of it, so the entry of the address
+/// is always removed before the address can be reused by a new object. The map is never borrowed
+/// while the collector can run, so it can always be borrowed here.
+pub(crate) fn track_finalization(address: usize) {
+    let _ = WEAK_TARGETS.try_with(|targets| {
+        if let Some(flags) = targets.borrow_mut().remove(&address) {
+            for alive in flags {
+                alive.set(false);
+            }
+        }
+    });
+}
+
+/// A weak reference to a [`GcObject`], which does not keep the object alive.
+///
+/// Once the garbage collector has finalized the object, [`upgrade`](Self::upgrade) returns
+/// `None`.
+pub struct WeakGcObject {
+    target: GcObject,
+    alive: Rc<Cell<bool>>,
+}
+
+impl WeakGcObject {
+    /// Creates a new weak reference to `object`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    pub fn new(object: &GcObject) -> Self {
+        let address = &*object.borrow() as *const Object as usize;
+        let alive = Rc::new(Cell::new(true));
+        WEAK_TARGETS.with(|targets| {
+            let mut targets = targets.borrow_mut();
+            let flags = targets.entry(address).or_default();
+            // Forget the flags of the weak references that have already been dropped.
+            flags.retain(|flag| Rc::strong_count(flag) > 1);
+            flags.push(alive.clone());
+        });
+
+        let target = object.clone();
+        // SAFETY: This handle is never traced, so it must not count as a root of the object
+        // either. It is only dereferenced while `alive` is set, and `Object::finalize` clears it
+        // before the object is freed. Dropping an unrooted handle does not touch the object.
+        unsafe { target.unroot() };
+
+        Self { target, alive }
+    }
+
+    /// Creates a weak reference whose object has already been collected.
+    pub(crate) fn collected() -> Self {
+        let target = GcObject::new(Object::default());
+        // SAFETY: See `WeakGcObject::new`, the handle is never dereferenced since `alive` is
+        // never set.
+        unsafe { target.unroot() };
+
+        Self {
+            target,
+            alive: Rc::new(Cell::new(false)),
+        }
+    }
+
+    /// Returns the object if it has not been collected yet.
+    #[inline]
+    pub fn upgrade(&self) -> Option<GcObject> {
+        if self.alive.get() {
+            Some(self.target.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the object has been collected.
+    #[inline]
+    pub fn is_collected(&self) -> bool {
+        !self.alive.get()
+    }
+
+    /// Checks if this is a weak reference to `object`.
+    #[inline]
+    pub fn is(&self, object: &GcObject) -> bool {
+        self.upgrade()
+            .map_or(false, |target| GcObject::equals(&target, object))
+    }
+}
+
+impl Finalize for WeakGcObject {}
+
+// SAFETY: The target is deliberately not traced, see `WeakGcObject::new`.
+unsafe impl Trace for WeakGcObject {
+    empty_trace!();
+}
+
+impl fmt::Debug for WeakGcObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakGcObject")
+            .field("collected", &self.is_collected())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Object;
+
+    #[test]
+    fn stats_follow_allocations_and_collections() {
+        let before = stats();
+
+        let live: Vec<GcObject> = (0..100_000)
+            .map(|_| GcObject::new(Object::default()))
+            .collect();
+        for _ in 0..1_000_000 {
+            GcObject::new(Object::default());
+        }
+
+        let allocated = stats();
+        assert!(allocated.object_count >= before.object_count + live.len());
+        assert!(allocated.allocated_bytes > before.allocated_bytes);
+
+        drop(live);
+        force_collect();
+
+        let collected = stats();
+        assert!(collected.object_count + 100_000 <= allocated.object_count);
+        assert!(collected.allocated_bytes < allocated.allocated_bytes);
+        assert_eq!(collected.collections, allocated.collections + 1);
+    }
+
+    #[test]
+    fn threshold_starts_collections() {
+        set_threshold(Some(1024));
+        let before = stats();
+        for _ in 0..1_000 {
+            GcObject::new(Object::default());
+        }
+        set_threshold(None);
+
+        assert!(stats().collections > before.collections);
+    }
+
+    #[test]
+    fn weak_reference_is_cleared_after_collection() {
+        let object = GcObject::new(Object::default());
+        let weak = WeakGcObject::new(&object);
+        let kept = GcObject::new(Object::default());
+        let kept_weak = WeakGcObject::new(&kept);
+
+        force_collect();
+        assert!(weak.is(&object));
+
+        drop(object);
+        force_collect();
+        assert!(weak.upgrade().is_none());
+        assert!(weak.is_collected());
+        assert!(kept_weak.is(&kept));
+    }
+
+    #[test]
+    fn weak_references_survive_address_reuse() {
+        let mut collected = Vec::new();
+        for _ in 0..1_000 {
+            let object = GcObject::new(Object::default());
+            let weak = WeakGcObject::new(&object);
+            drop(object);
+            force_collect();
+            assert!(weak.is_collected());
+            collected.push(weak);
+
+            // The new object is likely allocated at the address of a collected one.
+            let object = GcObject::new(Object::default());
+            let weak = WeakGcObject::new(&object);
+            assert!(weak.is(&object));
+            assert!(collected
+                .iter()
+                .all(|weak| weak.upgrade().is_none() && !weak.is(&object)));
+        }
+    }
+}
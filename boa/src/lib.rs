@@ -4,7 +4,15 @@ This is an experimental Javascript lexer, parser and compiler written in Rust. C
 # Crate Features
  - **serde** - Enables serialization and deserialization of the AST (Abstract Syntax Tree).
  - **console** - Enables `boa`s WHATWG `console` object implementation.
- - **profiler** - Enables profiling with measureme (this is mostly internal).
+ - **profiler** - Enables profiling with measureme, and the in-process summary of
+   `BoaProfiler::summary` (this is mostly internal).
+ - **shapes** - Stores the string properties of the objects in shapes (hidden classes), and
+   caches the slots of the properties in the member accesses.
+ - **system** - Uses the clock, the random number generator and the standard output of the
+   operating system by default (enabled by default). Disable it to build for targets without
+   them, like `wasm32-unknown-unknown`; local times are then in UTC.
+ - **vm** - Adds the bytecode VM, used by the contexts built with `ContextBuilder::use_vm`.
+   The code that the VM doesn't support yet is run by the AST interpreter.
 
 **/
 
@@ -44,14 +52,19 @@ This is an experimental Javascript lexer, parser and compiler written in Rust. C
 
 pub mod builtins;
 pub mod class;
+pub mod coverage;
 pub mod environment;
+pub mod error;
 pub mod exec;
 pub mod gc;
 pub mod object;
+pub mod pool;
 pub mod profiler;
 pub mod property;
 pub mod realm;
+pub mod snapshot;
 pub mod syntax;
+pub mod trace;
 pub mod value;
 #[cfg(feature = "vm")]
 pub mod vm;
@@ -85,6 +98,23 @@ pub fn parse<T: AsRef<[u8]>>(src: T, strict_mode: bool) -> StdResult<StatementLi
     Parser::new(src_bytes, strict_mode).parse_all()
 }
 
+/// Parses the given source code and serializes the resulting AST to JSON.
+///
+/// The AST owns all its identifiers and string literals, so the serialized AST is self-contained,
+/// and can be cached and loaded back with [`ast_from_json`].
+#[cfg(feature = "deser")]
+pub fn parse_to_json<T: AsRef<[u8]>>(src: T, strict_mode: bool) -> StdResult<String, ParseError> {
+    let statement_list = parse(src, strict_mode)?;
+
+    Ok(serde_json::to_string(&statement_list).expect("could not serialize the AST"))
+}
+
+/// Deserializes an AST previously serialized with [`parse_to_json`].
+#[cfg(feature = "deser")]
+pub fn ast_from_json(json: &str) -> serde_json::Result<StatementList> {
+    serde_json::from_str(json)
+}
+
 /// Execute the code using an existing Context
 /// The str is consumed and the state of the Context is changed
 #[cfg(test)]
@@ -141,8 +171,9 @@ pub(crate) fn forward_val<T: AsRef<[u8]>>(context: &mut Context, src: T) -> Resu
 pub(crate) fn exec<T: AsRef<[u8]>>(src: T) -> String {
     let src_bytes: &[u8] = src.as_ref();
 
-    match Context::new().eval(src_bytes) {
+    let mut context = Context::new();
+    match context.eval(src_bytes) {
         Ok(value) => value.display().to_string(),
-        Err(error) => error.display().to_string(),
+        Err(error) => error.into_value(&mut context).display().to_string(),
     }
 }
@@ -2,19 +2,23 @@
 //!
 //! The `GcObject` is a garbage collected Object.
 
-use super::{NativeObject, Object, PROTOTYPE};
+use super::{internal_methods::string_get_own_property, NativeObject, Object, PROTOTYPE};
 use crate::{
     builtins::function::{
         create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
     },
+    context::SourceText,
     environment::{
         function_environment_record::BindingStatus, lexical_environment::new_function_environment,
     },
-    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    exec::CompletionType,
+    property::{PartialDescriptor, PropertyKey},
     syntax::ast::node::RcStatementList,
     value::PreferredType,
-    Context, Executable, Result, Value,
+    Context, Result, Value,
 };
+#[cfg(feature = "profiler")]
+use crate::{profiler::category, BoaProfiler};
 use gc::{Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
 use serde_json::{map::Map, Value as JSONValue};
 use std::{
@@ -32,23 +36,34 @@ pub type Ref<'a, T> = GcCellRef<'a, T>;
 pub type RefMut<'a, T> = GcCellRefMut<'a, T>;
 
 /// Garbage collected `Object`.
-#[derive(Trace, Finalize, Clone, Default)]
+#[derive(Trace, Finalize, Clone)]
 pub struct GcObject(Gc<GcCell<Object>>);
 
+impl Default for GcObject {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Object::default())
+    }
+}
+
 /// The body of a JavaScript function.
 ///
 /// This is needed for the call method since we cannot mutate the function itself since we
 /// already borrow it so we get the function body clone it then drop the borrow and run the body
+///
+/// The body of an ordinary function is run with the source text of the script that defines it,
+/// so that the functions it creates know their source code.
 enum FunctionBody {
     BuiltInFunction(NativeFunction),
     BuiltInConstructor(NativeFunction),
-    Ordinary(RcStatementList),
+    Ordinary(RcStatementList, Option<SourceText>),
 }
 
 impl GcObject {
     /// Create a new `GcObject` from a `Object`.
     #[inline]
     pub fn new(object: Object) -> Self {
+        crate::gc::track_allocation(std::mem::size_of::<Object>());
         Self(Gc::new(GcCell::new(object)))
     }
 
@@ -132,15 +147,24 @@ impl GcObject {
                         params,
                         environment,
                         flags,
+                        home_object,
+                        source,
                     } => {
                         // Create a new Function environment whose parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
                         let local_env = new_function_environment(
                             this_function_object,
+                            // <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
                             if flags.is_lexical_this_mode() {
                                 None
-                            } else {
+                            } else if flags.is_strict() || this.is_object() {
                                 Some(this.clone())
+                            } else if this.is_null_or_undefined() {
+                                // Sloppy mode functions see the global object instead.
+                                Some(context.global_object().clone().into())
+                            } else {
+                                // Sloppy mode functions see primitive `this` values boxed.
+                                Some(this.to_object(context)?.into())
                             },
                             Some(environment.clone()),
                             // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
@@ -150,6 +174,9 @@ impl GcObject {
                                 BindingStatus::Uninitialized
                             },
                             Value::undefined(),
+                            home_object
+                                .clone()
+                                .map_or_else(Value::undefined, Value::from),
                         );
 
                         // Add argument bindings to the function environment
@@ -177,28 +204,59 @@ impl GcObject {
 
                         context.realm_mut().environment.push(local_env);
 
-                        FunctionBody::Ordinary(body.clone())
+                        FunctionBody::Ordinary(
+                            body.clone(),
+                            source.as_ref().map(|source| source.script().clone()),
+                        )
                     }
                 }
             } else {
                 return context.throw_type_error("function object is not callable");
             }
         } else {
-            return context.throw_type_error("not a function");
+            return context.throw_type_error("object is not a function");
+        };
+
+        #[cfg(feature = "profiler")]
+        let _timer = match f_body {
+            FunctionBody::Ordinary(..) => None,
+            _ => Some(
+                BoaProfiler::global().start_event(&self.builtin_name(), category::BUILTIN_CALL),
+            ),
         };
 
         match f_body {
-            FunctionBody::BuiltInFunction(func) => func(this, args, context),
-            FunctionBody::BuiltInConstructor(func) => func(&Value::undefined(), args, context),
-            FunctionBody::Ordinary(body) => {
-                let result = body.run(context);
+            FunctionBody::BuiltInFunction(func) => context.call_native(func, this, args),
+            FunctionBody::BuiltInConstructor(func) => {
+                context.call_native(func, &Value::undefined(), args)
+            }
+            FunctionBody::Ordinary(body, script) => {
+                let result =
+                    context.with_active_source(script, |context| context.run_statement_list(&body));
                 context.realm_mut().environment.pop();
 
-                result
+                // A function returns `undefined` unless it ends with a `return` statement.
+                let completion = result?;
+                if completion.kind() == &CompletionType::Return {
+                    Ok(completion.into_value())
+                } else {
+                    Ok(Value::undefined())
+                }
             }
         }
     }
 
+    /// Returns the `name` of a builtin function, to name its calls in the profiler.
+    ///
+    /// The name is read from the own data property, so that no getter runs.
+    #[cfg(feature = "profiler")]
+    fn builtin_name(&self) -> String {
+        self.get_own_property(&"name".into())
+            .and_then(|property| property.as_data_descriptor().map(|data| data.value()))
+            .and_then(|name| name.as_string().map(|name| name.to_string()))
+            .unwrap_or_else(|| "anonymous".to_string())
+    }
+
     /// Construct an instance of this object with the specified arguments.
     ///
     /// # Panics
@@ -224,6 +282,8 @@ impl GcObject {
                         params,
                         environment,
                         flags,
+                        home_object,
+                        source,
                     } => {
                         // If the prototype of the constructor is not an object, then use the default object
                         // prototype as prototype for the new object
@@ -258,6 +318,9 @@ impl GcObject {
                                 BindingStatus::Uninitialized
                             },
                             new_target.clone(),
+                            home_object
+                                .clone()
+                                .map_or_else(Value::undefined, Value::from),
                         );
 
                         // Add argument bindings to the function environment
@@ -285,7 +348,10 @@ impl GcObject {
 
                         context.realm_mut().environment.push(local_env);
 
-                        FunctionBody::Ordinary(body.clone())
+                        FunctionBody::Ordinary(
+                            body.clone(),
+                            source.as_ref().map(|source| source.script().clone()),
+                        )
                     }
                 }
             } else {
@@ -296,16 +362,29 @@ impl GcObject {
                 return context.throw_type_error(format!("{} is not a constructor", name));
             }
         } else {
-            return context.throw_type_error("not a function");
+            return context.throw_type_error("object is not a constructor");
+        };
+
+        #[cfg(feature = "profiler")]
+        let _timer = match body {
+            FunctionBody::Ordinary(..) => None,
+            _ => Some(
+                BoaProfiler::global().start_event(&self.builtin_name(), category::BUILTIN_CALL),
+            ),
         };
 
         match body {
-            FunctionBody::BuiltInConstructor(function) => function(&new_target, args, context),
-            FunctionBody::Ordinary(body) => {
-                let _ = body.run(context);
+            FunctionBody::BuiltInConstructor(function) => {
+                context.call_native(function, &new_target, args)
+            }
+            FunctionBody::Ordinary(body, script) => {
+                let _ =
+                    context.with_active_source(script, |context| context.run_statement_list(&body));
 
-                // local_env gets dropped here, its no longer needed
                 let binding = context.realm_mut().environment.get_this_binding();
+
+                // local_env gets dropped here, its no longer needed
+                let _ = context.realm_mut().environment.pop();
                 binding.map_err(|e| e.to_error(context))
             }
             FunctionBody::BuiltInFunction(_) => unreachable!("Cannot have a function in construct"),
@@ -396,114 +475,25 @@ impl GcObject {
             let this = Value::from(self.clone());
             for key in keys {
                 let value = this.get_field(key, context)?;
-                if value.is_undefined() || value.is_function() || value.is_symbol() {
-                    arr.push(JSONValue::Null);
-                } else {
-                    arr.push(value.to_json(context)?);
-                }
+                arr.push(value.to_json_property(context)?.unwrap_or(JSONValue::Null));
             }
             Ok(JSONValue::Array(arr))
         } else {
             let mut new_obj = Map::new();
             let this = Value::from(self.clone());
-            for k in self.borrow().keys() {
+            // The keys are collected first, the getters of the properties may modify the object.
+            let keys: Vec<_> = self.borrow().keys().collect();
+            for k in keys {
                 let key = k.clone();
                 let value = this.get_field(k.to_string(), context)?;
-                if !value.is_undefined() && !value.is_function() && !value.is_symbol() {
-                    new_obj.insert(key.to_string(), value.to_json(context)?);
+                if let Some(value) = value.to_json_property(context)? {
+                    new_obj.insert(key.to_string(), value);
                 }
             }
             Ok(JSONValue::Object(new_obj))
         }
     }
 
-    /// Convert the object to a `PropertyDescritptor`
-    ///
-    /// # Panics
-    ///
-    /// Panics if the object is currently mutably borrowed.
-    pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
-        let mut attribute = Attribute::empty();
-
-        let enumerable_key = PropertyKey::from("enumerable");
-        if self.has_property(&enumerable_key)
-            && self
-                .get(&enumerable_key, self.clone().into(), context)?
-                .to_boolean()
-        {
-            attribute |= Attribute::ENUMERABLE;
-        }
-
-        let configurable_key = PropertyKey::from("configurable");
-        if self.has_property(&configurable_key)
-            && self
-                .get(&configurable_key, self.clone().into(), context)?
-                .to_boolean()
-        {
-            attribute |= Attribute::CONFIGURABLE;
-        }
-
-        let mut value = None;
-        let value_key = PropertyKey::from("value");
-        if self.has_property(&value_key) {
-            value = Some(self.get(&value_key, self.clone().into(), context)?);
-        }
-
-        let mut has_writable = false;
-        let writable_key = PropertyKey::from("writable");
-        if self.has_property(&writable_key) {
-            has_writable = true;
-            if self
-                .get(&writable_key, self.clone().into(), context)?
-                .to_boolean()
-            {
-                attribute |= Attribute::WRITABLE;
-            }
-        }
-
-        let mut get = None;
-        let get_key = PropertyKey::from("get");
-        if self.has_property(&get_key) {
-            let getter = self.get(&get_key, self.clone().into(), context)?;
-            match getter {
-                Value::Object(ref object) if object.is_callable() => {
-                    get = Some(object.clone());
-                }
-                _ => {
-                    return Err(
-                        context.construct_type_error("Property descriptor getter must be callable")
-                    );
-                }
-            }
-        }
-
-        let mut set = None;
-        let set_key = PropertyKey::from("set");
-        if self.has_property(&set_key) {
-            let setter = self.get(&set_key, self.clone().into(), context)?;
-            match setter {
-                Value::Object(ref object) if object.is_callable() => {
-                    set = Some(object.clone());
-                }
-                _ => {
-                    return Err(
-                        context.construct_type_error("Property descriptor setter must be callable")
-                    );
-                }
-            };
-        }
-
-        if get.is_some() || set.is_some() {
-            if value.is_some() || has_writable {
-                return Err(context.construct_type_error("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute"));
-            }
-
-            Ok(AccessorDescriptor::new(get, set, attribute).into())
-        } else {
-            Ok(DataDescriptor::new(value.unwrap_or_else(Value::undefined), attribute).into())
-        }
-    }
-
     /// Reeturn `true` if it is a native object and the native type is `T`.
     ///
     /// # Panics
@@ -798,7 +788,12 @@ impl GcObject {
         K: Into<PropertyKey>,
     {
         let key = key.into();
-        self.get_own_property(&key).is_some()
+        // The descriptor is only looked up, not cloned.
+        let object = self.borrow();
+        object.get_own_property_ref(&key).is_some()
+            || object.as_string().map_or(false, |string| {
+                string_get_own_property(&string, &key).is_some()
+            })
     }
 
     /// Defines the property or throws a `TypeError` if the operation fails.
@@ -816,7 +811,7 @@ impl GcObject {
     ) -> Result<()>
     where
         K: Into<PropertyKey>,
-        P: Into<PropertyDescriptor>,
+        P: Into<PartialDescriptor>,
     {
         let key = key.into();
         let desc = desc.into();
@@ -828,6 +823,60 @@ impl GcObject {
             Ok(())
         }
     }
+
+    /// Sets the property, with the object as the receiver, or throws a `TypeError` if the
+    /// operation fails.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-o-p-v-throw
+    #[inline]
+    pub(crate) fn set_or_throw<K, V>(
+        &mut self,
+        key: K,
+        value: V,
+        context: &mut Context,
+    ) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        let receiver = self.clone().into();
+
+        let success = self.set(key.clone(), value.into(), receiver, context)?;
+        if !success {
+            Err(context
+                .construct_type_error(format!("Cannot assign to read only property: {}", key)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Deletes the property or throws a `TypeError` if the operation fails.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    #[inline]
+    pub(crate) fn delete_property_or_throw<K>(
+        &mut self,
+        key: K,
+        context: &mut Context,
+    ) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+
+        if !self.delete(&key) {
+            Err(context.construct_type_error(format!("Cannot delete property: {}", key)))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl AsRef<GcCell<Object>> for GcObject {
@@ -6,8 +6,12 @@
 //! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
 
 use crate::{
-    object::{GcObject, Object, ObjectData},
-    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    gc,
+    object::{GcObject, IntegrityLevel, Object, ObjectData},
+    property::{
+        AccessorDescriptor, Attribute, DataDescriptor, PartialDescriptor, PropertyDescriptor,
+        PropertyKey,
+    },
     value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
@@ -21,16 +25,14 @@ impl GcObject {
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-hasproperty-p
     #[inline]
     pub fn has_property(&self, key: &PropertyKey) -> bool {
-        let prop = self.get_own_property(key);
-        if prop.is_none() {
-            let parent = self.get_prototype_of();
-            return if let Value::Object(ref object) = parent {
-                object.has_property(key)
-            } else {
-                false
-            };
+        if self.has_own_property(key.clone()) {
+            return true;
+        }
+        if let Value::Object(ref object) = self.get_prototype_of() {
+            object.has_property(key)
+        } else {
+            false
         }
-        true
     }
 
     /// Check if it is extensible.
@@ -56,6 +58,62 @@ impl GcObject {
         true
     }
 
+    /// The abstract operation `SetIntegrityLevel`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+    pub fn set_integrity_level(
+        &mut self,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<bool> {
+        if !self.prevent_extensions() {
+            return Ok(false);
+        }
+
+        for key in self.own_property_keys() {
+            let desc = match level {
+                IntegrityLevel::Sealed => PartialDescriptor::new().configurable(false),
+                IntegrityLevel::Frozen => match self.get_own_property(&key) {
+                    Some(PropertyDescriptor::Accessor(_)) => {
+                        PartialDescriptor::new().configurable(false)
+                    }
+                    Some(PropertyDescriptor::Data(_)) => {
+                        PartialDescriptor::new().configurable(false).writable(false)
+                    }
+                    None => continue,
+                },
+            };
+            self.define_property_or_throw(key, desc, context)?;
+        }
+
+        Ok(true)
+    }
+
+    /// The abstract operation `TestIntegrityLevel`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-testintegritylevel
+    pub fn test_integrity_level(&self, level: IntegrityLevel) -> bool {
+        if self.is_extensible() {
+            return false;
+        }
+
+        self.own_property_keys()
+            .iter()
+            .all(|key| match self.get_own_property(key) {
+                Some(desc) if desc.configurable() => false,
+                Some(PropertyDescriptor::Data(desc)) if level == IntegrityLevel::Frozen => {
+                    !desc.writable()
+                }
+                _ => true,
+            })
+    }
+
     /// Delete property.
     #[inline]
     pub fn delete(&mut self, key: &PropertyKey) -> bool {
@@ -72,8 +130,25 @@ impl GcObject {
     /// `[[Get]]`
     /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver>
     pub fn get(&self, key: &PropertyKey, receiver: Value, context: &mut Context) -> Result<Value> {
-        match self.get_own_property(key) {
+        // The descriptor is read in place, so that only the value or the getter is cloned.
+        let object = self.borrow();
+        match object.get_own_property_ref(key) {
+            Some(PropertyDescriptor::Data(desc)) => Ok(desc.value()),
+            Some(PropertyDescriptor::Accessor(AccessorDescriptor { get: Some(get), .. })) => {
+                let get = get.clone();
+                drop(object);
+                get.call(&receiver, &[], context)
+            }
+            Some(PropertyDescriptor::Accessor(_)) => Ok(Value::undefined()),
             None => {
+                if let Some(PropertyDescriptor::Data(desc)) = object
+                    .as_string()
+                    .and_then(|string| string_get_own_property(&string, key))
+                {
+                    return Ok(desc.value());
+                }
+                drop(object);
+
                 // parent will either be null or an Object
                 if let Some(parent) = self.get_prototype_of().as_object() {
                     Ok(parent.get(key, receiver, context)?)
@@ -81,13 +156,6 @@ impl GcObject {
                     Ok(Value::undefined())
                 }
             }
-            Some(ref desc) => match desc {
-                PropertyDescriptor::Data(desc) => Ok(desc.value()),
-                PropertyDescriptor::Accessor(AccessorDescriptor { get: Some(get), .. }) => {
-                    get.call(&receiver, &[], context)
-                }
-                _ => Ok(Value::undefined()),
-            },
         }
     }
 
@@ -102,6 +170,20 @@ impl GcObject {
     ) -> Result<bool> {
         let _timer = BoaProfiler::global().start_event("Object::set", "object");
 
+        // Fast path for a writable own data property of the receiver, which is only updated.
+        // Arrays are left to `[[DefineOwnProperty]]`, which maintains their `length`.
+        if let Value::Object(ref receiver_object) = receiver {
+            if GcObject::equals(self, receiver_object) && !self.is_array() {
+                let mut object = self.borrow_mut();
+                if let Some(PropertyDescriptor::Data(desc)) = object.get_own_property_mut(&key) {
+                    if desc.writable() {
+                        *desc = DataDescriptor::new(val, desc.attributes());
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+
         // Fetch property key
         let own_desc = if let Some(desc) = self.get_own_property(&key) {
             desc
@@ -126,8 +208,7 @@ impl GcObject {
                                 }
                                 receiver.define_own_property(
                                     key,
-                                    DataDescriptor::new(val, existing_data_desc.attributes())
-                                        .into(),
+                                    PartialDescriptor::new().value(val),
                                     context,
                                 )
                             }
@@ -160,14 +241,19 @@ impl GcObject {
     pub fn define_own_property<K>(
         &mut self,
         key: K,
-        desc: PropertyDescriptor,
+        desc: PartialDescriptor,
         context: &mut Context,
     ) -> Result<bool>
     where
         K: Into<PropertyKey>,
     {
+        let key = key.into();
+        context.initialize_global_property(self, &key);
+
         if self.is_array() {
             self.array_define_own_property(key, desc, context)
+        } else if self.borrow().is_string() {
+            Ok(self.string_define_own_property(key, desc))
         } else {
             Ok(self.ordinary_define_own_property(key, desc))
         }
@@ -179,86 +265,64 @@ impl GcObject {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinarydefineownproperty
-    pub fn ordinary_define_own_property<K>(&mut self, key: K, desc: PropertyDescriptor) -> bool
+    pub fn ordinary_define_own_property<K>(&mut self, key: K, desc: PartialDescriptor) -> bool
     where
         K: Into<PropertyKey>,
     {
         let _timer = BoaProfiler::global().start_event("Object::define_own_property", "object");
 
         let key = key.into();
+        let current = self.get_own_property(&key);
         let extensible = self.is_extensible();
 
-        let current = if let Some(desc) = self.get_own_property(&key) {
-            desc
-        } else {
-            if !extensible {
-                return false;
-            }
-
-            self.insert(key, desc);
-            return true;
-        };
-
-        // 4
-        if !current.configurable() {
-            if desc.configurable() {
-                return false;
-            }
+        self.validate_and_apply_property_descriptor(key, extensible, desc, current)
+    }
 
-            if desc.enumerable() != current.enumerable() {
-                return false;
-            }
+    /// Check whether `desc` can be applied on top of the `current` descriptor of the property
+    /// `key`, and if so apply it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
+    fn validate_and_apply_property_descriptor(
+        &mut self,
+        key: PropertyKey,
+        extensible: bool,
+        desc: PartialDescriptor,
+        current: Option<PropertyDescriptor>,
+    ) -> bool {
+        if !is_compatible_property_descriptor(extensible, &desc, current.as_ref()) {
+            return false;
         }
 
-        match (&current, &desc) {
-            (PropertyDescriptor::Data(current), PropertyDescriptor::Data(desc)) => {
-                if !current.configurable() && !current.writable() {
-                    if desc.writable() {
-                        return false;
-                    }
-
-                    if !same_value(&desc.value(), &current.value()) {
-                        return false;
-                    }
-                }
-            }
-            (PropertyDescriptor::Data(current), PropertyDescriptor::Accessor(_)) => {
-                if !current.configurable() {
-                    return false;
-                }
-
-                let current = AccessorDescriptor::new(None, None, current.attributes());
-                self.insert(key, current);
-                return true;
-            }
-            (PropertyDescriptor::Accessor(current), PropertyDescriptor::Data(_)) => {
-                if !current.configurable() {
-                    return false;
-                }
-
-                let current = DataDescriptor::new(Value::undefined(), current.attributes());
-                self.insert(key, current);
-                return true;
-            }
-            (PropertyDescriptor::Accessor(current), PropertyDescriptor::Accessor(desc)) => {
-                if !current.configurable() {
-                    if let (Some(current_get), Some(desc_get)) = (current.getter(), desc.getter()) {
-                        if !GcObject::equals(&current_get, &desc_get) {
-                            return false;
-                        }
-                    }
+        // 2.c - 2.e, 9.
+        self.insert(key, desc.apply(current.as_ref()));
+        true
+    }
 
-                    if let (Some(current_set), Some(desc_set)) = (current.setter(), desc.setter()) {
-                        if !GcObject::equals(&current_set, &desc_set) {
-                            return false;
-                        }
-                    }
-                }
-            }
+    /// Define an own property for a `String` exotic object, whose index properties can not
+    /// be redefined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects-defineownproperty-p-desc
+    fn string_define_own_property<K>(&mut self, key: K, desc: PartialDescriptor) -> bool
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        let string_desc = self
+            .borrow()
+            .as_string()
+            .and_then(|string| string_get_own_property(&string, &key));
+        if let Some(string_desc) = string_desc {
+            let extensible = self.is_extensible();
+            return is_compatible_property_descriptor(extensible, &desc, Some(&string_desc));
         }
 
-        self.insert(key, desc);
-        true
+        self.ordinary_define_own_property(key, desc)
     }
 
     /// Define an own property for an array.
@@ -270,7 +334,7 @@ impl GcObject {
     fn array_define_own_property<K>(
         &mut self,
         key: K,
-        desc: PropertyDescriptor,
+        desc: PartialDescriptor,
         context: &mut Context,
     ) -> Result<bool>
     where
@@ -278,83 +342,7 @@ impl GcObject {
     {
         let key = key.into();
         match key {
-            PropertyKey::String(ref s) if s == "length" => {
-                match desc {
-                    PropertyDescriptor::Accessor(_) => {
-                        return Ok(self.ordinary_define_own_property("length", desc))
-                    }
-                    PropertyDescriptor::Data(ref d) => {
-                        if d.value().is_undefined() {
-                            return Ok(self.ordinary_define_own_property("length", desc));
-                        }
-                        let new_len = d.value().to_u32(context)?;
-                        let number_len = d.value().to_number(context)?;
-                        #[allow(clippy::float_cmp)]
-                        if new_len as f64 != number_len {
-                            return Err(context.construct_range_error("bad length for array"));
-                        }
-                        let mut new_len_desc =
-                            PropertyDescriptor::Data(DataDescriptor::new(new_len, d.attributes()));
-                        let old_len_desc = self.get_own_property(&"length".into()).unwrap();
-                        let old_len_desc = old_len_desc.as_data_descriptor().unwrap();
-                        let old_len = old_len_desc.value();
-                        if new_len >= old_len.to_u32(context)? {
-                            return Ok(self.ordinary_define_own_property("length", new_len_desc));
-                        }
-                        if !old_len_desc.writable() {
-                            return Ok(false);
-                        }
-                        let new_writable = if new_len_desc.attributes().writable() {
-                            true
-                        } else {
-                            let mut new_attributes = new_len_desc.attributes();
-                            new_attributes.set_writable(true);
-                            new_len_desc = PropertyDescriptor::Data(DataDescriptor::new(
-                                new_len,
-                                new_attributes,
-                            ));
-                            false
-                        };
-                        if !self.ordinary_define_own_property("length", new_len_desc.clone()) {
-                            return Ok(false);
-                        }
-                        let keys_to_delete = {
-                            let obj = self.borrow();
-                            let mut keys = obj
-                                .index_property_keys()
-                                .filter(|&&k| k >= new_len)
-                                .cloned()
-                                .collect::<Vec<_>>();
-                            keys.sort_unstable();
-                            keys
-                        };
-                        for key in keys_to_delete.into_iter().rev() {
-                            if !self.delete(&key.into()) {
-                                let mut new_len_desc_attribute = new_len_desc.attributes();
-                                if !new_writable {
-                                    new_len_desc_attribute.set_writable(false);
-                                }
-                                let new_len_desc = PropertyDescriptor::Data(DataDescriptor::new(
-                                    key + 1,
-                                    new_len_desc_attribute,
-                                ));
-                                self.ordinary_define_own_property("length", new_len_desc);
-                                return Ok(false);
-                            }
-                        }
-                        if !new_writable {
-                            let mut new_desc_attr = new_len_desc.attributes();
-                            new_desc_attr.set_writable(false);
-                            let new_desc = PropertyDescriptor::Data(DataDescriptor::new(
-                                new_len,
-                                new_desc_attr,
-                            ));
-                            self.ordinary_define_own_property("length", new_desc);
-                        }
-                    }
-                }
-                Ok(true)
-            }
+            PropertyKey::String(ref s) if s == "length" => self.array_set_length(desc, context),
             PropertyKey::Index(index) => {
                 let old_len_desc = self.get_own_property(&"length".into()).unwrap();
                 let old_len_data_desc = old_len_desc.as_data_descriptor().unwrap();
@@ -362,23 +350,96 @@ impl GcObject {
                 if index >= old_len && !old_len_data_desc.writable() {
                     return Ok(false);
                 }
-                if self.ordinary_define_own_property(key, desc) {
-                    if index >= old_len && index < std::u32::MAX {
-                        let desc = PropertyDescriptor::Data(DataDescriptor::new(
-                            index + 1,
-                            old_len_data_desc.attributes(),
-                        ));
-                        self.ordinary_define_own_property("length", desc);
-                    }
-                    Ok(true)
-                } else {
-                    Ok(false)
+                if !self.ordinary_define_own_property(key, desc) {
+                    return Ok(false);
+                }
+                if index >= old_len && index < std::u32::MAX {
+                    self.ordinary_define_own_property(
+                        "length",
+                        PartialDescriptor::new().value(index + 1),
+                    );
                 }
+                Ok(true)
             }
             _ => Ok(self.ordinary_define_own_property(key, desc)),
         }
     }
 
+    /// Set the `length` of an array, deleting the elements past the new length.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraysetlength
+    fn array_set_length(&mut self, desc: PartialDescriptor, context: &mut Context) -> Result<bool> {
+        // 1.
+        let value = if let Some(ref value) = desc.value {
+            value.clone()
+        } else {
+            return Ok(self.ordinary_define_own_property("length", desc));
+        };
+
+        // 3. - 5.
+        let new_len = value.to_u32(context)?;
+        let number_len = value.to_number(context)?;
+        #[allow(clippy::float_cmp)]
+        if new_len as f64 != number_len {
+            return Err(context.construct_range_error("bad length for array"));
+        }
+        let mut new_len_desc = desc.value(new_len);
+
+        // 7. - 11.
+        let old_len_desc = self.get_own_property(&"length".into()).unwrap();
+        let old_len_desc = old_len_desc.as_data_descriptor().unwrap();
+        let old_len = old_len_desc.value().to_u32(context)?;
+        if new_len >= old_len {
+            return Ok(self.ordinary_define_own_property("length", new_len_desc));
+        }
+        if !old_len_desc.writable() {
+            return Ok(false);
+        }
+
+        // 12. - 13.
+        let new_writable = new_len_desc.writable != Some(false);
+        if !new_writable {
+            new_len_desc = new_len_desc.writable(true);
+        }
+
+        // 14. - 15.
+        if !self.ordinary_define_own_property("length", new_len_desc.clone()) {
+            return Ok(false);
+        }
+
+        // 16.
+        let keys_to_delete = {
+            let obj = self.borrow();
+            let mut keys = obj
+                .index_property_keys()
+                .filter(|&&k| k >= new_len)
+                .cloned()
+                .collect::<Vec<_>>();
+            keys.sort_unstable();
+            keys
+        };
+        for key in keys_to_delete.into_iter().rev() {
+            if !self.delete(&key.into()) {
+                let mut new_len_desc = new_len_desc.value(key + 1);
+                if !new_writable {
+                    new_len_desc = new_len_desc.writable(false);
+                }
+                self.ordinary_define_own_property("length", new_len_desc);
+                return Ok(false);
+            }
+        }
+
+        // 17.
+        if !new_writable {
+            self.ordinary_define_own_property("length", PartialDescriptor::new().writable(false));
+        }
+
+        Ok(true)
+    }
+
     /// The specification returns a Property Descriptor or Undefined.
     ///
     /// These are 2 separate types and we can't do that here.
@@ -392,13 +453,11 @@ impl GcObject {
         let _timer = BoaProfiler::global().start_event("Object::get_own_property", "object");
 
         let object = self.borrow();
-        let property = match key {
-            PropertyKey::Index(index) => object.indexed_properties.get(&index),
-            PropertyKey::String(ref st) => object.string_properties.get(st),
-            PropertyKey::Symbol(ref symbol) => object.symbol_properties.get(symbol),
-        };
-
-        property.cloned()
+        object.get_own_property_ref(key).cloned().or_else(|| {
+            object
+                .as_string()
+                .and_then(|string| string_get_own_property(&string, key))
+        })
     }
 
     /// Essential internal method OwnPropertyKeys
@@ -410,7 +469,33 @@ impl GcObject {
     #[inline]
     #[track_caller]
     pub fn own_property_keys(&self) -> Vec<PropertyKey> {
-        self.borrow().keys().collect()
+        let object = self.borrow();
+        // String exotic objects list the indices of their string first.
+        let string_length = object
+            .as_string()
+            .map_or(0, |string| string.encode_utf16().count() as u32);
+
+        // The indices are listed in ascending order, then the string keys and the symbols in the
+        // order they were added.
+        let mut indices: Vec<u32> = object.index_property_keys().copied().collect();
+        indices.sort_unstable();
+
+        (0..string_length)
+            .chain(indices)
+            .map(PropertyKey::Index)
+            .chain(
+                object
+                    .string_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .chain(
+                object
+                    .symbol_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .collect()
     }
 
     /// The abstract operation ObjectDefineProperties
@@ -423,7 +508,7 @@ impl GcObject {
     pub fn define_properties(&mut self, props: Value, context: &mut Context) -> Result<()> {
         let props = &props.to_object(context)?;
         let keys = props.own_property_keys();
-        let mut descriptors: Vec<(PropertyKey, PropertyDescriptor)> = Vec::new();
+        let mut descriptors: Vec<(PropertyKey, PartialDescriptor)> = Vec::new();
 
         for next_key in keys {
             if let Some(prop_desc) = props.get_own_property(&next_key) {
@@ -436,7 +521,7 @@ impl GcObject {
         }
 
         for (p, d) in descriptors {
-            self.define_own_property(p, d, context)?;
+            self.define_property_or_throw(p, d, context)?;
         }
 
         Ok(())
@@ -563,6 +648,32 @@ impl GcObject {
 }
 
 impl Object {
+    /// Gets a reference to the own property `key` stored in the object, without cloning it.
+    ///
+    /// The indices of String objects are not stored, so they are not returned, see
+    /// [`GcObject::get_own_property`] for the complete `[[GetOwnProperty]]`.
+    #[inline]
+    pub(crate) fn get_own_property_ref(&self, key: &PropertyKey) -> Option<&PropertyDescriptor> {
+        match key {
+            PropertyKey::Index(index) => self.indexed_properties.get(index),
+            PropertyKey::String(ref string) => self.string_properties.get(string),
+            PropertyKey::Symbol(ref symbol) => self.symbol_properties.get(symbol),
+        }
+    }
+
+    /// Mutable version of [`get_own_property_ref`](Object::get_own_property_ref).
+    #[inline]
+    pub(crate) fn get_own_property_mut(
+        &mut self,
+        key: &PropertyKey,
+    ) -> Option<&mut PropertyDescriptor> {
+        match key {
+            PropertyKey::Index(index) => self.indexed_properties.get_mut(index),
+            PropertyKey::String(ref string) => self.string_properties.get_mut(string),
+            PropertyKey::Symbol(ref symbol) => self.symbol_properties.get_mut(symbol),
+        }
+    }
+
     /// Helper function for property insertion.
     #[inline]
     pub(crate) fn insert<K, P>(&mut self, key: K, property: P) -> Option<PropertyDescriptor>
@@ -571,7 +682,7 @@ impl Object {
         P: Into<PropertyDescriptor>,
     {
         let property = property.into();
-        match key.into() {
+        let previous = match key.into() {
             PropertyKey::Index(index) => self.indexed_properties.insert(index, property),
             PropertyKey::String(ref string) => {
                 self.string_properties.insert(string.clone(), property)
@@ -579,17 +690,25 @@ impl Object {
             PropertyKey::Symbol(ref symbol) => {
                 self.symbol_properties.insert(symbol.clone(), property)
             }
+        };
+        if previous.is_none() {
+            gc::track_data_allocation(Self::PROPERTY_SIZE);
         }
+        previous
     }
 
     /// Helper function for property removal.
     #[inline]
     pub(crate) fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
-        match key {
+        let removed = match key {
             PropertyKey::Index(index) => self.indexed_properties.remove(&index),
             PropertyKey::String(ref string) => self.string_properties.remove(string),
             PropertyKey::Symbol(ref symbol) => self.symbol_properties.remove(symbol),
+        };
+        if removed.is_some() {
+            gc::track_data_deallocation(Self::PROPERTY_SIZE);
         }
+        removed
     }
 
     /// Inserts a field in the object `properties` without checking if it's writable.
@@ -610,3 +729,123 @@ impl Object {
         self.insert(key.into(), DataDescriptor::new(value, attribute))
     }
 }
+
+/// Check whether the partial descriptor `desc` can be applied on top of `current`, the
+/// descriptor of an existing property or `None` if there is no such property.
+///
+/// This is [ValidateAndApplyPropertyDescriptor][spec] with `O` set to `undefined`.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
+fn is_compatible_property_descriptor(
+    extensible: bool,
+    desc: &PartialDescriptor,
+    current: Option<&PropertyDescriptor>,
+) -> bool {
+    // 2.
+    let current = if let Some(current) = current {
+        current
+    } else {
+        return extensible;
+    };
+
+    // 3.
+    if desc.is_empty() {
+        return true;
+    }
+
+    // 4.
+    if !current.configurable() {
+        if desc.configurable == Some(true) {
+            return false;
+        }
+
+        if let Some(enumerable) = desc.enumerable {
+            if enumerable != current.enumerable() {
+                return false;
+            }
+        }
+    }
+
+    if desc.is_generic_descriptor() {
+        // 5. No further validation is required.
+    } else if current.is_data_descriptor() != desc.is_data_descriptor() {
+        // 6.
+        if !current.configurable() {
+            return false;
+        }
+    } else {
+        match *current {
+            // 7.
+            PropertyDescriptor::Data(ref current) => {
+                if !current.configurable() && !current.writable() {
+                    if desc.writable == Some(true) {
+                        return false;
+                    }
+
+                    if let Some(ref value) = desc.value {
+                        if !same_value(value, &current.value()) {
+                            return false;
+                        }
+                    }
+
+                    return true;
+                }
+            }
+            // 8.
+            PropertyDescriptor::Accessor(ref current) => {
+                if !current.configurable() {
+                    if let Some(ref get) = desc.get {
+                        if !same_function(get.as_ref(), current.getter()) {
+                            return false;
+                        }
+                    }
+
+                    if let Some(ref set) = desc.set {
+                        if !same_function(set.as_ref(), current.setter()) {
+                            return false;
+                        }
+                    }
+
+                    return true;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+/// Get the descriptor of an index property of the string wrapped by a `String` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+pub(super) fn string_get_own_property(
+    string: &str,
+    key: &PropertyKey,
+) -> Option<PropertyDescriptor> {
+    let index = match *key {
+        PropertyKey::Index(index) => index as usize,
+        _ => return None,
+    };
+    let code_unit = string.encode_utf16().nth(index)?;
+
+    Some(
+        DataDescriptor::new(
+            String::from_utf16_lossy(&[code_unit]),
+            Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+        )
+        .into(),
+    )
+}
+
+/// Whether two optional accessor functions are the same, `None` standing for `undefined`.
+#[inline]
+fn same_function(x: Option<&GcObject>, y: Option<&GcObject>) -> bool {
+    match (x, y) {
+        (Some(x), Some(y)) => GcObject::equals(x, y),
+        (None, None) => true,
+        _ => false,
+    }
+}
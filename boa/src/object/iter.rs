@@ -2,6 +2,20 @@ use super::{Object, PropertyDescriptor, PropertyKey};
 use crate::value::{RcString, RcSymbol};
 use std::{collections::hash_map, iter::FusedIterator};
 
+#[cfg(not(feature = "shapes"))]
+type StringIter<'a> = super::property_map::Iter<'a>;
+#[cfg(not(feature = "shapes"))]
+type StringKeys<'a> = super::property_map::Keys<'a>;
+#[cfg(not(feature = "shapes"))]
+type StringValues<'a> = super::property_map::Values<'a>;
+
+#[cfg(feature = "shapes")]
+type StringIter<'a> = super::shape::Iter<'a>;
+#[cfg(feature = "shapes")]
+type StringKeys<'a> = super::shape::Keys<'a>;
+#[cfg(feature = "shapes")]
+type StringValues<'a> = super::shape::Values<'a>;
+
 impl Object {
     /// An iterator visiting all key-value pairs in arbitrary order. The iterator element type is `(PropertyKey, &'a Property)`.
     ///
@@ -109,7 +123,7 @@ impl Object {
 #[derive(Debug, Clone)]
 pub struct Iter<'a> {
     indexed_properties: hash_map::Iter<'a, u32, PropertyDescriptor>,
-    string_properties: hash_map::Iter<'a, RcString, PropertyDescriptor>,
+    string_properties: StringIter<'a>,
     symbol_properties: hash_map::Iter<'a, RcSymbol, PropertyDescriptor>,
 }
 
@@ -342,7 +356,7 @@ impl FusedIterator for IndexPropertyValues<'_> {}
 
 /// An iterator over the `String` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct StringProperties<'a>(hash_map::Iter<'a, RcString, PropertyDescriptor>);
+pub struct StringProperties<'a>(StringIter<'a>);
 
 impl<'a> Iterator for StringProperties<'a> {
     type Item = (&'a RcString, &'a PropertyDescriptor);
@@ -369,7 +383,7 @@ impl FusedIterator for StringProperties<'_> {}
 
 /// An iterator over the string keys (`RcString`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyKeys<'a>(hash_map::Keys<'a, RcString, PropertyDescriptor>);
+pub struct StringPropertyKeys<'a>(StringKeys<'a>);
 
 impl<'a> Iterator for StringPropertyKeys<'a> {
     type Item = &'a RcString;
@@ -396,7 +410,7 @@ impl FusedIterator for StringPropertyKeys<'_> {}
 
 /// An iterator over the string values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyValues<'a>(hash_map::Values<'a, RcString, PropertyDescriptor>);
+pub struct StringPropertyValues<'a>(StringValues<'a>);
 
 impl<'a> Iterator for StringPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
@@ -3,6 +3,7 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
+        finalization_registry::FinalizationRegistry,
         function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
@@ -10,8 +11,9 @@ use crate::{
         BigInt, Date, RegExp,
     },
     context::StandardConstructor,
-    gc::{Finalize, Trace},
-    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    gc::{Finalize, Trace, WeakGcObject},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    snapshot::HeapCopier,
     value::{same_value, RcBigInt, RcString, RcSymbol, Value},
     BoaProfiler, Context,
 };
@@ -28,11 +30,36 @@ mod tests;
 mod gcobject;
 mod internal_methods;
 mod iter;
+#[cfg(not(feature = "shapes"))]
+mod property_map;
+#[cfg(feature = "shapes")]
+pub mod shape;
 
 use crate::builtins::object::for_in_iterator::ForInIterator;
 pub use gcobject::{GcObject, RecursionLimiter, Ref, RefMut};
 pub use iter::*;
 
+/// The storage of the string properties of an object, which is shaped with the `shapes` feature.
+#[cfg(not(feature = "shapes"))]
+use property_map::StringPropertyMap;
+#[cfg(feature = "shapes")]
+use shape::StringPropertyMap;
+
+/// The integrity level an object can be sealed or frozen to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityLevel {
+    /// No property can be added, removed or reconfigured.
+    Sealed,
+
+    /// Like `Sealed`, and additionally no data property can be written.
+    Frozen,
+}
+
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
 pub static PROTOTYPE: &str = "prototype";
 
@@ -60,13 +87,13 @@ impl<T: Any + Debug + Trace> NativeObject for T {
 }
 
 /// The internal representation of an JavaScript object.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Trace)]
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
     indexed_properties: FxHashMap<u32, PropertyDescriptor>,
     /// Properties
-    string_properties: FxHashMap<RcString, PropertyDescriptor>,
+    string_properties: StringPropertyMap,
     /// Symbol Properties
     symbol_properties: FxHashMap<RcSymbol, PropertyDescriptor>,
     /// Instance prototype `__proto__`.
@@ -95,6 +122,8 @@ pub enum ObjectData {
     Ordinary,
     Date(Date),
     Global,
+    WeakRef(WeakGcObject),
+    FinalizationRegistry(FinalizationRegistry),
     NativeObject(Box<dyn NativeObject>),
 }
 
@@ -121,12 +150,63 @@ impl Display for ObjectData {
                 Self::BigInt(_) => "BigInt",
                 Self::Date(_) => "Date",
                 Self::Global => "Global",
+                Self::WeakRef(_) => "WeakRef",
+                Self::FinalizationRegistry(_) => "FinalizationRegistry",
                 Self::NativeObject(_) => "NativeObject",
             }
         )
     }
 }
 
+impl ObjectData {
+    /// Copies the data of an object for a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// Returns `None` for native objects, which cannot be copied.
+    fn copy_with(&self, copier: &mut HeapCopier) -> Option<Self> {
+        let data = match self {
+            Self::Array => Self::Array,
+            Self::ArrayIterator(iterator) => Self::ArrayIterator(iterator.copy_with(copier)),
+            Self::Map(map) => {
+                let mut copy = OrderedMap::new();
+                for (key, value) in map.iter() {
+                    copy.insert(copier.copy_value(key), copier.copy_value(value));
+                }
+                Self::Map(copy)
+            }
+            Self::MapIterator(iterator) => Self::MapIterator(iterator.copy_with(copier)),
+            Self::RegExp(regexp) => Self::RegExp(regexp.clone()),
+            Self::BigInt(bigint) => Self::BigInt(bigint.clone()),
+            Self::Boolean(boolean) => Self::Boolean(*boolean),
+            Self::ForInIterator(iterator) => Self::ForInIterator(iterator.copy_with(copier)),
+            Self::Function(function) => Self::Function(function.copy_with(copier)),
+            Self::String(string) => Self::String(string.clone()),
+            Self::StringIterator(iterator) => Self::StringIterator(iterator.copy_with(copier)),
+            Self::Number(number) => Self::Number(*number),
+            Self::Symbol(symbol) => Self::Symbol(symbol.clone()),
+            Self::Error => Self::Error,
+            Self::Ordinary => Self::Ordinary,
+            Self::Date(date) => Self::Date(*date),
+            Self::Global => Self::Global,
+            Self::WeakRef(weak) => Self::WeakRef(copier.copy_weak(weak)),
+            Self::FinalizationRegistry(registry) => {
+                Self::FinalizationRegistry(registry.copy_with(copier))
+            }
+            Self::NativeObject(_) => return None,
+        };
+
+        Some(data)
+    }
+}
+
+impl Finalize for Object {
+    #[inline]
+    fn finalize(&self) {
+        crate::gc::track_deallocation(std::mem::size_of::<Self>());
+        crate::gc::track_data_deallocation(self.iter().len() * Self::PROPERTY_SIZE);
+        crate::gc::track_finalization(self as *const Self as usize);
+    }
+}
+
 impl Default for Object {
     /// Return a new ObjectData struct, with `kind` set to Ordinary
     #[inline]
@@ -134,7 +214,7 @@ impl Default for Object {
         Self {
             data: ObjectData::Ordinary,
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
+            string_properties: StringPropertyMap::default(),
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
@@ -143,6 +223,10 @@ impl Default for Object {
 }
 
 impl Object {
+    /// The approximate number of bytes used to store a property, for the heap statistics.
+    pub(crate) const PROPERTY_SIZE: usize =
+        std::mem::size_of::<PropertyKey>() + std::mem::size_of::<PropertyDescriptor>();
+
     #[inline]
     pub fn new() -> Self {
         Default::default()
@@ -156,7 +240,7 @@ impl Object {
         Self {
             data: ObjectData::Function(function),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
+            string_properties: StringPropertyMap::default(),
             symbol_properties: FxHashMap::default(),
             prototype,
             extensible: true,
@@ -183,7 +267,7 @@ impl Object {
         Self {
             data: ObjectData::Boolean(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
+            string_properties: StringPropertyMap::default(),
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
@@ -196,7 +280,7 @@ impl Object {
         Self {
             data: ObjectData::Number(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
+            string_properties: StringPropertyMap::default(),
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
@@ -212,7 +296,7 @@ impl Object {
         Self {
             data: ObjectData::String(value.into()),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
+            string_properties: StringPropertyMap::default(),
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
@@ -225,7 +309,7 @@ impl Object {
         Self {
             data: ObjectData::BigInt(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
+            string_properties: StringPropertyMap::default(),
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
@@ -241,7 +325,7 @@ impl Object {
         Self {
             data: ObjectData::NativeObject(Box::new(value)),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
+            string_properties: StringPropertyMap::default(),
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
@@ -388,6 +472,14 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_function_mut(&mut self) -> Option<&mut Function> {
+        match self.data {
+            ObjectData::Function(ref mut function) => Some(function),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Symbol object.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -502,6 +594,35 @@ impl Object {
         }
     }
 
+    /// Copies the object for a [`Snapshot`](crate::snapshot::Snapshot).
+    ///
+    /// Returns `None` if the data of the object cannot be copied.
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Option<Self> {
+        let data = self.data.copy_with(copier)?;
+        crate::gc::track_data_allocation(self.iter().len() * Self::PROPERTY_SIZE);
+
+        Some(Self {
+            data,
+            indexed_properties: self
+                .indexed_properties
+                .iter()
+                .map(|(index, property)| (*index, copier.copy_property(property)))
+                .collect(),
+            string_properties: self
+                .string_properties
+                .iter()
+                .map(|(key, property)| (key.clone(), copier.copy_property(property)))
+                .collect(),
+            symbol_properties: self
+                .symbol_properties
+                .iter()
+                .map(|(key, property)| (key.clone(), copier.copy_property(property)))
+                .collect(),
+            prototype: copier.copy_value(&self.prototype),
+            extensible: self.extensible,
+        })
+    }
+
     /// Similar to `Value::new_object`, but you can pass a prototype to create from, plus a kind
     #[inline]
     pub fn with_prototype(proto: Value, data: ObjectData) -> Object {
@@ -713,7 +834,7 @@ impl<'context> FunctionBuilder<'context> {
                 .prototype()
                 .into(),
         );
-        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let attribute = Attribute::BUILTIN_READONLY;
         if let Some(name) = self.name.take() {
             function.insert_property("name", name, attribute);
         } else {
@@ -738,7 +859,7 @@ impl<'context> FunctionBuilder<'context> {
                 .prototype()
                 .into(),
         );
-        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let attribute = Attribute::BUILTIN_READONLY;
         if let Some(name) = self.name.take() {
             object.insert_property("name", name, attribute);
         } else {
@@ -748,6 +869,28 @@ impl<'context> FunctionBuilder<'context> {
     }
 }
 
+/// Wrap the native getter and setter of an accessor property named `name` in function objects.
+fn native_accessor_functions(
+    context: &mut Context,
+    name: &str,
+    get: Option<NativeFunction>,
+    set: Option<NativeFunction>,
+) -> (Option<GcObject>, Option<GcObject>) {
+    let get = get.map(|get| {
+        FunctionBuilder::new(context, get)
+            .name(format!("get {}", name))
+            .length(0)
+            .build()
+    });
+    let set = set.map(|set| {
+        FunctionBuilder::new(context, set)
+            .name(format!("set {}", name))
+            .length(1)
+            .build()
+    });
+    (get, set)
+}
+
 /// Builder for creating objects with properties.
 ///
 /// # Examples
@@ -770,6 +913,20 @@ impl<'context> FunctionBuilder<'context> {
 ///     func: function() {}
 /// }
 /// ```
+///
+/// Accessor properties take their getter and setter as function objects, which can be created
+/// with a [`FunctionBuilder`]:
+///
+/// ```
+/// # use boa::{Context, Value, object::{FunctionBuilder, ObjectInitializer}, property::Attribute};
+/// let mut context = Context::new();
+/// let getter = FunctionBuilder::new(&mut context, |_, _, _| Ok(Value::from(42)))
+///     .name("get answer")
+///     .build();
+/// let object = ObjectInitializer::new(&mut context)
+///     .accessor("answer", Some(getter), None, Attribute::CONFIGURABLE)
+///     .build();
+/// ```
 #[derive(Debug)]
 pub struct ObjectInitializer<'context> {
     context: &'context mut Context,
@@ -798,11 +955,9 @@ impl<'context> ObjectInitializer<'context> {
             .constructable(false)
             .build();
 
-        self.object.borrow_mut().insert_property(
-            binding.binding,
-            function,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        );
+        self.object
+            .borrow_mut()
+            .insert_property(binding.binding, function, Attribute::BUILTIN);
         self
     }
 
@@ -818,6 +973,50 @@ impl<'context> ObjectInitializer<'context> {
         self
     }
 
+    /// Add an accessor property to the object.
+    ///
+    /// A `writable` flag in `attribute` is ignored, accessors can not have one.
+    #[inline]
+    pub fn accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<GcObject>,
+        set: Option<GcObject>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let property = AccessorDescriptor::new(get, set, attribute);
+        self.object.borrow_mut().insert(key, property);
+        self
+    }
+
+    /// Add an accessor property whose getter and setter are native functions.
+    ///
+    /// The functions are wrapped in function objects named `get <name>` and `set <name>`.
+    #[inline]
+    pub fn accessor_native<B>(
+        &mut self,
+        binding: B,
+        get: Option<NativeFunction>,
+        set: Option<NativeFunction>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let (get, set) = native_accessor_functions(self.context, &binding.name, get, set);
+        self.accessor(binding.binding, get, set, attribute)
+    }
+
+    /// Return the current context.
+    #[inline]
+    pub fn context(&mut self) -> &'_ mut Context {
+        self.context
+    }
+
     /// Build the object.
     #[inline]
     pub fn build(&mut self) -> GcObject {
@@ -902,11 +1101,9 @@ impl<'context> ConstructorBuilder<'context> {
             .constructable(false)
             .build();
 
-        self.prototype.borrow_mut().insert_property(
-            binding.binding,
-            function,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        );
+        self.prototype
+            .borrow_mut()
+            .insert_property(binding.binding, function, Attribute::BUILTIN);
         self
     }
 
@@ -932,7 +1129,7 @@ impl<'context> ConstructorBuilder<'context> {
         self.constructor_object.borrow_mut().insert_property(
             binding.binding,
             function,
-            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            Attribute::BUILTIN,
         );
         self
     }
@@ -961,6 +1158,75 @@ impl<'context> ConstructorBuilder<'context> {
         self
     }
 
+    /// Add new accessor property to the constructors prototype.
+    #[inline]
+    pub fn accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<GcObject>,
+        set: Option<GcObject>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let property = AccessorDescriptor::new(get, set, attribute);
+        self.prototype.borrow_mut().insert(key, property);
+        self
+    }
+
+    /// Add new static accessor property to the constructors object itself.
+    #[inline]
+    pub fn static_accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<GcObject>,
+        set: Option<GcObject>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let property = AccessorDescriptor::new(get, set, attribute);
+        self.constructor_object.borrow_mut().insert(key, property);
+        self
+    }
+
+    /// Add new accessor property to the constructors prototype, with native getter and setter.
+    #[inline]
+    pub fn accessor_native<B>(
+        &mut self,
+        binding: B,
+        get: Option<NativeFunction>,
+        set: Option<NativeFunction>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let (get, set) = native_accessor_functions(self.context, &binding.name, get, set);
+        self.accessor(binding.binding, get, set, attribute)
+    }
+
+    /// Add new static accessor property to the constructors object itself, with native getter
+    /// and setter.
+    #[inline]
+    pub fn static_accessor_native<B>(
+        &mut self,
+        binding: B,
+        get: Option<NativeFunction>,
+        set: Option<NativeFunction>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let (get, set) = native_accessor_functions(self.context, &binding.name, get, set);
+        self.static_accessor(binding.binding, get, set, attribute)
+    }
+
     /// Specify how many arguments the constructor function takes.
     ///
     /// Default is `0`.
@@ -1024,13 +1290,10 @@ impl<'context> ConstructorBuilder<'context> {
             FunctionFlags::from_parameters(self.callable, self.constructable),
         );
 
-        let length = DataDescriptor::new(
-            self.length,
-            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-        );
+        let length = DataDescriptor::new(self.length, Attribute::BUILTIN_READONLY);
         let name = DataDescriptor::new(
             self.name.take().unwrap_or_else(|| String::from("[object]")),
-            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            Attribute::BUILTIN_READONLY,
         );
 
         {
@@ -1047,11 +1310,7 @@ impl<'context> ConstructorBuilder<'context> {
                     .into(),
             );
 
-            constructor.insert_property(
-                PROTOTYPE,
-                self.prototype.clone(),
-                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-            );
+            constructor.insert_property(PROTOTYPE, self.prototype.clone(), Attribute::CONSTANT);
         }
 
         {
@@ -1059,7 +1318,7 @@ impl<'context> ConstructorBuilder<'context> {
             prototype.insert_property(
                 "constructor",
                 self.constructor_object.clone(),
-                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                Attribute::BUILTIN,
             );
 
             if let Some(proto) = self.inherit.take() {
@@ -0,0 +1,88 @@
+//! The storage of the string properties of an object without the `shapes` feature.
+//!
+//! The properties are kept in the order they were added, which is the order
+//! `OrdinaryOwnPropertyKeys` lists the string keys in.
+
+use super::PropertyDescriptor;
+use crate::{
+    gc::{custom_trace, Finalize, Trace},
+    value::RcString,
+};
+use indexmap::IndexMap;
+use rustc_hash::FxHasher;
+use std::{hash::BuildHasherDefault, iter::FromIterator};
+
+type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
+
+pub(crate) type Iter<'a> = indexmap::map::Iter<'a, RcString, PropertyDescriptor>;
+pub(crate) type Keys<'a> = indexmap::map::Keys<'a, RcString, PropertyDescriptor>;
+pub(crate) type Values<'a> = indexmap::map::Values<'a, RcString, PropertyDescriptor>;
+
+/// The string properties of an object, in the order they were added.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StringPropertyMap(FxIndexMap<RcString, PropertyDescriptor>);
+
+impl Finalize for StringPropertyMap {}
+unsafe impl Trace for StringPropertyMap {
+    custom_trace!(this, {
+        for property in this.0.values() {
+            mark(property);
+        }
+    });
+}
+
+impl StringPropertyMap {
+    #[inline]
+    pub(crate) fn get(&self, key: &RcString) -> Option<&PropertyDescriptor> {
+        self.0.get(key)
+    }
+
+    #[inline]
+    pub(crate) fn get_mut(&mut self, key: &RcString) -> Option<&mut PropertyDescriptor> {
+        self.0.get_mut(key)
+    }
+
+    #[inline]
+    pub(crate) fn insert(
+        &mut self,
+        key: RcString,
+        property: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        self.0.insert(key, property)
+    }
+
+    /// Removes a property, shifting the following ones to keep the order of the keys.
+    #[inline]
+    pub(crate) fn remove(&mut self, key: &RcString) -> Option<PropertyDescriptor> {
+        self.0.shift_remove(key)
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub(crate) fn iter(&self) -> Iter<'_> {
+        self.0.iter()
+    }
+
+    #[inline]
+    pub(crate) fn keys(&self) -> Keys<'_> {
+        self.0.keys()
+    }
+
+    #[inline]
+    pub(crate) fn values(&self) -> Values<'_> {
+        self.0.values()
+    }
+}
+
+impl FromIterator<(RcString, PropertyDescriptor)> for StringPropertyMap {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (RcString, PropertyDescriptor)>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
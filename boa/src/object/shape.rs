@@ -0,0 +1,426 @@
+//! Shapes of the objects, also known as hidden classes.
+//!
+//! With the `shapes` feature, the objects with the same string keys, added in the same order,
+//! share a [`Shape`] that maps each key to the slot of its property, so that the properties are
+//! stored in a vector. The member accesses of the AST remember the slot of their property for the
+//! last shape they saw in an [`InlineCache`], and read it again without looking up the key.
+//!
+//! Adding a property transitions the object to a child shape, which is shared by all the objects
+//! adding this key. Removing the last added property transitions it back to the parent shape.
+//! Other removals, and objects with many properties, turn the object into a dictionary, which is
+//! not cached.
+
+use super::{GcObject, PropertyDescriptor};
+use crate::{
+    gc::{custom_trace, empty_trace, Finalize, Trace},
+    property::DataDescriptor,
+    value::RcString,
+    Value,
+};
+use indexmap::{IndexMap, IndexSet};
+use rustc_hash::{FxHashMap, FxHasher};
+use std::{
+    cell::{Cell, RefCell},
+    hash::BuildHasherDefault,
+    iter::{FromIterator, FusedIterator, Zip},
+    mem,
+    rc::{Rc, Weak},
+    slice,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+type FxIndexSet<T> = IndexSet<T, BuildHasherDefault<FxHasher>>;
+type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
+
+/// The number of properties above which an object becomes a dictionary.
+///
+/// Each shape holds all of its keys, so objects used as maps would otherwise create as many
+/// shapes as they have properties.
+const MAX_SHAPED_PROPERTIES: usize = 64;
+
+/// The identifier of the next shape.
+static NEXT_SHAPE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The layout of the string properties of an object.
+#[derive(Debug)]
+pub struct Shape {
+    /// The unique identifier of the shape, which the inline caches compare.
+    id: usize,
+    /// The keys of the properties, at the index of their slot.
+    keys: FxIndexSet<RcString>,
+    /// The shape without the last key.
+    parent: Option<Rc<Shape>>,
+    /// The shapes with one more key, which are shared by the objects adding it.
+    transitions: RefCell<FxHashMap<RcString, Weak<Shape>>>,
+}
+
+impl Shape {
+    /// Creates a new shape.
+    fn new(keys: FxIndexSet<RcString>, parent: Option<Rc<Shape>>) -> Rc<Self> {
+        Rc::new(Self {
+            id: NEXT_SHAPE_ID.fetch_add(1, Ordering::Relaxed),
+            keys,
+            parent,
+            transitions: RefCell::default(),
+        })
+    }
+
+    /// Gets the shape of the objects without string properties.
+    fn root() -> Rc<Self> {
+        thread_local! {
+            static ROOT: Rc<Shape> = Shape::new(FxIndexSet::default(), None);
+        }
+        ROOT.with(Rc::clone)
+    }
+
+    /// Gets the shape with `key` added after the keys of this one.
+    fn with_key(self: &Rc<Self>, key: &RcString) -> Rc<Self> {
+        let mut transitions = self.transitions.borrow_mut();
+        if let Some(shape) = transitions.get(key).and_then(Weak::upgrade) {
+            return shape;
+        }
+
+        let mut keys = self.keys.clone();
+        keys.insert(key.clone());
+        let shape = Self::new(keys, Some(self.clone()));
+        transitions.insert(key.clone(), Rc::downgrade(&shape));
+        shape
+    }
+
+    /// Gets the unique identifier of the shape.
+    #[inline]
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Gets the slot of the property `key`.
+    #[inline]
+    pub fn slot(&self, key: &str) -> Option<usize> {
+        self.keys.get_index_of(key)
+    }
+}
+
+/// The string properties of an object.
+#[derive(Debug, Clone)]
+pub(crate) enum StringPropertyMap {
+    /// The properties are in the slots of a shape.
+    Shaped {
+        shape: Rc<Shape>,
+        slots: Vec<PropertyDescriptor>,
+    },
+    /// The properties are in a map, for the objects that don't fit a shape.
+    Dictionary(FxIndexMap<RcString, PropertyDescriptor>),
+}
+
+impl Default for StringPropertyMap {
+    fn default() -> Self {
+        Self::Shaped {
+            shape: Shape::root(),
+            slots: Vec::new(),
+        }
+    }
+}
+
+impl Finalize for StringPropertyMap {}
+unsafe impl Trace for StringPropertyMap {
+    custom_trace!(this, {
+        match this {
+            Self::Shaped { slots, .. } => {
+                for property in slots {
+                    mark(property);
+                }
+            }
+            Self::Dictionary(map) => {
+                for property in map.values() {
+                    mark(property);
+                }
+            }
+        }
+    });
+}
+
+impl StringPropertyMap {
+    /// Gets the shape of the properties, unless they are in a dictionary.
+    #[inline]
+    pub(crate) fn shape(&self) -> Option<&Rc<Shape>> {
+        match self {
+            Self::Shaped { shape, .. } => Some(shape),
+            Self::Dictionary(_) => None,
+        }
+    }
+
+    /// Gets the property at `slot`, if the properties have the shape `shape_id`.
+    #[inline]
+    pub(crate) fn get_slot(&self, shape_id: usize, slot: usize) -> Option<&PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } if shape.id() == shape_id => slots.get(slot),
+            _ => None,
+        }
+    }
+
+    /// Mutable version of [`get_slot`](StringPropertyMap::get_slot).
+    #[inline]
+    pub(crate) fn get_slot_mut(
+        &mut self,
+        shape_id: usize,
+        slot: usize,
+    ) -> Option<&mut PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } if shape.id() == shape_id => slots.get_mut(slot),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, key: &RcString) -> Option<&PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => slots.get(shape.slot(key)?),
+            Self::Dictionary(map) => map.get(key),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_mut(&mut self, key: &RcString) -> Option<&mut PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => slots.get_mut(shape.slot(key)?),
+            Self::Dictionary(map) => map.get_mut(key),
+        }
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        key: RcString,
+        property: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => {
+                if let Some(slot) = shape.slot(&key) {
+                    return Some(mem::replace(&mut slots[slot], property));
+                }
+                if slots.len() < MAX_SHAPED_PROPERTIES {
+                    *shape = shape.with_key(&key);
+                    slots.push(property);
+                    return None;
+                }
+            }
+            Self::Dictionary(map) => return map.insert(key, property),
+        }
+
+        self.make_dictionary().insert(key, property)
+    }
+
+    pub(crate) fn remove(&mut self, key: &RcString) -> Option<PropertyDescriptor> {
+        match self {
+            Self::Shaped { shape, slots } => {
+                let slot = shape.slot(key)?;
+                if slot + 1 == slots.len() {
+                    let parent = shape
+                        .parent
+                        .clone()
+                        .expect("a shape with keys has a parent");
+                    *shape = parent;
+                    return slots.pop();
+                }
+            }
+            Self::Dictionary(map) => return map.shift_remove(key),
+        }
+
+        self.make_dictionary().shift_remove(key)
+    }
+
+    /// Moves the properties to a dictionary.
+    fn make_dictionary(&mut self) -> &mut FxIndexMap<RcString, PropertyDescriptor> {
+        if let Self::Shaped { shape, slots } = self {
+            let map = shape.keys.iter().cloned().zip(mem::take(slots)).collect();
+            *self = Self::Dictionary(map);
+        }
+
+        match self {
+            Self::Dictionary(map) => map,
+            Self::Shaped { .. } => unreachable!("the properties were moved to a dictionary"),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Shaped { slots, .. } => slots.len(),
+            Self::Dictionary(map) => map.len(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn iter(&self) -> Iter<'_> {
+        match self {
+            Self::Shaped { shape, slots } => Iter::Shaped(shape.keys.iter().zip(slots.iter())),
+            Self::Dictionary(map) => Iter::Dictionary(map.iter()),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn keys(&self) -> Keys<'_> {
+        Keys(self.iter())
+    }
+
+    #[inline]
+    pub(crate) fn values(&self) -> Values<'_> {
+        Values(self.iter())
+    }
+}
+
+impl FromIterator<(RcString, PropertyDescriptor)> for StringPropertyMap {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (RcString, PropertyDescriptor)>,
+    {
+        let mut map = Self::default();
+        for (key, property) in iter {
+            map.insert(key, property);
+        }
+        map
+    }
+}
+
+/// An iterator over the string properties of an object, in the order they were added.
+#[derive(Debug, Clone)]
+pub(crate) enum Iter<'a> {
+    Shaped(Zip<indexmap::set::Iter<'a, RcString>, slice::Iter<'a, PropertyDescriptor>>),
+    Dictionary(indexmap::map::Iter<'a, RcString, PropertyDescriptor>),
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a RcString, &'a PropertyDescriptor);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Shaped(iter) => iter.next(),
+            Self::Dictionary(iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Shaped(iter) => iter.size_hint(),
+            Self::Dictionary(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+/// An iterator over the string keys of an object, in the order they were added.
+#[derive(Debug, Clone)]
+pub(crate) struct Keys<'a>(Iter<'a>);
+
+impl<'a> Iterator for Keys<'a> {
+    type Item = &'a RcString;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Keys<'_> {}
+
+impl FusedIterator for Keys<'_> {}
+
+/// An iterator over the string properties of an object, in the order they were added.
+#[derive(Debug, Clone)]
+pub(crate) struct Values<'a>(Iter<'a>);
+
+impl<'a> Iterator for Values<'a> {
+    type Item = &'a PropertyDescriptor;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, property)| property)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Values<'_> {}
+
+impl FusedIterator for Values<'_> {}
+
+/// A one entry cache of the slot of a property, for the member accesses of the AST.
+///
+/// It holds the slot of the property in the last shape it was looked up for, which is valid as
+/// long as the object has this shape.
+#[derive(Debug, Clone, Default)]
+pub struct InlineCache(Cell<Option<(usize, usize)>>);
+
+unsafe impl Trace for InlineCache {
+    empty_trace!();
+}
+
+impl Finalize for InlineCache {}
+
+impl PartialEq for InlineCache {
+    /// Caches don't change the meaning of a node, so they are all equal.
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl InlineCache {
+    /// Gets the value of the cached property of `object`, if it has the cached shape and the
+    /// property is a data property.
+    #[inline]
+    pub(crate) fn get(&self, object: &GcObject) -> Option<Value> {
+        let (shape_id, slot) = self.0.get()?;
+        match object.borrow().string_properties.get_slot(shape_id, slot)? {
+            PropertyDescriptor::Data(data) => Some(data.value()),
+            PropertyDescriptor::Accessor(_) => None,
+        }
+    }
+
+    /// Sets the value of the cached property of `object`, if it has the cached shape and the
+    /// property is a writable data property.
+    ///
+    /// Returns `false` if the property was not set.
+    #[inline]
+    pub(crate) fn set(&self, object: &GcObject, value: &Value) -> bool {
+        let (shape_id, slot) = match self.0.get() {
+            Some(entry) => entry,
+            None => return false,
+        };
+
+        let mut object = object.borrow_mut();
+        // Arrays define their `length` property by themselves.
+        if object.is_array() {
+            return false;
+        }
+        match object.string_properties.get_slot_mut(shape_id, slot) {
+            Some(PropertyDescriptor::Data(data)) if data.writable() => {
+                *data = DataDescriptor::new(value.clone(), data.attributes());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Caches the slot of the property `key` of `object`, if it has a shape.
+    #[inline]
+    pub(crate) fn update(&self, object: &GcObject, key: &str) {
+        let object = object.borrow();
+        let entry = object
+            .string_properties
+            .shape()
+            .and_then(|shape| Some((shape.id(), shape.slot(key)?)));
+        self.0.set(entry);
+    }
+}
@@ -17,3 +17,108 @@ fn ordinary_has_instance_nonobject_prototype() {
         "\"TypeError: function has non-object prototype in instanceof check\""
     );
 }
+
+#[test]
+fn property_access_after_shape_changes() {
+    let scenario = r#"
+        function getX(point) {
+            return point.x;
+        }
+
+        let a = { x: 1, y: 2 };
+        let b = { y: 3, x: 4 };
+        let c = { x: 5, y: 6 };
+        let results = [getX(a), getX(b), getX(c)];
+
+        delete c.x;
+        results.push(getX(c));
+        c.x = 7;
+        results.push(getX(c));
+
+        Object.defineProperty(a, "x", { get() { return 8; } });
+        results.push(getX(a));
+        results.join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,4,5,,7,8\"");
+}
+
+#[test]
+fn property_write_after_shape_changes() {
+    let scenario = r#"
+        function setX(point, x) {
+            point.x = x;
+        }
+
+        let a = { x: 1 };
+        let b = { x: 2 };
+        setX(a, 3);
+        setX(b, 4);
+        Object.freeze(b);
+        setX(b, 5);
+
+        let proto = { set x(value) { this.y = value; } };
+        let c = Object.create(proto);
+        setX(c, 6);
+        [a.x, b.x, c.x, c.y].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"3,4,,6\"");
+}
+
+#[test]
+fn property_order_after_removal() {
+    let scenario = r#"
+        let object = { a: 1, b: 2, c: 3 };
+        delete object.b;
+        object.b = 4;
+        delete object.c;
+        object.d = 5;
+        Object.keys(object).join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a,b,d\"");
+}
+
+#[cfg(feature = "shapes")]
+#[test]
+fn objects_share_shapes() {
+    use crate::{forward_val, Context};
+
+    let mut context = Context::new();
+    let a = forward_val(&mut context, "({ x: 1, y: 2 })").unwrap();
+    let b = forward_val(&mut context, "({ x: 3, y: 4 })").unwrap();
+    let c = forward_val(&mut context, "({ y: 5, x: 6 })").unwrap();
+
+    let shape_id = |value: &crate::Value| {
+        value
+            .as_object()
+            .unwrap()
+            .borrow()
+            .string_properties
+            .shape()
+            .map(|shape| shape.id())
+    };
+    assert!(shape_id(&a).is_some());
+    assert_eq!(shape_id(&a), shape_id(&b));
+    assert_ne!(shape_id(&a), shape_id(&c));
+
+    a.remove_property("y");
+    b.remove_property("x");
+    assert!(shape_id(&a).is_some());
+    assert_eq!(shape_id(&b), None);
+}
+
+#[test]
+fn property_reads_and_writes() {
+    let scenario = r#"
+        let proto = { x: 1, get y() { return this.x * 10; } };
+        let object = Object.create(proto);
+        object.x = 2;
+        let string = new String("ab");
+        string[0] = "c";
+        [proto.x, object.x, object.y, "x" in object, string[0], 1 in string].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,20,true,a,true\"");
+}
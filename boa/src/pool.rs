@@ -0,0 +1,350 @@
+//! A pool of contexts running on dedicated threads.
+//!
+//! A [`Context`] and all of its values are bound to the thread that created it, because the
+//! garbage collected heap is per thread. A [`ContextPool`] owns its contexts on worker threads,
+//! and the pool itself can be shared between threads, so scripts can be evaluated from
+//! multi-threaded code such as async web servers. Only `Send` data crosses the threads: the
+//! tasks sent to the workers and their results.
+
+use crate::Context;
+use std::{
+    fmt,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{mpsc, Arc, Condvar, Mutex},
+    task::{Poll, Waker},
+    thread::{self, JoinHandle},
+};
+
+/// A task waiting to be run by one of the workers of a [`ContextPool`].
+type Task = Box<dyn FnOnce(&mut Context) + Send>;
+
+/// A pool of [`Context`]s, each owned by a dedicated worker thread.
+///
+/// Tasks are run by the first idle worker, in the order they were sent. Every worker keeps its
+/// context between tasks, so the globals defined by a task are visible to the later tasks run
+/// by the same worker, but not to the other workers.
+///
+/// A task which panics does not stop its worker: the worker replaces its context, which may have
+/// been left in an inconsistent state, with a new one and keeps running the following tasks.
+///
+/// # Examples
+///
+/// ```
+/// use boa::pool::ContextPool;
+///
+/// let pool = ContextPool::new(2);
+/// assert_eq!(pool.eval("1 + 1").wait(), Ok("2".to_string()));
+/// assert_eq!(
+///     pool.eval("throw 'oops'").wait(),
+///     Err("\"oops\"".to_string())
+/// );
+/// ```
+pub struct ContextPool {
+    sender: Option<Mutex<mpsc::Sender<Task>>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ContextPool {
+    /// Creates a pool of `size` workers, each with a default [`Context`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn new(size: usize) -> Self {
+        Self::with_init(size, Context::new)
+    }
+
+    /// Creates a pool of `size` workers, each with a [`Context`] created by `init`.
+    ///
+    /// `init` is called on the worker threads, so it can set up the contexts with a
+    /// [`ContextBuilder`](crate::context::ContextBuilder) or run setup code in them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn with_init<F>(size: usize, init: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        assert!(size > 0, "a context pool needs at least one worker");
+
+        let (sender, receiver) = mpsc::channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let init = Arc::new(init);
+        let workers = (0..size)
+            .map(|index| {
+                let receiver = receiver.clone();
+                let init = init.clone();
+                thread::Builder::new()
+                    .name(format!("boa-context-{}", index))
+                    .spawn(move || {
+                        let mut context = init();
+                        loop {
+                            // The lock is released before running the task.
+                            let task = receiver.lock().expect("poisoned receiver").recv();
+                            match task {
+                                Ok(task) => {
+                                    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+                                        task(&mut context)
+                                    }));
+                                    if run.is_err() {
+                                        context = init();
+                                    }
+                                }
+                                Err(_) => break,
+                            }
+                        }
+                    })
+                    .expect("could not spawn a context pool worker")
+            })
+            .collect();
+
+        Self {
+            sender: Some(Mutex::new(sender)),
+            workers,
+        }
+    }
+
+    /// Returns the number of workers of the pool.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Runs `task` with the context of one of the workers.
+    ///
+    /// The returned [`PoolFuture`] resolves to the result of the task. It can be awaited, or
+    /// waited for with [`PoolFuture::wait`].
+    pub fn run<F, T>(&self, task: F) -> PoolFuture<T>
+    where
+        F: FnOnce(&mut Context) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                result: None,
+                finished: false,
+                waker: None,
+            }),
+            finished: Condvar::new(),
+        });
+
+        let completion = Completion(shared.clone());
+        let job: Task = Box::new(move |context| completion.complete(task(context)));
+        // The workers only stop receiving when the pool is dropped.
+        let _ = self
+            .sender
+            .as_ref()
+            .expect("the pool has been dropped")
+            .lock()
+            .expect("poisoned sender")
+            .send(job);
+
+        PoolFuture(shared)
+    }
+
+    /// Evaluates `src` with the context of one of the workers.
+    ///
+    /// The result is the displayed value of the script, or the displayed error it threw.
+    pub fn eval<S>(&self, src: S) -> PoolFuture<Result<String, String>>
+    where
+        S: Into<String>,
+    {
+        let src = src.into();
+        self.run(move |context| match context.eval(src) {
+            Ok(value) => Ok(value.display().to_string()),
+            Err(error) => Err(error.to_string()),
+        })
+    }
+}
+
+impl Drop for ContextPool {
+    fn drop(&mut self) {
+        // Closing the channel stops the workers once the pending tasks have been run.
+        self.sender = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl fmt::Debug for ContextPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextPool")
+            .field("size", &self.size())
+            .finish()
+    }
+}
+
+/// The state shared by a [`PoolFuture`] and its task.
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    finished: Condvar,
+}
+
+struct State<T> {
+    result: Option<T>,
+    /// Set when the task has either completed or panicked.
+    finished: bool,
+    waker: Option<Waker>,
+}
+
+/// Completes a [`PoolFuture`], even if the worker panics while running the task.
+struct Completion<T>(Arc<Shared<T>>);
+
+impl<T> Completion<T> {
+    fn complete(self, result: T) {
+        self.0.state.lock().expect("poisoned state").result = Some(result);
+    }
+}
+
+impl<T> Drop for Completion<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = match self.0.state.lock() {
+                Ok(state) => state,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            state.finished = true;
+            state.waker.take()
+        };
+        self.0.finished.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// The result of a task run by a [`ContextPool`].
+///
+/// # Panics
+///
+/// Waiting for or polling the future panics if the task panicked.
+pub struct PoolFuture<T>(Arc<Shared<T>>);
+
+impl<T> PoolFuture<T> {
+    /// Blocks the current thread until the task has been run, and returns its result.
+    pub fn wait(self) -> T {
+        let mut state = self.0.state.lock().expect("poisoned state");
+        while !state.finished {
+            state = self.0.finished.wait(state).expect("poisoned state");
+        }
+        state.result.take().expect("the context pool task panicked")
+    }
+}
+
+impl<T> Future for PoolFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<T> {
+        let mut state = self.0.state.lock().expect("poisoned state");
+        if state.finished {
+            Poll::Ready(state.result.take().expect("the context pool task panicked"))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> fmt::Debug for PoolFuture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolFuture").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::Wake;
+
+    /// Wakes a thread blocked in `block_on`.
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// A minimal executor, running a future on the current thread.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = std::task::Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn eval_from_many_threads() {
+        let pool = Arc::new(ContextPool::new(3));
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let pool = pool.clone();
+                thread::spawn(move || {
+                    block_on(async {
+                        let sum = pool.eval(format!("[1, 2, 3].map(x => x * {}).join()", i));
+                        let error = pool.eval("null.property");
+                        (sum.await, error.await)
+                    })
+                })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            let (sum, error) = handle.join().expect("thread panicked");
+            assert_eq!(sum, Ok(format!("\"{},{},{}\"", i, 2 * i, 3 * i)));
+            assert!(error.unwrap_err().contains("TypeError"));
+        }
+    }
+
+    #[test]
+    fn workers_keep_their_context() {
+        let pool = ContextPool::with_init(1, || {
+            let mut context = Context::new();
+            context.eval("var calls = 0;").unwrap();
+            context
+        });
+
+        pool.eval("calls++").wait().unwrap();
+        pool.eval("calls++").wait().unwrap();
+        assert_eq!(pool.eval("calls").wait(), Ok("2".to_string()));
+        assert!(pool.run(|context| context.eval("calls").is_ok()).wait());
+    }
+
+    #[test]
+    fn panicking_task() {
+        let pool = ContextPool::new(1);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            pool.run(|_| panic!("task panicked")).wait()
+        }));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn worker_survives_a_panicking_task() {
+        let pool = ContextPool::new(1);
+        pool.eval("var kept = true;").wait().unwrap();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            pool.run(|_| panic!("task panicked")).wait()
+        }));
+        assert!(result.is_err());
+
+        // The worker runs the following tasks with a new context.
+        assert_eq!(pool.eval("1 + 1").wait(), Ok("2".to_string()));
+        assert_eq!(
+            pool.eval("typeof kept").wait(),
+            Ok("\"undefined\"".to_string())
+        );
+    }
+}
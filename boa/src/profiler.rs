@@ -1,19 +1,106 @@
+//! The profiler of boa.
+//!
+//! With the `profiler` feature, events are recorded with `measureme` into the `my_trace` files,
+//! and summed up in process, see [`BoaProfiler::summary`]. Without it, all the methods of the
+//! profiler are no-ops that compile to nothing.
+
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
 #[cfg(feature = "profiler")]
 use measureme::{EventId, Profiler, TimingGuard};
 #[cfg(feature = "profiler")]
 use once_cell::sync::OnceCell;
-use std::fmt::{self, Debug};
+#[cfg(feature = "profiler")]
+use rustc_hash::FxHashMap;
+use std::{
+    fmt::{self, Debug},
+    time::Duration,
+};
 #[cfg(feature = "profiler")]
 use std::{
     path::Path,
+    sync::Mutex,
     thread::{current, ThreadId},
+    time::Instant,
 };
 
+/// The categories of the events recorded by the profiler.
+pub mod category {
+    /// The lexing of tokens.
+    pub const LEXING: &str = "Lexing";
+    /// The parsing of the syntax tree, with an event per top-level statement of scripts.
+    pub const PARSING: &str = "Parsing";
+    /// The hoisting of the declarations of statement lists.
+    pub const HOISTING: &str = "Hoisting";
+    /// The calls to builtin functions, named after the `name` of the function object.
+    pub const BUILTIN_CALL: &str = "BuiltinCall";
+    /// The pauses of the garbage collector started by boa.
+    pub const GC: &str = "GC";
+}
+
+/// The total time spent in the events of a category and name, and their number.
+pub type EventSummary = (String, String, Duration, u64);
+
 #[cfg(feature = "profiler")]
 pub struct BoaProfiler {
     profiler: Profiler,
+    summary: Mutex<Summary>,
+}
+
+/// The events recorded so far, summed up by category and name.
+#[cfg(feature = "profiler")]
+#[derive(Default)]
+struct Summary {
+    /// The indices of the events in `events`, by category and name.
+    indices: FxHashMap<String, FxHashMap<String, usize>>,
+    events: Vec<EventSummary>,
+}
+
+#[cfg(feature = "profiler")]
+impl Summary {
+    /// Returns the index of the summary of the events of a category and name.
+    fn index(&mut self, category: &str, label: &str) -> usize {
+        if let Some(&index) = self
+            .indices
+            .get(category)
+            .and_then(|labels| labels.get(label))
+        {
+            return index;
+        }
+
+        let index = self.events.len();
+        self.events.push((
+            category.to_string(),
+            label.to_string(),
+            Duration::default(),
+            0,
+        ));
+        self.indices
+            .entry(category.to_string())
+            .or_default()
+            .insert(label.to_string(), index);
+        index
+    }
+}
+
+/// Records an event until it is dropped.
+#[cfg(feature = "profiler")]
+pub struct EventGuard<'a> {
+    _timing: TimingGuard<'a>,
+    profiler: &'a BoaProfiler,
+    index: usize,
+    start: Instant,
+}
+
+#[cfg(feature = "profiler")]
+impl Drop for EventGuard<'_> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let mut summary = self.profiler.summary.lock().expect("poisoned summary");
+        let event = &mut summary.events[self.index];
+        event.2 += elapsed;
+        event.3 += 1;
+    }
 }
 
 /// This static instance should never be public, and its only access should be done through the `global()` and `drop()` methods
@@ -23,17 +110,43 @@ static mut INSTANCE: OnceCell<BoaProfiler> = OnceCell::new();
 
 #[cfg(feature = "profiler")]
 impl BoaProfiler {
-    pub fn start_event(&self, label: &str, category: &str) -> TimingGuard<'_> {
+    pub fn start_event(&self, label: &str, category: &str) -> EventGuard<'_> {
+        let index = self
+            .summary
+            .lock()
+            .expect("poisoned summary")
+            .index(category, label);
         let kind = self.profiler.alloc_string(category);
         let id = EventId::from_label(self.profiler.alloc_string(label));
         let thread_id = Self::thread_id_to_u32(current().id());
-        self.profiler
-            .start_recording_interval_event(kind, id, thread_id)
+        EventGuard {
+            _timing: self
+                .profiler
+                .start_recording_interval_event(kind, id, thread_id),
+            profiler: self,
+            index,
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the total time spent in the events recorded so far, and their number, by
+    /// category and name, in the order they were first recorded.
+    ///
+    /// The time of nested events is also counted in the events containing them.
+    pub fn summary(&self) -> Vec<EventSummary> {
+        self.summary
+            .lock()
+            .expect("poisoned summary")
+            .events
+            .clone()
     }
 
     pub fn default() -> BoaProfiler {
         let profiler = Profiler::new(Path::new("./my_trace")).unwrap();
-        BoaProfiler { profiler }
+        BoaProfiler {
+            profiler,
+            summary: Mutex::default(),
+        }
     }
 
     pub fn global() -> &'static BoaProfiler {
@@ -73,15 +186,52 @@ pub struct BoaProfiler;
 #[allow(clippy::unused_unit)]
 #[cfg(not(feature = "profiler"))]
 impl BoaProfiler {
+    #[inline(always)]
     pub fn start_event(&self, _label: &str, _category: &str) -> () {
         ()
     }
 
+    /// Returns nothing, as no events are recorded without the `profiler` feature.
+    #[inline(always)]
+    pub fn summary(&self) -> Vec<EventSummary> {
+        Vec::new()
+    }
+
+    #[inline(always)]
     pub fn drop(&self) {
         ()
     }
 
+    #[inline(always)]
     pub fn global() -> BoaProfiler {
         BoaProfiler
     }
 }
+
+#[cfg(all(test, feature = "profiler"))]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn summary() {
+        let mut context = Context::new();
+        context
+            .eval("var array = [3, 1]; array.push(2); array.join();")
+            .unwrap();
+
+        // The profiler is shared by the tests running in parallel.
+        let summary = BoaProfiler::global().summary();
+        let count = |category: &str, name: &str| {
+            summary
+                .iter()
+                .find(|event| event.0 == category && event.1 == name)
+                .map_or(0, |event| event.3)
+        };
+        assert!(count(category::LEXING, "Identifier") >= 1);
+        assert!(count(category::PARSING, "top-level statement") >= 3);
+        assert!(count(category::HOISTING, "StatementList") >= 1);
+        assert!(count(category::BUILTIN_CALL, "push") >= 1);
+        assert!(count(category::BUILTIN_CALL, "join") >= 1);
+    }
+}
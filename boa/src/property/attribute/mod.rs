@@ -48,6 +48,29 @@ unsafe impl Trace for Attribute {
 }
 
 impl Attribute {
+    /// The attributes of the builtin methods, and of the other data properties of the builtins
+    /// unless the specification says otherwise: writable and configurable, but not enumerable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ecmascript-standard-built-in-objects
+    pub const BUILTIN: Self = Self {
+        bits: Self::WRITABLE.bits | Self::CONFIGURABLE.bits,
+    };
+
+    /// The attributes of the `name` and `length` properties of the builtin functions, and of the
+    /// `@@toStringTag` properties of the builtins: configurable, but neither writable nor
+    /// enumerable.
+    pub const BUILTIN_READONLY: Self = Self {
+        bits: Self::CONFIGURABLE.bits,
+    };
+
+    /// The attributes of the constants, like `Math.PI` or `Number.MAX_SAFE_INTEGER`, and of the
+    /// `prototype` property of the builtin constructors: neither writable, enumerable nor
+    /// configurable.
+    pub const CONSTANT: Self = Self { bits: 0 };
+
     /// Clear all flags.
     #[inline]
     pub fn clear(&mut self) {
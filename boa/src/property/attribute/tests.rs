@@ -135,3 +135,19 @@ fn set_configurable_to_false() {
     assert!(!attribute.enumerable());
     assert!(!attribute.configurable());
 }
+
+#[test]
+fn builtin_combinations() {
+    assert!(Attribute::BUILTIN.writable());
+    assert!(!Attribute::BUILTIN.enumerable());
+    assert!(Attribute::BUILTIN.configurable());
+
+    assert!(!Attribute::BUILTIN_READONLY.writable());
+    assert!(!Attribute::BUILTIN_READONLY.enumerable());
+    assert!(Attribute::BUILTIN_READONLY.configurable());
+
+    assert_eq!(
+        Attribute::CONSTANT,
+        Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT
+    );
+}
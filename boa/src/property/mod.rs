@@ -16,8 +16,9 @@
 
 use crate::{
     gc::{Finalize, Trace},
-    object::GcObject,
-    value::{RcString, RcSymbol, Value},
+    object::{GcObject, ObjectInitializer},
+    value::{PreferredType, RcString, RcSymbol, Value},
+    Context, Result,
 };
 use std::{convert::TryFrom, fmt};
 
@@ -294,6 +295,324 @@ impl PropertyDescriptor {
             Self::Data(ref data) => data.attributes(),
         }
     }
+
+    /// The abstract operation `FromPropertyDescriptor`.
+    ///
+    /// Creates the ordinary object that `Object.getOwnPropertyDescriptor` returns for this
+    /// descriptor: `value` and `writable` for data descriptors, `get` and `set` for accessor
+    /// descriptors, followed by `enumerable` and `configurable`.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
+    pub fn to_object(&self, context: &mut Context) -> Value {
+        let mut object = ObjectInitializer::new(context);
+
+        match self {
+            Self::Data(ref data) => {
+                object
+                    .property("value", data.value(), Attribute::all())
+                    .property("writable", data.writable(), Attribute::all());
+            }
+            Self::Accessor(ref accessor) => {
+                let get = accessor
+                    .getter()
+                    .cloned()
+                    .map_or_else(Value::undefined, Value::from);
+                let set = accessor
+                    .setter()
+                    .cloned()
+                    .map_or_else(Value::undefined, Value::from);
+                object.property("get", get, Attribute::all()).property(
+                    "set",
+                    set,
+                    Attribute::all(),
+                );
+            }
+        }
+
+        object
+            .property("enumerable", self.enumerable(), Attribute::all())
+            .property("configurable", self.configurable(), Attribute::all());
+
+        object.build().into()
+    }
+}
+
+/// A property descriptor in which every field may be absent.
+///
+/// This is what `Object.defineProperty` and `Object.defineProperties` receive: only the
+/// fields present on the descriptor object are changed, everything else is taken from the
+/// existing property (or defaulted if there is none) when the descriptor is applied.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-property-descriptor-specification-type
+#[derive(Debug, Clone, Default)]
+pub struct PartialDescriptor {
+    pub(crate) value: Option<Value>,
+    pub(crate) writable: Option<bool>,
+    pub(crate) get: Option<Option<GcObject>>,
+    pub(crate) set: Option<Option<GcObject>>,
+    pub(crate) enumerable: Option<bool>,
+    pub(crate) configurable: Option<bool>,
+}
+
+impl PartialDescriptor {
+    /// Create an empty (generic) `PartialDescriptor`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `[[Value]]` field.
+    #[inline]
+    pub fn value<V>(mut self, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Set the `[[Writable]]` field.
+    #[inline]
+    pub fn writable(mut self, writable: bool) -> Self {
+        self.writable = Some(writable);
+        self
+    }
+
+    /// Set the `[[Get]]` field, `None` meaning `undefined`.
+    #[inline]
+    pub fn getter(mut self, get: Option<GcObject>) -> Self {
+        self.get = Some(get);
+        self
+    }
+
+    /// Set the `[[Set]]` field, `None` meaning `undefined`.
+    #[inline]
+    pub fn setter(mut self, set: Option<GcObject>) -> Self {
+        self.set = Some(set);
+        self
+    }
+
+    /// Set the `[[Enumerable]]` field.
+    #[inline]
+    pub fn enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = Some(enumerable);
+        self
+    }
+
+    /// Set the `[[Configurable]]` field.
+    #[inline]
+    pub fn configurable(mut self, configurable: bool) -> Self {
+        self.configurable = Some(configurable);
+        self
+    }
+
+    /// Whether the descriptor has a `[[Get]]` or `[[Set]]` field.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isaccessordescriptor
+    #[inline]
+    pub fn is_accessor_descriptor(&self) -> bool {
+        self.get.is_some() || self.set.is_some()
+    }
+
+    /// Whether the descriptor has a `[[Value]]` or `[[Writable]]` field.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isdatadescriptor
+    #[inline]
+    pub fn is_data_descriptor(&self) -> bool {
+        self.value.is_some() || self.writable.is_some()
+    }
+
+    /// Whether the descriptor is neither a data nor an accessor descriptor.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isgenericdescriptor
+    #[inline]
+    pub fn is_generic_descriptor(&self) -> bool {
+        !self.is_accessor_descriptor() && !self.is_data_descriptor()
+    }
+
+    /// Whether every field of the descriptor is absent.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.is_generic_descriptor() && self.enumerable.is_none() && self.configurable.is_none()
+    }
+
+    /// The abstract operation `ToPropertyDescriptor`.
+    ///
+    /// Reads the descriptor fields present on `object`, throwing a `TypeError` if a getter or
+    /// setter is not callable, or if both accessor and data fields are present.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-topropertydescriptor
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    pub fn from_object(object: &GcObject, context: &mut Context) -> Result<Self> {
+        let mut desc = Self::new();
+
+        let enumerable_key = PropertyKey::from("enumerable");
+        if object.has_property(&enumerable_key) {
+            let enumerable = object
+                .get(&enumerable_key, object.clone().into(), context)?
+                .to_boolean();
+            desc = desc.enumerable(enumerable);
+        }
+
+        let configurable_key = PropertyKey::from("configurable");
+        if object.has_property(&configurable_key) {
+            let configurable = object
+                .get(&configurable_key, object.clone().into(), context)?
+                .to_boolean();
+            desc = desc.configurable(configurable);
+        }
+
+        let value_key = PropertyKey::from("value");
+        if object.has_property(&value_key) {
+            desc = desc.value(object.get(&value_key, object.clone().into(), context)?);
+        }
+
+        let writable_key = PropertyKey::from("writable");
+        if object.has_property(&writable_key) {
+            let writable = object
+                .get(&writable_key, object.clone().into(), context)?
+                .to_boolean();
+            desc = desc.writable(writable);
+        }
+
+        let get_key = PropertyKey::from("get");
+        if object.has_property(&get_key) {
+            let getter = object.get(&get_key, object.clone().into(), context)?;
+            match getter {
+                Value::Object(ref object) if object.is_callable() => {
+                    desc = desc.getter(Some(object.clone()));
+                }
+                Value::Undefined => desc = desc.getter(None),
+                _ => {
+                    return Err(
+                        context.construct_type_error("Property descriptor getter must be callable")
+                    );
+                }
+            }
+        }
+
+        let set_key = PropertyKey::from("set");
+        if object.has_property(&set_key) {
+            let setter = object.get(&set_key, object.clone().into(), context)?;
+            match setter {
+                Value::Object(ref object) if object.is_callable() => {
+                    desc = desc.setter(Some(object.clone()));
+                }
+                Value::Undefined => desc = desc.setter(None),
+                _ => {
+                    return Err(
+                        context.construct_type_error("Property descriptor setter must be callable")
+                    );
+                }
+            };
+        }
+
+        if desc.is_accessor_descriptor() && desc.is_data_descriptor() {
+            return Err(context.construct_type_error("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute"));
+        }
+
+        Ok(desc)
+    }
+
+    /// Build the complete descriptor that results from applying this one on top of `current`.
+    ///
+    /// Absent fields are taken from `current` when it is of the same kind, and otherwise get
+    /// their default value as in [CompletePropertyDescriptor][spec]. A generic descriptor keeps
+    /// the kind of `current`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-completepropertydescriptor
+    pub fn apply(self, current: Option<&PropertyDescriptor>) -> PropertyDescriptor {
+        let mut attributes = Attribute::empty();
+        attributes.set_enumerable(
+            self.enumerable
+                .or_else(|| current.map(PropertyDescriptor::enumerable))
+                .unwrap_or(false),
+        );
+        attributes.set_configurable(
+            self.configurable
+                .or_else(|| current.map(PropertyDescriptor::configurable))
+                .unwrap_or(false),
+        );
+
+        let current_accessor = current.and_then(PropertyDescriptor::as_accessor_descriptor);
+        let is_accessor = self.is_accessor_descriptor()
+            || (self.is_generic_descriptor() && current_accessor.is_some());
+
+        if is_accessor {
+            let get = self
+                .get
+                .unwrap_or_else(|| current_accessor.and_then(|c| c.getter().cloned()));
+            let set = self
+                .set
+                .unwrap_or_else(|| current_accessor.and_then(|c| c.setter().cloned()));
+            AccessorDescriptor::new(get, set, attributes).into()
+        } else {
+            let current_data = current.and_then(PropertyDescriptor::as_data_descriptor);
+            attributes.set_writable(
+                self.writable
+                    .or_else(|| current_data.map(DataDescriptor::writable))
+                    .unwrap_or(false),
+            );
+            let value = self
+                .value
+                .or_else(|| current_data.map(DataDescriptor::value))
+                .unwrap_or_else(Value::undefined);
+            DataDescriptor::new(value, attributes).into()
+        }
+    }
+}
+
+impl From<DataDescriptor> for PartialDescriptor {
+    #[inline]
+    fn from(value: DataDescriptor) -> Self {
+        Self::new()
+            .value(value.value())
+            .writable(value.writable())
+            .enumerable(value.enumerable())
+            .configurable(value.configurable())
+    }
+}
+
+impl From<AccessorDescriptor> for PartialDescriptor {
+    #[inline]
+    fn from(value: AccessorDescriptor) -> Self {
+        Self::new()
+            .enumerable(value.enumerable())
+            .configurable(value.configurable())
+            .getter(value.get)
+            .setter(value.set)
+    }
+}
+
+impl From<PropertyDescriptor> for PartialDescriptor {
+    #[inline]
+    fn from(value: PropertyDescriptor) -> Self {
+        match value {
+            PropertyDescriptor::Accessor(accessor) => accessor.into(),
+            PropertyDescriptor::Data(data) => data.into(),
+        }
+    }
 }
 
 /// This abstracts away the need for IsPropertyKey by transforming the PropertyKey
@@ -310,10 +629,45 @@ pub enum PropertyKey {
     Index(u32),
 }
 
+impl PropertyKey {
+    /// Converts a value to a property key.
+    ///
+    /// Symbols stay symbols, the other values are converted to a primitive, preferring a string,
+    /// and then to a string. Strings that are canonical integers become indices, so `obj[1]` and
+    /// `obj["1"]` are the same property, but `obj["01"]` is another one.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-topropertykey
+    pub fn from_value(value: &Value, context: &mut Context) -> Result<Self> {
+        Ok(match value {
+            // Fast path:
+            Value::String(string) => string.clone().into(),
+            Value::Symbol(symbol) => symbol.clone().into(),
+            // Slow path:
+            _ => match value.to_primitive(context, PreferredType::String)? {
+                Value::String(ref string) => string.clone().into(),
+                Value::Symbol(ref symbol) => symbol.clone().into(),
+                primitive => primitive.to_string(context)?.into(),
+            },
+        })
+    }
+}
+
+/// Parses a string which is the canonical form of an index, like `"1"` but not `"01"` or `"+1"`.
+#[inline]
+fn parse_index(string: &str) -> Option<u32> {
+    if string.starts_with('+') || (string.len() > 1 && string.starts_with('0')) {
+        return None;
+    }
+    string.parse().ok()
+}
+
 impl From<RcString> for PropertyKey {
     #[inline]
     fn from(string: RcString) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string)
@@ -324,7 +678,7 @@ impl From<RcString> for PropertyKey {
 impl From<&str> for PropertyKey {
     #[inline]
     fn from(string: &str) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -335,7 +689,7 @@ impl From<&str> for PropertyKey {
 impl From<String> for PropertyKey {
     #[inline]
     fn from(string: String) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -346,7 +700,7 @@ impl From<String> for PropertyKey {
 impl From<Box<str>> for PropertyKey {
     #[inline]
     fn from(string: Box<str>) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -457,8 +811,11 @@ impl From<i32> for PropertyKey {
 impl From<f64> for PropertyKey {
     fn from(value: f64) -> Self {
         use num_traits::cast::FromPrimitive;
-        if let Some(index) = u32::from_f64(value) {
-            return PropertyKey::Index(index);
+        // Only integers are indices, `from_f64` would truncate the others.
+        if value.fract() == 0.0 {
+            if let Some(index) = u32::from_f64(value) {
+                return PropertyKey::Index(index);
+            }
         }
 
         PropertyKey::String(ryu_js::Buffer::new().format(value).into())
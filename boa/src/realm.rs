@@ -7,15 +7,16 @@
 use crate::object::{GcObject, Object, ObjectData};
 use crate::{
     environment::{
-        declarative_environment_record::DeclarativeEnvironmentRecord,
+        declarative_environment_record::{Bindings, DeclarativeEnvironmentRecord},
         global_environment_record::GlobalEnvironmentRecord,
         lexical_environment::LexicalEnvironment,
         object_environment_record::ObjectEnvironmentRecord,
     },
+    snapshot::HeapCopier,
     BoaProfiler, Value,
 };
 use gc::{Gc, GcCell};
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashSet;
 
 /// Representation of a Realm.
 ///
@@ -49,6 +50,15 @@ impl Realm {
             environment: LexicalEnvironment::new(gc_global.into()),
         }
     }
+
+    /// Copies the realm for a [`Snapshot`](crate::snapshot::Snapshot).
+    pub(crate) fn copy_with(&self, copier: &mut HeapCopier) -> Self {
+        Self {
+            global_object: copier.copy_object(&self.global_object),
+            global_env: Gc::new(GcCell::new(self.global_env.borrow().copy_with(copier))),
+            environment: self.environment.copy_with(copier),
+        }
+    }
 }
 
 // Similar to new_global_environment in lexical_environment, except we need to return a GlobalEnvirionment
@@ -68,7 +78,7 @@ fn new_global_environment(
     };
 
     let dcl_rec = DeclarativeEnvironmentRecord {
-        env_rec: FxHashMap::default(),
+        env_rec: Bindings::default(),
         outer_env: None,
     };
 
@@ -0,0 +1,142 @@
+//! Snapshots of the state of a [`Context`].
+//!
+//! Building a [`Context`] creates all the intrinsic objects, and embedders usually run some setup
+//! code on top of them. A [`Snapshot`] captures this state once, so that many isolated contexts
+//! can be created from it by copying the objects instead of initializing them again. Strings,
+//! symbols and the parsed code of functions are immutable, so they are shared, not copied.
+
+use crate::{
+    environment::{
+        declarative_environment_record::{Bindings, DeclarativeEnvironmentRecordBinding},
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::Environment,
+    },
+    gc::WeakGcObject,
+    object::{GcObject, Object},
+    property::{AccessorDescriptor, DataDescriptor, PropertyDescriptor},
+    Context, Value,
+};
+use gc::{Gc, GcCell};
+use rustc_hash::FxHashMap;
+
+/// The state of a [`Context`] that new contexts can be created from.
+///
+/// Snapshots are taken with [`Context::snapshot`], and contexts are created from them with
+/// [`Context::from_snapshot`].
+#[derive(Debug)]
+pub struct Snapshot {
+    pub(crate) context: Context,
+}
+
+/// Copies a graph of objects and environments, preserving the references between them.
+///
+/// Every object and environment is copied at most once, so shared references and cycles are
+/// copied faithfully.
+#[derive(Debug, Default)]
+pub struct HeapCopier {
+    objects: FxHashMap<*const GcCell<Object>, GcObject>,
+    environments: FxHashMap<*const GcCell<Box<dyn EnvironmentRecordTrait>>, Environment>,
+    /// The objects whose contents have not been copied yet, with their copies.
+    pending: Vec<(GcObject, GcObject)>,
+}
+
+impl HeapCopier {
+    /// Creates a new `HeapCopier`.
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the copy of `value`.
+    ///
+    /// Primitive values are immutable, so they are not copied.
+    #[inline]
+    pub fn copy_value(&mut self, value: &Value) -> Value {
+        match value {
+            Value::Object(object) => self.copy_object(object).into(),
+            _ => value.clone(),
+        }
+    }
+
+    /// Returns the copy of `object`.
+    ///
+    /// The copy is empty until its contents are copied by [`finish`](Self::finish), so that
+    /// long chains of objects do not have to be copied recursively.
+    pub fn copy_object(&mut self, object: &GcObject) -> GcObject {
+        let address = object.as_ref() as *const GcCell<Object>;
+        if let Some(copy) = self.objects.get(&address) {
+            return copy.clone();
+        }
+
+        let copy = GcObject::new(Object::default());
+        self.objects.insert(address, copy.clone());
+        self.pending.push((object.clone(), copy.clone()));
+        copy
+    }
+
+    /// Returns a weak reference to the copy of the target of `weak`.
+    pub fn copy_weak(&mut self, weak: &WeakGcObject) -> WeakGcObject {
+        weak.upgrade()
+            .map_or_else(WeakGcObject::collected, |target| {
+                WeakGcObject::new(&self.copy_object(&target))
+            })
+    }
+
+    /// Returns the copy of `environment`.
+    pub fn copy_environment(&mut self, environment: &Environment) -> Environment {
+        let address = &**environment as *const GcCell<Box<dyn EnvironmentRecordTrait>>;
+        if let Some(copy) = self.environments.get(&address) {
+            return copy.clone();
+        }
+
+        let record = environment.borrow().copy_record(self);
+        let copy = Gc::new(GcCell::new(record));
+        self.environments.insert(address, copy.clone());
+        copy
+    }
+
+    /// Returns the copy of `property`.
+    pub fn copy_property(&mut self, property: &PropertyDescriptor) -> PropertyDescriptor {
+        match property {
+            PropertyDescriptor::Data(data) => {
+                DataDescriptor::new(self.copy_value(&data.value()), data.attributes()).into()
+            }
+            PropertyDescriptor::Accessor(accessor) => AccessorDescriptor::new(
+                accessor.getter().map(|get| self.copy_object(get)),
+                accessor.setter().map(|set| self.copy_object(set)),
+                accessor.attributes(),
+            )
+            .into(),
+        }
+    }
+
+    /// Returns the copy of the bindings of a declarative environment.
+    pub(crate) fn copy_bindings(&mut self, bindings: &Bindings) -> Bindings {
+        bindings
+            .iter()
+            .map(|(name, binding)| {
+                let binding = DeclarativeEnvironmentRecordBinding {
+                    value: binding.value.as_ref().map(|value| self.copy_value(value)),
+                    ..binding.clone()
+                };
+                (name.clone(), binding)
+            })
+            .collect()
+    }
+
+    /// Copies the contents of all the objects copied so far.
+    ///
+    /// Returns `false` if some of them could not be copied, like the native objects of the
+    /// embedder, which are left empty.
+    pub(crate) fn finish(&mut self) -> bool {
+        let mut copied = true;
+        while let Some((object, copy)) = self.pending.pop() {
+            let contents = object.borrow().copy_with(self);
+            match contents {
+                Some(contents) => *copy.borrow_mut() = contents,
+                None => copied = false,
+            }
+        }
+        copied
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     builtins::{iterable, Array},
     exec::Executable,
     gc::{Finalize, Trace},
+    property::{Attribute, DataDescriptor},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -39,29 +40,35 @@ impl Executable for ArrayDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
         let array = Array::new_array(context)?;
-        let mut elements = Vec::new();
+        let mut index: usize = 0;
         for elem in self.as_ref() {
-            if let Node::Spread(ref x) = elem {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                // TODO after proper internal Array representation as per https://github.com/boa-dev/boa/pull/811#discussion_r502460858
-                // next_index variable should be utilized here as per https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation
-                // let mut next_index = 0;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
+            match elem {
+                Node::Spread(ref x) => {
+                    let val = x.run(context)?;
+                    let iterator_record = iterable::get_iterator(context, val)?;
+                    loop {
+                        let next = iterator_record.next(context)?;
+                        if next.is_done() {
+                            break;
+                        }
+                        array.set_property(
+                            index,
+                            DataDescriptor::new(next.value(), Attribute::all()),
+                        );
+                        index += 1;
                     }
-                    let next_value = next.value();
-                    //next_index += 1;
-                    elements.push(next_value.clone());
                 }
-            } else {
-                elements.push(elem.run(context)?);
+                // Elisions only count in the length, leaving a hole.
+                Node::Empty => index += 1,
+                _ => {
+                    let value = elem.run(context)?;
+                    array.set_property(index, DataDescriptor::new(value, Attribute::all()));
+                    index += 1;
+                }
             }
         }
 
-        Array::add_to_array_object(&array, &elements, context)?;
+        array.set_field("length", index, context)?;
         Ok(array)
     }
 }
@@ -85,6 +92,10 @@ impl fmt::Display for ArrayDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("[")?;
         join_nodes(f, &self.arr)?;
+        // A trailing elision needs its own comma, since the last comma of a list is ignored.
+        if let Some(Node::Empty) = self.arr.last() {
+            f.write_str(",")?;
+        }
         f.write_str("]")
     }
 }
@@ -3,8 +3,7 @@
 use super::{Node, StatementList};
 use crate::{
     environment::lexical_environment::new_declarative_environment,
-    exec::Executable,
-    exec::InterpreterState,
+    exec::{Completion, Executable},
     gc::{Finalize, Trace},
     BoaProfiler, Context, Result, Value,
 };
@@ -38,7 +37,7 @@ pub struct Block {
 
 impl Block {
     /// Gets the list of statements and declarations in this block.
-    pub(crate) fn items(&self) -> &[Node] {
+    pub fn items(&self) -> &[Node] {
         self.statements.items()
     }
 
@@ -52,45 +51,21 @@ impl Block {
 
 impl Executable for Block {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
-            let env = &mut context.realm_mut().environment;
-            env.push(new_declarative_environment(Some(
-                env.get_current_environment_ref().clone(),
-            )));
-        }
-
-        // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
-        // The return value is uninitialized, which means it defaults to Value::Undefined
-        let mut obj = Value::default();
-        for statement in self.items() {
-            obj = statement.run(context)?;
-
-            match context.executor().get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
+        self.run_statement(context).map(Completion::into_value)
+    }
 
-                    // Early break.
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    // TODO, continue to a label
-                    break;
-                }
-                InterpreterState::Executing => {
-                    // Continue execution
-                }
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
+        let _timer = BoaProfiler::global().start_event("Block", "exec");
+        context.run_in_environment_scope(|context| {
+            {
+                let env = &mut context.realm_mut().environment;
+                env.push(new_declarative_environment(Some(
+                    env.get_current_environment_ref().clone(),
+                )));
             }
-        }
-
-        // pop the block env
-        let _ = context.realm_mut().environment.pop();
 
-        Ok(obj)
+            self.statements.run_statement(context)
+        })
     }
 }
 
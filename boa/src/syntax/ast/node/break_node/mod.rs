@@ -1,7 +1,6 @@
 use super::Node;
 use crate::{
-    exec::Executable,
-    exec::InterpreterState,
+    exec::{Completion, CompletionType, Executable},
     gc::{Finalize, Trace},
     Context, Result, Value,
 };
@@ -53,11 +52,14 @@ impl Break {
 
 impl Executable for Break {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        context
-            .executor()
-            .set_current_state(InterpreterState::Break(self.label().map(Box::from)));
+        self.run_statement(context).map(Completion::into_value)
+    }
 
-        Ok(Value::undefined())
+    fn run_statement(&self, _: &mut Context) -> Result<Completion> {
+        Ok(Completion::new(
+            CompletionType::Break(self.label().map(Box::from)),
+            None,
+        ))
     }
 }
 
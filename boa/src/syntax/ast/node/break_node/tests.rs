@@ -1,5 +1,5 @@
 use crate::{
-    exec::{Executable, InterpreterState},
+    exec::{CompletionType, Executable},
     syntax::ast::node::Break,
     Context,
 };
@@ -10,10 +10,10 @@ fn check_post_state() {
 
     let brk: Break = Break::new("label");
 
-    brk.run(&mut context).unwrap();
+    let completion = brk.run_statement(&mut context).unwrap();
 
     assert_eq!(
-        context.executor().get_current_state(),
-        &InterpreterState::Break(Some("label".into()))
+        completion.kind(),
+        &CompletionType::Break(Some("label".into()))
     );
 }
@@ -1,10 +1,9 @@
 use crate::{
     builtins::iterable,
-    exec::Executable,
-    exec::InterpreterState,
+    exec::{Executable, Reference},
     gc::{Finalize, Trace},
     syntax::ast::node::{join_nodes, Node},
-    value::{Type, Value},
+    value::Value,
     BoaProfiler, Context, Result,
 };
 use std::fmt;
@@ -61,32 +60,15 @@ impl Executable for Call {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Call", "exec");
         let (this, func) = match self.expr() {
-            Node::GetConstField(ref get_const_field) => {
-                let mut obj = get_const_field.obj().run(context)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(context)?);
-                }
-                (
-                    obj.clone(),
-                    obj.get_field(get_const_field.field(), context)?,
-                )
-            }
-            Node::GetField(ref get_field) => {
-                let mut obj = get_field.obj().run(context)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(context)?);
-                }
-                let field = get_field.field().run(context)?;
-                (
-                    obj.clone(),
-                    obj.get_field(field.to_property_key(context)?, context)?,
-                )
+            // Method calls use the base of the reference as `this`, or the current `this` for
+            // `super` properties.
+            Node::GetConstField(_) | Node::GetField(_) => {
+                let reference = Reference::resolve(self.expr(), context)?;
+                (reference.this_value(), reference.get_value(context)?)
             }
-            _ => (
-                // 'this' binding should come from the function's self-contained environment
-                context.global_object().clone().into(),
-                self.expr().run(context)?,
-            ),
+            // Calls through an environment reference have no base, so `this` is undefined.
+            // Sloppy mode functions replace it with the global object when they are called.
+            _ => (Value::undefined(), self.expr().run(context)?),
         };
         let mut v_args = Vec::with_capacity(self.args().len());
         for arg in self.args() {
@@ -108,14 +90,7 @@ impl Executable for Call {
         }
 
         // execute the function call itself
-        let fnct_result = context.call(&func, &this, &v_args);
-
-        // unset the early return flag
-        context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
-
-        fnct_result
+        context.call(&func, &this, &v_args)
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::{
-    exec::Executable,
+    exec::{Completion, Executable},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::StatementLocation, Node},
     Context, Result, Value,
 };
 use std::fmt;
@@ -31,6 +31,12 @@ pub struct If {
     cond: Box<Node>,
     body: Box<Node>,
     else_node: Option<Box<Node>>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    body_location: StatementLocation,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    else_location: StatementLocation,
 }
 
 impl If {
@@ -58,9 +64,21 @@ impl If {
             cond: Box::new(condition.into()),
             body: Box::new(body.into()),
             else_node: else_node.into().map(E::into).map(Box::new),
+            body_location: StatementLocation::default(),
+            else_location: StatementLocation::default(),
         }
     }
 
+    /// Sets the locations of the body and of the `else` statement, if they are not blocks.
+    pub(in crate::syntax) fn set_locations(
+        &mut self,
+        body_location: StatementLocation,
+        else_location: StatementLocation,
+    ) {
+        self.body_location = body_location;
+        self.else_location = else_location;
+    }
+
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
@@ -80,13 +98,20 @@ impl If {
 
 impl Executable for If {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(context)?.to_boolean() {
-            self.body().run(context)?
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
+        let completion = if self.cond().run(context)?.to_boolean() {
+            self.body_location.run_body(self.body(), context)?
         } else if let Some(ref else_e) = self.else_node() {
-            else_e.run(context)?
+            self.else_location.run_body(else_e, context)?
         } else {
-            Value::undefined()
-        })
+            Completion::empty()
+        };
+
+        // https://tc39.es/ecma262/#sec-if-statement-runtime-semantics-evaluation
+        Ok(completion.update_empty(Value::undefined()))
     }
 }
 
@@ -2,7 +2,10 @@ use crate::{
     builtins::function::FunctionFlags,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, StatementList},
+        Span,
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -24,15 +27,18 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-ArrowFunction
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct ArrowFunctionDecl {
     params: Box<[FormalParameter]>,
     body: StatementList,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    span: Option<Span>,
 }
 
 impl ArrowFunctionDecl {
     /// Creates a new `ArrowFunctionDecl` AST node.
-    pub(in crate::syntax) fn new<P, B>(params: P, body: B) -> Self
+    pub fn new<P, B>(params: P, body: B) -> Self
     where
         P: Into<Box<[FormalParameter]>>,
         B: Into<StatementList>,
@@ -40,19 +46,31 @@ impl ArrowFunctionDecl {
         Self {
             params: params.into(),
             body: body.into(),
+            span: None,
         }
     }
 
     /// Gets the list of parameters of the arrow function.
-    pub(crate) fn params(&self) -> &[FormalParameter] {
+    pub fn params(&self) -> &[FormalParameter] {
         &self.params
     }
 
     /// Gets the body of the arrow function.
-    pub(crate) fn body(&self) -> &[Node] {
+    pub fn body(&self) -> &[Node] {
         &self.body.items()
     }
 
+    /// Gets the span of the source code of the arrow function, if it was parsed.
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Sets the span of the source code of the arrow function.
+    pub(in crate::syntax) fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -66,15 +84,35 @@ impl ArrowFunctionDecl {
     }
 }
 
-impl Executable for ArrowFunctionDecl {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        Ok(context.create_function(
+impl ArrowFunctionDecl {
+    /// Creates the function object, named `name`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-instantiatearrowfunctionexpression
+    pub(crate) fn instantiate(&self, name: &str, context: &mut Context) -> Result<Value> {
+        context.create_function(
+            name,
             self.params().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
+            self.span,
             FunctionFlags::CALLABLE
                 | FunctionFlags::CONSTRUCTABLE
                 | FunctionFlags::LEXICAL_THIS_MODE,
-        )?)
+        )
+    }
+}
+
+impl PartialEq for ArrowFunctionDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.params == other.params && self.body == other.body
+    }
+}
+
+impl Executable for ArrowFunctionDecl {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        self.instantiate("", context)
     }
 }
 
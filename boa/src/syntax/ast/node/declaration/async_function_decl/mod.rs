@@ -29,7 +29,7 @@ pub struct AsyncFunctionDecl {
 
 impl AsyncFunctionDecl {
     /// Creates a new async function declaration.
-    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
         N: Into<Option<Box<str>>>,
         P: Into<Box<[FormalParameter]>>,
@@ -72,7 +72,7 @@ impl AsyncFunctionDecl {
             }
         }
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
@@ -30,7 +30,7 @@ pub struct AsyncFunctionExpr {
 
 impl AsyncFunctionExpr {
     /// Creates a new function expression
-    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
         N: Into<Option<Box<str>>>,
         P: Into<Box<[FormalParameter]>>,
@@ -70,7 +70,7 @@ impl AsyncFunctionExpr {
         }
         f.write_str("(")?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
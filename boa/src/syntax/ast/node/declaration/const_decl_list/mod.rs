@@ -39,7 +39,7 @@ impl Executable for ConstDeclList {
     fn run(&self, context: &mut Context) -> Result<Value> {
         for decl in self.as_ref() {
             let val = if let Some(init) = decl.init() {
-                init.run(context)?
+                init.run_named(decl.name(), context)?
             } else {
                 return context.throw_syntax_error("missing = in const declaration");
             };
@@ -119,7 +119,7 @@ impl fmt::Display for ConstDecl {
 
 impl ConstDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: Option<I>) -> Self
+    pub fn new<N, I>(name: N, init: Option<I>) -> Self
     where
         N: Into<Identifier>,
         I: Into<Node>,
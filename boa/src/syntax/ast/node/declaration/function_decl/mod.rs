@@ -3,7 +3,10 @@ use crate::{
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, StatementList},
+        Span,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -30,16 +33,19 @@ use serde::{Deserialize, Serialize};
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function
 /// [func_expr]: ../enum.Node.html#variant.FunctionExpr
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct FunctionDecl {
     name: Box<str>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    span: Option<Span>,
 }
 
 impl FunctionDecl {
     /// Creates a new function declaration.
-    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
         N: Into<Box<str>>,
         P: Into<Box<[FormalParameter]>>,
@@ -49,6 +55,7 @@ impl FunctionDecl {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            span: None,
         }
     }
 
@@ -67,6 +74,17 @@ impl FunctionDecl {
         self.body.items()
     }
 
+    /// Gets the span of the source code of the function, if it was parsed.
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Sets the span of the source code of the function.
+    pub(in crate::syntax) fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -75,7 +93,7 @@ impl FunctionDecl {
     ) -> fmt::Result {
         write!(f, "function {}(", self.name)?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
@@ -83,18 +101,24 @@ impl FunctionDecl {
     }
 }
 
+impl PartialEq for FunctionDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.parameters == other.parameters && self.body == other.body
+    }
+}
+
 impl Executable for FunctionDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
         let val = context.create_function(
+            self.name(),
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
+            self.span,
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         )?;
 
-        // Set the name and assign it in the current environment
-        val.set_field("name", self.name(), context)?;
-
+        // Assign the function in the current environment
         let environment = &mut context.realm_mut().environment;
         if environment.has_binding(self.name()) {
             environment
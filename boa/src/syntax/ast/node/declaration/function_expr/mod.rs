@@ -2,7 +2,10 @@ use crate::{
     builtins::function::FunctionFlags,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::{
+        node::{join_nodes, FormalParameter, Node, StatementList},
+        Span,
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -27,16 +30,19 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-terms-and-definitions-function
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct FunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    span: Option<Span>,
 }
 
 impl FunctionExpr {
     /// Creates a new function expression
-    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    pub fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
         N: Into<Option<Box<str>>>,
         P: Into<Box<[FormalParameter]>>,
@@ -46,6 +52,7 @@ impl FunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            span: None,
         }
     }
 
@@ -64,6 +71,17 @@ impl FunctionExpr {
         self.body.items()
     }
 
+    /// Gets the span of the source code of the function, if it was parsed.
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Sets the span of the source code of the function.
+    pub(in crate::syntax) fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -76,27 +94,55 @@ impl FunctionExpr {
         }
         f.write_str("(")?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
         writeln!(f, "}}")
     }
+
+    /// Implements the display formatting of the function as the method `key` of an object.
+    pub(in crate::syntax::ast::node) fn display_method(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        key: &str,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "{}(", key)?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {\n")?;
+        self.body.display(f, indentation + 1)?;
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
 }
 
-impl Executable for FunctionExpr {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        let val = context.create_function(
+impl FunctionExpr {
+    /// Creates the function object, named `name` if the expression has no name of its own.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-instantiateordinaryfunctionexpression
+    pub(crate) fn instantiate(&self, name: &str, context: &mut Context) -> Result<Value> {
+        context.create_function(
+            self.name().unwrap_or(name),
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
+            self.span,
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
-        )?;
+        )
+    }
+}
 
-        if let Some(name) = self.name() {
-            val.set_field("name", Value::from(name), context)?;
-        }
+impl PartialEq for FunctionExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.parameters == other.parameters && self.body == other.body
+    }
+}
 
-        Ok(val)
+impl Executable for FunctionExpr {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        self.instantiate("", context)
     }
 }
 
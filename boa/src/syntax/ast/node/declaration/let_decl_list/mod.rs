@@ -38,7 +38,7 @@ impl Executable for LetDeclList {
     fn run(&self, context: &mut Context) -> Result<Value> {
         for var in self.as_ref() {
             let val = match var.init() {
-                Some(v) => v.run(context)?,
+                Some(v) => v.run_named(var.name(), context)?,
                 None => Value::undefined(),
             };
             context
@@ -116,7 +116,7 @@ impl fmt::Display for LetDecl {
 
 impl LetDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: I) -> Self
+    pub fn new<N, I>(name: N, init: I) -> Self
     where
         N: Into<Identifier>,
         I: Into<Option<Node>>,
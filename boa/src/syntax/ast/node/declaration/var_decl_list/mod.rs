@@ -39,7 +39,7 @@ impl Executable for VarDeclList {
     fn run(&self, context: &mut Context) -> Result<Value> {
         for var in self.as_ref() {
             let val = match var.init() {
-                Some(v) => v.run(context)?,
+                Some(v) => v.run_named(var.name(), context)?,
                 None => Value::undefined(),
             };
             let environment = &mut context.realm_mut().environment;
@@ -124,7 +124,7 @@ impl fmt::Display for VarDecl {
 
 impl VarDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: I) -> Self
+    pub fn new<N, I>(name: N, init: I) -> Self
     where
         N: Into<Identifier>,
         I: Into<Option<Node>>,
@@ -1,12 +1,15 @@
 use crate::{
-    exec::Executable,
+    exec::{Executable, Reference},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
-    value::{Type, Value},
+    value::Value,
     Context, Result,
 };
 use std::fmt;
 
+#[cfg(feature = "shapes")]
+use crate::object::shape::InlineCache;
+
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
@@ -36,6 +39,9 @@ use serde::{Deserialize, Serialize};
 pub struct GetConstField {
     obj: Box<Node>,
     field: Box<str>,
+    #[cfg(feature = "shapes")]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    cache: InlineCache,
 }
 
 impl GetConstField {
@@ -48,6 +54,8 @@ impl GetConstField {
         Self {
             obj: Box::new(value.into()),
             field: label.into(),
+            #[cfg(feature = "shapes")]
+            cache: InlineCache::default(),
         }
     }
 
@@ -60,16 +68,68 @@ impl GetConstField {
     pub fn field(&self) -> &str {
         &self.field
     }
+
+    /// Gets the field of `obj`.
+    #[cfg(not(feature = "shapes"))]
+    pub(crate) fn get_field_of(&self, obj: Value, context: &mut Context) -> Result<Value> {
+        obj.get_v(&self.field().into(), context)
+    }
+
+    /// Gets the field of `obj`, from the slot of the inline cache if `obj` has its shape.
+    #[cfg(feature = "shapes")]
+    pub(crate) fn get_field_of(&self, obj: Value, context: &mut Context) -> Result<Value> {
+        let object = match obj {
+            Value::Object(ref object) => object.clone(),
+            _ => return obj.get_v(&self.field().into(), context),
+        };
+
+        if let Some(value) = self.cache.get(&object) {
+            return Ok(value);
+        }
+        let value = object.get(&self.field().into(), obj, context)?;
+        self.cache.update(&object, self.field());
+        Ok(value)
+    }
+
+    /// Sets the field of `obj` to `value`.
+    #[cfg(not(feature = "shapes"))]
+    pub(crate) fn set_field_of(
+        &self,
+        obj: &Value,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        obj.set_field(self.field(), value, context)
+    }
+
+    /// Sets the field of `obj` to `value`, in the slot of the inline cache if `obj` has its shape.
+    #[cfg(feature = "shapes")]
+    pub(crate) fn set_field_of(
+        &self,
+        obj: &Value,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = match obj {
+            Value::Object(object) => object,
+            _ => return obj.set_field(self.field(), value, context),
+        };
+
+        if !self.cache.set(object, &value) {
+            obj.set_field(self.field(), value.clone(), context)?;
+            self.cache.update(object, self.field());
+        }
+        Ok(value)
+    }
 }
 
 impl Executable for GetConstField {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(context)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(context)?);
+        if let Node::Super = self.obj() {
+            return Reference::resolve_super(self.field().into(), context)?.get_value(context);
         }
-
-        Ok(obj.get_field(self.field(), context)?)
+        let obj = self.obj().run(context)?;
+        self.get_field_of(obj, context)
     }
 }
 
@@ -1,8 +1,8 @@
 use crate::{
-    exec::Executable,
+    exec::{Executable, Reference},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
-    value::{Type, Value},
+    value::Value,
     Context, Result,
 };
 use std::fmt;
@@ -63,13 +63,15 @@ impl GetField {
 
 impl Executable for GetField {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(context)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(context)?);
+        if let Node::Super = self.obj() {
+            let key = self.field().run(context)?.to_property_key(context)?;
+            return Reference::resolve_super(key, context)?.get_value(context);
         }
+        let obj = self.obj().run(context)?;
         let field = self.field().run(context)?;
+        let key = field.to_property_key(context)?;
 
-        Ok(obj.get_field(field.to_property_key(context)?, context)?)
+        obj.get_v(&key, context)
     }
 }
 
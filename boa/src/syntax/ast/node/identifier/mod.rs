@@ -1,12 +1,16 @@
 //! Local identifier node.
 
 use crate::{
+    environment::{
+        environment_record_trait::BindingValue,
+        lexical_environment::{BindingLocator, VariableScope},
+    },
     exec::Executable,
-    gc::{Finalize, Trace},
+    gc::{empty_trace, Finalize, Trace},
     syntax::ast::node::Node,
     Context, Result, Value,
 };
-use std::fmt;
+use std::{cell::Cell, fmt};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
@@ -29,18 +33,86 @@ use serde::{Deserialize, Serialize};
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Glossary/Identifier
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "deser", serde(transparent))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Finalize)]
 pub struct Identifier {
     ident: Box<str>,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    binding: Cell<Option<BindingLocator>>,
+}
+
+impl Identifier {
+    /// Gets the location of the binding of this identifier, if it was resolved after parsing.
+    pub fn binding(&self) -> Option<BindingLocator> {
+        self.binding.get()
+    }
+
+    /// Sets the location of the binding of this identifier.
+    pub(crate) fn set_binding(&self, locator: BindingLocator) {
+        self.binding.set(Some(locator));
+    }
+
+    /// Sets the value of the binding, which throws a `ReferenceError` if it doesn't exist.
+    pub(crate) fn set_value(&self, value: Value, context: &mut Context) -> Result<()> {
+        let environment = &mut context.realm_mut().environment;
+        let result = match self.binding() {
+            Some(locator) => {
+                environment.set_mutable_binding_at(locator, self.as_ref(), value, true)
+            }
+            None => environment.set_mutable_binding(self.as_ref(), value, true),
+        };
+        result.map_err(|e| e.to_error(context))
+    }
+
+    /// Assigns a value to the binding, which is created in the function scope if it doesn't
+    /// exist.
+    pub(crate) fn assign(&self, value: Value, context: &mut Context) -> Result<()> {
+        let environment = &mut context.realm_mut().environment;
+
+        if let Some(locator) = self.binding() {
+            environment
+                .set_mutable_binding_at(locator, self.as_ref(), value, true)
+                .map_err(|e| e.to_error(context))
+        } else if environment.has_binding(self.as_ref()) {
+            // Binding already exists
+            environment
+                .set_mutable_binding(self.as_ref(), value, true)
+                .map_err(|e| e.to_error(context))
+        } else {
+            environment
+                .create_mutable_binding(self.as_ref().to_owned(), true, VariableScope::Function)
+                .map_err(|e| e.to_error(context))?;
+            let environment = &mut context.realm_mut().environment;
+            environment
+                .initialize_binding(self.as_ref(), value)
+                .map_err(|e| e.to_error(context))
+        }
+    }
+}
+
+unsafe impl Trace for Identifier {
+    empty_trace!();
+}
+
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident
+    }
 }
 
 impl Executable for Identifier {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        context
-            .realm()
-            .environment
-            .get_binding_value(self.as_ref())
-            .map_err(|e| e.to_error(context))
+        let environment = &context.realm().environment;
+        let binding = match self.binding() {
+            Some(locator) => environment.get_binding_at(locator, self.as_ref()),
+            None => environment.get_binding(self.as_ref()),
+        };
+        match binding {
+            Ok(BindingValue::Value(value)) => Ok(value),
+            // The environments can't call the getters of the accessor properties of the global
+            // object or of a `with` object, so they are called here.
+            Ok(BindingValue::Getter(getter, object)) => context.call(&getter.into(), &object, &[]),
+            Err(e) => Err(e.to_error(context)),
+        }
     }
 }
 
@@ -61,7 +133,10 @@ where
     T: Into<Box<str>>,
 {
     fn from(stm: T) -> Self {
-        Self { ident: stm.into() }
+        Self {
+            ident: stm.into(),
+            binding: Cell::default(),
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    exec::{Executable, InterpreterState},
+    exec::{Completion, CompletionType, Executable},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
     Context, Result, Value,
@@ -47,11 +47,14 @@ impl Continue {
 
 impl Executable for Continue {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        context
-            .executor()
-            .set_current_state(InterpreterState::Continue(self.label().map(Box::from)));
+        self.run_statement(context).map(Completion::into_value)
+    }
 
-        Ok(Value::undefined())
+    fn run_statement(&self, _: &mut Context) -> Result<Completion> {
+        Ok(Completion::new(
+            CompletionType::Continue(self.label().map(Box::from)),
+            None,
+        ))
     }
 }
 
@@ -1,7 +1,8 @@
+use super::{loop_continues, loop_exit};
 use crate::{
-    exec::{Executable, InterpreterState},
+    exec::{Completion, Executable},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::StatementLocation, Node},
     Context, Result, Value,
 };
 use std::fmt;
@@ -27,6 +28,9 @@ pub struct DoWhileLoop {
     body: Box<Node>,
     cond: Box<Node>,
     label: Option<Box<str>>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    body_location: StatementLocation,
 }
 
 impl DoWhileLoop {
@@ -56,9 +60,15 @@ impl DoWhileLoop {
             body: Box::new(body.into()),
             cond: Box::new(condition.into()),
             label: None,
+            body_location: StatementLocation::default(),
         }
     }
 
+    /// Sets the location of the body, if it is not a block.
+    pub(in crate::syntax) fn set_body_location(&mut self, location: StatementLocation) {
+        self.body_location = location;
+    }
+
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
@@ -72,29 +82,24 @@ impl DoWhileLoop {
 
 impl Executable for DoWhileLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let mut result;
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
+        let mut value = Value::undefined();
         loop {
-            result = self.body().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
-                }
+            let mut completion = self.body_location.run_body(self.body(), context)?;
+            if !loop_continues(&completion, self.label()) {
+                return Ok(loop_exit(completion, self.label(), value));
+            }
+            if let Some(result) = completion.take_value() {
+                value = result;
             }
             if !self.cond().run(context)?.to_boolean() {
                 break;
             }
         }
-        Ok(result)
+        Ok(Completion::normal(value))
     }
 }
 
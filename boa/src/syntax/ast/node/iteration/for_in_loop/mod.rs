@@ -1,9 +1,10 @@
+use super::{loop_continues, loop_exit};
 use crate::{
     builtins::{iterable::IteratorRecord, ForInIterator},
     environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::{Executable, InterpreterState},
+    exec::{Completion, Executable, Reference},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::StatementLocation, Node},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -18,6 +19,9 @@ pub struct ForInLoop {
     expr: Box<Node>,
     body: Box<Node>,
     label: Option<Box<str>>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    body_location: StatementLocation,
 }
 
 impl ForInLoop {
@@ -32,6 +36,7 @@ impl ForInLoop {
             expr: Box::new(expr.into()),
             body: Box::new(body.into()),
             label: None,
+            body_location: StatementLocation::default(),
         }
     }
 
@@ -55,6 +60,30 @@ impl ForInLoop {
         self.label = Some(label);
     }
 
+    /// Sets the location of the body, if it is not a block.
+    pub(in crate::syntax) fn set_body_location(&mut self, location: StatementLocation) {
+        self.body_location = location;
+    }
+
+    /// Assigns `value` to the `var` variable `name` of the head of the loop.
+    fn assign_var(name: &str, value: Value, context: &mut Context) -> Result<()> {
+        let environment = &mut context.realm_mut().environment;
+
+        if environment.has_binding(name) {
+            environment
+                .set_mutable_binding(name, value, true)
+                .map_err(|e| e.to_error(context))
+        } else {
+            environment
+                .create_mutable_binding(name.to_owned(), false, VariableScope::Function)
+                .map_err(|e| e.to_error(context))?;
+            let environment = &mut context.realm_mut().environment;
+            environment
+                .initialize_binding(name, value)
+                .map_err(|e| e.to_error(context))
+        }
+    }
+
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         write!(f, "for ({} in {}) {{", self.variable, self.expr,)?;
         self.body().display(f, indentation + 1)?;
@@ -76,12 +105,29 @@ impl From<ForInLoop> for Node {
 
 impl Executable for ForInLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
         let _timer = BoaProfiler::global().start_event("ForIn", "exec");
+
+        // The initializer of a `var` declaration, only allowed outside of strict mode, is assigned
+        // before the object is evaluated, even if it has no property to enumerate.
+        // <https://tc39.es/ecma262/#sec-initializers-in-forin-statement-heads>
+        if let Node::VarDeclList(ref list) = self.variable() {
+            if let [var] = list.as_ref() {
+                if let Some(init) = var.init() {
+                    let value = init.run(context)?;
+                    Self::assign_var(var.name(), value, context)?;
+                }
+            }
+        }
+
         let object = self.expr().run(context)?;
-        let mut result = Value::undefined();
+        let mut value = Value::undefined();
 
         if object.is_null_or_undefined() {
-            return Ok(result);
+            return Ok(Completion::normal(value));
         }
         let object = object.to_object(context)?;
         let for_in_iterator = ForInIterator::create_for_in_iterator(context, Value::from(object))?;
@@ -91,153 +137,128 @@ impl Executable for ForInLoop {
             .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
         let iterator = IteratorRecord::new(for_in_iterator, next_function);
 
-        loop {
-            {
-                let env = &mut context.realm_mut().environment;
-                env.push(new_declarative_environment(Some(
-                    env.get_current_environment_ref().clone(),
-                )));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.is_done() {
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    let environment = &mut context.realm_mut().environment;
-
-                    if environment.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        environment
-                            .set_mutable_binding(name.as_ref(), next_result.clone(), true)
-                            .map_err(|e| e.to_error(context))?;
-                    } else {
-                        environment
-                            .create_mutable_binding(
-                                name.as_ref().to_owned(),
-                                true,
-                                VariableScope::Function,
-                            )
-                            .map_err(|e| e.to_error(context))?;
-                        let environment = &mut context.realm_mut().environment;
-                        environment
-                            .initialize_binding(name.as_ref(), next_result.clone())
-                            .map_err(|e| e.to_error(context))?;
-                    }
+        context.run_in_environment_scope(|context| {
+            loop {
+                {
+                    let env = &mut context.realm_mut().environment;
+                    env.push(new_declarative_environment(Some(
+                        env.get_current_environment_ref().clone(),
+                    )));
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut context.realm_mut().environment;
+                let iterator_result = iterator.next(context)?;
+                if iterator_result.is_done() {
+                    break;
+                }
+                let next_result = iterator_result.value();
 
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
-                        }
+                match self.variable() {
+                    Node::Identifier(ref name) => {
+                        let environment = &mut context.realm_mut().environment;
 
-                        if environment.has_binding(var.name()) {
+                        if environment.has_binding(name.as_ref()) {
+                            // Binding already exists
                             environment
-                                .set_mutable_binding(var.name(), next_result, true)
+                                .set_mutable_binding(name.as_ref(), next_result.clone(), true)
                                 .map_err(|e| e.to_error(context))?;
                         } else {
                             environment
                                 .create_mutable_binding(
-                                    var.name().to_owned(),
-                                    false,
+                                    name.as_ref().to_owned(),
+                                    true,
                                     VariableScope::Function,
                                 )
                                 .map_err(|e| e.to_error(context))?;
                             let environment = &mut context.realm_mut().environment;
                             environment
-                                .initialize_binding(var.name(), next_result)
+                                .initialize_binding(name.as_ref(), next_result.clone())
                                 .map_err(|e| e.to_error(context))?;
                         }
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
-                    }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut context.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                    Node::VarDeclList(ref list) => match list.as_ref() {
+                        [var] => Self::assign_var(var.name(), next_result, context)?,
+                        _ => {
+                            return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-in loop",
+                            )
                         }
+                    },
+                    Node::LetDeclList(ref list) => match list.as_ref() {
+                        [var] => {
+                            let environment = &mut context.realm_mut().environment;
 
-                        environment
-                            .create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Block,
-                            )
-                            .map_err(|e| e.to_error(context))?;
-                        let environment = &mut context.realm_mut().environment;
-                        environment
-                            .initialize_binding(var.name(), next_result)
-                            .map_err(|e| e.to_error(context))?;
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
-                    }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut context.realm_mut().environment;
+                            if var.init().is_some() {
+                                return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                            }
 
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                            environment
+                                .create_mutable_binding(
+                                    var.name().to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )
+                                .map_err(|e| e.to_error(context))?;
+                            let environment = &mut context.realm_mut().environment;
+                            environment
+                                .initialize_binding(var.name(), next_result)
+                                .map_err(|e| e.to_error(context))?;
+                        }
+                        _ => {
+                            return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-in loop",
+                            )
                         }
+                    },
+                    Node::ConstDeclList(ref list) => match list.as_ref() {
+                        [var] => {
+                            let environment = &mut context.realm_mut().environment;
+
+                            if var.init().is_some() {
+                                return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                            }
 
-                        environment
-                            .create_immutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Block,
+                            environment
+                                .create_immutable_binding(
+                                    var.name().to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )
+                                .map_err(|e| e.to_error(context))?;
+                            let environment = &mut context.realm_mut().environment;
+                            environment
+                                .initialize_binding(var.name(), next_result)
+                                .map_err(|e| e.to_error(context))?;
+                        }
+                        _ => {
+                            return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-in loop",
                             )
-                            .map_err(|e| e.to_error(context))?;
-                        let environment = &mut context.realm_mut().environment;
-                        environment
-                            .initialize_binding(var.name(), next_result)
-                            .map_err(|e| e.to_error(context))?;
+                        }
+                    },
+                    Node::Assign(_) => {
+                        return context.throw_syntax_error(
+                            "a declaration in the head of a for-in loop can't have an initializer",
+                        );
+                    }
+                    Node::GetConstField(_) | Node::GetField(_) => {
+                        Reference::resolve(self.variable(), context)?
+                            .put_value(next_result, context)?;
                     }
                     _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+                        return context
+                            .throw_syntax_error("unknown left hand side in head of for-in loop")
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-in loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-in loop")
                 }
-            }
 
-            result = self.body().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
+                let mut completion = self.body_location.run_body(self.body(), context)?;
+                let _ = context.realm_mut().environment.pop();
+                if !loop_continues(&completion, self.label()) {
+                    return Ok(loop_exit(completion, self.label(), value));
                 }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
-                }
-                InterpreterState::Return => return Ok(result),
-                InterpreterState::Executing => {
-                    // Continue execution.
+                if let Some(result) = completion.take_value() {
+                    value = result;
                 }
             }
-            let _ = context.realm_mut().environment.pop();
-        }
-        Ok(result)
+            Ok(Completion::normal(value))
+        })
     }
 }
@@ -1,8 +1,9 @@
+use super::{loop_continues, loop_exit};
 use crate::{
     environment::lexical_environment::new_declarative_environment,
-    exec::{Executable, InterpreterState},
+    exec::{Completion, Executable},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::StatementLocation, Node},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -27,11 +28,14 @@ pub struct ForLoop {
     #[cfg_attr(feature = "deser", serde(flatten))]
     inner: Box<InnerForLoop>,
     label: Option<Box<str>>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    body_location: StatementLocation,
 }
 
 impl ForLoop {
     /// Creates a new for loop AST node.
-    pub(in crate::syntax) fn new<I, C, E, B>(init: I, condition: C, final_expr: E, body: B) -> Self
+    pub fn new<I, C, E, B>(init: I, condition: C, final_expr: E, body: B) -> Self
     where
         I: Into<Option<Node>>,
         C: Into<Option<Node>>,
@@ -41,6 +45,7 @@ impl ForLoop {
         Self {
             inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
             label: None,
+            body_location: StatementLocation::default(),
         }
     }
 
@@ -95,57 +100,55 @@ impl ForLoop {
     pub fn set_label(&mut self, label: Box<str>) {
         self.label = Some(label);
     }
+
+    /// Sets the location of the body, if it is not a block.
+    pub(in crate::syntax) fn set_body_location(&mut self, location: StatementLocation) {
+        self.body_location = location;
+    }
 }
 
 impl Executable for ForLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        // Create the block environment.
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
         let _timer = BoaProfiler::global().start_event("ForLoop", "exec");
-        {
-            let env = &mut context.realm_mut().environment;
-            env.push(new_declarative_environment(Some(
-                env.get_current_environment_ref().clone(),
-            )));
-        }
+        context.run_in_environment_scope(|context| {
+            // Create the block environment.
+            {
+                let env = &mut context.realm_mut().environment;
+                env.push(new_declarative_environment(Some(
+                    env.get_current_environment_ref().clone(),
+                )));
+            }
 
-        if let Some(init) = self.init() {
-            init.run(context)?;
-        }
+            if let Some(init) = self.init() {
+                init.run(context)?;
+            }
 
-        while self
-            .condition()
-            .map(|cond| cond.run(context).map(|v| v.to_boolean()))
-            .transpose()?
-            .unwrap_or(true)
-        {
-            let result = self.body().run(context)?;
-
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
+            let mut value = Value::undefined();
+            while self
+                .condition()
+                .map(|cond| cond.run(context).map(|v| v.to_boolean()))
+                .transpose()?
+                .unwrap_or(true)
+            {
+                let mut completion = self.body_location.run_body(self.body(), context)?;
+                if !loop_continues(&completion, self.label()) {
+                    return Ok(loop_exit(completion, self.label(), value));
                 }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
+                if let Some(result) = completion.take_value() {
+                    value = result;
                 }
 
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
+                if let Some(final_expr) = self.final_expr() {
+                    final_expr.run(context)?;
                 }
             }
 
-            if let Some(final_expr) = self.final_expr() {
-                final_expr.run(context)?;
-            }
-        }
-
-        // pop the block env
-        let _ = context.realm_mut().environment.pop();
-
-        Ok(Value::undefined())
+            Ok(Completion::normal(value))
+        })
     }
 }
 
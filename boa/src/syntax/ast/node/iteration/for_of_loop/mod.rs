@@ -1,9 +1,10 @@
+use super::{loop_continues, loop_exit};
 use crate::{
     builtins::iterable::get_iterator,
     environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::{Executable, InterpreterState},
+    exec::{Completion, Executable},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::StatementLocation, Node},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -18,6 +19,9 @@ pub struct ForOfLoop {
     iterable: Box<Node>,
     body: Box<Node>,
     label: Option<Box<str>>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    body_location: StatementLocation,
 }
 
 impl ForOfLoop {
@@ -32,6 +36,7 @@ impl ForOfLoop {
             iterable: Box::new(iterable.into()),
             body: Box::new(body.into()),
             label: None,
+            body_location: StatementLocation::default(),
         }
     }
 
@@ -55,6 +60,11 @@ impl ForOfLoop {
         self.label = Some(label);
     }
 
+    /// Sets the location of the body, if it is not a block.
+    pub(in crate::syntax) fn set_body_location(&mut self, location: StatementLocation) {
+        self.body_location = location;
+    }
+
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         write!(f, "for ({} of {}) {{", self.variable, self.iterable)?;
         self.body().display(f, indentation + 1)?;
@@ -76,159 +86,158 @@ impl From<ForOfLoop> for Node {
 
 impl Executable for ForOfLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
         let _timer = BoaProfiler::global().start_event("ForOf", "exec");
         let iterable = self.iterable().run(context)?;
         let iterator = get_iterator(context, iterable)?;
-        let mut result = Value::undefined();
-
-        loop {
-            {
-                let env = &mut context.realm_mut().environment;
-                env.push(new_declarative_environment(Some(
-                    env.get_current_environment_ref().clone(),
-                )));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.is_done() {
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    let environment = &mut context.realm_mut().environment;
-
-                    if environment.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        environment
-                            .set_mutable_binding(name.as_ref(), next_result.clone(), true)
-                            .map_err(|e| e.to_error(context))?;
-                    } else {
-                        environment
-                            .create_mutable_binding(
-                                name.as_ref().to_owned(),
-                                true,
-                                VariableScope::Function,
-                            )
-                            .map_err(|e| e.to_error(context))?;
-                        let environment = &mut context.realm_mut().environment;
-                        environment
-                            .initialize_binding(name.as_ref(), next_result.clone())
-                            .map_err(|e| e.to_error(context))?;
-                    }
+        let mut value = Value::undefined();
+
+        context.run_in_environment_scope(|context| {
+            loop {
+                {
+                    let env = &mut context.realm_mut().environment;
+                    env.push(new_declarative_environment(Some(
+                        env.get_current_environment_ref().clone(),
+                    )));
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut context.realm_mut().environment;
+                let iterator_result = iterator.next(context)?;
+                if iterator_result.is_done() {
+                    break;
+                }
+                let next_result = iterator_result.value();
 
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
+                match self.variable() {
+                    Node::Identifier(ref name) => {
+                        let environment = &mut context.realm_mut().environment;
 
-                        if environment.has_binding(var.name()) {
+                        if environment.has_binding(name.as_ref()) {
+                            // Binding already exists
                             environment
-                                .set_mutable_binding(var.name(), next_result, true)
+                                .set_mutable_binding(name.as_ref(), next_result.clone(), true)
                                 .map_err(|e| e.to_error(context))?;
                         } else {
                             environment
                                 .create_mutable_binding(
-                                    var.name().to_owned(),
-                                    false,
+                                    name.as_ref().to_owned(),
+                                    true,
                                     VariableScope::Function,
                                 )
                                 .map_err(|e| e.to_error(context))?;
                             let environment = &mut context.realm_mut().environment;
                             environment
-                                .initialize_binding(var.name(), next_result)
+                                .initialize_binding(name.as_ref(), next_result.clone())
                                 .map_err(|e| e.to_error(context))?;
                         }
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut context.realm_mut().environment;
+                    Node::VarDeclList(ref list) => match list.as_ref() {
+                        [var] => {
+                            let environment = &mut context.realm_mut().environment;
 
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            if var.init().is_some() {
+                                return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            }
+
+                            if environment.has_binding(var.name()) {
+                                environment
+                                    .set_mutable_binding(var.name(), next_result, true)
+                                    .map_err(|e| e.to_error(context))?;
+                            } else {
+                                environment
+                                    .create_mutable_binding(
+                                        var.name().to_owned(),
+                                        false,
+                                        VariableScope::Function,
+                                    )
+                                    .map_err(|e| e.to_error(context))?;
+                                let environment = &mut context.realm_mut().environment;
+                                environment
+                                    .initialize_binding(var.name(), next_result)
+                                    .map_err(|e| e.to_error(context))?;
+                            }
                         }
-
-                        environment
-                            .create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Block,
+                        _ => {
+                            return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-of loop",
                             )
-                            .map_err(|e| e.to_error(context))?;
+                        }
+                    },
+                    Node::LetDeclList(ref list) => match list.as_ref() {
+                        [var] => {
+                            let environment = &mut context.realm_mut().environment;
 
-                        let environment = &mut context.realm_mut().environment;
-                        environment
-                            .initialize_binding(var.name(), next_result)
-                            .map_err(|e| e.to_error(context))?;
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut context.realm_mut().environment;
+                            if var.init().is_some() {
+                                return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            }
 
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            environment
+                                .create_mutable_binding(
+                                    var.name().to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )
+                                .map_err(|e| e.to_error(context))?;
+
+                            let environment = &mut context.realm_mut().environment;
+                            environment
+                                .initialize_binding(var.name(), next_result)
+                                .map_err(|e| e.to_error(context))?;
+                        }
+                        _ => {
+                            return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-of loop",
+                            )
                         }
+                    },
+                    Node::ConstDeclList(ref list) => match list.as_ref() {
+                        [var] => {
+                            let environment = &mut context.realm_mut().environment;
 
-                        environment
-                            .create_immutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Block,
+                            if var.init().is_some() {
+                                return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
+                            }
+
+                            environment
+                                .create_immutable_binding(
+                                    var.name().to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )
+                                .map_err(|e| e.to_error(context))?;
+                            let environment = &mut context.realm_mut().environment;
+                            environment
+                                .initialize_binding(var.name(), next_result)
+                                .map_err(|e| e.to_error(context))?;
+                        }
+                        _ => {
+                            return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-of loop",
                             )
-                            .map_err(|e| e.to_error(context))?;
-                        let environment = &mut context.realm_mut().environment;
-                        environment
-                            .initialize_binding(var.name(), next_result)
-                            .map_err(|e| e.to_error(context))?;
+                        }
+                    },
+                    Node::Assign(_) => {
+                        return context.throw_syntax_error(
+                            "a declaration in the head of a for-of loop can't have an initializer",
+                        );
                     }
                     _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
+                        return context
+                            .throw_syntax_error("unknown left hand side in head of for-of loop")
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-of loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-of loop")
                 }
-            }
 
-            result = self.body().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
+                let mut completion = self.body_location.run_body(self.body(), context)?;
+                let _ = context.realm_mut().environment.pop();
+                if !loop_continues(&completion, self.label()) {
+                    return Ok(loop_exit(completion, self.label(), value));
                 }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
-                }
-                InterpreterState::Return => return Ok(result),
-                InterpreterState::Executing => {
-                    // Continue execution.
+                if let Some(result) = completion.take_value() {
+                    value = result;
                 }
             }
-            let _ = context.realm_mut().environment.pop();
-        }
-        Ok(result)
+            Ok(Completion::normal(value))
+        })
     }
 }
@@ -5,29 +5,45 @@ pub use self::{
     for_of_loop::ForOfLoop, while_loop::WhileLoop,
 };
 
+use crate::{
+    exec::{Completion, CompletionType},
+    Value,
+};
+
 #[cfg(test)]
 mod tests;
 
-// Checking labels for break and continue is the same operation for `ForLoop`, `While` and `DoWhile`
-#[macro_use]
-macro_rules! handle_state_with_labels {
-    ($self:ident, $label:ident, $interpreter:ident, $state:tt) => {{
-        if let Some(brk_label) = $label {
-            if let Some(stmt_label) = $self.label() {
-                // Break from where we are, keeping "continue" set as the state
-                if stmt_label != brk_label.as_ref() {
-                    break;
-                }
-            } else {
-                // if a label is set but the current block has no label, break
-                break;
-            }
-        }
+/// Checks whether a loop labelled with `label` keeps running after an iteration completing
+/// with `completion`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-loopcontinues
+fn loop_continues(completion: &Completion, label: Option<&str>) -> bool {
+    match completion.kind() {
+        CompletionType::Normal | CompletionType::Continue(None) => true,
+        CompletionType::Continue(Some(target)) => label == Some(target.as_ref()),
+        CompletionType::Return | CompletionType::Break(_) => false,
+    }
+}
 
-        $interpreter
-            .executor()
-            .set_current_state(InterpreterState::Executing);
-    }};
+/// The completion of a loop labelled with `label`, stopped by the iteration completing with
+/// `completion`, where `value` is the value of the previous iterations.
+///
+/// The `break` statements targeting the loop complete it normally.
+fn loop_exit(completion: Completion, label: Option<&str>, value: Value) -> Completion {
+    let mut completion = completion.update_empty(value);
+    let breaks_loop = match completion.kind() {
+        CompletionType::Break(None) => true,
+        CompletionType::Break(Some(target)) => label == Some(target.as_ref()),
+        _ => false,
+    };
+    if breaks_loop {
+        Completion::new(CompletionType::Normal, completion.take_value())
+    } else {
+        completion
+    }
 }
 
 pub mod continue_node;
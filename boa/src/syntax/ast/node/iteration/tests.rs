@@ -502,3 +502,133 @@ fn for_in_continue_label() {
     "#;
     assert_eq!(&exec(scenario), "\"00\"")
 }
+
+#[test]
+fn for_in_property_order() {
+    let scenario = r#"
+        var obj = { b: 1, a: 2, 10: 3, 2: 4 };
+        obj.c = 5;
+        var keys = [];
+        for (var key in obj) {
+            keys.push(key);
+        }
+        keys.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"2,10,b,a,c\"");
+}
+
+#[test]
+fn for_in_prototype_chain() {
+    let scenario = r#"
+        var proto = { a: 1, b: 2, c: 3 };
+        var obj = Object.create(proto);
+        obj.b = 4;
+        Object.defineProperty(obj, "c", { value: 5, enumerable: false });
+        var keys = [];
+        for (var key in obj) {
+            keys.push(key);
+        }
+        keys.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"b,a\"");
+}
+
+#[test]
+fn for_in_skips_symbols() {
+    let scenario = r#"
+        var obj = { a: 1 };
+        obj[Symbol("b")] = 2;
+        var keys = [];
+        for (var key in obj) {
+            keys.push(typeof key);
+        }
+        keys.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"string\"");
+}
+
+#[test]
+fn for_in_deleted_during_iteration() {
+    let scenario = r#"
+        var obj = { a: 1, b: 2, c: 3 };
+        var keys = [];
+        for (var key in obj) {
+            keys.push(key);
+            delete obj.b;
+        }
+        keys.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"a,c\"");
+}
+
+#[test]
+fn for_in_added_during_iteration() {
+    let scenario = r#"
+        var proto = { p: 1 };
+        var obj = Object.create(proto);
+        obj.a = 1;
+        var keys = [];
+        for (var key in obj) {
+            keys.push(key);
+            obj.b = 2;
+            proto.q = 3;
+        }
+        keys.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"a,p,q\"");
+}
+
+#[test]
+fn for_in_member_target() {
+    let scenario = r#"
+        var obj = {};
+        for (obj.key in { x: 1, y: 2 });
+        obj.key
+    "#;
+    assert_eq!(&exec(scenario), "\"y\"");
+
+    let scenario = r#"
+        var arr = [];
+        var i = 0;
+        for (arr[i++] in { x: 1, y: 2 });
+        arr.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"x,y\"");
+}
+
+#[test]
+fn for_in_var_initializer() {
+    let scenario = r#"
+        var seen = [];
+        for (var key = "init" in (seen.push(key), { a: 1, b: 2 })) {
+            seen.push(key);
+        }
+        seen.join() + "," + key
+    "#;
+    assert_eq!(&exec(scenario), "\"init,a,b,b\"");
+}
+
+#[test]
+fn for_in_var_initializer_without_properties() {
+    let scenario = r#"
+        for (var empty = 1 in {}) {
+            empty = 0;
+        }
+        for (var nothing = 2 in null);
+        for (var missing = 3 in undefined);
+        [empty, nothing, missing].join()
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,3\"");
+}
+
+#[test]
+fn for_loop_comma_in_head() {
+    let scenario = r#"
+        var i, j, str = "";
+        for (i = 0, j = 3; i < j; i++, j--) {
+            str = str + i + j;
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"0312\"");
+}
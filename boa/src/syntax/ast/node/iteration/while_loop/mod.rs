@@ -1,7 +1,8 @@
+use super::{loop_continues, loop_exit};
 use crate::{
-    exec::{Executable, InterpreterState},
+    exec::{Completion, Executable},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::StatementLocation, Node},
     Context, Result, Value,
 };
 use std::fmt;
@@ -26,6 +27,9 @@ pub struct WhileLoop {
     cond: Box<Node>,
     expr: Box<Node>,
     label: Option<Box<str>>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    body_location: StatementLocation,
 }
 
 impl WhileLoop {
@@ -55,9 +59,15 @@ impl WhileLoop {
             cond: Box::new(condition.into()),
             expr: Box::new(body.into()),
             label: None,
+            body_location: StatementLocation::default(),
         }
     }
 
+    /// Sets the location of the body, if it is not a block.
+    pub(in crate::syntax) fn set_body_location(&mut self, location: StatementLocation) {
+        self.body_location = location;
+    }
+
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
@@ -70,26 +80,21 @@ impl WhileLoop {
 
 impl Executable for WhileLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let mut result = Value::undefined();
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
+        let mut value = Value::undefined();
         while self.cond().run(context)?.to_boolean() {
-            result = self.expr().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue)
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
-                }
+            let mut completion = self.body_location.run_body(self.expr(), context)?;
+            if !loop_continues(&completion, self.label()) {
+                return Ok(loop_exit(completion, self.label(), value));
+            }
+            if let Some(result) = completion.take_value() {
+                value = result;
             }
         }
-        Ok(result)
+        Ok(Completion::normal(value))
     }
 }
 
@@ -48,7 +48,7 @@ pub use self::{
 };
 use super::Const;
 use crate::{
-    exec::Executable,
+    exec::{Completion, Executable},
     gc::{empty_trace, Finalize, Trace},
     BoaProfiler, Context, Result, Value,
 };
@@ -117,6 +117,14 @@ pub enum Node {
     /// A do ... while statement. [More information](./iteration/struct.DoWhileLoop.html).
     DoWhileLoop(DoWhileLoop),
 
+    /// An elision in an array literal, which leaves a hole in the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Elision
+    Empty,
+
     /// A function declaration node. [More information](./declaration/struct.FunctionDecl.html).
     FunctionDecl(FunctionDecl),
 
@@ -188,6 +196,19 @@ pub enum Node {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/this
     This,
 
+    /// The `super` keyword, as the object of a `super.prop` or `super[expr]` property access.
+    ///
+    /// The property is looked up on the prototype of the home object of the enclosing method,
+    /// with the current `this` as the receiver.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-super-keyword
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+    Super,
+
     /// Unary operation node. [More information](./operator/struct.UnaryOp.html)
     UnaryOp(UnaryOp),
 
@@ -227,6 +248,37 @@ impl Node {
         Self::This
     }
 
+    /// Checks if the node is a function definition without a name of its own, which takes the
+    /// name of the binding or property it is assigned to.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isanonymousfunctiondefinition
+    #[cfg(feature = "vm")]
+    pub(crate) fn is_anonymous_function_definition(&self) -> bool {
+        match *self {
+            Self::FunctionExpr(ref expr) => expr.name().is_none(),
+            Self::ArrowFunctionDecl(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Evaluates the node, naming the function it creates `name` if it is an anonymous function
+    /// definition.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-namedevaluation
+    pub(crate) fn run_named(&self, name: &str, context: &mut Context) -> Result<Value> {
+        match *self {
+            Self::FunctionExpr(ref expr) => expr.instantiate(name, context),
+            Self::ArrowFunctionDecl(ref decl) => decl.instantiate(name, context),
+            _ => self.run(context),
+        }
+    }
+
     /// Implements the display formatting with indentation.
     fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         let indent = "    ".repeat(indentation);
@@ -243,6 +295,8 @@ impl Node {
             Self::ForOfLoop(ref for_of) => for_of.display(f, indentation),
             Self::ForInLoop(ref for_in) => for_in.display(f, indentation),
             Self::This => write!(f, "this"),
+            Self::Super => write!(f, "super"),
+            Self::Empty => Ok(()),
             Self::Try(ref try_catch) => try_catch.display(f, indentation),
             Self::Break(ref break_smt) => Display::fmt(break_smt, f),
             Self::Continue(ref cont) => Display::fmt(cont, f),
@@ -281,6 +335,7 @@ impl Node {
 impl Executable for Node {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Executable", "exec");
+        context.check_memory_limit()?;
         match *self {
             Node::AsyncFunctionDecl(ref decl) => decl.run(context),
             Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(context),
@@ -296,6 +351,7 @@ impl Executable for Node {
             // Do Const values need to be garbage collected? We no longer need them once we've generated Values
             Node::Const(Const::String(ref value)) => Ok(Value::string(value.to_string())),
             Node::Const(Const::Bool(value)) => Ok(Value::boolean(value)),
+            Node::Empty => Ok(Value::undefined()),
             Node::Block(ref block) => block.run(context),
             Node::Identifier(ref identifier) => identifier.run(context),
             Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(context),
@@ -335,11 +391,41 @@ impl Executable for Node {
                     .get_this_binding()
                     .map_err(|e| e.to_error(context))
             }
+            // `super` is only evaluated as part of a property reference.
+            Node::Super => context.throw_syntax_error("'super' keyword unexpected here"),
             Node::Try(ref try_node) => try_node.run(context),
             Node::Break(ref break_node) => break_node.run(context),
             Node::Continue(ref continue_node) => continue_node.run(context),
         }
     }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
+        context.check_memory_limit()?;
+        match *self {
+            Node::Block(ref block) => block.run_statement(context),
+            Node::If(ref if_smt) => if_smt.run_statement(context),
+            Node::WhileLoop(ref while_loop) => while_loop.run_statement(context),
+            Node::DoWhileLoop(ref do_while) => do_while.run_statement(context),
+            Node::ForLoop(ref for_loop) => for_loop.run_statement(context),
+            Node::ForOfLoop(ref for_of_loop) => for_of_loop.run_statement(context),
+            Node::ForInLoop(ref for_in_loop) => for_in_loop.run_statement(context),
+            Node::Switch(ref switch) => switch.run_statement(context),
+            Node::Try(ref try_node) => try_node.run_statement(context),
+            Node::Return(ref ret) => ret.run_statement(context),
+            Node::Break(ref break_node) => break_node.run_statement(context),
+            Node::Continue(ref continue_node) => continue_node.run_statement(context),
+            // Declarations complete normally with an empty value.
+            Node::VarDeclList(_)
+            | Node::LetDeclList(_)
+            | Node::ConstDeclList(_)
+            | Node::FunctionDecl(_)
+            | Node::AsyncFunctionDecl(_) => {
+                self.run(context)?;
+                Ok(Completion::empty())
+            }
+            _ => self.run(context).map(Completion::normal),
+        }
+    }
 }
 
 /// Utility to join multiple Nodes into a single string.
@@ -383,7 +469,7 @@ pub struct FormalParameter {
 
 impl FormalParameter {
     /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    pub fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
     where
         N: Into<Box<str>>,
     {
@@ -4,7 +4,7 @@ use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor},
-    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition},
+    syntax::ast::node::{FunctionExpr, MethodDefinitionKind, Node, PropertyDefinition},
     Context, Result, Value,
 };
 use std::fmt;
@@ -61,14 +61,36 @@ impl Object {
                 PropertyDefinition::SpreadObject(key) => {
                     write!(f, "{}    ...{},", indent, key)?;
                 }
-                PropertyDefinition::MethodDefinition(_kind, _key, _node) => {
-                    // TODO: Implement display for PropertyDefinition::MethodDefinition.
-                    unimplemented!("Display for PropertyDefinition::MethodDefinition");
+                PropertyDefinition::MethodDefinition(kind, key, func) => {
+                    let prefix = match kind {
+                        MethodDefinitionKind::Get => "get ",
+                        MethodDefinitionKind::Set => "set ",
+                        MethodDefinitionKind::Ordinary => "",
+                    };
+                    write!(f, "{}    {}", indent, prefix)?;
+                    func.display_method(f, key, indent + 1)?;
+                    f.write_str(",")?;
                 }
             }
         }
         f.write_str("}")
     }
+
+    /// Creates the function of a method definition named `name`, with `obj` as its home object.
+    fn method(
+        func: &FunctionExpr,
+        name: &str,
+        obj: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let method = func.instantiate(name, context)?;
+        if let (Some(method), Some(obj)) = (method.as_object(), obj.as_object()) {
+            if let Some(function) = method.borrow_mut().as_function_mut() {
+                function.set_home_object(obj);
+            }
+        }
+        Ok(method)
+    }
 }
 
 impl Executable for Object {
@@ -82,7 +104,7 @@ impl Executable for Object {
                     obj.set_property(
                         key.clone(),
                         PropertyDescriptor::Data(DataDescriptor::new(
-                            value.run(context)?,
+                            value.run_named(key, context)?,
                             Attribute::all(),
                         )),
                     );
@@ -92,7 +114,7 @@ impl Executable for Object {
                         obj.set_property(
                             name.clone(),
                             PropertyDescriptor::Data(DataDescriptor::new(
-                                func.run(context)?,
+                                Self::method(func, name, &obj, context)?,
                                 Attribute::all(),
                             )),
                         );
@@ -106,7 +128,8 @@ impl Executable for Object {
                         obj.set_property(
                             name.clone(),
                             PropertyDescriptor::Accessor(AccessorDescriptor {
-                                get: func.run(context)?.as_object(),
+                                get: Self::method(func, &format!("get {}", name), &obj, context)?
+                                    .as_object(),
                                 set,
                                 attributes: Attribute::WRITABLE
                                     | Attribute::ENUMERABLE
@@ -124,7 +147,8 @@ impl Executable for Object {
                             name.clone(),
                             PropertyDescriptor::Accessor(AccessorDescriptor {
                                 get,
-                                set: func.run(context)?.as_object(),
+                                set: Self::method(func, &format!("set {}", name), &obj, context)?
+                                    .as_object(),
                                 attributes: Attribute::WRITABLE
                                     | Attribute::ENUMERABLE
                                     | Attribute::CONFIGURABLE,
@@ -1,6 +1,5 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
-    exec::Executable,
+    exec::{Executable, Reference},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
     BoaProfiler, Context, Result, Value,
@@ -30,7 +29,7 @@ pub struct Assign {
 
 impl Assign {
     /// Creates an `Assign` AST node.
-    pub(in crate::syntax) fn new<L, R>(lhs: L, rhs: R) -> Self
+    pub fn new<L, R>(lhs: L, rhs: R) -> Self
     where
         L: Into<Node>,
         R: Into<Node>,
@@ -55,33 +54,24 @@ impl Assign {
 impl Executable for Assign {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(context)?;
+        let val = match self.lhs() {
+            Node::Identifier(ref name) => self.rhs().run_named(name.as_ref(), context)?,
+            _ => self.rhs().run(context)?,
+        };
         match self.lhs() {
-            Node::Identifier(ref name) => {
-                let environment = &mut context.realm_mut().environment;
-
-                if environment.has_binding(name.as_ref()) {
-                    // Binding already exists
-                    environment
-                        .set_mutable_binding(name.as_ref(), val.clone(), true)
-                        .map_err(|e| e.to_error(context))?;
-                } else {
-                    environment
-                        .create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
-                        )
-                        .map_err(|e| e.to_error(context))?;
-                    let environment = &mut context.realm_mut().environment;
-                    environment
-                        .initialize_binding(name.as_ref(), val.clone())
-                        .map_err(|e| e.to_error(context))?;
-                }
+            Node::Identifier(ref name) => name.assign(val.clone(), context)?,
+            // `super` properties are assigned with `this` as the receiver.
+            Node::GetConstField(ref get_const_field)
+                if matches!(get_const_field.obj(), Node::Super) =>
+            {
+                Reference::resolve(self.lhs(), context)?.put_value(val.clone(), context)?
+            }
+            Node::GetField(ref get_field) if matches!(get_field.obj(), Node::Super) => {
+                Reference::resolve(self.lhs(), context)?.put_value(val.clone(), context)?
             }
             Node::GetConstField(ref get_const_field) => {
                 let val_obj = get_const_field.obj().run(context)?;
-                val_obj.set_field(get_const_field.field(), val.clone(), context)?;
+                get_const_field.set_field_of(&val_obj, val.clone(), context)?;
             }
             Node::GetField(ref get_field) => {
                 let object = get_field.obj().run(context)?;
@@ -1,5 +1,5 @@
 use crate::{
-    exec::Executable,
+    exec::{Executable, Reference},
     gc::{Finalize, Trace},
     syntax::ast::{
         node::Node,
@@ -15,7 +15,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "vm")]
 use crate::{
     profiler::BoaProfiler,
-    vm::{compilation::CodeGen, Compiler, Instruction},
+    vm::{
+        compilation::{CodeGen, CompileResult, Unsupported},
+        Compiler, Instruction,
+    },
 };
 
 /// Binary operators requires two operands, one before the operator and one after the operator.
@@ -34,7 +37,7 @@ pub struct BinOp {
 
 impl BinOp {
     /// Creates a `BinOp` AST node.
-    pub(in crate::syntax) fn new<O, L, R>(op: O, lhs: L, rhs: R) -> Self
+    pub fn new<O, L, R>(op: O, lhs: L, rhs: R) -> Self
     where
         O: Into<op::BinOp>,
         L: Into<Node>,
@@ -151,30 +154,7 @@ impl Executable for BinOp {
                         let key = x.to_property_key(context)?;
                         context.has_property(&y, &key)
                     }
-                    CompOp::InstanceOf => {
-                        if let Some(object) = y.as_object() {
-                            let key = context.well_known_symbols().has_instance_symbol();
-
-                            match object.get_method(context, key)? {
-                                Some(instance_of_handler) => {
-                                    instance_of_handler.call(&y, &[x], context)?.to_boolean()
-                                }
-                                None if object.is_callable() => {
-                                    object.ordinary_has_instance(context, &x)?
-                                }
-                                None => {
-                                    return context.throw_type_error(
-                                        "right-hand side of 'instanceof' is not callable",
-                                    );
-                                }
-                            }
-                        } else {
-                            return context.throw_type_error(format!(
-                                "right-hand side of 'instanceof' should be an object, got {}",
-                                y.get_type().as_str()
-                            ));
-                        }
-                    }
+                    CompOp::InstanceOf => x.instance_of(&y, context)?,
                 }))
             }
             op::BinOp::Log(op) => Ok(match op {
@@ -203,31 +183,27 @@ impl Executable for BinOp {
                     }
                 }
             }),
-            op::BinOp::Assign(op) => match self.lhs() {
-                Node::Identifier(ref name) => {
-                    let v_a = context
-                        .realm()
-                        .environment
-                        .get_binding_value(name.as_ref())
-                        .map_err(|e| e.to_error(context))?;
+            op::BinOp::Assign(op) => {
+                // The target is evaluated once, and is read and written through the same
+                // reference.
+                let reference = Reference::resolve(self.lhs(), context)?;
+                let v_a = reference.get_value(context)?;
 
-                    let value = Self::run_assign(op, v_a, self.rhs(), context)?;
-                    context
-                        .realm_mut()
-                        .environment
-                        .set_mutable_binding(name.as_ref(), value.clone(), true)
-                        .map_err(|e| e.to_error(context))?;
-                    Ok(value)
+                // The logical assignments don't write the target when they short-circuit.
+                let short_circuits = match op {
+                    AssignOp::BoolAnd => !v_a.to_boolean(),
+                    AssignOp::BoolOr => v_a.to_boolean(),
+                    AssignOp::Coalesce => !v_a.is_null_or_undefined(),
+                    _ => false,
+                };
+                if short_circuits {
+                    return Ok(v_a);
                 }
-                Node::GetConstField(ref get_const_field) => {
-                    let v_r_a = get_const_field.obj().run(context)?;
-                    let v_a = v_r_a.get_field(get_const_field.field(), context)?;
-                    let value = Self::run_assign(op, v_a, self.rhs(), context)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone(), context)?;
-                    Ok(value)
-                }
-                _ => Ok(Value::undefined()),
-            },
+
+                let value = Self::run_assign(op, v_a, self.rhs(), context)?;
+                reference.put_value(value.clone(), context)?;
+                Ok(value)
+            }
             op::BinOp::Comma => {
                 self.lhs().run(context)?;
                 Ok(self.rhs().run(context)?)
@@ -236,14 +212,72 @@ impl Executable for BinOp {
     }
 }
 
+#[cfg(feature = "vm")]
+impl BinOp {
+    /// Compiles the right hand side of a short-circuiting operation, which only runs if `jump`
+    /// doesn't jump over it with the value of the left hand side.
+    fn compile_short_circuit(
+        jump: Instruction,
+        rhs: &Node,
+        compiler: &mut Compiler,
+    ) -> CompileResult {
+        compiler.add_instruction(Instruction::Dup);
+        let end = compiler.add_jump(jump);
+        compiler.add_instruction(Instruction::Pop);
+        rhs.compile(compiler)?;
+        compiler.patch_jump(end);
+        Ok(())
+    }
+
+    /// Compiles the assignment operators, applied to the value on top of the stack.
+    ///
+    /// The logical assignments return the address of the jump taken when they short-circuit,
+    /// which must skip the write of the target.
+    fn compile_assign(
+        op: AssignOp,
+        rhs: &Node,
+        compiler: &mut Compiler,
+    ) -> Result<Option<usize>, Unsupported> {
+        let instruction = match op {
+            AssignOp::Add => Instruction::Add,
+            AssignOp::Sub => Instruction::Sub,
+            AssignOp::Mul => Instruction::Mul,
+            AssignOp::Exp => Instruction::Pow,
+            AssignOp::Div => Instruction::Div,
+            AssignOp::Mod => Instruction::Mod,
+            AssignOp::And => Instruction::BitAnd,
+            AssignOp::Or => Instruction::BitOr,
+            AssignOp::Xor => Instruction::BitXor,
+            AssignOp::Shl => Instruction::Shl,
+            AssignOp::Shr => Instruction::Shr,
+            AssignOp::Ushr => Instruction::UShr,
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => {
+                let jump = match op {
+                    AssignOp::BoolAnd => Instruction::JumpIfFalse(0),
+                    AssignOp::BoolOr => Instruction::JumpIfTrue(0),
+                    _ => Instruction::JumpIfNotNullish(0),
+                };
+                compiler.add_instruction(Instruction::Dup);
+                let short_circuit = compiler.add_jump(jump);
+                compiler.add_instruction(Instruction::Pop);
+                rhs.compile(compiler)?;
+                return Ok(Some(short_circuit));
+            }
+        };
+        rhs.compile(compiler)?;
+        compiler.add_instruction(instruction);
+        Ok(None)
+    }
+}
+
 #[cfg(feature = "vm")]
 impl CodeGen for BinOp {
-    fn compile(&self, compiler: &mut Compiler) {
+    fn compile(&self, compiler: &mut Compiler) -> CompileResult {
         let _timer = BoaProfiler::global().start_event("binOp", "codeGen");
         match self.op() {
             op::BinOp::Num(op) => {
-                self.lhs().compile(compiler);
-                self.rhs().compile(compiler);
+                self.lhs().compile(compiler)?;
+                self.rhs().compile(compiler)?;
                 match op {
                     NumOp::Add => compiler.add_instruction(Instruction::Add),
                     NumOp::Sub => compiler.add_instruction(Instruction::Sub),
@@ -254,8 +288,8 @@ impl CodeGen for BinOp {
                 }
             }
             op::BinOp::Bit(op) => {
-                self.lhs().compile(compiler);
-                self.rhs().compile(compiler);
+                self.lhs().compile(compiler)?;
+                self.rhs().compile(compiler)?;
                 match op {
                     BitOp::And => compiler.add_instruction(Instruction::BitAnd),
                     BitOp::Or => compiler.add_instruction(Instruction::BitOr),
@@ -266,8 +300,8 @@ impl CodeGen for BinOp {
                 }
             }
             op::BinOp::Comp(op) => {
-                self.lhs().compile(compiler);
-                self.rhs().compile(compiler);
+                self.lhs().compile(compiler)?;
+                self.rhs().compile(compiler)?;
                 match op {
                     CompOp::Equal => compiler.add_instruction(Instruction::Eq),
                     CompOp::NotEqual => compiler.add_instruction(Instruction::NotEq),
@@ -281,8 +315,50 @@ impl CodeGen for BinOp {
                     CompOp::InstanceOf => compiler.add_instruction(Instruction::InstanceOf),
                 }
             }
-            _ => unimplemented!(),
+            op::BinOp::Log(op) => {
+                self.lhs().compile(compiler)?;
+                let jump = match op {
+                    LogOp::And => Instruction::JumpIfFalse(0),
+                    LogOp::Or => Instruction::JumpIfTrue(0),
+                    LogOp::Coalesce => Instruction::JumpIfNotNullish(0),
+                };
+                Self::compile_short_circuit(jump, self.rhs(), compiler)?;
+            }
+            op::BinOp::Assign(op) => match self.lhs() {
+                Node::Identifier(ref name) => {
+                    self.lhs().compile(compiler)?;
+                    let short_circuit = Self::compile_assign(op, self.rhs(), compiler)?;
+                    let index = compiler.identifier_index(name);
+                    compiler.add_instruction(Instruction::SetName(index));
+                    if let Some(short_circuit) = short_circuit {
+                        compiler.patch_jump(short_circuit);
+                    }
+                }
+                Node::GetConstField(ref get_const_field) => {
+                    get_const_field.obj().compile(compiler)?;
+                    compiler.add_instruction(Instruction::Dup);
+                    let index = compiler.name_index(get_const_field.field());
+                    compiler.add_instruction(Instruction::GetField(index));
+                    let short_circuit = Self::compile_assign(op, self.rhs(), compiler)?;
+                    compiler.add_instruction(Instruction::SetField(index));
+                    if let Some(short_circuit) = short_circuit {
+                        // The object is still below the value when the assignment short-circuits.
+                        let end = compiler.add_jump(Instruction::Jump(0));
+                        compiler.patch_jump(short_circuit);
+                        compiler.add_instruction(Instruction::Swap);
+                        compiler.add_instruction(Instruction::Pop);
+                        compiler.patch_jump(end);
+                    }
+                }
+                _ => return Err(Unsupported),
+            },
+            op::BinOp::Comma => {
+                self.lhs().compile(compiler)?;
+                compiler.add_instruction(Instruction::Pop);
+                self.rhs().compile(compiler)?;
+            }
         }
+        Ok(())
     }
 }
 
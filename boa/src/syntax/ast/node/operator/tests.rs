@@ -113,3 +113,160 @@ fn logical_assignment() {
 
     assert_eq!(&exec(scenario), "20");
 }
+
+#[test]
+fn logical_assignment_short_circuit_does_not_write() {
+    let scenario = r#"
+        const a = 1;
+        a ||= 2;
+        a;
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn update_expression_on_members() {
+    let scenario = r#"
+        let o = { a: 1 };
+        let arr = [5, 6];
+        let i = 1;
+        let results = [o.a++, ++o.a, arr[i]--, --arr[i], o.a, arr[1]];
+        results.join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,3,6,4,3,4\"");
+}
+
+#[test]
+fn update_expression_converts_to_numeric() {
+    let scenario = r#"
+        let a = "5";
+        let old = a++;
+        typeof old + old + a
+        "#;
+
+    assert_eq!(&exec(scenario), "\"number56\"");
+
+    let scenario = r#"
+        let o = { n: 10n };
+        let old = o.n--;
+        [old, o.n, ++o.n].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"10,9,10\"");
+}
+
+#[test]
+fn update_and_compound_assignment_evaluate_base_once() {
+    let scenario = r#"
+        let calls = 0;
+        let o = { a: 1, b: 1 };
+        function base() {
+            calls++;
+            return o;
+        }
+        function key() {
+            calls++;
+            return "b";
+        }
+        base().a++;
+        base()[key()] += 10;
+        base()[key()] **= 2;
+        [calls, o.a, o.b].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"5,2,121\"");
+}
+
+#[test]
+fn update_expression_on_getters_and_setters() {
+    let scenario = r#"
+        let log = [];
+        let o = {
+            get x() { log.push("get"); return 1; },
+            set x(v) { log.push("set " + v); }
+        };
+        let result = o.x++;
+        log.push(result);
+        log.join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"get,set 2,1\"");
+}
+
+#[test]
+fn compound_assignment_operators() {
+    let scenario = r#"
+        let results = [];
+        let a;
+        a = 7; a += 2; results.push(a);
+        a = 7; a -= 2; results.push(a);
+        a = 7; a *= 2; results.push(a);
+        a = 7; a /= 2; results.push(a);
+        a = 7; a %= 2; results.push(a);
+        a = 7; a **= 2; results.push(a);
+        a = 7; a <<= 2; results.push(a);
+        a = -7; a >>= 1; results.push(a);
+        a = -7; a >>>= 28; results.push(a);
+        a = 7; a &= 2; results.push(a);
+        a = 7; a |= 8; results.push(a);
+        a = 7; a ^= 2; results.push(a);
+        results.join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"9,5,14,3.5,1,49,28,-4,15,2,15,5\"");
+}
+
+#[test]
+fn compound_assignment_string_and_bigint() {
+    let scenario = r#"
+        let s = 1;
+        s += "2";
+        let n = 10n;
+        n -= 3n;
+        n **= 2n;
+        n >>= 1n;
+        typeof s + s + typeof n + n
+        "#;
+
+    assert_eq!(&exec(scenario), "\"string12bigint24\"");
+
+    let scenario = r#"
+        let n = 1n;
+        try {
+            n >>>= 1n;
+        } catch (err) {
+            err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"TypeError: BigInts have no unsigned right shift, use >> instead\""
+    );
+}
+
+#[test]
+fn logical_assignment_skips_set_and_rhs() {
+    let scenario = r#"
+        let sets = 0, rhs = 0;
+        let value = 1;
+        let o = {
+            get x() { return value; },
+            set x(v) { sets++; value = v; }
+        };
+        function f(v) {
+            rhs++;
+            return v;
+        }
+        o.x ||= f(2);
+        o.x &&= f(3);
+        o.x ??= f(4);
+        value = null;
+        o.x ??= f(5);
+        [sets, rhs, value].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2,2,5\"");
+}
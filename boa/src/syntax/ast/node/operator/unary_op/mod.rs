@@ -1,5 +1,5 @@
 use crate::{
-    exec::Executable,
+    exec::{Executable, Reference},
     gc::{Finalize, Trace},
     syntax::ast::{node::Node, op},
     Context, Result, Value,
@@ -12,7 +12,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "vm")]
 use crate::{
     profiler::BoaProfiler,
-    vm::{compilation::CodeGen, Compiler, Instruction},
+    vm::{
+        compilation::{CodeGen, CompileResult, Unsupported},
+        Compiler, Instruction,
+    },
 };
 
 /// A unary operation is an operation with only one operand.
@@ -32,7 +35,7 @@ pub struct UnaryOp {
 
 impl UnaryOp {
     /// Creates a new `UnaryOp` AST node.
-    pub(in crate::syntax) fn new<V>(op: op::UnaryOp, target: V) -> Self
+    pub fn new<V>(op: op::UnaryOp, target: V) -> Self
     where
         V: Into<Node>,
     {
@@ -51,35 +54,49 @@ impl UnaryOp {
     pub fn target(&self) -> &Node {
         self.target.as_ref()
     }
+
+    /// Runs the increment and decrement operators, which read and write the target through the
+    /// same reference.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-update-expressions
+    fn run_update(&self, context: &mut Context) -> Result<Value> {
+        let reference = Reference::resolve(self.target(), context)?;
+        let old_value = reference.get_value(context)?.to_numeric(context)?;
+        let new_value = match self.op() {
+            op::UnaryOp::IncrementPost | op::UnaryOp::IncrementPre => old_value.add_integer(1),
+            _ => old_value.add_integer(-1),
+        };
+        reference.put_value(new_value.clone().into(), context)?;
+
+        Ok(match self.op() {
+            op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => old_value.into(),
+            _ => new_value.into(),
+        })
+    }
 }
 
 impl Executable for UnaryOp {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        match self.op() {
+            op::UnaryOp::IncrementPost
+            | op::UnaryOp::IncrementPre
+            | op::UnaryOp::DecrementPost
+            | op::UnaryOp::DecrementPre => return self.run_update(context),
+            _ => {}
+        }
+
         let x = self.target().run(context)?;
 
         Ok(match self.op() {
             op::UnaryOp::Minus => x.neg(context)?,
             op::UnaryOp::Plus => Value::from(x.to_number(context)?),
-            op::UnaryOp::IncrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::IncrementPre => {
-                let result = x.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?
-            }
-            op::UnaryOp::DecrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::DecrementPre => {
-                let result = x.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?
-            }
+            op::UnaryOp::IncrementPost
+            | op::UnaryOp::IncrementPre
+            | op::UnaryOp::DecrementPost
+            | op::UnaryOp::DecrementPre => unreachable!("the update expressions ran above"),
             op::UnaryOp::Not => x.not(context)?.into(),
             op::UnaryOp::Tilde => {
                 let num_v_a = x.to_number(context)?;
@@ -92,6 +109,14 @@ impl Executable for UnaryOp {
             }
             op::UnaryOp::Void => Value::undefined(),
             op::UnaryOp::Delete => match *self.target() {
+                Node::GetConstField(ref get_const_field)
+                    if matches!(get_const_field.obj(), Node::Super) =>
+                {
+                    return context.throw_reference_error("Cannot delete a super property");
+                }
+                Node::GetField(ref get_field) if matches!(get_field.obj(), Node::Super) => {
+                    return context.throw_reference_error("Cannot delete a super property");
+                }
                 Node::GetConstField(ref get_const_field) => Value::boolean(
                     get_const_field
                         .obj()
@@ -137,21 +162,50 @@ impl From<UnaryOp> for Node {
 
 #[cfg(feature = "vm")]
 impl CodeGen for UnaryOp {
-    fn compile(&self, compiler: &mut Compiler) {
+    fn compile(&self, compiler: &mut Compiler) -> CompileResult {
         let _timer = BoaProfiler::global().start_event("UnaryOp", "codeGen");
-        self.target().compile(compiler);
-        match self.op {
-            op::UnaryOp::Void => compiler.add_instruction(Instruction::Void),
-            op::UnaryOp::Plus => compiler.add_instruction(Instruction::Pos),
-            op::UnaryOp::Minus => compiler.add_instruction(Instruction::Neg),
-            op::UnaryOp::TypeOf => compiler.add_instruction(Instruction::TypeOf),
-            op::UnaryOp::Not => compiler.add_instruction(Instruction::Not),
-            op::UnaryOp::Tilde => compiler.add_instruction(Instruction::BitNot),
-            op::UnaryOp::IncrementPost => {}
-            op::UnaryOp::IncrementPre => {}
-            op::UnaryOp::DecrementPost => {}
-            op::UnaryOp::DecrementPre => {}
-            op::UnaryOp::Delete => {}
-        }
+        let instruction = match self.op {
+            op::UnaryOp::Void => Instruction::Void,
+            op::UnaryOp::Plus => Instruction::Pos,
+            op::UnaryOp::Minus => Instruction::Neg,
+            op::UnaryOp::TypeOf => Instruction::TypeOf,
+            op::UnaryOp::Not => Instruction::Not,
+            op::UnaryOp::Tilde => Instruction::BitNot,
+            op::UnaryOp::IncrementPost
+            | op::UnaryOp::IncrementPre
+            | op::UnaryOp::DecrementPost
+            | op::UnaryOp::DecrementPre => {
+                let name = match self.target() {
+                    Node::Identifier(ref name) => name,
+                    _ => return Err(Unsupported),
+                };
+                self.target().compile(compiler)?;
+                compiler.add_instruction(Instruction::ToNumeric);
+                let post = matches!(
+                    self.op,
+                    op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost
+                );
+                if post {
+                    // The old value is the result of the expression.
+                    compiler.add_instruction(Instruction::Dup);
+                }
+                match self.op {
+                    op::UnaryOp::IncrementPost | op::UnaryOp::IncrementPre => {
+                        compiler.add_instruction(Instruction::Inc)
+                    }
+                    _ => compiler.add_instruction(Instruction::Dec),
+                }
+                let index = compiler.identifier_index(name);
+                compiler.add_instruction(Instruction::SetName(index));
+                if post {
+                    compiler.add_instruction(Instruction::Pop);
+                }
+                return Ok(());
+            }
+            op::UnaryOp::Delete => return Err(Unsupported),
+        };
+        self.target().compile(compiler)?;
+        compiler.add_instruction(instruction);
+        Ok(())
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    exec::{Executable, InterpreterState},
+    exec::{Completion, CompletionType, Executable},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
     Context, Result, Value,
@@ -59,15 +59,15 @@ impl Return {
 
 impl Executable for Return {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
         let result = match self.expr() {
-            Some(ref v) => v.run(context),
-            None => Ok(Value::undefined()),
+            Some(ref v) => v.run(context)?,
+            None => Value::undefined(),
         };
-        // Set flag for return
-        context
-            .executor()
-            .set_current_state(InterpreterState::Return);
-        result
+        Ok(Completion::new(CompletionType::Return, Some(result)))
     }
 }
 
@@ -1,9 +1,9 @@
 //! Statement list node.
 
 use crate::{
-    exec::{Executable, InterpreterState},
+    exec::{Completion, CompletionType, Executable},
     gc::{empty_trace, Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::{node::Node, Span},
     BoaProfiler, Context, Result, Value,
 };
 use std::{fmt, ops::Deref, rc::Rc};
@@ -12,7 +12,10 @@ use std::{fmt, ops::Deref, rc::Rc};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "vm")]
-use crate::vm::{compilation::CodeGen, Compiler};
+use crate::vm::{
+    compilation::{CodeGen, CompileResult},
+    CodeBlock, CodeCache, Compiler,
+};
 
 /// List of statements.
 ///
@@ -23,10 +26,21 @@ use crate::vm::{compilation::CodeGen, Compiler};
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct StatementList {
     #[cfg_attr(feature = "deser", serde(flatten))]
     items: Box<[Node]>,
+    #[cfg_attr(feature = "deser", serde(default))]
+    strict: bool,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    spans: Box<[Span]>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    indices: Box<[u32]>,
+    #[cfg(feature = "vm")]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    code: CodeCache,
 }
 
 impl StatementList {
@@ -35,6 +49,54 @@ impl StatementList {
         &self.items
     }
 
+    /// Whether the statements are strict mode code.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Gets the span of the source code of each item, if the statements were parsed.
+    ///
+    /// The spans are in the same order as the items, and are empty for the statements that were
+    /// not parsed.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Sets the span of the source code of each item.
+    pub(in crate::syntax) fn with_spans<S>(mut self, spans: S) -> Self
+    where
+        S: Into<Box<[Span]>>,
+    {
+        self.spans = spans.into();
+        debug_assert_eq!(self.spans.len(), self.items.len());
+        self
+    }
+
+    /// Sets the index of each item among the statements of its script, which the coverage of the
+    /// script is counted by.
+    pub(in crate::syntax) fn with_statement_indices<I>(mut self, indices: I) -> Self
+    where
+        I: Into<Box<[u32]>>,
+    {
+        self.indices = indices.into();
+        debug_assert_eq!(self.indices.len(), self.items.len());
+        self
+    }
+
+    /// Marks the statements as strict mode code.
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Gets the code block of the statements, compiling it the first time.
+    ///
+    /// Returns `None` if the statements use something that the VM doesn't support.
+    #[cfg(feature = "vm")]
+    pub(crate) fn code_block(&self) -> Option<Rc<CodeBlock>> {
+        self.code
+            .get_or_compile(|| Compiler::compile_statement_list(self))
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -59,50 +121,97 @@ impl StatementList {
 
 impl Executable for StatementList {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
         let _timer = BoaProfiler::global().start_event("StatementList", "exec");
 
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
-        // The return value is uninitialized, which means it defaults to Value::Undefined
-        let mut obj = Value::default();
+        // The value is the value of the last statement that has one, and is empty otherwise.
+        let mut value = None;
+        for (index, item) in self.items().iter().enumerate() {
+            let location = StatementLocation {
+                span: self.spans.get(index).copied(),
+                index: self.indices.get(index).copied(),
+            };
+            let mut completion = location.run_statement(item, context)?.update_empty(value);
+            if completion.is_abrupt() {
+                return Ok(completion);
+            }
+            value = completion.take_value();
+        }
+
+        Ok(Completion::new(CompletionType::Normal, value))
+    }
+}
+
+/// The location of a statement in the source code of its script, with its index among the
+/// statements of the script.
+///
+/// The statement lists keep the location of their items, and the statements nested in another one
+/// without braces, like the body of `if (a) b();`, keep theirs in the statement they are nested
+/// in. Like the spans of the statement lists, the locations are not part of the syntax tree, so
+/// the comparisons of the nodes ignore them.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct StatementLocation {
+    span: Option<Span>,
+    index: Option<u32>,
+}
+
+impl StatementLocation {
+    /// Creates the location of a parsed statement.
+    pub(in crate::syntax) fn new(span: Span, index: u32) -> Self {
+        Self {
+            span: Some(span),
+            index: Some(index),
+        }
+    }
+
+    /// Runs `statement`, which is at this location, after calling the trace hook and counting
+    /// its execution.
+    pub(crate) fn run_statement(
+        &self,
+        statement: &Node,
+        context: &mut Context,
+    ) -> Result<Completion> {
         context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
-        for (i, item) in self.items().iter().enumerate() {
-            let val = item.run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    obj = val;
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // Early break.
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    break;
+            .trace_statement(self.span)
+            .and_then(|()| {
+                context.cover_statement(self.index);
+                statement.run_statement(context)
+            })
+            .map_err(|error| {
+                if let Some(span) = self.span {
+                    context.record_error_location(&error, span);
                 }
-                InterpreterState::Executing => {
-                    // Continue execution
-                }
-            }
-            if i + 1 == self.items().len() {
-                obj = val;
-            }
+                error
+            })
+    }
+
+    /// Runs `body`, a statement nested in another one, which is at this location.
+    ///
+    /// A block is run directly, since the items of its statement list have their own locations.
+    pub(crate) fn run_body(&self, body: &Node, context: &mut Context) -> Result<Completion> {
+        match body {
+            Node::Block(_) => body.run_statement(context),
+            _ => self.run_statement(body, context),
         }
+    }
+}
 
-        Ok(obj)
+impl PartialEq for StatementLocation {
+    fn eq(&self, _: &Self) -> bool {
+        true
     }
 }
 
 #[cfg(feature = "vm")]
 impl CodeGen for StatementList {
-    fn compile(&self, compiler: &mut Compiler) {
+    fn compile(&self, compiler: &mut Compiler) -> CompileResult {
         let _timer = BoaProfiler::global().start_event("StatementList - Code Gen", "codeGen");
 
-        for item in self.items().iter() {
-            item.compile(compiler);
-        }
+        compiler.compile_items(self.items())
     }
 }
 
@@ -111,7 +220,20 @@ where
     T: Into<Box<[Node]>>,
 {
     fn from(stm: T) -> Self {
-        Self { items: stm.into() }
+        Self {
+            items: stm.into(),
+            strict: false,
+            spans: Box::default(),
+            indices: Box::default(),
+            #[cfg(feature = "vm")]
+            code: CodeCache::default(),
+        }
+    }
+}
+
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.strict == other.strict
     }
 }
 
@@ -1,7 +1,7 @@
 //! Switch node.
 //!
 use crate::{
-    exec::{Executable, InterpreterState},
+    exec::{Completion, CompletionType, Executable},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
     Context, Result, Value,
@@ -123,12 +123,13 @@ impl Switch {
 
 impl Executable for Switch {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
         let val = self.val().run(context)?;
-        let mut result = Value::null();
+        let mut value = Value::undefined();
         let mut matched = false;
-        context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
 
         // If a case block does not end with a break statement then subsequent cases will be run without
         // checking their conditions until a break is encountered.
@@ -139,63 +140,42 @@ impl Executable for Switch {
             let block = case.body();
             if fall_through || val.strict_equals(&cond.run(context)?) {
                 matched = true;
-                let result = block.run(context)?;
-                match context.executor().get_current_state() {
-                    InterpreterState::Return => {
-                        // Early return.
-                        return Ok(result);
-                    }
-                    InterpreterState::Break(_label) => {
-                        // TODO, break to a label.
-                        // Break statement encountered so therefore end switch statement.
-                        context
-                            .executor()
-                            .set_current_state(InterpreterState::Executing);
-                        break;
-                    }
-                    InterpreterState::Continue(_label) => {
-                        // TODO, continue to a label.
-                        break;
-                    }
-                    InterpreterState::Executing => {
-                        // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
-                    }
+                let mut completion = block.run_statement(context)?;
+                if completion.is_abrupt() {
+                    return Ok(switch_exit(completion, value));
                 }
+                if let Some(result) = completion.take_value() {
+                    value = result;
+                }
+                // Continuing execution / falling through to next case statement(s).
+                fall_through = true;
             }
         }
 
         if !matched {
-            if let Some(default) = self.default() {
-                context
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(context)?;
-                    match context.executor().get_current_state() {
-                        InterpreterState::Return => {
-                            // Early return.
-                            result = val;
-                            break;
-                        }
-                        InterpreterState::Break(_label) => {
-                            // TODO, break to a label.
-
-                            // Early break.
-                            break;
-                        }
-                        _ => {
-                            // Continue execution
-                        }
-                    }
-                    if i == default.len() - 1 {
-                        result = val;
-                    }
+            if let Some(ref default) = self.default {
+                let mut completion = default.run_statement(context)?;
+                if completion.is_abrupt() {
+                    return Ok(switch_exit(completion, value));
+                }
+                if let Some(result) = completion.take_value() {
+                    value = result;
                 }
             }
         }
 
-        Ok(result)
+        Ok(Completion::normal(value))
+    }
+}
+
+/// Ends a switch statement with an abrupt completion, where an unlabelled `break` ends the
+/// switch itself.
+fn switch_exit(completion: Completion, value: Value) -> Completion {
+    let mut completion = completion.update_empty(value);
+    if completion.kind() == &CompletionType::Break(None) {
+        Completion::new(CompletionType::Normal, completion.take_value())
+    } else {
+        completion
     }
 }
 
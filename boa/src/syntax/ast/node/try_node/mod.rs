@@ -1,6 +1,6 @@
 use crate::{
     environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::Executable,
+    exec::{Completion, Executable},
     gc::{Finalize, Trace},
     syntax::ast::node::{Block, Identifier, Node},
     BoaProfiler, Context, Result, Value,
@@ -36,17 +36,19 @@ pub struct Try {
 
 impl Try {
     /// Creates a new `Try` AST node.
-    pub(in crate::syntax) fn new<B>(
-        block: B,
-        catch: Option<Catch>,
-        finally: Option<Finally>,
-    ) -> Self
+    ///
+    /// # Panics
+    ///
+    /// Panics if both `catch` and `finally` are `None`, since a `try` statement needs at least
+    /// one of them.
+    #[track_caller]
+    pub fn new<B>(block: B, catch: Option<Catch>, finally: Option<Finally>) -> Self
     where
         B: Into<Block>,
     {
         assert!(
             catch.is_some() || finally.is_some(),
-            "one of catch or finally must be pressent"
+            "one of catch or finally must be present"
         );
 
         Self {
@@ -93,35 +95,37 @@ impl Try {
 
 impl Executable for Try {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        self.run_statement(context).map(Completion::into_value)
+    }
+
+    fn run_statement(&self, context: &mut Context) -> Result<Completion> {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(context).map_or_else(
+        let res = self.block().run_statement(context).map_or_else(
             |err| {
                 if let Some(catch) = self.catch() {
-                    {
-                        let env = &mut context.realm_mut().environment;
-                        env.push(new_declarative_environment(Some(
-                            env.get_current_environment_ref().clone(),
-                        )));
-
-                        if let Some(param) = catch.parameter() {
-                            env.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            )
-                            .map_err(|e| e.to_error(context))?;
+                    context.clear_error_location();
+                    context.run_in_environment_scope(|context| {
+                        {
                             let env = &mut context.realm_mut().environment;
-                            env.initialize_binding(param, err)
+                            env.push(new_declarative_environment(Some(
+                                env.get_current_environment_ref().clone(),
+                            )));
+
+                            if let Some(param) = catch.parameter() {
+                                env.create_mutable_binding(
+                                    param.to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )
                                 .map_err(|e| e.to_error(context))?;
+                                let env = &mut context.realm_mut().environment;
+                                env.initialize_binding(param, err)
+                                    .map_err(|e| e.to_error(context))?;
+                            }
                         }
-                    }
-
-                    let res = catch.block().run(context);
-
-                    // pop the block env
-                    let _ = context.realm_mut().environment.pop();
 
-                    res
+                        catch.block().run_statement(context)
+                    })
                 } else {
                     Err(err)
                 }
@@ -130,10 +134,14 @@ impl Executable for Try {
         );
 
         if let Some(finally) = self.finally() {
-            finally.run(context)?;
+            // An abrupt completion of the `finally` block overrides the one of the `try` statement.
+            let completion = finally.run_statement(context)?;
+            if completion.is_abrupt() {
+                return Ok(completion);
+            }
         }
 
-        res
+        res.map(|completion| completion.update_empty(Value::undefined()))
     }
 }
 
@@ -159,7 +167,7 @@ pub struct Catch {
 
 impl Catch {
     /// Creates a new catch block.
-    pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
+    pub fn new<OI, I, B>(parameter: OI, block: B) -> Self
     where
         OI: Into<Option<I>>,
         I: Into<Identifier>,
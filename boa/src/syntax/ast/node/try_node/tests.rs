@@ -1,4 +1,7 @@
-use crate::exec;
+use crate::{
+    exec,
+    syntax::ast::node::{Node, Try},
+};
 
 #[test]
 fn simple_try() {
@@ -93,3 +96,9 @@ fn catch_binding_finally() {
     "#;
     assert_eq!(&exec(scenario), "30");
 }
+
+#[test]
+#[should_panic(expected = "one of catch or finally must be present")]
+fn try_without_catch_or_finally() {
+    Try::new(Vec::<Node>::new(), None, None);
+}
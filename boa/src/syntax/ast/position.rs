@@ -45,6 +45,65 @@ impl Position {
     pub fn column_number(self) -> u32 {
         self.column_number.get()
     }
+
+    /// Finds the byte offset of the position in the source code, if the source contains it.
+    ///
+    /// The lines and columns are counted as the lexer does: a column is a character, and a line
+    /// ends with `\r\n`, `\r`, `\n`, U+2028 or U+2029.
+    pub(crate) fn byte_offset(self, source: &str) -> Option<usize> {
+        let target = (self.line_number(), self.column_number());
+        let (mut line, mut column) = (1, 1);
+        let mut chars = source.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            if (line, column) == target {
+                return Some(offset);
+            }
+            match c {
+                '\r' => {
+                    if let Some(&(_, '\n')) = chars.peek() {
+                        let _ = chars.next();
+                    }
+                    line += 1;
+                    column = 1;
+                }
+                '\n' | '\u{2028}' | '\u{2029}' => {
+                    line += 1;
+                    column = 1;
+                }
+                _ => column += 1,
+            }
+        }
+
+        // The position right after the last character is the end of the source.
+        if (line, column) == target {
+            Some(source.len())
+        } else {
+            None
+        }
+    }
+
+    /// Gets the position right after the last character of the source code.
+    pub(crate) fn end_of(source: &str) -> Self {
+        let (mut line, mut column) = (1, 1);
+        let mut chars = source.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\r' => {
+                    if let Some('\n') = chars.peek() {
+                        let _ = chars.next();
+                    }
+                    line += 1;
+                    column = 1;
+                }
+                '\n' | '\u{2028}' | '\u{2029}' => {
+                    line += 1;
+                    column = 1;
+                }
+                _ => column += 1,
+            }
+        }
+        Self::new(line, column)
+    }
 }
 
 impl fmt::Display for Position {
@@ -94,6 +153,14 @@ impl Span {
         let other = other.into();
         self.start <= other.start && self.end >= other.end
     }
+
+    /// Gets the text of the span in the source code it comes from, if the source contains it.
+    #[inline]
+    pub fn slice(self, source: &str) -> Option<&str> {
+        let start = self.start.byte_offset(source)?;
+        let end = self.end.byte_offset(source)?;
+        source.get(start..end)
+    }
 }
 
 impl From<Position> for Span {
@@ -293,4 +360,31 @@ mod tests {
         assert!(span_ab < span_cd);
         assert!(span_cd > span_ab);
     }
+
+    /// Checks that the text of a span is sliced out of its source.
+    #[test]
+    fn span_slice() {
+        let source = "let a = 1;\r\nlet é = 'ü';\u{2028}a";
+
+        let span = Span::new(Position::new(1, 5), Position::new(1, 6));
+        assert_eq!(span.slice(source), Some("a"));
+
+        let span = Span::new(Position::new(2, 5), Position::new(2, 13));
+        assert_eq!(span.slice(source), Some("é = 'ü';"));
+
+        let span = Span::new(Position::new(1, 1), Position::new(3, 2));
+        assert_eq!(span.slice(source), Some(source));
+
+        let span = Span::new(Position::new(3, 1), Position::new(4, 1));
+        assert_eq!(span.slice(source), None);
+    }
+
+    /// Checks the position of the end of the source code.
+    #[test]
+    fn end_of_source() {
+        assert_eq!(Position::end_of(""), Position::new(1, 1));
+        assert_eq!(Position::end_of("let é"), Position::new(1, 6));
+        assert_eq!(Position::end_of("a;\r\nb;\r\n"), Position::new(3, 1));
+        assert_eq!(Position::end_of("a\rbc"), Position::new(2, 3));
+    }
 }
@@ -2,13 +2,13 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::{
         ast::{Position, Span},
         lexer::{Token, TokenKind},
     },
 };
-use std::io::Read;
+use std::io::{self, ErrorKind, Read};
 
 /// Lexes a single line comment.
 ///
@@ -27,7 +27,7 @@ impl<R> Tokenizer<R> for SingleLineComment {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("SingleLineComment", "Lexing");
+        let _timer = BoaProfiler::global().start_event("SingleLineComment", category::LEXING);
 
         // Skip either to the end of the line or to the end of the input
         while let Some(ch) = cursor.peek()? {
@@ -62,7 +62,7 @@ impl<R> Tokenizer<R> for MultiLineComment {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("MultiLineComment", "Lexing");
+        let _timer = BoaProfiler::global().start_event("MultiLineComment", category::LEXING);
 
         let mut new_line = false;
         loop {
@@ -73,10 +73,10 @@ impl<R> Tokenizer<R> for MultiLineComment {
                     new_line = true;
                 }
             } else {
-                return Err(Error::syntax(
+                return Err(Error::from(io::Error::new(
+                    ErrorKind::UnexpectedEof,
                     "unterminated multiline comment",
-                    cursor.pos(),
-                ));
+                )));
             }
         }
 
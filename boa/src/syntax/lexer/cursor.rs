@@ -1,5 +1,8 @@
 //! Module implementing the lexer cursor. This is used for managing the input byte stream.
-use crate::{profiler::BoaProfiler, syntax::ast::Position};
+use crate::{
+    profiler::{category, BoaProfiler},
+    syntax::ast::Position,
+};
 use std::io::{self, Bytes, Error, ErrorKind, Read};
 
 /// Cursor over the source code.
@@ -69,7 +72,7 @@ where
     /// Peeks the next byte.
     #[inline]
     pub(super) fn peek(&mut self) -> Result<Option<u8>, Error> {
-        let _timer = BoaProfiler::global().start_event("cursor::peek()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::peek()", category::LEXING);
 
         self.iter.peek_byte()
     }
@@ -77,7 +80,7 @@ where
     /// Peeks the next n bytes, the maximum number of peeked bytes is 4 (n <= 4).
     #[inline]
     pub(super) fn peek_n(&mut self, n: u8) -> Result<u32, Error> {
-        let _timer = BoaProfiler::global().start_event("cursor::peek_n()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::peek_n()", category::LEXING);
 
         self.iter.peek_n_bytes(n)
     }
@@ -85,7 +88,7 @@ where
     /// Peeks the next UTF-8 character in u32 code point.
     #[inline]
     pub(super) fn peek_char(&mut self) -> Result<Option<u32>, Error> {
-        let _timer = BoaProfiler::global().start_event("cursor::peek_char()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::peek_char()", category::LEXING);
 
         self.iter.peek_char()
     }
@@ -93,7 +96,7 @@ where
     /// Compares the byte passed in to the next byte, if they match true is returned and the buffer is incremented
     #[inline]
     pub(super) fn next_is(&mut self, byte: u8) -> io::Result<bool> {
-        let _timer = BoaProfiler::global().start_event("cursor::next_is()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::next_is()", category::LEXING);
 
         Ok(match self.peek()? {
             Some(next) if next == byte => {
@@ -114,7 +117,8 @@ where
     where
         F: Fn(char) -> bool,
     {
-        let _timer = BoaProfiler::global().start_event("cursor::next_is_ascii_pred()", "Lexing");
+        let _timer =
+            BoaProfiler::global().start_event("cursor::next_is_ascii_pred()", category::LEXING);
 
         Ok(match self.peek()? {
             Some(byte) => match byte {
@@ -135,7 +139,8 @@ where
     where
         F: Fn(u32) -> bool,
     {
-        let _timer = BoaProfiler::global().start_event("cursor::next_is_char_pred()", "Lexing");
+        let _timer =
+            BoaProfiler::global().start_event("cursor::next_is_char_pred()", category::LEXING);
 
         Ok(if let Some(peek) = self.peek_char()? {
             pred(peek)
@@ -149,7 +154,7 @@ where
     ///
     /// Note that all bytes up until the stop byte are added to the buffer, including the byte right before.
     pub(super) fn take_until(&mut self, stop: u8, buf: &mut Vec<u8>) -> io::Result<()> {
-        let _timer = BoaProfiler::global().start_event("cursor::take_until()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::take_until()", category::LEXING);
 
         loop {
             if self.next_is(stop)? {
@@ -173,7 +178,8 @@ where
     where
         F: Fn(char) -> bool,
     {
-        let _timer = BoaProfiler::global().start_event("cursor::take_while_ascii_pred()", "Lexing");
+        let _timer =
+            BoaProfiler::global().start_event("cursor::take_while_ascii_pred()", category::LEXING);
 
         loop {
             if !self.next_is_ascii_pred(pred)? {
@@ -195,14 +201,19 @@ where
     where
         F: Fn(u32) -> bool,
     {
-        let _timer = BoaProfiler::global().start_event("cursor::take_while_char_pred()", "Lexing");
+        let _timer =
+            BoaProfiler::global().start_event("cursor::take_while_char_pred()", category::LEXING);
 
         loop {
             if !self.next_is_char_pred(pred)? {
                 return Ok(());
             } else if let Some(ch) = self.peek_char()? {
+                // A sequence truncated by the end of the input is taken up to the end.
                 for _ in 0..utf8_len(ch) {
-                    buf.push(self.next_byte()?.unwrap());
+                    match self.next_byte()? {
+                        Some(byte) => buf.push(byte),
+                        None => return Ok(()),
+                    }
                 }
             } else {
                 // next_is_pred will return false if the next value is None so the None case should already be handled.
@@ -217,7 +228,7 @@ where
     /// `UnexpectedEof` I/O error.
     #[inline]
     pub(super) fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
-        let _timer = BoaProfiler::global().start_event("cursor::fill_bytes()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::fill_bytes()", category::LEXING);
 
         self.iter.fill_bytes(buf)
     }
@@ -225,7 +236,7 @@ where
     /// Retrieves the next byte.
     #[inline]
     pub(crate) fn next_byte(&mut self) -> Result<Option<u8>, Error> {
-        let _timer = BoaProfiler::global().start_event("cursor::next_byte()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::next_byte()", category::LEXING);
 
         let byte = self.iter.next_byte()?;
 
@@ -259,7 +270,7 @@ where
     /// Retrieves the next UTF-8 character.
     #[inline]
     pub(crate) fn next_char(&mut self) -> Result<Option<u32>, Error> {
-        let _timer = BoaProfiler::global().start_event("cursor::next_char()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("cursor::next_char()", category::LEXING);
 
         let ch = self.iter.next_char()?;
 
@@ -496,7 +507,7 @@ fn utf8_acc_cont_byte(ch: u32, byte: u8) -> u32 {
 /// bits `11`).
 #[inline]
 fn utf8_is_first_byte(byte: u8) -> bool {
-    byte <= 0x7F || (byte >> 6) == 0x11
+    byte <= 0x7F || (byte >> 6) == 0b11
 }
 
 #[inline]
@@ -1,8 +1,8 @@
 //! This module implements lexing for identifiers (foo, myvar, etc.) used in the JavaScript programing language.
 
-use super::{Cursor, Error, Tokenizer};
+use super::{Cursor, Error, StringLiteral, Tokenizer};
 use crate::{
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::{
         ast::{Keyword, Position, Span},
         lexer::{Token, TokenKind},
@@ -11,7 +11,6 @@ use crate::{
 use boa_unicode::UnicodeProperties;
 use core::convert::TryFrom;
 use std::io::Read;
-use std::str;
 
 const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
     "eval",
@@ -84,22 +83,44 @@ impl<R> Tokenizer<R> for Identifier {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("Identifier", "Lexing");
-
-        let mut init_buf = [0u8; 4];
-        let mut buf = Vec::new();
-        self.init.encode_utf8(&mut init_buf);
-        buf.extend(init_buf.iter().take(self.init.len_utf8()));
-
-        cursor.take_while_char_pred(&mut buf, &Self::is_identifier_part)?;
-
-        let token_str = unsafe { str::from_utf8_unchecked(buf.as_slice()) };
-        let tk = match token_str {
-            "true" => TokenKind::BooleanLiteral(true),
-            "false" => TokenKind::BooleanLiteral(false),
-            "null" => TokenKind::NullLiteral,
-            slice => {
-                if let Ok(keyword) = slice.parse() {
+        let _timer = BoaProfiler::global().start_event("Identifier", category::LEXING);
+
+        let mut contains_escaped_chars = false;
+        let mut buf = String::new();
+
+        if self.init == '\\' {
+            // The identifier starts with an escape sequence: \uXXXX or \u{X..X}
+            contains_escaped_chars = true;
+            buf.push(Self::take_identifier_escape(cursor, start_pos, true)?);
+        } else {
+            buf.push(self.init);
+        }
+
+        loop {
+            match cursor.peek_char()? {
+                Some(0x005C /* \ */) => {
+                    let escape_pos = cursor.pos();
+                    let _ = cursor.next_char()?;
+                    contains_escaped_chars = true;
+                    buf.push(Self::take_identifier_escape(cursor, escape_pos, false)?);
+                }
+                Some(ch) if Self::is_identifier_part(ch) => {
+                    let _ = cursor.next_char()?;
+                    // `is_identifier_part` only accepts valid code points.
+                    buf.push(char::try_from(ch).expect("invalid identifier part"));
+                }
+                _ => break,
+            }
+        }
+
+        let tk = match buf.as_str() {
+            // Reserved words written with unicode escapes are never treated as the keyword or the
+            // literal they spell.
+            "true" if !contains_escaped_chars => TokenKind::BooleanLiteral(true),
+            "false" if !contains_escaped_chars => TokenKind::BooleanLiteral(false),
+            "null" if !contains_escaped_chars => TokenKind::NullLiteral,
+            slice => match slice.parse() {
+                Ok(keyword) if !contains_escaped_chars => {
                     if cursor.strict_mode() && keyword == Keyword::With {
                         return Err(Error::Syntax(
                             "using 'with' statement not allowed in strict mode".into(),
@@ -107,7 +128,8 @@ impl<R> Tokenizer<R> for Identifier {
                         ));
                     }
                     TokenKind::Keyword(keyword)
-                } else {
+                }
+                _ => {
                     if cursor.strict_mode() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&slice) {
                         return Err(Error::Syntax(
                             format!(
@@ -120,9 +142,57 @@ impl<R> Tokenizer<R> for Identifier {
                     }
                     TokenKind::identifier(slice)
                 }
-            }
+            },
         };
 
         Ok(Token::new(tk, Span::new(start_pos, cursor.pos())))
     }
 }
+
+impl Identifier {
+    /// Decodes a unicode escape sequence inside an identifier, assuming that the starting `\` has
+    /// already been consumed.
+    ///
+    /// The decoded code point must be a valid `IdentifierStart` if it's the first character of
+    /// the identifier, or a valid `IdentifierPart` otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-identifier-names-static-semantics-early-errors
+    fn take_identifier_escape<R>(
+        cursor: &mut Cursor<R>,
+        start_pos: Position,
+        is_start: bool,
+    ) -> Result<char, Error>
+    where
+        R: Read,
+    {
+        if !cursor.next_is(b'u')? {
+            return Err(Error::syntax(
+                "invalid escape sequence in identifier",
+                start_pos,
+            ));
+        }
+
+        let code_point = StringLiteral::take_unicode_escape_sequence(cursor, start_pos)?;
+
+        let is_valid = if is_start {
+            Self::is_identifier_start(code_point)
+        } else {
+            Self::is_identifier_part(code_point)
+        };
+
+        match char::try_from(code_point) {
+            Ok(ch) if is_valid => Ok(ch),
+            _ => Err(Error::syntax(
+                format!(
+                    "invalid identifier {} '\\u{{{:X}}}' in escape sequence",
+                    if is_start { "start" } else { "part" },
+                    code_point
+                ),
+                start_pos,
+            )),
+        }
+    }
+}
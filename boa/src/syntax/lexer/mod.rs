@@ -40,7 +40,10 @@ use self::{
     string::StringLiteral,
     template::TemplateLiteral,
 };
-use crate::syntax::ast::{Punctuator, Span};
+use crate::{
+    profiler::category,
+    syntax::ast::{Punctuator, Span},
+};
 pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
 use core::convert::TryFrom;
 pub use error::Error;
@@ -59,6 +62,10 @@ trait Tokenizer<R> {
 pub struct Lexer<R> {
     cursor: Cursor<R>,
     goal_symbol: InputElement,
+    source_goal: GoalSymbol,
+    /// Whether only whitespace and comments were found since the last line terminator, or since
+    /// the start of the input.
+    line_start: bool,
 }
 
 impl<R> Lexer<R> {
@@ -91,6 +98,18 @@ impl<R> Lexer<R> {
         self.goal_symbol
     }
 
+    /// Sets whether the source code is a script or a module.
+    #[inline]
+    pub fn set_source_goal(&mut self, goal: GoalSymbol) {
+        self.source_goal = goal;
+    }
+
+    /// Gets whether the source code is a script or a module.
+    #[inline]
+    pub fn source_goal(&self) -> GoalSymbol {
+        self.source_goal
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.cursor.strict_mode()
@@ -110,6 +129,8 @@ impl<R> Lexer<R> {
         Self {
             cursor: Cursor::new(reader),
             goal_symbol: Default::default(),
+            source_goal: Default::default(),
+            line_start: true,
         }
     }
 
@@ -124,7 +145,7 @@ impl<R> Lexer<R> {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("lex_slash_token", "Lexing");
+        let _timer = BoaProfiler::global().start_event("lex_slash_token", category::LEXING);
 
         if let Some(c) = self.cursor.peek()? {
             match c {
@@ -157,19 +178,38 @@ impl<R> Lexer<R> {
                         }
                         InputElement::RegExp | InputElement::RegExpOrTemplateTail => {
                             // Can be a regular expression.
-                            RegexLiteral.lex(&mut self.cursor, start)
+                            RegexLiteral::new(false).lex(&mut self.cursor, start)
                         }
                     }
                 }
             }
         } else {
-            Err(Error::syntax(
-                "Abrupt end: Expecting Token /,*,= or regex",
-                start,
+            // A slash at the end of the input can only be a division.
+            Ok(Token::new(
+                Punctuator::Div.into(),
+                Span::new(start, self.cursor.pos()),
             ))
         }
     }
 
+    /// Lexes a regular expression literal, after its starting `/` was lexed as a `Div` punctuator,
+    /// or its starting `/=` was lexed as an `AssignDiv` punctuator.
+    ///
+    /// This is used by the parser when it finds a division punctuator in a position where only a
+    /// regular expression is allowed, which happens if the punctuator was lexed using the `Div`
+    /// goal symbol.
+    pub(crate) fn lex_regex(
+        &mut self,
+        start: Position,
+        after_assign_div: bool,
+    ) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        self.set_goal(InputElement::RegExp);
+        RegexLiteral::new(after_assign_div).lex(&mut self.cursor, start)
+    }
+
     /// Retrieves the next token from the lexer.
     // We intentionally don't implement Iterator trait as Result<Option> is cleaner to handle.
     #[allow(clippy::should_implement_trait)]
@@ -177,7 +217,7 @@ impl<R> Lexer<R> {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("next()", "Lexing");
+        let _timer = BoaProfiler::global().start_event("next()", category::LEXING);
 
         let (start, next_ch) = loop {
             let start = self.cursor.pos();
@@ -207,6 +247,25 @@ impl<R> Lexer<R> {
                     Punctuator::Colon.into(),
                     Span::new(start, self.cursor.pos()),
                 )),
+                // A hashbang comment, only allowed at the very start of the input.
+                '#' if start == Position::new(1, 1) && self.cursor.next_is(b'!')? => {
+                    SingleLineComment.lex(&mut self.cursor, start)
+                }
+                // `<!--` starts a single line comment in scripts.
+                '<' if self.source_goal == GoalSymbol::Script
+                    && self.cursor.peek_n(3)? == u32::from_le_bytes([b'!', b'-', b'-', 0]) =>
+                {
+                    self.skip_bytes(3)?;
+                    SingleLineComment.lex(&mut self.cursor, start)
+                }
+                // `-->` starts a single line comment in scripts, at the start of a line.
+                '-' if self.source_goal == GoalSymbol::Script
+                    && self.line_start
+                    && self.cursor.peek_n(2)? == u32::from_le_bytes([b'-', b'>', 0, 0]) =>
+                {
+                    self.skip_bytes(2)?;
+                    SingleLineComment.lex(&mut self.cursor, start)
+                }
                 '.' => {
                     if self.cursor.peek()?.map(|c| (b'0'..=b'9').contains(&c)) == Some(true) {
                         NumberLiteral::new(next_ch as u8).lex(&mut self.cursor, start)
@@ -252,6 +311,7 @@ impl<R> Lexer<R> {
                 _ if Identifier::is_identifier_start(c as u32) => {
                     Identifier::new(c).lex(&mut self.cursor, start)
                 }
+                '\\' => Identifier::new(c).lex(&mut self.cursor, start),
                 _ => {
                     let details = format!(
                         "unexpected '{}' at line {}, column {}",
@@ -263,11 +323,17 @@ impl<R> Lexer<R> {
                 }
             }?;
 
-            if token.kind() == &TokenKind::Comment {
+            match token.kind() {
                 // Skip comment
-                self.next()
-            } else {
-                Ok(Some(token))
+                TokenKind::Comment => self.next(),
+                TokenKind::LineTerminator => {
+                    self.line_start = true;
+                    Ok(Some(token))
+                }
+                _ => {
+                    self.line_start = false;
+                    Ok(Some(token))
+                }
             }
         } else {
             Err(Error::syntax(
@@ -288,6 +354,40 @@ impl<R> Lexer<R> {
     {
         TemplateLiteral.lex(&mut self.cursor, start)
     }
+
+    /// Skips the given number of bytes, which must have been peeked.
+    fn skip_bytes(&mut self, n: usize) -> Result<(), Error>
+    where
+        R: Read,
+    {
+        for _ in 0..n {
+            let _ = self.cursor.next_byte()?.expect("peeked byte vanished");
+        }
+        Ok(())
+    }
+}
+
+/// The goal symbol of the source code, which tells apart scripts and modules.
+///
+/// The HTML-like comments (`<!--` and `-->`) of web browsers are only allowed in scripts. In
+/// modules, they are lexed as the punctuators they are made of.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-html-like-comments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalSymbol {
+    /// The source code is a script.
+    Script,
+    /// The source code is a module.
+    Module,
+}
+
+impl Default for GoalSymbol {
+    fn default() -> Self {
+        GoalSymbol::Script
+    }
 }
 
 /// ECMAScript goal symbols.
@@ -3,7 +3,7 @@
 use super::{Cursor, Error, TokenKind, Tokenizer};
 use crate::{
     builtins::BigInt,
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::{
         ast::{Position, Span},
         lexer::{token::Numeric, Token},
@@ -182,7 +182,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("NumberLiteral", "Lexing");
+        let _timer = BoaProfiler::global().start_event("NumberLiteral", category::LEXING);
 
         let mut buf = vec![self.init];
 
@@ -3,7 +3,7 @@
 use super::{Cursor, Error, Tokenizer};
 use crate::syntax::lexer::TokenKind;
 use crate::{
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::{
         ast::{Position, Punctuator, Span},
         lexer::Token,
@@ -14,44 +14,31 @@ use std::io::Read;
 /// `vop` tests the next token to see if we're on an assign operation of just a plain binary operation.
 ///
 /// If the next value is not an assignment operation it will pattern match  the provided values and return the corresponding token.
+///
+/// The longest operator is always taken, and the end of the input ends the operator.
 macro_rules! vop {
     ($cursor:ident, $assign_op:expr, $op:expr) => ({
         match $cursor.peek()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             }
-            Some(_) => $op,
+            _ => $op,
         }
     });
     ($cursor:ident, $assign_op:expr, $op:expr, {$($case:pat => $block:expr), +}) => ({
         match $cursor.peek()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             },
             $($case => {
                 $cursor.next_byte()?.expect("Token vanished");
-                $cursor.next_column();
                 $block
             })+,
             _ => $op,
         }
     });
-    ($cursor:ident, $op:expr, {$($case:pat => $block:expr),+}) => {
-        match $cursor.peek().ok_or_else(|| Error::syntax("could not preview next value", $cursor.pos()))? {
-            $($case => {
-                $cursor.next_byte()?;
-                $cursor.next_column();
-                $block
-            })+,
-            _ => $op
-        }
-    }
 }
 
 /// The `op` macro handles binary operations or assignment operations and converts them into tokens.
@@ -98,7 +85,7 @@ impl<R> Tokenizer<R> for Operator {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("Operator", "Lexing");
+        let _timer = BoaProfiler::global().start_event("Operator", category::LEXING);
 
         match self.init {
             b'*' => op!(cursor, start_pos, Ok(Punctuator::AssignMul), Ok(Punctuator::Mul), {
@@ -134,6 +121,14 @@ impl<R> Tokenizer<R> for Operator {
                         Ok(Punctuator::Coalesce)
                     )
                 }
+                // `?.5` is a conditional operator followed by a number.
+                Some(b'.') if !((cursor.peek_n(2)? >> 8) as u8).is_ascii_digit() => {
+                    let _ = cursor.next_byte()?.expect(". vanished");
+                    Ok(Token::new(
+                        TokenKind::Punctuator(Punctuator::Optional),
+                        Span::new(start_pos, cursor.pos()),
+                    ))
+                }
                 _ => Ok(Token::new(
                     TokenKind::Punctuator(Punctuator::Question),
                     Span::new(start_pos, cursor.pos()),
@@ -2,7 +2,7 @@
 
 use super::{Cursor, Error, Span, Tokenizer};
 use crate::{
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::{
         ast::Position,
         lexer::{Token, TokenKind},
@@ -32,16 +32,36 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// [spec]: https://www.ecma-international.org/ecma-262/#sec-literals-regular-expression-literals
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
 #[derive(Debug, Clone, Copy)]
-pub(super) struct RegexLiteral;
+pub(super) struct RegexLiteral {
+    body_starts_with_eq: bool,
+}
+
+impl RegexLiteral {
+    /// Creates a new regex literal lexer.
+    ///
+    /// If `body_starts_with_eq` is `true`, the `=` following the initial `/` is expected to be
+    /// already consumed, since the `/=` was first lexed as an `AssignDiv` punctuator.
+    pub(super) fn new(body_starts_with_eq: bool) -> Self {
+        Self {
+            body_starts_with_eq,
+        }
+    }
+}
 
 impl<R> Tokenizer<R> for RegexLiteral {
     fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("RegexLiteral", "Lexing");
+        let _timer = BoaProfiler::global().start_event("RegexLiteral", category::LEXING);
 
         let mut body = Vec::new();
+        if self.body_starts_with_eq {
+            body.push(b'=');
+        }
+
+        // A `/` inside of a class (`[...]`) does not terminate the body.
+        let mut in_class = false;
 
         // Lex RegularExpressionBody.
         loop {
@@ -55,7 +75,15 @@ impl<R> Tokenizer<R> for RegexLiteral {
                 }
                 Some(b) => {
                     match b {
-                        b'/' => break, // RegularExpressionBody finished.
+                        b'/' if !in_class => break, // RegularExpressionBody finished.
+                        b'[' => {
+                            in_class = true;
+                            body.push(b);
+                        }
+                        b']' => {
+                            in_class = false;
+                            body.push(b);
+                        }
                         b'\n' | b'\r' => {
                             // Not allowed in Regex literal.
                             return Err(Error::syntax(
@@ -2,7 +2,7 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::{
         ast::{Position, Punctuator, Span},
         lexer::Token,
@@ -35,21 +35,16 @@ impl<R> Tokenizer<R> for SpreadLiteral {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("SpreadLiteral", "Lexing");
+        let _timer = BoaProfiler::global().start_event("SpreadLiteral", category::LEXING);
 
-        // . or ...
-        if cursor.next_is(b'.')? {
-            if cursor.next_is(b'.')? {
-                Ok(Token::new(
-                    Punctuator::Spread.into(),
-                    Span::new(start_pos, cursor.pos()),
-                ))
-            } else {
-                Err(Error::syntax(
-                    "Expecting Token '.' as part of spread",
-                    cursor.pos(),
-                ))
-            }
+        // . or ..., where `..` is two dots.
+        if cursor.peek_n(2)? == u32::from_le_bytes([b'.', b'.', 0, 0]) {
+            let _ = cursor.next_byte()?.expect(". vanished");
+            let _ = cursor.next_byte()?.expect(". vanished");
+            Ok(Token::new(
+                Punctuator::Spread.into(),
+                Span::new(start_pos, cursor.pos()),
+            ))
         } else {
             Ok(Token::new(
                 Punctuator::Dot.into(),
@@ -2,16 +2,13 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::{
         ast::{Position, Span},
         lexer::{Token, TokenKind},
     },
 };
-use std::{
-    io::{self, ErrorKind, Read},
-    str,
-};
+use std::io::{self, ErrorKind, Read};
 
 /// String literal lexing.
 ///
@@ -81,10 +78,19 @@ impl<R> Tokenizer<R> for StringLiteral {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("StringLiteral", "Lexing");
+        let _timer = BoaProfiler::global().start_event("StringLiteral", category::LEXING);
 
         let (lit, span) =
-            Self::take_string_characters(cursor, start_pos, self.terminator, cursor.strict_mode())?;
+            Self::take_string_characters(cursor, start_pos, self.terminator, cursor.strict_mode())
+                .map_err(|err| match err {
+                    // A string literal can't continue on the next lines, so reaching the end of
+                    // the input in an escape sequence is a syntax error like any other.
+                    Error::IO(ref e) if e.kind() == ErrorKind::UnexpectedEof => Error::syntax(
+                        "unterminated string literal: unexpected end of input",
+                        start_pos,
+                    ),
+                    err => err,
+                })?;
 
         Ok(Token::new(TokenKind::string_literal(lit), span))
     }
@@ -125,9 +131,14 @@ impl StringLiteral {
                 Some(0x0022 /* " */) if terminator == StringTerminator::DoubleQuote => break,
                 Some(0x005C /* \ */) => {
                     let _timer = BoaProfiler::global()
-                        .start_event("StringLiteral - escape sequence", "Lexing");
+                        .start_event("StringLiteral - escape sequence", category::LEXING);
 
-                    if let Some(escape_value) = Self::take_escape_sequence_or_line_continuation(cursor, ch_start_pos, is_strict_mode, false)? {
+                    if let Some(escape_value) = Self::take_escape_sequence_or_line_continuation(
+                        cursor,
+                        ch_start_pos,
+                        is_strict_mode,
+                        false,
+                    )? {
                         buf.push_code_point(escape_value);
                     }
                 }
@@ -136,11 +147,17 @@ impl StringLiteral {
                 Some(ch) if !Self::is_line_terminator(ch) => {
                     buf.push_code_point(ch);
                 }
-                _ => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "unterminated string literal",
-                    )));
+                Some(_) => {
+                    return Err(Error::syntax(
+                        "unterminated string literal: line terminators must be escaped",
+                        ch_start_pos,
+                    ));
+                }
+                None => {
+                    return Err(Error::syntax(
+                        "unterminated string literal: unexpected end of input",
+                        ch_start_pos,
+                    ));
                 }
             }
         }
@@ -248,13 +265,9 @@ impl StringLiteral {
             let mut code_point_buf = Vec::with_capacity(6);
             cursor.take_until(b'}', &mut code_point_buf)?;
 
-            let code_point = str::from_utf8(code_point_buf.as_slice())
-                .ok()
-                .and_then(|code_point_str| {
-                    // The `code_point_str` should represent a single unicode codepoint, convert to u32
-                    u32::from_str_radix(&code_point_str, 16).ok()
-                })
-                .ok_or_else(|| {
+            // The `code_point_buf` should represent a single unicode codepoint, convert to u32
+            let code_point =
+                Self::parse_hex_digits(code_point_buf.as_slice()).ok_or_else(|| {
                     Error::syntax("malformed Unicode character escape sequence", start_pos)
                 })?;
 
@@ -274,12 +287,10 @@ impl StringLiteral {
             cursor.fill_bytes(&mut code_point_utf8_bytes)?;
 
             // Convert to u16
-            let code_point = str::from_utf8(&code_point_utf8_bytes)
-                .ok()
-                .and_then(|code_point_str| u16::from_str_radix(&code_point_str, 16).ok())
+            let code_point = Self::parse_hex_digits(&code_point_utf8_bytes)
                 .ok_or_else(|| Error::syntax("invalid Unicode escape sequence", start_pos))?;
 
-            Ok(code_point as u32)
+            Ok(code_point)
         }
     }
 
@@ -293,12 +304,26 @@ impl StringLiteral {
     {
         let mut code_point_utf8_bytes = [0u8; 2];
         cursor.fill_bytes(&mut code_point_utf8_bytes)?;
-        let code_point = str::from_utf8(&code_point_utf8_bytes)
-            .ok()
-            .and_then(|code_point_str| u16::from_str_radix(&code_point_str, 16).ok())
+        let code_point = Self::parse_hex_digits(&code_point_utf8_bytes)
             .ok_or_else(|| Error::syntax("invalid Hexadecimal escape sequence", start_pos))?;
 
-        Ok(code_point as u32)
+        Ok(code_point)
+    }
+
+    /// Parses a non-empty sequence of ASCII hexadecimal digits.
+    ///
+    /// Unlike `u32::from_str_radix`, this will not accept a leading sign, and it will return `None`
+    /// if the value doesn't fit in a `u32`.
+    #[inline]
+    fn parse_hex_digits(digits: &[u8]) -> Option<u32> {
+        if digits.is_empty() {
+            return None;
+        }
+
+        digits.iter().try_fold(0u32, |acc, &digit| {
+            let value = (digit as char).to_digit(16)?;
+            acc.checked_mul(16)?.checked_add(value)
+        })
     }
 
     #[inline]
@@ -2,7 +2,7 @@
 
 use super::{Cursor, Error, Tokenizer};
 use crate::{
-    profiler::BoaProfiler,
+    profiler::{category, BoaProfiler},
     syntax::lexer::string::{StringLiteral, UTF16CodeUnitsBuffer},
     syntax::{
         ast::{Position, Span},
@@ -29,7 +29,7 @@ impl<R> Tokenizer<R> for TemplateLiteral {
     where
         R: Read,
     {
-        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Lexing");
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", category::LEXING);
 
         let mut buf = Vec::new();
         loop {
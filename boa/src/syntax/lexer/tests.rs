@@ -70,6 +70,98 @@ fn check_multi_line_comment() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn check_hashbang_comment() {
+    let s = "#!/usr/bin/env node\nvar x;";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let line_terminator = lexer.next().unwrap().unwrap();
+    assert_eq!(line_terminator.kind(), &TokenKind::LineTerminator);
+    assert_eq!(line_terminator.span(), span((1, 20), (2, 1)));
+
+    let var = lexer.next().unwrap().unwrap();
+    assert_eq!(var.kind(), &TokenKind::Keyword(Keyword::Var));
+    assert_eq!(var.span(), span((2, 1), (2, 4)));
+
+    let expected = [
+        TokenKind::identifier("x"),
+        TokenKind::Punctuator(Punctuator::Semicolon),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_hashbang_only_at_start() {
+    for s in &[" #!/usr/bin/env node", "\n#!/usr/bin/env node", "x #!"] {
+        let mut lexer = Lexer::new(s.as_bytes());
+        let result = std::iter::from_fn(|| lexer.next().transpose()).collect::<Result<Vec<_>, _>>();
+        assert!(result.is_err(), "`{}` was lexed as a hashbang", s);
+    }
+}
+
+#[test]
+fn check_html_like_comments() {
+    let s = "a <!-- b\n--> c\n  --> d\n/* \n */ --> e\nf";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::identifier("a"),
+        TokenKind::LineTerminator,
+        TokenKind::LineTerminator,
+        TokenKind::LineTerminator,
+        TokenKind::LineTerminator,
+        TokenKind::LineTerminator,
+        TokenKind::identifier("f"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_html_close_comment_mid_line() {
+    // `-->` is a decrement and a greater than when it is not at the start of a line.
+    let s = "a; --> b\nx /**/ --> y";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Semicolon),
+        TokenKind::Punctuator(Punctuator::Dec),
+        TokenKind::Punctuator(Punctuator::GreaterThan),
+        TokenKind::identifier("b"),
+        TokenKind::LineTerminator,
+        TokenKind::identifier("x"),
+        TokenKind::Punctuator(Punctuator::Dec),
+        TokenKind::Punctuator(Punctuator::GreaterThan),
+        TokenKind::identifier("y"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_html_like_comments_in_modules() {
+    let s = "#!/usr/bin/env node\na <!-- b\n--> c";
+    let mut lexer = Lexer::new(s.as_bytes());
+    lexer.set_source_goal(GoalSymbol::Module);
+
+    let expected = [
+        TokenKind::LineTerminator,
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::LessThan),
+        TokenKind::Punctuator(Punctuator::Not),
+        TokenKind::Punctuator(Punctuator::Dec),
+        TokenKind::identifier("b"),
+        TokenKind::LineTerminator,
+        TokenKind::Punctuator(Punctuator::Dec),
+        TokenKind::Punctuator(Punctuator::GreaterThan),
+        TokenKind::identifier("c"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
 #[test]
 fn check_identifier() {
     let s = "x x1 _x $x __ $$ Ѐ ЀЀ x\u{200C}\u{200D}";
@@ -116,6 +208,101 @@ fn check_invalid_identifier_part() {
     }
 }
 
+#[test]
+fn check_identifier_unicode() {
+    let s = "café π ℘x x\u{0301} a\u{200C}b\u{200D}";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::identifier("café"),
+        TokenKind::identifier("π"),
+        TokenKind::identifier("℘x"),
+        TokenKind::identifier("x\u{0301}"),
+        TokenKind::identifier("a\u{200C}b\u{200D}"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_identifier_escapes() {
+    let s = "abc \\u0061bc \\u{61}\\u{62}\\u{63} $ _ _\u{200C}$\\u{200D} caf\\u{E9} \\u{1D49C}";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::identifier("abc"),
+        TokenKind::identifier("abc"),
+        TokenKind::identifier("abc"),
+        TokenKind::identifier("$"),
+        TokenKind::identifier("_"),
+        TokenKind::identifier("_\u{200C}$\u{200D}"),
+        TokenKind::identifier("café"),
+        TokenKind::identifier("\u{1D49C}"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_escaped_keywords_are_identifiers() {
+    let s = "\\u0069f i\\u{66} tru\\u0065 \\u{6E}ull \\u{66}unction if";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::identifier("if"),
+        TokenKind::identifier("if"),
+        TokenKind::identifier("true"),
+        TokenKind::identifier("null"),
+        TokenKind::identifier("function"),
+        TokenKind::Keyword(Keyword::If),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_invalid_identifier_escapes() {
+    let invalid_identifiers = [
+        // Not a unicode escape.
+        "\\x61",
+        "a\\x62",
+        // Digits are not valid identifier starts.
+        "\\u0031a",
+        // ZWJ/ZWNJ are valid identifier parts, but not identifier starts.
+        "\\u200C",
+        "\\u{200D}",
+        // Not valid identifier parts.
+        "a\\u{20}",
+        "a\\u002E",
+        // Lone surrogates are not valid code points.
+        "a\\uD800",
+        // Malformed escapes.
+        "\\u00",
+        "\\u{110000}",
+        "\\u{zz}",
+    ];
+
+    for s in invalid_identifiers.iter() {
+        let mut lexer = Lexer::new(s.as_bytes());
+        lexer
+            .next()
+            .expect_err("Invalid identifier escape not rejected as expected");
+    }
+}
+
+#[test]
+fn check_invalid_non_ascii_identifier_start() {
+    // ARABIC-INDIC DIGIT THREE and FULLWIDTH DIGIT ONE are digits, not identifier starts.
+    let invalid_identifier_starts = ["\u{0663}abc", "\u{FF11}abc"];
+
+    for s in invalid_identifier_starts.iter() {
+        let mut lexer = Lexer::new(s.as_bytes());
+        lexer
+            .next()
+            .expect_err("Invalid identifier start not rejected as expected");
+    }
+}
+
 #[test]
 fn check_string() {
     let s = "'aaa' \"bbb\"";
@@ -155,7 +342,7 @@ fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
     let s = "{ ( ) [ ] . ... ; , < > <= >= == != === !== \
              + - * % -- << >> >>> & | ^ ! ~ && || ? : \
-             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||=";
+             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||= ?.";
     let mut lexer = Lexer::new(s.as_bytes());
 
     let expected = [
@@ -212,11 +399,264 @@ fn check_punctuators() {
         TokenKind::Punctuator(Punctuator::AssignCoalesce),
         TokenKind::Punctuator(Punctuator::AssignBoolAnd),
         TokenKind::Punctuator(Punctuator::AssignBoolOr),
+        TokenKind::Punctuator(Punctuator::Optional),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+/// Every punctuator, with its source text.
+const PUNCTUATORS: &[(&str, Punctuator)] = &[
+    ("{", Punctuator::OpenBlock),
+    ("}", Punctuator::CloseBlock),
+    ("(", Punctuator::OpenParen),
+    (")", Punctuator::CloseParen),
+    ("[", Punctuator::OpenBracket),
+    ("]", Punctuator::CloseBracket),
+    (".", Punctuator::Dot),
+    ("...", Punctuator::Spread),
+    ("?.", Punctuator::Optional),
+    (";", Punctuator::Semicolon),
+    (",", Punctuator::Comma),
+    ("<", Punctuator::LessThan),
+    (">", Punctuator::GreaterThan),
+    ("<=", Punctuator::LessThanOrEq),
+    (">=", Punctuator::GreaterThanOrEq),
+    ("==", Punctuator::Eq),
+    ("!=", Punctuator::NotEq),
+    ("===", Punctuator::StrictEq),
+    ("!==", Punctuator::StrictNotEq),
+    ("+", Punctuator::Add),
+    ("-", Punctuator::Sub),
+    ("*", Punctuator::Mul),
+    ("/", Punctuator::Div),
+    ("%", Punctuator::Mod),
+    ("**", Punctuator::Exp),
+    ("++", Punctuator::Inc),
+    ("--", Punctuator::Dec),
+    ("<<", Punctuator::LeftSh),
+    (">>", Punctuator::RightSh),
+    (">>>", Punctuator::URightSh),
+    ("&", Punctuator::And),
+    ("|", Punctuator::Or),
+    ("^", Punctuator::Xor),
+    ("!", Punctuator::Not),
+    ("~", Punctuator::Neg),
+    ("&&", Punctuator::BoolAnd),
+    ("||", Punctuator::BoolOr),
+    ("??", Punctuator::Coalesce),
+    ("?", Punctuator::Question),
+    (":", Punctuator::Colon),
+    ("=", Punctuator::Assign),
+    ("=>", Punctuator::Arrow),
+    ("+=", Punctuator::AssignAdd),
+    ("-=", Punctuator::AssignSub),
+    ("*=", Punctuator::AssignMul),
+    ("/=", Punctuator::AssignDiv),
+    ("%=", Punctuator::AssignMod),
+    ("**=", Punctuator::AssignPow),
+    ("<<=", Punctuator::AssignLeftSh),
+    (">>=", Punctuator::AssignRightSh),
+    (">>>=", Punctuator::AssignURightSh),
+    ("&=", Punctuator::AssignAnd),
+    ("|=", Punctuator::AssignOr),
+    ("^=", Punctuator::AssignXor),
+    ("&&=", Punctuator::AssignBoolAnd),
+    ("||=", Punctuator::AssignBoolOr),
+    ("??=", Punctuator::AssignCoalesce),
+];
+
+#[test]
+fn check_punctuators_between_identifiers() {
+    for (text, punctuator) in PUNCTUATORS {
+        assert_eq!(&punctuator.to_string(), text);
+
+        for src in &[format!("a {} b", text), format!("a{}b", text)] {
+            let mut lexer = Lexer::new(src.as_bytes());
+            // `/` after an identifier is a division.
+            lexer.set_goal(InputElement::Div);
+            let tokens: Vec<Token> = (0..3)
+                .map(|_| lexer.next().unwrap().expect("missing token"))
+                .collect();
+            assert!(lexer.next().unwrap().is_none(), "extra token in `{}`", src);
+
+            assert_eq!(
+                tokens[0].kind(),
+                &TokenKind::identifier("a"),
+                "in `{}`",
+                src
+            );
+            assert_eq!(
+                tokens[1].kind(),
+                &TokenKind::Punctuator(*punctuator),
+                "in `{}`",
+                src
+            );
+            assert_eq!(
+                tokens[2].kind(),
+                &TokenKind::identifier("b"),
+                "in `{}`",
+                src
+            );
+
+            let gap = if src.contains(' ') { 1 } else { 0 };
+            assert_eq!(
+                tokens[1].span(),
+                span((1, 2 + gap), (1, 2 + gap + text.len() as u32)),
+                "in `{}`",
+                src
+            );
+            assert_eq!(
+                tokens[2].span().start().column_number(),
+                2 + 2 * gap + text.len() as u32,
+                "in `{}`",
+                src
+            );
+        }
+    }
+}
+
+#[test]
+fn check_punctuators_maximal_munch() {
+    let p = |punctuator| TokenKind::Punctuator(punctuator);
+    let a = || TokenKind::identifier("a");
+    let b = || TokenKind::identifier("b");
+
+    for (src, expected) in &[
+        (
+            "a ?? = b",
+            vec![a(), p(Punctuator::Coalesce), p(Punctuator::Assign), b()],
+        ),
+        (
+            "a ? ? b",
+            vec![a(), p(Punctuator::Question), p(Punctuator::Question), b()],
+        ),
+        ("a--b", vec![a(), p(Punctuator::Dec), b()]),
+        (
+            "a- -b",
+            vec![a(), p(Punctuator::Sub), p(Punctuator::Sub), b()],
+        ),
+        (
+            "a---b",
+            vec![a(), p(Punctuator::Dec), p(Punctuator::Sub), b()],
+        ),
+        (
+            "a+++b",
+            vec![a(), p(Punctuator::Inc), p(Punctuator::Add), b()],
+        ),
+        ("a<<=b", vec![a(), p(Punctuator::AssignLeftSh), b()]),
+        ("a<<b", vec![a(), p(Punctuator::LeftSh), b()]),
+        ("a<b", vec![a(), p(Punctuator::LessThan), b()]),
+        (
+            "a< <b",
+            vec![a(), p(Punctuator::LessThan), p(Punctuator::LessThan), b()],
+        ),
+        ("a>>>=b", vec![a(), p(Punctuator::AssignURightSh), b()]),
+        (
+            "a>>>>b",
+            vec![
+                a(),
+                p(Punctuator::URightSh),
+                p(Punctuator::GreaterThan),
+                b(),
+            ],
+        ),
+        ("a**=b", vec![a(), p(Punctuator::AssignPow), b()]),
+        (
+            "a***b",
+            vec![a(), p(Punctuator::Exp), p(Punctuator::Mul), b()],
+        ),
+        (
+            "a====b",
+            vec![a(), p(Punctuator::StrictEq), p(Punctuator::Assign), b()],
+        ),
+        ("a=>b", vec![a(), p(Punctuator::Arrow), b()]),
+        (
+            "a==>b",
+            vec![a(), p(Punctuator::Eq), p(Punctuator::GreaterThan), b()],
+        ),
+        ("a&&=b", vec![a(), p(Punctuator::AssignBoolAnd), b()]),
+        (
+            "a&&&b",
+            vec![a(), p(Punctuator::BoolAnd), p(Punctuator::And), b()],
+        ),
+        ("a||=b", vec![a(), p(Punctuator::AssignBoolOr), b()]),
+        ("a??=b", vec![a(), p(Punctuator::AssignCoalesce), b()]),
+        (
+            "a???b",
+            vec![a(), p(Punctuator::Coalesce), p(Punctuator::Question), b()],
+        ),
+        ("a?.b", vec![a(), p(Punctuator::Optional), b()]),
+        (
+            "a??.b",
+            vec![a(), p(Punctuator::Coalesce), p(Punctuator::Dot), b()],
+        ),
+        ("...a", vec![p(Punctuator::Spread), a()]),
+        (
+            ". . .a",
+            vec![
+                p(Punctuator::Dot),
+                p(Punctuator::Dot),
+                p(Punctuator::Dot),
+                a(),
+            ],
+        ),
+        (
+            "a..b",
+            vec![a(), p(Punctuator::Dot), p(Punctuator::Dot), b()],
+        ),
+        (
+            "....a",
+            vec![p(Punctuator::Spread), p(Punctuator::Dot), a()],
+        ),
+        ("a!==b", vec![a(), p(Punctuator::StrictNotEq), b()]),
+        ("a!b", vec![a(), p(Punctuator::Not), b()]),
+    ] {
+        let mut lexer = Lexer::new(src.as_bytes());
+        for expect in expected {
+            assert_eq!(
+                lexer.next().unwrap().expect("missing token").kind(),
+                expect,
+                "in `{}`",
+                src
+            );
+        }
+        assert!(lexer.next().unwrap().is_none(), "extra token in `{}`", src);
+    }
+}
+
+#[test]
+fn check_optional_chaining_before_digit() {
+    // `a?.5:1` is a conditional expression, not an optional chain.
+    let mut lexer = Lexer::new(&b"a?.5:1"[..]);
+
+    let expected = [
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Question),
+        TokenKind::numeric_literal(0.5),
+        TokenKind::Punctuator(Punctuator::Colon),
+        TokenKind::numeric_literal(1),
     ];
 
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn check_punctuators_at_end_of_input() {
+    for (text, punctuator) in PUNCTUATORS {
+        let mut lexer = Lexer::new(text.as_bytes());
+        lexer.set_goal(InputElement::Div);
+        let token = lexer
+            .next()
+            .unwrap_or_else(|e| panic!("could not lex `{}`: {}", text, e))
+            .expect("missing token");
+
+        assert_eq!(token.kind(), &TokenKind::Punctuator(*punctuator));
+        assert_eq!(token.span(), span((1, 1), (1, 1 + text.len() as u32)));
+        assert!(lexer.next().unwrap().is_none(), "extra token in `{}`", text);
+    }
+}
+
 #[test]
 fn check_keywords() {
     // https://tc39.es/ecma262/#sec-keywords
@@ -578,6 +1018,18 @@ fn regex_literal_flags() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn regex_literal_slash_in_class() {
+    let mut lexer = Lexer::new(&br"/[/]\]/ /[\]/]/"[..]);
+
+    let expected = [
+        TokenKind::regular_expression_literal("[/]\\]", RegExpFlags::default()),
+        TokenKind::regular_expression_literal("[\\]/]", RegExpFlags::default()),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::new(&b"1+1"[..]);
@@ -965,6 +1417,123 @@ fn string_line_continuation() {
     expect_tokens(&mut lexer, &expected_tokens);
 }
 
+#[test]
+fn string_line_continuation_line_terminators() {
+    let test_cases = [
+        "'hello \\\nworld'",
+        "'hello \\\rworld'",
+        "'hello \\\r\nworld'",
+        "'hello \\\u{2028}world'",
+        "'hello \\\u{2029}world'",
+    ];
+
+    for s in test_cases.iter() {
+        let mut lexer = Lexer::new(s.as_bytes());
+
+        let expected_tokens = [TokenKind::StringLiteral("hello world".into())];
+
+        expect_tokens(&mut lexer, &expected_tokens);
+    }
+}
+
+#[test]
+fn string_escape_sequences() {
+    let test_cases = [
+        (r#"'\b\t\n\v\f\r'"#, "\u{8}\t\n\u{b}\u{c}\r"),
+        (r#"'\"\'\\'"#, "\"'\\"),
+        (r#"'\x41\x7a\x7A'"#, "Azz"),
+        (r#"'Aé'"#, "Aé"),
+        (r#"'\u{41}\u{0000041}'"#, "AA"),
+        (r#"'\u{1F600}'"#, "\u{1F600}"),
+        (r#"'\u{10FFFF}'"#, "\u{10FFFF}"),
+        (r#"'😀'"#, "\u{1F600}"),
+        (r#"'\0'"#, "\u{0}"),
+        (r#"'\0a'"#, "\u{0}a"),
+        (r#"'\a\c'"#, "ac"),
+        ("'a\u{2028}b\u{2029}c'", "a\u{2028}b\u{2029}c"),
+    ];
+
+    for (s, expected) in test_cases.iter() {
+        let mut lexer = Lexer::new(s.as_bytes());
+
+        let expected_tokens = [TokenKind::StringLiteral((*expected).into())];
+
+        expect_tokens(&mut lexer, &expected_tokens);
+    }
+}
+
+#[test]
+fn string_invalid_escape_sequences() {
+    let test_cases = [
+        r#"'\x4'"#,
+        r#"'\x+4'"#,
+        r#"'\xGG'"#,
+        r#"'\u004'"#,
+        r#"'\u+041'"#,
+        r#"'\u{}'"#,
+        r#"'\u{+41}'"#,
+        r#"'\u{110000}'"#,
+        r#"'\u{FFFFFFFFF}'"#,
+    ];
+
+    for s in test_cases.iter() {
+        let mut lexer = Lexer::new(s.as_bytes());
+
+        if let Error::Syntax(_, pos) = lexer
+            .next()
+            .expect_err("Invalid escape sequence not rejected as expected")
+        {
+            assert_eq!(pos, Position::new(1, 2));
+        } else {
+            panic!("invalid error type");
+        }
+    }
+}
+
+#[test]
+fn string_zero_escape_followed_by_digit_strict() {
+    let mut lexer = Lexer::new(&br#"'\08'"#[..]);
+    lexer.set_strict_mode(true);
+
+    if let Error::Syntax(_, pos) = lexer
+        .next()
+        .expect_err("Octal-escape in strict mode not rejected as expected")
+    {
+        assert_eq!(pos, Position::new(1, 2));
+    } else {
+        panic!("invalid error type");
+    }
+}
+
+#[test]
+fn string_unterminated() {
+    let test_cases = [
+        ("'hello\nworld'", Position::new(1, 7)),
+        ("\"hello\r\nworld\"", Position::new(1, 7)),
+        ("a = 'abc\n'", Position::new(1, 9)),
+        ("'hello", Position::new(1, 7)),
+        ("'hello\\\n", Position::new(2, 1)),
+    ];
+
+    for (s, expected_pos) in test_cases.iter() {
+        let mut lexer = Lexer::new(s.as_bytes());
+
+        let error = loop {
+            match lexer.next() {
+                Ok(Some(_)) => {}
+                Ok(None) => panic!("Unterminated string not rejected as expected"),
+                Err(e) => break e,
+            }
+        };
+
+        if let Error::Syntax(_, pos) = error {
+            assert_eq!(pos, *expected_pos);
+        } else {
+            panic!("invalid error type");
+        }
+    }
+}
+
 mod carriage_return {
     use super::*;
 
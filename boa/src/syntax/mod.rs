@@ -3,6 +3,7 @@
 pub mod ast;
 pub mod lexer;
 pub mod parser;
+mod resolver;
 
 pub use lexer::Lexer;
 pub use parser::Parser;
@@ -76,12 +76,18 @@ where
         self.lexer.set_goal(elm)
     }
 
-    /// Lexes the next tokens as a regex assuming that the starting '/' has already been consumed.
+    /// Lexes the next tokens as a regex assuming that the starting '/' (or '/=' if
+    /// `after_assign_div` is `true`) has already been consumed.
     #[inline]
-    pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
+    pub(super) fn lex_regex(
+        &mut self,
+        start: Position,
+        after_assign_div: bool,
+    ) -> Result<Token, ParseError> {
         let _timer = BoaProfiler::global().start_event("cursor::lex_regex()", "Parsing");
-        self.set_goal(InputElement::RegExp);
-        self.lexer.lex_slash_token(start).map_err(|e| e.into())
+        self.lexer
+            .lex_regex(start, after_assign_div)
+            .map_err(|e| e.into())
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
@@ -3,11 +3,11 @@ mod buffered_lexer;
 
 use super::ParseError;
 use crate::syntax::{
-    ast::Punctuator,
+    ast::{Keyword, Punctuator, Span},
     lexer::{InputElement, Lexer, Position, Token, TokenKind},
 };
 use buffered_lexer::BufferedLexer;
-use std::io::Read;
+use std::{cell::Cell, io::Read, rc::Rc};
 
 /// The result of a peek for a semicolon.
 #[derive(Debug)]
@@ -22,6 +22,16 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    /// Whether `super` property references are allowed, inside of methods.
+    allow_super_property: bool,
+    /// The end of the last token returned by the cursor.
+    previous_token_end: Position,
+    /// The depth currently entered, in operators, see [`Nesting`].
+    depth: Rc<Cell<usize>>,
+    /// The maximum number of nesting levels.
+    depth_limit: usize,
+    /// The span of each statement parsed so far, by the index assigned to it.
+    statement_spans: Vec<Span>,
 }
 
 impl<R> Cursor<R>
@@ -33,27 +43,101 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            allow_super_property: false,
+            previous_token_end: Position::new(1, 1),
+            depth: Rc::new(Cell::new(0)),
+            depth_limit: super::DEFAULT_DEPTH_LIMIT,
+            statement_spans: Vec::new(),
         }
     }
 
+    /// Assigns the next index to the statement at `span`.
+    ///
+    /// The statements of a script are numbered in the order their parsing ends, so that the
+    /// interpreter can count their executions in a vector, see [`crate::coverage`].
+    #[inline]
+    pub(super) fn add_statement(&mut self, span: Span) -> u32 {
+        let index = self.statement_spans.len() as u32;
+        self.statement_spans.push(span);
+        index
+    }
+
+    /// Gets the span of each statement parsed so far, by index.
+    #[inline]
+    pub(super) fn statement_spans(&self) -> &[Span] {
+        &self.statement_spans
+    }
+
+    #[inline]
+    pub(super) fn set_depth_limit(&mut self, depth_limit: usize) {
+        self.depth_limit = depth_limit;
+    }
+
+    /// Starts counting the nesting levels entered by a production, with no level entered yet.
+    #[inline]
+    pub(super) fn nesting(&self) -> Nesting {
+        Nesting {
+            depth: self.depth.clone(),
+            limit: self.depth_limit.saturating_mul(OPERATORS_PER_LEVEL),
+            entered: 0,
+        }
+    }
+
+    /// Enters a nesting level at the next token, which is left when the returned value is
+    /// dropped.
+    ///
+    /// Every production that can contain itself enters a level, so that deeply nested code is
+    /// reported as an error instead of overflowing the stack.
+    pub(super) fn enter_nested(&mut self) -> Result<Nesting, ParseError> {
+        let previous_token_end = self.previous_token_end;
+        let position = self
+            .peek(0)?
+            .map_or(previous_token_end, |token| token.span().start());
+
+        let mut nesting = self.nesting();
+        nesting.enter(position)?;
+        Ok(nesting)
+    }
+
     #[inline]
     pub(super) fn set_goal(&mut self, elm: InputElement) {
         self.buffered_lexer.set_goal(elm)
     }
 
+    /// Lexes a regular expression, after a division punctuator was found where only a regular
+    /// expression is allowed.
     #[inline]
-    pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_regex(start)
+    pub(super) fn lex_regex(
+        &mut self,
+        start: Position,
+        after_assign_div: bool,
+    ) -> Result<Token, ParseError> {
+        let token = self.buffered_lexer.lex_regex(start, after_assign_div)?;
+        self.previous_token_end = token.span().end();
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_template(start)
+        let token = self.buffered_lexer.lex_template(start)?;
+        self.previous_token_end = token.span().end();
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn next(&mut self) -> Result<Option<Token>, ParseError> {
-        self.buffered_lexer.next(true)
+        let token = self.buffered_lexer.next(true)?;
+        if let Some(ref token) = token {
+            self.previous_token_end = token.span().end();
+        }
+        Ok(token)
+    }
+
+    /// Gets the end of the last token returned by the cursor, where the source code of the
+    /// last parsed production ends.
+    #[inline]
+    pub(super) fn previous_token_end(&self) -> Position {
+        self.previous_token_end
     }
 
     #[inline]
@@ -71,6 +155,16 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    #[inline]
+    pub(super) fn allow_super_property(&self) -> bool {
+        self.allow_super_property
+    }
+
+    #[inline]
+    pub(super) fn set_allow_super_property(&mut self, allow_super_property: bool) {
+        self.allow_super_property = allow_super_property;
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(&mut self, kind: K, context: &'static str) -> Result<Token, ParseError>
@@ -153,6 +247,71 @@ where
         }
     }
 
+    /// Checks if the peeked token (skipping n tokens) is a line terminator.
+    ///
+    /// Line terminators are not skipped, so this can be used to enforce the restricted productions
+    /// of the grammar, that forbid a line terminator at a given position.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rules-of-automatic-semicolon-insertion
+    #[inline]
+    pub(super) fn peek_is_line_terminator(&mut self, skip_n: usize) -> Result<bool, ParseError> {
+        if let Some(t) = self.buffered_lexer.peek(skip_n, false)? {
+            Ok(t.kind() == &TokenKind::LineTerminator)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Checks if the next token is an `async` keyword starting an async function, which is the
+    /// case when it is followed by `function` on the same line.
+    ///
+    /// Otherwise, `async` is used as an identifier.
+    pub(super) fn peek_is_async_function(&mut self) -> Result<bool, ParseError> {
+        // Only one line terminator is kept between two tokens.
+        let skip_n = if self.peek_is_line_terminator(0)? {
+            1
+        } else {
+            0
+        };
+        if !matches!(
+            self.buffered_lexer.peek(skip_n, false)?,
+            Some(t) if t.kind() == &TokenKind::Keyword(Keyword::Async)
+        ) {
+            return Ok(false);
+        }
+        Ok(matches!(
+            self.buffered_lexer.peek(skip_n + 1, false)?,
+            Some(t) if t.kind() == &TokenKind::Keyword(Keyword::Function)
+        ))
+    }
+
+    /// Checks if the next token is a `let` keyword starting a lexical declaration, which is the
+    /// case when it is followed by a binding identifier or pattern.
+    ///
+    /// Otherwise, `let` is used as an identifier, which is only allowed outside of strict mode.
+    pub(super) fn peek_is_let_declaration(&mut self) -> Result<bool, ParseError> {
+        if !matches!(
+            self.peek(0)?,
+            Some(t) if t.kind() == &TokenKind::Keyword(Keyword::Let)
+        ) {
+            return Ok(false);
+        }
+        Ok(matches!(
+            self.peek(1)?.map(Token::kind),
+            Some(TokenKind::Identifier(_))
+                | Some(TokenKind::Keyword(Keyword::Async))
+                | Some(TokenKind::Keyword(Keyword::Await))
+                | Some(TokenKind::Keyword(Keyword::Let))
+                | Some(TokenKind::Keyword(Keyword::Of))
+                | Some(TokenKind::Keyword(Keyword::Yield))
+                | Some(TokenKind::Punctuator(Punctuator::OpenBlock))
+                | Some(TokenKind::Punctuator(Punctuator::OpenBracket))
+        ))
+    }
+
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
     ///
     /// When the next token is a `kind` token, get the token, otherwise return `None`.
@@ -174,3 +333,53 @@ where
         })
     }
 }
+
+/// The number of operators of a chain that count as one nesting level.
+///
+/// The operators of a chain are parsed in a loop, but they nest the expression on their left one
+/// level deeper, which the interpreter then recurses into. This only takes a few frames per
+/// operator, against a few dozen for the parser to enter a nesting level.
+const OPERATORS_PER_LEVEL: usize = 8;
+
+/// The nesting levels entered by a production, which are left when it is dropped.
+///
+/// This is created by [`Cursor::nesting`] or [`Cursor::enter_nested`].
+#[derive(Debug)]
+pub(super) struct Nesting {
+    /// The depth currently entered by all the productions, in operators.
+    depth: Rc<Cell<usize>>,
+    limit: usize,
+    /// The depth entered by this production, in operators.
+    entered: usize,
+}
+
+impl Nesting {
+    /// Enters one more nesting level, failing if the depth limit is reached.
+    ///
+    /// The position is the one reported in the error.
+    pub(super) fn enter(&mut self, position: Position) -> Result<(), ParseError> {
+        self.enter_depth(OPERATORS_PER_LEVEL, position)
+    }
+
+    /// Enters the operator of a binary, logical or comma chain, which counts as a fraction of a
+    /// nesting level, see [`OPERATORS_PER_LEVEL`].
+    pub(super) fn enter_operator(&mut self, position: Position) -> Result<(), ParseError> {
+        self.enter_depth(1, position)
+    }
+
+    fn enter_depth(&mut self, depth: usize, position: Position) -> Result<(), ParseError> {
+        let entered = self.depth.get() + depth;
+        if entered > self.limit {
+            return Err(ParseError::depth_limit_exceeded(position));
+        }
+        self.depth.set(entered);
+        self.entered += depth;
+        Ok(())
+    }
+}
+
+impl Drop for Nesting {
+    fn drop(&mut self) {
+        self.depth.set(self.depth.get() - self.entered);
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::syntax::ast::{position::Position, Node};
 use crate::syntax::lexer::{Error as LexError, Token, TokenKind};
-use std::fmt;
+use std::{error, fmt, io};
 
 /// Result of a parsing operation.
 pub type ParseResult = Result<Node, ParseError>;
@@ -51,6 +51,8 @@ pub enum ParseError {
         message: &'static str,
         position: Position,
     },
+    /// When the code is nested deeper than the depth limit of the parser
+    DepthLimitExceeded { position: Position },
 }
 
 impl ParseError {
@@ -97,6 +99,11 @@ impl ParseError {
         Self::Lex { err: e }
     }
 
+    /// Creates a `DepthLimitExceeded` parsing error.
+    pub(super) fn depth_limit_exceeded(position: Position) -> Self {
+        Self::DepthLimitExceeded { position }
+    }
+
     /// Creates a new `Unimplemented` parsing error.
     #[allow(dead_code)]
     pub(super) fn unimplemented(message: &'static str, position: Position) -> Self {
@@ -104,8 +111,50 @@ impl ParseError {
     }
 }
 
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ParseError {
+    /// Gets the position in the source code where the error was found, if known.
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected { found, .. } | Self::Unexpected { found, .. } => {
+                Some(found.span().start())
+            }
+            Self::General { position, .. }
+            | Self::Unimplemented { position, .. }
+            | Self::DepthLimitExceeded { position } => Some(*position),
+            Self::Lex {
+                err: LexError::Syntax(_, position),
+            } => Some(*position),
+            Self::Lex { .. } | Self::AbruptEnd => None,
+        }
+    }
+
+    /// Checks if the error is only caused by the source code ending too early, such as in an
+    /// unclosed block, template literal or comment.
+    ///
+    /// Appending more code could make such source code valid, which is what a REPL checks to
+    /// know if it should ask for more lines. Unterminated string literals are not reported
+    /// here, since they cannot span lines.
+    pub fn is_unexpected_eof(&self) -> bool {
+        match self {
+            Self::AbruptEnd => true,
+            Self::Lex {
+                err: LexError::IO(err),
+            } => err.kind() == io::ErrorKind::UnexpectedEof,
+            _ => false,
+        }
+    }
+
+    /// Creates a displayable version of the error that is prefixed with the name of the source
+    /// and the position of the error, such as `myfile.js:3:14: unexpected token '='`.
+    pub fn with_source_name<'a>(&'a self, source_name: &'a str) -> SourceParseError<'a> {
+        SourceParseError {
+            error: self,
+            source_name,
+        }
+    }
+
+    /// Writes the error message, without the position of the error.
+    fn fmt_message(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Expected {
                 expected,
@@ -113,7 +162,7 @@ impl fmt::Display for ParseError {
                 context,
             } => write!(
                 f,
-                "expected {}, got '{}' in {} at line {}, col {}",
+                "expected {}, got '{}' in {}",
                 if expected.len() == 1 {
                     format!(
                         "token '{}'",
@@ -143,37 +192,73 @@ impl fmt::Display for ParseError {
                 },
                 found,
                 context,
-                found.span().start().line_number(),
-                found.span().start().column_number()
             ),
             Self::Unexpected { found, message } => write!(
                 f,
-                "unexpected token '{}'{} at line {}, col {}",
+                "unexpected token '{}'{}",
                 found,
                 if let Some(m) = message {
                     format!(", {}", m)
                 } else {
                     String::new()
                 },
-                found.span().start().line_number(),
-                found.span().start().column_number()
             ),
             Self::AbruptEnd => f.write_str("abrupt end"),
-            Self::General { message, position } => write!(
+            Self::General { message, .. } => f.write_str(message),
+            Self::Lex {
+                err: LexError::Syntax(message, _),
+            } => f.write_str(message),
+            Self::Lex { err } => fmt::Display::fmt(err, f),
+            Self::Unimplemented { message, .. } => write!(f, "{} not yet implemented", message),
+            Self::DepthLimitExceeded { .. } => f.write_str("the code is nested too deeply"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Self::Lex { err } = self {
+            return fmt::Display::fmt(err, f);
+        }
+
+        self.fmt_message(f)?;
+        if let Some(position) = self.position() {
+            write!(
                 f,
-                "{} at line {}, col {}",
-                message,
+                " at line {}, col {}",
                 position.line_number(),
                 position.column_number()
-            ),
-            Self::Lex { err } => fmt::Display::fmt(err, f),
-            Self::Unimplemented { message, position } => write!(
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A parsing error, displayed along with the name of the source code where it was found.
+///
+/// This is created by [`ParseError::with_source_name`].
+#[derive(Debug, Clone, Copy)]
+pub struct SourceParseError<'a> {
+    error: &'a ParseError,
+    source_name: &'a str,
+}
+
+impl fmt::Display for SourceParseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.source_name)?;
+        if let Some(position) = self.error.position() {
+            write!(
                 f,
-                "{} not yet implemented at line {}, col {}",
-                message,
+                ":{}:{}",
                 position.line_number(),
                 position.column_number()
-            ),
+            )?;
         }
+        f.write_str(": ")?;
+
+        self.error.fmt_message(f)
     }
 }
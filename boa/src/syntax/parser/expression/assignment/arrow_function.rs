@@ -13,7 +13,7 @@ use crate::{
     syntax::{
         ast::{
             node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
-            Punctuator,
+            Punctuator, Span,
         },
         parser::{
             error::{ErrorContext, ParseError, ParseResult},
@@ -71,6 +71,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = next_token.span().start();
 
         let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
             // CoverParenthesizedExpressionAndArrowParameterList
@@ -90,7 +91,8 @@ where
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
         let body = ConciseBody::new(self.allow_in).parse(cursor)?;
-        Ok(ArrowFunctionDecl::new(params, body))
+        let span = Span::new(start, cursor.previous_token_end());
+        Ok(ArrowFunctionDecl::new(params, body).with_span(span))
     }
 }
 
@@ -71,8 +71,11 @@ where
         if let Some(tok) = cursor.peek(0)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Question) {
                 cursor.next()?.expect("? character vanished"); // Consume the token.
+
+                // The `in` operator is always allowed between `?` and `:`, even in the
+                // initializer of a `for` loop.
                 let then_clause =
-                    AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
                 cursor.expect(Punctuator::Colon, "conditional expression")?;
 
@@ -92,6 +92,7 @@ where
         if let Some(tok) = cursor.peek(0)? {
             if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind() {
                 cursor.next()?.expect("** token vanished"); // Consume the token.
+                let _nesting = cursor.enter_nested()?;
                 return Ok(BinOp::new(NumOp::Exp, lhs, self.parse(cursor)?).into());
             }
         }
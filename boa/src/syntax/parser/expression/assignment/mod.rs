@@ -10,6 +10,8 @@
 mod arrow_function;
 mod conditional;
 mod exponentiation;
+#[cfg(test)]
+mod tests;
 
 use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
 use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
@@ -17,7 +19,7 @@ use crate::{
     syntax::{
         ast::{
             node::{Assign, BinOp, Node},
-            Keyword, Punctuator,
+            op, Keyword, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
@@ -80,6 +82,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
+        let _nesting = cursor.enter_nested()?;
         cursor.set_goal(InputElement::Div);
 
         // Arrow function
@@ -87,7 +90,10 @@ where
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
-            | TokenKind::Keyword(Keyword::Await) => {
+            | TokenKind::Keyword(Keyword::Await)
+            | TokenKind::Keyword(Keyword::Async)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Of) => {
                 if let Ok(tok) = cursor.peek_expect_no_lineterminator(1, "assignment expression") {
                     if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
                         return ArrowFunction::new(
@@ -190,7 +196,7 @@ where
                         )));
                     }
                 }
-                TokenKind::Punctuator(p) if p.as_binop().is_some() && p != &Punctuator::Comma => {
+                TokenKind::Punctuator(p) if matches!(p.as_binop(), Some(op::BinOp::Assign(_))) => {
                     cursor.next()?.expect("token vanished"); // Consume the token.
                     if is_assignable(&lhs) {
                         let binop = p.as_binop().expect("binop disappeared");
@@ -217,6 +223,6 @@ where
 /// [spec]: https://tc39.es/ecma262/#sec-assignment-operators-static-semantics-early-errors
 #[inline]
 pub(crate) fn is_assignable(node: &Node) -> bool {
-    matches!(node, Node::GetConstField(_) | Node::GetField(_) | Node::Assign(_)
-                   | Node::Call(_) | Node::Identifier(_) | Node::Object(_))
+    matches!(node, Node::GetConstField(_) | Node::GetField(_) | Node::Call(_)
+                   | Node::Identifier(_) | Node::Object(_))
 }
@@ -0,0 +1,78 @@
+//! Tests for the precedence and associativity of the conditional and assignment operators.
+
+use crate::syntax::{
+    ast::{Const, Node},
+    parser::{tests::check_invalid, Parser},
+};
+
+/// Writes an expression with every operation in parentheses, to show the shape of its tree.
+fn shape(node: &Node) -> String {
+    match node {
+        Node::ConditionalOp(op) => format!(
+            "({} ? {} : {})",
+            shape(op.cond()),
+            shape(op.if_true()),
+            shape(op.if_false())
+        ),
+        Node::Assign(assign) => format!("({} = {})", shape(assign.lhs()), shape(assign.rhs())),
+        Node::BinOp(op) => format!("({} {} {})", shape(op.lhs()), op.op(), shape(op.rhs())),
+        Node::Identifier(ident) => ident.as_ref().to_owned(),
+        Node::Const(Const::Int(int)) => int.to_string(),
+        node => panic!("unexpected node in the expression: {}", node),
+    }
+}
+
+/// Checks that the given expression statement parses to the expected tree shape.
+#[track_caller]
+fn check_shape(js: &str, expected: &str) {
+    let statements = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .unwrap_or_else(|error| panic!("failed to parse `{}`: {}", js, error));
+    match statements.items() {
+        [expression] => assert_eq!(shape(expression), expected, "in `{}`", js),
+        items => panic!("`{}` parsed to {} statements", js, items.len()),
+    }
+}
+
+#[test]
+fn conditional_and_assignment_shapes() {
+    for (js, expected) in &[
+        ("a ? b : c ? d : e", "(a ? b : (c ? d : e))"),
+        ("a ? b ? c : d : e", "(a ? (b ? c : d) : e)"),
+        ("x = y = z", "(x = (y = z))"),
+        ("x += y -= z", "(x += (y -= z))"),
+        ("x = y += 1", "(x = (y += 1))"),
+        ("a ? b = 1 : c", "(a ? (b = 1) : c)"),
+        ("a ? b : c = 1", "(a ? b : (c = 1))"),
+        ("a ? b = 1 : c = 2", "(a ? (b = 1) : (c = 2))"),
+        ("x = a ? b : c", "(x = (a ? b : c))"),
+        ("x = a ? y = b : c", "(x = (a ? (y = b) : c))"),
+        ("a || b ? c : d", "((a || b) ? c : d)"),
+        ("a ?? b ? c : d", "((a ?? b) ? c : d)"),
+        ("a ? b || c : d && e", "(a ? (b || c) : (d && e))"),
+        ("a + b ? c + d : e + f", "((a + b) ? (c + d) : (e + f))"),
+        ("x ||= a ? b : c", "(x ||= (a ? b : c))"),
+        ("a ? b in c : d", "(a ? (b in c) : d)"),
+    ] {
+        check_shape(js, expected);
+    }
+}
+
+#[test]
+fn in_operator_in_conditional_of_for_initializer() {
+    // The `in` operator is allowed between `?` and `:`, even where it is otherwise forbidden.
+    assert!(
+        Parser::new(b"for (var x = a ? b in c : d; x;) {}".as_ref(), false)
+            .parse_all()
+            .is_ok()
+    );
+}
+
+#[test]
+fn invalid_assignment_targets() {
+    check_invalid("a ? b : c += 1 = 2");
+    check_invalid("(a = b) = c");
+    check_invalid("(a ? b : c) = d");
+    check_invalid("a + b = c");
+    check_invalid("(a = b) += c");
+}
@@ -79,14 +79,18 @@ where
             ));
         };
 
+        // Each call or property access nests the expression on its left one level deeper.
+        let mut nesting = cursor.nesting();
         while let Some(tok) = cursor.peek(0)? {
             let token = tok.clone();
             match token.kind() {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    nesting.enter(token.span().start())?;
                     let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
                     lhs = Node::from(Call::new(lhs, args));
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
+                    nesting.enter(token.span().start())?;
                     cursor.next()?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
 
                     match &cursor.next()?.ok_or(ParseError::AbruptEnd)?.kind() {
@@ -96,6 +100,12 @@ where
                         TokenKind::Keyword(kw) => {
                             lhs = GetConstField::new(lhs, kw.to_string()).into();
                         }
+                        TokenKind::BooleanLiteral(boolean) => {
+                            lhs = GetConstField::new(lhs, boolean.to_string()).into();
+                        }
+                        TokenKind::NullLiteral => {
+                            lhs = GetConstField::new(lhs, "null").into();
+                        }
                         _ => {
                             return Err(ParseError::expected(
                                 vec![TokenKind::identifier("identifier")],
@@ -106,6 +116,7 @@ where
                     }
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    nesting.enter(token.span().start())?;
                     let _ = cursor.next()?.ok_or(ParseError::AbruptEnd)?; // We move the parser.
                     let idx =
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
@@ -113,6 +124,7 @@ where
                     lhs = GetField::new(lhs, idx).into();
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    nesting.enter(token.span().start())?;
                     lhs = TaggedTemplateLiteral::new(
                         self.allow_yield,
                         self.allow_await,
@@ -68,17 +68,47 @@ where
             == &TokenKind::Keyword(Keyword::New)
         {
             let _ = cursor.next().expect("new keyword disappeared");
+            let _nesting = cursor.enter_nested()?;
             let lhs = self.parse(cursor)?;
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
             let call_node = Call::new(lhs, args);
 
             Node::from(New::from(call_node))
+        } else if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Keyword(Keyword::Super)
+        {
+            let token = cursor.next()?.expect("super keyword disappeared");
+            if !cursor.allow_super_property() {
+                return Err(ParseError::general(
+                    "'super' keyword unexpected here",
+                    token.span().start(),
+                ));
+            }
+            // Only `super` properties are supported, the property itself is parsed below.
+            let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            match next.kind() {
+                TokenKind::Punctuator(Punctuator::Dot)
+                | TokenKind::Punctuator(Punctuator::OpenBracket) => Node::Super,
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::Dot),
+                            TokenKind::Punctuator(Punctuator::OpenBracket),
+                        ],
+                        next.clone(),
+                        "super property",
+                    ))
+                }
+            }
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
+        // Each property access nests the object expression one level deeper.
+        let mut nesting = cursor.nesting();
         while let Some(tok) = cursor.peek(0)? {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Dot) => {
+                    nesting.enter(tok.span().start())?;
                     cursor.next()?.expect("dot punctuator token disappeared"); // We move the parser forward.
 
                     let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
@@ -90,6 +120,10 @@ where
                         TokenKind::Keyword(kw) => {
                             lhs = GetConstField::new(lhs, kw.to_string()).into()
                         }
+                        TokenKind::BooleanLiteral(boolean) => {
+                            lhs = GetConstField::new(lhs, boolean.to_string()).into()
+                        }
+                        TokenKind::NullLiteral => lhs = GetConstField::new(lhs, "null").into(),
                         _ => {
                             return Err(ParseError::expected(
                                 vec![TokenKind::identifier("identifier")],
@@ -100,6 +134,7 @@ where
                     }
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    nesting.enter(tok.span().start())?;
                     cursor
                         .next()?
                         .expect("open bracket punctuator token disappeared"); // We move the parser forward.
@@ -109,6 +144,7 @@ where
                     lhs = GetField::new(lhs, idx).into();
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    nesting.enter(tok.span().start())?;
                     lhs = TaggedTemplateLiteral::new(
                         self.allow_yield,
                         self.allow_await,
@@ -79,10 +79,13 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                 cursor.set_goal($goal.unwrap());
             }
 
+            // The operators are parsed in a loop, but each one nests the expression on its left.
+            let mut nesting = cursor.nesting();
             let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
             while let Some(tok) = cursor.peek(0)? {
                 match *tok.kind() {
                     TokenKind::Punctuator(op) if $( op == $op )||* => {
+                        nesting.enter_operator(tok.span().start())?;
                         let _ = cursor.next().expect("token disappeared");
                         lhs = BinOp::new(
                             op.as_binop().expect("Could not get binary operation."),
@@ -91,6 +94,7 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                         ).into();
                     }
                     TokenKind::Keyword(op) if $( op == $op )||* => {
+                        nesting.enter_operator(tok.span().start())?;
                         let _ = cursor.next().expect("token disappeared");
                         lhs = BinOp::new(
                             op.as_binop().expect("Could not get binary operation."),
@@ -219,6 +223,9 @@ where
                 .parse(cursor)?;
         let mut previous = self.previous;
 
+        // The right hand side of `||` is parsed recursively, while the other operators are parsed
+        // in a loop but nest the expression on their left.
+        let mut nesting = cursor.nesting();
         while let Some(tok) = cursor.peek(0)? {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::BoolAnd) => {
@@ -229,6 +236,7 @@ where
                             "logical expression (cannot use '??' without parentheses within '||' or '&&')",
                         ));
                     }
+                    nesting.enter_operator(tok.span().start())?;
                     let _ = cursor.next()?.expect("'&&' expected");
                     previous = PreviousExpr::Logical;
                     let rhs =
@@ -245,6 +253,7 @@ where
                             "logical expression (cannot use '??' without parentheses within '||' or '&&')",
                         ));
                     }
+                    nesting.enter(tok.span().start())?;
                     let _ = cursor.next()?.expect("'||' expected");
                     previous = PreviousExpr::Logical;
                     let rhs = ShortCircuitExpression::with_previous(
@@ -267,6 +276,7 @@ where
                             "cannot use '??' unparenthesized within '||' or '&&'",
                         ));
                     }
+                    nesting.enter_operator(tok.span().start())?;
                     let _ = cursor.next()?.expect("'??' expected");
                     previous = PreviousExpr::Coalesce;
                     let rhs =
@@ -486,6 +496,8 @@ where
             cursor.set_goal(None::<InputElement>.unwrap());
         }
 
+        // The operators are parsed in a loop, but each one nests the expression on its left.
+        let mut nesting = cursor.nesting();
         let mut lhs = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
         while let Some(tok) = cursor.peek(0)? {
             match *tok.kind() {
@@ -495,6 +507,7 @@ where
                         || op == Punctuator::LessThanOrEq
                         || op == Punctuator::GreaterThanOrEq =>
                 {
+                    nesting.enter_operator(tok.span().start())?;
                     let _ = cursor.next().expect("token disappeared");
                     lhs = BinOp::new(
                         op.as_binop().expect("Could not get binary operation."),
@@ -507,6 +520,7 @@ where
                     if op == Keyword::InstanceOf
                         || (op == Keyword::In && self.allow_in == AllowIn(true)) =>
                 {
+                    nesting.enter_operator(tok.span().start())?;
                     let _ = cursor.next().expect("token disappeared");
                     lhs = BinOp::new(
                         op.as_binop().expect("Could not get binary operation."),
@@ -14,7 +14,7 @@ use crate::{
     syntax::{
         ast::{
             node::{ArrayDecl, Node, Spread},
-            Const, Punctuator,
+            Punctuator,
         },
         parser::{
             expression::AssignmentExpression, AllowAwait, AllowYield, Cursor, ParseError,
@@ -67,14 +67,14 @@ where
         loop {
             // TODO: Support all features.
             while cursor.next_if(Punctuator::Comma)?.is_some() {
-                elements.push(Node::Const(Const::Undefined));
+                elements.push(Node::Empty);
             }
 
             if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
                 break;
             }
 
-            let _ = cursor.peek(0)?.ok_or(ParseError::AbruptEnd); // Check that there are more tokens to read.
+            cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?; // Check that there are more tokens to read.
 
             if cursor.next_if(Punctuator::Spread)?.is_some() {
                 let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
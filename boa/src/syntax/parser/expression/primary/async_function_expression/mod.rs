@@ -6,7 +6,7 @@ use crate::{
         ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
         lexer::TokenKind,
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{parse_function_parameters_and_body, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowYield, Cursor, ParseError, TokenParser,
         },
@@ -63,14 +63,12 @@ where
             return Err(ParseError::AbruptEnd);
         };
 
-        cursor.expect(Punctuator::OpenParen, "async function expression")?;
-
-        let params = FormalParameters::new(false, true).parse(cursor)?;
-
-        cursor.expect(Punctuator::CloseParen, "async function expression")?;
-        cursor.expect(Punctuator::OpenBlock, "async function expression")?;
-
-        let body = FunctionBody::new(false, true).parse(cursor)?;
+        let (params, body) = parse_function_parameters_and_body(
+            FormalParameters::new(false, true),
+            FunctionBody::new(false, true),
+            cursor,
+            "async function expression",
+        )?;
 
         cursor.expect(Punctuator::CloseBlock, "async function expression")?;
 
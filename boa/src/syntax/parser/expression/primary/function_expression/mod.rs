@@ -12,10 +12,10 @@ mod tests;
 
 use crate::{
     syntax::{
-        ast::{node::FunctionExpr, Keyword, Punctuator},
+        ast::{node::FunctionExpr, Keyword, Position, Punctuator, Span},
         lexer::TokenKind,
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{parse_function_parameters_and_body, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             Cursor, ParseError, TokenParser,
         },
@@ -34,7 +34,17 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionExpression
 #[derive(Debug, Clone, Copy)]
-pub(super) struct FunctionExpression;
+pub(super) struct FunctionExpression {
+    /// The start of the `function` keyword, which was already consumed.
+    start: Position,
+}
+
+impl FunctionExpression {
+    /// Creates a new `FunctionExpression` parser, for the expression starting at `start`.
+    pub(super) fn new(start: Position) -> Self {
+        Self { start }
+    }
+}
 
 impl<R> TokenParser<R> for FunctionExpression
 where
@@ -58,17 +68,18 @@ where
             None
         };
 
-        cursor.expect(Punctuator::OpenParen, "function expression")?;
-
-        let params = FormalParameters::new(false, false).parse(cursor)?;
-
-        cursor.expect(Punctuator::CloseParen, "function expression")?;
-        cursor.expect(Punctuator::OpenBlock, "function expression")?;
-
-        let body = FunctionBody::new(false, false).parse(cursor)?;
+        let (params, body) = parse_function_parameters_and_body(
+            FormalParameters::new(false, false),
+            FunctionBody::new(false, false),
+            cursor,
+            "function expression",
+        )?;
 
-        cursor.expect(Punctuator::CloseBlock, "function expression")?;
+        let end = cursor
+            .expect(Punctuator::CloseBlock, "function expression")?
+            .span()
+            .end();
 
-        Ok(FunctionExpr::new(name, params, body))
+        Ok(FunctionExpr::new(name, params, body).with_span(Span::new(self.start, end)))
     }
 }
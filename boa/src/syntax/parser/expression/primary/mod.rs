@@ -77,14 +77,29 @@ where
 
         let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
-        match tok.kind() {
+        let primary = match tok.kind() {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
-            TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
-            }
-            TokenKind::Keyword(Keyword::Async) => AsyncFunctionExpression::new(self.allow_yield)
+            TokenKind::Keyword(Keyword::Function) => FunctionExpression::new(tok.span().start())
                 .parse(cursor)
                 .map(Node::from),
+            TokenKind::Keyword(Keyword::Async)
+                if !cursor.peek_is_line_terminator(0)?
+                    && matches!(
+                        cursor.peek(0)?,
+                        Some(t) if t.kind() == &TokenKind::Keyword(Keyword::Function)
+                    ) =>
+            {
+                AsyncFunctionExpression::new(self.allow_yield)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
+            // The contextual keywords are identifiers where they do not start their construct.
+            TokenKind::Keyword(k @ Keyword::Async) | TokenKind::Keyword(k @ Keyword::Of) => {
+                Ok(Identifier::from(k.as_str()).into())
+            }
+            TokenKind::Keyword(k @ Keyword::Let) if !cursor.strict_mode() => {
+                Ok(Identifier::from(k.as_str()).into())
+            }
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 cursor.set_goal(InputElement::RegExp);
                 let expr =
@@ -122,8 +137,10 @@ where
                     ],
                 ))))
             }
-            TokenKind::Punctuator(Punctuator::Div) => {
-                let tok = cursor.lex_regex(tok.span().start())?;
+            TokenKind::Punctuator(Punctuator::Div)
+            | TokenKind::Punctuator(Punctuator::AssignDiv) => {
+                let after_assign_div = tok.kind() == &TokenKind::Punctuator(Punctuator::AssignDiv);
+                let tok = cursor.lex_regex(tok.span().start(), after_assign_div)?;
 
                 if let TokenKind::RegularExpressionLiteral(body, flags) = tok.kind() {
                     Ok(Node::from(New::from(Call::new(
@@ -147,6 +164,12 @@ where
             .parse(cursor)
             .map(Node::TemplateLit),
             _ => Err(ParseError::unexpected(tok.clone(), "primary expression")),
-        }
+        };
+
+        // A primary expression can only be followed by a division punctuator, never by the start
+        // of a regular expression: `[] / 2` or `({}) / 2`.
+        cursor.set_goal(InputElement::Div);
+
+        primary
     }
 }
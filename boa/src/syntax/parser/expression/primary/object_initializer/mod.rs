@@ -14,7 +14,7 @@ use crate::{
     syntax::{
         ast::{
             node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
-            Punctuator,
+            Position, Punctuator, Span,
         },
         parser::{
             expression::AssignmentExpression,
@@ -134,7 +134,9 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        let prop_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let start = prop_token.span().start();
+        let prop_name = prop_token.to_string();
         if cursor.next_if(Punctuator::Colon)?.is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
@@ -157,7 +159,7 @@ where
             .is_some()
             || ["get", "set"].contains(&prop_name.as_str())
         {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
+            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name, start)
                 .parse(cursor);
         }
 
@@ -177,11 +179,13 @@ struct MethodDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     identifier: String,
+    /// The start of the method definition, whose first token was already consumed.
+    start: Position,
 }
 
 impl MethodDefinition {
     /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I, start: Position) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -191,6 +195,7 @@ impl MethodDefinition {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             identifier: identifier.into(),
+            start,
         }
     }
 }
@@ -204,12 +209,18 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
 
+        // Methods can reference the properties of `super`.
+        let allow_super_property = cursor.allow_super_property();
+        cursor.set_allow_super_property(true);
+
         let (methodkind, prop_name, params) = match self.identifier.as_str() {
             idn @ "get" | idn @ "set"
                 if matches!(
                     cursor.peek(0)?.map(|t| t.kind()),
-                    Some(&TokenKind::Identifier(_)) | Some(&TokenKind::Keyword(_))
-                        | Some(&TokenKind::BooleanLiteral(_)) | Some(&TokenKind::NullLiteral)
+                    Some(&TokenKind::Identifier(_))
+                        | Some(&TokenKind::Keyword(_))
+                        | Some(&TokenKind::BooleanLiteral(_))
+                        | Some(&TokenKind::NullLiteral)
                         | Some(&TokenKind::NumericLiteral(_))
                 ) =>
             {
@@ -255,15 +266,19 @@ where
             "property method definition",
         )?;
         let body = FunctionBody::new(false, false).parse(cursor)?;
-        cursor.expect(
-            TokenKind::Punctuator(Punctuator::CloseBlock),
-            "property method definition",
-        )?;
+        let end = cursor
+            .expect(
+                TokenKind::Punctuator(Punctuator::CloseBlock),
+                "property method definition",
+            )?
+            .span()
+            .end();
+        cursor.set_allow_super_property(allow_super_property);
 
         Ok(node::PropertyDefinition::method_definition(
             methodkind,
             prop_name,
-            FunctionExpr::new(None, params, body),
+            FunctionExpr::new(None, params, body).with_span(Span::new(self.start, end)),
         ))
     }
 }
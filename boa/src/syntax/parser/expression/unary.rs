@@ -60,6 +60,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("UnaryExpression", "Parsing");
+        let _nesting = cursor.enter_nested()?;
 
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
         let token_start = tok.span().start();
@@ -77,6 +77,13 @@ where
         }
 
         let lhs = LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        // A postfix `++` or `--` is a restricted production: if there is a line terminator between
+        // the operand and the operator, a semicolon is automatically inserted before the operator.
+        if cursor.peek_is_line_terminator(0)? {
+            return Ok(lhs);
+        }
+
         if let Some(tok) = cursor.peek(0)? {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Inc) => {
@@ -217,6 +217,33 @@ where
     }
 }
 
+/// Parses the parameters and the body of a function declaration or expression, from the opening
+/// parenthesis to the end of the body, before its closing brace.
+///
+/// Unlike methods and arrow functions, these functions can't reference the properties of `super`,
+/// even inside of methods, neither in their parameters nor in their body.
+pub(in crate::syntax::parser) fn parse_function_parameters_and_body<R>(
+    params: FormalParameters,
+    body: FunctionBody,
+    cursor: &mut Cursor<R>,
+    context: &'static str,
+) -> Result<(Box<[node::FormalParameter]>, node::StatementList), ParseError>
+where
+    R: Read,
+{
+    let allow_super_property = cursor.allow_super_property();
+    cursor.set_allow_super_property(false);
+
+    cursor.expect(Punctuator::OpenParen, context)?;
+    let params = params.parse(cursor)?;
+    cursor.expect(Punctuator::CloseParen, context)?;
+    cursor.expect(Punctuator::OpenBlock, context)?;
+    let body = body.parse(cursor)?;
+
+    cursor.set_allow_super_property(allow_super_property);
+    Ok((params, body))
+}
+
 /// A `FunctionBody` is equivalent to a `FunctionStatementList`.
 ///
 /// More information:
@@ -262,12 +289,15 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FunctionStatementList", "Parsing");
+        let _nesting = cursor.enter_nested()?;
 
         let global_strict_mode = cursor.strict_mode();
         if let Some(tk) = cursor.peek(0)? {
             match tk.kind() {
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
-                    return Ok(Vec::new().into());
+                    let mut stmlist = node::StatementList::from(Vec::new());
+                    stmlist.set_strict(global_strict_mode);
+                    return Ok(stmlist);
                 }
                 TokenKind::StringLiteral(string)
                 | TokenKind::TemplateNoSubstitution { cooked: string, .. } => {
@@ -286,7 +316,11 @@ where
             true,
             &FUNCTION_BREAK_TOKENS,
         )
-        .parse(cursor);
+        .parse(cursor)
+        .map(|mut stmlist| {
+            stmlist.set_strict(cursor.strict_mode());
+            stmlist
+        });
 
         // Reset strict mode back to the global scope.
         cursor.set_strict_mode(global_strict_mode);
@@ -8,8 +8,12 @@ mod statement;
 #[cfg(test)]
 mod tests;
 
-pub use self::error::{ParseError, ParseResult};
-use crate::syntax::{ast::node::StatementList, lexer::TokenKind};
+pub use self::error::{ParseError, ParseResult, SourceParseError};
+use crate::syntax::{
+    ast::{node::StatementList, Span},
+    lexer::TokenKind,
+    resolver,
+};
 
 use cursor::Cursor;
 
@@ -81,6 +85,9 @@ impl From<bool> for AllowDefault {
     }
 }
 
+/// The default maximum depth of nested code, see [`Parser::set_depth_limit`].
+pub const DEFAULT_DEPTH_LIMIT: usize = 256;
+
 #[derive(Debug)]
 pub struct Parser<R> {
     /// Cursor of the parser, pointing to the lexer and used to get tokens for the parser.
@@ -98,11 +105,36 @@ impl<R> Parser<R> {
         Self { cursor }
     }
 
+    /// Sets the maximum depth of nested code, such as nested parentheses, blocks or functions,
+    /// which defaults to [`DEFAULT_DEPTH_LIMIT`].
+    ///
+    /// The parser and the interpreter recurse once per level, so parsing fails with
+    /// [`ParseError::DepthLimitExceeded`] past this depth, instead of overflowing the stack on
+    /// untrusted code. A chain of property accesses or calls counts one level per access, and a
+    /// chain of `||` operators one level per operator. The other binary operators and the comma
+    /// operator take much less stack, so eight of them in a chain count as one level.
+    pub fn set_depth_limit(&mut self, limit: usize)
+    where
+        R: Read,
+    {
+        self.cursor.set_depth_limit(limit);
+    }
+
     pub fn parse_all(&mut self) -> Result<StatementList, ParseError>
     where
         R: Read,
     {
-        Script.parse(&mut self.cursor)
+        let script = Script.parse(&mut self.cursor)?;
+        resolver::resolve_script(&script);
+        Ok(script)
+    }
+
+    /// Gets the span of each statement parsed so far, by the index the parser assigned to it.
+    pub(crate) fn statement_spans(&self) -> &[Span]
+    where
+        R: Read,
+    {
+        self.cursor.statement_spans()
     }
 }
 
@@ -133,7 +165,9 @@ where
                     }
                     _ => {}
                 }
-                ScriptBody.parse(cursor)
+                let mut stmlist = ScriptBody.parse(cursor)?;
+                stmlist.set_strict(cursor.strict_mode());
+                Ok(stmlist)
             }
             None => Ok(StatementList::from(Vec::new())),
         }
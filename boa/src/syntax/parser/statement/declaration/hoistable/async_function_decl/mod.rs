@@ -5,7 +5,8 @@ use crate::syntax::{
     ast::{node::AsyncFunctionDecl, Keyword, Punctuator},
     lexer::TokenKind,
     parser::{
-        function::FormalParameters, function::FunctionBody, statement::BindingIdentifier,
+        function::{parse_function_parameters_and_body, FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
         AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
     },
 };
@@ -73,14 +74,12 @@ where
             return Err(ParseError::AbruptEnd);
         };
 
-        cursor.expect(Punctuator::OpenParen, "async function declaration")?;
-
-        let params = FormalParameters::new(false, true).parse(cursor)?;
-
-        cursor.expect(Punctuator::CloseParen, "async function declaration")?;
-        cursor.expect(Punctuator::OpenBlock, "async function declaration")?;
-
-        let body = FunctionBody::new(false, true).parse(cursor)?;
+        let (params, body) = parse_function_parameters_and_body(
+            FormalParameters::new(false, true),
+            FunctionBody::new(false, true),
+            cursor,
+            "async function declaration",
+        )?;
 
         cursor.expect(Punctuator::CloseBlock, "async function declaration")?;
 
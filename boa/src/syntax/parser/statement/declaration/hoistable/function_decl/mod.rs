@@ -2,9 +2,10 @@
 mod tests;
 
 use crate::syntax::{
-    ast::{node::FunctionDecl, Keyword, Punctuator},
+    ast::{node::FunctionDecl, Keyword, Punctuator, Span},
     parser::{
-        function::FormalParameters, function::FunctionBody, statement::BindingIdentifier,
+        function::{parse_function_parameters_and_body, FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
         AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
     },
 };
@@ -49,22 +50,26 @@ where
     type Output = FunctionDecl;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        cursor.expect(Keyword::Function, "function declaration")?;
+        let start = cursor
+            .expect(Keyword::Function, "function declaration")?
+            .span()
+            .start();
 
         // TODO: If self.is_default, then this can be empty.
         let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
-        cursor.expect(Punctuator::OpenParen, "function declaration")?;
+        let (params, body) = parse_function_parameters_and_body(
+            FormalParameters::new(false, false),
+            FunctionBody::new(self.allow_yield, self.allow_await),
+            cursor,
+            "function declaration",
+        )?;
 
-        let params = FormalParameters::new(false, false).parse(cursor)?;
+        let end = cursor
+            .expect(Punctuator::CloseBlock, "function declaration")?
+            .span()
+            .end();
 
-        cursor.expect(Punctuator::CloseParen, "function declaration")?;
-        cursor.expect(Punctuator::OpenBlock, "function declaration")?;
-
-        let body = FunctionBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
-
-        cursor.expect(Punctuator::CloseBlock, "function declaration")?;
-
-        Ok(FunctionDecl::new(name, params, body))
+        Ok(FunctionDecl::new(name, params, body).with_span(Span::new(start, end)))
     }
 }
@@ -6,7 +6,10 @@ use super::Statement;
 use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
-        ast::{node::If, Keyword, Node, Punctuator},
+        ast::{
+            node::{statement_list::StatementLocation, If},
+            Keyword, Node, Punctuator,
+        },
         parser::{
             expression::Expression, AllowAwait, AllowReturn, AllowYield, Cursor, ParseError,
             TokenParser,
@@ -65,23 +68,23 @@ where
 
         cursor.expect(Punctuator::CloseParen, "if statement")?;
 
-        let then_stm =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let (then_stm, then_location) =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                .parse_nested(cursor)?;
 
-        let else_stm = if let Some(else_tok) = cursor.peek(0)? {
-            if else_tok.kind() == &TokenKind::Keyword(Keyword::Else) {
+        let (else_stm, else_location) = match cursor.peek(0)? {
+            Some(else_tok) if else_tok.kind() == &TokenKind::Keyword(Keyword::Else) => {
                 cursor.next()?.expect("else token vanished");
-                Some(
+                let (else_stm, else_location) =
                     Statement::new(self.allow_yield, self.allow_await, self.allow_return)
-                        .parse(cursor)?,
-                )
-            } else {
-                None
+                        .parse_nested(cursor)?;
+                (Some(else_stm), else_location)
             }
-        } else {
-            None
+            _ => (None, StatementLocation::default()),
         };
 
-        Ok(If::new::<_, _, Node, _>(cond, then_stm, else_stm))
+        let mut if_stm = If::new::<_, _, Node, _>(cond, then_stm, else_stm);
+        if_stm.set_locations(then_location, else_location);
+        Ok(if_stm)
     }
 }
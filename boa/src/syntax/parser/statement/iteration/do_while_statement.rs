@@ -65,8 +65,9 @@ where
         let _timer = BoaProfiler::global().start_event("DoWhileStatement", "Parsing");
         cursor.expect(Keyword::Do, "do while statement")?;
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let (body, location) =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                .parse_nested(cursor)?;
 
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
@@ -95,6 +96,8 @@ where
             }
         }
 
-        Ok(DoWhileLoop::new(body, cond))
+        let mut do_while_loop = DoWhileLoop::new(body, cond);
+        do_while_loop.set_body_location(location);
+        Ok(do_while_loop)
     }
 }
@@ -12,7 +12,7 @@ use crate::{
     syntax::{
         ast::{
             node::{ForInLoop, ForLoop, ForOfLoop, Node},
-            Const, Keyword, Punctuator,
+            Const, Keyword, Position, Punctuator,
         },
         parser::{
             expression::Expression,
@@ -72,6 +72,7 @@ where
         cursor.expect(Keyword::For, "for statement")?;
         cursor.expect(Punctuator::OpenParen, "for statement")?;
 
+        let is_let_declaration = cursor.peek_is_let_declaration()?;
         let init = match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
             TokenKind::Keyword(Keyword::Var) => {
                 let _ = cursor.next()?;
@@ -81,6 +82,9 @@ where
                         .map(Node::from)?,
                 )
             }
+            TokenKind::Keyword(Keyword::Let) if !is_let_declaration => {
+                Some(Expression::new(false, self.allow_yield, self.allow_await).parse(cursor)?)
+            }
             TokenKind::Keyword(Keyword::Let) | TokenKind::Keyword(Keyword::Const) => {
                 Some(Declaration::new(self.allow_yield, self.allow_await, false).parse(cursor)?)
             }
@@ -90,22 +94,34 @@ where
 
         match cursor.peek(0)? {
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) && init.is_some() => {
+                let position = tok.span().start();
                 let _ = cursor.next();
+                let init = init.unwrap();
+                check_for_in_of_declaration(&init, true, cursor.strict_mode(), position)?;
                 let expr =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "for in statement")?;
-                let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor)?;
-                return Ok(ForInLoop::new(init.unwrap(), expr, body).into());
+                let (body, location) =
+                    Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse_nested(cursor)?;
+                let mut for_in_loop = ForInLoop::new(init, expr, body);
+                for_in_loop.set_body_location(location);
+                return Ok(for_in_loop.into());
             }
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) && init.is_some() => {
+                let position = tok.span().start();
                 let _ = cursor.next();
+                let init = init.unwrap();
+                check_for_in_of_declaration(&init, false, cursor.strict_mode(), position)?;
                 let iterable =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "for of statement")?;
-                let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor)?;
-                return Ok(ForOfLoop::new(init.unwrap(), iterable, body).into());
+                let (body, location) =
+                    Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse_nested(cursor)?;
+                let mut for_of_loop = ForOfLoop::new(init, iterable, body);
+                for_of_loop.set_body_location(location);
+                return Ok(for_of_loop.into());
             }
             _ => {}
         }
@@ -131,10 +147,63 @@ where
             Some(step)
         };
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let (body, location) =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                .parse_nested(cursor)?;
 
         // TODO: do not encapsulate the `for` in a block just to have an inner scope.
-        Ok(ForLoop::new(init, cond, step, body).into())
+        let mut for_loop = ForLoop::new(init, cond, step, body);
+        for_loop.set_body_location(location);
+        Ok(for_loop.into())
+    }
+}
+
+/// Checks that the declaration in the head of a `for...in` or `for...of` loop declares a single
+/// binding, without an initializer.
+///
+/// Outside of strict mode, the `var` declaration of a `for...in` loop can have an initializer,
+/// as in the web browsers.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-initializers-in-forin-statement-heads
+fn check_for_in_of_declaration(
+    init: &Node,
+    for_in: bool,
+    strict: bool,
+    position: Position,
+) -> Result<(), ParseError> {
+    let (bindings, initialized, is_var) = match init {
+        Node::VarDeclList(list) => (
+            list.as_ref().len(),
+            list.as_ref().iter().any(|decl| decl.init().is_some()),
+            true,
+        ),
+        Node::LetDeclList(list) => (
+            list.as_ref().len(),
+            list.as_ref().iter().any(|decl| decl.init().is_some()),
+            false,
+        ),
+        Node::ConstDeclList(list) => (
+            list.as_ref().len(),
+            list.as_ref().iter().any(|decl| decl.init().is_some()),
+            false,
+        ),
+        _ => return Ok(()),
+    };
+
+    if bindings != 1 {
+        Err(ParseError::general(
+            "only one variable can be declared in the head of a for-in or for-of loop",
+            position,
+        ))
+    } else if initialized && !(for_in && is_var && !strict) {
+        Err(ParseError::general(
+            "for-in and for-of loop variable declarations may not have initializers",
+            position,
+        ))
+    } else {
+        Ok(())
     }
 }
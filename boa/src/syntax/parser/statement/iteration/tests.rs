@@ -1,13 +1,13 @@
 use crate::syntax::{
     ast::{
         node::{
-            field::GetConstField, BinOp, Block, Break, Call, DoWhileLoop, Identifier, UnaryOp,
-            VarDecl, VarDeclList, WhileLoop,
+            field::GetConstField, BinOp, Block, Break, Call, DoWhileLoop, ForInLoop, ForLoop,
+            Identifier, Node, UnaryOp, VarDecl, VarDeclList, WhileLoop,
         },
         op::{self, AssignOp, CompOp},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks do-while statement parsing.
@@ -136,3 +136,61 @@ fn do_while_spaces() {
         .into()],
     );
 }
+
+/// Checks that the `in` operator is not part of the initializer of a `for...in` loop variable.
+#[test]
+fn for_in_var_initializer() {
+    check_parser(
+        "for (var x = 'a' in obj) {}",
+        vec![ForInLoop::new(
+            VarDeclList::from(vec![VarDecl::new("x", Some(Const::from("a").into()))]),
+            Identifier::from("obj"),
+            Block::from(Vec::<Node>::new()),
+        )
+        .into()],
+    );
+}
+
+/// Checks that a parenthesized `in` operator can be used in the head of a `for` loop.
+#[test]
+fn for_parenthesized_in() {
+    check_parser(
+        "for (var x = ('a' in obj); x;) {}",
+        vec![ForLoop::new(
+            Node::from(VarDeclList::from(vec![VarDecl::new(
+                "x",
+                Some(BinOp::new(CompOp::In, Const::from("a"), Identifier::from("obj")).into()),
+            )])),
+            Node::from(Identifier::from("x")),
+            None::<Node>,
+            Block::from(Vec::<Node>::new()),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "for (('a' in obj); false;) {}",
+        vec![ForLoop::new(
+            Node::from(BinOp::new(
+                CompOp::In,
+                Const::from("a"),
+                Identifier::from("obj"),
+            )),
+            Node::from(Const::from(false)),
+            None::<Node>,
+            Block::from(Vec::<Node>::new()),
+        )
+        .into()],
+    );
+}
+
+/// Checks the `for` loop heads that are invalid because of the `in` operator.
+#[test]
+fn for_in_invalid_heads() {
+    check_invalid("for (a in b; c; d) {}");
+    check_invalid("for (var x = 'a' in obj; x;) {}");
+    check_invalid("for (var x, y in obj) {}");
+    check_invalid("for (let x = 1 in obj) {}");
+    check_invalid("for (const x = 1 of arr) {}");
+    check_invalid("'use strict'; for (var x = 'a' in obj) {}");
+}
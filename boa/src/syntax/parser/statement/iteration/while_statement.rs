@@ -62,9 +62,12 @@ where
 
         cursor.expect(Punctuator::CloseParen, "while statement")?;
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let (body, location) =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                .parse_nested(cursor)?;
 
-        Ok(WhileLoop::new(cond, body))
+        let mut while_loop = WhileLoop::new(cond, body);
+        while_loop.set_body_location(location);
+        Ok(while_loop)
     }
 }
@@ -39,8 +39,12 @@ use self::{
 use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
 
 use crate::{
+    profiler::category,
     syntax::{
-        ast::{node, Keyword, Node, Punctuator},
+        ast::{
+            node::{self, statement_list::StatementLocation},
+            Keyword, Node, Punctuator, Span,
+        },
         lexer::{Error as LexError, InputElement, TokenKind},
         parser::expression::await_expr::AwaitExpression,
     },
@@ -48,7 +52,7 @@ use crate::{
 };
 use labelled_stm::LabelledStatement;
 
-use std::io::Read;
+use std::{collections::HashSet, io::Read};
 
 /// Statement parsing.
 ///
@@ -97,6 +101,33 @@ impl Statement {
             allow_return: allow_return.into(),
         }
     }
+
+    /// Parses a statement nested in another one, like the body of an `if` statement or of a
+    /// loop, with its location.
+    ///
+    /// The statements which are not blocks are numbered like the items of the statement lists,
+    /// so that they are traced and covered as well. The items of a block are numbered by its
+    /// statement list.
+    pub(super) fn parse_nested<R>(
+        self,
+        cursor: &mut Cursor<R>,
+    ) -> Result<(Node, StatementLocation), ParseError>
+    where
+        R: Read,
+    {
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+        let statement = self.parse(cursor)?;
+        if let Node::Block(_) = statement {
+            return Ok((statement, StatementLocation::default()));
+        }
+
+        let end = cursor.previous_token_end().max(start);
+        let span = Span::new(start, end);
+        Ok((
+            statement,
+            StatementLocation::new(span, cursor.add_statement(span)),
+        ))
+    }
 }
 
 impl<R> TokenParser<R> for Statement
@@ -107,6 +138,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Statement", "Parsing");
+        let _nesting = cursor.enter_nested()?;
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
@@ -178,7 +210,10 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
-            TokenKind::Identifier(_) => {
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(Keyword::Async)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Of) => {
                 // Labelled Statement check
                 cursor.set_goal(InputElement::Div);
                 let tok = cursor.peek(1)?;
@@ -263,13 +298,22 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut lexically_declared_names = HashSet::new();
 
         loop {
-            match cursor.peek(0)? {
+            let start = match cursor.peek(0)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
                 None => break,
-                _ => {}
-            }
+                Some(token) => token.span().start(),
+            };
+
+            // Only the statement list of scripts ends with the input, so these are the top-level
+            // statements.
+            let _timer = if self.break_nodes.is_empty() {
+                Some(BoaProfiler::global().start_event("top-level statement", category::PARSING))
+            } else {
+                None
+            };
 
             let item = StatementListItem::new(
                 self.allow_yield,
@@ -278,15 +322,43 @@ where
                 self.in_block,
             )
             .parse(cursor)?;
-            items.push(item);
+
+            // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
+            for name in lexically_declared_names_of(&item) {
+                if !lexically_declared_names.insert(name.to_owned()) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("Identifier {} has already been declared", name).into(),
+                        start,
+                    )));
+                }
+            }
+
+            // The span ends with the last token of the item, without the semicolon.
+            let end = cursor.previous_token_end().max(start);
+            let span = Span::new(start, end);
+            items.push((item, (span, cursor.add_statement(span))));
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
         }
 
-        items.sort_by(Node::hoistable_order);
+        let _timer = BoaProfiler::global().start_event("StatementList", category::HOISTING);
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
 
-        Ok(items.into())
+        let (items, statements): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        let (spans, indices): (Vec<_>, Vec<_>) = statements.into_iter().unzip();
+        Ok(node::StatementList::from(items)
+            .with_spans(spans)
+            .with_statement_indices(indices))
+    }
+}
+
+/// Gets the names declared by the `let` and `const` declarations of a statement list item.
+fn lexically_declared_names_of(item: &Node) -> Vec<&str> {
+    match item {
+        Node::LetDeclList(list) => list.as_ref().iter().map(|decl| decl.name()).collect(),
+        Node::ConstDeclList(list) => list.as_ref().iter().map(|decl| decl.name()).collect(),
+        _ => Vec::new(),
     }
 }
 
@@ -334,9 +406,14 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementListItem", "Parsing");
         let strict_mode = cursor.strict_mode();
+        let is_async_function = cursor.peek_is_async_function()?;
+        let is_let_declaration = cursor.peek_is_let_declaration()?;
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match *tok.kind() {
+            TokenKind::Keyword(Keyword::Async) if !is_async_function => {
+                Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+            }
             TokenKind::Keyword(Keyword::Function) | TokenKind::Keyword(Keyword::Async) => {
                 if strict_mode && self.in_block {
                     return Err(ParseError::lex(LexError::Syntax(
@@ -346,6 +423,9 @@ where
                 }
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
+            TokenKind::Keyword(Keyword::Let) if !is_let_declaration => {
+                Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+            }
             TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
@@ -406,6 +486,19 @@ where
 
         match next_token.kind() {
             TokenKind::Identifier(ref s) => Ok(s.clone()),
+            TokenKind::Keyword(k @ Keyword::Async) | TokenKind::Keyword(k @ Keyword::Of) => {
+                Ok(k.as_str().into())
+            }
+            TokenKind::Keyword(k @ Keyword::Let) => {
+                if cursor.strict_mode() {
+                    Err(ParseError::lex(LexError::Syntax(
+                        "let keyword in binding identifier not allowed in strict mode".into(),
+                        next_token.span().start(),
+                    )))
+                } else {
+                    Ok(k.as_str().into())
+                }
+            }
             TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => {
                 if cursor.strict_mode() {
                     Err(ParseError::lex(LexError::Syntax(
@@ -173,7 +173,10 @@ where
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
-                Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
+                Some(
+                    Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor)?,
+                )
             } else {
                 None
             }
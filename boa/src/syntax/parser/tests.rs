@@ -1,13 +1,14 @@
 //! Tests for the parser.
 
-use super::Parser;
+use super::{ParseError, Parser};
 use crate::syntax::ast::{
     node::{
-        field::GetConstField, ArrowFunctionDecl, Assign, BinOp, Call, FormalParameter,
-        FunctionDecl, Identifier, LetDecl, LetDeclList, New, Node, Return, StatementList, UnaryOp,
-        VarDecl, VarDeclList,
+        field::GetConstField, ArrayDecl, ArrowFunctionDecl, Assign, BinOp, Block, Break, Call,
+        Continue, DoWhileLoop, FormalParameter, FunctionDecl, Identifier, LetDecl, LetDeclList,
+        New, Node, Object, PropertyDefinition, Return, StatementList, Throw, UnaryOp, VarDecl,
+        VarDeclList, WhileLoop,
     },
-    op::{self, CompOp, LogOp, NumOp},
+    op::{self, AssignOp, CompOp, LogOp, NumOp},
     Const,
 };
 
@@ -292,3 +293,612 @@ fn spread_in_arrow_function() {
         ],
     );
 }
+
+/// Checks that a line terminator after `return` inserts a semicolon, so the value is not returned.
+#[test]
+fn asi_return_restricted_production() {
+    let s = r#"
+    function f() {
+        return
+        1
+    }
+    "#;
+
+    check_parser(
+        s,
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![
+                Return::new::<Node, Option<_>, Option<_>>(None, None).into(),
+                Const::from(1).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a line terminator before `++` makes it a prefix of the next expression.
+#[test]
+fn asi_postfix_increment_restricted_production() {
+    let s = r#"
+    a
+    ++b
+    "#;
+
+    check_parser(
+        s,
+        vec![
+            Identifier::from("a").into(),
+            UnaryOp::new(op::UnaryOp::IncrementPre, Identifier::from("b")).into(),
+        ],
+    );
+}
+
+/// Checks that a line terminator before `--` makes it a prefix of the next expression.
+#[test]
+fn asi_postfix_decrement_restricted_production() {
+    let s = r#"
+    a
+    --
+    b
+    "#;
+
+    check_parser(
+        s,
+        vec![
+            Identifier::from("a").into(),
+            UnaryOp::new(op::UnaryOp::DecrementPre, Identifier::from("b")).into(),
+        ],
+    );
+}
+
+/// Checks that a postfix `++` in the same line is still parsed as postfix.
+#[test]
+fn asi_postfix_increment_same_line() {
+    let s = r#"
+    a++
+    b
+    "#;
+
+    check_parser(
+        s,
+        vec![
+            UnaryOp::new(op::UnaryOp::IncrementPost, Identifier::from("a")).into(),
+            Identifier::from("b").into(),
+        ],
+    );
+}
+
+/// Checks that a line terminator after `throw` is a syntax error.
+#[test]
+fn asi_throw_restricted_production() {
+    check_invalid(
+        r#"
+    throw
+    1
+    "#,
+    );
+}
+
+/// Checks that a semicolon is inserted after a `throw` statement at the end of a line.
+#[test]
+fn asi_throw_end_of_line() {
+    let s = r#"
+    throw 1
+    a
+    "#;
+
+    check_parser(
+        s,
+        vec![
+            Throw::new(Const::from(1)).into(),
+            Identifier::from("a").into(),
+        ],
+    );
+}
+
+/// Checks that a line terminator after `break` and `continue` discards the label.
+#[test]
+fn asi_break_continue_restricted_production() {
+    let s = r#"
+    while (true) {
+        break
+        foo
+        continue
+        bar
+    }
+    "#;
+
+    check_parser(
+        s,
+        vec![WhileLoop::new(
+            Const::from(true),
+            Block::from(vec![
+                Break::new::<_, Box<str>>(None).into(),
+                Identifier::from("foo").into(),
+                Continue::new::<_, Box<str>>(None).into(),
+                Identifier::from("bar").into(),
+            ]),
+        )
+        .into()],
+    );
+}
+
+/// Checks that a semicolon is inserted after a `do...while` even without a line terminator.
+#[test]
+fn asi_do_while_no_semicolon() {
+    check_parser(
+        "do {} while (false) a",
+        vec![
+            DoWhileLoop::new(Block::from(vec![]), Const::from(false)).into(),
+            Identifier::from("a").into(),
+        ],
+    );
+}
+
+/// Checks that a semicolon is inserted before a `}` or at the end of the input.
+#[test]
+fn asi_before_close_block_and_eof() {
+    check_parser(
+        "{ a } b",
+        vec![
+            Block::from(vec![Identifier::from("a").into()]).into(),
+            Identifier::from("b").into(),
+        ],
+    );
+}
+
+/// Checks that no semicolon is inserted if the offending token is not separated by a line
+/// terminator, and it's not a `}` or the end of the input.
+#[test]
+fn asi_not_inserted_in_same_line() {
+    check_invalid("a = 1 b = 2");
+    check_invalid("var a = 1 var b = 2");
+    check_invalid("throw 1 2");
+    check_invalid("function f() { return 1 2 }");
+}
+
+/// Checks that no semicolon is inserted if the next line can continue the expression.
+#[test]
+fn asi_not_inserted_if_expression_continues() {
+    let s = r#"
+    a = b
+    (c)
+    "#;
+
+    check_parser(
+        s,
+        vec![Assign::new(
+            Identifier::from("a"),
+            Call::new(Identifier::from("b"), vec![Identifier::from("c").into()]),
+        )
+        .into()],
+    );
+}
+
+/// Creates the node that the parser generates for a regular expression literal.
+fn regex_literal(body: &str, flags: &str) -> Node {
+    New::from(Call::new(
+        Identifier::from("RegExp"),
+        vec![Const::from(body).into(), Const::from(flags).into()],
+    ))
+    .into()
+}
+
+#[test]
+fn regex_div_consecutive_divisions() {
+    check_parser(
+        "a / b / c",
+        vec![BinOp::new(
+            NumOp::Div,
+            BinOp::new(NumOp::Div, Identifier::from("a"), Identifier::from("b")),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn regex_div_after_parenthesized_expression() {
+    check_parser(
+        "(1) / 2",
+        vec![BinOp::new(NumOp::Div, Const::from(1), Const::from(2)).into()],
+    );
+}
+
+#[test]
+fn regex_div_after_array_and_object_literals() {
+    check_parser(
+        "x = [] / 2",
+        vec![Assign::new(
+            Identifier::from("x"),
+            BinOp::new(NumOp::Div, ArrayDecl::from(vec![]), Const::from(2)),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "x = {} / 2",
+        vec![Assign::new(
+            Identifier::from("x"),
+            BinOp::new(NumOp::Div, Object::from(vec![]), Const::from(2)),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn regex_div_assign_div() {
+    check_parser(
+        "a /= 2 / b",
+        vec![BinOp::new(
+            AssignOp::Div,
+            Identifier::from("a"),
+            BinOp::new(NumOp::Div, Const::from(2), Identifier::from("b")),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn regex_div_after_return() {
+    check_parser(
+        "function f() { return /re/g }",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![Return::new::<_, _, Option<Box<str>>>(regex_literal("re", "g"), None).into()],
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn regex_div_after_typeof() {
+    check_parser(
+        "typeof /re/",
+        vec![UnaryOp::new(op::UnaryOp::TypeOf, regex_literal("re", "")).into()],
+    );
+}
+
+#[test]
+fn regex_div_starting_with_eq() {
+    check_parser(
+        "x = /=/g",
+        vec![Assign::new(Identifier::from("x"), regex_literal("=", "g")).into()],
+    );
+}
+
+#[test]
+fn regex_div_after_block() {
+    let s = r#"
+    {}
+    /foo/g
+    "#;
+
+    check_parser(
+        s,
+        vec![Block::from(vec![]).into(), regex_literal("foo", "g")],
+    );
+}
+
+#[test]
+fn regex_div_in_arguments_and_arrays() {
+    check_parser(
+        "f(/a/, [/b/])",
+        vec![Call::new(
+            Identifier::from("f"),
+            vec![
+                regex_literal("a", ""),
+                ArrayDecl::from(vec![regex_literal("b", "")]).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn regex_div_slash_in_class() {
+    check_parser(
+        "x = /[/]/.source / 2",
+        vec![Assign::new(
+            Identifier::from("x"),
+            BinOp::new(
+                NumOp::Div,
+                GetConstField::new(regex_literal("[/]", ""), "source"),
+                Const::from(2),
+            ),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn super_property_only_in_methods() {
+    for valid in &[
+        "({ m() { return super.m(); } })",
+        "({ get x() { return super['x']; } })",
+        "({ m() { return () => super.x; } })",
+    ] {
+        assert!(
+            Parser::new(valid.as_bytes(), false).parse_all().is_ok(),
+            "{}",
+            valid
+        );
+    }
+
+    check_invalid("super.x");
+    check_invalid("({ m: function () { return super.x; } })");
+    check_invalid("({ m() { function f() { return super.x; } } })");
+    check_invalid("({ m() { (function (a = super.x) {}); } })");
+    check_invalid("({ m() { async function f() { await super.x; } } })");
+    check_invalid("({ m() { return super; } })");
+    check_invalid("({ m() { super(); } })");
+}
+
+#[test]
+fn hashbang_and_html_like_comments() {
+    check_parser(
+        "#!/usr/bin/env node\nx = 1; <!-- comment\n--> comment\nx",
+        vec![
+            Assign::new(Identifier::from("x"), Const::from(1)).into(),
+            Identifier::from("x").into(),
+        ],
+    );
+
+    // `-->` does not start a comment in the middle of a line.
+    check_invalid("x = 1; --> not a comment");
+}
+
+#[test]
+fn unexpected_eof() {
+    for incomplete in &[
+        "function f() {",
+        "{ {",
+        "if (x) {",
+        "foo(1,",
+        "[1, 2",
+        "1 +",
+        "`abc",
+        "`a ${",
+        "/* comment",
+    ] {
+        let error = Parser::new(incomplete.as_bytes(), false)
+            .parse_all()
+            .expect_err(incomplete);
+        assert!(error.is_unexpected_eof(), "{}: {}", incomplete, error);
+    }
+
+    for invalid in &["'abc", "\"abc\\", "var = ;", "{ }}"] {
+        let error = Parser::new(invalid.as_bytes(), false)
+            .parse_all()
+            .expect_err(invalid);
+        assert!(!error.is_unexpected_eof(), "{}: {}", invalid, error);
+    }
+}
+
+/// Nests `inner` `depth` times between `open` and `close`.
+fn nested(open: &str, inner: &str, close: &str, depth: usize) -> String {
+    format!("{}{}{}", open.repeat(depth), inner, close.repeat(depth))
+}
+
+/// Code nested at each level by the given strings, as `(open, inner, close)`.
+const NESTED_CODE: [(&str, &str, &str); 22] = [
+    ("x = (", "1", ")"),
+    ("x = [", "1", "]"),
+    ("x = {a: ", "1", "}"),
+    ("x = f(", "1", ")"),
+    ("x = `${", "1", "}`"),
+    ("x = !", "1", ""),
+    ("x = -", "1", ""),
+    ("x = typeof ", "1", ""),
+    ("x = new ", "F", "()"),
+    ("x = a => ", "1", ""),
+    ("x = a = ", "1", ""),
+    ("x = a ? ", "1", " : 1"),
+    ("x = a || ", "1", ""),
+    ("x = 2 ** ", "2", ""),
+    ("x = ", "a", ".a"),
+    ("x = ", "a", "[0]"),
+    ("x = ", "f", "()"),
+    ("x = function () { return ", "1", "}"),
+    ("{", "", "}"),
+    ("if (a) ", "b", ""),
+    ("while (a) ", "b", ""),
+    ("function f() {", "", "}"),
+];
+
+#[test]
+fn deeply_nested_code() {
+    let check = || {
+        for (open, inner, close) in NESTED_CODE.iter() {
+            let js = nested(open, inner, close, 20);
+            assert!(
+                Parser::new(js.as_bytes(), false).parse_all().is_ok(),
+                "could not parse `{}`",
+                js
+            );
+
+            let js = nested(open, inner, close, 100_000);
+            match Parser::new(js.as_bytes(), false).parse_all() {
+                Err(error @ ParseError::DepthLimitExceeded { .. }) => {
+                    assert!(error.position().is_some())
+                }
+                result => panic!("`{}...` gave {:?}", nested(open, "", "", 2), result),
+            }
+        }
+    };
+
+    // Debug builds use much more stack per level than release builds, so the code is parsed on
+    // a thread with a stack large enough for the default limit.
+    std::thread::Builder::new()
+        .stack_size(16 * 1024 * 1024)
+        .spawn(check)
+        .expect("could not spawn the test thread")
+        .join()
+        .expect("the test thread panicked");
+}
+
+#[test]
+fn long_operator_chains() {
+    // Eight operators of a chain count as one nesting level.
+    for separator in &[" + ", " && ", " ?? ", " < ", ", "] {
+        let js = vec!["a"; 1_000].join(separator);
+        assert!(
+            Parser::new(js.as_bytes(), false).parse_all().is_ok(),
+            "could not parse a chain of `{}`",
+            separator
+        );
+
+        let js = vec!["a"; 100_000].join(separator);
+        match Parser::new(js.as_bytes(), false).parse_all() {
+            Err(ParseError::DepthLimitExceeded { .. }) => {}
+            result => panic!("a chain of `{}` gave {:?}", separator, result),
+        }
+    }
+}
+
+#[test]
+fn depth_limit() {
+    let js = nested("x = (", "1", ")", 10);
+
+    let mut parser = Parser::new(js.as_bytes(), false);
+    parser.set_depth_limit(100);
+    assert!(parser.parse_all().is_ok());
+
+    let mut parser = Parser::new(js.as_bytes(), false);
+    parser.set_depth_limit(10);
+    let error = parser
+        .parse_all()
+        .expect_err("the depth limit was not checked");
+    assert_eq!(
+        error.to_string(),
+        "the code is nested too deeply at line 1, col 13"
+    );
+}
+
+/// Every keyword, and the literals spelled like identifiers, which are all identifier names.
+const IDENTIFIER_NAME_KEYWORDS: [&str; 41] = [
+    "await",
+    "async",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "let",
+    "new",
+    "of",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+    "true",
+    "false",
+    "null",
+];
+
+#[test]
+fn keywords_as_property_names() {
+    for &name in IDENTIFIER_NAME_KEYWORDS.iter() {
+        check_parser(
+            &format!("a.{}", name),
+            vec![GetConstField::new(Identifier::from("a"), name).into()],
+        );
+        check_parser(
+            &format!("a.b().{}", name),
+            vec![GetConstField::new(
+                Call::new(
+                    GetConstField::new(Identifier::from("a"), "b"),
+                    Vec::<Node>::new(),
+                ),
+                name,
+            )
+            .into()],
+        );
+        check_parser(
+            &format!("a = {{ {}: 1 }}", name),
+            vec![Assign::new(
+                Identifier::from("a"),
+                Object::from(vec![PropertyDefinition::property(name, Const::from(1))]),
+            )
+            .into()],
+        );
+    }
+}
+
+#[test]
+fn contextual_keywords_as_identifiers() {
+    for &name in &["async", "of", "let", "static", "get", "set", "as"] {
+        check_parser(
+            &format!("var {} = 1; {} + 1;", name, name),
+            vec![
+                VarDeclList::from(vec![VarDecl::new(name, Some(Const::from(1).into()))]).into(),
+                BinOp::new(NumOp::Add, Identifier::from(name), Const::from(1)).into(),
+            ],
+        );
+    }
+
+    check_parser(
+        "let = 1",
+        vec![Assign::new(Identifier::from("let"), Const::from(1)).into()],
+    );
+    check_parser(
+        "async\nfunction f() {}",
+        vec![
+            FunctionDecl::new(Box::from("f"), vec![], Vec::<Node>::new()).into(),
+            Identifier::from("async").into(),
+        ],
+    );
+    check_parser(
+        "let\nof = 1",
+        vec![LetDeclList::from(vec![LetDecl::new::<&str, Option<Node>>(
+            "of",
+            Some(Const::from(1).into()),
+        )])
+        .into()],
+    );
+
+    check_invalid("'use strict'; var let = 1;");
+    check_invalid("'use strict'; let = 1;");
+}
+
+#[test]
+fn contextual_keywords_in_both_roles() {
+    for source in &[
+        "let of = [1]; for (let x of of) {} for (var of of of) {}",
+        "var get = 5, set = 6; ({ get get() { return get; }, set set(v) { set = v; } });",
+        "function async() {} async function f() { async(); } var o = { async() {} };",
+        "var static = 1; ({ static() { return static; }, get static() { return 1; } });",
+        "let: for (;;) { break let; } async: of: while (true) { continue async; }",
+    ] {
+        if let Err(error) = Parser::new(source.as_bytes(), false).parse_all() {
+            panic!("failed to parse `{}`: {}", source, error);
+        }
+    }
+}
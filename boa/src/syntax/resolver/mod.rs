@@ -0,0 +1,367 @@
+//! Resolution of the identifiers to the slots of their bindings.
+//!
+//! After parsing, this pass annotates the identifiers that are bound in the scopes of their own
+//! function with a [`BindingLocator`], so that they are read and written by index at runtime,
+//! instead of looking up their name in every environment of the stack.
+//!
+//! Each scope of the resolver matches an environment that the interpreter pushes: the function
+//! environment, and the ones of the blocks, `for` loops, iterations of `for...in` and `for...of`
+//! loops and `catch` clauses. The slots are predicted from the order the bindings are declared in.
+//! The interpreter checks the name of the binding it finds at a slot and falls back to the lookup
+//! by name when it doesn't match, as happens when a declaration is skipped at runtime.
+//!
+//! The identifiers of the global scope and the ones captured from an outer function are always
+//! looked up by name.
+
+use crate::{
+    environment::lexical_environment::BindingLocator,
+    syntax::ast::node::{FormalParameter, Node, PropertyDefinition, StatementList},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Resolves the identifiers of a script, and of all the functions it contains.
+pub(in crate::syntax) fn resolve_script(script: &StatementList) {
+    let mut resolver = Resolver::default();
+    for node in script.items() {
+        resolver.resolve(node);
+    }
+}
+
+/// Resolves the identifiers of the body of a function, which runs in its own environment.
+fn resolve_function(parameters: &[FormalParameter], body: &[Node]) {
+    let mut scope = Scope::default();
+    for parameter in parameters {
+        scope.declare(parameter.name());
+    }
+    scope.declare("arguments");
+    for node in body {
+        scope.declare_bindings(node, true, true);
+    }
+
+    let mut resolver = Resolver {
+        scopes: vec![scope],
+    };
+    for node in body {
+        resolver.resolve(node);
+    }
+}
+
+/// A scope of the resolver, matching an environment pushed at runtime.
+#[derive(Debug, Default)]
+struct Scope<'a> {
+    /// The names of the bindings of the environment, in the order they are created.
+    bindings: Vec<&'a str>,
+}
+
+impl<'a> Scope<'a> {
+    /// Declares a binding, if it is not already declared.
+    fn declare(&mut self, name: &'a str) {
+        if !self.bindings.contains(&name) {
+            self.bindings.push(name);
+        }
+    }
+
+    /// Declares the bindings that `node` creates in the environment it runs in.
+    ///
+    /// `lexical` declares the `let` and `const` declarations, which are scoped to the nearest
+    /// environment, and `var_scoped` declares the `var` and function declarations, which are
+    /// scoped to the function.
+    fn declare_bindings(&mut self, node: &'a Node, lexical: bool, var_scoped: bool) {
+        if !lexical && !var_scoped {
+            return;
+        }
+
+        match node {
+            Node::VarDeclList(list) if var_scoped => {
+                for var in list.as_ref() {
+                    self.declare(var.name());
+                }
+            }
+            Node::FunctionDecl(decl) if var_scoped => self.declare(decl.name()),
+            Node::LetDeclList(list) if lexical => {
+                for decl in list.as_ref() {
+                    self.declare(decl.name());
+                }
+            }
+            Node::ConstDeclList(list) if lexical => {
+                for decl in list.as_ref() {
+                    self.declare(decl.name());
+                }
+            }
+            Node::If(if_node) => {
+                self.declare_bindings(if_node.body(), lexical, var_scoped);
+                if let Some(else_node) = if_node.else_node() {
+                    self.declare_bindings(else_node, lexical, var_scoped);
+                }
+            }
+            Node::WhileLoop(while_loop) => {
+                self.declare_bindings(while_loop.expr(), lexical, var_scoped);
+            }
+            Node::DoWhileLoop(do_while) => {
+                self.declare_bindings(do_while.body(), lexical, var_scoped);
+            }
+            Node::Switch(switch) => {
+                for case in switch.cases() {
+                    for node in case.body().items() {
+                        self.declare_bindings(node, lexical, var_scoped);
+                    }
+                }
+                for node in switch.default().unwrap_or_default() {
+                    self.declare_bindings(node, lexical, var_scoped);
+                }
+            }
+            // The statements below run in environments of their own.
+            Node::Block(block) => {
+                for node in block.items() {
+                    self.declare_bindings(node, false, var_scoped);
+                }
+            }
+            Node::ForLoop(for_loop) => {
+                if let Some(init) = for_loop.init() {
+                    self.declare_bindings(init, false, var_scoped);
+                }
+                self.declare_bindings(for_loop.body(), false, var_scoped);
+            }
+            Node::ForInLoop(for_in) => {
+                self.declare_bindings(for_in.variable(), false, var_scoped);
+                self.declare_bindings(for_in.body(), false, var_scoped);
+            }
+            Node::ForOfLoop(for_of) => {
+                self.declare_bindings(for_of.variable(), false, var_scoped);
+                self.declare_bindings(for_of.body(), false, var_scoped);
+            }
+            Node::Try(try_node) => {
+                for node in try_node.block().items() {
+                    self.declare_bindings(node, false, var_scoped);
+                }
+                if let Some(catch) = try_node.catch() {
+                    for node in catch.block().items() {
+                        self.declare_bindings(node, false, var_scoped);
+                    }
+                }
+                if let Some(finally) = try_node.finally() {
+                    for node in finally.items() {
+                        self.declare_bindings(node, false, var_scoped);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolves the identifiers of a function body, or of the global code.
+#[derive(Debug, Default)]
+struct Resolver<'a> {
+    /// The scopes the resolver is in, the innermost last.
+    ///
+    /// In the global code, the global environment has no scope, so that its identifiers are not
+    /// resolved.
+    scopes: Vec<Scope<'a>>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Finds the binding `name` in the scopes of the resolver.
+    fn locate(&self, name: &str) -> Option<BindingLocator> {
+        self.scopes
+            .iter()
+            .rev()
+            .enumerate()
+            .find_map(|(depth, scope)| {
+                scope
+                    .bindings
+                    .iter()
+                    .position(|binding| *binding == name)
+                    .map(|slot| BindingLocator::new(depth, slot))
+            })
+    }
+
+    /// Resolves the statements of a block, in a new scope with their lexical declarations.
+    fn resolve_block(&mut self, nodes: &'a [Node]) {
+        let mut scope = Scope::default();
+        for node in nodes {
+            scope.declare_bindings(node, true, false);
+        }
+
+        self.scopes.push(scope);
+        for node in nodes {
+            self.resolve(node);
+        }
+        self.scopes.pop();
+    }
+
+    /// Resolves the identifiers of `node`.
+    fn resolve(&mut self, node: &'a Node) {
+        match node {
+            Node::Identifier(identifier) => {
+                if let Some(locator) = self.locate(identifier.as_ref()) {
+                    identifier.set_binding(locator);
+                }
+            }
+            Node::ArrayDecl(array) => self.resolve_all(array.as_ref()),
+            Node::ArrowFunctionDecl(decl) => resolve_function(decl.params(), decl.body()),
+            Node::Assign(assign) => {
+                self.resolve(assign.lhs());
+                self.resolve(assign.rhs());
+            }
+            Node::AsyncFunctionDecl(decl) => resolve_function(decl.parameters(), decl.body()),
+            Node::AsyncFunctionExpr(expr) => resolve_function(expr.parameters(), expr.body()),
+            Node::BinOp(bin_op) => {
+                self.resolve(bin_op.lhs());
+                self.resolve(bin_op.rhs());
+            }
+            Node::Block(block) => self.resolve_block(block.items()),
+            Node::Call(call) => {
+                self.resolve(call.expr());
+                self.resolve_all(call.args());
+            }
+            Node::ConditionalOp(op) => {
+                self.resolve(op.cond());
+                self.resolve(op.if_true());
+                self.resolve(op.if_false());
+            }
+            Node::ConstDeclList(list) => {
+                for decl in list.as_ref() {
+                    if let Some(init) = decl.init() {
+                        self.resolve(init);
+                    }
+                }
+            }
+            Node::DoWhileLoop(do_while) => {
+                self.resolve(do_while.body());
+                self.resolve(do_while.cond());
+            }
+            Node::FunctionDecl(decl) => resolve_function(decl.parameters(), decl.body()),
+            Node::FunctionExpr(expr) => resolve_function(expr.parameters(), expr.body()),
+            Node::GetConstField(get_const_field) => self.resolve(get_const_field.obj()),
+            Node::GetField(get_field) => {
+                self.resolve(get_field.obj());
+                self.resolve(get_field.field());
+            }
+            Node::ForLoop(for_loop) => {
+                let mut scope = Scope::default();
+                if let Some(init) = for_loop.init() {
+                    scope.declare_bindings(init, true, false);
+                }
+
+                self.scopes.push(scope);
+                if let Some(init) = for_loop.init() {
+                    self.resolve(init);
+                }
+                if let Some(condition) = for_loop.condition() {
+                    self.resolve(condition);
+                }
+                if let Some(final_expr) = for_loop.final_expr() {
+                    self.resolve(final_expr);
+                }
+                self.resolve(for_loop.body());
+                self.scopes.pop();
+            }
+            Node::ForInLoop(for_in) => {
+                self.resolve(for_in.expr());
+                self.resolve_iteration(for_in.variable(), for_in.body());
+            }
+            Node::ForOfLoop(for_of) => {
+                self.resolve(for_of.iterable());
+                self.resolve_iteration(for_of.variable(), for_of.body());
+            }
+            Node::If(if_node) => {
+                self.resolve(if_node.cond());
+                self.resolve(if_node.body());
+                if let Some(else_node) = if_node.else_node() {
+                    self.resolve(else_node);
+                }
+            }
+            Node::LetDeclList(list) => {
+                for decl in list.as_ref() {
+                    if let Some(init) = decl.init() {
+                        self.resolve(init);
+                    }
+                }
+            }
+            Node::New(new) => {
+                self.resolve(new.expr());
+                self.resolve_all(new.args());
+            }
+            Node::Object(object) => {
+                for property in object.properties() {
+                    match property {
+                        PropertyDefinition::Property(_, value)
+                        | PropertyDefinition::SpreadObject(value) => self.resolve(value),
+                        PropertyDefinition::MethodDefinition(_, _, expr) => {
+                            resolve_function(expr.parameters(), expr.body())
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Node::Return(return_node) => {
+                if let Some(expr) = return_node.expr() {
+                    self.resolve(expr);
+                }
+            }
+            Node::Switch(switch) => {
+                self.resolve(switch.val());
+                for case in switch.cases() {
+                    self.resolve(case.condition());
+                    self.resolve_all(case.body().items());
+                }
+                if let Some(default) = switch.default() {
+                    self.resolve_all(default);
+                }
+            }
+            Node::Spread(spread) => self.resolve(spread.val()),
+            Node::Throw(throw) => self.resolve(throw.expr()),
+            Node::Try(try_node) => {
+                self.resolve_block(try_node.block().items());
+                if let Some(catch) = try_node.catch() {
+                    // The parameter is bound in an environment around the one of the block.
+                    let mut scope = Scope::default();
+                    if let Some(parameter) = catch.parameter() {
+                        scope.declare(parameter);
+                    }
+
+                    self.scopes.push(scope);
+                    self.resolve_block(catch.block().items());
+                    self.scopes.pop();
+                }
+                if let Some(finally) = try_node.finally() {
+                    self.resolve_block(finally.items());
+                }
+            }
+            Node::UnaryOp(unary_op) => self.resolve(unary_op.target()),
+            Node::VarDeclList(list) => {
+                for var in list.as_ref() {
+                    if let Some(init) = var.init() {
+                        self.resolve(init);
+                    }
+                }
+            }
+            Node::WhileLoop(while_loop) => {
+                self.resolve(while_loop.cond());
+                self.resolve(while_loop.expr());
+            }
+            _ => {}
+        }
+    }
+
+    /// Resolves the identifiers of all the `nodes`.
+    fn resolve_all(&mut self, nodes: &'a [Node]) {
+        for node in nodes {
+            self.resolve(node);
+        }
+    }
+
+    /// Resolves the body of a `for...in` or `for...of` loop, each iteration of which runs in an
+    /// environment with the lexical declaration of the loop, if any.
+    fn resolve_iteration(&mut self, variable: &'a Node, body: &'a Node) {
+        let mut scope = Scope::default();
+        scope.declare_bindings(variable, true, false);
+
+        self.scopes.push(scope);
+        self.resolve(body);
+        self.scopes.pop();
+    }
+}
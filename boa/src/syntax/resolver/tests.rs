@@ -0,0 +1,120 @@
+//! Tests for the resolution of identifiers.
+
+use crate::{
+    environment::lexical_environment::BindingLocator,
+    syntax::{ast::node::Node, Parser},
+};
+
+/// Parses `js` and returns the locators of the identifiers of the expression statements of its
+/// last block.
+#[track_caller]
+fn resolve_last_block(js: &str) -> Vec<Option<BindingLocator>> {
+    let script = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse");
+
+    let mut items = script.items();
+    loop {
+        let nested = items.iter().rev().find_map(|node| match node {
+            Node::Block(block) => Some(block.items()),
+            Node::FunctionDecl(decl) => Some(decl.body()),
+            Node::ForLoop(for_loop) => match for_loop.body() {
+                Node::Block(block) => Some(block.items()),
+                _ => None,
+            },
+            Node::Try(try_node) => try_node.catch().map(|catch| catch.block().items()),
+            _ => None,
+        });
+        match nested {
+            Some(nested) => items = nested,
+            None => break,
+        }
+    }
+
+    items
+        .iter()
+        .filter_map(|node| match node {
+            Node::Identifier(identifier) => Some(identifier.binding()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn function_bindings() {
+    let locators = resolve_last_block(
+        r#"
+        function f(a) {
+            var b;
+            {
+                let c;
+                a; b; c; d; arguments;
+            }
+        }
+        "#,
+    );
+
+    assert_eq!(
+        locators,
+        vec![
+            Some(BindingLocator::new(1, 0)),
+            Some(BindingLocator::new(1, 2)),
+            Some(BindingLocator::new(0, 0)),
+            None,
+            Some(BindingLocator::new(1, 1)),
+        ]
+    );
+}
+
+#[test]
+fn shadowed_bindings() {
+    let locators = resolve_last_block(
+        r#"
+        function f(a) {
+            for (let a = 0; a < 1; a++) {
+                let b;
+                a; b;
+            }
+        }
+        "#,
+    );
+
+    assert_eq!(
+        locators,
+        vec![
+            Some(BindingLocator::new(1, 0)),
+            Some(BindingLocator::new(0, 0))
+        ]
+    );
+}
+
+#[test]
+fn catch_parameter() {
+    let locators = resolve_last_block(
+        r#"
+        function f() {
+            try {} catch (e) {
+                e;
+            }
+        }
+        "#,
+    );
+
+    assert_eq!(locators, vec![Some(BindingLocator::new(1, 0))]);
+}
+
+#[test]
+fn global_and_captured_bindings() {
+    let locators = resolve_last_block(
+        r#"
+        let a;
+        function f(b) {
+            function g() {
+                a; b; g;
+            }
+        }
+        "#,
+    );
+
+    assert_eq!(locators, vec![None, None, None]);
+}
@@ -0,0 +1,279 @@
+//! Tracing of the statements run by a [`Context`], for debuggers and step-through tools.
+//!
+//! The hook set with [`Context::set_trace_hook`] is called before each statement is executed,
+//! with a [`TraceEvent`] describing where the script is. The script waits for the hook to return,
+//! so a debugger pauses it by blocking in the hook until the user resumes it, and stops it by
+//! returning [`TraceControl::Stop`]. The bodies of the `if` statements and loops written without
+//! braces are traced like the statements of a block.
+//!
+//! Only the statements of the tree-walking interpreter are traced: the code of the contexts using
+//! the VM is interpreted while a hook is set.
+
+use crate::{context::ErrorKind, environment::scope::Scope, syntax::ast::Span, Context, Value};
+use std::fmt;
+
+/// What the script does once the trace hook returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceControl {
+    /// Runs the statement.
+    Continue,
+    /// Stops the script before the statement.
+    ///
+    /// An error is thrown in its place, and again before every statement the script runs
+    /// afterwards, so that `catch` and `finally` blocks cannot resume it. The evaluation then
+    /// fails with [`TerminationReason::TraceHook`](crate::error::TerminationReason::TraceHook).
+    Stop,
+}
+
+/// A statement about to be executed, passed to the trace hook.
+#[derive(Clone, Copy)]
+pub struct TraceEvent<'a> {
+    span: Option<Span>,
+    context: &'a Context,
+}
+
+impl<'a> TraceEvent<'a> {
+    /// Creates a new `TraceEvent`.
+    fn new(span: Option<Span>, context: &'a Context) -> Self {
+        Self { span, context }
+    }
+
+    /// Gets the span of the source code of the statement.
+    ///
+    /// It is `None` for the statements that were not parsed, such as the ones built with the
+    /// constructors of the [`node`](crate::syntax::ast::node) module, and for the bodies of the
+    /// arrow functions without braces.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Gets the name of the source code of the statement, such as its file name.
+    ///
+    /// It is `None` if the statements were executed with [`Context::execute_ast`].
+    #[inline]
+    pub fn source_name(&self) -> Option<&'a str> {
+        self.context.active_source().map(|source| source.name())
+    }
+
+    /// Gets the name of the function the statement is in, or `None` at the top level of the
+    /// script.
+    ///
+    /// The name is read from the own data property of the function, so that no getter runs, and
+    /// is empty for anonymous functions.
+    pub fn function_name(&self) -> Option<String> {
+        let function = self
+            .context
+            .realm()
+            .environment
+            .environments()
+            .find_map(|env| env.borrow().get_function_object())?;
+        let name = function
+            .get_own_property(&"name".into())
+            .and_then(|property| property.as_data_descriptor().map(|data| data.value()))
+            .and_then(|name| name.as_string().map(|name| name.to_string()))
+            .unwrap_or_default();
+        Some(name)
+    }
+
+    /// Returns the scopes of the statement, from the innermost one to the global scope.
+    #[inline]
+    pub fn scopes(&self) -> impl Iterator<Item = Scope> + 'a {
+        self.context.scopes()
+    }
+
+    /// Gets the value of the variable `name` in the scope of the statement.
+    ///
+    /// Returns `None` if there is no such variable, or if it is a `let` or `const` variable
+    /// which has not been initialized yet. No getter runs, so the variables that are accessor
+    /// properties of the global object or of a `with` object are `undefined`.
+    pub fn variable(&self, name: &str) -> Option<Value> {
+        self.context
+            .realm()
+            .environment
+            .get_binding_value(name)
+            .ok()
+    }
+}
+
+impl fmt::Debug for TraceEvent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraceEvent")
+            .field("span", &self.span)
+            .finish()
+    }
+}
+
+/// The trace hook of a [`Context`], with the error thrown once it stopped the script.
+pub(crate) struct Tracer {
+    hook: Box<dyn FnMut(TraceEvent<'_>) -> TraceControl>,
+    stop_error: Option<Value>,
+}
+
+impl Tracer {
+    /// Creates a new `Tracer`.
+    pub(crate) fn new(hook: Box<dyn FnMut(TraceEvent<'_>) -> TraceControl>) -> Self {
+        Self {
+            hook,
+            stop_error: None,
+        }
+    }
+
+    /// Calls the hook for the statement at `span`.
+    ///
+    /// Returns the error to throw in place of the statement if the script has been stopped.
+    pub(crate) fn trace(&mut self, span: Option<Span>, context: &mut Context) -> Option<Value> {
+        if self.stop_error.is_none() {
+            if (self.hook)(TraceEvent::new(span, context)) == TraceControl::Continue {
+                return None;
+            }
+            self.stop_error = Some(
+                context
+                    .construct_error(ErrorKind::Error, "the script was stopped by the trace hook"),
+            );
+        }
+        self.stop_error.clone()
+    }
+
+    /// Takes the error thrown once the hook stopped the script, which resumes it.
+    pub(crate) fn take_stop_error(&mut self) -> Option<Value> {
+        self.stop_error.take()
+    }
+}
+
+impl fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracer")
+            .field("stop_error", &self.stop_error)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{EvalError, TerminationReason};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn line_numbers() {
+        let mut context = Context::new();
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let recorded = lines.clone();
+        context.set_trace_hook(move |event: TraceEvent<'_>| {
+            let line = event.span().unwrap().start().line_number();
+            recorded.borrow_mut().push((line, event.function_name()));
+            TraceControl::Continue
+        });
+
+        let source = r#"
+            function double(x) {
+                let y = x * 2;
+                return y;
+            }
+            var result = double(1);
+            result + 1;
+        "#;
+        assert_eq!(context.eval(source).unwrap(), Value::from(3));
+
+        let double = Some(String::from("double"));
+        assert_eq!(
+            *lines.borrow(),
+            [
+                (2, None),
+                (6, None),
+                (3, double.clone()),
+                (4, double),
+                (7, None)
+            ]
+        );
+    }
+
+    #[test]
+    fn bodies_without_braces() {
+        let mut context = Context::new();
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let recorded = lines.clone();
+        context.set_trace_hook(move |event: TraceEvent<'_>| {
+            recorded
+                .borrow_mut()
+                .push(event.span().unwrap().start().line_number());
+            TraceControl::Continue
+        });
+
+        let source = r#"
+            var i = 0;
+            while (i < 2)
+                i++;
+            if (i > 1) i = 0;
+            else
+                i = 1;
+        "#;
+        context.eval(source).unwrap();
+
+        assert_eq!(*lines.borrow(), [2, 3, 4, 4, 5, 5]);
+    }
+
+    #[test]
+    fn variables() {
+        let mut context = Context::new();
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let recorded = values.clone();
+        context.set_trace_hook(move |event: TraceEvent<'_>| {
+            recorded.borrow_mut().push(event.variable("y"));
+            TraceControl::Continue
+        });
+
+        context
+            .eval("function f(x) { let y = x + 1; return y; } f(41);")
+            .unwrap();
+
+        // `y` is not declared at the top level, and not initialized before its declaration.
+        assert_eq!(*values.borrow(), [None, None, None, Some(Value::from(42))]);
+    }
+
+    #[test]
+    fn stop() {
+        let mut context = Context::new();
+        context.set_trace_hook(|event: TraceEvent<'_>| {
+            if event.span().unwrap().start().line_number() == 4 {
+                TraceControl::Stop
+            } else {
+                TraceControl::Continue
+            }
+        });
+
+        let source = r#"
+            var reached = "try";
+            try {
+                reached = "stopped";
+            } catch (e) {
+                reached = "catch";
+            } finally {
+                reached = "finally";
+            }
+            reached = "end";
+        "#;
+        assert!(matches!(
+            context.eval(source).unwrap_err(),
+            EvalError::Terminated(TerminationReason::TraceHook)
+        ));
+        assert_eq!(context.eval("reached").unwrap(), Value::from("try"));
+    }
+
+    #[test]
+    fn remove_hook() {
+        let mut context = Context::new();
+        let count = Rc::new(RefCell::new(0));
+        let counted = count.clone();
+        context.set_trace_hook(move |_: TraceEvent<'_>| {
+            *counted.borrow_mut() += 1;
+            TraceControl::Continue
+        });
+
+        context.eval("1; 2;").unwrap();
+        context.remove_trace_hook();
+        context.eval("3; 4;").unwrap();
+        assert_eq!(*count.borrow(), 2);
+    }
+}
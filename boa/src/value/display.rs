@@ -1,272 +1,285 @@
 use super::*;
 
 /// This object is used for displaying a `Value`.
+///
+/// It inspects the value the way the REPL prints results by default: strings are quoted, and
+/// objects are printed with their properties. It is created by [`Value::display`], and its
+/// methods change how the value is inspected.
+///
+/// This is not the `ToString` conversion of the specification, which is done by
+/// [`Value::to_string`].
 #[derive(Debug, Clone, Copy)]
 pub struct ValueDisplay<'value> {
     pub(super) value: &'value Value,
+    pub(super) depth_limit: usize,
+    pub(super) quote_strings: bool,
+    pub(super) show_hidden: bool,
 }
 
-/// A helper macro for printing objects
-/// Can be used to print both properties and internal slots
-/// All of the overloads take:
-/// - The object to be printed
-/// - The function with which to print
-/// - The indentation for the current level (for nested objects)
-/// - A HashSet with the addresses of the already printed objects for the current branch
-///      (used to avoid infinite loops when there are cyclic deps)
-macro_rules! print_obj_value {
-    (all of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr) => {
-        {
-            let mut internals = print_obj_value!(internals of $obj, $display_fn, $indent, $encounters);
-            let mut props = print_obj_value!(props of $obj, $display_fn, $indent, $encounters, true);
-
-            props.reserve(internals.len());
-            props.append(&mut internals);
+impl<'value> ValueDisplay<'value> {
+    /// The depth up to which nested objects are printed by default.
+    pub(super) const DEFAULT_DEPTH: usize = 2;
 
-            props
+    /// Creates the display of a value, with the default options.
+    #[inline]
+    pub(super) fn new(value: &'value Value) -> Self {
+        Self {
+            value,
+            depth_limit: Self::DEFAULT_DEPTH,
+            quote_strings: true,
+            show_hidden: false,
         }
-    };
-    (internals of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr) => {
-        {
-            let object = $obj.borrow();
-            if object.prototype_instance().is_object() {
-                vec![format!(
-                    "{:>width$}: {}",
-                    "__proto__",
-                    $display_fn(object.prototype_instance(), $encounters, $indent.wrapping_add(4), true),
-                    width = $indent,
-                )]
-            } else {
-                vec![format!(
-                    "{:>width$}: {}",
-                    "__proto__",
-                    object.prototype_instance().display(),
-                    width = $indent,
-                )]
-            }
-        }
-    };
-    (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
-        print_obj_value!(impl $obj, |(key, val)| {
-            if val.is_data_descriptor() {
-                let v = &val
-                    .as_data_descriptor()
-                    .unwrap()
-                    .value();
-                format!(
-                    "{:>width$}: {}",
-                    key,
-                    $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals),
-                    width = $indent,
-                )
-            } else {
-               let accessor = val.as_accessor_descriptor().unwrap();
-               let display = match (accessor.setter().is_some(), accessor.getter().is_some()) {
-                    (true, true) => "Getter & Setter",
-                    (true, false) => "Setter",
-                    (false, true) => "Getter",
-                    _ => "No Getter/Setter"
-                };
-               format!("{:>width$}: {}", key, display, width = $indent)
-            }
-        })
-    };
+    }
 
-    // A private overload of the macro
-    // DO NOT use directly
-    (impl $v:expr, $f:expr) => {
-        $v
-            .borrow()
-            .iter()
-            .map($f)
-            .collect::<Vec<String>>()
-    };
-}
+    /// Sets the depth up to which nested objects and arrays are printed, the deeper ones are
+    /// summarized as `[Object]` or `[Array]`.
+    #[inline]
+    pub fn depth(mut self, depth_limit: usize) -> Self {
+        self.depth_limit = depth_limit;
+        self
+    }
 
-pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children: bool) -> String {
-    match x {
-        // We don't want to print private (compiler) or prototype properties
-        Value::Object(ref v) => {
-            // Can use the private "type" field of an Object to match on
-            // which type of Object it represents for special printing
-            match v.borrow().data {
-                ObjectData::String(ref string) => format!("String {{ \"{}\" }}", string),
-                ObjectData::Boolean(boolean) => format!("Boolean {{ {} }}", boolean),
-                ObjectData::Number(rational) => {
-                    if rational.is_sign_negative() && rational == 0.0 {
-                        "Number { -0 }".to_string()
-                    } else {
-                        let mut buffer = ryu_js::Buffer::new();
-                        format!("Number {{ {} }}", buffer.format(rational))
-                    }
-                }
-                ObjectData::Array => {
-                    let len = v
-                        .get_own_property(&PropertyKey::from("length"))
-                        // TODO: do this in a better way `unwrap`
-                        .unwrap()
-                        // FIXME: handle accessor descriptors
-                        .as_data_descriptor()
-                        .unwrap()
-                        .value()
-                        .as_number()
-                        .map(|n| n as i32)
-                        .unwrap_or_default();
+    /// Sets whether the strings are quoted.
+    ///
+    /// Quoted strings are printed in double quotes, wherever they are. Otherwise, the string
+    /// being displayed is printed as is and the nested strings are printed in single quotes,
+    /// the way `console.log` prints them.
+    #[inline]
+    pub fn quote_strings(mut self, quote_strings: bool) -> Self {
+        self.quote_strings = quote_strings;
+        self
+    }
 
-                    if print_children {
-                        if len == 0 {
-                            return String::from("[]");
-                        }
+    /// Sets whether the non-enumerable properties of the objects are printed, with their key in
+    /// square brackets.
+    #[inline]
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+}
 
-                        let arr = (0..len)
-                            .map(|i| {
-                                // Introduce recursive call to stringify any objects
-                                // which are part of the Array
-                                log_string_from(
-                                    &v.get_own_property(&i.into())
-                                        // FIXME: handle accessor descriptors
-                                        .and_then(|p| p.as_data_descriptor().map(|d| d.value()))
-                                        .unwrap_or_default(),
-                                    print_internals,
-                                    false,
-                                )
-                            })
-                            .collect::<Vec<String>>()
-                            .join(", ");
+/// The number of array items that are inspected before the rest are summarized.
+const MAX_INSPECTED_ARRAY_ITEMS: usize = 100;
 
-                        format!("[ {} ]", arr)
-                    } else {
-                        format!("Array({})", len)
-                    }
-                }
-                ObjectData::Map(ref map) => {
-                    let size = v
-                        .get_own_property(&PropertyKey::from("size"))
-                        // TODO: do this in a better way "unwrap"
-                        .unwrap()
-                        // FIXME: handle accessor descriptors
-                        .as_data_descriptor()
-                        .unwrap()
-                        .value()
-                        .as_number()
-                        .unwrap() as i32;
-                    if size == 0 {
-                        return String::from("Map(0)");
-                    }
+/// Returns the data value of an own property of the object, if it has one.
+fn own_data_value(object: &GcObject, key: &str) -> Option<Value> {
+    object
+        .get_own_property(&key.into())
+        .and_then(|p| p.as_data_descriptor().map(|d| d.value()))
+}
 
-                    if print_children {
-                        let mappings = map
-                            .iter()
-                            .map(|(key, value)| {
-                                let key = log_string_from(key, print_internals, false);
-                                let value = log_string_from(value, print_internals, false);
-                                format!("{} → {}", key, value)
-                            })
-                            .collect::<Vec<String>>()
-                            .join(", ");
-                        format!("Map {{ {} }}", mappings)
-                    } else {
-                        format!("Map({})", size)
-                    }
-                }
-                _ => display_obj(&x, print_internals),
+/// Quotes a nested string.
+fn quote_string(string: &str, options: &ValueDisplay<'_>) -> String {
+    if options.quote_strings {
+        format!("\"{}\"", string)
+    } else {
+        format!("'{}'", string.replace('\\', "\\\\").replace('\'', "\\'"))
+    }
+}
+
+/// Formats a property key, quoting it unless it is a valid identifier name.
+fn inspect_key(key: &PropertyKey, options: &ValueDisplay<'_>) -> String {
+    match key {
+        PropertyKey::String(ref string) => {
+            let mut chars = string.chars();
+            let is_identifier = chars
+                .next()
+                .map_or(false, |c| c.is_alphabetic() || c == '_' || c == '$')
+                && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+            if is_identifier {
+                string.to_string()
+            } else {
+                quote_string(string, options)
             }
         }
-        Value::Symbol(ref symbol) => symbol.to_string(),
-        _ => format!("{}", x.display()),
+        PropertyKey::Index(index) => quote_string(&index.to_string(), options),
+        PropertyKey::Symbol(ref symbol) => format!("[{}]", symbol),
     }
 }
 
-/// A helper function for specifically printing object values
-pub(crate) fn display_obj(v: &Value, print_internals: bool) -> String {
-    // A simple helper for getting the address of a value
-    // TODO: Find a more general place for this, as it can be used in other situations as well
-    fn address_of<T>(t: &T) -> usize {
-        let my_ptr: *const T = t;
-        my_ptr as usize
+/// Inspects a value found `depth` levels deep, keeping the chain of objects that are currently
+/// being printed in `ancestors` to detect cycles.
+fn inspect(
+    value: &Value,
+    depth: usize,
+    options: &ValueDisplay<'_>,
+    ancestors: &mut Vec<GcObject>,
+) -> String {
+    match value {
+        Value::Null => String::from("null"),
+        Value::Undefined => String::from("undefined"),
+        Value::Boolean(v) => v.to_string(),
+        Value::Symbol(ref symbol) => match symbol.description() {
+            Some(description) => format!("Symbol({})", description),
+            None => String::from("Symbol()"),
+        },
+        Value::String(ref string) if depth == 0 && !options.quote_strings => string.to_string(),
+        Value::String(ref string) => quote_string(string, options),
+        Value::Rational(v) => format_rational(*v),
+        Value::Integer(v) => v.to_string(),
+        Value::BigInt(ref num) => format!("{}n", num),
+        Value::Object(ref object) => inspect_object(object, depth, options, ancestors),
     }
+}
 
-    // We keep track of which objects we have encountered by keeping their
-    // in-memory address in this set
-    let mut encounters = HashSet::new();
+/// Inspects an object, see [`inspect`].
+fn inspect_object(
+    object: &GcObject,
+    depth: usize,
+    options: &ValueDisplay<'_>,
+    ancestors: &mut Vec<GcObject>,
+) -> String {
+    // Cycles are detected by identity, two distinct but equal objects are both printed.
+    if ancestors
+        .iter()
+        .any(|ancestor| GcObject::equals(ancestor, object))
+    {
+        return String::from("[Circular]");
+    }
 
-    if let Value::Object(object) = v {
-        if object.borrow().is_error() {
-            let name = v
-                .get_property("name")
-                .as_ref()
-                .and_then(|p| p.as_data_descriptor())
-                .map(|d| d.value())
-                .unwrap_or_else(Value::undefined);
-            let message = v
-                .get_property("message")
-                .as_ref()
-                .and_then(|p| p.as_data_descriptor())
-                .map(|d| d.value())
-                .unwrap_or_else(Value::undefined);
-            return format!("{}: {}", name.display(), message.display());
+    let (is_array, map_size) = {
+        let borrowed = object.borrow();
+        match borrowed.data {
+            ObjectData::Function(_) => {
+                return match own_data_value(object, "name") {
+                    Some(Value::String(ref name)) if !name.is_empty() => {
+                        format!("[Function: {}]", name)
+                    }
+                    _ => String::from("[Function (anonymous)]"),
+                };
+            }
+            ObjectData::Error => {
+                let name = own_data_value(object, "name")
+                    .or_else(|| {
+                        borrowed
+                            .prototype_instance()
+                            .get_property("name")
+                            .and_then(|p| p.as_data_descriptor().map(|d| d.value()))
+                    })
+                    .unwrap_or_default();
+                let message = own_data_value(object, "message").unwrap_or_default();
+                return format!(
+                    "{}: {}",
+                    inspect(&name, 0, options, ancestors),
+                    inspect(&message, 0, options, ancestors)
+                );
+            }
+            ObjectData::String(ref string) => {
+                return format!("[String: {}]", quote_string(string, options))
+            }
+            ObjectData::Boolean(boolean) => return format!("[Boolean: {}]", boolean),
+            ObjectData::Number(number) => return format!("[Number: {}]", format_rational(number)),
+            ObjectData::Symbol(ref symbol) => return format!("[Symbol: {}]", symbol),
+            ObjectData::Date(ref date) => return date.to_string(),
+            ObjectData::Array => (true, None),
+            ObjectData::Map(ref map) => (false, Some(map.len())),
+            _ => (false, None),
         }
+    };
+
+    if depth > options.depth_limit {
+        return String::from(match (is_array, map_size) {
+            (true, _) => "[Array]",
+            (_, Some(_)) => "[Map]",
+            _ => "[Object]",
+        });
     }
 
-    fn display_obj_internal(
-        data: &Value,
-        encounters: &mut HashSet<usize>,
-        indent: usize,
-        print_internals: bool,
-    ) -> String {
-        if let Value::Object(ref v) = *data {
-            // The in-memory address of the current object
-            let addr = address_of(v.as_ref());
+    ancestors.push(object.clone());
 
-            // We need not continue if this object has already been
-            // printed up the current chain
-            if encounters.contains(&addr) {
-                return String::from("[Cycle]");
-            }
+    let result = if is_array {
+        let len = own_data_value(object, "length")
+            .and_then(|length| length.as_number())
+            .map(|length| length as usize)
+            .unwrap_or_default();
 
-            // Mark the current object as encountered
-            encounters.insert(addr);
-
-            let result = if print_internals {
-                print_obj_value!(all of v, display_obj_internal, indent, encounters).join(",\n")
-            } else {
-                print_obj_value!(props of v, display_obj_internal, indent, encounters, print_internals)
-                        .join(",\n")
-            };
+        let mut items: Vec<String> = (0..len.min(MAX_INSPECTED_ARRAY_ITEMS))
+            .map(|index| match own_data_value(object, &index.to_string()) {
+                Some(item) => inspect(&item, depth + 1, options, ancestors),
+                None => String::from("<empty item>"),
+            })
+            .collect();
+        if len > MAX_INSPECTED_ARRAY_ITEMS {
+            items.push(format!(
+                "... {} more items",
+                len - MAX_INSPECTED_ARRAY_ITEMS
+            ));
+        }
 
-            // If the current object is referenced in a different branch,
-            // it will not cause an infinte printing loop, so it is safe to be printed again
-            encounters.remove(&addr);
+        if items.is_empty() {
+            String::from("[]")
+        } else {
+            format!("[ {} ]", items.join(", "))
+        }
+    } else if let Some(size) = map_size {
+        let entries: Vec<(Value, Value)> = object
+            .borrow()
+            .as_map_ref()
+            .map(|map| map.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+            .unwrap_or_default();
+        let entries: Vec<String> = entries
+            .iter()
+            .map(|(key, value)| {
+                format!(
+                    "{} => {}",
+                    inspect(key, depth + 1, options, ancestors),
+                    inspect(value, depth + 1, options, ancestors)
+                )
+            })
+            .collect();
 
-            let closing_indent = String::from_utf8(vec![b' '; indent.wrapping_sub(4)])
-                .expect("Could not create the closing brace's indentation string");
+        if entries.is_empty() {
+            format!("Map({}) {{}}", size)
+        } else {
+            format!("Map({}) {{ {} }}", size, entries.join(", "))
+        }
+    } else {
+        let properties: Vec<(PropertyKey, PropertyDescriptor)> = object
+            .borrow()
+            .iter()
+            .filter(|(_, property)| options.show_hidden || property.enumerable())
+            .map(|(key, property)| (key, property.clone()))
+            .collect();
+        let properties: Vec<String> = properties
+            .iter()
+            .map(|(key, property)| {
+                let value = match property {
+                    PropertyDescriptor::Data(data) => {
+                        inspect(&data.value(), depth + 1, options, ancestors)
+                    }
+                    PropertyDescriptor::Accessor(accessor) => {
+                        match (accessor.getter().is_some(), accessor.setter().is_some()) {
+                            (true, true) => String::from("[Getter/Setter]"),
+                            (true, false) => String::from("[Getter]"),
+                            (false, true) => String::from("[Setter]"),
+                            (false, false) => String::from("undefined"),
+                        }
+                    }
+                };
+                let key = inspect_key(key, options);
+                if property.enumerable() {
+                    format!("{}: {}", key, value)
+                } else {
+                    format!("[{}]: {}", key, value)
+                }
+            })
+            .collect();
 
-            format!("{{\n{}\n{}}}", result, closing_indent)
+        if properties.is_empty() {
+            String::from("{}")
         } else {
-            // Every other type of data is printed with the display method
-            format!("{}", data.display())
+            format!("{{ {} }}", properties.join(", "))
         }
-    }
+    };
 
-    display_obj_internal(v, &mut encounters, 4, print_internals)
+    ancestors.pop();
+    result
 }
 
 impl Display for ValueDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.value {
-            Value::Null => write!(f, "null"),
-            Value::Undefined => write!(f, "undefined"),
-            Value::Boolean(v) => write!(f, "{}", v),
-            Value::Symbol(ref symbol) => match symbol.description() {
-                Some(description) => write!(f, "Symbol({})", description),
-                None => write!(f, "Symbol()"),
-            },
-            Value::String(ref v) => write!(f, "\"{}\"", v),
-            Value::Rational(v) => format_rational(*v, f),
-            Value::Object(_) => write!(f, "{}", log_string_from(self.value, true, true)),
-            Value::Integer(v) => write!(f, "{}", v),
-            Value::BigInt(ref num) => write!(f, "{}n", num),
-        }
+        f.write_str(&inspect(self.value, 0, self, &mut Vec::new()))
     }
 }
 
@@ -275,11 +288,11 @@ impl Display for ValueDisplay<'_> {
 /// This function prints `-0` as `-0` instead of pasitive `0` as the specification says.
 /// This is done to make it easer for the user of the REPL to identify what is a `-0` vs `0`,
 /// since the REPL is not bound to the ECMAScript specification we can do this.
-fn format_rational(v: f64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+fn format_rational(v: f64) -> String {
     if v.is_sign_negative() && v == 0.0 {
-        f.write_str("-0")
+        String::from("-0")
     } else {
         let mut buffer = ryu_js::Buffer::new();
-        write!(f, "{}", buffer.format(v))
+        buffer.format(v).to_owned()
     }
 }
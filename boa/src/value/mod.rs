@@ -7,17 +7,16 @@ mod tests;
 
 use crate::{
     builtins::{
-        number::{f64_to_int32, f64_to_uint32},
+        number::{f64_to_int32, f64_to_uint32, string_to_number},
         BigInt, Number,
     },
     object::{GcObject, Object, ObjectData},
-    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    property::{Attribute, DataDescriptor, PartialDescriptor, PropertyDescriptor, PropertyKey},
     BoaProfiler, Context, Result,
 };
 use gc::{Finalize, Trace};
 use serde_json::{Number as JSONNumber, Value as JSONValue};
 use std::{
-    collections::HashSet,
     convert::TryFrom,
     f64::NAN,
     fmt::{self, Display},
@@ -215,49 +214,60 @@ impl Value {
     }
 
     /// Converts the `Value` to `JSON`.
+    ///
+    /// The values which have no JSON representation, such as `undefined`, are converted to
+    /// `null`.
     pub fn to_json(&self, context: &mut Context) -> Result<JSONValue> {
+        Ok(self.to_json_property(context)?.unwrap_or(JSONValue::Null))
+    }
+
+    /// Converts the `Value` to `JSON` as a property of an object or an array, after calling its
+    /// `toJSON` method.
+    ///
+    /// Returns `None` if the value is `undefined`, a symbol or a function, which the callers
+    /// omit from objects and replace by `null` in arrays.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    pub(crate) fn to_json_property(&self, context: &mut Context) -> Result<Option<JSONValue>> {
         let to_json = self.get_field("toJSON", context)?;
         if to_json.is_function() {
             let json_value = context.call(&to_json, self, &[])?;
-            return json_value.to_json(context);
+            return json_value.to_json_property(context);
         }
 
         match *self {
-            Self::Null => Ok(JSONValue::Null),
-            Self::Boolean(b) => Ok(JSONValue::Bool(b)),
-            Self::Object(ref obj) => obj.to_json(context),
-            Self::String(ref str) => Ok(JSONValue::String(str.to_string())),
+            Self::Null => Ok(Some(JSONValue::Null)),
+            Self::Boolean(b) => Ok(Some(JSONValue::Bool(b))),
+            Self::Object(ref obj) if obj.is_function() => Ok(None),
+            Self::Object(ref obj) => obj.to_json(context).map(Some),
+            Self::String(ref str) => Ok(Some(JSONValue::String(str.to_string()))),
             Self::Rational(num) => {
                 if num.is_finite() {
-                    Ok(JSONValue::Number(
+                    Ok(Some(JSONValue::Number(
                         JSONNumber::from_str(&Number::to_native_string(num))
                             .expect("invalid number found"),
-                    ))
+                    )))
                 } else {
-                    Ok(JSONValue::Null)
+                    Ok(Some(JSONValue::Null))
                 }
             }
-            Self::Integer(val) => Ok(JSONValue::Number(JSONNumber::from(val))),
+            Self::Integer(val) => Ok(Some(JSONValue::Number(JSONNumber::from(val)))),
             Self::BigInt(_) => {
                 Err(context.construct_type_error("BigInt value can't be serialized in JSON"))
             }
-            Self::Symbol(_) | Self::Undefined => {
-                unreachable!("Symbols and Undefined JSON Values depend on parent type");
-            }
+            Self::Symbol(_) | Self::Undefined => Ok(None),
         }
     }
 
-    /// This will tell us if we can exten an object or not, not properly implemented yet
-    ///
-    /// For now always returns true.
-    ///
-    /// For scalar types it should be false, for objects check the private field for extensibilaty.
-    /// By default true.
+    /// Returns true if the value is an extensible object.
     ///
-    /// <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal> would turn `extensible` to `false`
-    /// <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze> would also turn `extensible` to `false`
+    /// Primitive values are never extensible.
     pub fn is_extensible(&self) -> bool {
-        true
+        self.as_object()
+            .map_or(false, |object| object.is_extensible())
     }
 
     /// Returns true if the value is an object
@@ -418,8 +428,7 @@ impl Value {
         Key: Into<PropertyKey>,
     {
         self.as_object()
-            .map(|mut x| x.remove(&key.into()))
-            .is_some()
+            .map_or(false, |mut x| x.remove(&key.into()).is_some())
     }
 
     /// Resolve the property in the object.
@@ -458,6 +467,17 @@ impl Value {
         }
     }
 
+    /// Gets the property `key` of the value, reading the properties of a primitive through its
+    /// wrapper object, while the primitive remains the receiver of the getters.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getv
+    pub(crate) fn get_v(&self, key: &PropertyKey, context: &mut Context) -> Result<Self> {
+        self.to_object(context)?.get(key, self.clone(), context)
+    }
+
     /// Check to see if the Value has the field, mainly used by environment records.
     #[inline]
     pub fn has_field<K>(&self, key: K) -> bool
@@ -582,20 +602,51 @@ impl Value {
         }
     }
 
-    /// Returns an object that implements `Display`.
+    /// Returns an object that implements `Display` and inspects the value the way the REPL
+    /// prints results.
+    ///
+    /// Strings are quoted, nested objects and arrays are printed up to two levels deep, cycles
+    /// are shown as `[Circular]` and functions as `[Function: name]`. The options of the returned
+    /// [`ValueDisplay`] change the depth, the quoting and whether non-enumerable properties are
+    /// shown. Use [`Value::to_string`] for the `ToString` conversion of the specification.
     ///
     /// # Examples
     ///
     /// ```
-    /// use boa::Value;
+    /// use boa::{Context, Value};
     ///
-    /// let value = Value::number(3);
+    /// let mut context = Context::new();
+    /// let value = context.eval("({ a: [1, 'x'], b: { c: {} } })").unwrap();
     ///
-    /// println!("{}", value.display());
+    /// assert_eq!(value.display().to_string(), "{ a: [ 1, \"x\" ], b: { c: {} } }");
+    /// assert_eq!(value.display().depth(0).to_string(), "{ a: [Array], b: [Object] }");
+    /// assert_eq!(Value::from("x").display().to_string(), "\"x\"");
     /// ```
     #[inline]
     pub fn display(&self) -> ValueDisplay<'_> {
-        ValueDisplay { value: self }
+        ValueDisplay::new(self)
+    }
+
+    /// Returns an object that implements `Display` and inspects the value the way a developer
+    /// console does.
+    ///
+    /// This is [`Value::display`] with the given depth limit, and with the strings only quoted
+    /// when nested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::{Context, Value};
+    ///
+    /// let mut context = Context::new();
+    /// let value = context.eval("({ a: { b: ['x'] } })").unwrap();
+    ///
+    /// assert_eq!(value.display_obj(0).to_string(), "{ a: [Object] }");
+    /// assert_eq!(value.display_obj(2).to_string(), "{ a: { b: [ 'x' ] } }");
+    /// ```
+    #[inline]
+    pub fn display_obj(&self, depth_limit: usize) -> ValueDisplay<'_> {
+        self.display().depth(depth_limit).quote_strings(false)
     }
 
     /// Converts the value to a string.
@@ -660,7 +711,7 @@ impl Value {
                 object.insert_property(
                     PropertyKey::String("length".into()),
                     Value::from(string.encode_utf16().count()),
-                    Attribute::NON_ENUMERABLE,
+                    Attribute::CONSTANT,
                 );
                 Ok(object)
             }
@@ -684,19 +735,12 @@ impl Value {
 
     /// Converts the value to a `PropertyKey`, that can be used as a key for properties.
     ///
+    /// This is [`PropertyKey::from_value`].
+    ///
     /// See <https://tc39.es/ecma262/#sec-topropertykey>
+    #[inline]
     pub fn to_property_key(&self, context: &mut Context) -> Result<PropertyKey> {
-        Ok(match self {
-            // Fast path:
-            Value::String(string) => string.clone().into(),
-            Value::Symbol(symbol) => symbol.clone().into(),
-            // Slow path:
-            _ => match self.to_primitive(context, PreferredType::String)? {
-                Value::String(ref string) => string.clone().into(),
-                Value::Symbol(ref symbol) => symbol.clone().into(),
-                primitive => primitive.to_string(context)?.into(),
-            },
-        })
+        PropertyKey::from_value(self, context)
     }
 
     /// It returns value converted to a numeric value of type `Number` or `BigInt`.
@@ -809,13 +853,7 @@ impl Value {
             Value::Null => Ok(0.0),
             Value::Undefined => Ok(f64::NAN),
             Value::Boolean(b) => Ok(if b { 1.0 } else { 0.0 }),
-            // TODO: this is probably not 100% correct, see https://tc39.es/ecma262/#sec-tonumber-applied-to-the-string-type
-            Value::String(ref string) => {
-                if string.trim().is_empty() {
-                    return Ok(0.0);
-                }
-                Ok(string.parse().unwrap_or(f64::NAN))
-            }
+            Value::String(ref string) => Ok(string_to_number(string)),
             Value::Rational(number) => Ok(number),
             Value::Integer(integer) => Ok(f64::from(integer)),
             Value::Symbol(_) => Err(context.construct_type_error("argument must not be a symbol")),
@@ -860,10 +898,13 @@ impl Value {
         }
     }
 
+    /// Converts the value to a `PartialDescriptor`, throwing a `TypeError` if it is not an object.
+    ///
+    /// See: <https://tc39.es/ecma262/#sec-topropertydescriptor>
     #[inline]
-    pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
+    pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PartialDescriptor> {
         if let Self::Object(ref object) = self {
-            object.to_property_descriptor(context)
+            PartialDescriptor::from_object(object, context)
         } else {
             Err(context.construct_type_error("Property description must be an object"))
         }
@@ -924,6 +965,20 @@ pub enum Numeric {
     BigInt(RcBigInt),
 }
 
+impl Numeric {
+    /// Adds `delta` to the numeric value, which is how the increment and decrement operators
+    /// update it.
+    #[inline]
+    pub(crate) fn add_integer(&self, delta: i32) -> Self {
+        match self {
+            Self::Number(number) => Self::Number(number + f64::from(delta)),
+            Self::BigInt(bigint) => {
+                Self::BigInt((bigint.as_inner().clone() + BigInt::from(delta)).into())
+            }
+        }
+    }
+}
+
 impl From<f64> for Numeric {
     #[inline]
     fn from(value: f64) -> Self {
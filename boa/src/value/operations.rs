@@ -2,6 +2,31 @@ use super::*;
 use crate::builtins::number::{f64_to_int32, f64_to_uint32, Number};
 
 impl Value {
+    /// The `instanceof` operator, with `target` on its right-hand side.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-instanceofoperator
+    pub(crate) fn instance_of(&self, target: &Self, context: &mut Context) -> Result<bool> {
+        if let Some(object) = target.as_object() {
+            let key = context.well_known_symbols().has_instance_symbol();
+
+            match object.get_method(context, key)? {
+                Some(instance_of_handler) => Ok(instance_of_handler
+                    .call(target, &[self.clone()], context)?
+                    .to_boolean()),
+                None if object.is_callable() => object.ordinary_has_instance(context, self),
+                None => context.throw_type_error("right-hand side of 'instanceof' is not callable"),
+            }
+        } else {
+            context.throw_type_error(format!(
+                "right-hand side of 'instanceof' should be an object, got {}",
+                target.get_type().as_str()
+            ))
+        }
+    }
+
     #[inline]
     pub fn add(&self, other: &Self, context: &mut Context) -> Result<Value> {
         Ok(match (self, other) {
@@ -11,9 +36,13 @@ impl Value {
             (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x) + y),
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x + f64::from(*y)),
 
-            (Self::String(ref x), Self::String(ref y)) => Self::string(format!("{}{}", x, y)),
-            (Self::String(ref x), ref y) => Self::string(format!("{}{}", x, y.to_string(context)?)),
-            (ref x, Self::String(ref y)) => Self::string(format!("{}{}", x.to_string(context)?, y)),
+            (Self::String(ref x), Self::String(ref y)) => Self::string(RcString::concat(x, y)),
+            (Self::String(ref x), ref y) => {
+                Self::string(RcString::concat(x, &y.to_string(context)?))
+            }
+            (ref x, Self::String(ref y)) => {
+                Self::string(RcString::concat(&x.to_string(context)?, y))
+            }
             (Self::BigInt(ref n1), Self::BigInt(ref n2)) => {
                 Self::bigint(n1.as_inner().clone() + n2.as_inner().clone())
             }
@@ -24,10 +53,10 @@ impl Value {
                 other.to_primitive(context, PreferredType::Default)?,
             ) {
                 (Self::String(ref x), ref y) => {
-                    Self::string(format!("{}{}", x, y.to_string(context)?))
+                    Self::string(RcString::concat(x, &y.to_string(context)?))
                 }
                 (ref x, Self::String(ref y)) => {
-                    Self::string(format!("{}{}", x.to_string(context)?, y))
+                    Self::string(RcString::concat(&x.to_string(context)?, y))
                 }
                 (x, y) => match (x.to_numeric(context)?, y.to_numeric(context)?) {
                     (Numeric::Number(x), Numeric::Number(y)) => Self::rational(x + y),
@@ -141,8 +170,11 @@ impl Value {
             (Self::Integer(x), Self::Integer(y)) => {
                 if *y == 0 {
                     Self::nan()
+                } else if let Some(remainder) = x.checked_rem(*y) {
+                    Self::integer(remainder)
                 } else {
-                    Self::integer(x % *y)
+                    // `i32::MIN % -1` overflows, its result is `-0`.
+                    Self::rational(-0.0)
                 }
             }
             (Self::Rational(x), Self::Rational(y)) => Self::rational(x % y),
@@ -423,20 +455,12 @@ impl Value {
     #[inline]
     pub fn neg(&self, context: &mut Context) -> Result<Value> {
         Ok(match *self {
-            Self::Symbol(_) | Self::Undefined => Self::rational(NAN),
-            Self::Object(_) => Self::rational(match self.to_numeric_number(context) {
-                Ok(num) => -num,
-                Err(_) => NAN,
-            }),
-            Self::String(ref str) => Self::rational(match f64::from_str(str) {
-                Ok(num) => -num,
-                Err(_) => NAN,
-            }),
             Self::Rational(num) => Self::rational(-num),
             Self::Integer(num) => Self::rational(-f64::from(num)),
-            Self::Boolean(true) => Self::integer(1),
-            Self::Boolean(false) | Self::Null => Self::integer(0),
-            Self::BigInt(ref num) => Self::bigint(-num.as_inner().clone()),
+            _ => match self.to_numeric(context)? {
+                Numeric::Number(num) => Self::rational(-num),
+                Numeric::BigInt(ref num) => Self::bigint(-num.as_inner().clone()),
+            },
         })
     }
 
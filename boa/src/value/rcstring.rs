@@ -1,36 +1,197 @@
 use crate::gc::{empty_trace, Finalize, Trace};
 
+use once_cell::unsync::OnceCell;
 use std::{
     borrow::Borrow,
-    fmt::{self, Display},
+    cell::RefCell,
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
     ops::Deref,
     rc::Rc,
 };
 
-#[derive(Debug, Finalize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct RcString(Rc<str>);
+/// A shared, immutable string.
+///
+/// Concatenating strings with [`RcString::concat`] creates a rope, which holds the two strings
+/// instead of copying them, so that building a string in a loop takes linear time. The rope is
+/// flattened into a contiguous string the first time its text is read, and keeps it afterwards.
+#[derive(Finalize, Clone)]
+pub struct RcString(Rc<Inner>);
 
 unsafe impl Trace for RcString {
     empty_trace!();
 }
 
+/// The shared data of a [`RcString`].
+struct Inner {
+    /// The length of the string, in bytes.
+    len: usize,
+    /// The text of the string, once flattened.
+    flat: OnceCell<Box<str>>,
+    /// The strings concatenated by a rope, until it is flattened.
+    rope: RefCell<Option<(RcString, RcString)>>,
+}
+
 impl RcString {
+    /// The length below which concatenations are copied instead of creating a rope.
+    const MIN_ROPE_LENGTH: usize = 64;
+
+    /// Wraps the string, recording its data in the heap statistics.
+    #[inline]
+    fn new(string: Box<str>) -> Self {
+        crate::gc::track_data_allocation(string.len());
+        Self(Rc::new(Inner {
+            len: string.len(),
+            flat: OnceCell::from(string),
+            rope: RefCell::new(None),
+        }))
+    }
+
+    /// Concatenates two strings.
+    ///
+    /// Short results are copied, and long ones are ropes that copy the text of both strings only
+    /// when it is first read.
+    pub fn concat(left: &Self, right: &Self) -> Self {
+        if right.is_empty() {
+            return left.clone();
+        }
+        if left.is_empty() {
+            return right.clone();
+        }
+
+        let len = left.len() + right.len();
+        if len < Self::MIN_ROPE_LENGTH {
+            let mut string = String::with_capacity(len);
+            string.push_str(left);
+            string.push_str(right);
+            return string.into();
+        }
+
+        Self(Rc::new(Inner {
+            len,
+            flat: OnceCell::new(),
+            rope: RefCell::new(Some((left.clone(), right.clone()))),
+        }))
+    }
+
+    /// Gets the length of the string in bytes, without flattening it.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len
+    }
+
+    /// Returns `true` if the string is empty, without flattening it.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.len == 0
+    }
+
     #[inline]
     pub fn as_str(&self) -> &str {
-        &self.0
+        if let Some(flat) = self.0.flat.get() {
+            return flat;
+        }
+
+        let flat = self.flatten();
+        crate::gc::track_data_allocation(flat.len());
+        let _ = self.0.flat.set(flat);
+        // The parts of the rope are not needed anymore.
+        let rope = self.0.rope.borrow_mut().take();
+        drop(rope);
+        self.as_str()
+    }
+
+    /// Copies the text of the parts of a rope into a new string.
+    ///
+    /// The parts are walked with an explicit stack, as ropes built in a loop are as deep as the
+    /// number of iterations. They are not flattened themselves, to copy every part only once.
+    fn flatten(&self) -> Box<str> {
+        let mut string = String::with_capacity(self.len());
+        let mut parts = vec![self.clone()];
+        while let Some(part) = parts.pop() {
+            if let Some(flat) = part.0.flat.get() {
+                string.push_str(flat);
+            } else if let Some((left, right)) = &*part.0.rope.borrow() {
+                parts.push(right.clone());
+                parts.push(left.clone());
+            }
+        }
+        string.into_boxed_str()
+    }
+}
+
+impl Drop for RcString {
+    #[inline]
+    fn drop(&mut self) {
+        if Rc::strong_count(&self.0) != 1 {
+            return;
+        }
+
+        if let Some(flat) = self.0.flat.get() {
+            crate::gc::track_data_deallocation(flat.len());
+        }
+
+        // Drop the parts of the ropes that are not shared with an explicit stack, as dropping
+        // them recursively could overflow the stack for deep ropes.
+        let mut parts = Vec::new();
+        parts.extend(self.0.rope.borrow_mut().take());
+        while let Some((left, right)) = parts.pop() {
+            for part in [left, right].iter() {
+                if Rc::strong_count(&part.0) == 1 {
+                    parts.extend(part.0.rope.borrow_mut().take());
+                }
+            }
+        }
     }
 }
 
 impl Default for RcString {
     #[inline]
     fn default() -> Self {
-        Self(Rc::from(String::new()))
+        Self::new(Box::from(""))
+    }
+}
+
+impl Debug for RcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RcString").field(&self.as_str()).finish()
+    }
+}
+
+impl PartialEq for RcString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0) || self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for RcString {}
+
+impl PartialOrd for RcString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RcString {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for RcString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
     }
 }
 
 impl Display for RcString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.0, f)
+        Display::fmt(self.as_str(), f)
     }
 }
 
@@ -67,14 +228,14 @@ impl Deref for RcString {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.as_str()
     }
 }
 
 impl Borrow<str> for RcString {
     #[inline]
     fn borrow(&self) -> &str {
-        self.0.borrow()
+        self.as_str()
     }
 }
 
@@ -88,7 +249,7 @@ impl AsRef<str> for RcString {
 impl From<String> for RcString {
     #[inline]
     fn from(string: String) -> Self {
-        Self(Rc::from(string))
+        Self::new(string.into_boxed_str())
     }
 }
 
@@ -102,13 +263,13 @@ impl From<&RcString> for String {
 impl From<Box<str>> for RcString {
     #[inline]
     fn from(string: Box<str>) -> Self {
-        Self(Rc::from(string))
+        Self::new(string)
     }
 }
 
 impl From<&str> for RcString {
     #[inline]
     fn from(string: &str) -> Self {
-        Self(Rc::from(string))
+        Self::new(Box::from(string))
     }
 }
@@ -4,14 +4,52 @@ use crate::{
 };
 
 use std::{
+    cmp::Ordering,
     fmt::{self, Display},
+    hash::{Hash, Hasher},
     ops::Deref,
     rc::Rc,
 };
 
-#[derive(Debug, Finalize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A shared JavaScript symbol.
+///
+/// Symbols are compared and hashed by identity: two `RcSymbol`s are only equal if they are
+/// clones of the same symbol, even if their hashes and descriptions are the same.
+#[derive(Debug, Finalize, Clone)]
 pub struct RcSymbol(Rc<Symbol>);
 
+impl PartialEq for RcSymbol {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for RcSymbol {}
+
+impl Hash for RcSymbol {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
+impl PartialOrd for RcSymbol {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RcSymbol {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Symbol::hash(&self.0)
+            .cmp(&Symbol::hash(&other.0))
+            .then_with(|| Rc::as_ptr(&self.0).cmp(&Rc::as_ptr(&other.0)))
+    }
+}
+
 unsafe impl Trace for RcSymbol {
     empty_trace!();
 }
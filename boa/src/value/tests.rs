@@ -44,6 +44,17 @@ fn get_set_field() {
     );
 }
 
+#[test]
+fn remove_property_reports_removal() {
+    let mut context = Context::new();
+    let obj = Value::new_object(&context);
+    obj.set_field("foo", Value::from("bar"), &mut context)
+        .unwrap();
+    assert!(obj.remove_property("foo"));
+    assert!(!obj.remove_property("foo"));
+    assert!(!Value::from("foo").remove_property("length"));
+}
+
 #[test]
 fn integer_is_true() {
     assert_eq!(Value::from(1).to_boolean(), true);
@@ -171,6 +182,41 @@ fn hash_object() {
     assert_ne!(hash_value(&object1), hash_value(&object2));
 }
 
+#[test]
+fn concatenated_strings() {
+    let long = RcString::from("a".repeat(100));
+    let rope = RcString::concat(&long, &RcString::from("b"));
+    assert_eq!(rope.len(), 101);
+
+    let flat = RcString::from(format!("{}b", "a".repeat(100)));
+    let value1 = Value::string(rope.clone());
+    let value2 = Value::string(flat.clone());
+    assert_eq!(hash_value(&value1), hash_value(&value2));
+    assert_eq!(value1, value2);
+    assert_eq!(rope, flat);
+
+    let empty = RcString::default();
+    assert_eq!(RcString::concat(&empty, &long), long);
+    assert_eq!(RcString::concat(&long, &empty), long);
+}
+
+#[test]
+fn deep_rope() {
+    let chunk = RcString::from("0123456789".repeat(10));
+    let mut string = RcString::default();
+    for _ in 0..100_000 {
+        string = RcString::concat(&string, &chunk);
+    }
+    assert_eq!(string.len(), 10_000_000);
+    assert!(string.ends_with("789"));
+
+    let mut unflattened = RcString::default();
+    for _ in 0..100_000 {
+        unflattened = RcString::concat(&unflattened, &chunk);
+    }
+    drop(unflattened);
+}
+
 #[test]
 fn get_types() {
     let mut context = Context::new();
@@ -468,7 +514,7 @@ fn display_boolean_object() {
         bool
     "#;
     let value = forward_val(&mut context, d_obj).unwrap();
-    assert_eq!(value.display().to_string(), "Boolean { false }")
+    assert_eq!(value.display().to_string(), "[Boolean: false]")
 }
 
 #[test]
@@ -479,7 +525,7 @@ fn display_number_object() {
         num
     "#;
     let value = forward_val(&mut context, d_obj).unwrap();
-    assert_eq!(value.display().to_string(), "Number { 3.14 }")
+    assert_eq!(value.display().to_string(), "[Number: 3.14]")
 }
 
 #[test]
@@ -490,7 +536,98 @@ fn display_negative_zero_object() {
         num
     "#;
     let value = forward_val(&mut context, d_obj).unwrap();
-    assert_eq!(value.display().to_string(), "Number { -0 }")
+    assert_eq!(value.display().to_string(), "[Number: -0]")
+}
+
+#[test]
+fn display_obj_depth_limit() {
+    let mut context = Context::new();
+
+    let value = forward_val(&mut context, "({ a: { b: { c: { d: 1 } } } })").unwrap();
+    assert_eq!(
+        value.display_obj(2).to_string(),
+        "{ a: { b: { c: [Object] } } }"
+    );
+    assert_eq!(
+        value.display_obj(3).to_string(),
+        "{ a: { b: { c: { d: 1 } } } }"
+    );
+    assert_eq!(value.display_obj(0).to_string(), "{ a: [Object] }");
+}
+
+#[test]
+fn display_obj_self_reference() {
+    let mut context = Context::new();
+    let src = r#"
+        let o = {};
+        o.self = o;
+        o
+    "#;
+
+    let value = forward_val(&mut context, src).unwrap();
+    assert_eq!(value.display_obj(5).to_string(), "{ self: [Circular] }");
+}
+
+#[test]
+fn display_obj_indirect_cycle() {
+    let mut context = Context::new();
+    let src = r#"
+        let a = [1];
+        let b = { a };
+        a.push(b);
+        a
+    "#;
+
+    let value = forward_val(&mut context, src).unwrap();
+    assert_eq!(value.display_obj(5).to_string(), "[ 1, { a: [Circular] } ]");
+}
+
+#[test]
+fn display_obj_shared_reference_is_not_circular() {
+    let mut context = Context::new();
+    let src = r#"
+        let shared = { x: 1 };
+        [shared, shared, { x: 1 }]
+    "#;
+
+    let value = forward_val(&mut context, src).unwrap();
+    assert_eq!(
+        value.display_obj(2).to_string(),
+        "[ { x: 1 }, { x: 1 }, { x: 1 } ]"
+    );
+}
+
+#[test]
+fn display_obj_functions_and_strings() {
+    let mut context = Context::new();
+    let src = r#"
+        function foo() {}
+        [foo, () => 1, "s", ["nested"]]
+    "#;
+
+    let value = forward_val(&mut context, src).unwrap();
+    assert_eq!(
+        value.display_obj(2).to_string(),
+        "[ [Function: foo], [Function (anonymous)], 's', [ 'nested' ] ]"
+    );
+    assert_eq!(Value::from("top").display_obj(2).to_string(), "top");
+}
+
+#[test]
+fn display_obj_long_array() {
+    let mut context = Context::new();
+    let src = r#"
+        let a = [];
+        for (let i = 0; i < 1000; i++) {
+            a.push(i);
+        }
+        a
+    "#;
+
+    let value = forward_val(&mut context, src).unwrap();
+    let display = value.display_obj(2).to_string();
+    assert!(display.starts_with("[ 0, 1, 2, "));
+    assert!(display.ends_with(", 98, 99, ... 900 more items ]"));
 }
 
 #[test]
@@ -507,57 +644,50 @@ fn debug_object() {
 }
 
 #[test]
-#[ignore] // TODO: Once objects are printed in a simpler way this test can be simplified and used
 fn display_object() {
     let mut context = Context::new();
     let d_obj = r#"
-        let o = {a: 'a'};
+        let o = {a: 'a', nested: { b: [1, 'b'] }};
+        Object.defineProperty(o, 'hidden', { value: true, enumerable: false });
         o
     "#;
     let value = forward_val(&mut context, d_obj).unwrap();
     assert_eq!(
         value.display().to_string(),
-        r#"{
-   a: "a",
-__proto__: {
-constructor: {
-setPrototypeOf: {
-          length: 2
-            },
-   prototype: [Cycle],
-        name: "Object",
-      length: 1,
-defineProperty: {
-          length: 3
-            },
-getPrototypeOf: {
-          length: 1
-            },
-          is: {
-          length: 2
-            },
-   __proto__: {
-     constructor: {
-                name: "Function",
-           prototype: [Cycle],
-              length: 1,
-           __proto__: undefined
-                },
-       __proto__: undefined
-            }
-        },
-hasOwnProperty: {
-      length: 0
-        },
-propertyIsEnumerable: {
-      length: 0
-        },
-toString: {
-      length: 0
-        }
-    }
-}"#
+        r#"{ a: "a", nested: { b: [ 1, "b" ] } }"#
+    );
+    assert_eq!(
+        value.display().show_hidden(true).to_string(),
+        r#"{ a: "a", nested: { b: [ 1, "b" ] }, [hidden]: true }"#
+    );
+    assert_eq!(
+        value.display().quote_strings(false).to_string(),
+        "{ a: 'a', nested: { b: [ 1, 'b' ] } }"
+    );
+    assert_eq!(
+        value.display().depth(1).to_string(),
+        r#"{ a: "a", nested: { b: [Array] } }"#
+    );
+}
+
+#[test]
+fn display_is_not_to_string() {
+    let mut context = Context::new();
+
+    let value = forward_val(&mut context, "({ a: 1 })").unwrap();
+    assert_eq!(value.display().to_string(), "{ a: 1 }");
+    assert_eq!(
+        value.to_string(&mut context).unwrap().as_str(),
+        "[object Object]"
     );
+
+    let value = forward_val(&mut context, "[1, [2, 3]]").unwrap();
+    assert_eq!(value.display().to_string(), "[ 1, [ 2, 3 ] ]");
+    assert_eq!(value.to_string(&mut context).unwrap().as_str(), "1,2,3");
+
+    let value = Value::from("text");
+    assert_eq!(value.display().to_string(), "\"text\"");
+    assert_eq!(value.to_string(&mut context).unwrap().as_str(), "text");
 }
 
 #[test]
@@ -635,6 +765,164 @@ fn to_primitive() {
     assert_eq!(forward(&mut context, "primitive"), "42");
 }
 
+/// The values converted by `ToNumber`, as JavaScript source, with the number they convert to.
+const TO_NUMBER_CASES: &[(&str, &str)] = &[
+    ("undefined", "NaN"),
+    ("null", "0"),
+    ("true", "1"),
+    ("false", "0"),
+    ("-0", "-0"),
+    ("1.5", "1.5"),
+    ("''", "0"),
+    ("' \\t\\n\\u00A0\\uFEFF\\u2028 '", "0"),
+    ("' 12 '", "12"),
+    ("'\\uFEFF12'", "12"),
+    ("'\\u008512'", "NaN"),
+    ("'+12'", "12"),
+    ("'-12'", "-12"),
+    ("'-0'", "-0"),
+    ("'+-12'", "NaN"),
+    ("'1.'", "1"),
+    ("'.5'", "0.5"),
+    ("'1e3'", "1000"),
+    ("'1E-3'", "0.001"),
+    ("'1e'", "NaN"),
+    ("'.'", "NaN"),
+    ("'1_000'", "NaN"),
+    ("'12px'", "NaN"),
+    ("'foo'", "NaN"),
+    ("'0b11'", "3"),
+    ("'0B11'", "3"),
+    ("'0o17'", "15"),
+    ("'0O17'", "15"),
+    ("'0x1F'", "31"),
+    ("'0X1f'", "31"),
+    ("'0b'", "NaN"),
+    ("'0b12'", "NaN"),
+    ("'0o8'", "NaN"),
+    ("'0xG'", "NaN"),
+    ("'-0x10'", "NaN"),
+    ("'0x_1'", "NaN"),
+    ("'Infinity'", "Infinity"),
+    ("'+Infinity'", "Infinity"),
+    ("'-Infinity'", "-Infinity"),
+    ("'infinity'", "NaN"),
+    ("'inf'", "NaN"),
+    ("'NaN'", "NaN"),
+    ("'1e400'", "Infinity"),
+    ("[]", "0"),
+    ("[7]", "7"),
+    ("[1, 2]", "NaN"),
+    ("{}", "NaN"),
+    ("{ valueOf() { return '0x10'; } }", "16"),
+    ("new Number(3)", "3"),
+    ("new String('0o10')", "8"),
+];
+
+#[test]
+fn to_number_conversions() {
+    let mut context = Context::new();
+    for (value, expected) in TO_NUMBER_CASES {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("Object.is(+({}), {})", value, expected)
+            ),
+            "true",
+            "+({}) is not {}",
+            value,
+            expected
+        );
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("Object.is(-({}), -({}))", value, expected)
+            ),
+            "true",
+            "-({}) is not -({})",
+            value,
+            expected
+        );
+    }
+}
+
+#[test]
+fn number_function_conversions() {
+    let mut context = Context::new();
+    for (value, expected) in TO_NUMBER_CASES {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("Object.is(Number({}), {})", value, expected)
+            ),
+            "true",
+            "Number({}) is not {}",
+            value,
+            expected
+        );
+        assert_eq!(
+            forward(&mut context, &format!("isNaN({})", value)),
+            (*expected == "NaN").to_string(),
+            "isNaN({})",
+            value
+        );
+        assert_eq!(
+            forward(&mut context, &format!("isFinite({})", value)),
+            (!expected.contains("NaN") && !expected.contains("Infinity")).to_string(),
+            "isFinite({})",
+            value
+        );
+    }
+
+    // `Number` converts BigInts, which `ToNumber` rejects.
+    assert_eq!(forward(&mut context, "Number(12n)"), "12");
+    assert_eq!(
+        forward(&mut context, "Number(-(2n ** 64n)) === -(2 ** 64)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Number(Object(1n))"), "1");
+    assert_eq!(forward(&mut context, "-Object(2n) === -2n"), "true");
+    assert_eq!(
+        forward(&mut context, "try { +1n } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { isNaN(1n) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    // Symbols can't be converted.
+    for call in &[
+        "Number(Symbol())",
+        "new Number(Symbol())",
+        "+Symbol()",
+        "-Symbol()",
+        "isNaN(Symbol())",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {} }} catch (e) {{ e.name }}", call)
+            ),
+            "\"TypeError\"",
+            "{} did not throw",
+            call
+        );
+    }
+
+    assert_eq!(forward(&mut context, "Number()"), "0");
+    assert_eq!(
+        forward(&mut context, "Object.is(Number(undefined), NaN)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "typeof Number('0b11')"), "\"number\"");
+    assert_eq!(
+        forward(&mut context, "typeof new Number('0b11')"),
+        "\"object\""
+    );
+    assert_eq!(forward(&mut context, "new Number('0b11').valueOf()"), "3");
+}
+
 /// Test cyclic conversions that previously caused stack overflows
 /// Relevant mitigations for these are in `GcObject::ordinary_to_primitive` and
 /// `GcObject::to_json`
@@ -29,6 +29,15 @@ impl Type {
             Self::BigInt => "bigint",
         }
     }
+
+    /// The name of the type as used in error messages, which unlike `typeof` does not report
+    /// `null` as an `object`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            _ => self.as_str(),
+        }
+    }
 }
 
 impl Value {
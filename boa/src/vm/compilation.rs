@@ -1,10 +1,42 @@
 use super::*;
-use crate::{syntax::ast::Const, syntax::ast::Node, value::RcBigInt, value::RcString};
+use crate::{
+    syntax::ast::{
+        node::{Identifier, StatementList},
+        Const, Node,
+    },
+    value::RcBigInt,
+    value::RcString,
+};
+
+/// The error of a compilation, returned when the code uses something that the VM doesn't
+/// support yet.
+///
+/// The code is then run by the AST interpreter instead.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Unsupported;
+
+pub(crate) type CompileResult = std::result::Result<(), Unsupported>;
+
+/// A loop being compiled, so that `break` and `continue` can jump out of it.
+#[derive(Debug)]
+struct Loop {
+    /// The address of the condition of the loop.
+    start: usize,
+    /// The number of environments pushed by the code block when the loop starts.
+    environment_depth: usize,
+    /// The addresses of the jumps of the `break` statements, patched at the end of the loop.
+    breaks: Vec<usize>,
+}
 
 #[derive(Debug, Default)]
 pub struct Compiler {
     pub(super) instructions: Vec<Instruction>,
     pub(super) pool: Vec<Value>,
+    pub(super) identifiers: Vec<Identifier>,
+    pub(super) names: Vec<RcString>,
+    pub(super) functions: Vec<Node>,
+    loops: Vec<Loop>,
+    environment_depth: usize,
 }
 
 impl Compiler {
@@ -30,14 +62,233 @@ impl Compiler {
         self.add_instruction(Instruction::BigInt(index));
         self.pool.push(bigint.into().into());
     }
+
+    /// Adds a jump instruction, and returns its address so that it can be patched later.
+    pub fn add_jump(&mut self, jump: Instruction) -> usize {
+        let address = self.instructions.len();
+        self.add_instruction(jump);
+        address
+    }
+
+    /// Makes the jump at `address` jump to the next instruction.
+    pub fn patch_jump(&mut self, address: usize) {
+        let target = self.instructions.len();
+        self.instructions[address] = match self.instructions[address] {
+            Instruction::Jump(_) => Instruction::Jump(target),
+            Instruction::JumpIfFalse(_) => Instruction::JumpIfFalse(target),
+            Instruction::JumpIfTrue(_) => Instruction::JumpIfTrue(target),
+            Instruction::JumpIfNotNullish(_) => Instruction::JumpIfNotNullish(target),
+            instruction => unreachable!("{} is not a jump", instruction),
+        };
+    }
+
+    /// Adds an identifier to the code block, and returns its index.
+    ///
+    /// The identifiers are not deduplicated, since each of them holds the location of its own
+    /// binding.
+    pub(crate) fn identifier_index(&mut self, identifier: &Identifier) -> usize {
+        self.identifiers.push(identifier.clone());
+        self.identifiers.len() - 1
+    }
+
+    /// Adds a property or binding name to the code block, and returns its index.
+    pub(crate) fn name_index(&mut self, name: &str) -> usize {
+        if let Some(index) = self.names.iter().position(|n| n.as_str() == name) {
+            return index;
+        }
+        self.names.push(name.into());
+        self.names.len() - 1
+    }
+
+    /// Compiles an expression whose value is bound to `name`, which names the function it creates
+    /// if it is an anonymous function definition.
+    fn compile_named(&mut self, node: &Node, name: &str) -> CompileResult {
+        if !node.is_anonymous_function_definition() {
+            return node.compile(self);
+        }
+        self.functions.push(node.clone());
+        let index = self.functions.len() - 1;
+        let name = self.name_index(name);
+        self.add_instruction(Instruction::NamedFunction(index, name));
+        Ok(())
+    }
+
+    /// Adds the instructions that leave the current environments up to the depth of the
+    /// innermost loop, and jump out of it or to its condition.
+    fn compile_loop_exit(&mut self, is_break: bool) -> CompileResult {
+        let (start, depth) = match self.loops.last() {
+            Some(innermost) => (innermost.start, innermost.environment_depth),
+            None => return Err(Unsupported),
+        };
+
+        for _ in depth..self.environment_depth {
+            self.add_instruction(Instruction::PopEnv);
+        }
+        if is_break {
+            let jump = self.add_jump(Instruction::Jump(0));
+            self.loops
+                .last_mut()
+                .expect("the innermost loop was checked above")
+                .breaks
+                .push(jump);
+        } else {
+            self.add_instruction(Instruction::Jump(start));
+        }
+        Ok(())
+    }
+
+    /// Compiles the statements of a list, which leave nothing on the stack.
+    pub(crate) fn compile_items(&mut self, items: &[Node]) -> CompileResult {
+        for item in items {
+            self.compile_statement(item)?;
+        }
+        Ok(())
+    }
+
+    /// Compiles a statement.
+    ///
+    /// Statements leave nothing on the stack: the value of an expression statement becomes the
+    /// completion value of the code block, and declarations complete with an empty value, which
+    /// keeps the previous one.
+    fn compile_statement(&mut self, node: &Node) -> CompileResult {
+        match *node {
+            Node::FunctionDecl(_) => {
+                node.compile(self)?;
+                self.add_instruction(Instruction::Pop);
+            }
+            Node::VarDeclList(ref list) => {
+                for var_decl in list.as_ref() {
+                    let index = self.name_index(var_decl.name());
+                    match var_decl.init() {
+                        Some(init) => {
+                            self.compile_named(init, var_decl.name())?;
+                            self.add_instruction(Instruction::InitVar(index));
+                        }
+                        None => self.add_instruction(Instruction::DefVar(index)),
+                    }
+                }
+            }
+            Node::LetDeclList(ref list) => {
+                for let_decl in list.as_ref() {
+                    match let_decl.init() {
+                        Some(init) => self.compile_named(init, let_decl.name())?,
+                        None => self.add_instruction(Instruction::Undefined),
+                    }
+                    let index = self.name_index(let_decl.name());
+                    self.add_instruction(Instruction::DefLet(index));
+                }
+            }
+            Node::ConstDeclList(ref list) => {
+                for const_decl in list.as_ref() {
+                    // The missing initializer is a syntax error thrown by the interpreter.
+                    let init = const_decl.init().as_ref().ok_or(Unsupported)?;
+                    self.compile_named(init, const_decl.name())?;
+                    let index = self.name_index(const_decl.name());
+                    self.add_instruction(Instruction::DefConst(index));
+                }
+            }
+            Node::If(ref if_node) => {
+                // The value of an `if` statement whose branch has an empty value is `undefined`.
+                self.add_instruction(Instruction::Undefined);
+                self.add_instruction(Instruction::SetCompletion);
+                if_node.cond().compile(self)?;
+                let if_false = self.add_jump(Instruction::JumpIfFalse(0));
+                self.compile_statement(if_node.body())?;
+                if let Some(else_node) = if_node.else_node() {
+                    let end = self.add_jump(Instruction::Jump(0));
+                    self.patch_jump(if_false);
+                    self.compile_statement(else_node)?;
+                    self.patch_jump(end);
+                } else {
+                    self.patch_jump(if_false);
+                }
+            }
+            Node::Block(ref block) => {
+                self.add_instruction(Instruction::PushBlockEnv);
+                self.environment_depth += 1;
+                self.compile_items(block.items())?;
+                self.environment_depth -= 1;
+                self.add_instruction(Instruction::PopEnv);
+            }
+            Node::WhileLoop(ref while_loop) => {
+                if while_loop.label().is_some() {
+                    return Err(Unsupported);
+                }
+                // The value of the loop is the last value of its body, or `undefined`.
+                self.add_instruction(Instruction::Undefined);
+                self.add_instruction(Instruction::SetCompletion);
+                let start = self.instructions.len();
+                while_loop.cond().compile(self)?;
+                let exit = self.add_jump(Instruction::JumpIfFalse(0));
+
+                self.loops.push(Loop {
+                    start,
+                    environment_depth: self.environment_depth,
+                    breaks: Vec::new(),
+                });
+                let body = self.compile_statement(while_loop.expr());
+                let innermost = self.loops.pop().expect("the loop was pushed above");
+                body?;
+
+                self.add_instruction(Instruction::Jump(start));
+                self.patch_jump(exit);
+                for jump in innermost.breaks {
+                    self.patch_jump(jump);
+                }
+            }
+            Node::Break(ref break_node) => {
+                if break_node.label().is_some() {
+                    return Err(Unsupported);
+                }
+                self.compile_loop_exit(true)?;
+            }
+            Node::Continue(ref continue_node) => {
+                if continue_node.label().is_some() {
+                    return Err(Unsupported);
+                }
+                self.compile_loop_exit(false)?;
+            }
+            Node::Return(ref return_node) => {
+                match return_node.expr() {
+                    Some(expr) => expr.compile(self)?,
+                    None => self.add_instruction(Instruction::Undefined),
+                }
+                self.add_instruction(Instruction::Return);
+            }
+            _ => {
+                node.compile(self)?;
+                self.add_instruction(Instruction::SetCompletion);
+            }
+        }
+        Ok(())
+    }
+
+    /// Compiles the statements of a script or a function body.
+    pub(crate) fn compile_statement_list(list: &StatementList) -> Option<CodeBlock> {
+        let mut compiler = Self::default();
+        list.compile(&mut compiler).ok()?;
+        Some(compiler.finish())
+    }
+
+    /// Finishes the code block.
+    pub fn finish(self) -> CodeBlock {
+        CodeBlock {
+            instructions: self.instructions,
+            pool: self.pool,
+            identifiers: self.identifiers,
+            names: self.names,
+            functions: self.functions,
+        }
+    }
 }
 
 pub(crate) trait CodeGen {
-    fn compile(&self, compiler: &mut Compiler);
+    fn compile(&self, compiler: &mut Compiler) -> CompileResult;
 }
 
 impl CodeGen for Node {
-    fn compile(&self, compiler: &mut Compiler) {
+    fn compile(&self, compiler: &mut Compiler) -> CompileResult {
+        #[cfg(feature = "profiler")]
         let _timer = BoaProfiler::global().start_event(&format!("Node ({})", &self), "codeGen");
         match *self {
             Node::Const(Const::Undefined) => compiler.add_instruction(Instruction::Undefined),
@@ -56,49 +307,87 @@ impl CodeGen for Node {
             Node::Const(Const::BigInt(ref bigint)) => {
                 compiler.add_bigint_instruction(bigint.clone())
             }
-            Node::BinOp(ref op) => op.compile(compiler),
-            Node::UnaryOp(ref op) => op.compile(compiler),
-            Node::VarDeclList(ref list) => {
-                for var_decl in list.as_ref() {
-                    let name = var_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefVar(index));
-                    compiler.pool.push(name.into());
-
-                    if let Some(v) = var_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
-                }
+            Node::BinOp(ref op) => op.compile(compiler)?,
+            Node::UnaryOp(ref op) => op.compile(compiler)?,
+            Node::Identifier(ref identifier) => {
+                let index = compiler.identifier_index(identifier);
+                compiler.add_instruction(Instruction::GetName(index));
             }
-            Node::LetDeclList(ref list) => {
-                for let_decl in list.as_ref() {
-                    let name = let_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefLet(index));
-                    compiler.pool.push(name.into());
-
-                    // If name has a value we can init here too
-                    if let Some(v) = let_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
+            Node::Assign(ref assign) => match assign.lhs() {
+                Node::Identifier(ref identifier) => {
+                    compiler.compile_named(assign.rhs(), identifier.as_ref())?;
+                    let index = compiler.identifier_index(identifier);
+                    compiler.add_instruction(Instruction::AssignName(index));
                 }
+                Node::GetConstField(ref get_const_field) => {
+                    assign.rhs().compile(compiler)?;
+                    get_const_field.obj().compile(compiler)?;
+                    compiler.add_instruction(Instruction::Swap);
+                    let index = compiler.name_index(get_const_field.field());
+                    compiler.add_instruction(Instruction::SetField(index));
+                }
+                Node::GetField(ref get_field) => {
+                    assign.rhs().compile(compiler)?;
+                    get_field.obj().compile(compiler)?;
+                    get_field.field().compile(compiler)?;
+                    compiler.add_instruction(Instruction::SetFieldDyn);
+                }
+                _ => assign.rhs().compile(compiler)?,
+            },
+            Node::GetConstField(ref get_const_field) => {
+                get_const_field.obj().compile(compiler)?;
+                let index = compiler.name_index(get_const_field.field());
+                compiler.add_instruction(Instruction::GetField(index));
             }
-            Node::ConstDeclList(ref list) => {
-                for const_decl in list.as_ref() {
-                    let name = const_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefConst(index));
-                    compiler.pool.push(name.into());
-
-                    if let Some(v) = const_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
+            Node::GetField(ref get_field) => {
+                get_field.obj().compile(compiler)?;
+                get_field.field().compile(compiler)?;
+                compiler.add_instruction(Instruction::GetFieldDyn);
+            }
+            Node::Call(ref call) => {
+                match call.expr() {
+                    Node::GetConstField(ref get_const_field) => {
+                        get_const_field.obj().compile(compiler)?;
+                        compiler.add_instruction(Instruction::Dup);
+                        let index = compiler.name_index(get_const_field.field());
+                        compiler.add_instruction(Instruction::GetField(index));
+                    }
+                    Node::GetField(ref get_field) => {
+                        get_field.obj().compile(compiler)?;
+                        compiler.add_instruction(Instruction::Dup);
+                        get_field.field().compile(compiler)?;
+                        compiler.add_instruction(Instruction::GetFieldDyn);
+                    }
+                    expr => {
+                        // the sloppy mode global `this` is substituted by the call itself
+                        compiler.add_instruction(Instruction::Undefined);
+                        expr.compile(compiler)?;
+                    }
+                }
+                for arg in call.args() {
+                    if let Node::Spread(_) = arg {
+                        return Err(Unsupported);
+                    }
+                    arg.compile(compiler)?;
                 }
+                compiler.add_instruction(Instruction::Call(call.args().len()));
+            }
+            Node::FunctionDecl(_) | Node::FunctionExpr(_) | Node::ArrowFunctionDecl(_) => {
+                compiler.functions.push(self.clone());
+                let index = compiler.functions.len() - 1;
+                compiler.add_instruction(Instruction::Function(index));
+            }
+            Node::ConditionalOp(ref op) => {
+                op.cond().compile(compiler)?;
+                let if_false = compiler.add_jump(Instruction::JumpIfFalse(0));
+                op.if_true().compile(compiler)?;
+                let end = compiler.add_jump(Instruction::Jump(0));
+                compiler.patch_jump(if_false);
+                op.if_false().compile(compiler)?;
+                compiler.patch_jump(end);
             }
-            _ => unimplemented!(),
+            _ => return Err(Unsupported),
         }
+        Ok(())
     }
 }
@@ -15,6 +15,18 @@ pub enum Instruction {
     /// Loads an f32 onto the stack
     Rational(f64),
 
+    /// Pops the value on top of the stack
+    Pop,
+
+    /// Pushes a copy of the value on top of the stack
+    Dup,
+
+    /// Swaps the two values on top of the stack
+    Swap,
+
+    /// Loads the global object onto the stack
+    GlobalObject,
+
     /// Adds the values from destination and source and stores the result in destination
     Add,
 
@@ -58,14 +70,67 @@ pub enum Instruction {
     BitNot,
     Not,
 
-    /// The usize is the index of the variable name in the pool
+    /// Converts the value on top of the stack to a number or a bigint
+    ToNumeric,
+    /// Converts the value on top of the stack to a number or a bigint, and adds one to it
+    Inc,
+    /// Converts the value on top of the stack to a number or a bigint, and subtracts one from it
+    Dec,
+
+    /// Loads the value of a binding, the usize is the index of the identifier in the code block
+    GetName(usize),
+    /// Sets the value of an existing binding to the value on top of the stack, which is kept
+    SetName(usize),
+    /// Assigns the value on top of the stack to a binding, which is created if it doesn't exist
+    AssignName(usize),
+
+    /// Declares a variable without initializer, the usize is the index of the identifier
     DefVar(usize),
-    /// The usize is the index of the variable name in the pool
+    /// Declares a variable and initializes it with the value it pops
+    InitVar(usize),
+    /// Declares a `let` binding and initializes it with the value it pops
     DefLet(usize),
-    /// The usize is the index of the variable name in the pool
+    /// Declares a `const` binding and initializes it with the value it pops
     DefConst(usize),
-    /// The usize is the index of the value to initiate the variable with in the pool
-    InitLexical(usize),
+
+    /// Replaces the object on top of the stack by the value of its property, the usize is the
+    /// index of the property name in the code block
+    GetField(usize),
+    /// Replaces the object and the key on top of the stack by the value of the property
+    GetFieldDyn,
+    /// Pops a value and an object, sets the property of the object and pushes the value back
+    SetField(usize),
+    /// Pops a key, an object and a value, sets the property of the object and pushes the value
+    /// back
+    SetFieldDyn,
+
+    /// Calls a function with the usize arguments on top of the stack, after the function and
+    /// the `this` value
+    Call(usize),
+    /// Creates the function of the usize-th function node of the code block
+    Function(usize),
+    /// Creates the function of the first usize-th function node of the code block, which is an
+    /// anonymous function definition, named after the second usize-th name
+    NamedFunction(usize, usize),
+    /// Pops a value and makes it the completion value of the code block, which is the value of
+    /// the last statement that has one
+    SetCompletion,
+    /// Ends the code block with the value it pops
+    Return,
+
+    /// Jumps to the address
+    Jump(usize),
+    /// Pops a value and jumps to the address if it is falsy
+    JumpIfFalse(usize),
+    /// Pops a value and jumps to the address if it is truthy
+    JumpIfTrue(usize),
+    /// Pops a value and jumps to the address if it is neither `null` nor `undefined`
+    JumpIfNotNullish(usize),
+
+    /// Pushes a declarative environment for a block
+    PushBlockEnv,
+    /// Pops the current environment
+    PopEnv,
 }
 
 impl std::fmt::Display for Instruction {
@@ -81,6 +146,10 @@ impl std::fmt::Display for Instruction {
             Self::BigInt(usize) => write!(f, "BigInt({})", usize),
             Self::Int32(i32) => write!(f, "Int32({})", i32),
             Self::Rational(f64) => write!(f, "Rational({})", f64),
+            Self::Pop => write!(f, "Pop"),
+            Self::Dup => write!(f, "Dup"),
+            Self::Swap => write!(f, "Swap"),
+            Self::GlobalObject => write!(f, "GlobalObject"),
             Self::Add => write!(f, "Add"),
             Self::Sub => write!(f, "Sub"),
             Self::Mul => write!(f, "Mul"),
@@ -109,10 +178,31 @@ impl std::fmt::Display for Instruction {
             Self::Neg => write!(f, "Neg"),
             Self::BitNot => write!(f, "BitNot"),
             Self::Not => write!(f, "Not"),
+            Self::ToNumeric => write!(f, "ToNumeric"),
+            Self::Inc => write!(f, "Inc"),
+            Self::Dec => write!(f, "Dec"),
+            Self::GetName(name) => write!(f, "GetName({})", name),
+            Self::SetName(name) => write!(f, "SetName({})", name),
+            Self::AssignName(name) => write!(f, "AssignName({})", name),
             Self::DefVar(name) => write!(f, "DefVar({})", name),
+            Self::InitVar(name) => write!(f, "InitVar({})", name),
             Self::DefLet(name) => write!(f, "DefLet({})", name),
             Self::DefConst(name) => write!(f, "DefConst({})", name),
-            Self::InitLexical(value) => write!(f, "InitLexical({})", value),
+            Self::GetField(name) => write!(f, "GetField({})", name),
+            Self::GetFieldDyn => write!(f, "GetFieldDyn"),
+            Self::SetField(name) => write!(f, "SetField({})", name),
+            Self::SetFieldDyn => write!(f, "SetFieldDyn"),
+            Self::Call(argc) => write!(f, "Call({})", argc),
+            Self::Function(index) => write!(f, "Function({})", index),
+            Self::NamedFunction(index, name) => write!(f, "NamedFunction({}, {})", index, name),
+            Self::SetCompletion => write!(f, "SetCompletion"),
+            Self::Return => write!(f, "Return"),
+            Self::Jump(address) => write!(f, "Jump({})", address),
+            Self::JumpIfFalse(address) => write!(f, "JumpIfFalse({})", address),
+            Self::JumpIfTrue(address) => write!(f, "JumpIfTrue({})", address),
+            Self::JumpIfNotNullish(address) => write!(f, "JumpIfNotNullish({})", address),
+            Self::PushBlockEnv => write!(f, "PushBlockEnv"),
+            Self::PopEnv => write!(f, "PopEnv"),
         }
     }
 }
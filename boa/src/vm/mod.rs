@@ -1,29 +1,97 @@
-use crate::{environment::lexical_environment::VariableScope, BoaProfiler, Context, Result, Value};
+//! The bytecode virtual machine.
+//!
+//! Scripts and function bodies are compiled to a [`CodeBlock`] the first time they run, and are
+//! then executed by the [`VM`]. The VM supports a subset of the language: the code blocks using
+//! anything else are not compiled, and are run by the AST interpreter instead.
+
+use crate::{
+    environment::lexical_environment::{new_declarative_environment, VariableScope},
+    exec::{Completion, CompletionType, Executable},
+    gc::{empty_trace, Finalize, Trace},
+    syntax::ast::node::{Identifier, Node},
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use once_cell::unsync::OnceCell;
+use std::rc::Rc;
 
 pub(crate) mod compilation;
 pub(crate) mod instructions;
 
+#[cfg(test)]
+mod tests;
+
 pub use compilation::Compiler;
 pub use instructions::Instruction;
 
+/// The compiled code of a script or a function body.
+#[derive(Debug)]
+pub struct CodeBlock {
+    /// The instructions of the code.
+    pub(crate) instructions: Vec<Instruction>,
+    /// The string and bigint constants.
+    pub(crate) pool: Vec<Value>,
+    /// The identifiers of the bindings that are read or written.
+    pub(crate) identifiers: Vec<Identifier>,
+    /// The names of the properties and of the declared bindings.
+    pub(crate) names: Vec<RcString>,
+    /// The function declarations and expressions, which are created by the AST interpreter.
+    pub(crate) functions: Vec<Node>,
+}
+
+impl CodeBlock {
+    /// Gets the instructions of the code block.
+    #[inline]
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+}
+
+/// The code block of a statement list, compiled the first time it runs.
+///
+/// It holds `None` if the statements are not supported by the VM.
+#[derive(Debug, Clone, Default, Finalize)]
+pub(crate) struct CodeCache(OnceCell<Option<Rc<CodeBlock>>>);
+
+impl CodeCache {
+    /// Gets the code block, compiling it with `compile` if it wasn't already.
+    pub(crate) fn get_or_compile<F>(&self, compile: F) -> Option<Rc<CodeBlock>>
+    where
+        F: FnOnce() -> Option<CodeBlock>,
+    {
+        self.0.get_or_init(|| compile().map(Rc::new)).clone()
+    }
+}
+
+/// The cache is not part of the code, so it is ignored when comparing statement lists.
+impl PartialEq for CodeCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+// SAFETY: The constants of a code block are strings and bigints, which hold no `Gc` pointers.
+unsafe impl Trace for CodeCache {
+    empty_trace!();
+}
+
 // Virtual Machine.
 #[derive(Debug)]
 pub struct VM<'a> {
     ctx: &'a mut Context,
-    instructions: Vec<Instruction>,
-    pool: Vec<Value>,
+    code: Rc<CodeBlock>,
     stack: Vec<Value>,
-    stack_pointer: usize,
+    /// The value of the last statement that has one, or `None` if it is empty.
+    completion_value: Option<Value>,
 }
 
 impl<'a> VM<'a> {
-    pub fn new(compiler: Compiler, ctx: &'a mut Context) -> Self {
+    pub fn new(code: Rc<CodeBlock>, ctx: &'a mut Context) -> Self {
         Self {
             ctx,
-            instructions: compiler.instructions,
-            pool: compiler.pool,
+            code,
             stack: vec![],
-            stack_pointer: 0,
+            completion_value: None,
         }
     }
 
@@ -43,14 +111,27 @@ impl<'a> VM<'a> {
         self.stack.pop().unwrap()
     }
 
-    pub fn run(&mut self) -> Result<Value> {
+    /// Runs the code block, and returns its completion record, which holds the value of its last
+    /// statement or of the `return` statement.
+    ///
+    /// The environments pushed by the code block are popped, even if it throws.
+    pub fn run(&mut self) -> Result<Completion> {
         let _timer = BoaProfiler::global().start_event("runVM", "vm");
+        let depth = self.ctx.realm().environment.depth();
+        let result = self.execute();
+        self.ctx.realm_mut().environment.truncate(depth);
+        result
+    }
+
+    fn execute(&mut self) -> Result<Completion> {
+        let code = Rc::clone(&self.code);
         let mut idx = 0;
 
-        while idx < self.instructions.len() {
+        while idx < code.instructions.len() {
+            #[cfg(feature = "profiler")]
             let _timer =
-                BoaProfiler::global().start_event(&self.instructions[idx].to_string(), "vm");
-            match self.instructions[idx] {
+                BoaProfiler::global().start_event(&code.instructions[idx].to_string(), "vm");
+            match code.instructions[idx] {
                 Instruction::Undefined => self.push(Value::undefined()),
                 Instruction::Null => self.push(Value::null()),
                 Instruction::True => self.push(Value::boolean(true)),
@@ -60,13 +141,28 @@ impl<'a> VM<'a> {
                 Instruction::Int32(i) => self.push(Value::integer(i)),
                 Instruction::Rational(r) => self.push(Value::rational(r)),
                 Instruction::String(index) => {
-                    let value = self.pool[index].clone();
+                    let value = code.pool[index].clone();
                     self.push(value)
                 }
                 Instruction::BigInt(index) => {
-                    let value = self.pool[index].clone();
+                    let value = code.pool[index].clone();
                     self.push(value)
                 }
+                Instruction::Pop => {
+                    self.pop();
+                }
+                Instruction::Dup => {
+                    let value = self.stack.last().cloned().unwrap();
+                    self.push(value);
+                }
+                Instruction::Swap => {
+                    let len = self.stack.len();
+                    self.stack.swap(len - 1, len - 2);
+                }
+                Instruction::GlobalObject => {
+                    let global = self.ctx.global_object().clone();
+                    self.push(global.into());
+                }
                 Instruction::Add => {
                     let r = self.pop();
                     let l = self.pop();
@@ -182,7 +278,7 @@ impl<'a> VM<'a> {
                 Instruction::Gt => {
                     let r = self.pop();
                     let l = self.pop();
-                    let val = l.ge(&r, self.ctx)?;
+                    let val = l.gt(&r, self.ctx)?;
 
                     self.push(val.into());
                 }
@@ -224,16 +320,10 @@ impl<'a> VM<'a> {
                 }
                 Instruction::InstanceOf => {
                     let r = self.pop();
-                    let _l = self.pop();
-                    if !r.is_object() {
-                        return self.ctx.throw_type_error(format!(
-                            "right-hand side of 'instanceof' should be an object, got {}",
-                            r.get_type().as_str()
-                        ));
-                    }
+                    let l = self.pop();
+                    let val = l.instance_of(&r, self.ctx)?;
 
-                    // spec: https://tc39.es/ecma262/#sec-instanceofoperator
-                    todo!("instanceof operator")
+                    self.push(val.into());
                 }
                 Instruction::Void => {
                     let _value = self.pop();
@@ -250,11 +340,13 @@ impl<'a> VM<'a> {
                 }
                 Instruction::Neg => {
                     let value = self.pop();
-                    self.push(Value::from(!value.to_boolean()));
+                    let value = value.neg(self.ctx)?;
+                    self.push(value);
                 }
                 Instruction::Not => {
                     let value = self.pop();
-                    self.push((!value.to_boolean()).into());
+                    let value = value.not(self.ctx)?;
+                    self.push(value.into());
                 }
                 Instruction::BitNot => {
                     let target = self.pop();
@@ -267,50 +359,200 @@ impl<'a> VM<'a> {
                     };
                     self.push(value.into());
                 }
-                Instruction::DefVar(name_index) => {
-                    let name: String = self.pool[name_index].to_string(self.ctx)?.to_string();
+                Instruction::ToNumeric => {
+                    let value = self.pop();
+                    let value = value.to_numeric(self.ctx)?;
+                    self.push(value.into());
+                }
+                Instruction::Inc => {
+                    let value = self.pop();
+                    let value = value.to_numeric(self.ctx)?.add_integer(1);
+                    self.push(value.into());
+                }
+                Instruction::Dec => {
+                    let value = self.pop();
+                    let value = value.to_numeric(self.ctx)?.add_integer(-1);
+                    self.push(value.into());
+                }
+                Instruction::GetName(index) => {
+                    let value = code.identifiers[index].run(self.ctx)?;
+                    self.push(value);
+                }
+                Instruction::SetName(index) => {
+                    let value = self.stack.last().cloned().unwrap();
+                    code.identifiers[index].set_value(value, self.ctx)?;
+                }
+                Instruction::AssignName(index) => {
+                    let value = self.stack.last().cloned().unwrap();
+                    code.identifiers[index].assign(value, self.ctx)?;
+                }
+                Instruction::DefVar(index) => {
+                    let name = &code.names[index];
+                    let environment = &mut self.ctx.realm_mut().environment;
+
+                    if !environment.has_binding(name) {
+                        environment
+                            .create_mutable_binding(
+                                name.to_string(),
+                                false,
+                                VariableScope::Function,
+                            )
+                            .map_err(|e| e.to_error(self.ctx))?;
+                        self.ctx
+                            .realm_mut()
+                            .environment
+                            .initialize_binding(name, Value::undefined())
+                            .map_err(|e| e.to_error(self.ctx))?;
+                    }
+                }
+                Instruction::InitVar(index) => {
+                    let name = &code.names[index];
+                    let value = self.pop();
+                    let environment = &mut self.ctx.realm_mut().environment;
+
+                    if environment.has_binding(name) {
+                        environment
+                            .set_mutable_binding(name, value, true)
+                            .map_err(|e| e.to_error(self.ctx))?;
+                    } else {
+                        environment
+                            .create_mutable_binding(
+                                name.to_string(),
+                                false,
+                                VariableScope::Function,
+                            )
+                            .map_err(|e| e.to_error(self.ctx))?;
+                        self.ctx
+                            .realm_mut()
+                            .environment
+                            .initialize_binding(name, value)
+                            .map_err(|e| e.to_error(self.ctx))?;
+                    }
+                }
+                Instruction::DefLet(index) => {
+                    let name = &code.names[index];
+                    let value = self.pop();
 
                     self.ctx
                         .realm_mut()
                         .environment
-                        .create_mutable_binding(name.to_string(), false, VariableScope::Function)
+                        .create_mutable_binding(name.to_string(), false, VariableScope::Block)
                         .map_err(|e| e.to_error(self.ctx))?;
-                }
-                Instruction::DefLet(name_index) => {
-                    let name = self.pool[name_index].to_string(self.ctx)?;
-
                     self.ctx
                         .realm_mut()
                         .environment
-                        .create_mutable_binding(name.to_string(), false, VariableScope::Block)
+                        .initialize_binding(name, value)
                         .map_err(|e| e.to_error(self.ctx))?;
                 }
-                Instruction::DefConst(name_index) => {
-                    let name = self.pool[name_index].to_string(self.ctx)?;
+                Instruction::DefConst(index) => {
+                    let name = &code.names[index];
+                    let value = self.pop();
 
                     self.ctx
                         .realm_mut()
                         .environment
                         .create_immutable_binding(name.to_string(), false, VariableScope::Block)
                         .map_err(|e| e.to_error(self.ctx))?;
-                }
-                Instruction::InitLexical(name_index) => {
-                    let name = self.pool[name_index].to_string(self.ctx)?;
-                    let value = self.pop();
                     self.ctx
                         .realm_mut()
                         .environment
-                        .initialize_binding(&name, value.clone())
+                        .initialize_binding(name, value)
                         .map_err(|e| e.to_error(self.ctx))?;
+                }
+                Instruction::GetField(index) => {
+                    let object = self.pop();
+
+                    let value = object.get_v(&code.names[index].clone().into(), self.ctx)?;
+                    self.push(value);
+                }
+                Instruction::GetFieldDyn => {
+                    let key = self.pop();
+                    let object = self.pop();
+                    let key = key.to_property_key(self.ctx)?;
+
+                    let value = object.get_v(&key, self.ctx)?;
+                    self.push(value);
+                }
+                Instruction::SetField(index) => {
+                    let value = self.pop();
+                    let object = self.pop();
+
+                    object.set_field(code.names[index].clone(), value.clone(), self.ctx)?;
+                    self.push(value);
+                }
+                Instruction::SetFieldDyn => {
+                    let key = self.pop();
+                    let object = self.pop();
+                    let value = self.pop();
+                    let key = key.to_property_key(self.ctx)?;
 
+                    object.set_field(key, value.clone(), self.ctx)?;
                     self.push(value);
                 }
+                Instruction::Call(argc) => {
+                    let args = self.stack.split_off(self.stack.len() - argc);
+                    let function = self.pop();
+                    let this = self.pop();
+
+                    let result = self.ctx.call(&function, &this, &args)?;
+                    self.push(result);
+                }
+                Instruction::Function(index) => {
+                    let function = code.functions[index].run(self.ctx)?;
+                    self.push(function);
+                }
+                Instruction::NamedFunction(index, name) => {
+                    let name = &code.names[name];
+                    let function = code.functions[index].run_named(name, self.ctx)?;
+                    self.push(function);
+                }
+                Instruction::SetCompletion => {
+                    let value = self.pop();
+                    self.completion_value = Some(value);
+                }
+                Instruction::Return => {
+                    let value = self.pop();
+                    return Ok(Completion::new(CompletionType::Return, Some(value)));
+                }
+                Instruction::Jump(address) => {
+                    idx = address;
+                    continue;
+                }
+                Instruction::JumpIfFalse(address) => {
+                    if !self.pop().to_boolean() {
+                        idx = address;
+                        continue;
+                    }
+                }
+                Instruction::JumpIfTrue(address) => {
+                    if self.pop().to_boolean() {
+                        idx = address;
+                        continue;
+                    }
+                }
+                Instruction::JumpIfNotNullish(address) => {
+                    if !self.pop().is_null_or_undefined() {
+                        idx = address;
+                        continue;
+                    }
+                }
+                Instruction::PushBlockEnv => {
+                    let environment = &mut self.ctx.realm_mut().environment;
+                    environment.push(new_declarative_environment(Some(
+                        environment.get_current_environment_ref().clone(),
+                    )));
+                }
+                Instruction::PopEnv => {
+                    let _ = self.ctx.realm_mut().environment.pop();
+                }
             }
 
             idx += 1;
         }
 
-        let res = self.pop();
-        Ok(res)
+        Ok(Completion::new(
+            CompletionType::Normal,
+            self.completion_value.take(),
+        ))
     }
 }
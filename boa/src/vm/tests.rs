@@ -0,0 +1,196 @@
+use crate::{
+    context::ContextBuilder,
+    error::{EvalError, TerminationReason},
+    exec, parse,
+    vm::Compiler,
+};
+
+/// Runs the code in the VM, and checks that the result is the same as with the AST interpreter.
+fn exec_vm(src: &str) -> String {
+    let mut context = ContextBuilder::new().use_vm(true).build();
+    let result = match context.eval(src) {
+        Ok(value) => value.display().to_string(),
+        Err(error) => error.into_value(&mut context).display().to_string(),
+    };
+    assert_eq!(result, exec(src), "different results for {}", src);
+    result
+}
+
+/// Checks whether the VM supports the code.
+fn compiles(src: &str) -> bool {
+    let statement_list = parse(src, false).expect("could not parse the code");
+    Compiler::compile_statement_list(&statement_list).is_some()
+}
+
+#[test]
+fn expressions() {
+    assert_eq!(exec_vm("1 + 2 * 3"), "7");
+    assert_eq!(exec_vm("'a' + 1"), "\"a1\"");
+    assert_eq!(exec_vm("2 > 1"), "true");
+    assert_eq!(exec_vm("1 > 1"), "false");
+    assert_eq!(exec_vm("-'3'"), "-3");
+    assert_eq!(exec_vm("!0"), "true");
+    assert_eq!(exec_vm("typeof 1n"), "\"bigint\"");
+    assert_eq!(exec_vm("(0 || null) ?? 'default'"), "\"default\"");
+    assert_eq!(exec_vm("1 && 0"), "0");
+    assert_eq!(exec_vm("true ? 1 : 2"), "1");
+    assert_eq!(exec_vm("(1, 2)"), "2");
+}
+
+#[test]
+fn bindings() {
+    assert_eq!(exec_vm("var a; a"), "undefined");
+    assert_eq!(exec_vm("var a = 1; var a; a"), "1");
+    assert_eq!(exec_vm("let a = 1; a += 2; a"), "3");
+    assert_eq!(exec_vm("let a = 1; a++"), "1");
+    assert_eq!(exec_vm("let a = 1; ++a"), "2");
+    assert_eq!(exec_vm("let a = null; a ??= 4; a"), "4");
+    assert_eq!(exec_vm("const c = 1; c ||= 2; c"), "1");
+    assert_eq!(exec_vm("b = 5; b"), "5");
+    assert_eq!(
+        exec_vm("const a = 1; a = 2"),
+        "Uncaught \"TypeError\": \"Cannot mutate an immutable binding a\""
+    );
+    assert_eq!(
+        exec_vm("undeclared"),
+        "Uncaught \"ReferenceError\": \"undeclared is not defined\""
+    );
+}
+
+#[test]
+fn blocks() {
+    assert_eq!(exec_vm("let a = 1; { let a = 2; } a"), "1");
+    assert_eq!(exec_vm("{ var b = 2; } b"), "2");
+    assert_eq!(exec_vm("if (false) { 1 } else { 2 }"), "2");
+    assert_eq!(exec_vm("if (false) { 1 }"), "undefined");
+    assert_eq!(exec_vm("1; var a = 2;"), "1");
+    assert_eq!(exec_vm("1; { let a = 2; }"), "1");
+    assert_eq!(exec_vm("1; if (true) {}"), "undefined");
+}
+
+#[test]
+fn loops() {
+    let scenario = r#"
+        let i = 0;
+        let sum = 0;
+        while (i < 10) {
+            i++;
+            if (i % 2 === 0) {
+                continue;
+            }
+            if (i > 7) {
+                break;
+            }
+            sum += i;
+        }
+        sum
+    "#;
+    assert_eq!(exec_vm(scenario), "16");
+    assert_eq!(exec_vm("let i = 0; while (i < 3) { i++; }"), "2");
+    assert_eq!(exec_vm("let i = 0; while (i < 3) { i++; break; }"), "0");
+    assert_eq!(exec_vm("1; while (false) {}"), "undefined");
+}
+
+#[test]
+fn objects_and_calls() {
+    let scenario = r#"
+        let o = Object();
+        o.a = 1;
+        o["b"] = 2;
+        o.a += o.b;
+        o.a
+    "#;
+    assert_eq!(exec_vm(scenario), "3");
+    assert_eq!(exec_vm("let o = Object(); o.a = 1; o.a ||= 2"), "1");
+    assert_eq!(exec_vm("let o = Object(); o.a ??= 2; o.a"), "2");
+    assert_eq!(exec_vm("'abc'.length"), "3");
+    assert_eq!(exec_vm("Math.max(1, 3, 2)"), "3");
+    assert_eq!(exec_vm("'abc'['charAt'](1)"), "\"b\"");
+    assert_eq!(
+        exec_vm("let x = 1; x()"),
+        "Uncaught \"TypeError\": \"number is not a function\""
+    );
+}
+
+#[test]
+fn functions() {
+    let scenario = r#"
+        function fib(n) {
+            if (n < 2) {
+                return n;
+            }
+            return fib(n - 1) + fib(n - 2);
+        }
+        fib(15)
+    "#;
+    assert_eq!(exec_vm(scenario), "610");
+
+    let scenario = r#"
+        function find(limit) {
+            let i = 0;
+            while (true) {
+                {
+                    let j = i * i;
+                    if (j > limit) {
+                        return i;
+                    }
+                }
+                i++;
+            }
+        }
+        find(50)
+    "#;
+    assert_eq!(exec_vm(scenario), "8");
+    assert_eq!(exec_vm("let add = (a, b) => a + b; add(1, 2)"), "3");
+    assert_eq!(exec_vm("const f = () => {}; f.name"), "\"f\"");
+    assert_eq!(exec_vm("var g; g = function () {}; g.name"), "\"g\"");
+}
+
+#[test]
+fn fallback() {
+    assert!(compiles("let a = 1; a + 1"));
+    assert!(!compiles("[1, 2]"));
+    assert!(!compiles("for (let i = 0; i < 2; i++) {}"));
+
+    // The script is not supported, but the function bodies are compiled separately.
+    let scenario = r#"
+        function sum(a, b) {
+            return a + b;
+        }
+        let values = [];
+        for (let i = 0; i < 3; i++) {
+            values.push(sum(i, 1));
+        }
+        values.join()
+    "#;
+    assert_eq!(exec_vm(scenario), "\"1,2,3\"");
+    assert_eq!(
+        exec_vm("function f() { throw new Error('oops'); } f()"),
+        "Uncaught \"Error\": \"oops\""
+    );
+}
+
+#[test]
+fn memory_limit() {
+    let scenario = r#"
+        let a = Object();
+        let i = 0;
+        while (true) {
+            a[i] = 'x'.repeat(1000000);
+            i++;
+        }
+    "#;
+    assert!(compiles(scenario));
+
+    let mut context = ContextBuilder::new()
+        .use_vm(true)
+        .memory_limit(10 * 1024 * 1024)
+        .build();
+    let error = context
+        .eval(scenario)
+        .expect_err("the memory limit was not enforced");
+    assert!(matches!(
+        error,
+        EvalError::Terminated(TerminationReason::MemoryLimit)
+    ));
+}
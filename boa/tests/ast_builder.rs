@@ -0,0 +1,89 @@
+//! Checks that a program can be built from the public AST node constructors, without the parser.
+//!
+//! The hand-built program is pretty-printed, parsed back and executed, which must all agree.
+
+use boa::{
+    syntax::ast::{
+        node::{
+            ArrayDecl, BinOp, Call, ConstDecl, ConstDeclList, FormalParameter, FunctionDecl,
+            GetField, Identifier, Node, Return, StatementList,
+        },
+        op::NumOp,
+        Const,
+    },
+    Context, Value,
+};
+
+/// Builds the AST of:
+///
+/// ```js
+/// function add(a, b = 1) {
+///     return a + b;
+/// }
+/// const values = [add(2, 3), add(41)];
+/// values[0] * values[1];
+/// ```
+fn program() -> StatementList {
+    let add = FunctionDecl::new(
+        "add",
+        vec![
+            FormalParameter::new("a", None, false),
+            FormalParameter::new("b", Some(Const::from(1).into()), false),
+        ],
+        vec![Return::new(
+            BinOp::new(NumOp::Add, Identifier::from("a"), Identifier::from("b")),
+            None,
+        )
+        .into()],
+    );
+    let values = ConstDeclList::from(ConstDecl::new(
+        "values",
+        Some(ArrayDecl::from(vec![
+            Call::new(
+                Identifier::from("add"),
+                vec![Const::from(2).into(), Const::from(3).into()],
+            )
+            .into(),
+            Call::new(Identifier::from("add"), vec![Const::from(41).into()]).into(),
+        ])),
+    ));
+    let product = BinOp::new(
+        NumOp::Mul,
+        GetField::new(Identifier::from("values"), Const::from(0)),
+        GetField::new(Identifier::from("values"), Const::from(1)),
+    );
+
+    StatementList::from(vec![Node::from(add), values.into(), product.into()])
+}
+
+#[test]
+fn built_ast_executes() {
+    let mut context = Context::new();
+
+    assert_eq!(context.execute_ast(&program()).unwrap(), Value::from(210));
+}
+
+#[test]
+fn built_ast_pretty_prints_to_the_same_program() {
+    let program = program();
+    let printed = program.to_string();
+
+    assert!(
+        printed.starts_with("function add(a, b = 1) {\n"),
+        "{}",
+        printed
+    );
+    assert!(
+        printed.contains("const values = [add(2, 3), add(41)];\n"),
+        "{}",
+        printed
+    );
+    assert!(printed.ends_with("values[0] * values[1];\n"), "{}", printed);
+
+    let reparsed = boa::parse(&printed, false).unwrap();
+    assert_eq!(reparsed.items()[0], program.items()[0]);
+    assert_eq!(
+        Context::new().execute_ast(&reparsed).unwrap(),
+        Value::from(210)
+    );
+}
@@ -0,0 +1,137 @@
+//! Checks that evaluating unusual but valid scripts never panics.
+//!
+//! Each script either completes or throws an error, which is all the engine may do with code it
+//! can parse. Scripts that used to abort the process are added here when the panic is fixed.
+
+use boa::Context;
+use std::panic::{self, AssertUnwindSafe};
+
+/// The scripts, which are each evaluated in a new context.
+const WEIRD_SCRIPTS: &[&str] = &[
+    // Strings with surrogate pairs, cut in the middle.
+    "'😀'.charAt(0)",
+    "'😀'.substring(1)",
+    "'a😀b'.substring(0, 2)",
+    "[...'😀x😀']",
+    "'é'.padEnd(5, 'é😀')",
+    "'a'.padStart(5, '')",
+    "'a'.padEnd(5, '')",
+    // Patterns given as strings are searched literally.
+    "'a(b'.replace('(', 'x')",
+    "'a[b'.replace('[', '$&$&')",
+    "'a\\\\b'.replace('\\\\', '/')",
+    "'abc'.replace('', '-')",
+    "'abc'.replace(/(?:)/g, '$0$1$<x>$')",
+    // Regular expressions with a `lastIndex` out of the string or inside a character.
+    "var re = /a/g; re.lastIndex = 100; re.exec('a')",
+    "var re = /a/g; re.lastIndex = 100; re.test('a')",
+    "var re = /./g; re.lastIndex = 1; re.exec('éa')",
+    "var re = /a/y; re.lastIndex = -1; re.test('a')",
+    "var re = /a/; re.exec({ toString() { re.lastIndex = 1; re.foo = 1; return 'a'; } })",
+    // Arrays with unusual lengths.
+    "new Array(-1)",
+    "new Array(2 ** 32)",
+    "new Array(1.5)",
+    "new Array(2 ** 31).length",
+    "Array(4294967295).length",
+    "var a = []; a.length = 4294967295; a.push(1)",
+    "[].lastIndexOf(1, 0)",
+    "[1, 2, 3].lastIndexOf(1, 100)",
+    // Iterators used by the getters they call.
+    "var it; var a = [1]; Object.defineProperty(a, 0, { get() { it.next(); it.x = 1; return 1; } }); it = a.values(); it.next()",
+    "var it = Array.prototype.entries.call({ get length() { it.next(); return 2; } }); it.next()",
+    "var m = new Map([[1, 2]]); for (var [k, v] of m) { m.delete(k); m.set(k + 1, v); if (k > 5) break; }",
+    // Numbers converted to strings in other radixes, or with unusual digits.
+    "(0.1).toString(16)",
+    "(1 / 3).toString(36)",
+    "Math.PI.toString(36)",
+    "(-1e-7).toString(2)",
+    "(5e-324).toString(36)",
+    "(1.7976931348623157e308).toString(2)",
+    "(1).toFixed(1e9)",
+    "(1).toFixed(Infinity)",
+    "(1).toFixed(-Infinity)",
+    "(1e-7).toPrecision(2)",
+    "(1e21).toPrecision(3)",
+    "(123.456).toPrecision(100)",
+    "parseInt('zz', 36) + parseInt('1'.repeat(400), 10)",
+    // Integer arithmetic at the edges.
+    "(-2147483648 | 0) % -1",
+    "var x = -2147483648 | 0; x %= -1",
+    "(2147483647 | 0) + 1",
+    "-(-2147483648 | 0)",
+    "(-2147483648 | 0) * -1",
+    "1 << 32 >> 33 >>> 34",
+    "10n ** 100n % 7n",
+    "BigInt.asIntN(64, -(2n ** 63n) - 1n)",
+    "BigInt.asUintN(0, 5n)",
+    "2n ** 64n < 1e30",
+    // Dates out of range.
+    "Date.UTC(2000, 5e9)",
+    "new Date(2000, 5e9, 1)",
+    "new Date(2000, -5e9, 1)",
+    "new Date(0).setMilliseconds(-1e30)",
+    "new Date(0).setMonth(1e10)",
+    "new Date(8.64e15 + 1)",
+    "new Date(NaN).toISOString === undefined",
+    // Functions with fewer arguments than parameters.
+    "function f(a, b, ...rest) { return rest.length; } f(1)",
+    "function g(...rest) { return rest; } g()",
+    "((a, b, c, ...d) => d)()",
+    // Declarations that clash with parameters or each other.
+    "function f(a) { let a = 1; } f()",
+    "function f() { let a; let a; } f()",
+    "{ const a = 1; const a = 2; }",
+    "{ let a; let a; }",
+    "function f(a, a) { return a; } f(1, 2)",
+    // JSON with values that have no JSON representation.
+    "JSON.stringify({ toJSON() {} })",
+    "JSON.stringify({ a: { toJSON() { return Symbol(); } } })",
+    "JSON.stringify([undefined, function () {}, Symbol()])",
+    "JSON.stringify({ a: Symbol() }, ['a'])",
+    "JSON.stringify({ a: 1 }, null, 'éééééééééééé')",
+    "JSON.stringify({ a: 1 }, null, '😀😀😀😀😀😀')",
+    "var o = { get a() { this.b = 1; delete this.c; return 1; }, c: 2 }; JSON.stringify(o)",
+    "var o = { a: 1, b: 2 }; JSON.stringify(o, function (k, v) { o.c = 3; return v; })",
+    "var r = ['a']; JSON.stringify({ a: 1 }, [{ toString() { r.push('b'); return 'a'; } }])",
+    // Code displayed in error messages.
+    "new ({ m() {}, get a() { return 1; }, set a(v) {} })",
+    "new ({ a: 1, ...{ b: 2 } })",
+    "new (function () {}).call",
+    // Other unusual but valid scripts.
+    "Object.setPrototypeOf(Object.prototype, {})",
+    "var o = {}; Object.setPrototypeOf(o, Object.create(o))",
+    "Object.defineProperty([], 'length', { value: -1 })",
+    "Object.defineProperty([], 'length', { get() {} })",
+    "var a = [1, 2, 3]; a.length = 0; a[10] = 1; a.length",
+    "for (var k in 'abc') {} for (var k in null) {} for (var k in 1) {}",
+    "String(Symbol('a'))",
+    "new String(Symbol('a'))",
+    "Symbol() + ''",
+    "'' + {}.__proto__",
+    "[].concat.call(1, 2)",
+    "Function.prototype.call.call(function () { return this; })",
+    "(function () { return arguments.length; })(...new Array(3))",
+    "this.x = 1; delete this.x; delete this.undefined; x",
+];
+
+#[test]
+fn eval_never_panics() {
+    let mut panics = Vec::new();
+    for script in WEIRD_SCRIPTS {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut context = Context::new();
+            let _ = context.eval(script);
+        }));
+        if result.is_err() {
+            panics.push(*script);
+        }
+    }
+
+    assert!(
+        panics.is_empty(),
+        "evaluating {} scripts panicked:\n{}",
+        panics.len(),
+        panics.join("\n")
+    );
+}
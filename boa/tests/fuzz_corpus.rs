@@ -0,0 +1,80 @@
+//! Checks that parsing the fuzzing corpus never panics.
+//!
+//! The inputs of `fuzz/corpus/parse` are parsed along with simple mutations of them: their
+//! prefixes, which end the code anywhere, and copies with one byte removed or inserted. This
+//! runs the cases the fuzzer found without needing libFuzzer, see `docs/fuzzing.md`.
+
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+/// The bytes inserted in the inputs, which start or end the tokens that are the hardest to lex.
+const INSERTED_BYTES: &[u8] = b"/\\`'\"([{}.0x$\n\xE2\xFF";
+
+/// Reads the inputs of the corpus, with their file names.
+fn corpus() -> Vec<(String, Vec<u8>)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuzz/corpus/parse");
+    let mut inputs: Vec<_> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", dir.display(), e))
+        .map(|entry| {
+            let path = entry.expect("could not read the corpus").path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let input = fs::read(&path).expect("could not read a corpus input");
+            (name, input)
+        })
+        .collect();
+    inputs.sort();
+    inputs
+}
+
+/// Returns the input and its mutations.
+fn mutations(input: &[u8]) -> Vec<Vec<u8>> {
+    let mut mutations = vec![input.to_vec()];
+    for i in 0..input.len() {
+        mutations.push(input[..i].to_vec());
+
+        let mut removed = input.to_vec();
+        removed.remove(i);
+        mutations.push(removed);
+
+        for &byte in INSERTED_BYTES {
+            let mut inserted = input.to_vec();
+            inserted.insert(i, byte);
+            mutations.push(inserted);
+        }
+    }
+    mutations
+}
+
+#[test]
+fn parsing_never_panics() {
+    let corpus = corpus();
+    assert!(!corpus.is_empty(), "the corpus is empty");
+
+    let mut panics = Vec::new();
+    for (name, input) in &corpus {
+        for mutation in mutations(input) {
+            for &strict in &[false, true] {
+                let result =
+                    panic::catch_unwind(AssertUnwindSafe(|| boa::parse(&mutation, strict)));
+                if result.is_err() {
+                    panics.push(format!(
+                        "{} (strict: {}): {:?}",
+                        name,
+                        strict,
+                        String::from_utf8_lossy(&mutation)
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        panics.is_empty(),
+        "parsing panicked on {} inputs:\n{}",
+        panics.len(),
+        panics.join("\n")
+    );
+}
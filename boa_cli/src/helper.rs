@@ -1,10 +1,11 @@
+use boa::syntax::parser::Parser;
 use colored::*;
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 use rustyline::{
     error::ReadlineError,
     highlight::Highlighter,
-    validate::{MatchingBracketValidator, ValidationContext, ValidationResult, Validator},
+    validate::{ValidationContext, ValidationResult, Validator},
 };
 use rustyline_derive::{Completer, Helper, Hinter};
 use std::borrow::Cow;
@@ -37,7 +38,6 @@ const IDENTIFIER_COLOR: Color = Color::TrueColor {
 #[derive(Completer, Helper, Hinter)]
 pub(crate) struct RLHelper {
     highlighter: LineHighlighter,
-    validator: MatchingBracketValidator,
 }
 
 impl RLHelper {
@@ -45,7 +45,6 @@ impl RLHelper {
     pub(crate) fn new() -> Self {
         Self {
             highlighter: LineHighlighter,
-            validator: MatchingBracketValidator::new(),
         }
     }
 }
@@ -55,11 +54,13 @@ impl Validator for RLHelper {
         &self,
         context: &mut ValidationContext<'_>,
     ) -> Result<ValidationResult, ReadlineError> {
-        self.validator.validate(context)
-    }
-
-    fn validate_while_typing(&self) -> bool {
-        self.validator.validate_while_typing()
+        // More lines are read while the input is only missing its end, the other errors are
+        // reported when it is evaluated.
+        let result = Parser::new(context.input().as_bytes(), false).parse_all();
+        Ok(match result {
+            Err(ref error) if error.is_unexpected_eof() => ValidationResult::Incomplete,
+            _ => ValidationResult::Valid(None),
+        })
     }
 }
 
@@ -151,9 +151,9 @@ pub fn main() -> Result<(), std::io::Error> {
                 eprintln!("{}", e);
             }
         } else {
-            match context.eval(&buffer) {
+            match context.eval_with_source_name(&buffer, &file.to_string_lossy()) {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {}", v.display()),
+                Err(e) => eprintln!("Uncaught {}", e),
             }
         }
     }
@@ -189,9 +189,7 @@ pub fn main() -> Result<(), std::io::Error> {
                     } else {
                         match context.eval(line.trim_end()) {
                             Ok(v) => println!("{}", v.display()),
-                            Err(v) => {
-                                eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
-                            }
+                            Err(e) => eprintln!("{}: {}", "Uncaught".red(), e.to_string().red()),
                         }
                     }
                 }
@@ -1,35 +1,69 @@
 //! Execution module for the test runner.
 
 use super::{
-    Harness, Outcome, Phase, SuiteResult, Test, TestFlags, TestOutcomeResult, TestResult,
-    TestSuite, IGNORED,
+    host, FeatureResult, Harness, Outcome, Phase, SuiteResult, Test, TestFlags, TestOutcomeResult,
+    TestResult, TestSuite, IGNORED,
 };
-use boa::{parse, Context, Value};
+use boa::{error::EvalError, parse, Context, Value};
 use colored::Colorize;
-use std::panic;
+use rayon::prelude::*;
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::Write,
+    panic,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
 
 impl TestSuite {
     /// Runs the test suite.
-    pub(crate) fn run(&self, harness: &Harness, verbose: u8) -> SuiteResult {
+    pub(crate) fn run(
+        &self,
+        harness: &Arc<Harness>,
+        verbose: u8,
+        timeout: Duration,
+    ) -> SuiteResult {
         if verbose != 0 {
             println!("Suite {}:", self.name);
         }
 
-        // TODO: in parallel
+        // The results are collected in the order of the suites and tests, whatever the order in
+        // which they were run.
         let suites: Vec<_> = self
             .suites
-            .iter()
-            .map(|suite| suite.run(harness, verbose))
+            .par_iter()
+            .map(|suite| suite.run(harness, verbose, timeout))
             .collect();
 
-        // TODO: in parallel
-        let tests: Vec<_> = self
+        let runs: Vec<_> = self
             .tests
-            .iter()
-            .map(|test| test.run(harness, verbose))
-            .flatten()
+            .par_iter()
+            .map(|test| (test, test.run(harness, verbose, timeout)))
             .collect();
 
+        let mut features = BTreeMap::<_, FeatureResult>::new();
+        for suite in &suites {
+            for (name, result) in &suite.features {
+                features.entry(name.clone()).or_default().merge(result);
+            }
+        }
+        let mut tests = Vec::new();
+        for (test, results) in runs {
+            for feature in test.features.iter() {
+                let feature = features.entry(feature.clone()).or_default();
+                for result in &results {
+                    feature.add_outcome(result.result);
+                }
+            }
+            tests.extend(results);
+        }
+
         if verbose != 0 {
             println!();
         }
@@ -38,11 +72,13 @@ impl TestSuite {
         let mut passed = 0;
         let mut ignored = 0;
         let mut panic = 0;
+        let mut timed_out = 0;
         for test in &tests {
             match test.result {
                 TestOutcomeResult::Passed => passed += 1,
                 TestOutcomeResult::Ignored => ignored += 1,
                 TestOutcomeResult::Panic => panic += 1,
+                TestOutcomeResult::Timeout => timed_out += 1,
                 TestOutcomeResult::Failed => {}
             }
         }
@@ -54,17 +90,20 @@ impl TestSuite {
             passed += suite.passed;
             ignored += suite.ignored;
             panic += suite.panic;
+            timed_out += suite.timeout;
         }
 
         if verbose != 0 {
             println!(
-                "Results: total: {}, passed: {}, ignored: {}, failed: {} (panics: {}{}), conformance: {:.2}%",
+                "Results of {}: total: {}, passed: {}, ignored: {}, failed: {} (panics: {}{}, timeouts: {}), conformance: {:.2}%",
+                self.name,
                 total,
                 passed.to_string().green(),
                 ignored.to_string().yellow(),
                 (total - passed - ignored).to_string().red(),
                 if panic == 0 {"0".normal()} else {panic.to_string().red()},
                 if panic != 0 {" ⚠"} else {""}.red(),
+                if timed_out == 0 {"0".normal()} else {timed_out.to_string().red()},
                 (passed as f64 / total as f64) * 100.0
             );
         }
@@ -75,29 +114,44 @@ impl TestSuite {
             passed,
             ignored,
             panic,
+            timeout: timed_out,
             suites,
             tests,
+            features,
         }
     }
 }
 
 impl Test {
     /// Runs the test.
-    pub(crate) fn run(&self, harness: &Harness, verbose: u8) -> Vec<TestResult> {
+    pub(crate) fn run(
+        &self,
+        harness: &Arc<Harness>,
+        verbose: u8,
+        timeout: Duration,
+    ) -> Vec<TestResult> {
         let mut results = Vec::new();
         if self.flags.contains(TestFlags::STRICT) {
-            results.push(self.run_once(harness, true, verbose));
+            results.push(self.run_once(harness, true, verbose, timeout));
         }
 
         if self.flags.contains(TestFlags::NO_STRICT) || self.flags.contains(TestFlags::RAW) {
-            results.push(self.run_once(harness, false, verbose));
+            results.push(self.run_once(harness, false, verbose, timeout));
         }
 
         results
     }
 
     /// Runs the test once, in strict or non-strict mode
-    fn run_once(&self, harness: &Harness, strict: bool, verbose: u8) -> TestResult {
+    ///
+    /// The test is reported as timed out if it doesn't finish within `timeout`.
+    fn run_once(
+        &self,
+        harness: &Arc<Harness>,
+        strict: bool,
+        verbose: u8,
+        timeout: Duration,
+    ) -> TestResult {
         if verbose > 1 {
             println!(
                 "Starting `{}`{}",
@@ -106,6 +160,18 @@ impl Test {
             );
         }
 
+        // The verbose report of the test, once it has run.
+        let mut log = String::new();
+        if verbose > 1 {
+            writeln!(
+                log,
+                "Finished `{}`{}",
+                self.name,
+                if strict { " (strict mode)" } else { "" }
+            )
+            .expect("writing to a string cannot fail");
+        }
+
         let (result, result_text) = if !IGNORED.contains_any_flag(self.flags)
             && !IGNORED.contains_test(&self.name)
             && !IGNORED.contains_any_feature(&self.features)
@@ -122,96 +188,56 @@ impl Test {
                     phase: Phase::Runtime,
                     error_type: _,
                 })) {
-            let res = panic::catch_unwind(|| match self.expected_outcome {
-                Outcome::Positive => {
-                    // TODO: implement async and add `harness/doneprintHandle.js` to the includes.
-
-                    match self.set_up_env(&harness, strict) {
-                        Ok(mut context) => {
-                            let res = context.eval(&self.content.as_ref());
-
-                            let passed = res.is_ok();
-                            let text = match res {
-                                Ok(val) => format!("{}", val.display()),
-                                Err(e) => format!("Uncaught {}", e.display()),
-                            };
+            // The test runs on its own thread, which is abandoned if it doesn't finish in time.
+            let (sender, receiver) = mpsc::channel();
+            let test = self.clone();
+            let test_harness = Arc::clone(harness);
+            thread::Builder::new()
+                .name(self.name.to_string())
+                .spawn(move || {
+                    let outcome = panic::catch_unwind(|| test.evaluate(&test_harness, strict))
+                        .map_err(|payload| {
+                            LAST_PANIC
+                                .with(|last| last.borrow_mut().take())
+                                .unwrap_or_else(|| panic_message(payload.as_ref()))
+                        });
+                    // The receiver is gone if the test has timed out.
+                    let _ = sender.send(outcome);
+                })
+                .expect("could not spawn the test thread");
 
-                            (passed, text)
-                        }
-                        Err(e) => (false, e),
-                    }
-                }
-                Outcome::Negative {
-                    phase: Phase::Parse,
-                    ref error_type,
+            let result = match receiver.recv_timeout(timeout) {
+                Ok(Ok((true, text))) => (TestOutcomeResult::Passed, text),
+                Ok(Ok((false, text))) => (TestOutcomeResult::Failed, text),
+                Ok(Err(message)) => {
+                    eprintln!("last panic was on test \"{}\"", self.name);
+                    (TestOutcomeResult::Panic, message)
                 }
-                | Outcome::Negative {
-                    phase: Phase::Early,
-                    ref error_type,
-                } => {
-                    assert_eq!(
-                        error_type.as_ref(),
-                        "SyntaxError",
-                        "non-SyntaxError parsing/early error found in {}",
-                        self.name
-                    );
-
-                    match parse(&self.content.as_ref(), strict) {
-                        Ok(n) => (false, format!("{:?}", n)),
-                        Err(e) => (true, format!("Uncaught {}", e)),
-                    }
+                Err(RecvTimeoutError::Timeout) => {
+                    eprintln!("test \"{}\" timed out", self.name);
+                    let text = format!("did not finish within {} seconds", timeout.as_secs());
+                    (TestOutcomeResult::Timeout, text)
                 }
-                Outcome::Negative {
-                    phase: Phase::Resolution,
-                    error_type: _,
-                } => todo!("check module resolution errors"),
-                Outcome::Negative {
-                    phase: Phase::Runtime,
-                    ref error_type,
-                } => {
-                    if let Err(e) = parse(&self.content.as_ref(), strict) {
-                        (false, format!("Uncaught {}", e))
-                    } else {
-                        match self.set_up_env(&harness, strict) {
-                            Ok(mut context) => match context.eval(&self.content.as_ref()) {
-                                Ok(res) => (false, format!("{}", res.display())),
-                                Err(e) => {
-                                    let passed =
-                                        e.display().to_string().contains(error_type.as_ref());
-
-                                    (passed, format!("Uncaught {}", e.display()))
-                                }
-                            },
-                            Err(e) => (false, e),
-                        }
-                    }
+                Err(RecvTimeoutError::Disconnected) => {
+                    unreachable!("the test thread always sends the outcome")
                 }
-            });
-
-            let result = res
-                .map(|(res, text)| {
-                    if res {
-                        (TestOutcomeResult::Passed, text)
-                    } else {
-                        (TestOutcomeResult::Failed, text)
-                    }
-                })
-                .unwrap_or_else(|_| {
-                    eprintln!("last panic was on test \"{}\"", self.name);
-                    (TestOutcomeResult::Panic, String::new())
-                });
+            };
 
             if verbose > 1 {
-                println!(
+                writeln!(
+                    log,
                     "Result: {}",
                     if matches!(result, (TestOutcomeResult::Passed, _)) {
                         "Passed".green()
                     } else if matches!(result, (TestOutcomeResult::Failed, _)) {
                         "Failed".red()
+                    } else if matches!(result, (TestOutcomeResult::Timeout, _)) {
+                        "⏱ Timeout ⏱".red()
                     } else {
                         "⚠ Panic ⚠".red()
                     }
-                );
+                )
+                .expect("writing to a string cannot fail");
             } else {
                 print!(
                     "{}",
@@ -226,7 +252,8 @@ impl Test {
             result
         } else {
             if verbose > 1 {
-                println!("Result: {}", "Ignored".yellow());
+                writeln!(log, "Result: {}", "Ignored".yellow())
+                    .expect("writing to a string cannot fail");
             } else {
                 print!("{}", ".".yellow());
             }
@@ -234,24 +261,147 @@ impl Test {
         };
 
         if verbose > 1 {
-            println!("Result text:");
-            println!("{}", result_text);
-            println!();
+            writeln!(log, "Result text:\n{}\n", result_text)
+                .expect("writing to a string cannot fail");
+            // Tests run in parallel, so the report of each test is printed at once.
+            print!("{}", log);
         }
 
+        let error = match result {
+            TestOutcomeResult::Passed | TestOutcomeResult::Ignored => None,
+            _ => Some(truncate_error(&result_text)),
+        };
+
         TestResult {
             name: self.name.clone(),
             strict,
             result,
             result_text: result_text.into_boxed_str(),
+            error,
+        }
+    }
+
+    /// Evaluates the test, returning whether it passed and the text of its result.
+    fn evaluate(&self, harness: &Harness, strict: bool) -> (bool, String) {
+        match self.expected_outcome {
+            Outcome::Positive => {
+                match self.set_up_env(&harness, strict) {
+                    Ok(mut context) => {
+                        let res = context.eval(&self.content.as_ref());
+
+                        if self.flags.contains(TestFlags::ASYNC) {
+                            // The test is done once the jobs it queued have run.
+                            return match res
+                                .and_then(|_| context.run_jobs().map_err(EvalError::from))
+                            {
+                                Ok(()) => async_outcome(),
+                                Err(e) => (false, format!("Uncaught {}", e)),
+                            };
+                        }
+
+                        let passed = res.is_ok();
+                        let text = match res {
+                            Ok(val) => format!("{}", val.display()),
+                            Err(e) => format!("Uncaught {}", e),
+                        };
+
+                        (passed, text)
+                    }
+                    Err(e) => (false, e),
+                }
+            }
+            Outcome::Negative {
+                phase: Phase::Resolution,
+                error_type: _,
+            } => todo!("check module resolution errors"),
+            Outcome::Negative {
+                phase,
+                ref error_type,
+            } => self.evaluate_negative(harness, strict, phase, error_type),
+        }
+    }
+
+    /// Evaluates a negative test, which passes if it throws an error of type `error_type` in the
+    /// given phase.
+    ///
+    /// The errors of the parser are `SyntaxError`s of the parse phase, which includes the early
+    /// errors. The type of the values thrown at runtime is their `name` property.
+    fn evaluate_negative(
+        &self,
+        harness: &Harness,
+        strict: bool,
+        phase: Phase,
+        error_type: &str,
+    ) -> (bool, String) {
+        let (actual_phase, actual_type, text) = match parse(&self.content.as_ref(), strict) {
+            Err(e) => (
+                Phase::Parse,
+                Some(String::from("SyntaxError")),
+                format!("Uncaught SyntaxError: {}", e),
+            ),
+            Ok(statements) => {
+                if matches!(phase, Phase::Parse | Phase::Early) {
+                    return (
+                        false,
+                        format!(
+                            "expected a {} in the {} phase, but the test was parsed:\n{:?}",
+                            error_type, phase, statements
+                        ),
+                    );
+                }
+
+                let mut context = match self.set_up_env(harness, strict) {
+                    Ok(context) => context,
+                    Err(e) => return (false, e),
+                };
+                match context.eval(&self.content.as_ref()) {
+                    Ok(res) => {
+                        return (
+                            false,
+                            format!(
+                                "expected a {} in the {} phase, but the test completed with {}",
+                                error_type,
+                                phase,
+                                res.display()
+                            ),
+                        )
+                    }
+                    Err(e) => (
+                        Phase::Runtime,
+                        e.name(&mut context),
+                        format!("Uncaught {}", e),
+                    ),
+                }
+            }
+        };
+
+        let phase_matches = match phase {
+            Phase::Parse | Phase::Early => matches!(actual_phase, Phase::Parse),
+            Phase::Runtime => matches!(actual_phase, Phase::Runtime),
+            Phase::Resolution => false,
+        };
+        if phase_matches && actual_type.as_deref() == Some(error_type) {
+            (true, text)
+        } else {
+            (
+                false,
+                format!(
+                    "expected a {} in the {} phase, got {} in the {} phase:\n{}",
+                    error_type,
+                    phase,
+                    actual_type.as_deref().unwrap_or("a value without a name"),
+                    actual_phase,
+                    text
+                ),
+            )
         }
     }
 
     /// Sets the environment up to run the test.
     fn set_up_env(&self, harness: &Harness, strict: bool) -> Result<Context, String> {
         // Create new Realm
-        // TODO: in parallel.
         let mut context = Context::new();
+        PRINTED.with(|printed| printed.borrow_mut().clear());
 
         // Register the print() function.
         context
@@ -262,22 +412,27 @@ impl Test {
                     e.display()
                 )
             })?;
-        // TODO: add the $262 object.
+        host::register_host_object(&mut context);
 
         if strict {
             context
                 .eval(r#""use strict";"#)
-                .map_err(|e| format!("could not set strict mode:\n{}", e.display()))?;
+                .map_err(|e| format!("could not set strict mode:\n{}", e))?;
         }
 
         context
             .eval(&harness.assert.as_ref())
-            .map_err(|e| format!("could not run assert.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run assert.js:\n{}", e))?;
         context
             .eval(&harness.sta.as_ref())
-            .map_err(|e| format!("could not run sta.js:\n{}", e.display()))?;
+            .map_err(|e| format!("could not run sta.js:\n{}", e))?;
 
-        for include in self.includes.iter() {
+        // Asynchronous tests report their outcome by printing it when calling `$DONE()`.
+        let mut includes: Vec<&str> = self.includes.iter().map(AsRef::as_ref).collect();
+        if self.flags.contains(TestFlags::ASYNC) && !includes.contains(&ASYNC_INCLUDE) {
+            includes.insert(0, ASYNC_INCLUDE);
+        }
+        for include in includes {
             context
                 .eval(
                     &harness
@@ -289,8 +444,7 @@ impl Test {
                 .map_err(|e| {
                     format!(
                         "could not run the {} include file:\nUncaught {}",
-                        include,
-                        e.display()
+                        include, e
                     )
                 })?;
         }
@@ -299,7 +453,86 @@ impl Test {
     }
 }
 
+/// Include defining the `$DONE()` function of the asynchronous tests.
+const ASYNC_INCLUDE: &str = "doneprintHandle.js";
+
+/// Message printed by `$DONE()` when an asynchronous test passes.
+const ASYNC_COMPLETE: &str = "Test262:AsyncTestComplete";
+
+/// Prefix of the message printed by `$DONE()` when an asynchronous test fails.
+const ASYNC_FAILURE: &str = "Test262:AsyncTestFailure:";
+
+/// Gets the outcome of an asynchronous test from the messages it printed.
+///
+/// A test that never called `$DONE()` fails, since nothing is left to run once the job queue is
+/// empty.
+fn async_outcome() -> (bool, String) {
+    PRINTED.with(|printed| {
+        let printed = printed.borrow();
+        printed
+            .iter()
+            .find_map(|message| {
+                if message == ASYNC_COMPLETE {
+                    Some((true, message.clone()))
+                } else if message.starts_with(ASYNC_FAILURE) {
+                    Some((false, message.clone()))
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| (false, String::from("$DONE() was not called")))
+    })
+}
+
+/// Maximum number of characters kept from the error of a test.
+const MAX_ERROR_LENGTH: usize = 1000;
+
+/// Truncates the error of a test to [`MAX_ERROR_LENGTH`] characters.
+fn truncate_error(text: &str) -> Box<str> {
+    match text.char_indices().nth(MAX_ERROR_LENGTH) {
+        Some((end, _)) => format!("{}…", &text[..end]).into_boxed_str(),
+        None => text.into(),
+    }
+}
+
+thread_local! {
+    /// The message of the last panic of the thread, with its location.
+    static LAST_PANIC: RefCell<Option<String>> = RefCell::new(None);
+
+    /// The messages printed by the test running on the thread.
+    static PRINTED: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// Sets a panic hook that keeps the message of the panics of the tests, before reporting them
+/// like the default hook.
+pub(crate) fn set_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(info.to_string()));
+        default_hook(info);
+    }));
+}
+
+/// Gets the message of a panic from its payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("panic with a non-string payload")
+    }
+}
+
 /// `print()` function required by the test262 suite.
-fn test262_print(_this: &Value, _: &[Value], _context: &mut Context) -> boa::Result<Value> {
-    todo!("print() function");
+///
+/// The messages are kept to get the outcome of the asynchronous tests.
+fn test262_print(_this: &Value, args: &[Value], context: &mut Context) -> boa::Result<Value> {
+    let message = args
+        .get(0)
+        .cloned()
+        .unwrap_or_default()
+        .to_string(context)?;
+    PRINTED.with(|printed| printed.borrow_mut().push(message.to_string()));
+    Ok(Value::undefined())
 }
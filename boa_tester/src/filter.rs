@@ -0,0 +1,276 @@
+//! Module to select the tests to run.
+
+use super::{results::FailedTests, Test, TestFlags, TestSuite};
+use fxhash::FxHashSet;
+
+/// Selection of the tests to run.
+///
+/// An empty filter selects every test.
+#[derive(Debug, Default)]
+pub(crate) struct Filter {
+    /// Glob patterns, one of which must match the path of a selected test.
+    globs: Box<[Box<str>]>,
+    /// Features, one of which must be used by a selected test.
+    features: FxHashSet<Box<str>>,
+    /// Features that must not be used by a selected test.
+    excluded_features: FxHashSet<Box<str>>,
+    /// The runs that did not pass in previous results, which are the only ones to run again.
+    failed: Option<FailedTests>,
+}
+
+impl Filter {
+    /// Creates a new filter.
+    pub(crate) fn new<G, F, E>(globs: G, features: F, excluded_features: E) -> Self
+    where
+        G: IntoIterator<Item = String>,
+        F: IntoIterator<Item = String>,
+        E: IntoIterator<Item = String>,
+    {
+        Self {
+            globs: globs.into_iter().map(String::into_boxed_str).collect(),
+            features: features.into_iter().map(String::into_boxed_str).collect(),
+            excluded_features: excluded_features
+                .into_iter()
+                .map(String::into_boxed_str)
+                .collect(),
+            failed: None,
+        }
+    }
+
+    /// Only selects the runs that did not pass in previous results.
+    pub(crate) fn rerun_failed(mut self, failed: FailedTests) -> Self {
+        self.failed = Some(failed);
+        self
+    }
+
+    /// The runs that did not pass in previous results, if only those are selected.
+    pub(crate) fn failed(&self) -> Option<&FailedTests> {
+        self.failed.as_ref()
+    }
+
+    /// Checks if the test at the given path is selected.
+    ///
+    /// When rerunning the failed tests, the strict or non-strict run of the test is removed if it
+    /// passed.
+    fn select(&self, path: &str, test: &mut Test) -> bool {
+        if !self.selects(path, test) {
+            return false;
+        }
+
+        if let Some(failed) = &self.failed {
+            let strict = failed.contains(path, true);
+            let non_strict = failed.contains(path, false);
+            if !strict {
+                test.flags.remove(TestFlags::STRICT);
+            }
+            if !non_strict {
+                test.flags.remove(TestFlags::NO_STRICT | TestFlags::RAW);
+            }
+            return strict || non_strict;
+        }
+        true
+    }
+
+    /// Checks if the test at the given path is selected by the globs and features.
+    fn selects(&self, path: &str, test: &Test) -> bool {
+        (self.globs.is_empty() || self.globs.iter().any(|glob| glob_matches(glob, path)))
+            && (self.features.is_empty()
+                || test
+                    .features
+                    .iter()
+                    .any(|feature| self.features.contains(feature)))
+            && !test
+                .features
+                .iter()
+                .any(|feature| self.excluded_features.contains(feature))
+    }
+}
+
+impl TestSuite {
+    /// Keeps only the tests selected by the filter, removing the suites left empty.
+    ///
+    /// The paths of the tests are relative to this suite.
+    pub(crate) fn filter(self, filter: &Filter) -> Self {
+        self.filter_at(filter, "")
+    }
+
+    /// Filters the suite, whose path is `path`.
+    fn filter_at(self, filter: &Filter, path: &str) -> Self {
+        let suites = self
+            .suites
+            .into_vec()
+            .into_iter()
+            .map(|suite| {
+                let path = format!("{}{}/", path, suite.name);
+                suite.filter_at(filter, &path)
+            })
+            .filter(|suite| !suite.suites.is_empty() || !suite.tests.is_empty())
+            .collect();
+
+        let tests = self
+            .tests
+            .into_vec()
+            .into_iter()
+            .filter_map(|mut test| {
+                let path = test_path(path, &test.name);
+                if filter.select(&path, &mut test) {
+                    Some(test)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            name: self.name,
+            suites,
+            tests,
+        }
+    }
+
+    /// Counts the tests of the suite and its sub-suites.
+    pub(crate) fn test_count(&self) -> usize {
+        self.tests.len() + self.suites.iter().map(TestSuite::test_count).sum::<usize>()
+    }
+}
+
+/// Path of a test, from the path of its suite and its name.
+pub(crate) fn test_path(suite_path: &str, name: &str) -> String {
+    // The tests ignored when reading the suite are named after their file.
+    if name.ends_with(".js") {
+        format!("{}{}", suite_path, name)
+    } else {
+        format!("{}{}.js", suite_path, name)
+    }
+}
+
+/// Checks if the path matches the glob pattern.
+///
+/// The path and the pattern are made of segments separated by `/`. In a pattern, `**` matches
+/// any number of segments, `*` matches any number of characters in a segment and `?` matches one
+/// character in a segment.
+fn glob_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<_> = pattern.split('/').collect();
+    let path: Vec<_> = path.split('/').collect();
+    segments_match(&pattern, &path)
+}
+
+/// Checks if the segments of a path match the segments of a glob pattern.
+fn segments_match(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| segments_match(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path_rest)) => {
+                segment_matches(segment, name) && segments_match(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Checks if a segment of a path matches a segment of a glob pattern.
+fn segment_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<_> = pattern.chars().collect();
+    let name: Vec<_> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` in the pattern, and the position in the name it matches up to.
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                // Let the last `*` match one more character.
+                Some((star_p, star_n)) => {
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_matches;
+
+    #[test]
+    fn literal_paths() {
+        assert!(glob_matches(
+            "built-ins/Array/length.js",
+            "built-ins/Array/length.js"
+        ));
+        assert!(!glob_matches(
+            "built-ins/Array",
+            "built-ins/Array/length.js"
+        ));
+        assert!(!glob_matches(
+            "built-ins/Array/length.js",
+            "built-ins/Array"
+        ));
+        assert!(!glob_matches(
+            "built-ins/Array/length",
+            "built-ins/Array/length.js"
+        ));
+    }
+
+    #[test]
+    fn single_star() {
+        assert!(glob_matches(
+            "built-ins/Array/*.js",
+            "built-ins/Array/length.js"
+        ));
+        assert!(glob_matches(
+            "built-ins/*/length.js",
+            "built-ins/Array/length.js"
+        ));
+        assert!(glob_matches(
+            "built-ins/Array/len*",
+            "built-ins/Array/length.js"
+        ));
+        assert!(glob_matches(
+            "built-ins/Array/*length*",
+            "built-ins/Array/length.js"
+        ));
+        assert!(!glob_matches("built-ins/*.js", "built-ins/Array/length.js"));
+        assert!(!glob_matches(
+            "built-ins/Array/*.json",
+            "built-ins/Array/length.js"
+        ));
+    }
+
+    #[test]
+    fn question_mark() {
+        assert!(glob_matches(
+            "built-ins/Array/S15.4_A1.?_T1.js",
+            "built-ins/Array/S15.4_A1.1_T1.js"
+        ));
+        assert!(!glob_matches("built-ins/Array/?", "built-ins/Array/ab"));
+        assert!(!glob_matches("built-ins?Array", "built-ins/Array"));
+    }
+
+    #[test]
+    fn double_star() {
+        let path = "built-ins/Array/prototype/map/15.4.4.19-1-1.js";
+        assert!(glob_matches("built-ins/Array/prototype/map/**", path));
+        assert!(glob_matches("**/map/**", path));
+        assert!(glob_matches("**/*.js", path));
+        assert!(glob_matches("**", path));
+        assert!(glob_matches("built-ins/**/15.4.4.19-1-1.js", path));
+        assert!(glob_matches("built-ins/Array/**/prototype/map/*", path));
+        assert!(!glob_matches("built-ins/Array/prototype/filter/**", path));
+        assert!(!glob_matches("**/filter/*.js", path));
+    }
+}
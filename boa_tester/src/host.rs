@@ -0,0 +1,63 @@
+//! The `$262` host-defined object, used by the test262 tests to reach the host.
+//!
+//! The properties of `$262` map to the engine like this:
+//!
+//! | Property            | Support                                                         |
+//! | ------------------- | --------------------------------------------------------------- |
+//! | `global`            | The global object of the context.                               |
+//! | `evalScript`        | Evaluates the source as a script, in the same context.          |
+//! | `gc`                | Forces a garbage collection.                                    |
+//! | `createRealm`       | Throws a `TypeError`, a context only has one realm.             |
+//! | `detachArrayBuffer` | Throws a `TypeError`, `ArrayBuffer` is not implemented.         |
+//! | `agent`             | Not installed, `SharedArrayBuffer` and `Atomics` are missing.   |
+//! | `IsHTMLDDA`         | Not installed, it is only defined by hosts that have `[[IsHTMLDDA]]` objects. |
+//!
+//! The tests needing the missing parts fail with the `TypeError` instead of panicking.
+
+use boa::{object::ObjectInitializer, property::Attribute, Context, Value};
+
+/// Adds the `$262` object to the global object of the context.
+pub(crate) fn register_host_object(context: &mut Context) {
+    let global = context.global_object().clone();
+    let host = ObjectInitializer::new(context)
+        .property("global", global, Attribute::all())
+        .function(eval_script, "evalScript", 1)
+        .function(gc, "gc", 0)
+        .function(create_realm, "createRealm", 0)
+        .function(detach_array_buffer, "detachArrayBuffer", 1)
+        .build();
+    context.register_global_property(
+        "$262",
+        host,
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+    );
+}
+
+/// `$262.evalScript(source)`, which evaluates `source` as a script and returns its completion
+/// value.
+fn eval_script(_this: &Value, args: &[Value], context: &mut Context) -> boa::Result<Value> {
+    let source = args
+        .get(0)
+        .cloned()
+        .unwrap_or_default()
+        .to_string(context)?;
+    context
+        .eval(source.as_str())
+        .map_err(|error| error.into_value(context))
+}
+
+/// `$262.gc()`, which collects the garbage.
+fn gc(_this: &Value, _: &[Value], _context: &mut Context) -> boa::Result<Value> {
+    boa::gc::force_collect();
+    Ok(Value::undefined())
+}
+
+/// `$262.createRealm()`, which would return the `$262` object of a new realm.
+fn create_realm(_this: &Value, _: &[Value], context: &mut Context) -> boa::Result<Value> {
+    context.throw_type_error("$262.createRealm() is not supported: a context only has one realm")
+}
+
+/// `$262.detachArrayBuffer(buffer)`, which would detach `buffer`.
+fn detach_array_buffer(_this: &Value, _: &[Value], context: &mut Context) -> boa::Result<Value> {
+    context.throw_type_error("$262.detachArrayBuffer() is not supported: there is no ArrayBuffer")
+}
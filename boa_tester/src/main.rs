@@ -37,12 +37,17 @@
 )]
 
 mod exec;
+mod filter;
+mod host;
 mod read;
+mod report;
 mod results;
 
 use self::{
+    filter::Filter,
     read::{read_harness, read_suite, read_test, MetaData, Negative, TestFlag},
-    results::{compare_results, write_json},
+    report::{write_report, ReportFormat},
+    results::{compare_results, write_json, FailedTests},
 };
 use bitflags::bitflags;
 use colored::Colorize;
@@ -50,8 +55,11 @@ use fxhash::{FxHashMap, FxHashSet};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::{
-    fs,
+    collections::BTreeMap,
+    fmt, fs,
     path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
 };
 use structopt::StructOpt;
 
@@ -180,6 +188,38 @@ enum Cli {
         /// Optional output folder for the full results information.
         #[structopt(short, long, parse(from_os_str))]
         output: Option<PathBuf>,
+
+        /// Number of tests to run in parallel, defaults to the number of CPU cores.
+        #[structopt(short, long)]
+        jobs: Option<usize>,
+
+        /// Number of seconds after which a test is reported as timed out.
+        #[structopt(long, default_value = "30")]
+        timeout: u64,
+
+        /// Only run the tests whose path, relative to the suite, matches one of these globs.
+        #[structopt(long = "filter", number_of_values = 1)]
+        filters: Vec<String>,
+
+        /// Only run the tests that use one of these features.
+        #[structopt(long = "feature", number_of_values = 1)]
+        features: Vec<String>,
+
+        /// Don't run the tests that use one of these features.
+        #[structopt(long = "exclude-feature", number_of_values = 1)]
+        excluded_features: Vec<String>,
+
+        /// Format of a per-test report for continuous integration, `junit` or `json-detailed`.
+        #[structopt(long, requires = "output-file")]
+        format: Option<ReportFormat>,
+
+        /// File to write the per-test report to.
+        #[structopt(long, parse(from_os_str), requires = "format")]
+        output_file: Option<PathBuf>,
+
+        /// Only run the tests that did not pass in these previous results, like a `latest.json`.
+        #[structopt(long, parse(from_os_str))]
+        rerun_failed: Option<PathBuf>,
     },
     Compare {
         /// Base results of the suite.
@@ -204,12 +244,34 @@ fn main() {
             test262_path,
             suite,
             output,
+            jobs,
+            timeout,
+            filters,
+            features,
+            excluded_features,
+            format,
+            output_file,
+            rerun_failed,
         } => {
+            let mut filter = Filter::new(filters, features, excluded_features);
+            if let Some(path) = rerun_failed {
+                let failed = FailedTests::read(&path).expect("could not read the previous results");
+                filter = filter.rerun_failed(failed);
+            }
+            exec::set_panic_hook();
+            rayon::ThreadPoolBuilder::new()
+                // Zero threads lets rayon use one thread per CPU core.
+                .num_threads(jobs.unwrap_or(0))
+                .build_global()
+                .expect("could not create the thread pool");
             run_test_suite(
                 verbose,
                 test262_path.as_path(),
                 suite.as_path(),
                 output.as_deref(),
+                Duration::from_secs(timeout),
+                &filter,
+                format.zip(output_file.as_deref()),
             );
         }
         Cli::Compare {
@@ -221,7 +283,15 @@ fn main() {
 }
 
 /// Runs the full test suite.
-fn run_test_suite(verbose: u8, test262_path: &Path, suite: &Path, output: Option<&Path>) {
+fn run_test_suite(
+    verbose: u8,
+    test262_path: &Path,
+    suite: &Path,
+    output: Option<&Path>,
+    timeout: Duration,
+    filter: &Filter,
+    report: Option<(ReportFormat, &Path)>,
+) {
     if let Some(path) = output {
         if path.exists() {
             if !path.is_dir() {
@@ -236,7 +306,9 @@ fn run_test_suite(verbose: u8, test262_path: &Path, suite: &Path, output: Option
     if verbose != 0 {
         println!("Loading the test suite...");
     }
-    let harness = read_harness(test262_path).expect("could not read initialization bindings");
+    // The harness is shared with the threads running the tests.
+    let harness =
+        Arc::new(read_harness(test262_path).expect("could not read initialization bindings"));
 
     if suite.to_string_lossy().ends_with(".js") {
         let test = read_test(&test262_path.join(suite)).expect("could not get the test to run");
@@ -244,35 +316,71 @@ fn run_test_suite(verbose: u8, test262_path: &Path, suite: &Path, output: Option
         if verbose != 0 {
             println!("Test loaded, starting...");
         }
-        test.run(&harness, verbose);
+        test.run(&harness, verbose, timeout);
 
         println!();
     } else {
         let suite =
             read_suite(&test262_path.join(suite)).expect("could not get the list of tests to run");
+        let available = suite.test_count();
+        let suite = suite.filter(filter);
+        let selected = suite.test_count();
 
         if verbose != 0 {
             println!("Test suite loaded, starting tests...");
         }
-        let results = suite.run(&harness, verbose);
+        let results = suite.run(&harness, verbose, timeout);
 
         println!();
         println!("Results:");
+        println!("Selected test files: {} out of {}", selected, available);
         println!("Total tests: {}", results.total);
         println!("Passed tests: {}", results.passed.to_string().green());
         println!("Ignored tests: {}", results.ignored.to_string().yellow());
         println!(
-            "Failed tests: {} (panics: {})",
+            "Failed tests: {} (panics: {}, timeouts: {})",
             (results.total - results.passed - results.ignored)
                 .to_string()
                 .red(),
-            results.panic.to_string().red()
+            results.panic.to_string().red(),
+            results.timeout.to_string().red()
         );
         println!(
             "Conformance: {:.2}%",
             (results.passed as f64 / results.total as f64) * 100.0
         );
 
+        if verbose != 0 && !results.features.is_empty() {
+            println!();
+            println!("Conformance by feature:");
+            let mut features: Vec<_> = results.features.iter().collect();
+            // The features with the most failing tests come first.
+            features.sort_by(|(a_name, a), (b_name, b)| {
+                b.failed().cmp(&a.failed()).then_with(|| a_name.cmp(b_name))
+            });
+            for (name, feature) in features {
+                println!(
+                    "  {}: {}/{} ({} failed)",
+                    name,
+                    feature.passed,
+                    feature.total,
+                    feature.failed()
+                );
+            }
+        }
+
+        if let Some(failed) = filter.failed() {
+            println!();
+            failed.print_changes(&results, verbose);
+        }
+
+        if let Some((format, path)) = report {
+            if verbose != 0 {
+                println!("Writing the test report to {}...", path.display());
+            }
+            write_report(&results, format, path).expect("could not write the test report");
+        }
+
         write_json(results, output, verbose)
             .expect("could not write the results to the output JSON file");
     }
@@ -307,12 +415,62 @@ struct SuiteResult {
     ignored: usize,
     #[serde(rename = "p")]
     panic: usize,
+    #[serde(rename = "to", default)]
+    timeout: usize,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     #[serde(rename = "s")]
     suites: Vec<SuiteResult>,
     #[serde(rename = "t")]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     tests: Vec<TestResult>,
+    /// Outcomes of the tests of the suite and its sub-suites, by test262 feature.
+    ///
+    /// They are only written once for the whole run, in the results information.
+    #[serde(skip)]
+    features: BTreeMap<Box<str>, FeatureResult>,
+}
+
+/// Outcome of the tests using a test262 feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct FeatureResult {
+    #[serde(rename = "c")]
+    total: usize,
+    #[serde(rename = "o")]
+    passed: usize,
+    #[serde(rename = "i")]
+    ignored: usize,
+    #[serde(rename = "p")]
+    panic: usize,
+    #[serde(rename = "to", default)]
+    timeout: usize,
+}
+
+impl FeatureResult {
+    /// Counts the outcome of a test run.
+    fn add_outcome(&mut self, outcome: TestOutcomeResult) {
+        self.total += 1;
+        match outcome {
+            TestOutcomeResult::Passed => self.passed += 1,
+            TestOutcomeResult::Ignored => self.ignored += 1,
+            TestOutcomeResult::Panic => self.panic += 1,
+            TestOutcomeResult::Timeout => self.timeout += 1,
+            TestOutcomeResult::Failed => {}
+        }
+    }
+
+    /// Adds the counts of another result.
+    fn merge(&mut self, other: &Self) {
+        self.total += other.total;
+        self.passed += other.passed;
+        self.ignored += other.ignored;
+        self.panic += other.panic;
+        self.timeout += other.timeout;
+    }
+
+    /// Number of test runs that did not pass and were not ignored.
+    fn failed(&self) -> usize {
+        self.total - self.passed - self.ignored
+    }
 }
 
 /// Outcome of a test.
@@ -324,10 +482,22 @@ struct TestResult {
     strict: bool,
     #[serde(skip)]
     result_text: Box<str>,
+    /// The thrown error or the panic message, truncated, for the runs that did not pass.
+    ///
+    /// It is only written in the detailed reports, to keep the results files small.
+    #[serde(skip)]
+    error: Option<Box<str>>,
     #[serde(rename = "r")]
     result: TestOutcomeResult,
 }
 
+impl TestResult {
+    /// The error of the run, or an empty string if it passed.
+    fn error(&self) -> &str {
+        self.error.as_deref().unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 enum TestOutcomeResult {
     #[serde(rename = "O")]
@@ -338,6 +508,8 @@ enum TestOutcomeResult {
     Failed,
     #[serde(rename = "P")]
     Panic,
+    #[serde(rename = "T")]
+    Timeout,
 }
 
 /// Represents a test.
@@ -478,6 +650,17 @@ enum Phase {
     Runtime,
 }
 
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Parse => "parse",
+            Self::Early => "early",
+            Self::Resolution => "resolution",
+            Self::Runtime => "runtime",
+        })
+    }
+}
+
 /// Locale information structure.
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(transparent)]
@@ -0,0 +1,371 @@
+//! Module to write the per-test reports used by continuous integration services.
+
+use super::{SuiteResult, TestOutcomeResult, TestResult};
+use serde::Serialize;
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, BufWriter, Write},
+    path::Path,
+    str::FromStr,
+};
+
+/// Format of a per-test report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReportFormat {
+    /// JUnit XML, with a test case for each run of a test.
+    Junit,
+    /// JSON, with the outcome and error of each run of a test.
+    JsonDetailed,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "junit" => Ok(Self::Junit),
+            "json-detailed" => Ok(Self::JsonDetailed),
+            _ => Err(format!(
+                "unknown report format `{}`, expected `junit` or `json-detailed`",
+                s
+            )),
+        }
+    }
+}
+
+/// Writes the report of the results of a test suite run to the given file.
+pub(crate) fn write_report(
+    results: &SuiteResult,
+    format: ReportFormat,
+    path: &Path,
+) -> io::Result<()> {
+    let mut output = BufWriter::new(fs::File::create(path)?);
+    match format {
+        ReportFormat::Junit => output.write_all(junit_report(results).as_bytes())?,
+        ReportFormat::JsonDetailed => write_detailed_report(results, &mut output)?,
+    }
+    output.flush()
+}
+
+/// Visits the suites of the results that directly contain tests, with their path.
+fn for_each_suite<'a, F>(results: &'a SuiteResult, path: &str, f: &mut F)
+where
+    F: FnMut(&str, &'a [TestResult]),
+{
+    let path = if path.is_empty() {
+        results.name.to_string()
+    } else {
+        format!("{}/{}", path, results.name)
+    };
+    if !results.tests.is_empty() {
+        f(&path, &results.tests);
+    }
+    for suite in &results.suites {
+        for_each_suite(suite, &path, f);
+    }
+}
+
+/// Name of a test case, which tells apart the strict mode run of a test.
+fn test_case_name(test: &TestResult) -> String {
+    if test.strict {
+        format!("{} (strict mode)", test.name)
+    } else {
+        test.name.to_string()
+    }
+}
+
+/// Generates the JUnit XML report.
+///
+/// Failed tests are reported as failures, while panics and timeouts are reported as errors.
+fn junit_report(results: &SuiteResult) -> String {
+    let mut suites = String::new();
+    let (mut total, mut failures, mut errors, mut skipped) = (0, 0, 0, 0);
+    for_each_suite(results, "", &mut |path, tests| {
+        let mut cases = String::new();
+        let (mut suite_failures, mut suite_errors, mut suite_skipped) = (0, 0, 0);
+        for test in tests {
+            write!(
+                cases,
+                r#"    <testcase name="{}" classname="{}""#,
+                xml_escape(&test_case_name(test)),
+                xml_escape(path)
+            )
+            .expect("writing to a string cannot fail");
+            match test.result {
+                TestOutcomeResult::Passed => cases.push_str("/>\n"),
+                TestOutcomeResult::Ignored => {
+                    suite_skipped += 1;
+                    cases.push_str(">\n      <skipped/>\n    </testcase>\n");
+                }
+                TestOutcomeResult::Failed => {
+                    suite_failures += 1;
+                    write_junit_problem(&mut cases, "failure", "failed", test.error());
+                }
+                TestOutcomeResult::Panic => {
+                    suite_errors += 1;
+                    write_junit_problem(&mut cases, "error", "panic", test.error());
+                }
+                TestOutcomeResult::Timeout => {
+                    suite_errors += 1;
+                    write_junit_problem(&mut cases, "error", "timeout", test.error());
+                }
+            }
+        }
+
+        writeln!(
+            suites,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="{}" skipped="{}">"#,
+            xml_escape(path),
+            tests.len(),
+            suite_failures,
+            suite_errors,
+            suite_skipped
+        )
+        .expect("writing to a string cannot fail");
+        suites.push_str(&cases);
+        suites.push_str("  </testsuite>\n");
+
+        total += tests.len();
+        failures += suite_failures;
+        errors += suite_errors;
+        skipped += suite_skipped;
+    });
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">\n\
+         {}</testsuites>\n",
+        xml_escape(&results.name),
+        total,
+        failures,
+        errors,
+        skipped,
+        suites
+    )
+}
+
+/// Writes the element reporting a failure or an error, closing the test case.
+fn write_junit_problem(cases: &mut String, element: &str, kind: &str, text: &str) {
+    // The message attribute holds the first line, the element holds the whole text.
+    let message = text.lines().next().unwrap_or(kind);
+    writeln!(
+        cases,
+        ">\n      <{element} type=\"{}\" message=\"{}\">{}</{element}>\n    </testcase>",
+        kind,
+        xml_escape(message),
+        xml_escape(text),
+        element = element
+    )
+    .expect("writing to a string cannot fail");
+}
+
+/// Escapes text for an XML attribute or element.
+///
+/// The characters that XML 1.0 does not allow are replaced by U+FFFD.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            '\u{0}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}' => escaped.push('\u{FFFD}'),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Outcome of a run of a test, in the detailed JSON report.
+#[derive(Debug, Serialize)]
+struct DetailedTestResult<'a> {
+    suite: &'a str,
+    name: &'a str,
+    strict: bool,
+    result: &'static str,
+    error: Option<&'a str>,
+}
+
+/// Writes the detailed JSON report.
+fn write_detailed_report<W: Write>(results: &SuiteResult, output: W) -> serde_json::Result<()> {
+    let mut suites = Vec::new();
+    for_each_suite(results, "", &mut |path, tests| {
+        suites.push((path.to_owned(), tests))
+    });
+
+    let report: Vec<_> = suites
+        .iter()
+        .flat_map(|(path, tests)| {
+            tests.iter().map(move |test| DetailedTestResult {
+                suite: path,
+                name: &test.name,
+                strict: test.strict,
+                result: match test.result {
+                    TestOutcomeResult::Passed => "passed",
+                    TestOutcomeResult::Ignored => "ignored",
+                    TestOutcomeResult::Failed => "failed",
+                    TestOutcomeResult::Panic => "panic",
+                    TestOutcomeResult::Timeout => "timeout",
+                },
+                error: test.error.as_deref(),
+            })
+        })
+        .collect();
+    serde_json::to_writer(output, &report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{junit_report, write_detailed_report};
+    use crate::{SuiteResult, TestOutcomeResult, TestResult};
+
+    fn test_result(name: &str, strict: bool, result: TestOutcomeResult, text: &str) -> TestResult {
+        TestResult {
+            name: name.into(),
+            strict,
+            result_text: text.into(),
+            error: match result {
+                TestOutcomeResult::Passed | TestOutcomeResult::Ignored => None,
+                _ => Some(text.into()),
+            },
+            result,
+        }
+    }
+
+    fn suite_result(name: &str, suites: Vec<SuiteResult>, tests: Vec<TestResult>) -> SuiteResult {
+        SuiteResult {
+            name: name.into(),
+            total: 0,
+            passed: 0,
+            ignored: 0,
+            panic: 0,
+            timeout: 0,
+            suites,
+            tests,
+            features: Default::default(),
+        }
+    }
+
+    fn sample_results() -> SuiteResult {
+        let map = suite_result(
+            "map",
+            Vec::new(),
+            vec![
+                test_result("passes", false, TestOutcomeResult::Passed, "undefined"),
+                test_result("passes", true, TestOutcomeResult::Passed, "undefined"),
+                test_result(
+                    "fails",
+                    false,
+                    TestOutcomeResult::Failed,
+                    "Uncaught \"Test262Error\": \"<a> & 'b'\"\nsecond line",
+                ),
+                test_result(
+                    "panics",
+                    false,
+                    TestOutcomeResult::Panic,
+                    "not yet implemented",
+                ),
+                test_result("hangs", true, TestOutcomeResult::Timeout, "did not finish"),
+                test_result("ignored", false, TestOutcomeResult::Ignored, ""),
+            ],
+        );
+        let array = suite_result(
+            "Array",
+            vec![map],
+            vec![test_result(
+                "length",
+                false,
+                TestOutcomeResult::Passed,
+                "\u{1}",
+            )],
+        );
+        suite_result("test", vec![array], Vec::new())
+    }
+
+    #[test]
+    fn junit_report_is_well_formed() {
+        let xml = junit_report(&sample_results());
+        let document = roxmltree::Document::parse(&xml).expect("the report is not valid XML");
+
+        let root = document.root_element();
+        assert_eq!(root.tag_name().name(), "testsuites");
+        assert_eq!(root.attribute("tests"), Some("7"));
+        assert_eq!(root.attribute("failures"), Some("1"));
+        assert_eq!(root.attribute("errors"), Some("2"));
+        assert_eq!(root.attribute("skipped"), Some("1"));
+
+        let suites: Vec<_> = root.children().filter(|node| node.is_element()).collect();
+        assert_eq!(suites.len(), 2);
+        for suite in &suites {
+            assert_eq!(suite.tag_name().name(), "testsuite");
+            let cases: Vec<_> = suite.children().filter(|node| node.is_element()).collect();
+            assert_eq!(
+                suite.attribute("tests"),
+                Some(cases.len().to_string().as_str())
+            );
+            for case in &cases {
+                assert_eq!(case.tag_name().name(), "testcase");
+                assert!(case.attribute("name").is_some());
+                assert_eq!(case.attribute("classname"), suite.attribute("name"));
+                assert!(case.children().filter(|node| node.is_element()).count() <= 1);
+            }
+        }
+        assert_eq!(suites[0].attribute("name"), Some("test/Array"));
+        assert_eq!(suites[1].attribute("name"), Some("test/Array/map"));
+
+        let failure = document
+            .descendants()
+            .find(|node| node.has_tag_name("failure"))
+            .expect("no failure element");
+        assert_eq!(
+            failure
+                .parent_element()
+                .and_then(|case| case.attribute("name")),
+            Some("fails")
+        );
+        assert_eq!(
+            failure.attribute("message"),
+            Some("Uncaught \"Test262Error\": \"<a> & 'b'\"")
+        );
+        assert_eq!(
+            failure.text(),
+            Some("Uncaught \"Test262Error\": \"<a> & 'b'\"\nsecond line")
+        );
+
+        let errors: Vec<_> = document
+            .descendants()
+            .filter(|node| node.has_tag_name("error"))
+            .map(|node| node.attribute("type"))
+            .collect();
+        assert_eq!(errors, vec![Some("panic"), Some("timeout")]);
+
+        let strict_case = document
+            .descendants()
+            .find(|node| node.attribute("name") == Some("passes (strict mode)"));
+        assert!(strict_case.is_some());
+    }
+
+    #[test]
+    fn detailed_report_lists_every_test() {
+        let mut output = Vec::new();
+        write_detailed_report(&sample_results(), &mut output).expect("could not write the report");
+        let report: serde_json::Value =
+            serde_json::from_slice(&output).expect("the report is not valid JSON");
+
+        let tests = report.as_array().expect("the report is not an array");
+        assert_eq!(tests.len(), 7);
+        assert_eq!(tests[0]["suite"], "test/Array");
+        assert_eq!(tests[0]["name"], "length");
+        assert_eq!(tests[3]["suite"], "test/Array/map");
+        assert_eq!(tests[3]["result"], "failed");
+        assert_eq!(tests[4]["result"], "panic");
+        assert_eq!(tests[4]["error"], "not yet implemented");
+        assert_eq!(tests[0]["error"], serde_json::Value::Null);
+        assert_eq!(tests[5]["result"], "timeout");
+        assert_eq!(tests[5]["strict"], true);
+    }
+}
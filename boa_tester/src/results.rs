@@ -1,8 +1,10 @@
-use super::SuiteResult;
+use super::{filter::test_path, FeatureResult, SuiteResult, TestOutcomeResult, TestResult};
+use fxhash::{FxHashMap, FxHashSet};
 use git2::Repository;
 use hex::ToHex;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::{BTreeMap, BTreeSet},
     env, fs,
     io::{self, BufReader, BufWriter},
     path::Path,
@@ -17,6 +19,8 @@ struct ResultInfo {
     test262_commit: Box<str>,
     #[serde(rename = "r")]
     results: SuiteResult,
+    #[serde(rename = "f", default, skip_serializing_if = "BTreeMap::is_empty")]
+    features: BTreeMap<Box<str>, FeatureResult>,
 }
 
 /// Structure to store full result information.
@@ -34,6 +38,8 @@ struct ReducedResultInfo {
     ignored: usize,
     #[serde(rename = "p")]
     panic: usize,
+    #[serde(rename = "to", default)]
+    timeout: usize,
 }
 
 impl From<ResultInfo> for ReducedResultInfo {
@@ -46,6 +52,7 @@ impl From<ResultInfo> for ReducedResultInfo {
             passed: info.results.passed,
             ignored: info.results.ignored,
             panic: info.results.panic,
+            timeout: info.results.timeout,
         }
     }
 }
@@ -92,6 +99,7 @@ pub(crate) fn write_json(
         let new_results = ResultInfo {
             commit: env::var("GITHUB_SHA").unwrap_or_default().into_boxed_str(),
             test262_commit: get_test262_commit(),
+            features: results.features.clone(),
             results,
         };
 
@@ -166,6 +174,119 @@ fn update_gh_pages_repo(path: &Path, verbose: u8) {
     }
 }
 
+/// Outcomes of the runs of tests that did not pass in previous results.
+///
+/// The runs are identified by the path of the test, relative to the suite, and by whether they
+/// are in strict mode.
+#[derive(Debug, Default)]
+pub(crate) struct FailedTests {
+    outcomes: FxHashMap<(Box<str>, bool), TestOutcomeResult>,
+}
+
+impl FailedTests {
+    /// Reads the runs that did not pass from a results file.
+    pub(crate) fn read(path: &Path) -> io::Result<Self> {
+        let info: ResultInfo = serde_json::from_reader(BufReader::new(fs::File::open(path)?))?;
+
+        let mut outcomes = FxHashMap::default();
+        for_each_test(&info.results, "", &mut |path, test| {
+            if matches!(
+                test.result,
+                TestOutcomeResult::Failed | TestOutcomeResult::Panic | TestOutcomeResult::Timeout
+            ) {
+                outcomes.insert((path.into(), test.strict), test.result);
+            }
+        });
+        Ok(Self { outcomes })
+    }
+
+    /// Checks if the run of the test at the given path did not pass.
+    pub(crate) fn contains(&self, path: &str, strict: bool) -> bool {
+        self.outcomes.contains_key(&(path.into(), strict))
+    }
+
+    /// Prints the changes in the outcomes of the runs that did not pass, once run again.
+    ///
+    /// The runs of tests that are not in the results anymore are reported as skipped.
+    pub(crate) fn print_changes(&self, results: &SuiteResult, verbose: u8) {
+        let mut before = SuiteResult {
+            name: results.name.clone(),
+            total: 0,
+            passed: 0,
+            ignored: 0,
+            panic: 0,
+            timeout: 0,
+            suites: Vec::new(),
+            tests: Vec::new(),
+            features: Default::default(),
+        };
+        for_each_test(results, "", &mut |path, test| {
+            if let Some(outcome) = self.outcomes.get(&(path.into(), test.strict)) {
+                before.total += 1;
+                match outcome {
+                    TestOutcomeResult::Panic => before.panic += 1,
+                    TestOutcomeResult::Timeout => before.timeout += 1,
+                    _ => {}
+                }
+            }
+        });
+
+        let skipped = self.outcomes.len() - before.total;
+        if skipped != 0 {
+            println!(
+                "Skipped {} previously failed test runs, which are not in the selected tests",
+                skipped
+            );
+            if verbose != 0 {
+                let mut seen = FxHashSet::default();
+                for_each_test(results, "", &mut |path, test| {
+                    seen.insert((Box::<str>::from(path), test.strict));
+                });
+                let mut missing: Vec<_> = self
+                    .outcomes
+                    .keys()
+                    .filter(|key| !seen.contains(*key))
+                    .collect();
+                missing.sort();
+                for (path, strict) in missing {
+                    println!("  {}{}", path, if *strict { " (strict mode)" } else { "" });
+                }
+            }
+        }
+
+        println!("Changes in the previously failed test runs:");
+        print_comparison(&before, results, false, ("before", "after"));
+
+        if verbose != 0 {
+            println!();
+            println!("Test runs that still don't pass:");
+            for_each_test(results, "", &mut |path, test| {
+                if let Some(error) = &test.error {
+                    println!(
+                        "  {}{}: {}",
+                        path,
+                        if test.strict { " (strict mode)" } else { "" },
+                        error
+                    );
+                }
+            });
+        }
+    }
+}
+
+/// Visits the tests of the results, with their path relative to the suite.
+fn for_each_test<F>(results: &SuiteResult, path: &str, f: &mut F)
+where
+    F: FnMut(&str, &TestResult),
+{
+    for test in &results.tests {
+        f(&test_path(path, &test.name), test);
+    }
+    for suite in &results.suites {
+        for_each_test(suite, &format!("{}{}/", path, suite.name), f);
+    }
+}
+
 /// Compares the results of two test suite runs.
 pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
     let base_results: ResultInfo = serde_json::from_reader(BufReader::new(
@@ -178,26 +299,100 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
     ))
     .expect("could not read the new results");
 
-    let base_total = base_results.results.total as isize;
-    let new_total = new_results.results.total as isize;
+    if markdown {
+        println!("### Test262 conformance changes:");
+    } else {
+        println!("Test262 conformance changes:");
+    }
+    print_comparison(
+        &base_results.results,
+        &new_results.results,
+        markdown,
+        ("master", "PR"),
+    );
+
+    if markdown {
+        print_feature_changes(&base_results.features, &new_results.features);
+    }
+}
+
+/// Prints a markdown table of the features whose number of passed tests changed.
+///
+/// Nothing is printed if there are no such features.
+fn print_feature_changes(
+    base: &BTreeMap<Box<str>, FeatureResult>,
+    new: &BTreeMap<Box<str>, FeatureResult>,
+) {
+    let names: BTreeSet<_> = base.keys().chain(new.keys()).collect();
+    let changes: Vec<_> = names
+        .into_iter()
+        .filter_map(|name| {
+            let base = base.get(name).copied().unwrap_or_default();
+            let new = new.get(name).copied().unwrap_or_default();
+            if base.passed == new.passed {
+                None
+            } else {
+                Some((name, base, new))
+            }
+        })
+        .collect();
+    if changes.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("<details><summary><b>Feature changes</b></summary>");
+    println!();
+    println!("| Feature | master passed | PR passed | difference |");
+    println!("| :-----: | :-----------: | :-------: | :--------: |");
+    for (name, base, new) in changes {
+        let diff = new.passed as isize - base.passed as isize;
+        println!(
+            "| {} | {}/{} | {}/{} | **{}{}** |",
+            name,
+            base.passed,
+            base.total,
+            new.passed,
+            new.total,
+            if diff > 0 { "+" } else { "" },
+            diff
+        );
+    }
+    println!();
+    println!("</details>");
+}
+
+/// Prints a table comparing the results of two runs, with the given names.
+fn print_comparison(
+    base: &SuiteResult,
+    new: &SuiteResult,
+    markdown: bool,
+    (base_name, new_name): (&str, &str),
+) {
+    let base_total = base.total as isize;
+    let new_total = new.total as isize;
     let total_diff = new_total - base_total;
 
-    let base_passed = base_results.results.passed as isize;
-    let new_passed = new_results.results.passed as isize;
+    let base_passed = base.passed as isize;
+    let new_passed = new.passed as isize;
     let passed_diff = new_passed - base_passed;
 
-    let base_ignored = base_results.results.ignored as isize;
-    let new_ignored = new_results.results.ignored as isize;
+    let base_ignored = base.ignored as isize;
+    let new_ignored = new.ignored as isize;
     let ignored_diff = new_ignored - base_ignored;
 
     let base_failed = base_total - base_passed - base_ignored;
     let new_failed = new_total - new_passed - new_ignored;
     let failed_diff = new_failed - base_failed;
 
-    let base_panics = base_results.results.panic as isize;
-    let new_panics = new_results.results.panic as isize;
+    let base_panics = base.panic as isize;
+    let new_panics = new.panic as isize;
     let panic_diff = new_panics - base_panics;
 
+    let base_timeouts = base.timeout as isize;
+    let new_timeouts = new.timeout as isize;
+    let timeout_diff = new_timeouts - base_timeouts;
+
     let base_conformance = (base_passed as f64 / base_total as f64) * 100_f64;
     let new_conformance = (new_passed as f64 / new_total as f64) * 100_f64;
     let conformance_diff = new_conformance - base_conformance;
@@ -216,8 +411,10 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
             )
         }
 
-        println!("### Test262 conformance changes:");
-        println!("| Test result | master count | PR count | difference |");
+        println!(
+            "| Test result | {} count | {} count | difference |",
+            base_name, new_name
+        );
         println!("| :---------: | :----------: | :------: | :--------: |");
         println!(
             "| Total | {} | {} | {} |",
@@ -249,6 +446,12 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
             new_panics.to_formatted_string(&Locale::en),
             diff_format(panic_diff),
         );
+        println!(
+            "| Timeouts | {} | {} | {} |",
+            base_timeouts.to_formatted_string(&Locale::en),
+            new_timeouts.to_formatted_string(&Locale::en),
+            diff_format(timeout_diff),
+        );
         println!(
             "| Conformance | {:.2} | {:.2} | {} |",
             base_conformance,
@@ -270,8 +473,10 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
             ),
         );
     } else {
-        println!("Test262 conformance changes:");
-        println!("| Test result | master |    PR   | difference |");
+        println!(
+            "| Test result | {:^6} | {:^5} | difference |",
+            base_name, new_name
+        );
         println!(
             "|    Passed   | {:^6} | {:^5} | {:^10} |",
             base_passed,
@@ -296,5 +501,11 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) {
             new_panics,
             base_panics - new_panics
         );
+        println!(
+            "|   Timeouts  | {:^6} | {:^5} | {:^10} |",
+            base_timeouts,
+            new_timeouts,
+            base_timeouts - new_timeouts
+        );
     }
 }
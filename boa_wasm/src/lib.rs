@@ -1,10 +1,50 @@
-use boa::{exec::Executable, parse, Context};
+use boa::{
+    builtins::console::{ConsoleLogger, LogMessage},
+    exec::Executable,
+    parse, Context,
+};
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = Math, js_name = random)]
+    fn math_random() -> f64;
+
+    #[wasm_bindgen(js_namespace = Date, js_name = now)]
+    fn date_now() -> f64;
+
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    fn performance_now() -> f64;
+
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    fn console_log(msg: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn console_error(msg: &str);
+}
+
+/// Prints the output of the `console` object to the console of the browser.
+#[derive(Debug, Clone, Copy)]
+struct BrowserLogger;
+
+impl ConsoleLogger for BrowserLogger {
+    fn log(&mut self, msg: LogMessage) {
+        match msg {
+            LogMessage::Error(msg) => console_error(&msg),
+            msg => console_log(msg.message()),
+        }
+    }
+}
+
 #[wasm_bindgen]
 pub fn evaluate(src: &str) -> Result<String, JsValue> {
-    // Setup executor
+    // Setup executor, with the hooks of the browser
     let mut context = Context::new();
+    context.set_random_source(math_random);
+    context.set_clock(date_now);
+    context.set_console_logger(BrowserLogger);
+    context.set_console_clock(|| Duration::from_secs_f64(performance_now() / 1000.0));
 
     let expr = match parse(src, false) {
         Ok(res) => res,
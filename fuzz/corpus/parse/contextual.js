@@ -0,0 +1 @@
+async function f() { await g(); } let async = 1, of = 2; var let = 3; for (let x of [1, 2]) {} for (x in {}) {} a => b; (a, b) => { return; }; new new F()(); f()()[0].x`t`;
\ No newline at end of file
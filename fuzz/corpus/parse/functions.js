@@ -0,0 +1,6 @@
+function fib(n) { return n < 2 ? n : fib(n - 1) + fib(n - 2); }
+var results = [];
+for (let i = 0; i < 10; i++) {
+    results.push(fib(i));
+}
+results.join(", ");
\ No newline at end of file
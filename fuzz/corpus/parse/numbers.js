@@ -0,0 +1 @@
+0x1F 0o17 0b101 1_000_000 1e10 1.5e-3 .5 5. 0n 123n 0xFFn 017 08 09.5 0x 0b2 1__0 1_ 0n1 1.5n 1e 1e+ .e1 08n 0_1
\ No newline at end of file
@@ -0,0 +1,4 @@
+#!/usr/bin/env node
+a <!-- b
+--> c
+x --> y; a?.b ?? c ??= d &&= e ||= f ** g ... . . /* unterminated
\ No newline at end of file
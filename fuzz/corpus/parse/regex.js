@@ -0,0 +1 @@
+var re = /a[/]b\/c(?:d|e)+/gimsuy; "x".replace(/[^a-z]/g, ""); x = a / b / c; /[/
\ No newline at end of file
@@ -0,0 +1,4 @@
+const point = { x: 1, y: 2, get length() { return Math.sqrt(this.x ** 2 + this.y ** 2); } };
+label: for (var key in point) { if (key === "y") break label; continue; }
+try { throw new TypeError(`bad ${point.x}`); } catch (e) { e.message; } finally { point.x = 0; }
+switch (typeof point) { case "object": point.y ||= 3; break; default: void 0; }
\ No newline at end of file
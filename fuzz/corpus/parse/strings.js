@@ -0,0 +1,2 @@
+"a\x41B\u{43}\0\
+b" 'c\101' `t${ `n${1}` }\u{110000}` "\u{FFFFFFFFF}" "\x4" "\u12" "\u{" \ a \u{62}c 0 "unterminated
\ No newline at end of file
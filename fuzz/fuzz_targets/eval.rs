@@ -0,0 +1,17 @@
+//! Evaluates arbitrary bytes in a new context.
+//!
+//! The heap is limited, so that scripts allocating without bounds throw a `RangeError`. Scripts
+//! that never finish are reported as timeouts by libFuzzer.
+
+#![no_main]
+
+use boa::context::ContextBuilder;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut context = ContextBuilder::new()
+        .memory_limit(64 * 1024 * 1024)
+        .random_seed(0)
+        .build();
+    let _ = context.eval(data);
+});
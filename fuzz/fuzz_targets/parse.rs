@@ -0,0 +1,12 @@
+//! Parses arbitrary bytes, in both sloppy and strict mode.
+//!
+//! Invalid code must give a `ParseError`, so any panic is a bug.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = boa::parse(data, false);
+    let _ = boa::parse(data, true);
+});